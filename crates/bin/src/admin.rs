@@ -0,0 +1,119 @@
+//! Admin endpoint of a running instance. Lets the log filter be changed
+//! without restarting, which would lose the tip sync:
+//!
+//! - `GET /log-filter` returns the current directives
+//! - `PUT /log-filter?for=<seconds>` with the directives as body, e.g
+//!   `brontes_classifier=debug`. With `for` the startup filter is restored
+//!   after that many seconds, unless the filter was changed in the meantime
+//! - `DELETE /log-filter` restores the startup filter
+use std::{
+    convert::Infallible,
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    time::Duration,
+};
+
+use brontes_tracing::{log_filter, LogFilterHandle};
+use eyre::WrapErr;
+use hyper::{
+    service::{make_service_fn, service_fn},
+    Body, Method, Request, Response, Server, StatusCode,
+};
+
+/// Only reachable from the machine brontes runs on
+const ADMIN_ENDPOINT_IP: Ipv4Addr = Ipv4Addr::LOCALHOST;
+
+pub async fn start_admin_endpoint(port: u16) -> eyre::Result<()> {
+    let filter = log_filter()
+        .ok_or_else(|| eyre::eyre!("log filter isn't reloadable, can't serve admin endpoint"))?;
+
+    let make_svc = make_service_fn(move |_| async move {
+        Ok::<_, Infallible>(service_fn(move |req| handle(filter, req)))
+    });
+
+    let addr = SocketAddr::new(IpAddr::V4(ADMIN_ENDPOINT_IP), port);
+    let server = Server::try_bind(&addr)
+        .wrap_err("Could not bind to address")?
+        .serve(make_svc);
+
+    tokio::spawn(async move { server.await.expect("Admin endpoint crashed") });
+    tracing::info!(target: "brontes", %addr, "started admin endpoint");
+
+    Ok(())
+}
+
+async fn handle(
+    filter: &'static LogFilterHandle,
+    req: Request<Body>,
+) -> Result<Response<Body>, Infallible> {
+    if req.uri().path() != "/log-filter" {
+        return Ok(respond(StatusCode::NOT_FOUND, "not found".to_string()))
+    }
+
+    let response = match *req.method() {
+        Method::GET => respond(StatusCode::OK, filter.current()),
+        Method::PUT | Method::POST => set_filter(filter, req).await,
+        Method::DELETE => match filter.reset() {
+            Ok(_) => {
+                tracing::info!(target: "brontes", "restored startup log filter");
+                respond(StatusCode::OK, filter.current())
+            }
+            Err(e) => respond(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+        },
+        _ => respond(StatusCode::METHOD_NOT_ALLOWED, "method not allowed".to_string()),
+    };
+
+    Ok(response)
+}
+
+async fn set_filter(filter: &'static LogFilterHandle, req: Request<Body>) -> Response<Body> {
+    let revert_after = match revert_after(req.uri().query()) {
+        Ok(revert_after) => revert_after,
+        Err(e) => return respond(StatusCode::BAD_REQUEST, e),
+    };
+
+    let directives = match hyper::body::to_bytes(req.into_body()).await {
+        Ok(body) => String::from_utf8_lossy(&body).trim().to_string(),
+        Err(e) => return respond(StatusCode::BAD_REQUEST, e.to_string()),
+    };
+
+    let generation = match filter.set(&directives) {
+        Ok(generation) => generation,
+        Err(e) => return respond(StatusCode::BAD_REQUEST, e.to_string()),
+    };
+    tracing::info!(target: "brontes", %directives, ?revert_after, "changed log filter");
+
+    if let Some(revert_after) = revert_after {
+        tokio::spawn(async move {
+            tokio::time::sleep(revert_after).await;
+            match filter.reset_if_unchanged(generation) {
+                Ok(true) => tracing::info!(target: "brontes", "restored startup log filter"),
+                Ok(false) => {}
+                Err(e) => {
+                    tracing::error!(target: "brontes", err=%e, "failed to restore log filter")
+                }
+            }
+        });
+    }
+
+    respond(StatusCode::OK, filter.current())
+}
+
+fn revert_after(query: Option<&str>) -> Result<Option<Duration>, String> {
+    let Some(secs) = query
+        .into_iter()
+        .flat_map(|query| query.split('&'))
+        .find_map(|param| param.strip_prefix("for="))
+    else {
+        return Ok(None)
+    };
+
+    secs.parse()
+        .map(|secs| Some(Duration::from_secs(secs)))
+        .map_err(|_| format!("invalid duration {}, expected seconds", secs))
+}
+
+fn respond(status: StatusCode, body: String) -> Response<Body> {
+    let mut response = Response::new(Body::from(body));
+    *response.status_mut() = status;
+    response
+}
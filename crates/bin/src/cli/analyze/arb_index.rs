@@ -0,0 +1,65 @@
+use brontes_database::{libmdbx::Libmdbx, MevBlocks, PairArbIndex, PairArbIndexData};
+use brontes_types::db::arb_index::PairArbSummary;
+use clap::Parser;
+
+use super::parse_block_range;
+
+#[derive(Debug, Parser)]
+pub struct ArbIndex {
+    /// Block range to index, format: "start..end"
+    #[arg(long, short, value_parser = parse_block_range)]
+    pub range:       (u64, u64),
+    /// Blocks per index entry, defaults to roughly a day
+    #[arg(long, default_value = "7200")]
+    pub bucket_size: u64,
+}
+
+impl ArbIndex {
+    /// Splits the range into buckets starting at the first block and writes
+    /// the per pair atomic and cex-dex arbs of each one to the pair arb index.
+    /// Re-running over the same range overwrites the previous entries.
+    pub fn execute(self, brontes_db_endpoint: String) -> eyre::Result<()> {
+        if self.bucket_size == 0 {
+            eyre::bail!("bucket size must be at least one block")
+        }
+
+        let db = Libmdbx::init_db(brontes_db_endpoint, None)?;
+        let (start_block, end_block) = self.range;
+
+        let entries = db.view_db(|tx| {
+            let mut entries = Vec::new();
+            let mut bucket_start = start_block;
+
+            while bucket_start <= end_block {
+                let bucket_end = bucket_start
+                    .saturating_add(self.bucket_size - 1)
+                    .min(end_block);
+
+                let mut cursor = tx.new_cursor::<MevBlocks>()?;
+                let mut bundles = Vec::new();
+                for entry in cursor.walk_range(bucket_start..=bucket_end)? {
+                    bundles.extend(entry?.1.mev);
+                }
+
+                entries.push(PairArbIndexData::new(
+                    bucket_start,
+                    PairArbSummary::from_bundles(bucket_end, &bundles),
+                ));
+
+                if bucket_end == u64::MAX {
+                    break
+                }
+                bucket_start = bucket_end + 1;
+            }
+
+            Ok(entries)
+        })?;
+
+        db.write_table::<PairArbIndex, PairArbIndexData>(&entries)?;
+
+        let pairs: usize = entries.iter().map(|entry| entry.value.pairs.len()).sum();
+        println!("indexed {} ranges with {} pair entries", entries.len(), pairs);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+use std::path::Path;
+
+use brontes_classifier::Classifier;
+use brontes_core::decoding::Parser as DParser;
+use brontes_database::MevBlocks;
+use brontes_metrics::ParserMetricsListener;
+use brontes_types::{mev::BlockAuction, UnboundedYapperReceiver};
+use clap::Parser;
+use tokio::sync::mpsc::unbounded_channel;
+
+use super::parse_block_range;
+use crate::{
+    cli::{get_env_vars, get_tracing_provider, load_libmdbx, static_object},
+    runner::CliContext,
+};
+
+#[derive(Debug, Parser)]
+pub struct Auction {
+    /// Block range to reconstruct the auctions of, format: "start..end"
+    #[arg(long, short, value_parser = parse_block_range)]
+    pub range: (u64, u64),
+    /// Also prints the blocks where no mev had to outbid a competitor
+    #[arg(long, default_value = "false")]
+    pub all:   bool,
+}
+
+impl Auction {
+    /// Rebuilds the tree of each block to get the gas paid by every
+    /// transaction, including the reverted ones, and ranks them against the
+    /// mev found in the block.
+    pub async fn execute(self, brontes_db_endpoint: String, ctx: CliContext) -> eyre::Result<()> {
+        let reth_db_path = get_env_vars()?;
+        let task_executor = ctx.task_executor;
+
+        let (metrics_tx, metrics_rx) = unbounded_channel();
+        let metrics_listener = ParserMetricsListener::new(UnboundedYapperReceiver::new(
+            metrics_rx,
+            10_000,
+            "metrics".to_string(),
+        ));
+        task_executor.spawn_critical("metrics", metrics_listener);
+
+        let libmdbx = static_object(load_libmdbx(&task_executor, brontes_db_endpoint)?);
+        let tracer = get_tracing_provider(Path::new(&reth_db_path), 1, task_executor.clone());
+        let parser = DParser::new(metrics_tx, libmdbx, tracer).await;
+
+        // pricing is disabled for every block, the updates are never read
+        let (tx, _rx) = unbounded_channel();
+        let classifier = Classifier::new(libmdbx, tx, parser.get_tracer());
+
+        let (start_block, end_block) = self.range;
+        for block in start_block..=end_block {
+            let Some((traces, header)) = parser.execute(block, 0, None).await else {
+                tracing::warn!(block, "no traces for block, skipping");
+                continue
+            };
+            let bundles = libmdbx
+                .db
+                .view_db(|tx| Ok(tx.get::<MevBlocks>(block)?))?
+                .map(|mev_block| mev_block.mev)
+                .unwrap_or_default();
+
+            let tree = classifier.build_block_tree(traces, header, false).await;
+            let auction = BlockAuction::new(&tree, &bundles);
+
+            if self.all || auction.has_competition() {
+                println!("{}", auction);
+            }
+        }
+        classifier.close();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,155 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering::SeqCst},
+        Arc,
+    },
+};
+
+use brontes_classifier::Classifier;
+use brontes_core::decoding::TracingProvider;
+use brontes_database::libmdbx::LibmdbxReader;
+use brontes_inspect::{composer::run_block_inspection, thresholds::ThresholdConfig, Inspectors};
+use brontes_pricing::{
+    path_config::PathConfig, price_overrides::PriceRules, BrontesBatchPricer, GraphManager,
+};
+use brontes_types::{
+    constants::USDT_ADDRESS_STRING, db::cex::CexExchange, simulated_bundle::SimulatedBundle,
+    BlockData, FastHashMap, MultiBlockData, UnboundedYapperReceiver,
+};
+use clap::Parser;
+use futures::StreamExt;
+use reth_primitives::BlockId;
+use tokio::sync::mpsc::unbounded_channel;
+
+use crate::{
+    cli::{
+        get_env_vars, get_tracing_provider, init_inspectors, load_libmdbx, run::TimeWindowArgs,
+        static_object,
+    },
+    runner::CliContext,
+};
+
+const SECONDS_PER_SLOT: u64 = 12;
+
+#[derive(Debug, Parser)]
+pub struct AnalyzeBundle {
+    /// Bundle to analyze, as json with the state `block`, the bundle
+    /// `transactions` as raw signed transactions or hashes of on chain ones,
+    /// and optional `state_overrides` in the `eth_call` format
+    #[arg(long, short)]
+    pub file:             PathBuf,
+    /// Inspectors to run, if omitted all inspectors are run
+    #[arg(long, short, value_delimiter = ',')]
+    pub inspectors:       Option<Vec<Inspectors>>,
+    /// Optional quote asset, if omitted it will default to USDT
+    #[arg(long, short, default_value = USDT_ADDRESS_STRING)]
+    pub quote_asset:      String,
+    /// Time window arguments for cex data
+    #[clap(flatten)]
+    pub time_window_args: TimeWindowArgs,
+    /// CEX exchanges to consider for cex-dex analysis
+    #[arg(
+        long,
+        short,
+        default_value = "Binance,Coinbase,Okex,BybitSpot,Kucoin,Kraken",
+        value_delimiter = ','
+    )]
+    pub cex_exchanges:    Vec<CexExchange>,
+}
+
+impl AnalyzeBundle {
+    pub async fn execute(self, brontes_db_endpoint: String, ctx: CliContext) -> eyre::Result<()> {
+        let bundle: SimulatedBundle = serde_json::from_slice(&std::fs::read(&self.file)?)?;
+        if bundle.transactions.is_empty() {
+            eyre::bail!("bundle {} has no transactions", self.file.display())
+        }
+
+        let reth_db_path = get_env_vars()?;
+        let quote_asset = self.quote_asset.parse()?;
+        let task_executor = ctx.task_executor;
+
+        let libmdbx = static_object(load_libmdbx(&task_executor, brontes_db_endpoint)?);
+        let tracer =
+            Arc::new(get_tracing_provider(Path::new(&reth_db_path), 1, task_executor.clone()));
+
+        let traces = tracer
+            .simulate_bundle(
+                bundle.transactions,
+                BlockId::from(bundle.block),
+                bundle.state_overrides,
+            )
+            .await?;
+
+        // the bundle lands in the block after the state block
+        let mut header = tracer
+            .header_by_number(bundle.block)
+            .await?
+            .ok_or_else(|| eyre::eyre!("no header for block {}", bundle.block))?;
+        header.parent_hash = header.hash_slow();
+        header.number += 1;
+        header.timestamp += SECONDS_PER_SLOT;
+        let block = header.number;
+
+        let (tx, rx) = unbounded_channel();
+        let classifier = Classifier::new(libmdbx, tx, tracer.clone());
+        let tree = classifier.build_block_tree(traces, header, true).await;
+        classifier.close();
+
+        let finished = Arc::new(AtomicBool::new(false));
+        let mut pricer = BrontesBatchPricer::new(
+            0,
+            finished.clone(),
+            quote_asset,
+            GraphManager::init_from_db_state(libmdbx.protocols_created_before(block)?, None),
+            UnboundedYapperReceiver::new(rx, 100_000, "bundle pricer".into()),
+            tracer,
+            block,
+            FastHashMap::default(),
+            Arc::new(AtomicBool::new(false)),
+            None,
+            task_executor,
+        )
+        .with_price_rules(PriceRules::load_from_config())
+        .with_path_config(PathConfig::load_from_config());
+        finished.store(true, SeqCst);
+        let dex_quotes = pricer.next().await.map(|(_, quotes)| quotes);
+
+        // the bundle's block doesn't exist yet, so the cex data and block
+        // metadata come from the state block
+        let mut metadata = libmdbx
+            .get_metadata_no_dex_price(bundle.block, quote_asset)
+            .unwrap_or_default();
+        metadata.block_metadata.block_num = block;
+        metadata.dex_quotes = dex_quotes;
+
+        let inspectors = init_inspectors(
+            quote_asset,
+            libmdbx,
+            self.inspectors,
+            self.cex_exchanges,
+            self.time_window_args.trade_config(),
+            &ThresholdConfig::default(),
+            false,
+            false,
+        );
+
+        let data = MultiBlockData {
+            per_block_data: vec![BlockData { metadata: metadata.into(), tree: tree.into() }],
+            blocks:         1,
+        };
+        let results = run_block_inspection(inspectors, data, libmdbx, false);
+
+        if results.mev_details.is_empty() {
+            println!("no mev found in bundle");
+        }
+        for bundle in results.mev_details {
+            println!("{}", bundle);
+        }
+        if !results.possible_mev_txes.0.is_empty() {
+            println!("{}", results.possible_mev_txes);
+        }
+
+        Ok(())
+    }
+}
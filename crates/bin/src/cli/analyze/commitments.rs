@@ -0,0 +1,65 @@
+use brontes_database::{libmdbx::Libmdbx, BlockCommitments, BlockCommitmentsData, MevBlocks};
+use brontes_types::db::commitment::{BlockCommitment, CommitmentTree};
+use clap::Parser;
+
+use super::parse_block_range;
+
+#[derive(Debug, Parser)]
+pub struct Commitments {
+    /// Block range to commit to, format: "start..end"
+    #[arg(long, short, value_parser = parse_block_range)]
+    pub range: (u64, u64),
+    /// Also prints the proof of this block against the root of the range
+    #[arg(long)]
+    pub proof: Option<u64>,
+}
+
+impl Commitments {
+    /// Writes the commitment of every block in the range that has results and
+    /// prints the root of the range. Two datasets agree on the range if their
+    /// roots match, otherwise the per block commitments show where they differ.
+    pub fn execute(self, brontes_db_endpoint: String) -> eyre::Result<()> {
+        let db = Libmdbx::init_db(brontes_db_endpoint, None)?;
+        let (start_block, end_block) = self.range;
+
+        let entries = db.view_db(|tx| {
+            let mut cursor = tx.new_cursor::<MevBlocks>()?;
+            let mut entries = Vec::new();
+            for entry in cursor.walk_range(start_block..=end_block)? {
+                let (block, mev_block) = entry?;
+                entries.push(BlockCommitmentsData::new(block, BlockCommitment::new(&mev_block)?));
+            }
+
+            Ok(entries)
+        })?;
+
+        db.write_table::<BlockCommitments, BlockCommitmentsData>(&entries)?;
+
+        let tree = CommitmentTree::new(entries.iter().map(|entry| (entry.key, entry.value.root)));
+        println!(
+            "committed to {} blocks, range {}..{} root {}",
+            entries.len(),
+            start_block,
+            end_block,
+            tree.root()
+        );
+
+        if let Some(block) = self.proof {
+            if block < start_block || block > end_block {
+                eyre::bail!("block {} isn't in the committed range", block)
+            }
+
+            let leaf = entries
+                .iter()
+                .find(|entry| entry.key == block)
+                .map(|entry| entry.value.root)
+                .unwrap_or_default();
+            println!("block {} leaf {}", block, leaf);
+            for (height, sibling) in tree.proof(block).into_iter().enumerate() {
+                println!("{} {}", height, sibling);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+use brontes_database::{libmdbx::Libmdbx, MevBlocks, MevBlocksStaging};
+use brontes_inspect::Inspectors;
+use brontes_types::{mev::Bundle, FastHashMap};
+use clap::Parser;
+
+use super::parse_block_range;
+
+#[derive(Debug, Parser)]
+pub struct Diff {
+    /// The inspector whose bundles to compare
+    #[arg(long, short)]
+    pub inspector: Inspectors,
+    /// Block range to compare, format: "start..end"
+    #[arg(long, short, value_parser = parse_block_range)]
+    pub range:     (u64, u64),
+}
+
+impl Diff {
+    pub fn execute(self, brontes_db_endpoint: String) -> eyre::Result<()> {
+        let db = Libmdbx::init_db(brontes_db_endpoint, None)?;
+        let mev_types = self.inspector.mev_types();
+        let (start_block, end_block) = self.range;
+
+        let mut not_staged = 0usize;
+        let mut added = 0usize;
+        let mut removed = 0usize;
+        let mut changed = 0usize;
+
+        db.view_db(|tx| {
+            for block in start_block..=end_block {
+                let Some(staged) = tx.get::<MevBlocksStaging>(block)? else {
+                    not_staged += 1;
+                    continue
+                };
+                let production = tx
+                    .get::<MevBlocks>(block)?
+                    .map(|b| b.mev)
+                    .unwrap_or_default();
+
+                let by_hash = |bundles: Vec<Bundle>| {
+                    bundles
+                        .into_iter()
+                        .filter(|b| mev_types.contains(&b.header.mev_type))
+                        .map(|b| (b.header.tx_hash, b))
+                        .collect::<FastHashMap<_, _>>()
+                };
+                let production = by_hash(production);
+                let staged = by_hash(staged.mev);
+
+                for (hash, bundle) in &staged {
+                    match production.get(hash) {
+                        None => {
+                            added += 1;
+                            println!(
+                                "+ block {} {} {:?} profit: {:.2}",
+                                block, hash, bundle.header.mev_type, bundle.header.profit_usd
+                            );
+                        }
+                        Some(prod) if prod != bundle => {
+                            changed += 1;
+                            println!(
+                                "~ block {} {} {:?} profit: {:.2} -> {:.2}",
+                                block,
+                                hash,
+                                bundle.header.mev_type,
+                                prod.header.profit_usd,
+                                bundle.header.profit_usd
+                            );
+                        }
+                        _ => {}
+                    }
+                }
+
+                for (hash, bundle) in production
+                    .iter()
+                    .filter(|(hash, _)| !staged.contains_key(*hash))
+                {
+                    removed += 1;
+                    println!(
+                        "- block {} {} {:?} profit: {:.2}",
+                        block, hash, bundle.header.mev_type, bundle.header.profit_usd
+                    );
+                }
+            }
+
+            Ok(())
+        })?;
+
+        println!(
+            "{}: {} added, {} removed, {} changed, {} blocks without staged results",
+            self.inspector, added, removed, changed, not_staged
+        );
+
+        Ok(())
+    }
+}
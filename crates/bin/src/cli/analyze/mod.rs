@@ -0,0 +1,98 @@
+use clap::{Parser, Subcommand};
+
+use crate::{cli::db::record_command, runner::CliContext};
+mod arb_index;
+mod auction;
+mod bundle;
+mod commitments;
+mod diff;
+mod promote;
+mod rerun;
+mod validate;
+
+#[derive(Debug, Parser)]
+pub struct Analyze {
+    #[clap(subcommand)]
+    pub command: AnalyzeCommands,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum AnalyzeCommands {
+    /// Re-runs a single inspector over a block range, writing the results to
+    /// the staging table
+    #[command(name = "rerun")]
+    Rerun(rerun::Rerun),
+    /// Compares the staged results of an inspector against the production
+    /// mev blocks
+    #[command(name = "diff")]
+    Diff(diff::Diff),
+    /// Replaces the production bundles of an inspector with the staged ones
+    #[command(name = "promote")]
+    Promote(promote::Promote),
+    /// Compares brontes results against a published mev dataset and reports
+    /// the agreement per mev type
+    #[command(name = "validate")]
+    Validate(validate::Validate),
+    /// Simulates a bundle on top of a block and runs the inspectors over it,
+    /// for analyzing bundles that never landed on chain
+    #[command(name = "bundle")]
+    Bundle(bundle::AnalyzeBundle),
+    /// Aggregates the realized atomic and cex-dex arbs per pair and block
+    /// range into the pair arb index table
+    #[command(name = "arb-index")]
+    ArbIndex(arb_index::ArbIndex),
+    /// Writes a commitment to the results of each block in a range and prints
+    /// the root of the range, for checking two datasets hold the same results
+    #[command(name = "commitments")]
+    Commitments(commitments::Commitments),
+    /// Reconstructs the gas auction of each block in a range, ranking every
+    /// transaction by bribe per gas and showing which competitors the mev
+    /// outbid, reverted attempts included
+    #[command(name = "auction")]
+    Auction(auction::Auction),
+}
+
+impl Analyze {
+    pub async fn execute(self, brontes_db_endpoint: String, ctx: CliContext) -> eyre::Result<()> {
+        match &self.command {
+            AnalyzeCommands::Rerun(_) => record_command(&brontes_db_endpoint, "analyze rerun")?,
+            AnalyzeCommands::Promote(_) => record_command(&brontes_db_endpoint, "analyze promote")?,
+            AnalyzeCommands::ArbIndex(_) => {
+                record_command(&brontes_db_endpoint, "analyze arb-index")?
+            }
+            AnalyzeCommands::Commitments(_) => {
+                record_command(&brontes_db_endpoint, "analyze commitments")?
+            }
+            _ => {}
+        }
+
+        match self.command {
+            AnalyzeCommands::Rerun(cmd) => cmd.execute(brontes_db_endpoint, ctx).await,
+            AnalyzeCommands::Diff(cmd) => cmd.execute(brontes_db_endpoint),
+            AnalyzeCommands::Promote(cmd) => cmd.execute(brontes_db_endpoint),
+            AnalyzeCommands::Validate(cmd) => cmd.execute(brontes_db_endpoint),
+            AnalyzeCommands::Bundle(cmd) => cmd.execute(brontes_db_endpoint, ctx).await,
+            AnalyzeCommands::ArbIndex(cmd) => cmd.execute(brontes_db_endpoint),
+            AnalyzeCommands::Commitments(cmd) => cmd.execute(brontes_db_endpoint),
+            AnalyzeCommands::Auction(cmd) => cmd.execute(brontes_db_endpoint, ctx).await,
+        }
+    }
+}
+
+/// parses a block range in the format `start..end`, both ends inclusive
+pub(crate) fn parse_block_range(range: &str) -> Result<(u64, u64), String> {
+    let (start, end) = range
+        .split_once("..")
+        .ok_or_else(|| format!("invalid range: {}, expected start..end", range))?;
+    let start: u64 = start
+        .parse()
+        .map_err(|_| format!("invalid start block: {}", start))?;
+    let end: u64 = end
+        .parse()
+        .map_err(|_| format!("invalid end block: {}", end))?;
+    if start > end {
+        return Err(format!("start block {} must be less than or equal to end block {}", start, end))
+    }
+
+    Ok((start, end))
+}
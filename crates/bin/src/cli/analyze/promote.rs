@@ -0,0 +1,58 @@
+use brontes_database::{libmdbx::Libmdbx, MevBlocks, MevBlocksData, MevBlocksStaging};
+use brontes_inspect::{composer::refresh_bundle_summary, Inspectors};
+use brontes_types::db::mev_block::MevBlockWithClassified;
+use clap::Parser;
+
+use super::parse_block_range;
+
+#[derive(Debug, Parser)]
+pub struct Promote {
+    /// The inspector whose staged bundles should replace production
+    #[arg(long, short)]
+    pub inspector: Inspectors,
+    /// Block range to promote, format: "start..end"
+    #[arg(long, short, value_parser = parse_block_range)]
+    pub range:     (u64, u64),
+}
+
+impl Promote {
+    /// Swaps out the bundles produced by the inspector in the production mev
+    /// blocks for the staged ones, leaving the bundles of all other
+    /// inspectors untouched. The block's bundle counts, profit and bribes are
+    /// summed again over the merged bundles. Blocks without staged results
+    /// are skipped.
+    pub fn execute(self, brontes_db_endpoint: String) -> eyre::Result<()> {
+        let db = Libmdbx::init_db(brontes_db_endpoint, None)?;
+        let mev_types = self.inspector.mev_types();
+        let (start_block, end_block) = self.range;
+
+        let promoted = db.view_db(|tx| {
+            let mut promoted = Vec::new();
+            for block in start_block..=end_block {
+                let Some(staged) = tx.get::<MevBlocksStaging>(block)? else { continue };
+
+                let merged = match tx.get::<MevBlocks>(block)? {
+                    Some(MevBlockWithClassified { block: mut mev_block, mev }) => {
+                        let mev = mev
+                            .into_iter()
+                            .filter(|b| !mev_types.contains(&b.header.mev_type))
+                            .chain(staged.mev)
+                            .collect::<Vec<_>>();
+                        refresh_bundle_summary(&mut mev_block, &mev);
+                        MevBlockWithClassified { block: mev_block, mev }
+                    }
+                    None => staged,
+                };
+
+                promoted.push(MevBlocksData::new(block, merged));
+            }
+
+            Ok(promoted)
+        })?;
+
+        db.write_table::<MevBlocks, MevBlocksData>(&promoted)?;
+        println!("promoted {} blocks of {} results", promoted.len(), self.inspector);
+
+        Ok(())
+    }
+}
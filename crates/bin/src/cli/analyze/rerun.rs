@@ -0,0 +1,148 @@
+use std::path::Path;
+
+use brontes_core::decoding::Parser as DParser;
+use brontes_database::clickhouse::cex_config::CexDownloadConfig;
+use brontes_inspect::{thresholds::ThresholdConfig, Inspectors};
+use brontes_metrics::ParserMetricsListener;
+use brontes_types::{
+    constants::USDT_ADDRESS_STRING, db::cex::CexExchange, init_thread_pools,
+    UnboundedYapperReceiver,
+};
+use clap::Parser;
+use tokio::sync::mpsc::unbounded_channel;
+
+use super::parse_block_range;
+use crate::{
+    cli::{
+        determine_max_tasks, get_env_vars, get_tracing_provider, init_inspectors, load_clickhouse,
+        load_libmdbx, run::TimeWindowArgs, static_object,
+    },
+    runner::CliContext,
+    BrontesRunConfig, RangeType, StagingProcessor,
+};
+
+#[derive(Debug, Parser)]
+pub struct Rerun {
+    /// The inspector to re-run
+    #[arg(long, short)]
+    pub inspector:         Inspectors,
+    /// Block range to re-run, format: "start..end"
+    #[arg(long, short, value_parser = parse_block_range)]
+    pub range:             (u64, u64),
+    /// Optional Max Tasks, if omitted it will default to 80% of the number of
+    /// physical cores on your machine
+    #[arg(long, short)]
+    pub max_tasks:         Option<u64>,
+    /// Optional minimum batch size
+    #[arg(long, default_value = "500")]
+    pub min_batch_size:    u64,
+    /// Optional quote asset, if omitted it will default to USDT
+    #[arg(long, short, default_value = USDT_ADDRESS_STRING)]
+    pub quote_asset:       String,
+    /// Time window arguments for cex data downloads
+    #[clap(flatten)]
+    pub time_window_args:  TimeWindowArgs,
+    /// CEX exchanges to consider for cex-dex analysis
+    #[arg(
+        long,
+        short,
+        default_value = "Binance,Coinbase,Okex,BybitSpot,Kucoin,Kraken",
+        value_delimiter = ','
+    )]
+    pub cex_exchanges:     Vec<CexExchange>,
+    /// Force DEX price calculation for every block, ignoring existing database
+    /// values.
+    #[arg(long, short, default_value = "false")]
+    pub force_dex_pricing: bool,
+}
+
+impl Rerun {
+    pub async fn execute(self, brontes_db_endpoint: String, ctx: CliContext) -> eyre::Result<()> {
+        let snapshot_mode = !cfg!(feature = "local-clickhouse");
+        let reth_db_path = get_env_vars()?;
+        let quote_asset = self.quote_asset.parse()?;
+        let task_executor = ctx.task_executor;
+
+        let max_tasks = determine_max_tasks(self.max_tasks);
+        init_thread_pools(max_tasks as usize);
+
+        let (metrics_tx, metrics_rx) = unbounded_channel();
+        let metrics_listener = ParserMetricsListener::new(UnboundedYapperReceiver::new(
+            metrics_rx,
+            10_000,
+            "metrics".to_string(),
+        ));
+        task_executor.spawn_critical("metrics", metrics_listener);
+
+        // results only go to the staging table, so we skip the clickhouse
+        // middleware and write straight to libmdbx
+        let libmdbx = static_object(load_libmdbx(&task_executor, brontes_db_endpoint)?);
+
+        let load_window = self.time_window_args.load_time_window();
+        let cex_download_config = CexDownloadConfig::new(
+            (load_window as u64, load_window as u64),
+            self.cex_exchanges.clone(),
+        );
+        let clickhouse = static_object(load_clickhouse(cex_download_config, None).await?);
+
+        let force_no_dex_pricing =
+            matches!(self.inspector, Inspectors::CexDex | Inspectors::CexDexMarkout);
+
+        let inspectors = init_inspectors(
+            quote_asset,
+            libmdbx,
+            Some(vec![self.inspector]),
+            self.cex_exchanges,
+            self.time_window_args.trade_config(),
+            &ThresholdConfig::default(),
+            false,
+            false,
+        );
+
+        let tracer =
+            get_tracing_provider(Path::new(&reth_db_path), max_tasks, task_executor.clone());
+        let parser = static_object(DParser::new(metrics_tx, libmdbx, tracer.clone()).await);
+
+        let (start_block, end_block) = self.range;
+        tracing::info!(inspector=%self.inspector, start_block, end_block, "re-running inspector");
+
+        let executor = task_executor.clone();
+        let result = executor
+            .clone()
+            .spawn_critical_with_graceful_shutdown_signal("rerun init", |shutdown| async move {
+                if let Ok(brontes) = BrontesRunConfig::<_, _, _, StagingProcessor>::new(
+                    RangeType::SingleRange {
+                        start_block:   Some(start_block),
+                        end_block:     Some(end_block),
+                        back_from_tip: 0,
+                    },
+                    max_tasks,
+                    self.min_batch_size,
+                    quote_asset,
+                    self.force_dex_pricing,
+                    force_no_dex_pricing,
+                    inspectors,
+                    clickhouse,
+                    parser,
+                    libmdbx,
+                    libmdbx,
+                    true,
+                    false,
+                    snapshot_mode,
+                    load_window,
+                )
+                .build(task_executor, shutdown)
+                .await
+                .map_err(|e| {
+                    tracing::error!(%e);
+                    e
+                }) {
+                    brontes.await;
+                }
+            });
+
+        result.await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,227 @@
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+    path::PathBuf,
+};
+
+use brontes_database::{libmdbx::Libmdbx, MevBlocks, MevBlocksStaging};
+use brontes_types::{
+    mev::{Mev, MevType},
+    FastHashMap, FastHashSet,
+};
+use clap::Parser;
+use reth_primitives::B256;
+use serde::Deserialize;
+
+use super::parse_block_range;
+
+#[derive(Debug, Parser)]
+pub struct Validate {
+    /// Reference dataset to compare against, as json lines with a
+    /// `block_number`, `tx_hash` and `mev_type` per line. Exports of
+    /// mev-inspect-py and ZeroMEV can be used directly
+    #[arg(long, short)]
+    pub dataset:       PathBuf,
+    /// Block range to compare, format: "start..end"
+    #[arg(long, short, value_parser = parse_block_range)]
+    pub range:         (u64, u64),
+    /// Validate the staged results of a rerun instead of production
+    #[arg(long, default_value_t = false)]
+    pub staged:        bool,
+    /// Fails if the agreement for any mev type is below this ratio
+    #[arg(long)]
+    pub min_agreement: Option<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReferenceRecord {
+    block_number: u64,
+    #[serde(alias = "transaction_hash")]
+    tx_hash:      B256,
+    #[serde(alias = "type")]
+    mev_type:     String,
+}
+
+/// Maps the mev type names used by the published datasets to ours. For
+/// sandwiches, ZeroMEV flags both the frontrun and the backrun, we only take
+/// the frontrun so a sandwich isn't counted twice
+fn reference_mev_type(name: &str) -> Option<MevType> {
+    match name.to_lowercase().as_str() {
+        "sandwich" | "sandwiches" | "frontrun" => Some(MevType::Sandwich),
+        "arb" | "arbitrage" | "arbitrages" => Some(MevType::AtomicArb),
+        "liquid" | "liquidation" | "liquidations" => Some(MevType::Liquidation),
+        _ => None,
+    }
+}
+
+/// The published datasets don't distinguish jit sandwiches from regular ones
+fn comparable_mev_type(mev_type: MevType) -> Option<MevType> {
+    match mev_type {
+        MevType::Sandwich | MevType::JitSandwich => Some(MevType::Sandwich),
+        MevType::AtomicArb => Some(MevType::AtomicArb),
+        MevType::Liquidation => Some(MevType::Liquidation),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Default)]
+struct Agreement {
+    reference: usize,
+    brontes:   usize,
+    matched:   usize,
+}
+
+impl Agreement {
+    fn recall(&self) -> f64 {
+        ratio(self.matched, self.reference)
+    }
+
+    fn precision(&self) -> f64 {
+        ratio(self.matched, self.brontes)
+    }
+
+    /// matched over the union of both sets
+    fn agreement(&self) -> f64 {
+        ratio(self.matched, self.reference + self.brontes - self.matched)
+    }
+}
+
+fn ratio(num: usize, denom: usize) -> f64 {
+    if denom == 0 {
+        return 1.0
+    }
+    num as f64 / denom as f64
+}
+
+impl Validate {
+    pub fn execute(self, brontes_db_endpoint: String) -> eyre::Result<()> {
+        let (start_block, end_block) = self.range;
+        let reference = self.load_reference(start_block, end_block)?;
+
+        let db = Libmdbx::init_db(brontes_db_endpoint, None)?;
+
+        let mut results: FastHashMap<MevType, Agreement> = FastHashMap::default();
+        let mut missing_blocks = 0usize;
+
+        db.view_db(|tx| {
+            for block in start_block..=end_block {
+                let bundles = if self.staged {
+                    tx.get::<MevBlocksStaging>(block)?
+                } else {
+                    tx.get::<MevBlocks>(block)?
+                };
+                let Some(bundles) = bundles.map(|b| b.mev) else {
+                    missing_blocks += 1;
+                    continue
+                };
+
+                let mut block_reference = reference
+                    .get(&block)
+                    .cloned()
+                    .unwrap_or_default()
+                    .into_iter()
+                    .collect::<FastHashSet<_>>();
+
+                for bundle in bundles {
+                    let Some(mev_type) = comparable_mev_type(bundle.header.mev_type) else {
+                        continue
+                    };
+                    let entry = results.entry(mev_type).or_default();
+                    entry.brontes += 1;
+
+                    let hashes = bundle.data.mev_transaction_hashes();
+                    let matched = block_reference
+                        .iter()
+                        .filter(|(ty, hash)| *ty == mev_type && hashes.contains(hash))
+                        .cloned()
+                        .collect::<Vec<_>>();
+
+                    if !matched.is_empty() {
+                        entry.matched += 1;
+                        entry.reference += 1;
+                        matched.iter().for_each(|m| {
+                            block_reference.remove(m);
+                        });
+                    } else {
+                        println!(
+                            "+ block {} {} {:?} only found by brontes",
+                            block, bundle.header.tx_hash, mev_type
+                        );
+                    }
+                }
+
+                for (mev_type, hash) in block_reference {
+                    results.entry(mev_type).or_default().reference += 1;
+                    println!("- block {} {} {:?} missed by brontes", block, hash, mev_type);
+                }
+            }
+
+            Ok(())
+        })?;
+
+        if missing_blocks != 0 {
+            println!(
+                "{} blocks in the range have no brontes results and were skipped",
+                missing_blocks
+            );
+        }
+
+        let mut failed = Vec::new();
+        for (mev_type, agreement) in &results {
+            println!(
+                "{:?}: reference {}, brontes {}, matched {}, recall {:.3}, precision {:.3}, \
+                 agreement {:.3}",
+                mev_type,
+                agreement.reference,
+                agreement.brontes,
+                agreement.matched,
+                agreement.recall(),
+                agreement.precision(),
+                agreement.agreement()
+            );
+
+            if self
+                .min_agreement
+                .is_some_and(|min| agreement.agreement() < min)
+            {
+                failed.push(*mev_type);
+            }
+        }
+
+        if !failed.is_empty() {
+            eyre::bail!("agreement below {:?} for {:?}", self.min_agreement.unwrap(), failed)
+        }
+
+        Ok(())
+    }
+
+    /// loads the reference records in the range, grouped by block
+    fn load_reference(
+        &self,
+        start_block: u64,
+        end_block: u64,
+    ) -> eyre::Result<FastHashMap<u64, Vec<(MevType, B256)>>> {
+        let reader = BufReader::new(File::open(&self.dataset)?);
+        let mut reference: FastHashMap<u64, Vec<(MevType, B256)>> = FastHashMap::default();
+
+        for line in reader.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue
+            }
+
+            let record: ReferenceRecord = serde_json::from_str(&line)?;
+            if record.block_number < start_block || record.block_number > end_block {
+                continue
+            }
+            let Some(mev_type) = reference_mev_type(&record.mev_type) else { continue };
+
+            reference
+                .entry(record.block_number)
+                .or_default()
+                .push((mev_type, record.tx_hash));
+        }
+
+        Ok(reference)
+    }
+}
@@ -0,0 +1,160 @@
+//! Seeds a new database with the metadata needed to classify blocks: the
+//! protocol of each known pool and its creation block, token info and the
+//! curated searcher lists. They are published as gzipped json rows of their
+//! table, listed in a manifest with the hash of each file, so a first run
+//! classifies most of the chain without backfilling the metadata first.
+use alloy_primitives::{keccak256, B256};
+use brontes_database::{
+    libmdbx::{types::LibmdbxData, Libmdbx},
+    AddressToProtocolInfo, AddressToProtocolInfoData, CompressedTable, PoolCreationBlocks,
+    PoolCreationBlocksData, SearcherContracts, SearcherContractsData, SearcherEOAs,
+    SearcherEOAsData, Tables, TokenDecimals, TokenDecimalsData,
+};
+use clap::Parser;
+use flate2::read::GzDecoder;
+use reqwest::Url;
+use serde::{de::DeserializeOwned, Deserialize};
+
+use super::db::record_command;
+
+const MANIFEST: &str = "manifest.json";
+
+#[derive(Debug, Parser)]
+pub struct Bootstrap {
+    /// Where the seed datasets are hosted
+    #[arg(long, default_value = "https://data.brontes.xyz/bootstrap/")]
+    pub endpoint:  Url,
+    /// Only load these datasets, by name. If omitted all of them are loaded
+    #[arg(long, short, value_delimiter = ',')]
+    pub datasets:  Option<Vec<String>>,
+    /// Load datasets into tables that already have entries, overwriting the
+    /// entries with the same key. By default these tables are skipped
+    #[arg(long, default_value_t = false)]
+    pub overwrite: bool,
+}
+
+/// A dataset in the manifest
+#[derive(Debug, Deserialize)]
+pub struct SeedDataset {
+    pub name:  String,
+    /// Table the rows are written to
+    pub table: Tables,
+    /// Path of the gzipped json rows, relative to the endpoint
+    pub file:  String,
+    /// keccak256 of the file
+    pub hash:  B256,
+}
+
+impl Bootstrap {
+    pub async fn execute(self, brontes_db_endpoint: String) -> eyre::Result<()> {
+        record_command(&brontes_db_endpoint, "bootstrap")?;
+        let db = Libmdbx::init_db(brontes_db_endpoint, None)?;
+
+        let client = reqwest::Client::new();
+        let manifest: Vec<SeedDataset> = client
+            .get(self.endpoint.join(MANIFEST)?)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        if let Some(wanted) = &self.datasets {
+            if let Some(missing) = wanted
+                .iter()
+                .find(|name| !manifest.iter().any(|d| &d.name == *name))
+            {
+                eyre::bail!("no dataset named {} at {}", missing, self.endpoint)
+            }
+        }
+
+        for dataset in manifest {
+            if self
+                .datasets
+                .as_ref()
+                .is_some_and(|wanted| !wanted.contains(&dataset.name))
+            {
+                continue
+            }
+
+            if !db.profile().stores(dataset.table) {
+                tracing::info!(dataset=%dataset.name, table=%dataset.table, "table isn't stored by this db, skipping");
+                continue
+            }
+
+            let file = client
+                .get(self.endpoint.join(&dataset.file)?)
+                .send()
+                .await?
+                .error_for_status()?
+                .bytes()
+                .await?;
+
+            let hash = keccak256(&file);
+            if hash != dataset.hash {
+                eyre::bail!(
+                    "{} failed verification, expected hash {:?} got {:?}",
+                    dataset.name,
+                    dataset.hash,
+                    hash
+                )
+            }
+
+            let loaded = match dataset.table {
+                Tables::AddressToProtocolInfo => self
+                    .load::<AddressToProtocolInfo, AddressToProtocolInfoData>(
+                        &db, &dataset, &file,
+                    )?,
+                Tables::TokenDecimals => {
+                    self.load::<TokenDecimals, TokenDecimalsData>(&db, &dataset, &file)?
+                }
+                Tables::PoolCreationBlocks => {
+                    self.load::<PoolCreationBlocks, PoolCreationBlocksData>(&db, &dataset, &file)?
+                }
+                Tables::SearcherEOAs => {
+                    self.load::<SearcherEOAs, SearcherEOAsData>(&db, &dataset, &file)?
+                }
+                Tables::SearcherContracts => {
+                    self.load::<SearcherContracts, SearcherContractsData>(&db, &dataset, &file)?
+                }
+                table => eyre::bail!("{} can't be bootstrapped into {}", dataset.name, table),
+            };
+
+            match loaded {
+                Some(rows) => {
+                    println!("loaded {} rows of {} into {}", rows, dataset.name, dataset.table)
+                }
+                None => println!(
+                    "skipped {}, {} already has entries. Use --overwrite to load it anyway",
+                    dataset.name, dataset.table
+                ),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes the rows of a verified file, `None` if the table already has
+    /// entries and isn't overwritten
+    fn load<T, D>(
+        &self,
+        db: &Libmdbx,
+        dataset: &SeedDataset,
+        file: &[u8],
+    ) -> eyre::Result<Option<usize>>
+    where
+        T: CompressedTable,
+        T::Value: From<T::DecompressedValue> + Into<T::DecompressedValue>,
+        D: LibmdbxData<T> + DeserializeOwned,
+    {
+        if !self.overwrite && db.view_db(|tx| Ok(tx.new_cursor::<T>()?.first()?.is_some()))? {
+            return Ok(None)
+        }
+
+        let rows: Vec<D> = serde_json::from_reader(GzDecoder::new(file))
+            .map_err(|e| eyre::eyre!("failed to decode {}: {}", dataset.name, e))?;
+        db.write_table::<T, D>(&rows)?;
+
+        Ok(Some(rows.len()))
+    }
+}
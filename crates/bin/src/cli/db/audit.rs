@@ -0,0 +1,62 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use brontes_database::{libmdbx::Libmdbx, AuditLog, AuditLogData};
+use brontes_types::db::audit::AuditEntry;
+use clap::Parser;
+
+#[derive(Debug, Parser)]
+pub struct Audit {
+    /// Only show the most recent entries
+    #[arg(long, short)]
+    pub limit:   Option<usize>,
+    /// Only show entries of this command, e.g "db clear"
+    #[arg(long, short)]
+    pub command: Option<String>,
+}
+
+impl Audit {
+    pub fn execute(self, brontes_db_endpoint: String) -> eyre::Result<()> {
+        let db = Libmdbx::init_db(brontes_db_endpoint, None)?;
+
+        let mut entries = db.view_db(|tx| {
+            let mut cursor = tx.new_cursor::<AuditLog>()?;
+            Ok(cursor
+                .walk_range(..)?
+                .flatten()
+                .filter(|(_, entry)| self.command.as_ref().map_or(true, |c| &entry.command == c))
+                .collect::<Vec<_>>())
+        })?;
+
+        if let Some(limit) = self.limit {
+            entries.drain(..entries.len().saturating_sub(limit));
+        }
+
+        for (started_at, entry) in entries {
+            println!(
+                "{:<10} {:<12} {:<24} {}",
+                started_at / 1_000_000_000,
+                entry.user,
+                entry.command,
+                entry.command_line()
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Appends the command to the audit log. This is done before the command
+/// runs, as it opens the database itself.
+pub(crate) fn record_command(brontes_db_endpoint: &str, command: &str) -> eyre::Result<()> {
+    let started_at = SystemTime::now().duration_since(UNIX_EPOCH)?.as_nanos() as u64;
+    let entry = AuditEntry {
+        command: command.to_string(),
+        args:    std::env::args().collect(),
+        user:    std::env::var("USER").unwrap_or_default(),
+    };
+
+    let db = Libmdbx::init_db(brontes_db_endpoint, None)?;
+    db.write_table::<AuditLog, AuditLogData>(&[AuditLogData::new(started_at, entry)])?;
+
+    Ok(())
+}
@@ -12,8 +12,9 @@ pub struct Clear {
         short,
         value_delimiter = ',',
         default_value = "CexPrice,DexPrice,CexTrades,BlockInfo,InitializedState,MevBlocks,\
-                         TokenDecimals,AddressToProtocolInfo,PoolCreationBlocks,Builder,\
-                         AddressMeta,SearcherEOAs,SearcherContracts,SubGraphs,TxTraces"
+                         MevBlocksStaging,TokenDecimals,InferredTokenDecimals,\
+                         AddressToProtocolInfo,AddressToProtocolHistory,PoolCreationBlocks,\
+                         Builder,AddressMeta,SearcherEOAs,SearcherContracts,SubGraphs,TxTraces"
     )]
     pub tables:                  Vec<Tables>,
     /// Mark metadata as uninitialized in the initialized state table
@@ -59,8 +60,11 @@ impl Clear {
                 BlockInfo,
                 DexPrice,
                 MevBlocks,
+                MevBlocksStaging,
                 TokenDecimals,
+                InferredTokenDecimals,
                 AddressToProtocolInfo,
+                AddressToProtocolHistory,
                 PoolCreationBlocks,
                 Builder,
                 AddressMeta,
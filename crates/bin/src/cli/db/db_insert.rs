@@ -45,14 +45,23 @@ impl Insert {
             BlockInfo,
             DexPrice,
             MevBlocks,
+            MevBlocksStaging,
             AddressToProtocolInfo,
+            AddressToProtocolHistory,
             TokenDecimals,
+            InferredTokenDecimals,
             TxTraces,
             Builder,
             AddressMeta,
             SearcherEOAs,
             SearcherContracts,
             InitializedState,
+            AuditLog,
+            PairArbIndex,
+            BlockCommitments,
+            CexListings,
+            TransferFees,
+            BuilderPayments,
             PoolCreationBlocks = &self.key,
             &self.value
         );
@@ -70,8 +70,11 @@ impl DatabaseQuery {
                     BlockInfo,
                     DexPrice,
                     MevBlocks,
+                    MevBlocksStaging,
                     TokenDecimals,
+                    InferredTokenDecimals,
                     AddressToProtocolInfo,
+                    AddressToProtocolHistory,
                     PoolCreationBlocks,
                     Builder,
                     AddressMeta,
@@ -89,8 +92,11 @@ impl DatabaseQuery {
                     BlockInfo,
                     DexPrice,
                     MevBlocks,
+                    MevBlocksStaging,
                     TokenDecimals,
+                    InferredTokenDecimals,
                     AddressToProtocolInfo,
+                    AddressToProtocolHistory,
                     Builder,
                     InitializedState,
                     AddressMeta,
@@ -0,0 +1,72 @@
+use std::{env, path::Path, sync::Arc};
+
+use brontes_database::tardis::{TardisConfig, TardisImporter};
+use brontes_types::init_thread_pools;
+use clap::{Parser, ValueEnum};
+
+use crate::{
+    cli::{get_env_vars, get_tracing_provider, load_libmdbx, static_object},
+    runner::CliContext,
+};
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum CexSource {
+    /// Tardis.dev's historical datasets, set TARDIS_API_KEY for days other
+    /// than the first of each month
+    Tardis,
+}
+
+#[derive(Debug, Parser)]
+pub struct ImportCex {
+    /// Where to download the cex quotes and trades from
+    #[arg(long, value_enum)]
+    pub source:      CexSource,
+    /// Start Block
+    #[arg(long, short)]
+    pub start_block: u64,
+    /// End Block
+    #[arg(long, short)]
+    pub end_block:   u64,
+    /// File listing the symbols to import along with the tokens they trade
+    #[arg(long, default_value = "config/tardis_config.toml")]
+    pub symbols:     String,
+    /// Take quotes from order book snapshots, keeping the depth used to price
+    /// large hedges. Downloads are much larger
+    #[arg(long, default_value_t = false)]
+    pub depth:       bool,
+}
+
+impl ImportCex {
+    pub async fn execute(self, brontes_db_endpoint: String, ctx: CliContext) -> eyre::Result<()> {
+        if self.start_block > self.end_block {
+            eyre::bail!("start block {} is after end block {}", self.start_block, self.end_block)
+        }
+        let db_path = get_env_vars()?;
+
+        init_thread_pools(10);
+        let task_executor = ctx.task_executor;
+
+        let libmdbx = static_object(load_libmdbx(&task_executor, brontes_db_endpoint)?);
+        let tracer = Arc::new(get_tracing_provider(Path::new(&db_path), 10, task_executor));
+
+        match self.source {
+            CexSource::Tardis => {
+                let config = TardisConfig::load(&self.symbols)?;
+                let api_key = env::var("TARDIS_API_KEY")
+                    .ok()
+                    .filter(|key| !key.is_empty());
+                TardisImporter::new(config, tracer, libmdbx, api_key)
+                    .with_depth(self.depth)
+                    .import(self.start_block, self.end_block)
+                    .await?;
+            }
+        }
+
+        println!(
+            "imported cex quotes and trades for blocks {} to {}",
+            self.start_block, self.end_block
+        );
+
+        Ok(())
+    }
+}
@@ -1,6 +1,9 @@
 use std::{path::Path, sync::Arc};
 
-use brontes_database::{libmdbx::LibmdbxInit, Tables};
+use brontes_database::{
+    libmdbx::{DbProfile, LibmdbxInit},
+    Tables,
+};
 use brontes_types::{db::cex::CexExchange, init_thread_pools};
 use clap::Parser;
 use indicatif::MultiProgress;
@@ -47,7 +50,7 @@ pub struct Init {
     #[arg(
         long,
         short,
-        default_value = "Binance,Coinbase,Okex,BybitSpot,Kucoin",
+        default_value = "Binance,Coinbase,Okex,BybitSpot,Kucoin,Kraken",
         value_delimiter = ','
     )]
     pub cex_exchanges:             Vec<CexExchange>,
@@ -61,15 +64,30 @@ pub struct Init {
     /// false it will run the dex pricing locally using raw on-chain data
     #[arg(long, short, default_value = "false")]
     pub download_dex_pricing:      bool,
+    /// Create a lite database that doesn't store the raw traces, only the
+    /// classified results. Can only be set when the database is first created
+    #[arg(long = "lite", default_value = "false")]
+    pub lite_profile:              bool,
 }
 
 impl Init {
+    /// Has to run before anything opens the database, as opening creates it
+    /// with whatever profile it has
+    pub fn select_profile(&self, brontes_db_endpoint: &str) -> eyre::Result<()> {
+        if self.lite_profile {
+            DbProfile::Lite.select(Path::new(brontes_db_endpoint))?;
+        }
+
+        Ok(())
+    }
+
     pub async fn execute(self, brontes_db_endpoint: String, ctx: CliContext) -> eyre::Result<()> {
         let db_path = get_env_vars()?;
 
         init_thread_pools(10);
         let task_executor = ctx.task_executor;
 
+        let profile = DbProfile::read(Path::new(&brontes_db_endpoint))?;
         let libmdbx =
             static_object(load_database(&task_executor, brontes_db_endpoint, None, None).await?);
         let clickhouse = static_object(load_clickhouse(Default::default(), None).await?);
@@ -86,7 +104,7 @@ impl Init {
 
             task_executor
                 .spawn_critical("init", async move {
-                    let tables = Tables::ALL.to_vec();
+                    let tables = profile.tables();
 
                     let multi = MultiProgress::default();
                     let tables_with_progress = Arc::new(
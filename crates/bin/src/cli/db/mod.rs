@@ -2,6 +2,8 @@ use clap::{Parser, Subcommand};
 mod r2_uploader;
 mod snapshot;
 use crate::runner::CliContext;
+mod audit;
+pub(crate) use audit::record_command;
 mod cex_data;
 #[cfg(feature = "local-clickhouse")]
 mod clickhouse_download;
@@ -13,11 +15,13 @@ mod discovery;
 #[cfg(feature = "local-clickhouse")]
 mod ensure_test_traces;
 mod export;
+mod import_cex;
 mod init;
 mod table_stats;
 #[cfg(feature = "local-clickhouse")]
 mod tip_tracer;
 mod trace_range;
+mod trees;
 pub mod utils;
 
 #[derive(Debug, Parser)]
@@ -48,6 +52,10 @@ pub enum DatabaseCommands {
     /// libmdbx.
     #[command(name = "init")]
     Init(init::Init),
+    /// Downloads historical cex quotes and trades for a block range from a
+    /// public source, without needing the Sorella DB
+    #[command(name = "import-cex")]
+    ImportCex(import_cex::ImportCex),
     /// Libmbdx Table Stats
     #[command(name = "table-stats")]
     TableStats(table_stats::Stats),
@@ -80,15 +88,61 @@ pub enum DatabaseCommands {
     #[cfg(feature = "local-clickhouse")]
     #[command(name = "run-discovery")]
     Discovery(discovery::DiscoveryFill),
+    /// Lists the state changing commands that were run against the db
+    #[command(name = "audit")]
+    Audit(audit::Audit),
+    /// Writes the traces of a block range to a file, so the classified trees
+    /// can be rebuilt on another machine without tracing the blocks again. The
+    /// trees themselves aren't serialized
+    #[command(name = "export-trees")]
+    ExportTrees(trees::ExportTrees),
+    /// Loads the traces written by `export-trees` into the db
+    #[command(name = "import-trees")]
+    ImportTrees(trees::ImportTrees),
+}
+
+impl DatabaseCommands {
+    /// Name the command is recorded under in the audit log, `None` for
+    /// commands that don't modify the db
+    fn audit_name(&self) -> Option<&'static str> {
+        match self {
+            DatabaseCommands::DbInserts(_) => Some("db insert"),
+            DatabaseCommands::DbClear(_) => Some("db clear"),
+            DatabaseCommands::TraceRange(_) => Some("db generate-traces"),
+            DatabaseCommands::Init(_) => Some("db init"),
+            DatabaseCommands::ImportCex(_) => Some("db import-cex"),
+            DatabaseCommands::DownloadSnapshot(_) => Some("db download-snapshot"),
+            DatabaseCommands::ImportTrees(_) => Some("db import-trees"),
+            #[cfg(feature = "local-clickhouse")]
+            DatabaseCommands::DownloadClickhouse(_) => Some("db download-clickhouse"),
+            #[cfg(feature = "local-clickhouse")]
+            DatabaseCommands::TraceAtTip(_) => Some("db trace-at-tip"),
+            #[cfg(feature = "local-clickhouse")]
+            DatabaseCommands::Discovery(_) => Some("db run-discovery"),
+            _ => None,
+        }
+    }
 }
 
 impl Database {
     pub async fn execute(self, brontes_db_endpoint: String, ctx: CliContext) -> eyre::Result<()> {
+        if let DatabaseCommands::Init(init) = &self.command {
+            init.select_profile(&brontes_db_endpoint)?;
+        }
+
+        if let Some(name) = self.command.audit_name() {
+            audit::record_command(&brontes_db_endpoint, name)?;
+        }
+
         match self.command {
+            DatabaseCommands::Audit(cmd) => cmd.execute(brontes_db_endpoint),
+            DatabaseCommands::ExportTrees(cmd) => cmd.execute(brontes_db_endpoint),
+            DatabaseCommands::ImportTrees(cmd) => cmd.execute(brontes_db_endpoint),
             DatabaseCommands::DbInserts(cmd) => cmd.execute(brontes_db_endpoint).await,
             DatabaseCommands::DbQuery(cmd) => cmd.execute(brontes_db_endpoint).await,
             DatabaseCommands::TraceRange(cmd) => cmd.execute(brontes_db_endpoint, ctx).await,
             DatabaseCommands::Init(cmd) => cmd.execute(brontes_db_endpoint, ctx).await,
+            DatabaseCommands::ImportCex(cmd) => cmd.execute(brontes_db_endpoint, ctx).await,
             DatabaseCommands::DbClear(cmd) => cmd.execute(brontes_db_endpoint).await,
             DatabaseCommands::UploadSnapshot(cmd) => cmd.execute(brontes_db_endpoint, ctx).await,
             DatabaseCommands::Export(cmd) => cmd.execute(brontes_db_endpoint, ctx).await,
@@ -1,5 +1,6 @@
 use std::{path::Path, sync::Arc};
 
+use brontes_database::libmdbx::DbProfile;
 use clap::Parser;
 use comfy_table::{Cell, Row, Table as ComfyTable};
 use eyre::WrapErr;
@@ -20,6 +21,7 @@ impl Stats {
     /// Execute `db stats` command
     pub fn execute(self, db_path: String) -> eyre::Result<()> {
         let db_path = Path::new(&db_path);
+        let profile = DbProfile::read(db_path)?;
         let chain = Arc::new(ChainSpec::default());
 
         let db = Arc::new(open_db(db_path, Default::default())?);
@@ -28,14 +30,18 @@ impl Stats {
         statis_files_path.push("static_files");
         let provider_factory = ProviderFactory::new(db, chain.clone(), statis_files_path)?;
 
-        self.run(&provider_factory)?;
+        self.run(&provider_factory, profile)?;
 
         Ok(())
     }
 
     /// Execute `db stats` command
-    fn run(self, provider_factory: &ProviderFactory<Arc<DatabaseEnv>>) -> eyre::Result<()> {
-        let db_stats_table = self.db_stats_table(provider_factory)?;
+    fn run(
+        self,
+        provider_factory: &ProviderFactory<Arc<DatabaseEnv>>,
+        profile: DbProfile,
+    ) -> eyre::Result<()> {
+        let db_stats_table = self.db_stats_table(provider_factory, profile)?;
         println!("{db_stats_table}");
 
         Ok(())
@@ -44,6 +50,7 @@ impl Stats {
     fn db_stats_table(
         &self,
         provider_factory: &ProviderFactory<Arc<DatabaseEnv>>,
+        profile: DbProfile,
     ) -> eyre::Result<ComfyTable> {
         let mut table = ComfyTable::new();
         table.load_preset(comfy_table::presets::ASCII_MARKDOWN);
@@ -57,10 +64,9 @@ impl Stats {
         ]);
 
         provider_factory.db_ref().view(|tx| {
-            let mut db_tables = brontes_database::libmdbx::tables::Tables::ALL
-                .iter()
-                .map(|table| table.name())
-                .collect::<Vec<_>>();
+            // lite databases don't have every table
+            let tables = profile.tables();
+            let mut db_tables = tables.iter().map(|table| table.name()).collect::<Vec<_>>();
             db_tables.sort();
             let mut total_size = 0;
             for db_table in db_tables {
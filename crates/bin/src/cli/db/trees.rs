@@ -0,0 +1,100 @@
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter},
+    path::PathBuf,
+};
+
+use brontes_database::{
+    libmdbx::{
+        tree_file::{TreeFileReader, TreeFileWriter},
+        Libmdbx,
+    },
+    Tables, TxTraces, TxTracesData,
+};
+use clap::Parser;
+
+use crate::cli::analyze::parse_block_range;
+
+/// Blocks written to the database per transaction on import
+const IMPORT_BATCH_SIZE: usize = 500;
+
+#[derive(Debug, Parser)]
+pub struct ExportTrees {
+    /// Block range to export, format: "start..end"
+    #[arg(long, short, value_parser = parse_block_range)]
+    pub range: (u64, u64),
+    /// File to write the trees to
+    #[arg(long, short)]
+    pub file:  PathBuf,
+}
+
+impl ExportTrees {
+    pub fn execute(self, brontes_db_endpoint: String) -> eyre::Result<()> {
+        let db = Libmdbx::init_db(brontes_db_endpoint, None)?;
+        ensure_stores_traces(&db)?;
+        let (start_block, end_block) = self.range;
+
+        let mut writer = TreeFileWriter::new(BufWriter::new(File::create(&self.file)?))?;
+        db.view_db(|tx| {
+            let mut cursor = tx.new_cursor::<TxTraces>()?;
+            for entry in cursor.walk_range(start_block..=end_block)? {
+                let (block, traces) = entry?;
+                writer.write_block(block, traces)?;
+            }
+
+            Ok(())
+        })?;
+        let blocks = writer.finish()?;
+
+        let missing = end_block - start_block + 1 - blocks;
+        println!("exported {} blocks to {}", blocks, self.file.display());
+        if missing != 0 {
+            println!("{} blocks in the range have no traces and were skipped", missing);
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Parser)]
+pub struct ImportTrees {
+    /// File written by `db export-trees`
+    #[arg(long, short)]
+    pub file: PathBuf,
+}
+
+impl ImportTrees {
+    /// Blocks already in the database are overwritten
+    pub fn execute(self, brontes_db_endpoint: String) -> eyre::Result<()> {
+        let db = Libmdbx::init_db(brontes_db_endpoint, None)?;
+        ensure_stores_traces(&db)?;
+
+        let reader = TreeFileReader::new(BufReader::new(File::open(&self.file)?))?;
+        let mut batch = Vec::with_capacity(IMPORT_BATCH_SIZE);
+        let mut imported = 0usize;
+        for entry in reader {
+            let (block, traces) = entry?;
+            batch.push(TxTracesData::new(block, traces));
+
+            if batch.len() == IMPORT_BATCH_SIZE {
+                db.write_table::<TxTraces, TxTracesData>(&batch)?;
+                imported += batch.len();
+                batch.clear();
+            }
+        }
+        db.write_table::<TxTraces, TxTracesData>(&batch)?;
+        imported += batch.len();
+
+        println!("imported {} blocks from {}", imported, self.file.display());
+
+        Ok(())
+    }
+}
+
+fn ensure_stores_traces(db: &Libmdbx) -> eyre::Result<()> {
+    if !db.profile().stores(Tables::TxTraces) {
+        eyre::bail!("{} database doesn't store traces", db.profile())
+    }
+
+    Ok(())
+}
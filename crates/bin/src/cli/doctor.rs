@@ -0,0 +1,406 @@
+//! Pre-flight checks for a run. A missing trace namespace, a pruned node or an
+//! unreachable database otherwise only shows up once the run reaches the
+//! block that needs it, often hours in. Every check is run, so a single pass
+//! lists everything that has to be fixed.
+use std::{env, path::Path};
+
+use alloy_primitives::{hex, Address};
+use brontes_database::libmdbx::Libmdbx;
+use clap::Parser;
+use colored::Colorize;
+
+use crate::runner::CliContext;
+
+/// Returns 1 as a 32 byte word: `PUSH1 1 PUSH1 0 MSTORE PUSH1 32 PUSH1 0
+/// RETURN`. Set as the code of [`PROBE`] through a state override, calling it
+/// only returns 1 if the node applied the override
+const RETURN_ONE: [u8; 10] = hex!("600160005260206000f3");
+const PROBE: Address = Address::new(hex!("00000000000000000000000000000000000b7075"));
+
+#[derive(Debug, Parser)]
+pub struct Doctor {
+    /// First block of the run, the node has to have its state. Defaults to
+    /// the tip of the node
+    #[arg(long, short)]
+    pub start_block: Option<u64>,
+    /// Last block of the run, the node has to be synced up to it. Defaults to
+    /// the tip of the node
+    #[arg(long, short)]
+    pub end_block:   Option<u64>,
+    /// Rpc endpoint of the node, if omitted it's built from `RETH_ENDPOINT`
+    /// and `RETH_PORT`
+    #[cfg(not(feature = "local-reth"))]
+    #[arg(long)]
+    pub rpc_url:     Option<String>,
+}
+
+struct Check {
+    name:    &'static str,
+    outcome: Result<String, Failure>,
+}
+
+struct Failure {
+    error: String,
+    /// What to change to make the check pass
+    fix:   String,
+}
+
+impl Check {
+    fn new(name: &'static str, result: eyre::Result<String>, fix: impl Into<String>) -> Self {
+        Self {
+            name,
+            outcome: result.map_err(|e| Failure { error: format!("{:#}", e), fix: fix.into() }),
+        }
+    }
+
+    fn print(&self) {
+        match &self.outcome {
+            Ok(detail) => println!("{} {}: {}", "ok".green().bold(), self.name, detail),
+            Err(failure) => {
+                println!("{} {}: {}", "failed".red().bold(), self.name, failure.error);
+                println!("       {}", failure.fix.yellow());
+            }
+        }
+    }
+}
+
+impl Doctor {
+    pub async fn execute(self, brontes_db_endpoint: String, ctx: CliContext) -> eyre::Result<()> {
+        self.check_range()?;
+
+        let mut checks = self.node_checks(&ctx).await;
+        checks.push(check_libmdbx(&brontes_db_endpoint));
+        checks.push(check_clickhouse().await);
+
+        checks.iter().for_each(Check::print);
+
+        let failed = checks.iter().filter(|c| c.outcome.is_err()).count();
+        if failed != 0 {
+            eyre::bail!("{} of {} checks failed", failed, checks.len())
+        }
+        println!("all checks passed");
+
+        Ok(())
+    }
+
+    fn check_range(&self) -> eyre::Result<()> {
+        if let (Some(start), Some(end)) = (self.start_block, self.end_block) {
+            if start > end {
+                eyre::bail!("start block must be less than end block")
+            }
+        }
+        Ok(())
+    }
+
+    /// The blocks to check the node against, the start and end of the range
+    fn range(&self, tip: u64) -> (u64, u64) {
+        (self.start_block.unwrap_or(tip), self.end_block.unwrap_or(tip))
+    }
+
+    /// The checks go through the provider a run uses, tracing the blocks
+    /// straight from the reth database
+    #[cfg(feature = "local-reth")]
+    async fn node_checks(&self, ctx: &CliContext) -> Vec<Check> {
+        use brontes_types::traits::TracingProvider;
+        use reth_primitives::{BlockNumberOrTag, Bytes};
+        use reth_rpc_types::{state::AccountOverride, TransactionRequest};
+
+        use super::{get_env_vars, get_tracing_provider};
+
+        let tracer = match get_env_vars() {
+            Ok(db_path) => get_tracing_provider(Path::new(&db_path), 1, ctx.task_executor.clone()),
+            Err(e) => return vec![Check::new("node", Err(e), "set DB_PATH to the reth datadir")],
+        };
+
+        let tip = match tracer.best_block_number() {
+            Ok(tip) => tip,
+            Err(e) => {
+                return vec![Check::new("node", Err(e), "check that DB_PATH is a reth database")]
+            }
+        };
+        let (start, end) = self.range(tip);
+        let mut checks = vec![Check::new("node", Ok(format!("synced to block {}", tip)), "")];
+
+        if end > tip {
+            checks.push(behind_range(tip, end));
+            return checks
+        }
+
+        checks.push(Check::new(
+            "traces",
+            tracer
+                .replay_block_transactions(end.into())
+                .await
+                .and_then(|traces| traces.ok_or_else(|| eyre::eyre!("block {} not found", end)))
+                .map(|traces| format!("traced {} transactions of block {}", traces.len(), end)),
+            "check that the reth version matches the one brontes is built against",
+        ));
+
+        checks.push(Check::new(
+            "receipts",
+            tracer
+                .block_receipts(BlockNumberOrTag::Number(end))
+                .await
+                .and_then(|r| r.ok_or_else(|| eyre::eyre!("block {} has no receipts", end)))
+                .map(|r| format!("{} receipts in block {}", r.len(), end)),
+            "the node has pruned receipts, disable receipt pruning",
+        ));
+
+        let overrides = [(
+            PROBE,
+            AccountOverride { code: Some(Bytes::from_static(&RETURN_ONE)), ..Default::default() },
+        )]
+        .into_iter()
+        .collect();
+        let req = TransactionRequest { to: Some(PROBE), ..Default::default() };
+        checks.push(Check::new(
+            "state overrides",
+            tracer
+                .eth_call(req, Some(end.into()), Some(overrides), None)
+                .await
+                .and_then(|res| returned_one(&res)),
+            "check that the reth version matches the one brontes is built against",
+        ));
+
+        checks.push(Check::new(
+            "archive depth",
+            tracer
+                .replay_block_transactions(start.into())
+                .await
+                .map(|_| format!("state of block {} is available", start)),
+            archive_fix(start),
+        ));
+
+        checks
+    }
+
+    /// A node without the local reth database is only reached over rpc, the
+    /// checks call the methods brontes depends on directly
+    #[cfg(not(feature = "local-reth"))]
+    async fn node_checks(&self, _: &CliContext) -> Vec<Check> {
+        use serde_json::json;
+
+        let url = match self.rpc_url.clone().map_or_else(rpc_url_from_env, Ok) {
+            Ok(url) => url,
+            Err(e) => {
+                return vec![Check::new(
+                    "node",
+                    Err(e.wrap_err("no rpc endpoint")),
+                    "pass --rpc-url or set RETH_ENDPOINT and RETH_PORT",
+                )]
+            }
+        };
+        let node = RpcNode { client: reqwest::Client::new(), url };
+
+        let tip = match node
+            .request("eth_blockNumber", json!([]))
+            .await
+            .and_then(quantity)
+        {
+            Ok(tip) => tip,
+            Err(e) => {
+                return vec![Check::new(
+                    "node",
+                    Err(e),
+                    format!("check that the node is running and serving rpc at {}", node.url),
+                )]
+            }
+        };
+        let (start, end) = self.range(tip);
+        let mut checks = vec![Check::new("node", Ok(format!("synced to block {}", tip)), "")];
+
+        if end > tip {
+            checks.push(behind_range(tip, end));
+            return checks
+        }
+
+        checks.push(Check::new(
+            "traces",
+            node.request(
+                "trace_replayBlockTransactions",
+                json!([hex_block(end), ["trace", "vmTrace"]]),
+            )
+            .await
+            .and_then(|traces| {
+                let traces = traces
+                    .as_array()
+                    .ok_or_else(|| eyre::eyre!("block {} not found", end))?;
+                if traces.iter().any(|trace| trace["vmTrace"].is_null()) {
+                    eyre::bail!("the node returned traces without a vmTrace")
+                }
+                Ok(format!("traced {} transactions of block {} with vmTrace", traces.len(), end))
+            }),
+            "enable the trace namespace on the node, e.g `--http.api eth,trace` on reth",
+        ));
+
+        checks.push(Check::new(
+            "receipts",
+            node.request("eth_getBlockReceipts", json!([hex_block(end)]))
+                .await
+                .and_then(|receipts| {
+                    let receipts = receipts
+                        .as_array()
+                        .ok_or_else(|| eyre::eyre!("block {} has no receipts", end))?;
+                    Ok(format!("{} receipts in block {}", receipts.len(), end))
+                }),
+            "the node has to support eth_getBlockReceipts and keep receipts of the range",
+        ));
+
+        let call = json!({ "to": PROBE });
+        let overrides =
+            json!({ (PROBE.to_string()): { "code": hex::encode_prefixed(RETURN_ONE) } });
+        checks.push(Check::new(
+            "state overrides",
+            node.request("eth_call", json!([call, hex_block(end), overrides]))
+                .await
+                .and_then(|res| {
+                    let res: alloy_primitives::Bytes = serde_json::from_value(res)?;
+                    returned_one(&res)
+                }),
+            "the node has to accept the state override argument of eth_call",
+        ));
+
+        checks.push(Check::new(
+            "archive depth",
+            node.request("eth_getBalance", json!([Address::ZERO, hex_block(start)]))
+                .await
+                .map(|_| format!("state of block {} is available", start)),
+            archive_fix(start),
+        ));
+
+        checks
+    }
+}
+
+#[cfg(not(feature = "local-reth"))]
+struct RpcNode {
+    client: reqwest::Client,
+    url:    String,
+}
+
+#[cfg(not(feature = "local-reth"))]
+impl RpcNode {
+    async fn request(
+        &self,
+        method: &str,
+        params: serde_json::Value,
+    ) -> eyre::Result<serde_json::Value> {
+        let mut res: serde_json::Value = self
+            .client
+            .post(&self.url)
+            .json(&serde_json::json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params }))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        if let Some(error) = res.get("error") {
+            eyre::bail!(
+                "{} failed: {}",
+                method,
+                error["message"].as_str().unwrap_or("unknown error")
+            )
+        }
+
+        Ok(res["result"].take())
+    }
+}
+
+#[cfg(not(feature = "local-reth"))]
+fn rpc_url_from_env() -> eyre::Result<String> {
+    Ok(format!("{}:{}", env::var("RETH_ENDPOINT")?, env::var("RETH_PORT")?))
+}
+
+#[cfg(not(feature = "local-reth"))]
+fn hex_block(block: u64) -> String {
+    format!("{:#x}", block)
+}
+
+#[cfg(not(feature = "local-reth"))]
+fn quantity(value: serde_json::Value) -> eyre::Result<u64> {
+    let value = value
+        .as_str()
+        .ok_or_else(|| eyre::eyre!("expected a hex quantity, got {}", value))?;
+    Ok(u64::from_str_radix(value.trim_start_matches("0x"), 16)?)
+}
+
+fn returned_one(res: &[u8]) -> eyre::Result<String> {
+    if res.len() != 32 || res[31] != 1 || res[..31].iter().any(|b| *b != 0) {
+        eyre::bail!("the override wasn't applied, the call returned 0x{}", hex::encode(res))
+    }
+    Ok("eth_call applies state overrides".to_string())
+}
+
+fn behind_range(tip: u64, end: u64) -> Check {
+    Check::new(
+        "range",
+        Err(eyre::eyre!("the node is at block {}, behind the end of the range {}", tip, end)),
+        "wait for the node to sync or lower --end-block",
+    )
+}
+
+fn archive_fix(start: u64) -> String {
+    format!(
+        "the node has pruned the state of block {}, use an archive node or start the range after \
+         its pruning horizon",
+        start
+    )
+}
+
+fn check_libmdbx(brontes_db_endpoint: &str) -> Check {
+    // opening creates the database if it's missing, which a check shouldn't
+    if !Path::new(brontes_db_endpoint).exists() {
+        return Check::new(
+            "libmdbx",
+            Err(eyre::eyre!("no database at {}", brontes_db_endpoint)),
+            "check BRONTES_DB_PATH, a new database is created with `brontes bootstrap`",
+        )
+    }
+
+    Check::new(
+        "libmdbx",
+        Libmdbx::init_db(brontes_db_endpoint, None)
+            .map(|db| format!("opened {} database at {}", db.profile(), brontes_db_endpoint)),
+        "check that no other brontes instance holds the database open",
+    )
+}
+
+#[cfg(feature = "local-clickhouse")]
+async fn check_clickhouse() -> Check {
+    use brontes_database::clickhouse::{clickhouse_config, Clickhouse};
+
+    let missing = ["CLICKHOUSE_URL", "CLICKHOUSE_PORT", "CLICKHOUSE_USER", "CLICKHOUSE_PASS"]
+        .into_iter()
+        .find(|var| env::var(var).is_err());
+    if let Some(var) = missing {
+        return Check::new(
+            "clickhouse",
+            Err(eyre::eyre!("{} isn't set", var)),
+            "set the clickhouse connection in .env",
+        )
+    }
+
+    // a run id is passed so connecting doesn't take a new one
+    let clickhouse =
+        Clickhouse::new(clickhouse_config(), Default::default(), None, false, Some(0)).await;
+    Check::new(
+        "clickhouse",
+        clickhouse
+            .max_traced_block()
+            .await
+            .map(|block| format!("connected, traced up to block {}", block)),
+        "check the clickhouse connection in .env and that the server is running",
+    )
+}
+
+#[cfg(not(feature = "local-clickhouse"))]
+async fn check_clickhouse() -> Check {
+    async fn reach_api() -> eyre::Result<String> {
+        let api = env::var("CLICKHOUSE_API")?;
+        reqwest::get(&api).await?;
+        Ok(format!("reached {}", api))
+    }
+
+    Check::new("clickhouse", reach_api().await, "set CLICKHOUSE_API to a reachable brontes api")
+}
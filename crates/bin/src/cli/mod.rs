@@ -1,6 +1,9 @@
 use clap::{Parser, Subcommand};
 
+mod analyze;
+mod bootstrap;
 mod db;
+mod doctor;
 mod misc;
 mod run;
 mod utils;
@@ -36,4 +39,15 @@ pub enum Commands {
     /// Brontes database commands
     #[command(name = "db")]
     Database(db::Database),
+    /// Re-run and compare inspector results over stored blocks
+    #[command(name = "analyze")]
+    Analyze(analyze::Analyze),
+    /// Seed a new database with the published protocol, token and searcher
+    /// metadata, so blocks can be classified without backfilling it first
+    #[command(name = "bootstrap")]
+    Bootstrap(bootstrap::Bootstrap),
+    /// Check that the node and databases support a run over the range, before
+    /// starting it
+    #[command(name = "doctor")]
+    Doctor(doctor::Doctor),
 }
@@ -1,8 +1,15 @@
-use std::{path::Path, time::Duration};
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
 
 use brontes_core::decoding::Parser as DParser;
-use brontes_database::clickhouse::cex_config::CexDownloadConfig;
-use brontes_inspect::Inspectors;
+use brontes_database::{
+    cex_feed::{CexFeedConfig, LiveCexFeed},
+    clickhouse::cex_config::CexDownloadConfig,
+    libmdbx::initialize::load_classifier_config,
+};
+use brontes_inspect::{thresholds::ThresholdConfig, Inspectors};
 use brontes_metrics::ParserMetricsListener;
 use brontes_types::{
     constants::USDT_ADDRESS_STRING,
@@ -15,10 +22,11 @@ use tokio::sync::mpsc::unbounded_channel;
 
 use super::{determine_max_tasks, get_env_vars, load_clickhouse, load_database, static_object};
 use crate::{
+    admin::start_admin_endpoint,
     banner::rain,
     cli::{get_tracing_provider, init_inspectors, load_tip_database},
     runner::CliContext,
-    BrontesRunConfig, MevProcessor, RangeType,
+    BrontesRunConfig, EthPriceCheck, EthPriceCheckMode, MevProcessor, RangeType,
 };
 
 const SECONDS_TO_US_FLOAT: f64 = 1_000_000.0;
@@ -27,73 +35,110 @@ const SECONDS_TO_US_FLOAT: f64 = 1_000_000.0;
 pub struct RunArgs {
     /// Optional Start Block, if omitted it will run at tip until killed
     #[arg(long, short)]
-    pub start_block:          Option<u64>,
+    pub start_block:             Option<u64>,
     /// Optional End Block, if omitted it will run historically & at tip until
     /// killed
     #[arg(long, short)]
-    pub end_block:            Option<u64>,
+    pub end_block:               Option<u64>,
     /// Optional Multiple Ranges, format: "start1-end1 start2-end2 ..."
     /// Use this if you want to specify the exact, non continuous block ranges
     /// you want to run
     #[arg(long, num_args = 1.., value_delimiter = ' ')]
-    pub ranges:               Option<Vec<String>>,
+    pub ranges:                  Option<Vec<String>>,
     /// Optional Max Tasks, if omitted it will default to 80% of the number of
     /// physical cores on your machine
     #[arg(long, short)]
-    pub max_tasks:            Option<u64>,
+    pub max_tasks:               Option<u64>,
     /// Optional minimum batch size
     #[arg(long, default_value = "500")]
-    pub min_batch_size:       u64,
+    pub min_batch_size:          u64,
     /// Optional quote asset, if omitted it will default to USDT
     #[arg(long, short, default_value = USDT_ADDRESS_STRING)]
-    pub quote_asset:          String,
+    pub quote_asset:             String,
     /// Inspectors to run. If omitted it defaults to running all inspectors
     #[arg(long, short, value_delimiter = ',')]
-    pub inspectors:           Option<Vec<Inspectors>>,
+    pub inspectors:              Option<Vec<Inspectors>>,
     /// Time window arguments for cex data downloads
     #[clap(flatten)]
-    pub time_window_args:     TimeWindowArgs,
+    pub time_window_args:        TimeWindowArgs,
     /// CEX exchanges to consider for cex-dex analysis
     #[arg(
         long,
         short,
-        default_value = "Binance,Coinbase,Okex,BybitSpot,Kucoin",
+        default_value = "Binance,Coinbase,Okex,BybitSpot,Kucoin,Kraken",
         value_delimiter = ','
     )]
-    pub cex_exchanges:        Vec<CexExchange>,
+    pub cex_exchanges:           Vec<CexExchange>,
     /// Force DEX price calculation for every block, ignoring existing database
     /// values.
     #[arg(long, short, default_value = "false")]
-    pub force_dex_pricing:    bool,
+    pub force_dex_pricing:       bool,
     /// Disables DEX pricing. Inspectors needing DEX prices will only calculate
     /// token PnL, not USD PnL, if DEX pricing is unavailable in the
     /// database.
     #[arg(long, default_value = "false")]
-    pub force_no_dex_pricing: bool,
+    pub force_no_dex_pricing:    bool,
     /// Number of blocks to lag behind the chain tip when processing.
     #[arg(long, default_value = "10")]
-    pub behind_tip:           u64,
+    pub behind_tip:              u64,
     /// Legacy, run in CLI only mode (no TUI) - will output progress bars to
     /// stdout
     #[arg(long, default_value = "true")]
-    pub cli_only:             bool,
+    pub cli_only:                bool,
     /// Export metrics
     #[arg(long, default_value = "false")]
-    pub with_metrics:         bool,
+    pub with_metrics:            bool,
     /// Wether or not to use a fallback server.
     #[arg(long, default_value_t = false)]
-    pub enable_fallback:      bool,
+    pub enable_fallback:         bool,
     /// Address of the fallback server.
     /// Triggers database writes if the main connection fails, preventing data
     /// loss.
     #[arg(long)]
-    pub fallback_server:      Option<String>,
+    pub fallback_server:         Option<String>,
+    /// Re-execute each block to get the exact state changes of every
+    /// transaction and verify bundle profits against them. Needs a local reth
+    /// node
+    #[arg(long, default_value_t = false)]
+    pub verify_profits:          bool,
+    /// Keep the bundles that lost money after gas instead of dropping them, to
+    /// study failed and negative ev searcher activity
+    #[arg(long, default_value_t = false)]
+    pub track_losses:            bool,
     /// Set a custom run ID used when inserting data into the Clickhouse
     ///
     /// If omitted, the ID will be automatically incremented from the last run
     /// stored in the Clickhouse database.
     #[arg(long, short)]
-    pub run_id:               Option<u64>,
+    pub run_id:                  Option<u64>,
+    /// Port of the admin endpoint, used to change the log filter while running.
+    /// Only bound on localhost. If omitted the endpoint isn't started
+    #[arg(long)]
+    pub admin_port:              Option<u16>,
+    /// TOML file of protocol addresses to register before running, laid out
+    /// like `config/classifier_config.toml`. Each address is classified by the
+    /// protocol it is listed under
+    #[arg(long)]
+    pub classifier_config:       Option<PathBuf>,
+    /// TOML file of per inspector minimum profit, minimum confidence and
+    /// denied tokens, laid out like `config/inspector_thresholds.toml`.
+    /// Bundles outside them are left out of the results
+    #[arg(long)]
+    pub thresholds:              Option<PathBuf>,
+    /// TOML file of cex symbols to stream quotes and trades for while running
+    /// at tip, laid out like `config/cex_feed_config.toml`. The data is written
+    /// to the database as blocks arrive, instead of waiting on Clickhouse
+    #[arg(long)]
+    pub live_cex_feed:           Option<PathBuf>,
+    /// Check each block's eth price against Chainlink's ETH / USD feed, and
+    /// flag or clamp prices that deviate from it. Needs a usd stable quote
+    /// asset
+    #[arg(long, value_enum)]
+    pub eth_price_check:         Option<EthPriceCheckMode>,
+    /// Largest deviation from Chainlink's price the eth price check allows, as
+    /// a share of it
+    #[arg(long, default_value_t = 0.02)]
+    pub max_eth_price_deviation: f64,
 
     /// shows a cool display at startup
     #[arg(long, short, default_value_t = false)]
@@ -134,6 +179,10 @@ impl RunArgs {
 
         task_executor.spawn_critical("metrics", metrics_listener);
 
+        if let Some(port) = self.admin_port {
+            start_admin_endpoint(port).await?;
+        }
+
         let hr = self.try_start_fallback_server().await;
 
         tracing::info!(target: "brontes", "starting database initialization at: '{}'", brontes_db_endpoint);
@@ -143,6 +192,17 @@ impl RunArgs {
         let tip = static_object(load_tip_database(libmdbx)?);
         tracing::info!(target: "brontes", "initialized libmdbx database");
 
+        if let Some(path) = &self.classifier_config {
+            let config = std::fs::read_to_string(path)?;
+            let registered = load_classifier_config(libmdbx, &config).await?;
+            tracing::info!(
+                target: "brontes",
+                "registered {} protocol addresses from {}",
+                registered,
+                path.display()
+            );
+        }
+
         let load_window = self.load_time_window();
 
         let cex_download_config = CexDownloadConfig::new(
@@ -170,12 +230,26 @@ impl RunArgs {
 
         let trade_config = self.time_window_args.trade_config();
 
+        let thresholds = match &self.thresholds {
+            Some(path) => {
+                let thresholds = ThresholdConfig::load(path)?;
+                tracing::info!(
+                    target: "brontes",
+                    "loaded inspector thresholds from {}",
+                    path.display()
+                );
+                thresholds
+            }
+            None => ThresholdConfig::default(),
+        };
         let inspectors = init_inspectors(
             quote_asset,
             libmdbx,
             self.inspectors,
             self.cex_exchanges,
             trade_config,
+            &thresholds,
+            self.track_losses,
             self.with_metrics,
         );
 
@@ -183,6 +257,38 @@ impl RunArgs {
             get_tracing_provider(Path::new(&reth_db_path), max_tasks, task_executor.clone());
         let parser = static_object(DParser::new(metrics_tx, libmdbx, tracer.clone()).await);
 
+        if let Some(path) = &self.live_cex_feed {
+            eyre::ensure!(
+                self.end_block.is_none() && self.ranges.is_none(),
+                "the live cex feed can only be used when running at tip"
+            );
+            let config = CexFeedConfig::load(path)?;
+
+            #[cfg(feature = "local-reth")]
+            let chain_tip = parser.get_latest_block_number()?;
+            #[cfg(not(feature = "local-reth"))]
+            let chain_tip = parser.get_latest_block_number().await?;
+
+            let feed = LiveCexFeed::new(config, parser.get_tracer(), libmdbx, chain_tip + 1);
+            task_executor.spawn_critical("live cex feed", feed.run());
+            tracing::info!(
+                target: "brontes",
+                "streaming live cex data from block {}",
+                chain_tip + 1
+            );
+        }
+
+        let eth_price_check = self
+            .eth_price_check
+            .map(|mode| {
+                eyre::ensure!(
+                    EthPriceCheck::supports_quote_asset(quote_asset),
+                    "the eth price check needs a usd stable quote asset"
+                );
+                EthPriceCheck::new(mode, self.max_eth_price_deviation)
+            })
+            .transpose()?;
+
         let executor = task_executor.clone();
         let result = executor
             .clone()
@@ -204,6 +310,9 @@ impl RunArgs {
                     snapshot_mode,
                     load_window,
                 )
+                .with_profit_verification(self.verify_profits)
+                .with_loss_tracking(self.track_losses)
+                .with_eth_price_check(eth_price_check)
                 .build(task_executor, shutdown)
                 .await
                 .map_err(|e| {
@@ -254,11 +363,7 @@ impl RunArgs {
 
     /// the time window in seconds for downloading
     fn load_time_window(&self) -> usize {
-        self.time_window_args
-            .max_vwap_pre
-            .max(self.time_window_args.max_vwap_post)
-            .max(self.time_window_args.max_optimistic_pre)
-            .max(self.time_window_args.max_optimistic_post) as usize
+        self.time_window_args.load_time_window()
     }
 
     fn check_proper_range(&self) -> eyre::Result<()> {
@@ -386,10 +491,32 @@ pub struct TimeWindowArgs {
     /// Cex Dex Quotes price time offset from block timestamp
     #[arg(long = "quote-offset", default_value = "0.0")]
     pub quote_offset: f64,
+
+    /// Time between the markouts taken of cex-dex trades after the block
+    #[arg(long = "markout-interval", default_value = "1.0")]
+    pub markout_interval: f64,
+
+    /// How many markouts to take of cex-dex trades, 0 to disable them
+    #[arg(long = "markout-intervals", default_value = "3")]
+    pub markout_intervals: u64,
+
+    /// Width of the trade window each markout is priced over
+    #[arg(long = "markout-window", default_value = "0.5")]
+    pub markout_window: f64,
 }
 
 impl TimeWindowArgs {
-    fn trade_config(&self) -> CexDexTradeConfig {
+    /// the time window in seconds for downloading
+    pub(crate) fn load_time_window(&self) -> usize {
+        self.max_vwap_pre
+            .max(self.max_vwap_post)
+            .max(self.max_optimistic_pre)
+            .max(self.max_optimistic_post)
+            .max(self.markout_interval * self.markout_intervals as f64 + self.markout_window)
+            as usize
+    }
+
+    pub(crate) fn trade_config(&self) -> CexDexTradeConfig {
         CexDexTradeConfig {
             initial_vwap_pre_block_us:  (self.initial_vwap_pre * SECONDS_TO_US_FLOAT) as u64,
             initial_vwap_post_block_us: (self.initial_vwap_post * SECONDS_TO_US_FLOAT) as u64,
@@ -417,6 +544,9 @@ impl TimeWindowArgs {
             pre_decay_weight_op:               self.pre_decay_weight_optimistic,
             post_decay_weight_op:              self.post_decay_weight_optimistic,
             quote_offset_from_block_us:        (self.quote_offset * SECONDS_TO_US_FLOAT) as u64,
+            markout_interval_us:               (self.markout_interval * SECONDS_TO_US_FLOAT) as u64,
+            markout_intervals:                 self.markout_intervals,
+            markout_window_us:                 (self.markout_window * SECONDS_TO_US_FLOAT) as u64,
         }
     }
 }
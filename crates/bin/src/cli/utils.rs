@@ -16,7 +16,7 @@ use brontes_database::clickhouse::ReadOnlyMiddleware;
 #[cfg(feature = "local-clickhouse")]
 use brontes_database::clickhouse::{dbms::BrontesClickhouseData, ClickhouseBuffered};
 use brontes_database::{clickhouse::cex_config::CexDownloadConfig, libmdbx::LibmdbxReadWriter};
-use brontes_inspect::{Inspector, Inspectors};
+use brontes_inspect::{thresholds::ThresholdConfig, Inspector, Inspectors};
 use brontes_metrics::inspectors::OutlierMetrics;
 #[cfg(feature = "local-clickhouse")]
 use brontes_types::UnboundedYapperReceiver;
@@ -156,6 +156,8 @@ pub fn init_inspectors<DB: LibmdbxReader>(
     inspectors: Option<Vec<Inspectors>>,
     cex_exchanges: Vec<CexExchange>,
     trade_config: CexDexTradeConfig,
+    thresholds: &ThresholdConfig,
+    track_losses: bool,
     metrics: bool,
 ) -> &'static [&'static dyn Inspector<Result = Vec<Bundle>>] {
     let mut res = Vec::new();
@@ -169,9 +171,12 @@ pub fn init_inspectors<DB: LibmdbxReader>(
             db,
             &cex_exchanges,
             trade_config,
+            thresholds,
+            track_losses,
             metrics.clone(),
         ));
     }
+    res.extend(Inspectors::registered());
 
     &*Box::leak(res.into_boxed_slice())
 }
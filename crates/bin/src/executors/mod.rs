@@ -27,16 +27,25 @@ use alloy_primitives::Address;
 use brontes_classifier::Classifier;
 use brontes_core::decoding::{Parser, TracingProvider};
 use brontes_database::libmdbx::LibmdbxInit;
-use brontes_inspect::Inspector;
-use brontes_pricing::{BrontesBatchPricer, GraphManager, LoadState};
-use brontes_types::{BrontesTaskExecutor, FastHashMap, UnboundedYapperReceiver};
+use brontes_inspect::{Inspector, MAX_BLOCK_WINDOW};
+use brontes_pricing::{
+    path_config::PathConfig, price_overrides::PriceRules, BrontesBatchPricer, GraphManager,
+    LoadState,
+};
+use brontes_types::{
+    BrontesTaskExecutor, FastHashMap, PipelineEvent, PipelineEvents, UnboundedYapperReceiver,
+};
 use futures::{stream::FuturesUnordered, Future, StreamExt};
 use indicatif::MultiProgress;
 use itertools::Itertools;
 pub use range::RangeExecutorWithPricing;
 use reth_tasks::shutdown::GracefulShutdown;
+pub use shared::eth_price_check::{EthPriceCheck, EthPriceCheckMode};
 pub use tip::TipInspector;
-use tokio::{sync::mpsc::unbounded_channel, task::JoinHandle};
+use tokio::{
+    sync::{broadcast, mpsc::unbounded_channel},
+    task::JoinHandle,
+};
 
 use self::shared::{
     dex_pricing::WaitingForPricerFuture, metadata_loader::MetadataLoader,
@@ -63,6 +72,15 @@ pub struct BrontesRunConfig<T: TracingProvider, DB: LibmdbxInit, CH: ClickhouseH
     pub metrics: bool,
     pub is_snapshot: bool,
     pub cex_window: usize,
+    /// stream of the progress of each block through the pipeline
+    pub events: PipelineEvents,
+    /// verify bundle profits against the exact state changes of each block
+    pub verify_profits: bool,
+    /// check each block's eth price against chainlink
+    pub eth_price_check: Option<EthPriceCheck>,
+    /// keep the bundles that lost money, has to match how the inspectors were
+    /// built
+    pub track_losses: bool,
     _p: PhantomData<P>,
 }
 
@@ -103,10 +121,43 @@ impl<T: TracingProvider, DB: LibmdbxInit, CH: ClickhouseHandle, P: Processor>
             tip_db,
             is_snapshot,
             cex_window,
+            events: PipelineEvents::default(),
+            verify_profits: false,
+            eth_price_check: None,
+            track_losses: false,
             _p: PhantomData,
         }
     }
 
+    /// Re-executes every block to get the exact state changes of each
+    /// transaction, which bundle profits are then checked against. Requires
+    /// a tracer that can execute blocks locally
+    pub fn with_profit_verification(mut self, verify_profits: bool) -> Self {
+        self.verify_profits = verify_profits;
+        self
+    }
+
+    /// Checks the eth price of each block against Chainlink's, flagging or
+    /// clamping it when they deviate. Requires a usd stable quote asset
+    pub fn with_eth_price_check(mut self, eth_price_check: Option<EthPriceCheck>) -> Self {
+        self.eth_price_check = eth_price_check;
+        self
+    }
+
+    /// Keeps the sandwiches and atomic arbs that lost money when composing
+    /// each block. The inspectors have to be built with the same flag, see
+    /// `Inspectors::init_mev_inspector`
+    pub fn with_loss_tracking(mut self, track_losses: bool) -> Self {
+        self.track_losses = track_losses;
+        self
+    }
+
+    /// Subscribes to the events emitted as blocks move through the pipeline.
+    /// Must be called before [`Self::build`] to not miss any events.
+    pub fn subscribe_events(&self) -> broadcast::Receiver<PipelineEvent> {
+        self.events.subscribe()
+    }
+
     pub async fn build(
         self,
         executor: BrontesTaskExecutor,
@@ -313,8 +364,10 @@ impl<T: TracingProvider, DB: LibmdbxInit, CH: ClickhouseHandle, P: Processor>
                         ),
                         self.libmdbx,
                         self.inspectors,
+                        self.track_losses,
                         prgrs_bar,
                         metrics,
+                        self.events.clone(),
                     )
                 }
             },
@@ -345,6 +398,8 @@ impl<T: TracingProvider, DB: LibmdbxInit, CH: ClickhouseHandle, P: Processor>
             self.parser,
             self.tip_db,
             self.inspectors,
+            self.track_losses,
+            self.events.clone(),
         )
     }
 
@@ -412,7 +467,9 @@ impl<T: TracingProvider, DB: LibmdbxInit, CH: ClickhouseHandle, P: Processor>
             data_req.clone(),
             pricing_metrics.clone(),
             executor.clone(),
-        );
+        )
+        .with_price_rules(PriceRules::load_from_config())
+        .with_path_config(PathConfig::load_from_config());
 
         let pricing = WaitingForPricerFuture::new(pricer, executor);
         let fetcher = MetadataLoader::new(
@@ -428,7 +485,7 @@ impl<T: TracingProvider, DB: LibmdbxInit, CH: ClickhouseHandle, P: Processor>
             .inspectors
             .iter()
             .max_by_key(|i| i.block_window())
-            .map(|v| v.block_window())
+            .map(|v| v.block_window().min(MAX_BLOCK_WINDOW))
             .expect("no inspectors loaded");
 
         let window = MultiBlockWindow::new(block_window_size);
@@ -441,6 +498,9 @@ impl<T: TracingProvider, DB: LibmdbxInit, CH: ClickhouseHandle, P: Processor>
             self.libmdbx,
             window,
             self.quote_asset,
+            self.events.clone(),
+            self.verify_profits,
+            self.eth_price_check.clone(),
         )
     }
 
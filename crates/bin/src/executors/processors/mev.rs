@@ -1,6 +1,6 @@
-#[cfg(feature = "local-clickhouse")]
 use std::sync::Arc;
 
+use alloy_primitives::Address;
 use brontes_database::libmdbx::{DBWriter, LibmdbxReader};
 use brontes_inspect::{
     composer::{run_block_inspection, ComposerResults},
@@ -15,10 +15,10 @@ use brontes_types::normalized_actions::Action;
 #[cfg(feature = "local-clickhouse")]
 use brontes_types::tree::BlockTree;
 use brontes_types::{
-    db::block_analysis::BlockAnalysis,
+    db::{block_analysis::BlockAnalysis, builder::BuilderRevenue},
     execute_on,
     mev::{Bundle, MevBlock, MevType},
-    BlockData, MultiBlockData,
+    BlockData, MultiBlockData, PipelineEvent, PipelineEvents,
 };
 use tracing::debug;
 
@@ -33,7 +33,9 @@ impl Processor for MevProcessor {
     async fn process_results<DB: DBWriter + LibmdbxReader>(
         db: &'static DB,
         inspectors: &'static [&dyn Inspector<Result = Self::InspectType>],
+        track_losses: bool,
         data: MultiBlockData,
+        events: PipelineEvents,
     ) {
         let last = data.get_most_recent_block().clone();
         let BlockData { metadata, tree } = last;
@@ -50,14 +52,23 @@ impl Processor for MevProcessor {
             insert_tree(db, inner_tree, metadata.block_num).await;
         }
 
+        let block_number = metadata.block_num;
         if tree.tx_roots.is_empty() {
+            events.emit(PipelineEvent::BlockCommitted { block_number });
             return
         }
 
-        let ComposerResults { block_details, mev_details, block_analysis, .. } =
-            execute_on!(async_inspect, { run_block_inspection(inspectors, data, db) }).await;
+        let ComposerResults {
+            block_details, mev_details, block_analysis, builder_payments, ..
+        } = execute_on!(async_inspect, {
+            run_block_inspection(inspectors, data, db, track_losses)
+        })
+        .await;
+
+        events.emit(PipelineEvent::MevFound { block_number, mev: Arc::new(mev_details.clone()) });
 
-        insert_mev_results(db, block_details, mev_details, block_analysis).await;
+        insert_mev_results(db, block_details, mev_details, block_analysis, builder_payments).await;
+        events.emit(PipelineEvent::BlockCommitted { block_number });
     }
 }
 
@@ -82,6 +93,7 @@ async fn insert_mev_results<DB: DBWriter + LibmdbxReader>(
     block_details: MevBlock,
     mev_details: Vec<Bundle>,
     analysis: BlockAnalysis,
+    builder_payments: BuilderRevenue,
 ) {
     debug!(
         target: "brontes::results",
@@ -91,6 +103,7 @@ async fn insert_mev_results<DB: DBWriter + LibmdbxReader>(
 
     let block_number = block_details.block_number;
     output_mev_and_update_searcher_info(database, &mev_details).await;
+    update_builder_payments(database, block_details.builder_address, builder_payments).await;
 
     // Attempt to save the MEV block details
     if let Err(e) = database
@@ -111,6 +124,30 @@ async fn insert_mev_results<DB: DBWriter + LibmdbxReader>(
         );
     }
 }
+
+async fn update_builder_payments<DB: DBWriter + LibmdbxReader>(
+    database: &DB,
+    builder: Address,
+    block_payments: BuilderRevenue,
+) {
+    if block_payments.bundles == 0 {
+        return
+    }
+
+    let mut payments = match database.try_fetch_builder_payments(builder) {
+        Ok(payments) => payments.unwrap_or_default(),
+        Err(e) => {
+            tracing::error!("Failed to fetch builder payments from the database: {:?}", e);
+            return
+        }
+    };
+    payments.merge(block_payments);
+
+    if let Err(e) = database.write_builder_payments(builder, payments).await {
+        tracing::error!("Failed to update builder payments in the database: {:?}", e);
+    }
+}
+
 async fn output_mev_and_update_searcher_info<DB: DBWriter + LibmdbxReader>(
     database: &DB,
     mev_details: &Vec<Bundle>,
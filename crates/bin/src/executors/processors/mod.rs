@@ -1,10 +1,12 @@
 pub mod mev;
+pub mod staging;
 
 use brontes_database::libmdbx::{DBWriter, LibmdbxReader};
 use brontes_inspect::Inspector;
-use brontes_types::MultiBlockData;
+use brontes_types::{MultiBlockData, PipelineEvents};
 use futures::Future;
 pub use mev::*;
+pub use staging::*;
 
 pub trait Processor: Send + Sync + 'static + Unpin + Copy + Clone {
     type InspectType: Send + Sync + Unpin;
@@ -12,6 +14,8 @@ pub trait Processor: Send + Sync + 'static + Unpin + Copy + Clone {
     fn process_results<DB: DBWriter + LibmdbxReader>(
         db: &'static DB,
         inspectors: &'static [&dyn Inspector<Result = Self::InspectType>],
+        track_losses: bool,
         data: MultiBlockData,
+        events: PipelineEvents,
     ) -> impl Future<Output = ()> + Send;
 }
@@ -0,0 +1,52 @@
+use std::sync::Arc;
+
+use brontes_database::libmdbx::{DBWriter, LibmdbxReader};
+use brontes_inspect::{
+    composer::{run_block_inspection, ComposerResults},
+    Inspector,
+};
+use brontes_types::{
+    execute_on, mev::Bundle, BlockData, MultiBlockData, PipelineEvent, PipelineEvents,
+};
+
+use crate::Processor;
+
+/// Writes inspector results to the staging table instead of the production
+/// mev blocks table. Dex quotes and searcher info are left untouched so a
+/// re-run can be compared and discarded without side effects.
+#[derive(Debug, Clone, Copy)]
+pub struct StagingProcessor;
+
+impl Processor for StagingProcessor {
+    type InspectType = Vec<Bundle>;
+
+    async fn process_results<DB: DBWriter + LibmdbxReader>(
+        db: &'static DB,
+        inspectors: &'static [&dyn Inspector<Result = Self::InspectType>],
+        track_losses: bool,
+        data: MultiBlockData,
+        events: PipelineEvents,
+    ) {
+        let BlockData { tree, metadata } = data.get_most_recent_block();
+        let block_number = metadata.block_num;
+        if tree.tx_roots.is_empty() {
+            events.emit(PipelineEvent::BlockCommitted { block_number });
+            return
+        }
+
+        let ComposerResults { block_details, mev_details, .. } = execute_on!(async_inspect, {
+            run_block_inspection(inspectors, data, db, track_losses)
+        })
+        .await;
+
+        events.emit(PipelineEvent::MevFound { block_number, mev: Arc::new(mev_details.clone()) });
+
+        if let Err(e) = db
+            .save_mev_blocks_staging(block_number, block_details, mev_details)
+            .await
+        {
+            tracing::error!(err=%e, %block_number, "failed to insert staged mev block into libmdbx");
+        }
+        events.emit(PipelineEvent::BlockCommitted { block_number });
+    }
+}
@@ -11,7 +11,7 @@ use brontes_database::{
 };
 use brontes_inspect::Inspector;
 use brontes_metrics::range::GlobalRangeMetrics;
-use brontes_types::MultiBlockData;
+use brontes_types::{MultiBlockData, PipelineEvents};
 use futures::{pin_mut, stream::FuturesUnordered, Future, StreamExt};
 use reth_tasks::shutdown::GracefulShutdown;
 use tracing::debug;
@@ -34,8 +34,10 @@ pub struct RangeExecutorWithPricing<
     end_block:      u64,
     libmdbx:        &'static DB,
     inspectors:     &'static [&'static dyn Inspector<Result = P::InspectType>],
+    track_losses:   bool,
     progress_bar:   Option<ProgressBar>,
     global_metrics: Option<GlobalRangeMetrics>,
+    events:         PipelineEvents,
     _p:             PhantomData<P>,
 }
 
@@ -49,8 +51,10 @@ impl<T: TracingProvider, DB: LibmdbxReader + DBWriter, CH: ClickhouseHandle, P:
         state_collector: StateCollector<T, DB, CH>,
         libmdbx: &'static DB,
         inspectors: &'static [&'static dyn Inspector<Result = P::InspectType>],
+        track_losses: bool,
         progress_bar: Option<ProgressBar>,
         global_metrics: Option<GlobalRangeMetrics>,
+        events: PipelineEvents,
     ) -> Self {
         Self {
             id,
@@ -60,8 +64,10 @@ impl<T: TracingProvider, DB: LibmdbxReader + DBWriter, CH: ClickhouseHandle, P:
             end_block,
             libmdbx,
             inspectors,
+            track_losses,
             progress_bar,
             global_metrics,
+            events,
             _p: PhantomData,
         }
     }
@@ -97,14 +103,24 @@ impl<T: TracingProvider, DB: LibmdbxReader + DBWriter, CH: ClickhouseHandle, P:
 
         let metrics = self.global_metrics.clone();
         let inspectors = self.inspectors;
+        let track_losses = self.track_losses;
         let libmdbx = self.libmdbx;
+        let events = self.events.clone();
         self.insert_futures.push(Box::pin(async move {
             if let Some(metrics) = metrics {
                 metrics
-                    .meter_processing(|| Box::pin(P::process_results(libmdbx, inspectors, data)))
+                    .meter_processing(|| {
+                        Box::pin(P::process_results(
+                            libmdbx,
+                            inspectors,
+                            track_losses,
+                            data,
+                            events,
+                        ))
+                    })
                     .await
             } else {
-                P::process_results(libmdbx, inspectors, data).await
+                P::process_results(libmdbx, inspectors, track_losses, data, events).await
             }
         }));
     }
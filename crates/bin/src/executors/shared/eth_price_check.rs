@@ -0,0 +1,174 @@
+//! An optional sanity check of the eth price taken from cex quotes, which
+//! prices gas and any profit made in eth. A single bad quote can put it far
+//! off the market, so it's compared against Chainlink's ETH / USD feed as of
+//! the block, and either flagged or clamped when the two deviate by more than
+//! the configured threshold.
+//!
+//! Chainlink prices in dollars, so the check is only meaningful when the quote
+//! asset is a usd stable.
+use alloy_primitives::{address, keccak256, Address, I256};
+use brontes_types::{
+    constants::{DAI_ADDRESS, USDC_ADDRESS, USDT_ADDRESS},
+    db::metadata::BlockMetadata,
+    traits::TracingProvider,
+    ToFloatNearest, ToScaledRational,
+};
+use clap::ValueEnum;
+use malachite::{
+    num::basic::traits::{One, Zero},
+    Rational,
+};
+use reth_rpc_types::{request::TransactionInput, TransactionRequest};
+use tracing::warn;
+
+/// Chainlink's ETH / USD aggregator proxy on mainnet
+const CHAINLINK_ETH_USD: Address = address!("5f4eC3Df9cbd43714FE2740f5E3616155c5b8419");
+/// Decimals of the feed's answer
+const CHAINLINK_ETH_USD_DECIMALS: u8 = 8;
+
+/// What to do with an eth price that deviates from Chainlink's
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum EthPriceCheckMode {
+    /// Log the block and keep the price
+    Flag,
+    /// Log the block and move the price to the edge of the allowed range
+    Clamp,
+}
+
+#[derive(Debug, Clone)]
+pub struct EthPriceCheck {
+    pub mode:          EthPriceCheckMode,
+    /// The largest deviation from Chainlink allowed, as a share of its price
+    pub max_deviation: Rational,
+}
+
+impl EthPriceCheck {
+    pub fn new(mode: EthPriceCheckMode, max_deviation: f64) -> eyre::Result<Self> {
+        let max_deviation = Rational::try_from_float_simplest(max_deviation)
+            .filter(|deviation| *deviation > Rational::ZERO && *deviation < Rational::ONE)
+            .ok_or_else(|| eyre::eyre!("max eth price deviation has to be between 0 and 1"))?;
+
+        Ok(Self { mode, max_deviation })
+    }
+
+    /// Whether the eth price is quoted in a usd stable Chainlink can check it
+    /// against
+    pub fn supports_quote_asset(quote_asset: Address) -> bool {
+        [USDT_ADDRESS, USDC_ADDRESS, DAI_ADDRESS].contains(&quote_asset)
+    }
+
+    /// Chainlink's ETH / USD price as of the end of `block`
+    pub async fn chainlink_price<T: TracingProvider>(
+        tracer: &T,
+        block: u64,
+    ) -> eyre::Result<Rational> {
+        let selector = keccak256("latestRoundData()".as_bytes())[..4].to_vec();
+        let req = TransactionRequest {
+            to: Some(CHAINLINK_ETH_USD),
+            input: TransactionInput::new(selector.into()),
+            ..Default::default()
+        };
+        let res = tracer.eth_call(req, Some(block.into()), None, None).await?;
+
+        // (roundId, answer, startedAt, updatedAt, answeredInRound)
+        let answer = res
+            .get(32..64)
+            .and_then(I256::try_from_be_slice)
+            .ok_or_else(|| eyre::eyre!("invalid latestRoundData return"))?;
+        if answer <= I256::ZERO {
+            eyre::bail!("chainlink returned a non positive eth price at block {block}")
+        }
+
+        Ok(answer.to_scaled_rational(CHAINLINK_ETH_USD_DECIMALS))
+    }
+
+    /// Checks the block's eth price against `chainlink`. Blocks without an eth
+    /// price are left to fall back on dex pricing, and without chainlink's
+    /// price the block goes unchecked
+    pub fn apply(&self, meta: &mut BlockMetadata, chainlink: Option<&Rational>) {
+        let Some(chainlink) = chainlink else { return };
+        if meta.eth_prices == Rational::ZERO {
+            return
+        }
+
+        let lower = chainlink * (Rational::ONE - &self.max_deviation);
+        let upper = chainlink * (Rational::ONE + &self.max_deviation);
+        if meta.eth_prices >= lower && meta.eth_prices <= upper {
+            return
+        }
+
+        let clamped = if meta.eth_prices < lower { lower } else { upper };
+        warn!(
+            target: "brontes::eth_price_check",
+            block = meta.block_num,
+            eth_price = %meta.eth_prices.clone().to_float(),
+            chainlink = %chainlink.clone().to_float(),
+            mode = ?self.mode,
+            "eth price deviates from chainlink"
+        );
+        if self.mode == EthPriceCheckMode::Clamp {
+            meta.eth_prices = clamped;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(mode: EthPriceCheckMode) -> EthPriceCheck {
+        EthPriceCheck::new(mode, 0.02).unwrap()
+    }
+
+    fn block_meta(eth_price: i64) -> BlockMetadata {
+        BlockMetadata { eth_prices: Rational::from(eth_price), ..Default::default() }
+    }
+
+    #[test]
+    fn test_new() {
+        assert_eq!(check(EthPriceCheckMode::Flag).max_deviation, Rational::from_signeds(1, 50));
+        assert!(EthPriceCheck::new(EthPriceCheckMode::Flag, 0.0).is_err());
+        assert!(EthPriceCheck::new(EthPriceCheckMode::Flag, 1.0).is_err());
+        assert!(EthPriceCheck::new(EthPriceCheckMode::Flag, -0.5).is_err());
+        assert!(EthPriceCheck::new(EthPriceCheckMode::Flag, f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_within_tolerance() {
+        let chainlink = Rational::from(3000);
+        for price in [2940, 3000, 3060] {
+            let mut meta = block_meta(price);
+            check(EthPriceCheckMode::Clamp).apply(&mut meta, Some(&chainlink));
+            assert_eq!(meta.eth_prices, Rational::from(price));
+        }
+    }
+
+    #[test]
+    fn test_out_of_tolerance() {
+        let chainlink = Rational::from(3000);
+
+        let mut meta = block_meta(3500);
+        check(EthPriceCheckMode::Flag).apply(&mut meta, Some(&chainlink));
+        assert_eq!(meta.eth_prices, Rational::from(3500));
+
+        check(EthPriceCheckMode::Clamp).apply(&mut meta, Some(&chainlink));
+        assert_eq!(meta.eth_prices, Rational::from(3060));
+
+        let mut meta = block_meta(100);
+        check(EthPriceCheckMode::Clamp).apply(&mut meta, Some(&chainlink));
+        assert_eq!(meta.eth_prices, Rational::from(2940));
+    }
+
+    #[test]
+    fn test_missing_price() {
+        // no chainlink price, the block goes unchecked
+        let mut meta = block_meta(3500);
+        check(EthPriceCheckMode::Clamp).apply(&mut meta, None);
+        assert_eq!(meta.eth_prices, Rational::from(3500));
+
+        // no eth price, left for dex pricing to fill in
+        let mut meta = block_meta(0);
+        check(EthPriceCheckMode::Clamp).apply(&mut meta, Some(&Rational::from(3000)));
+        assert_eq!(meta.eth_prices, Rational::ZERO);
+    }
+}
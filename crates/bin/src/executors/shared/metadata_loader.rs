@@ -15,23 +15,51 @@ use brontes_types::{
     db::{
         cex::trades::{window_loader::CexWindow, CexTradeMap},
         dex::DexQuotes,
-        metadata::Metadata,
+        metadata::{BlockMetadata, Metadata},
         traits::{DBWriter, LibmdbxReader},
     },
     normalized_actions::Action,
+    state_diff::TxStateDiff,
     traits::TracingProvider,
     BlockData, BlockTree,
 };
 use futures::{stream::FuturesOrdered, Future, Stream, StreamExt};
 use itertools::Itertools;
+use malachite::Rational;
+use reth_primitives::Withdrawal;
 
-use super::dex_pricing::WaitingForPricerFuture;
+use super::{dex_pricing::WaitingForPricerFuture, eth_price_check::EthPriceCheck};
 
 /// Limits the amount we work ahead in the processing. This is done
 /// as the Pricer is a slow process and otherwise we will end up caching 100+ gb
 /// of processed trees
 const MAX_PENDING_TREES: usize = 5;
 
+/// Block data that comes from the node instead of the database
+#[derive(Debug, Default)]
+pub struct BlockExecution {
+    pub withdrawals:         Vec<Withdrawal>,
+    pub state_diffs:         Vec<TxStateDiff>,
+    /// the check of the block's eth price, along with chainlink's price it's
+    /// checked against
+    pub eth_price_check:     Option<EthPriceCheck>,
+    pub chainlink_eth_price: Option<Rational>,
+}
+
+impl BlockExecution {
+    fn apply(self, meta: &mut BlockMetadata) {
+        if let Some(check) = &self.eth_price_check {
+            check.apply(meta, self.chainlink_eth_price.as_ref());
+        }
+        meta.withdrawals = self.withdrawals;
+        meta.state_diffs = self
+            .state_diffs
+            .into_iter()
+            .map(|diff| (diff.tx_hash, diff))
+            .collect();
+    }
+}
+
 pub type ClickhouseMetadataFuture =
     FuturesOrdered<Pin<Box<dyn Future<Output = (u64, BlockTree<Action>, Metadata)> + Send>>>;
 
@@ -97,6 +125,7 @@ impl<T: TracingProvider, CH: ClickhouseHandle> MetadataLoader<T, CH> {
     pub fn load_metadata_for_tree<DB: LibmdbxReader + DBWriter>(
         &mut self,
         tree: BlockTree<Action>,
+        execution: BlockExecution,
         libmdbx: &'static DB,
         quote_asset: Address,
     ) {
@@ -104,13 +133,20 @@ impl<T: TracingProvider, CH: ClickhouseHandle> MetadataLoader<T, CH> {
         let generate_dex_pricing = self.generate_dex_pricing(block, libmdbx);
 
         if !generate_dex_pricing && self.clickhouse.is_none() {
-            self.load_metadata_with_dex_prices(tree, libmdbx, block, quote_asset);
+            self.load_metadata_with_dex_prices(tree, execution, libmdbx, block, quote_asset);
         } else if let Some(clickhouse) = self.clickhouse {
-            self.load_metadata_from_clickhouse(tree, libmdbx, clickhouse, block, quote_asset);
+            self.load_metadata_from_clickhouse(
+                tree,
+                execution,
+                libmdbx,
+                clickhouse,
+                block,
+                quote_asset,
+            );
         } else if self.force_no_dex_pricing {
-            self.load_metadata_force_no_dex_pricing(tree, libmdbx, block, quote_asset);
+            self.load_metadata_force_no_dex_pricing(tree, execution, libmdbx, block, quote_asset);
         } else {
-            self.load_metadata_no_dex_pricing(tree, libmdbx, block, quote_asset);
+            self.load_metadata_no_dex_pricing(tree, execution, libmdbx, block, quote_asset);
         }
     }
 
@@ -149,6 +185,7 @@ impl<T: TracingProvider, CH: ClickhouseHandle> MetadataLoader<T, CH> {
     fn load_metadata_no_dex_pricing<DB: LibmdbxReader>(
         &mut self,
         tree: BlockTree<Action>,
+        execution: BlockExecution,
         libmdbx: &'static DB,
         block: u64,
         quote_asset: Address,
@@ -168,6 +205,7 @@ impl<T: TracingProvider, CH: ClickhouseHandle> MetadataLoader<T, CH> {
         meta.builder_info = libmdbx
             .try_fetch_builder_info(tree.header.beneficiary)
             .expect("failed to fetch builder info table in libmdbx");
+        execution.apply(&mut meta.block_metadata);
 
         meta.cex_trades = self.load_cex_trades(libmdbx, block);
 
@@ -180,6 +218,7 @@ impl<T: TracingProvider, CH: ClickhouseHandle> MetadataLoader<T, CH> {
     fn load_metadata_force_no_dex_pricing<DB: LibmdbxReader>(
         &mut self,
         tree: BlockTree<Action>,
+        execution: BlockExecution,
         libmdbx: &'static DB,
         block: u64,
         quote_asset: Address,
@@ -199,6 +238,7 @@ impl<T: TracingProvider, CH: ClickhouseHandle> MetadataLoader<T, CH> {
         meta.builder_info = libmdbx
             .try_fetch_builder_info(tree.header.beneficiary)
             .expect("failed to fetch builder info table in libmdbx");
+        execution.apply(&mut meta.block_metadata);
 
         let mut meta = meta.into_full_metadata(DexQuotes(vec![]));
         meta.cex_trades = self.load_cex_trades(libmdbx, block);
@@ -211,6 +251,7 @@ impl<T: TracingProvider, CH: ClickhouseHandle> MetadataLoader<T, CH> {
     fn load_metadata_with_dex_prices<DB: LibmdbxReader>(
         &mut self,
         tree: BlockTree<Action>,
+        execution: BlockExecution,
         libmdbx: &'static DB,
         block: u64,
         quote_asset: Address,
@@ -226,6 +267,7 @@ impl<T: TracingProvider, CH: ClickhouseHandle> MetadataLoader<T, CH> {
         meta.builder_info = libmdbx
             .try_fetch_builder_info(tree.header.beneficiary)
             .expect("failed to fetch builder info table in libmdbx");
+        execution.apply(&mut meta.block_metadata);
 
         meta.cex_trades = self.load_cex_trades(libmdbx, block);
 
@@ -237,6 +279,7 @@ impl<T: TracingProvider, CH: ClickhouseHandle> MetadataLoader<T, CH> {
     fn load_metadata_from_clickhouse<DB: LibmdbxReader + DBWriter>(
         &mut self,
         tree: BlockTree<Action>,
+        execution: BlockExecution,
         libmdbx: &'static DB,
         clickhouse: &'static CH,
         block: u64,
@@ -295,6 +338,7 @@ impl<T: TracingProvider, CH: ClickhouseHandle> MetadataLoader<T, CH> {
 
             meta.cex_trades = Some(trades);
             meta.builder_info = builder_info;
+            execution.apply(&mut meta.block_metadata);
             (block, tree, meta)
         });
 
@@ -1,4 +1,5 @@
 pub mod dex_pricing;
+pub mod eth_price_check;
 pub mod metadata_loader;
 pub mod multi_block_window;
 pub mod state_collector;
@@ -17,16 +17,21 @@ use brontes_types::{
     normalized_actions::Action,
     structured_trace::TxTrace,
     traits::TracingProvider,
-    BlockTree, MultiBlockData,
+    BlockTree, MultiBlockData, PipelineEvent, PipelineEvents,
 };
 use eyre::eyre;
 use futures::{Future, FutureExt, Stream, StreamExt};
 use reth_primitives::Header;
-use tracing::{span, trace, Instrument, Level};
+use tracing::{span, trace, warn, Instrument, Level};
 
-use super::{metadata_loader::MetadataLoader, multi_block_window::MultiBlockWindow};
+use super::{
+    eth_price_check::EthPriceCheck,
+    metadata_loader::{BlockExecution, MetadataLoader},
+    multi_block_window::MultiBlockWindow,
+};
 
-type CollectionFut<'a> = Pin<Box<dyn Future<Output = eyre::Result<BlockTree<Action>>> + Send + 'a>>;
+type CollectionFut<'a> =
+    Pin<Box<dyn Future<Output = eyre::Result<(BlockTree<Action>, BlockExecution)>> + Send + 'a>>;
 type ExecutionFut<'a> = Pin<Box<dyn Future<Output = Option<(Vec<TxTrace>, Header)>> + Send + 'a>>;
 
 pub struct StateCollector<T: TracingProvider, DB: LibmdbxReader + DBWriter, CH: ClickhouseHandle> {
@@ -39,6 +44,12 @@ pub struct StateCollector<T: TracingProvider, DB: LibmdbxReader + DBWriter, CH:
     collection_future: Option<CollectionFut<'static>>,
     multi_block:       MultiBlockWindow,
     quote_asset:       Address,
+    events:            PipelineEvents,
+    /// re-execute each block to get the exact per transaction state changes
+    /// used to verify bundle profits
+    fetch_state_diffs: bool,
+    /// check each block's eth price against chainlink
+    eth_price_check:   Option<EthPriceCheck>,
 }
 
 impl<T: TracingProvider, DB: LibmdbxReader + DBWriter, CH: ClickhouseHandle>
@@ -52,6 +63,9 @@ impl<T: TracingProvider, DB: LibmdbxReader + DBWriter, CH: ClickhouseHandle>
         db: &'static DB,
         multi_block: MultiBlockWindow,
         quote_asset: Address,
+        events: PipelineEvents,
+        fetch_state_diffs: bool,
+        eth_price_check: Option<EthPriceCheck>,
     ) -> Self {
         Self {
             mark_as_finished,
@@ -62,6 +76,9 @@ impl<T: TracingProvider, DB: LibmdbxReader + DBWriter, CH: ClickhouseHandle>
             collection_future: None,
             multi_block,
             quote_asset,
+            events,
+            fetch_state_diffs,
+            eth_price_check,
         }
     }
 
@@ -79,12 +96,15 @@ impl<T: TracingProvider, DB: LibmdbxReader + DBWriter, CH: ClickhouseHandle>
 
     async fn state_future(
         generate_pricing: bool,
+        fetch_state_diffs: bool,
+        eth_price_check: Option<EthPriceCheck>,
         block: u64,
         fut: ExecutionFut<'static>,
         classifier: &'static Classifier<'static, T, DB>,
+        parser: &'static Parser<T, DB>,
         id: usize,
         metrics: Option<GlobalRangeMetrics>,
-    ) -> eyre::Result<BlockTree<Action>> {
+    ) -> eyre::Result<(BlockTree<Action>, BlockExecution)> {
         let Some((traces, header)) = fut.await else {
             classifier.block_load_failure(block);
             return Err(eyre!("no traces found {block}"))
@@ -110,16 +130,55 @@ impl<T: TracingProvider, DB: LibmdbxReader + DBWriter, CH: ClickhouseHandle>
                 .unwrap()
         };
 
-        Ok(res)
+        // missing withdrawals only affect the builder payment accounting, so we
+        // don't fail the block over them
+        let withdrawals = parser.get_withdrawals(block).await.unwrap_or_else(|e| {
+            warn!(%block, error = %e, "failed to fetch block withdrawals");
+            vec![]
+        });
+
+        // same goes for the state diffs, without them bundles just go unverified
+        let state_diffs = if fetch_state_diffs {
+            parser.get_state_diffs(block).await.unwrap_or_else(|e| {
+                warn!(%block, error = %e, "failed to fetch block state diffs");
+                vec![]
+            })
+        } else {
+            vec![]
+        };
+
+        // and without chainlink's price the block's eth price goes unchecked
+        let chainlink_eth_price = if eth_price_check.is_some() {
+            EthPriceCheck::chainlink_price(parser.get_tracer().as_ref(), block)
+                .await
+                .inspect_err(|e| warn!(%block, error = %e, "failed to fetch chainlink eth price"))
+                .ok()
+        } else {
+            None
+        };
+
+        Ok((res, BlockExecution { withdrawals, state_diffs, eth_price_check, chainlink_eth_price }))
     }
 
     pub fn fetch_state_for(&mut self, block: u64, id: usize, metrics: Option<GlobalRangeMetrics>) {
+        self.events
+            .emit(PipelineEvent::BlockStarted { block_number: block });
         let execute_fut = self.parser.execute(block, id, metrics.clone());
 
         let generate_pricing = self.metadata_fetcher.generate_dex_pricing(block, self.db);
         self.collection_future = Some(Box::pin(
-            Self::state_future(generate_pricing, block, execute_fut, self.classifier, id, metrics)
-                .instrument(span!(Level::ERROR, "mev processor", block_number=%block)),
+            Self::state_future(
+                generate_pricing,
+                self.fetch_state_diffs,
+                self.eth_price_check.clone(),
+                block,
+                execute_fut,
+                self.classifier,
+                self.parser,
+                id,
+                metrics,
+            )
+            .instrument(span!(Level::ERROR, "mev processor", block_number=%block)),
         ))
     }
 
@@ -141,11 +200,16 @@ impl<T: TracingProvider, DB: LibmdbxReader + DBWriter, CH: ClickhouseHandle> Str
     ) -> std::task::Poll<Option<Self::Item>> {
         if let Some(mut collection_future) = self.collection_future.take() {
             match collection_future.poll_unpin(cx) {
-                Poll::Ready(Ok(tree)) => {
+                Poll::Ready(Ok((tree, execution))) => {
+                    self.events.emit(PipelineEvent::TreeBuilt {
+                        block_number: tree.header.number,
+                        tx_count:     tree.tx_roots.len(),
+                    });
+
                     let db = self.db;
                     let quote_asset = self.quote_asset;
                     self.metadata_fetcher
-                        .load_metadata_for_tree(tree, db, quote_asset);
+                        .load_metadata_for_tree(tree, execution, db, quote_asset);
 
                     cx.waker().wake_by_ref();
                 }
@@ -166,8 +230,12 @@ impl<T: TracingProvider, DB: LibmdbxReader + DBWriter, CH: ClickhouseHandle> Str
             return Poll::Ready(None)
         }
 
-        self.metadata_fetcher
-            .poll_next_unpin(cx)
-            .map(|inner| inner.map(|data| self.multi_block.new_block_data(data)))
+        self.metadata_fetcher.poll_next_unpin(cx).map(|inner| {
+            inner.map(|data| {
+                self.events
+                    .emit(PipelineEvent::PricesReady { block_number: data.metadata.block_num });
+                self.multi_block.new_block_data(data)
+            })
+        })
     }
 }
@@ -11,7 +11,7 @@ use brontes_database::{
     libmdbx::{DBWriter, LibmdbxReader},
 };
 use brontes_inspect::Inspector;
-use brontes_types::MultiBlockData;
+use brontes_types::{MultiBlockData, PipelineEvents};
 use futures::{pin_mut, stream::FuturesUnordered, Future, StreamExt};
 use reth_tasks::shutdown::GracefulShutdown;
 use tokio::time::{interval, Interval};
@@ -32,8 +32,10 @@ pub struct TipInspector<
     state_collector:    StateCollector<T, DB, CH>,
     database:           &'static DB,
     inspectors:         &'static [&'static dyn Inspector<Result = P::InspectType>],
+    track_losses:       bool,
     processing_futures: FuturesUnordered<Pin<Box<dyn Future<Output = ()> + Send + 'static>>>,
     poll_interval:      Interval,
+    events:             PipelineEvents,
     _p:                 PhantomData<P>,
 }
 
@@ -47,16 +49,20 @@ impl<T: TracingProvider, DB: DBWriter + LibmdbxReader, CH: ClickhouseHandle, P:
         parser: &'static Parser<T, DB>,
         database: &'static DB,
         inspectors: &'static [&'static dyn Inspector<Result = P::InspectType>],
+        track_losses: bool,
+        events: PipelineEvents,
     ) -> Self {
         Self {
             back_from_tip,
             state_collector,
             inspectors,
+            track_losses,
             current_block,
             parser,
             processing_futures: FuturesUnordered::new(),
             database,
             poll_interval: interval(Duration::from_secs(3)),
+            events,
             _p: PhantomData,
         }
     }
@@ -119,7 +125,9 @@ impl<T: TracingProvider, DB: DBWriter + LibmdbxReader, CH: ClickhouseHandle, P:
         self.processing_futures.push(Box::pin(P::process_results(
             self.database,
             self.inspectors,
+            self.track_losses,
             data,
+            self.events.clone(),
         )));
     }
 }
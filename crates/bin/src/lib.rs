@@ -23,6 +23,7 @@
 //!
 //! Please refer to the individual crate documentation for more details.
 
+pub mod admin;
 pub mod cli;
 pub mod executors;
 pub mod misc;
@@ -61,12 +61,27 @@ fn run() -> eyre::Result<()> {
                 command.execute(brontes_db_endpoint, ctx)
             })
         }
+        Commands::Analyze(command) => {
+            runner::run_command_until_exit(None, Duration::from_secs(5), |ctx| {
+                command.execute(brontes_db_endpoint, ctx)
+            })
+        }
+        Commands::Bootstrap(command) => {
+            runner::run_command_until_exit(None, Duration::from_secs(5), |_| {
+                command.execute(brontes_db_endpoint)
+            })
+        }
+        Commands::Doctor(command) => {
+            runner::run_command_until_exit(None, Duration::from_secs(5), |ctx| {
+                command.execute(brontes_db_endpoint, ctx)
+            })
+        }
     }
 }
 
 fn init_tracing(verbosity: Directive) {
     let layers = vec![
-        brontes_tracing::stdout(verbosity),
+        brontes_tracing::stdout_reloadable(verbosity),
         brontes_metrics::error_layer::BrontesErrorMetrics::default().boxed(),
     ];
 
@@ -1,5 +1,7 @@
+mod spark;
 mod v2;
 mod v3;
 
+pub use spark::*;
 pub use v2::*;
 pub use v3::*;
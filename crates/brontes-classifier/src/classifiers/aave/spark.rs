@@ -0,0 +1,258 @@
+use brontes_macros::action_impl;
+use brontes_types::{
+    normalized_actions::{
+        LendingAction, NormalizedFlashLoan, NormalizedLending, NormalizedLiquidation,
+    },
+    structured_trace::CallInfo,
+    utils::ToScaledRational,
+    Protocol,
+};
+use malachite::{num::basic::traits::Zero, Rational};
+
+// SparkLend is a fork of Aave V3 with the same pool interface. The classifiers
+// are registered on the pool proxy rather than the implementation, so the
+// market is the address that was called
+action_impl!(
+    Protocol::Spark,
+    crate::AaveV3::liquidationCallCall,
+    Liquidation,
+    [LiquidationEvent],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: liquidationCallCall,
+    db_tx: &DB | {
+
+        let debt_info = db_tx.try_fetch_token_info(call_data.debtAsset)?;
+        let collateral_info = db_tx.try_fetch_token_info(call_data.collateralAsset)?;
+
+        let covered_debt = call_data.debtToCover.to_scaled_rational(debt_info.decimals);
+
+        return Ok(NormalizedLiquidation {
+            protocol: Protocol::Spark,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            liquidator: info.msg_sender,
+            debtor: call_data.user,
+            collateral_asset: collateral_info,
+            debt_asset: debt_info,
+            covered_debt,
+            // filled in later
+            liquidated_collateral: Rational::ZERO,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::Spark,
+    crate::AaveV3::flashLoanCall,
+    FlashLoan,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: flashLoanCall,
+    db_tx: &DB | {
+        let (amounts, assets): (Vec<_>, Vec<_>) = call_data.assets
+            .iter()
+            .zip(call_data.amounts.iter())
+            .filter_map(|(asset, amount)| {
+                let token_info = db_tx.try_fetch_token_info(*asset).ok()?;
+                Some((amount.to_scaled_rational(token_info.decimals),token_info))
+        }).unzip();
+
+        return Ok(NormalizedFlashLoan {
+            protocol: Protocol::Spark,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            pool: info.target_address,
+            receiver_contract: call_data.receiverAddress,
+            assets,
+            amounts,
+            aave_mode: Some((call_data.interestRateModes, call_data.onBehalfOf)),
+            // These fields are all empty at this stage, they will be filled upon finalized classification
+            child_actions: vec![],
+            repayments: vec![],
+            fees_paid: vec![],
+            msg_value: info.msg_value,
+
+
+        })
+
+    }
+);
+
+action_impl!(
+    Protocol::Spark,
+    crate::AaveV3::flashLoanSimpleCall,
+    FlashLoan,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: flashLoanSimpleCall,
+    db_tx: &DB | {
+
+        let token_info = db_tx.try_fetch_token_info(call_data.asset)?;
+        let amount = call_data.amount.to_scaled_rational(token_info.decimals);
+
+        return Ok(NormalizedFlashLoan {
+            protocol: Protocol::Spark,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            pool: info.target_address,
+            receiver_contract: call_data.receiverAddress,
+            assets: vec![token_info],
+            amounts: vec![amount],
+            aave_mode: None,
+            // These fields are all empty at this stage, they will be filled upon finalized classification
+            child_actions: vec![],
+            repayments: vec![],
+            fees_paid: vec![],
+            msg_value: info.msg_value,
+
+
+        })
+
+    }
+);
+
+action_impl!(
+    Protocol::Spark,
+    crate::AaveV3::supplyCall,
+    Lending,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: supplyCall,
+    db_tx: &DB | {
+        let token = db_tx.try_fetch_token_info(call_data.asset)?;
+        let amount = call_data.amount.to_scaled_rational(token.decimals);
+
+        return Ok(NormalizedLending {
+            protocol: Protocol::Spark,
+            trace_index: info.trace_idx,
+            action: LendingAction::Deposit,
+            market: info.target_address,
+            from: info.msg_sender,
+            account: call_data.onBehalfOf,
+            token,
+            amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::Spark,
+    crate::AaveV3::depositCall,
+    Lending,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: depositCall,
+    db_tx: &DB | {
+        let token = db_tx.try_fetch_token_info(call_data.asset)?;
+        let amount = call_data.amount.to_scaled_rational(token.decimals);
+
+        return Ok(NormalizedLending {
+            protocol: Protocol::Spark,
+            trace_index: info.trace_idx,
+            action: LendingAction::Deposit,
+            market: info.target_address,
+            from: info.msg_sender,
+            account: call_data.onBehalfOf,
+            token,
+            amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::Spark,
+    crate::AaveV3::withdrawCall,
+    Lending,
+    [],
+    call_data: true,
+    return_data: true,
+    |
+    info: CallInfo,
+    call_data: withdrawCall,
+    return_data: withdrawReturn,
+    db_tx: &DB | {
+        let token = db_tx.try_fetch_token_info(call_data.asset)?;
+        let amount = return_data._0.to_scaled_rational(token.decimals);
+
+        return Ok(NormalizedLending {
+            protocol: Protocol::Spark,
+            trace_index: info.trace_idx,
+            action: LendingAction::Withdraw,
+            market: info.target_address,
+            from: info.msg_sender,
+            account: info.msg_sender,
+            token,
+            amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::Spark,
+    crate::AaveV3::borrowCall,
+    Lending,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: borrowCall,
+    db_tx: &DB | {
+        let token = db_tx.try_fetch_token_info(call_data.asset)?;
+        let amount = call_data.amount.to_scaled_rational(token.decimals);
+
+        return Ok(NormalizedLending {
+            protocol: Protocol::Spark,
+            trace_index: info.trace_idx,
+            action: LendingAction::Borrow,
+            market: info.target_address,
+            from: info.msg_sender,
+            account: call_data.onBehalfOf,
+            token,
+            amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::Spark,
+    crate::AaveV3::repayCall,
+    Lending,
+    [],
+    call_data: true,
+    return_data: true,
+    |
+    info: CallInfo,
+    call_data: repayCall,
+    return_data: repayReturn,
+    db_tx: &DB | {
+        let token = db_tx.try_fetch_token_info(call_data.asset)?;
+        let amount = return_data._0.to_scaled_rational(token.decimals);
+
+        return Ok(NormalizedLending {
+            protocol: Protocol::Spark,
+            trace_index: info.trace_idx,
+            action: LendingAction::Repay,
+            market: info.target_address,
+            from: info.msg_sender,
+            account: call_data.onBehalfOf,
+            token,
+            amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
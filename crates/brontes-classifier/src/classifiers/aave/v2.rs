@@ -1,6 +1,8 @@
 use brontes_macros::action_impl;
 use brontes_types::{
-    normalized_actions::{NormalizedFlashLoan, NormalizedLiquidation},
+    normalized_actions::{
+        LendingAction, NormalizedFlashLoan, NormalizedLending, NormalizedLiquidation,
+    },
     structured_trace::CallInfo,
     utils::ToScaledRational,
     Protocol,
@@ -25,7 +27,7 @@ action_impl!(
         return Ok(NormalizedLiquidation {
             protocol: Protocol::AaveV2,
             trace_index: info.trace_idx,
-            pool: info.from_address,
+            pool: info.target_address,
             liquidator: info.msg_sender,
             debtor: call_data.user,
             collateral_asset: collateral_info,
@@ -74,3 +76,115 @@ action_impl!(
 
     }
 );
+
+action_impl!(
+    Protocol::AaveV2,
+    crate::AaveV2::depositCall,
+    Lending,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: depositCall,
+    db_tx: &DB | {
+        let token = db_tx.try_fetch_token_info(call_data.asset)?;
+        let amount = call_data.amount.to_scaled_rational(token.decimals);
+
+        return Ok(NormalizedLending {
+            protocol: Protocol::AaveV2,
+            trace_index: info.trace_idx,
+            action: LendingAction::Deposit,
+            market: info.target_address,
+            from: info.msg_sender,
+            account: call_data.onBehalfOf,
+            token,
+            amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::AaveV2,
+    crate::AaveV2::withdrawCall,
+    Lending,
+    [],
+    call_data: true,
+    return_data: true,
+    |
+    info: CallInfo,
+    call_data: withdrawCall,
+    return_data: withdrawReturn,
+    db_tx: &DB | {
+        let token = db_tx.try_fetch_token_info(call_data.asset)?;
+        let amount = return_data._0.to_scaled_rational(token.decimals);
+
+        return Ok(NormalizedLending {
+            protocol: Protocol::AaveV2,
+            trace_index: info.trace_idx,
+            action: LendingAction::Withdraw,
+            market: info.target_address,
+            from: info.msg_sender,
+            account: info.msg_sender,
+            token,
+            amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::AaveV2,
+    crate::AaveV2::borrowCall,
+    Lending,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: borrowCall,
+    db_tx: &DB | {
+        let token = db_tx.try_fetch_token_info(call_data.asset)?;
+        let amount = call_data.amount.to_scaled_rational(token.decimals);
+
+        return Ok(NormalizedLending {
+            protocol: Protocol::AaveV2,
+            trace_index: info.trace_idx,
+            action: LendingAction::Borrow,
+            market: info.target_address,
+            from: info.msg_sender,
+            account: call_data.onBehalfOf,
+            token,
+            amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::AaveV2,
+    crate::AaveV2::repayCall,
+    Lending,
+    [],
+    call_data: true,
+    return_data: true,
+    |
+    info: CallInfo,
+    call_data: repayCall,
+    return_data: repayReturn,
+    db_tx: &DB | {
+        let token = db_tx.try_fetch_token_info(call_data.asset)?;
+        let amount = return_data._0.to_scaled_rational(token.decimals);
+
+        return Ok(NormalizedLending {
+            protocol: Protocol::AaveV2,
+            trace_index: info.trace_idx,
+            action: LendingAction::Repay,
+            market: info.target_address,
+            from: info.msg_sender,
+            account: call_data.onBehalfOf,
+            token,
+            amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
@@ -1,6 +1,8 @@
 use brontes_macros::action_impl;
 use brontes_types::{
-    normalized_actions::{NormalizedFlashLoan, NormalizedLiquidation},
+    normalized_actions::{
+        LendingAction, NormalizedFlashLoan, NormalizedLending, NormalizedLiquidation,
+    },
     structured_trace::CallInfo,
     utils::ToScaledRational,
     Protocol,
@@ -26,7 +28,7 @@ action_impl!(
         return Ok(NormalizedLiquidation {
             protocol: Protocol::AaveV3,
             trace_index: info.trace_idx,
-            pool: info.from_address,
+            pool: info.target_address,
             liquidator: info.msg_sender,
             debtor: call_data.user,
             collateral_asset: collateral_info,
@@ -113,6 +115,145 @@ action_impl!(
     }
 );
 
+action_impl!(
+    Protocol::AaveV3,
+    crate::AaveV3::supplyCall,
+    Lending,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: supplyCall,
+    db_tx: &DB | {
+        let token = db_tx.try_fetch_token_info(call_data.asset)?;
+        let amount = call_data.amount.to_scaled_rational(token.decimals);
+
+        return Ok(NormalizedLending {
+            protocol: Protocol::AaveV3,
+            trace_index: info.trace_idx,
+            action: LendingAction::Deposit,
+            market: info.target_address,
+            from: info.msg_sender,
+            account: call_data.onBehalfOf,
+            token,
+            amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::AaveV3,
+    crate::AaveV3::depositCall,
+    Lending,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: depositCall,
+    db_tx: &DB | {
+        let token = db_tx.try_fetch_token_info(call_data.asset)?;
+        let amount = call_data.amount.to_scaled_rational(token.decimals);
+
+        return Ok(NormalizedLending {
+            protocol: Protocol::AaveV3,
+            trace_index: info.trace_idx,
+            action: LendingAction::Deposit,
+            market: info.target_address,
+            from: info.msg_sender,
+            account: call_data.onBehalfOf,
+            token,
+            amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::AaveV3,
+    crate::AaveV3::withdrawCall,
+    Lending,
+    [],
+    call_data: true,
+    return_data: true,
+    |
+    info: CallInfo,
+    call_data: withdrawCall,
+    return_data: withdrawReturn,
+    db_tx: &DB | {
+        let token = db_tx.try_fetch_token_info(call_data.asset)?;
+        let amount = return_data._0.to_scaled_rational(token.decimals);
+
+        return Ok(NormalizedLending {
+            protocol: Protocol::AaveV3,
+            trace_index: info.trace_idx,
+            action: LendingAction::Withdraw,
+            market: info.target_address,
+            from: info.msg_sender,
+            account: info.msg_sender,
+            token,
+            amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::AaveV3,
+    crate::AaveV3::borrowCall,
+    Lending,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: borrowCall,
+    db_tx: &DB | {
+        let token = db_tx.try_fetch_token_info(call_data.asset)?;
+        let amount = call_data.amount.to_scaled_rational(token.decimals);
+
+        return Ok(NormalizedLending {
+            protocol: Protocol::AaveV3,
+            trace_index: info.trace_idx,
+            action: LendingAction::Borrow,
+            market: info.target_address,
+            from: info.msg_sender,
+            account: call_data.onBehalfOf,
+            token,
+            amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::AaveV3,
+    crate::AaveV3::repayCall,
+    Lending,
+    [],
+    call_data: true,
+    return_data: true,
+    |
+    info: CallInfo,
+    call_data: repayCall,
+    return_data: repayReturn,
+    db_tx: &DB | {
+        let token = db_tx.try_fetch_token_info(call_data.asset)?;
+        let amount = return_data._0.to_scaled_rational(token.decimals);
+
+        return Ok(NormalizedLending {
+            protocol: Protocol::AaveV3,
+            trace_index: info.trace_idx,
+            action: LendingAction::Repay,
+            market: info.target_address,
+            from: info.msg_sender,
+            account: call_data.onBehalfOf,
+            token,
+            amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
 #[cfg(test)]
 mod tests {
     use alloy_primitives::{hex, Address, B256, U256};
@@ -141,7 +282,9 @@ mod tests {
                 .get_token_info(Address::from(hex!("2260fac5e5542a773aa44fbcfedf7c193bc2c599"))),
             liquidator:            Address::from(hex!("80d4230c0a68fc59cb264329d3a717fcaa472a13")),
             pool:                  Address::from(hex!("87870bca3f3fd6335c3f4ce8392d69350b4fa4e2")),
-            trace_index:           6,
+            // the call into the pool proxy, the delegate call into the
+            // implementation under it is left unclassified
+            trace_index:           5,
             msg_value:             U256::ZERO,
         });
 
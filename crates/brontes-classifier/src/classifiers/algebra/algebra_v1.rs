@@ -0,0 +1,244 @@
+//! Algebra pools (Camelot, QuickSwap V3, ...) are concentrated liquidity pools
+//! like Uniswap V3, but with a fee that changes with volatility. Their `Swap`
+//! event carries the price and tick instead of a fee, which is only emitted in
+//! a `Fee` event when it changes. The amounts are taken from the return data,
+//! which is laid out the same as Uniswap V3's.
+use alloy_primitives::{Address, I256};
+use brontes_macros::action_impl;
+use brontes_types::{
+    db::traits::LibmdbxReader,
+    normalized_actions::{NormalizedBurn, NormalizedCollect, NormalizedMint, NormalizedSwap},
+    structured_trace::CallInfo,
+    Protocol, ToScaledRational,
+};
+
+action_impl!(
+    Protocol::AlgebraV1,
+    crate::AlgebraPool::swapCall,
+    Swap,
+    [Swap],
+    call_data: true,
+    return_data: true,
+    |
+    info: CallInfo,
+    call_data: swapCall,
+    return_data: swapReturn,
+    db_tx: &DB| {
+        algebra_swap(&info, call_data.recipient, return_data.amount0, return_data.amount1, db_tx)
+    }
+);
+
+// Swapping a token that takes a fee on transfer, the pool takes the input from
+// `sender` before swapping what it received
+action_impl!(
+    Protocol::AlgebraV1,
+    crate::AlgebraPool::swapSupportingFeeOnInputTokensCall,
+    Swap,
+    [Swap],
+    call_data: true,
+    return_data: true,
+    |
+    info: CallInfo,
+    call_data: swapSupportingFeeOnInputTokensCall,
+    return_data: swapSupportingFeeOnInputTokensReturn,
+    db_tx: &DB| {
+        algebra_swap(&info, call_data.recipient, return_data.amount0, return_data.amount1, db_tx)
+    }
+);
+
+action_impl!(
+    Protocol::AlgebraV1,
+    crate::AlgebraPool::mintCall,
+    Mint,
+    [Mint],
+    return_data: true,
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: mintCall,
+    return_data: mintReturn,
+    db_tx: &DB| {
+        let details = db_tx.get_protocol_details_sorted_at(info.target_address, info.block)?;
+        let t0_info = db_tx.try_fetch_token_info(details.token0)?;
+        let t1_info = db_tx.try_fetch_token_info(details.token1)?;
+
+        let am0 = return_data.amount0.to_scaled_rational(t0_info.decimals);
+        let am1 = return_data.amount1.to_scaled_rational(t1_info.decimals);
+
+        Ok(NormalizedMint {
+            protocol: Protocol::AlgebraV1,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            recipient: call_data.recipient,
+            pool: info.target_address,
+            token: vec![t0_info, t1_info],
+            amount: vec![am0, am1],
+        })
+    }
+);
+
+action_impl!(
+    Protocol::AlgebraV1,
+    crate::AlgebraPool::burnCall,
+    Burn,
+    [Burn],
+    return_data: true,
+    |
+    info: CallInfo,
+    return_data: burnReturn,
+    db_tx: &DB| {
+        let details = db_tx.get_protocol_details_sorted_at(info.target_address, info.block)?;
+        let t0_info = db_tx.try_fetch_token_info(details.token0)?;
+        let t1_info = db_tx.try_fetch_token_info(details.token1)?;
+
+        let am0 = return_data.amount0.to_scaled_rational(t0_info.decimals);
+        let am1 = return_data.amount1.to_scaled_rational(t1_info.decimals);
+
+        Ok(NormalizedBurn {
+            protocol: Protocol::AlgebraV1,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            recipient: info.target_address,
+            pool: info.target_address,
+            token: vec![t0_info, t1_info],
+            amount: vec![am0, am1],
+        })
+    }
+);
+
+action_impl!(
+    Protocol::AlgebraV1,
+    crate::AlgebraPool::collectCall,
+    Collect,
+    [Collect],
+    call_data: true,
+    return_data: true,
+    |
+    info: CallInfo,
+    call_data: collectCall,
+    return_data: collectReturn,
+    db_tx: &DB| {
+        let details = db_tx.get_protocol_details_sorted_at(info.target_address, info.block)?;
+        let t0_info = db_tx.try_fetch_token_info(details.token0)?;
+        let t1_info = db_tx.try_fetch_token_info(details.token1)?;
+
+        let am0 = return_data.amount0.to_scaled_rational(t0_info.decimals);
+        let am1 = return_data.amount1.to_scaled_rational(t1_info.decimals);
+
+        Ok(NormalizedCollect {
+            protocol: Protocol::AlgebraV1,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            recipient: call_data.recipient,
+            pool: info.target_address,
+            token: vec![t0_info, t1_info],
+            amount: vec![am0, am1],
+        })
+    }
+);
+
+/// The deltas are from the pool's side, the token paid in is positive
+fn algebra_swap<DB: LibmdbxReader>(
+    info: &CallInfo,
+    recipient: Address,
+    token_0_delta: I256,
+    token_1_delta: I256,
+    db_tx: &DB,
+) -> eyre::Result<NormalizedSwap> {
+    let details = db_tx.get_protocol_details_sorted_at(info.target_address, info.block)?;
+    let t0_info = db_tx.try_fetch_token_info(details.token0)?;
+    let t1_info = db_tx.try_fetch_token_info(details.token1)?;
+
+    let (amount_in, amount_out, token_in, token_out) = if token_0_delta.is_negative() {
+        (
+            token_1_delta.to_scaled_rational(t1_info.decimals),
+            token_0_delta.abs().to_scaled_rational(t0_info.decimals),
+            t1_info,
+            t0_info,
+        )
+    } else {
+        (
+            token_0_delta.to_scaled_rational(t0_info.decimals),
+            token_1_delta.abs().to_scaled_rational(t1_info.decimals),
+            t0_info,
+            t1_info,
+        )
+    };
+
+    Ok(NormalizedSwap {
+        protocol: Protocol::AlgebraV1,
+        trace_index: info.trace_idx,
+        from: info.from_address,
+        recipient,
+        pool: info.target_address,
+        token_in,
+        token_out,
+        amount_in,
+        amount_out,
+        msg_value: info.msg_value,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{Bytes, U256};
+    use alloy_sol_types::SolCall;
+    use brontes_classifier::test_utils::ClassifierTestUtils;
+    use brontes_types::{db::token_info::TokenInfoWithAddress, normalized_actions::Action};
+
+    use super::*;
+    use crate::AlgebraPool::swapCall;
+
+    #[brontes_macros::test]
+    async fn test_algebra_swap() {
+        let classifier_utils = ClassifierTestUtils::new().await;
+        let pool = Address::repeat_byte(0xa1);
+        let router = Address::repeat_byte(0x01);
+        let (usdc, weth) = (TokenInfoWithAddress::usdc(), TokenInfoWithAddress::weth());
+        classifier_utils.ensure_token(usdc.clone());
+        classifier_utils.ensure_token(weth.clone());
+        classifier_utils.ensure_protocol(
+            Protocol::AlgebraV1,
+            pool,
+            usdc.address,
+            Some(weth.address),
+            None,
+            None,
+            None,
+            None,
+        );
+
+        let weth_in = I256::from_raw(U256::from(1_000_000_000_000_000_000u128));
+        let usdc_out = I256::from_raw(U256::from(3_000_000_000u64));
+        let call_data = swapCall {
+            recipient:      router,
+            zeroToOne:      false,
+            amountRequired: weth_in,
+            limitSqrtPrice: Default::default(),
+            data:           Bytes::new(),
+        }
+        .abi_encode();
+        // from the pool's side, it paid out usdc and received weth
+        let return_data = swapCall::abi_encode_returns(&(-usdc_out, weth_in));
+
+        let swap = classifier_utils
+            .dispatch_call(pool, router, call_data.into(), return_data.into(), &[], 0)
+            .expect("swap wasn't classified");
+
+        assert_eq!(
+            swap,
+            Action::Swap(NormalizedSwap {
+                protocol: Protocol::AlgebraV1,
+                trace_index: 0,
+                from: router,
+                recipient: router,
+                pool,
+                token_in: weth,
+                token_out: usdc,
+                amount_in: weth_in.to_scaled_rational(18),
+                amount_out: usdc_out.to_scaled_rational(6),
+                msg_value: U256::ZERO,
+            })
+        );
+    }
+}
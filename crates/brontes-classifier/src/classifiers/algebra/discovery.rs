@@ -0,0 +1,50 @@
+use alloy_primitives::{Address, Log};
+use alloy_sol_types::SolEvent;
+use brontes_pricing::Protocol;
+use brontes_types::normalized_actions::pool::NormalizedNewPool;
+
+use crate::AlgebraFactory::Pool;
+
+/// Algebra is deployed by many forks, each with its own factory, so pools are
+/// discovered through the `Pool` event their factory emits. The factory
+/// doesn't create the pool itself, it calls into a pool deployer, so the
+/// logs are those of the frame that called the deployer.
+pub fn discover_algebra_pool(
+    deployed_address: Address,
+    trace_index: u64,
+    factory_logs: &[Log],
+) -> Option<NormalizedNewPool> {
+    factory_logs.iter().find_map(|log| {
+        let created = Pool::decode_log_data(&log.data, true).ok()?;
+        (created.pool == deployed_address).then(|| NormalizedNewPool {
+            trace_index,
+            protocol: Protocol::AlgebraV1,
+            pool_address: deployed_address,
+            tokens: vec![created.token0, created.token1],
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_discover_algebra_pool() {
+        let (token0, token1) = (Address::repeat_byte(0x10), Address::repeat_byte(0x11));
+        let pool = Address::repeat_byte(0x20);
+        let log = Log {
+            address: Address::repeat_byte(0xfa),
+            data:    Pool { token0, token1, pool }.encode_log_data(),
+        };
+
+        let created = discover_algebra_pool(pool, 2, &[log.clone()]).unwrap();
+        assert_eq!(created.protocol, Protocol::AlgebraV1);
+        assert_eq!(created.pool_address, pool);
+        assert_eq!(created.tokens, vec![token0, token1]);
+        assert_eq!(created.trace_index, 2);
+
+        // created by the same factory call, but not this pool
+        assert!(discover_algebra_pool(Address::repeat_byte(0x21), 2, &[log]).is_none());
+    }
+}
@@ -0,0 +1,5 @@
+mod algebra_v1;
+mod discovery;
+
+pub use algebra_v1::*;
+pub use discovery::*;
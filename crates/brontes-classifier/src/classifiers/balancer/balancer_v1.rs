@@ -81,7 +81,9 @@ action_impl!(
     call_data: bindCall,
     db_tx: &DB| {
 
-        let pool = db_tx.get_protocol_details(info.target_address).unwrap_or_default();
+        let pool = db_tx
+            .get_protocol_details_at(info.target_address, info.block)
+            .unwrap_or_default();
 
         let mut tokens = pool.get_tokens();
         tokens.push(call_data.token);
@@ -5,8 +5,8 @@ use brontes_pricing::Protocol;
 use brontes_types::{
     db::token_info::TokenInfoWithAddress,
     normalized_actions::{
-        NormalizedBurn, NormalizedFlashLoan, NormalizedMint, NormalizedNewPool,
-        NormalizedPoolConfigUpdate, NormalizedSwap,
+        GovernanceAction, NormalizedBurn, NormalizedFlashLoan, NormalizedGovernance,
+        NormalizedMint, NormalizedNewPool, NormalizedPoolConfigUpdate, NormalizedSwap,
     },
     structured_trace::CallInfo,
     ToScaledRational,
@@ -102,7 +102,8 @@ action_impl!(
     FlashLoan,
     [..FlashLoan*],
     call_data: true,
-    |info: CallInfo, call_data: flashLoanCall, db: &DB| {
+    logs: true,
+    |info: CallInfo, call_data: flashLoanCall, logs: BalancerV2FlashLoanCallLogs, db: &DB| {
         let (assets, amounts): (Vec<TokenInfoWithAddress>, Vec<Rational>) = call_data.tokens
             .iter()
             .zip(call_data.amounts.iter())
@@ -115,6 +116,19 @@ action_impl!(
             .map_err(|e: Error| <Error as Into<eyre::ErrReport>>::into(e))
             .map(|v| v.into_iter().unzip())?;
 
+        // the vault emits a flash loan event per token with the fee that is owed
+        let flash_loan_logs = logs.flash_loan_field?;
+        let fees_paid = assets
+            .iter()
+            .map(|asset| {
+                flash_loan_logs
+                    .iter()
+                    .find(|log| log.token == asset.address)
+                    .map(|log| log.feeAmount.to_scaled_rational(asset.decimals))
+                    .unwrap_or_default()
+            })
+            .collect();
+
         Ok(NormalizedFlashLoan {
             protocol: Protocol::BalancerV2,
             trace_index: info.trace_idx,
@@ -126,7 +140,7 @@ action_impl!(
             aave_mode: None,
             child_actions: vec![],
             repayments: vec![],
-            fees_paid: vec![],
+            fees_paid,
             msg_value: info.msg_value
         })
     }
@@ -215,6 +229,28 @@ action_impl!(
     }
 );
 
+// Pauses swaps, joins and flash loans of every pool in the vault, exits stay
+// open so that funds can be withdrawn
+action_impl!(
+    Protocol::BalancerV2,
+    crate::BalancerV2Vault::setPausedCall,
+    Governance,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: setPausedCall, _| {
+        let action =
+            if call_data.paused { GovernanceAction::Pause } else { GovernanceAction::Unpause };
+
+        Ok(NormalizedGovernance {
+            protocol: Protocol::BalancerV2,
+            trace_index: info.trace_idx,
+            action,
+            target: info.target_address,
+            from: info.msg_sender,
+        })
+    }
+);
+
 // ~ https://docs.balancer.fi/reference/contracts/pool-interfacing.html#poolids
 // The poolId is a unique identifier, the first portion of which is the pool's
 // contract address. For example, the pool with the id
@@ -234,6 +270,7 @@ mod tests {
         constants::WETH_ADDRESS, db::token_info::TokenInfo, normalized_actions::Action,
         Protocol::BalancerV2, TreeSearchBuilder,
     };
+    use malachite::num::basic::traits::Zero;
 
     use super::*;
 
@@ -319,7 +356,7 @@ mod tests {
             aave_mode:         None,
             child_actions:     vec![],
             repayments:        vec![],
-            fees_paid:         vec![],
+            fees_paid:         vec![Rational::ZERO],
             msg_value:         U256::ZERO,
         });
 
@@ -32,12 +32,56 @@ discovery_impl!(
     }
 );
 
+// Balancer V2 pools hold no funds, they are registered with the vault along
+// with their tokens by the pool's constructor. The tokens are passed to the
+// factory sorted, as the vault requires
+discovery_impl!(
+    BalancerV2WeightedPoolDiscovery,
+    crate::BalancerV2WeightedPoolFactory::createCall,
+    0x897888115Ada5773E02aA29F775430BFB5F34c51,
+    |deployed_address: Address, trace_index: u64, call_data: createCall, _| async move {
+        vec![NormalizedNewPool {
+            trace_index,
+            protocol: Protocol::BalancerV2,
+            pool_address: deployed_address,
+            tokens: call_data.tokens,
+        }]
+    }
+);
+
+// A composable stable pool also registers its own bpt as one of its tokens,
+// which isn't passed to the factory
+discovery_impl!(
+    BalancerV2ComposableStablePoolDiscovery,
+    crate::BalancerV2ComposableStablePoolFactory::createCall,
+    0xDB8d758BCb971e482B2C45f7F8a7740283A1bd3A,
+    |deployed_address: Address, trace_index: u64, call_data: createCall, _| async move {
+        let mut tokens = call_data.tokens;
+        tokens.push(deployed_address);
+        tokens.sort();
+
+        vec![NormalizedNewPool {
+            trace_index,
+            protocol: Protocol::BalancerV2,
+            pool_address: deployed_address,
+            tokens,
+        }]
+    }
+);
+
 #[cfg(test)]
 mod tests {
-    use alloy_primitives::{hex, Address, B256};
+    use alloy_primitives::{hex, Address, B256, U256};
+    use alloy_sol_types::SolCall;
     use brontes_types::{normalized_actions::pool::NormalizedNewPool, Protocol};
 
-    use crate::test_utils::ClassifierTestUtils;
+    use crate::{
+        test_utils::ClassifierTestUtils, BalancerV2ComposableStablePoolFactory,
+        BalancerV2WeightedPoolFactory,
+    };
+
+    const WSTETH: Address = Address::new(hex!("7f39C581F595B53c5cb19bD0b3f8dA6c935E2Ca0"));
+    const WETH: Address = Address::new(hex!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2"));
 
     #[brontes_macros::test]
     async fn test_balancer_v1_discovery() {
@@ -70,4 +114,74 @@ mod tests {
             .await
             .unwrap();
     }
+
+    #[brontes_macros::test]
+    async fn test_balancer_v2_weighted_discovery() {
+        let utils = ClassifierTestUtils::new().await;
+        let factory = Address::new(hex!("897888115Ada5773E02aA29F775430BFB5F34c51"));
+        let pool = Address::repeat_byte(0xb2);
+
+        let half = U256::from(500_000_000_000_000_000u64);
+        let call_data = BalancerV2WeightedPoolFactory::createCall {
+            name:              "50wstETH-50WETH".to_string(),
+            symbol:            "50wstETH-50WETH".to_string(),
+            tokens:            vec![WSTETH, WETH],
+            normalizedWeights: vec![half, half],
+            rateProviders:     vec![Address::ZERO, Address::ZERO],
+            swapFeePercentage: U256::from(1_000_000_000_000_000u64),
+            owner:             Address::ZERO,
+            salt:              B256::ZERO,
+        }
+        .abi_encode();
+
+        let pools = utils
+            .dispatch_discovery(factory, call_data.into(), pool)
+            .await;
+
+        assert_eq!(
+            pools,
+            vec![NormalizedNewPool {
+                trace_index:  0,
+                protocol:     Protocol::BalancerV2,
+                pool_address: pool,
+                tokens:       vec![WSTETH, WETH],
+            }]
+        );
+    }
+
+    #[brontes_macros::test]
+    async fn test_balancer_v2_composable_stable_discovery() {
+        let utils = ClassifierTestUtils::new().await;
+        let factory = Address::new(hex!("DB8d758BCb971e482B2C45f7F8a7740283A1bd3A"));
+        // sorts between the two tokens, so the bpt lands in the middle
+        let pool = Address::repeat_byte(0xb2);
+
+        let call_data = BalancerV2ComposableStablePoolFactory::createCall {
+            name: "wstETH-WETH-BPT".to_string(),
+            symbol: "wstETH-WETH-BPT".to_string(),
+            tokens: vec![WSTETH, WETH],
+            amplificationParameter: U256::from(50),
+            rateProviders: vec![Address::ZERO, Address::ZERO],
+            tokenRateCacheDurations: vec![U256::ZERO, U256::ZERO],
+            exemptFromYieldProtocolFeeFlag: false,
+            swapFeePercentage: U256::from(100_000_000_000_000u64),
+            owner: Address::ZERO,
+            salt: B256::ZERO,
+        }
+        .abi_encode();
+
+        let pools = utils
+            .dispatch_discovery(factory, call_data.into(), pool)
+            .await;
+
+        assert_eq!(
+            pools,
+            vec![NormalizedNewPool {
+                trace_index:  0,
+                protocol:     Protocol::BalancerV2,
+                pool_address: pool,
+                tokens:       vec![WSTETH, pool, WETH],
+            }]
+        );
+    }
 }
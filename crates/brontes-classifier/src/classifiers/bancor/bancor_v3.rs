@@ -0,0 +1,101 @@
+//! Bancor V3 routes every trade through a single `BancorNetwork` contract that
+//! holds the liquidity for all of its tokens. Unlike the pair based protocols,
+//! the pool address doesn't map to a token0 / token1 pair so the tokens are
+//! always taken from the trade itself rather than from the protocol details.
+use alloy_primitives::Address;
+use brontes_database::libmdbx::LibmdbxReader;
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{
+    constants::ETH_ADDRESS, db::token_info::TokenInfoWithAddress,
+    normalized_actions::NormalizedSwap, structured_trace::CallInfo, ToScaledRational,
+};
+
+action_impl!(
+    Protocol::BancorV3,
+    crate::BancorNetwork::tradeBySourceAmountCall,
+    Swap,
+    [..TokensTraded],
+    call_data: true,
+    logs: true,
+    |
+    info: CallInfo,
+    call_data: tradeBySourceAmountCall,
+    logs: BancorV3TradeBySourceAmountCallLogs,
+    db_tx: &DB| {
+        let logs = logs.tokens_traded_field?;
+
+        let token_in = load_token(logs.sourceToken, db_tx)?;
+        let token_out = load_token(logs.targetToken, db_tx)?;
+        let amount_in = logs.sourceAmount.to_scaled_rational(token_in.decimals);
+        let amount_out = logs.targetAmount.to_scaled_rational(token_out.decimals);
+
+        Ok(NormalizedSwap {
+            protocol: Protocol::BancorV3,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            recipient: recipient(call_data.beneficiary, info.from_address),
+            pool: info.target_address,
+            token_in,
+            token_out,
+            amount_in,
+            amount_out,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::BancorV3,
+    crate::BancorNetwork::tradeByTargetAmountCall,
+    Swap,
+    [..TokensTraded],
+    call_data: true,
+    logs: true,
+    |
+    info: CallInfo,
+    call_data: tradeByTargetAmountCall,
+    logs: BancorV3TradeByTargetAmountCallLogs,
+    db_tx: &DB| {
+        let logs = logs.tokens_traded_field?;
+
+        let token_in = load_token(logs.sourceToken, db_tx)?;
+        let token_out = load_token(logs.targetToken, db_tx)?;
+        let amount_in = logs.sourceAmount.to_scaled_rational(token_in.decimals);
+        let amount_out = logs.targetAmount.to_scaled_rational(token_out.decimals);
+
+        Ok(NormalizedSwap {
+            protocol: Protocol::BancorV3,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            recipient: recipient(call_data.beneficiary, info.from_address),
+            pool: info.target_address,
+            token_in,
+            token_out,
+            amount_in,
+            amount_out,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+/// Bancor uses the `0xeee..` sentinel for native eth. It's swapped for
+/// [`TokenInfoWithAddress::native_eth`], which keeps the `ETH` symbol but sits
+/// at the weth address, as that's the address dex pricing has eth quotes for.
+fn load_token<DB: LibmdbxReader>(token: Address, db: &DB) -> eyre::Result<TokenInfoWithAddress> {
+    if token == ETH_ADDRESS {
+        return Ok(TokenInfoWithAddress::native_eth())
+    }
+
+    db.try_fetch_token_info(token)
+}
+
+/// When no beneficiary is given the network sends the target tokens back to
+/// the caller.
+fn recipient(beneficiary: Address, caller: Address) -> Address {
+    if beneficiary == Address::ZERO {
+        caller
+    } else {
+        beneficiary
+    }
+}
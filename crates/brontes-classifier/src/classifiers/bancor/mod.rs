@@ -0,0 +1,3 @@
+mod bancor_v3;
+
+pub use bancor_v3::*;
@@ -0,0 +1,52 @@
+use alloy_primitives::U256;
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{normalized_actions::NormalizedBatch, structured_trace::CallInfo};
+
+use super::{scale_amounts, BebopFill};
+
+/// Fill percentages are in basis points
+const FULL_FILL_PERCENT: u16 = 10_000;
+
+action_impl!(
+    Protocol::Bebop,
+    crate::BebopJamSettlement::settleCall,
+    Batch,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: settleCall,
+    db_tx: &DB| {
+        let order = call_data.order;
+        let fill_percent = U256::from(call_data.solverData.curFillPercent);
+        let full = U256::from(FULL_FILL_PERCENT);
+
+        let sell_amounts = scale_amounts(&order.sellAmounts, fill_percent, full);
+        let buy_amounts = scale_amounts(&order.buyAmounts, fill_percent, full);
+
+        let user_swaps = BebopFill {
+            trace_index: info.trace_idx,
+            taker: order.taker,
+            receiver: order.receiver,
+            settlement: info.target_address,
+            taker_tokens: &order.sellTokens,
+            taker_amounts: &sell_amounts,
+            maker_tokens: &order.buyTokens,
+            maker_amounts: &buy_amounts,
+        }
+        .into_swaps(db_tx)?;
+
+        // jam orders are settled by the solver, which sources the liquidity
+        // through its interactions
+        Ok(NormalizedBatch {
+            protocol: Protocol::Bebop,
+            trace_index: info.trace_idx,
+            solver: info.msg_sender,
+            settlement_contract: info.target_address,
+            user_swaps,
+            solver_swaps: None,
+            msg_value: info.msg_value,
+        })
+    }
+);
@@ -0,0 +1,151 @@
+use alloy_primitives::U256;
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{normalized_actions::NormalizedBatch, structured_trace::CallInfo};
+
+use super::{scale_amounts, BebopFill};
+
+action_impl!(
+    Protocol::Bebop,
+    crate::BebopSettlement::swapSingleCall,
+    Batch,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: swapSingleCall,
+    db_tx: &DB| {
+        let order = call_data.order;
+        let filled = call_data.filledTakerAmount;
+
+        let taker_amounts = scale_amounts(&[order.taker_amount], filled, order.taker_amount);
+        let maker_amounts = scale_amounts(&[order.maker_amount], filled, order.taker_amount);
+
+        let user_swaps = BebopFill {
+            trace_index: info.trace_idx,
+            taker: order.taker_address,
+            receiver: order.receiver,
+            settlement: info.target_address,
+            taker_tokens: &[order.taker_token],
+            taker_amounts: &taker_amounts,
+            maker_tokens: &[order.maker_token],
+            maker_amounts: &maker_amounts,
+        }
+        .into_swaps(db_tx)?;
+
+        Ok(NormalizedBatch {
+            protocol: Protocol::Bebop,
+            trace_index: info.trace_idx,
+            solver: order.maker_address,
+            settlement_contract: info.target_address,
+            user_swaps,
+            solver_swaps: None,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::Bebop,
+    crate::BebopSettlement::swapMultiCall,
+    Batch,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: swapMultiCall,
+    db_tx: &DB| {
+        let order = call_data.order;
+
+        // partial fills are only possible when the taker sells a single token
+        let total = match order.taker_amounts.as_slice() {
+            [amount] => *amount,
+            _ => U256::ZERO,
+        };
+        let filled = call_data.filledTakerAmount;
+
+        let taker_amounts = scale_amounts(&order.taker_amounts, filled, total);
+        let maker_amounts = scale_amounts(&order.maker_amounts, filled, total);
+
+        let user_swaps = BebopFill {
+            trace_index: info.trace_idx,
+            taker: order.taker_address,
+            receiver: order.receiver,
+            settlement: info.target_address,
+            taker_tokens: &order.taker_tokens,
+            taker_amounts: &taker_amounts,
+            maker_tokens: &order.maker_tokens,
+            maker_amounts: &maker_amounts,
+        }
+        .into_swaps(db_tx)?;
+
+        Ok(NormalizedBatch {
+            protocol: Protocol::Bebop,
+            trace_index: info.trace_idx,
+            solver: order.maker_address,
+            settlement_contract: info.target_address,
+            user_swaps,
+            solver_swaps: None,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::Bebop,
+    crate::BebopSettlement::swapAggregateCall,
+    Batch,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: swapAggregateCall,
+    db_tx: &DB| {
+        let order = call_data.order;
+
+        // an aggregate order is a set of fills from different makers. Like multi
+        // orders, it can only be partially filled if the taker sells a single
+        // token, in which case the filled amount is spread over all makers
+        let single_taker_token = order
+            .taker_tokens
+            .iter()
+            .flatten()
+            .all(|token| Some(token) == order.taker_tokens.first().and_then(|t| t.first()));
+        let total = if single_taker_token {
+            order.taker_amounts.iter().flatten().copied().sum::<U256>()
+        } else {
+            U256::ZERO
+        };
+        let filled = call_data.filledTakerAmount;
+
+        let mut user_swaps = Vec::new();
+        for (maker_idx, taker_tokens) in order.taker_tokens.iter().enumerate() {
+            let taker_amounts = scale_amounts(&order.taker_amounts[maker_idx], filled, total);
+            let maker_amounts = scale_amounts(&order.maker_amounts[maker_idx], filled, total);
+
+            user_swaps.extend(
+                BebopFill {
+                    trace_index: info.trace_idx,
+                    taker: order.taker_address,
+                    receiver: order.receiver,
+                    settlement: info.target_address,
+                    taker_tokens,
+                    taker_amounts: &taker_amounts,
+                    maker_tokens: &order.maker_tokens[maker_idx],
+                    maker_amounts: &maker_amounts,
+                }
+                .into_swaps(db_tx)?,
+            );
+        }
+
+        Ok(NormalizedBatch {
+            protocol: Protocol::Bebop,
+            trace_index: info.trace_idx,
+            solver: order.maker_addresses.first().copied().unwrap_or_default(),
+            settlement_contract: info.target_address,
+            user_swaps,
+            solver_swaps: None,
+            msg_value: info.msg_value,
+        })
+    }
+);
@@ -0,0 +1,107 @@
+//! Bebop settles RFQ orders, which can trade several tokens for several other
+//! tokens in a single fill, directly against the settlement contracts. Each
+//! fill is classified as a batch, with one user swap per token pair.
+mod bebop_jam;
+mod bebop_pmm;
+
+use alloy_primitives::{Address, U256};
+pub use bebop_jam::*;
+pub use bebop_pmm::*;
+use brontes_database::libmdbx::LibmdbxReader;
+use brontes_pricing::Protocol;
+use brontes_types::{
+    constants::ETH_ADDRESS, db::token_info::TokenInfoWithAddress,
+    normalized_actions::NormalizedSwap, ToScaledRational,
+};
+
+/// The parties and token legs of a single Bebop order
+pub(crate) struct BebopFill<'a> {
+    pub trace_index:   u64,
+    pub taker:         Address,
+    pub receiver:      Address,
+    pub settlement:    Address,
+    pub taker_tokens:  &'a [Address],
+    pub taker_amounts: &'a [U256],
+    pub maker_tokens:  &'a [Address],
+    pub maker_amounts: &'a [U256],
+}
+
+impl BebopFill<'_> {
+    /// Builds a swap for every taker token, maker token pair. As the order
+    /// doesn't say how the amounts of a multi token side are split between
+    /// the tokens of the other side, each amount is split evenly across its
+    /// counterparts. This keeps the totals traded per token exact.
+    pub(crate) fn into_swaps<DB: LibmdbxReader>(
+        self,
+        db_tx: &DB,
+    ) -> eyre::Result<Vec<NormalizedSwap>> {
+        let taker_legs = U256::from(self.maker_tokens.len());
+        let maker_legs = U256::from(self.taker_tokens.len());
+
+        let mut swaps = Vec::with_capacity(self.taker_tokens.len() * self.maker_tokens.len());
+        for (token_in, amount_in) in self.taker_tokens.iter().zip(self.taker_amounts) {
+            let token_in = bebop_token(*token_in, db_tx)?;
+            let amount_in = (*amount_in / taker_legs).to_scaled_rational(token_in.decimals);
+
+            for (token_out, amount_out) in self.maker_tokens.iter().zip(self.maker_amounts) {
+                let token_out = bebop_token(*token_out, db_tx)?;
+
+                swaps.push(NormalizedSwap {
+                    protocol: Protocol::Bebop,
+                    trace_index: self.trace_index,
+                    from: self.taker,
+                    recipient: self.receiver,
+                    pool: self.settlement,
+                    token_in: token_in.clone(),
+                    amount_in: amount_in.clone(),
+                    amount_out: (*amount_out / maker_legs).to_scaled_rational(token_out.decimals),
+                    token_out,
+                    msg_value: U256::ZERO,
+                });
+            }
+        }
+
+        Ok(swaps)
+    }
+}
+
+/// Bebop uses the `0xeeee...` address for native eth
+fn bebop_token<DB: LibmdbxReader>(
+    token: Address,
+    db_tx: &DB,
+) -> eyre::Result<TokenInfoWithAddress> {
+    if token == ETH_ADDRESS {
+        return Ok(TokenInfoWithAddress::native_eth())
+    }
+
+    db_tx.try_fetch_token_info(token)
+}
+
+/// Scales the amounts of an order down to the part that was filled
+pub(crate) fn scale_amounts(amounts: &[U256], filled: U256, total: U256) -> Vec<U256> {
+    if total.is_zero() || filled.is_zero() || filled >= total {
+        return amounts.to_vec()
+    }
+
+    amounts
+        .iter()
+        .map(|amount| *amount * filled / total)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scale_partial_fill() {
+        let amounts = [U256::from(100), U256::from(50)];
+
+        assert_eq!(
+            scale_amounts(&amounts, U256::from(1), U256::from(4)),
+            vec![U256::from(25), U256::from(12)]
+        );
+        // a zero fill amount means the order was filled in full
+        assert_eq!(scale_amounts(&amounts, U256::ZERO, U256::from(4)), amounts.to_vec());
+    }
+}
@@ -0,0 +1,154 @@
+//! Blend lends Blur Pool eth against a single nft, the loan is held as a lien
+//! that has no expiry. The lender calls the loan by starting an auction, in
+//! which anyone can refinance it at a rising rate. If no one has by the end of
+//! the auction the lender seizes the nft. The repayment amounts include the
+//! accrued interest and aren't stored, so the principal of the lien is kept.
+use alloy_primitives::U256;
+use brontes_database::libmdbx::LibmdbxReader;
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{
+    normalized_actions::{NftLoanAction, NormalizedNftLoan},
+    structured_trace::CallInfo,
+    ToScaledRational,
+};
+
+use super::BLUR_POOL_ADDRESS;
+use crate::BlurBlend::Lien;
+
+action_impl!(
+    Protocol::BlurBlend,
+    crate::BlurBlend::borrowCall,
+    NftLoan,
+    [..LoanOfferTaken],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: BlurBlendBorrowCallLogs, db_tx: &DB| {
+        let taken = log_data.loan_offer_taken_field?;
+        let token = db_tx.try_fetch_token_info(BLUR_POOL_ADDRESS)?;
+
+        Ok(NormalizedNftLoan {
+            protocol: Protocol::BlurBlend,
+            trace_index: info.trace_idx,
+            action: NftLoanAction::Borrow,
+            market: info.target_address,
+            from: info.msg_sender,
+            lien_id: taken.lienId,
+            lender: taken.lender,
+            borrower: taken.borrower,
+            collection: taken.collection,
+            token_id: taken.tokenId,
+            amount: taken.loanAmount.to_scaled_rational(token.decimals),
+            token,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::BlurBlend,
+    crate::BlurBlend::repayCall,
+    NftLoan,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: repayCall, db_tx: &DB| {
+        lien_action(&info, NftLoanAction::Repay, call_data.lien, call_data.lienId, db_tx)
+    }
+);
+
+action_impl!(
+    Protocol::BlurBlend,
+    crate::BlurBlend::startAuctionCall,
+    NftLoan,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: startAuctionCall, db_tx: &DB| {
+        lien_action(&info, NftLoanAction::StartAuction, call_data.lien, call_data.lienId, db_tx)
+    }
+);
+
+// seizing takes a batch of liens, only single seizures are classified
+action_impl!(
+    Protocol::BlurBlend,
+    crate::BlurBlend::seizeCall,
+    NftLoan,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: seizeCall, db_tx: &DB| {
+        let [pointer] = <[_; 1]>::try_from(call_data.lienPointers)
+            .map_err(|_| eyre::eyre!("blend seize of more than one lien"))?;
+        lien_action(&info, NftLoanAction::Seize, pointer.lien, pointer.lienId, db_tx)
+    }
+);
+
+action_impl!(
+    Protocol::BlurBlend,
+    crate::BlurBlend::refinanceCall,
+    NftLoan,
+    [..Refinance],
+    logs: true,
+    call_data: true,
+    include_delegated_logs: true,
+    |
+    info: CallInfo,
+    call_data: refinanceCall,
+    log_data: BlurBlendRefinanceCallLogs,
+    db_tx: &DB| {
+        let refinance = log_data.refinance_field?;
+        let mut loan =
+            lien_action(&info, NftLoanAction::Refinance, call_data.lien, call_data.lienId, db_tx)?;
+        loan.lender = refinance.newLender;
+        loan.amount = refinance.newAmount.to_scaled_rational(loan.token.decimals);
+
+        Ok(loan)
+    }
+);
+
+action_impl!(
+    Protocol::BlurBlend,
+    crate::BlurBlend::refinanceAuctionCall,
+    NftLoan,
+    [..Refinance],
+    logs: true,
+    call_data: true,
+    include_delegated_logs: true,
+    |
+    info: CallInfo,
+    call_data: refinanceAuctionCall,
+    log_data: BlurBlendRefinanceAuctionCallLogs,
+    db_tx: &DB| {
+        let refinance = log_data.refinance_field?;
+        let mut loan =
+            lien_action(&info, NftLoanAction::Refinance, call_data.lien, call_data.lienId, db_tx)?;
+        loan.lender = refinance.newLender;
+        loan.amount = refinance.newAmount.to_scaled_rational(loan.token.decimals);
+
+        Ok(loan)
+    }
+);
+
+fn lien_action<DB: LibmdbxReader>(
+    info: &CallInfo,
+    action: NftLoanAction,
+    lien: Lien,
+    lien_id: U256,
+    db_tx: &DB,
+) -> eyre::Result<NormalizedNftLoan> {
+    let token = db_tx.try_fetch_token_info(BLUR_POOL_ADDRESS)?;
+
+    Ok(NormalizedNftLoan {
+        protocol: Protocol::BlurBlend,
+        trace_index: info.trace_idx,
+        action,
+        market: info.target_address,
+        from: info.msg_sender,
+        lien_id,
+        lender: lien.lender,
+        borrower: lien.borrower,
+        collection: lien.collection,
+        token_id: lien.tokenId,
+        amount: lien.amount.to_scaled_rational(token.decimals),
+        token,
+        msg_value: info.msg_value,
+    })
+}
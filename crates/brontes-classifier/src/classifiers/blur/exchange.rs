@@ -0,0 +1,184 @@
+//! Blur's exchange matches a sell order against a buy order, one of them signed
+//! by the maker and the other made up by the taker to fill it. The first
+//! version emits both orders in `OrdersMatched`, a bulk execution delegatecalls
+//! `_execute` on itself for every pair so each pair is classified on its own.
+//!
+//! The second version only emits a packed summary of an erc721 execution, with
+//! the maker as the trader of the order. Asks are paid in eth and bids from the
+//! Blur Pool.
+use alloy_primitives::{Address, B256, U256};
+use brontes_database::libmdbx::LibmdbxReader;
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{
+    db::token_info::TokenInfoWithAddress,
+    normalized_actions::{NftOrderSide, NormalizedNftTrade},
+    structured_trace::CallInfo,
+    ToScaledRational,
+};
+
+use super::BLUR_POOL_ADDRESS;
+use crate::{
+    BlurExchange::OrdersMatched,
+    BlurExchangeV2::{Execution721MakerFeePacked, Execution721Packed, Execution721TakerFeePacked},
+};
+
+action_impl!(
+    Protocol::Blur,
+    crate::BlurExchange::executeCall,
+    NftTrade,
+    [..OrdersMatched],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: BlurExecuteCallLogs, db_tx: &DB| {
+        orders_matched(&info, log_data.orders_matched_field?, db_tx)
+    }
+);
+
+action_impl!(
+    Protocol::Blur,
+    crate::BlurExchange::_executeCall,
+    NftTrade,
+    [..OrdersMatched],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: Blur_executeCallLogs, db_tx: &DB| {
+        orders_matched(&info, log_data.orders_matched_field?, db_tx)
+    }
+);
+
+/// The maker is the trader of the order listed first, the matching policies
+/// execute at the price of the maker's order
+fn orders_matched<DB: LibmdbxReader>(
+    info: &CallInfo,
+    matched: OrdersMatched,
+    db_tx: &DB,
+) -> eyre::Result<NormalizedNftTrade> {
+    let (side, maker_order) = if matched.maker == matched.sell.trader {
+        (NftOrderSide::Ask, &matched.sell)
+    } else {
+        (NftOrderSide::Bid, &matched.buy)
+    };
+
+    let payment_token = if maker_order.paymentToken == Address::ZERO {
+        TokenInfoWithAddress::native_eth()
+    } else {
+        db_tx.try_fetch_token_info(maker_order.paymentToken)?
+    };
+    let price = maker_order.price.to_scaled_rational(payment_token.decimals);
+
+    Ok(NormalizedNftTrade {
+        protocol: Protocol::Blur,
+        trace_index: info.trace_idx,
+        marketplace: info.target_address,
+        side,
+        maker: matched.maker,
+        taker: matched.taker,
+        collection: matched.sell.collection,
+        token_id: matched.sell.tokenId,
+        amount: matched.sell.amount,
+        payment_token,
+        price,
+        msg_value: info.msg_value,
+    })
+}
+
+action_impl!(
+    Protocol::Blur,
+    crate::BlurExchangeV2::takeAskSingleCall,
+    NftTrade,
+    [..(Execution721Packed | Execution721TakerFeePacked | Execution721MakerFeePacked)],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: BlurTakeAskSingleCallLogs, db_tx: &DB| {
+        let packed = packed_fields(
+            log_data.execution721_packed_field,
+            log_data.execution721_taker_fee_packed_field,
+            log_data.execution721_maker_fee_packed_field,
+        )?;
+        packed_execution(&info, packed, db_tx)
+    }
+);
+
+action_impl!(
+    Protocol::Blur,
+    crate::BlurExchangeV2::takeAskSinglePoolCall,
+    NftTrade,
+    [..(Execution721Packed | Execution721TakerFeePacked | Execution721MakerFeePacked)],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: BlurTakeAskSinglePoolCallLogs, db_tx: &DB| {
+        let packed = packed_fields(
+            log_data.execution721_packed_field,
+            log_data.execution721_taker_fee_packed_field,
+            log_data.execution721_maker_fee_packed_field,
+        )?;
+        packed_execution(&info, packed, db_tx)
+    }
+);
+
+action_impl!(
+    Protocol::Blur,
+    crate::BlurExchangeV2::takeBidSingleCall,
+    NftTrade,
+    [..(Execution721Packed | Execution721TakerFeePacked | Execution721MakerFeePacked)],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: BlurTakeBidSingleCallLogs, db_tx: &DB| {
+        let packed = packed_fields(
+            log_data.execution721_packed_field,
+            log_data.execution721_taker_fee_packed_field,
+            log_data.execution721_maker_fee_packed_field,
+        )?;
+        packed_execution(&info, packed, db_tx)
+    }
+);
+
+/// The execution is emitted with or without the fee of either side, the
+/// packed fields are the same in all three
+fn packed_fields(
+    packed: eyre::Result<Execution721Packed>,
+    taker_fee: eyre::Result<Execution721TakerFeePacked>,
+    maker_fee: eyre::Result<Execution721MakerFeePacked>,
+) -> eyre::Result<(U256, U256)> {
+    packed
+        .map(|e| (e.tokenIdListingIndexTrader, e.collectionPriceSide))
+        .or_else(|_| taker_fee.map(|e| (e.tokenIdListingIndexTrader, e.collectionPriceSide)))
+        .or_else(|_| maker_fee.map(|e| (e.tokenIdListingIndexTrader, e.collectionPriceSide)))
+}
+
+/// `tokenIdListingIndexTrader` packs the token id above the listing index
+/// byte and the trader, `collectionPriceSide` packs the order type byte
+/// above an 11 byte price and the collection
+fn packed_execution<DB: LibmdbxReader>(
+    info: &CallInfo,
+    (token_id_listing_index_trader, collection_price_side): (U256, U256),
+    db_tx: &DB,
+) -> eyre::Result<NormalizedNftTrade> {
+    let maker = Address::from_word(B256::from(token_id_listing_index_trader));
+    let token_id = token_id_listing_index_trader >> 168;
+
+    let collection = Address::from_word(B256::from(collection_price_side));
+    let price = (collection_price_side >> 160) & ((U256::from(1) << 88) - U256::from(1));
+
+    let (side, payment_token) = if collection_price_side >> 248 == U256::ZERO {
+        (NftOrderSide::Ask, TokenInfoWithAddress::native_eth())
+    } else {
+        (NftOrderSide::Bid, db_tx.try_fetch_token_info(BLUR_POOL_ADDRESS)?)
+    };
+
+    Ok(NormalizedNftTrade {
+        protocol: Protocol::Blur,
+        trace_index: info.trace_idx,
+        marketplace: info.target_address,
+        side,
+        maker,
+        taker: info.msg_sender,
+        collection,
+        token_id,
+        amount: U256::from(1),
+        price: price.to_scaled_rational(payment_token.decimals),
+        payment_token,
+        msg_value: info.msg_value,
+    })
+}
@@ -0,0 +1,13 @@
+use alloy_primitives::{hex, Address};
+
+// the exchange's _execute isn't camel case
+#[allow(non_camel_case_types)]
+pub(crate) mod exchange;
+pub use exchange::*;
+
+pub(crate) mod blend;
+pub use blend::*;
+
+/// Blur's wrapped eth, bids and loans are paid from balances deposited in it
+pub const BLUR_POOL_ADDRESS: Address =
+    Address::new(hex!("0000000000A39bb272e79075ade125fd351887Ac"));
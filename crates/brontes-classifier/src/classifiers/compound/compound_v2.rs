@@ -1,7 +1,8 @@
 use brontes_macros::action_impl;
 use brontes_pricing::Protocol;
 use brontes_types::{
-    normalized_actions::NormalizedLiquidation, structured_trace::CallInfo, utils::ToScaledRational,
+    db::token_info::TokenInfoWithAddress, normalized_actions::NormalizedLiquidation,
+    structured_trace::CallInfo, utils::ToScaledRational,
 };
 
 action_impl!(
@@ -17,10 +18,16 @@ action_impl!(
     log_data: CompoundV2LiquidateBorrowCallLogs,
     db_tx: &DB | {
         let logs = log_data.liquidate_borrow_field?;
-        let debt_asset = info.target_address;
-        let debt_info = db_tx.try_fetch_token_info(debt_asset)?;
+        // cEther is repaid with the call value. For erc20 markets the repaid underlying
+        // is set from the transfer into the ctoken during multi frame classification
+        let (debt_info, debt_covered) = if info.msg_value.is_zero() {
+            let debt_info = db_tx.try_fetch_token_info(info.target_address)?;
+            let debt_covered = logs.repayAmount.to_scaled_rational(debt_info.decimals);
+            (debt_info, debt_covered)
+        } else {
+            (TokenInfoWithAddress::native_eth(), info.msg_value.to_scaled_rational(18))
+        };
         let collateral = db_tx.try_fetch_token_info(call_data.cTokenCollateral)?;
-        let debt_covered = logs.repayAmount.to_scaled_rational(debt_info.decimals);
         let collateral_liquidated = logs.seizeTokens.to_scaled_rational(collateral.decimals);
         return Ok(NormalizedLiquidation {
             protocol: Protocol::CompoundV2,
@@ -88,10 +95,9 @@ mod tests {
         let eq_action = Action::Liquidation(NormalizedLiquidation {
             protocol:              Protocol::CompoundV2,
             liquidated_collateral: Rational::from_signeds(6140057900131i64, 100000000),
-            covered_debt:          Rational::from_signeds(48779241727i64, 100000000),
+            covered_debt:          Rational::from_signeds(48779241727i64, 1000000),
             debtor:                Address::from(hex!("De74395831F3Ba9EdC7cBEE1fcB441cf24c0AF4d")),
-            debt_asset:            classifier_utils
-                .get_token_info(Address::from(hex!("39aa39c021dfbae8fac545936693ac917d5e7563"))),
+            debt_asset:            TokenInfoWithAddress::usdc(),
             collateral_asset:      classifier_utils
                 .get_token_info(Address::from(hex!("70e36f6BF80a52b3B46b3aF8e106CC0ed743E8e4"))),
             liquidator:            Address::from(hex!("D911560979B78821D7b045C79E36E9CbfC2F6C6F")),
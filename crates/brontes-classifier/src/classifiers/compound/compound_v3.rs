@@ -0,0 +1,340 @@
+use brontes_macros::action_impl;
+use brontes_types::{
+    normalized_actions::{
+        GovernanceAction, LendingAction, NormalizedGovernance, NormalizedLending,
+        NormalizedLiquidation, NormalizedSwap,
+    },
+    structured_trace::CallInfo,
+    utils::ToScaledRational,
+    Protocol,
+};
+
+action_impl!(
+    Protocol::CompoundV3,
+    crate::CompoundV3Comet::supplyCall,
+    Lending,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: supplyCall,
+    db_tx: &DB | {
+        let token = db_tx.try_fetch_token_info(call_data.asset)?;
+        let amount = call_data.amount.to_scaled_rational(token.decimals);
+
+        return Ok(NormalizedLending {
+            protocol: Protocol::CompoundV3,
+            trace_index: info.trace_idx,
+            action: LendingAction::Deposit,
+            market: info.target_address,
+            from: info.msg_sender,
+            account: info.msg_sender,
+            token,
+            amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::CompoundV3,
+    crate::CompoundV3Comet::withdrawCall,
+    Lending,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: withdrawCall,
+    db_tx: &DB | {
+        let token = db_tx.try_fetch_token_info(call_data.asset)?;
+        let amount = call_data.amount.to_scaled_rational(token.decimals);
+
+        return Ok(NormalizedLending {
+            protocol: Protocol::CompoundV3,
+            trace_index: info.trace_idx,
+            action: LendingAction::Withdraw,
+            market: info.target_address,
+            from: info.msg_sender,
+            account: info.msg_sender,
+            token,
+            amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+// Comet liquidates in two steps. `absorb` moves the underwater position onto
+// the protocol, paying off the debt from its reserves, and the seized
+// collateral is then sold at a discount through `buyCollateral`. Only the first
+// absorbed account is classified, the collateral reported is the largest one
+// it held by usd value
+action_impl!(
+    Protocol::CompoundV3,
+    crate::CompoundV3Comet::absorbCall,
+    Liquidation,
+    [..AbsorbCollateral*, ..AbsorbDebt],
+    logs: true,
+    |
+    info: CallInfo,
+    log_data: CompoundV3AbsorbCallLogs,
+    db_tx: &DB | {
+        let debt_log = log_data.absorb_debt_field?;
+        let collateral_log = log_data
+            .absorb_collateral_field?
+            .into_iter()
+            .filter(|log| log.borrower == debt_log.borrower)
+            .max_by_key(|log| log.usdValue)
+            .ok_or_else(|| eyre::eyre!("absorb without collateral"))?;
+
+        let details = db_tx.get_protocol_details_at(info.target_address, info.block)?;
+        let debt_asset = db_tx.try_fetch_token_info(details.token0)?;
+        let collateral_asset = db_tx.try_fetch_token_info(collateral_log.asset)?;
+
+        let covered_debt = debt_log.basePaidOut.to_scaled_rational(debt_asset.decimals);
+        let liquidated_collateral =
+            collateral_log.collateralAbsorbed.to_scaled_rational(collateral_asset.decimals);
+
+        return Ok(NormalizedLiquidation {
+            protocol: Protocol::CompoundV3,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            liquidator: debt_log.absorber,
+            debtor: debt_log.borrower,
+            collateral_asset,
+            debt_asset,
+            covered_debt,
+            liquidated_collateral,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+// The discounted collateral sale that follows an absorb. Classified as a swap
+// of the base asset for the collateral so it shows up as the liquidation swap
+// when it lands in the same transaction as the absorb
+action_impl!(
+    Protocol::CompoundV3,
+    crate::CompoundV3Comet::buyCollateralCall,
+    Swap,
+    [..BuyCollateral],
+    call_data: true,
+    logs: true,
+    |
+    info: CallInfo,
+    call_data: buyCollateralCall,
+    log_data: CompoundV3BuyCollateralCallLogs,
+    db_tx: &DB | {
+        let logs = log_data.buy_collateral_field?;
+
+        let details = db_tx.get_protocol_details_at(info.target_address, info.block)?;
+        let token_in = db_tx.try_fetch_token_info(details.token0)?;
+        let token_out = db_tx.try_fetch_token_info(logs.asset)?;
+
+        let amount_in = logs.baseAmount.to_scaled_rational(token_in.decimals);
+        let amount_out = logs.collateralAmount.to_scaled_rational(token_out.decimals);
+
+        Ok(NormalizedSwap {
+            protocol: Protocol::CompoundV3,
+            trace_index: info.trace_idx,
+            from: info.msg_sender,
+            recipient: call_data.recipient,
+            pool: info.target_address,
+            token_in,
+            token_out,
+            amount_in,
+            amount_out,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+// The pause guardian sets every pause flag of the market at once, the market is
+// unpaused once none of them are set
+action_impl!(
+    Protocol::CompoundV3,
+    crate::CompoundV3Comet::pauseCall,
+    Governance,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: pauseCall, _| {
+        let paused = call_data.supplyPaused
+            || call_data.transferPaused
+            || call_data.withdrawPaused
+            || call_data.absorbPaused
+            || call_data.buyPaused;
+
+        Ok(NormalizedGovernance {
+            protocol: Protocol::CompoundV3,
+            trace_index: info.trace_idx,
+            action: if paused { GovernanceAction::Pause } else { GovernanceAction::Unpause },
+            target: info.target_address,
+            from: info.msg_sender,
+        })
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{Address, Bytes, Log, U256};
+    use alloy_sol_types::{SolCall, SolEvent};
+    use brontes_classifier::test_utils::ClassifierTestUtils;
+    use brontes_types::{db::token_info::TokenInfoWithAddress, normalized_actions::Action};
+
+    use super::*;
+    use crate::CompoundV3Comet::{
+        absorbCall, buyCollateralCall, supplyCall, AbsorbCollateral, AbsorbDebt, BuyCollateral,
+    };
+
+    const MARKET: Address = Address::repeat_byte(0xc3);
+    const ACCOUNT: Address = Address::repeat_byte(0x01);
+    const BORROWER: Address = Address::repeat_byte(0x02);
+
+    /// a usdc market with weth and usdt collateral
+    fn setup(classifier_utils: &ClassifierTestUtils) {
+        classifier_utils.ensure_token(TokenInfoWithAddress::usdc());
+        classifier_utils.ensure_token(TokenInfoWithAddress::weth());
+        classifier_utils.ensure_token(TokenInfoWithAddress::usdt());
+        classifier_utils.ensure_protocol(
+            Protocol::CompoundV3,
+            MARKET,
+            TokenInfoWithAddress::usdc().address,
+            Some(TokenInfoWithAddress::weth().address),
+            None,
+            None,
+            None,
+            None,
+        );
+    }
+
+    #[brontes_macros::test]
+    async fn test_compound_v3_supply() {
+        let classifier_utils = ClassifierTestUtils::new().await;
+        setup(&classifier_utils);
+
+        let amount = U256::from(5_000_000_000_000_000_000u128);
+        let call_data =
+            supplyCall { asset: TokenInfoWithAddress::weth().address, amount }.abi_encode();
+
+        let supply = classifier_utils
+            .dispatch_call(MARKET, ACCOUNT, call_data.into(), Bytes::new(), &[], 0)
+            .expect("supply wasn't classified");
+
+        assert_eq!(
+            supply,
+            Action::Lending(NormalizedLending {
+                protocol:    Protocol::CompoundV3,
+                trace_index: 0,
+                action:      LendingAction::Deposit,
+                market:      MARKET,
+                from:        ACCOUNT,
+                account:     ACCOUNT,
+                token:       TokenInfoWithAddress::weth(),
+                amount:      amount.to_scaled_rational(18),
+                msg_value:   U256::ZERO,
+            })
+        );
+    }
+
+    #[brontes_macros::test]
+    async fn test_compound_v3_absorb() {
+        let classifier_utils = ClassifierTestUtils::new().await;
+        setup(&classifier_utils);
+
+        let call_data = absorbCall { absorber: ACCOUNT, accounts: vec![BORROWER] }.abi_encode();
+        let weth_absorbed = U256::from(2_000_000_000_000_000_000u128);
+        let debt_paid = U256::from(5_500_000_000u64);
+        let logs = [
+            AbsorbCollateral {
+                absorber:           ACCOUNT,
+                borrower:           BORROWER,
+                asset:              TokenInfoWithAddress::usdt().address,
+                collateralAbsorbed: U256::from(100_000_000u64),
+                usdValue:           U256::from(100),
+            }
+            .encode_log_data(),
+            AbsorbCollateral {
+                absorber:           ACCOUNT,
+                borrower:           BORROWER,
+                asset:              TokenInfoWithAddress::weth().address,
+                collateralAbsorbed: weth_absorbed,
+                usdValue:           U256::from(6_000),
+            }
+            .encode_log_data(),
+            AbsorbDebt {
+                absorber:    ACCOUNT,
+                borrower:    BORROWER,
+                basePaidOut: debt_paid,
+                usdValue:    U256::from(5_500),
+            }
+            .encode_log_data(),
+        ]
+        .map(|data| Log { address: MARKET, data });
+
+        let liquidation = classifier_utils
+            .dispatch_call(MARKET, ACCOUNT, call_data.into(), Bytes::new(), &logs, 0)
+            .expect("absorb wasn't classified");
+
+        // the largest collateral of the position is the one reported
+        assert_eq!(
+            liquidation,
+            Action::Liquidation(NormalizedLiquidation {
+                protocol:              Protocol::CompoundV3,
+                trace_index:           0,
+                pool:                  MARKET,
+                liquidator:            ACCOUNT,
+                debtor:                BORROWER,
+                collateral_asset:      TokenInfoWithAddress::weth(),
+                debt_asset:            TokenInfoWithAddress::usdc(),
+                covered_debt:          debt_paid.to_scaled_rational(6),
+                liquidated_collateral: weth_absorbed.to_scaled_rational(18),
+                msg_value:             U256::ZERO,
+            })
+        );
+    }
+
+    #[brontes_macros::test]
+    async fn test_compound_v3_buy_collateral() {
+        let classifier_utils = ClassifierTestUtils::new().await;
+        setup(&classifier_utils);
+
+        let base_amount = U256::from(5_800_000_000u64);
+        let collateral_amount = U256::from(2_000_000_000_000_000_000u128);
+        let call_data = buyCollateralCall {
+            asset:      TokenInfoWithAddress::weth().address,
+            minAmount:  collateral_amount,
+            baseAmount: base_amount,
+            recipient:  ACCOUNT,
+        }
+        .abi_encode();
+        let log = Log {
+            address: MARKET,
+            data:    BuyCollateral {
+                buyer:            ACCOUNT,
+                asset:            TokenInfoWithAddress::weth().address,
+                baseAmount:       base_amount,
+                collateralAmount: collateral_amount,
+            }
+            .encode_log_data(),
+        };
+
+        let swap = classifier_utils
+            .dispatch_call(MARKET, ACCOUNT, call_data.into(), Bytes::new(), &[log], 0)
+            .expect("buyCollateral wasn't classified");
+
+        assert_eq!(
+            swap,
+            Action::Swap(NormalizedSwap {
+                protocol:    Protocol::CompoundV3,
+                trace_index: 0,
+                from:        ACCOUNT,
+                recipient:   ACCOUNT,
+                pool:        MARKET,
+                token_in:    TokenInfoWithAddress::usdc(),
+                token_out:   TokenInfoWithAddress::weth(),
+                amount_in:   base_amount.to_scaled_rational(6),
+                amount_out:  collateral_amount.to_scaled_rational(18),
+                msg_value:   U256::ZERO,
+            })
+        );
+    }
+}
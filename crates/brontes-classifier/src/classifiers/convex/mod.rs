@@ -0,0 +1,97 @@
+use alloy_primitives::Address;
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{
+    normalized_actions::{GovernanceAction, NormalizedGovernance, NormalizedStake, StakeAction},
+    structured_trace::CallInfo,
+};
+
+// The Booster holds every Convex pool, a pool is only referenced by its id. It
+// forwards the LP tokens to the Curve gauge of the pool through the voter
+// proxy, so the LP token and the amount are resolved from the transfers of the
+// call once it has been classified
+fn booster_stake(info: &CallInfo, action: StakeAction, account: Address) -> NormalizedStake {
+    NormalizedStake {
+        protocol: Protocol::Convex,
+        trace_index: info.trace_idx,
+        action,
+        staking_contract: info.target_address,
+        from: info.msg_sender,
+        account,
+        ..Default::default()
+    }
+}
+
+action_impl!(
+    Protocol::Convex,
+    crate::ConvexBooster::depositCall,
+    Stake,
+    [],
+    |info: CallInfo, _| { Ok(booster_stake(&info, StakeAction::Deposit, info.msg_sender)) }
+);
+
+action_impl!(
+    Protocol::Convex,
+    crate::ConvexBooster::depositAllCall,
+    Stake,
+    [],
+    |info: CallInfo, _| { Ok(booster_stake(&info, StakeAction::Deposit, info.msg_sender)) }
+);
+
+action_impl!(
+    Protocol::Convex,
+    crate::ConvexBooster::withdrawCall,
+    Stake,
+    [],
+    |info: CallInfo, _| { Ok(booster_stake(&info, StakeAction::Withdraw, info.msg_sender)) }
+);
+
+action_impl!(
+    Protocol::Convex,
+    crate::ConvexBooster::withdrawAllCall,
+    Stake,
+    [],
+    |info: CallInfo, _| { Ok(booster_stake(&info, StakeAction::Withdraw, info.msg_sender)) }
+);
+
+// Only called by the reward pool of the Convex pool, when the account
+// withdraws its staked deposit tokens and unwraps them
+action_impl!(
+    Protocol::Convex,
+    crate::ConvexBooster::withdrawToCall,
+    Stake,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: withdrawToCall, _| {
+        Ok(booster_stake(&info, StakeAction::Withdraw, call_data._to))
+    }
+);
+
+fn booster_governance(info: &CallInfo, action: GovernanceAction) -> NormalizedGovernance {
+    NormalizedGovernance {
+        protocol: Protocol::Convex,
+        trace_index: info.trace_idx,
+        action,
+        target: info.target_address,
+        from: info.msg_sender,
+    }
+}
+
+// Stops new deposits into every pool, the pools are then shut down one by one
+action_impl!(
+    Protocol::Convex,
+    crate::ConvexBooster::shutdownSystemCall,
+    Governance,
+    [],
+    |info: CallInfo, _| { Ok(booster_governance(&info, GovernanceAction::Pause)) }
+);
+
+// Withdraws all lp tokens of the pool from its gauge back into the Booster, so
+// that depositors can withdraw even if the gauge is broken
+action_impl!(
+    Protocol::Convex,
+    crate::ConvexBooster::shutdownPoolCall,
+    Governance,
+    [],
+    |info: CallInfo, _| { Ok(booster_governance(&info, GovernanceAction::EmergencyWithdraw)) }
+);
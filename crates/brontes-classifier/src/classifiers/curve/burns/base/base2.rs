@@ -17,7 +17,7 @@ action_impl!(
     |{
         let log = log.remove_liquidity_field?;
 
-        let details = db_tx.get_protocol_details(info.target_address)?;
+        let details = db_tx.get_protocol_details_at(info.target_address, info.block)?;
 
         let amounts = log.token_amounts;
         let (tokens, token_amts): (Vec<_>, Vec<_>) = details
@@ -58,7 +58,7 @@ action_impl!(
     |{
         let log = log.remove_liquidity_imbalance_field?;
 
-        let details = db_tx.get_protocol_details(info.target_address)?;
+        let details = db_tx.get_protocol_details_at(info.target_address, info.block)?;
 
         let amounts = log.token_amounts;
         let (tokens, token_amts): (Vec<_>, Vec<_>) = details
@@ -100,7 +100,7 @@ action_impl!(
     |{
         let log = log.remove_liquidity_one_field?;
 
-        let details = db_tx.get_protocol_details(info.target_address)?;
+        let details = db_tx.get_protocol_details_at(info.target_address, info.block)?;
 
         let token = match call_data.i {
             0 => details.token0,
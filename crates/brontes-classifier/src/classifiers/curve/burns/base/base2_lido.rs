@@ -19,7 +19,7 @@ action_impl!(
     |{
         let log = log.remove_liquidity_one_field?;
 
-        let details = db_tx.get_protocol_details(info.target_address)?;
+        let details = db_tx.get_protocol_details_at(info.target_address, info.block)?;
 
         let token = match call_data.i {
             0 => details.token0,
@@ -17,7 +17,7 @@ action_impl!(
     |{
         let log = log.remove_liquidity_field?;
 
-        let details = db_tx.get_protocol_details(info.from_address)?;
+        let details = db_tx.get_protocol_details_at(info.from_address, info.block)?;
         let token_addrs = vec![details.token0, details.curve_lp_token.ok_or(eyre::eyre!("Expected 'curve_lp_token', found 'None'"))?];
         let protocol = details.protocol;
 
@@ -60,7 +60,7 @@ action_impl!(
     |{
         let log = log.remove_liquidity_field?;
 
-        let details = db_tx.get_protocol_details(info.from_address)?;
+        let details = db_tx.get_protocol_details_at(info.from_address, info.block)?;
         let token_addrs = vec![details.token0, details.curve_lp_token.ok_or(eyre::eyre!("Expected 'curve_lp_token', found 'None'"))?];
         let protocol = details.protocol;
 
@@ -100,7 +100,7 @@ action_impl!(
     |{
         let log = log.remove_liquidity_imbalance_field?;
 
-        let details = db_tx.get_protocol_details(info.from_address)?;
+        let details = db_tx.get_protocol_details_at(info.from_address, info.block)?;
         let token_addrs = vec![details.token0, details.curve_lp_token.ok_or(eyre::eyre!("Expected 'curve_lp_token', found 'None'"))?];
         let protocol = details.protocol;
 
@@ -141,7 +141,7 @@ action_impl!(
     |{
         let log = log.remove_liquidity_imbalance_field?;
 
-        let details = db_tx.get_protocol_details(info.from_address)?;
+        let details = db_tx.get_protocol_details_at(info.from_address, info.block)?;
         let token_addrs = vec![details.token0, details.curve_lp_token.ok_or(eyre::eyre!("Expected 'curve_lp_token', found 'None'"))?];
         let protocol = details.protocol;
 
@@ -183,7 +183,7 @@ action_impl!(
     |{
         let log = log.remove_liquidity_one_field?;
 
-        let details = db_tx.get_protocol_details(info.from_address)?;
+        let details = db_tx.get_protocol_details_at(info.from_address, info.block)?;
         let protocol = details.protocol;
 
         let token = match call_data.i {
@@ -225,7 +225,7 @@ action_impl!(
     |{
         let log = log.remove_liquidity_one_field?;
 
-        let details = db_tx.get_protocol_details(info.from_address)?;
+        let details = db_tx.get_protocol_details_at(info.from_address, info.block)?;
         let protocol = details.protocol;
 
         let token = match call_data.i {
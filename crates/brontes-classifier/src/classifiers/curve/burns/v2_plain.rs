@@ -17,7 +17,7 @@ action_impl!(
     |{
         let log = log.remove_liquidity_field?;
 
-        let details = db_tx.get_protocol_details(info.from_address)?;
+        let details = db_tx.get_protocol_details_at(info.from_address, info.block)?;
         let protocol = details.protocol;
 
         let amounts = log.token_amounts;
@@ -59,7 +59,7 @@ action_impl!(
     |{
         let log = log.remove_liquidity_field?;
 
-        let details = db_tx.get_protocol_details(info.from_address)?;
+        let details = db_tx.get_protocol_details_at(info.from_address, info.block)?;
         let protocol = details.protocol;
 
         let amounts = log.token_amounts;
@@ -99,7 +99,7 @@ action_impl!(
     |{
         let log = log.remove_liquidity_imbalance_field?;
 
-        let details = db_tx.get_protocol_details(info.from_address)?;
+        let details = db_tx.get_protocol_details_at(info.from_address, info.block)?;
         let protocol = details.protocol;
 
         let amounts = log.token_amounts;
@@ -139,7 +139,7 @@ action_impl!(
     |{
         let log = log.remove_liquidity_imbalance_field?;
 
-        let details = db_tx.get_protocol_details(info.from_address)?;
+        let details = db_tx.get_protocol_details_at(info.from_address, info.block)?;
         let protocol = details.protocol;
 
         let amounts = log.token_amounts;
@@ -180,7 +180,7 @@ action_impl!(
     |{
         let log = log.remove_liquidity_one_field?;
 
-        let details = db_tx.get_protocol_details(info.from_address)?;
+        let details = db_tx.get_protocol_details_at(info.from_address, info.block)?;
         let protocol = details.protocol;
 
         let token = match call_data.i {
@@ -225,7 +225,7 @@ action_impl!(
     |{
         let log = log.remove_liquidity_one_field?;
 
-        let details = db_tx.get_protocol_details(info.from_address)?;
+        let details = db_tx.get_protocol_details_at(info.from_address, info.block)?;
         let protocol = details.protocol;
 
         let token = match call_data.i {
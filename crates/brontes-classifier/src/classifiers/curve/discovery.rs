@@ -339,3 +339,41 @@ mod tests {
             .unwrap();
     }
 }
+
+// Gauges deployed by the pool factories, for the pools they deployed. The pools
+// of these factories are their own LP token
+discovery_impl!(
+    CurveV2GaugeDiscovery,
+    crate::CurveV2MetapoolFactory::deploy_gaugeCall,
+    0xb9fc157394af804a3578134a6585c0dc9cc990d4,
+    |deployed_address: Address, trace_index: u64, call_data: deploy_gaugeCall, _| async move {
+        vec![gauge(deployed_address, trace_index, call_data._pool)]
+    }
+);
+
+discovery_impl!(
+    CurvecrvUSDGaugeDiscovery,
+    crate::CurvecrvUSDFactory::deploy_gaugeCall,
+    0x4f8846ae9380b90d2e71d5e3d042dff3e7ebb40d,
+    |deployed_address: Address, trace_index: u64, call_data: deploy_gaugeCall, _| async move {
+        vec![gauge(deployed_address, trace_index, call_data._pool)]
+    }
+);
+
+discovery_impl!(
+    CurveTriCryptoGaugeDiscovery,
+    crate::CurveTriCryptoFactory::deploy_gaugeCall,
+    0x0c0e5f2ff0ff18a3be9b835635039256dc4b4963,
+    |deployed_address: Address, trace_index: u64, call_data: deploy_gaugeCall, _| async move {
+        vec![gauge(deployed_address, trace_index, call_data._pool)]
+    }
+);
+
+fn gauge(deployed_address: Address, trace_index: u64, lp_token: Address) -> NormalizedNewPool {
+    NormalizedNewPool {
+        trace_index,
+        protocol: Protocol::CurveGauge,
+        pool_address: deployed_address,
+        tokens: vec![lp_token],
+    }
+}
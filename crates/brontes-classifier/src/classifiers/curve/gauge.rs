@@ -0,0 +1,91 @@
+//! Curve gauges stake the LP token of a pool to earn CRV. The gauge tracks the
+//! stake as a balance of its own and doesn't hand out anything for the LP
+//! tokens, so a deposit or withdrawal is only the LP token moving. Gauges are
+//! found when the pool factory deploys them, with the LP token as token0.
+use alloy_primitives::{Address, U256};
+use brontes_database::libmdbx::LibmdbxReader;
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{
+    normalized_actions::{NormalizedStake, StakeAction},
+    structured_trace::CallInfo,
+    ToScaledRational,
+};
+
+action_impl!(
+    Protocol::CurveGauge,
+    crate::CurveLiquidityGauge::deposit_0Call,
+    Stake,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: deposit_0Call, db_tx: &DB| {
+        gauge_stake(&info, StakeAction::Deposit, info.msg_sender, call_data._value, db_tx)
+    }
+);
+
+action_impl!(
+    Protocol::CurveGauge,
+    crate::CurveLiquidityGauge::deposit_1Call,
+    Stake,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: deposit_1Call, db_tx: &DB| {
+        gauge_stake(&info, StakeAction::Deposit, call_data._addr, call_data._value, db_tx)
+    }
+);
+
+action_impl!(
+    Protocol::CurveGauge,
+    crate::CurveLiquidityGauge::deposit_2Call,
+    Stake,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: deposit_2Call, db_tx: &DB| {
+        gauge_stake(&info, StakeAction::Deposit, call_data._addr, call_data._value, db_tx)
+    }
+);
+
+action_impl!(
+    Protocol::CurveGauge,
+    crate::CurveLiquidityGauge::withdraw_0Call,
+    Stake,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: withdraw_0Call, db_tx: &DB| {
+        gauge_stake(&info, StakeAction::Withdraw, info.msg_sender, call_data._value, db_tx)
+    }
+);
+
+action_impl!(
+    Protocol::CurveGauge,
+    crate::CurveLiquidityGauge::withdraw_1Call,
+    Stake,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: withdraw_1Call, db_tx: &DB| {
+        gauge_stake(&info, StakeAction::Withdraw, info.msg_sender, call_data._value, db_tx)
+    }
+);
+
+fn gauge_stake<DB: LibmdbxReader>(
+    info: &CallInfo,
+    action: StakeAction,
+    account: Address,
+    value: U256,
+    db_tx: &DB,
+) -> eyre::Result<NormalizedStake> {
+    let details = db_tx.get_protocol_details_at(info.target_address, info.block)?;
+    let token = db_tx.try_fetch_token_info(details.token0)?;
+    let amount = value.to_scaled_rational(token.decimals);
+
+    Ok(NormalizedStake {
+        protocol: Protocol::CurveGauge,
+        trace_index: info.trace_idx,
+        action,
+        staking_contract: info.target_address,
+        from: info.msg_sender,
+        account,
+        token,
+        amount,
+    })
+}
@@ -17,7 +17,7 @@ action_impl!(
     |{
         let log = log.add_liquidity_field?;
 
-        let details = db_tx.get_protocol_details(info.target_address)?;
+        let details = db_tx.get_protocol_details_at(info.target_address, info.block)?;
 
         let amounts = log.token_amounts;
         let (tokens, token_amts): (Vec<_>, Vec<_>) = details.into_iter()
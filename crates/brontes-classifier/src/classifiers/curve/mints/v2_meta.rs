@@ -16,7 +16,7 @@ action_impl!(
     db_tx: &DB|{
         let log = log.add_liquidity_field?;
 
-        let details = db_tx.get_protocol_details(info.from_address)?;
+        let details = db_tx.get_protocol_details_at(info.from_address, info.block)?;
         let token_addrs = vec![details.token0, details.curve_lp_token.ok_or(eyre::eyre!("Expected 'curve_lp_token', found 'None'"))?];
         let protocol = details.protocol;
 
@@ -56,7 +56,7 @@ action_impl!(
     db_tx: &DB|{
         let log = log.add_liquidity_field?;
 
-        let details = db_tx.get_protocol_details(info.from_address)?;
+        let details = db_tx.get_protocol_details_at(info.from_address, info.block)?;
         let token_addrs = vec![details.token0, details.curve_lp_token.ok_or(eyre::eyre!("Expected 'curve_lp_token', found 'None'"))?];
         let protocol = details.protocol;
 
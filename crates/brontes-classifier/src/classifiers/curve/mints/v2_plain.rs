@@ -16,7 +16,7 @@ action_impl!(
     db_tx: &DB|{
         let log = log.add_liquidity_field?;
 
-        let details = db_tx.get_protocol_details(info.from_address)?;
+        let details = db_tx.get_protocol_details_at(info.from_address, info.block)?;
         let protocol = details.protocol;
 
         let amounts = log.token_amounts;
@@ -53,7 +53,7 @@ action_impl!(
     db_tx: &DB|{
         let log = log.add_liquidity_field?;
 
-        let details = db_tx.get_protocol_details(info.from_address)?;
+        let details = db_tx.get_protocol_details_at(info.from_address, info.block)?;
         let protocol = details.protocol;
 
         let amounts = log.token_amounts;
@@ -17,7 +17,7 @@ action_impl!(
     |{
         let log = log.token_exchange_field?;
 
-        let details = db_tx.get_protocol_details(info.target_address)?;
+        let details = db_tx.get_protocol_details_at(info.target_address, info.block)?;
 
         let token_in_addr = match log.sold_id {
             0 => details.token0,
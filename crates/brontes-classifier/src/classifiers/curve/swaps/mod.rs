@@ -9,3 +9,6 @@ pub use v2_meta::*;
 
 mod v2_plain;
 pub use v2_plain::*;
+
+mod tricrypto_ng;
+pub use tricrypto_ng::*;
@@ -0,0 +1,129 @@
+//! The tricrypto-ng pools are deployed directly by the factory instead of
+//! through a proxy, so the pool is the target of the call. Their
+//! `TokenExchange` log also differs from the stableswap pools, the coin
+//! indexes are `uint256` and the fee and packed price scale are appended.
+use alloy_primitives::Address;
+use brontes_database::libmdbx::LibmdbxReader;
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{
+    normalized_actions::NormalizedSwap, structured_trace::CallInfo, ToScaledRational,
+};
+
+use crate::CurveTriCryptoNG::TokenExchange;
+
+action_impl!(
+    Protocol::CurveTriCryptoPool,
+    crate::CurveTriCryptoNG::exchange_0Call,
+    Swap,
+    [..TokenExchange],
+    logs: true,
+    |
+    info: CallInfo,
+    log: CurveTriCryptoPoolExchange_0CallLogs,
+    db_tx: &DB|{
+        let log = log.token_exchange_field?;
+        tricrypto_swap(&info, log, info.msg_sender, db_tx)
+    }
+);
+
+action_impl!(
+    Protocol::CurveTriCryptoPool,
+    crate::CurveTriCryptoNG::exchange_1Call,
+    Swap,
+    [..TokenExchange],
+    logs: true,
+    |
+    info: CallInfo,
+    log: CurveTriCryptoPoolExchange_1CallLogs,
+    db_tx: &DB|{
+        let log = log.token_exchange_field?;
+        tricrypto_swap(&info, log, info.msg_sender, db_tx)
+    }
+);
+
+action_impl!(
+    Protocol::CurveTriCryptoPool,
+    crate::CurveTriCryptoNG::exchange_2Call,
+    Swap,
+    [..TokenExchange],
+    call_data: true,
+    logs: true,
+    |
+    info: CallInfo,
+    call_data: exchange_2Call,
+    log: CurveTriCryptoPoolExchange_2CallLogs,
+    db_tx: &DB|{
+        let log = log.token_exchange_field?;
+        tricrypto_swap(&info, log, call_data.receiver, db_tx)
+    }
+);
+
+action_impl!(
+    Protocol::CurveTriCryptoPool,
+    crate::CurveTriCryptoNG::exchange_underlying_0Call,
+    Swap,
+    [..TokenExchange],
+    logs: true,
+    |
+    info: CallInfo,
+    log: CurveTriCryptoPoolExchange_underlying_0CallLogs,
+    db_tx: &DB|{
+        let log = log.token_exchange_field?;
+        tricrypto_swap(&info, log, info.msg_sender, db_tx)
+    }
+);
+
+action_impl!(
+    Protocol::CurveTriCryptoPool,
+    crate::CurveTriCryptoNG::exchange_underlying_1Call,
+    Swap,
+    [..TokenExchange],
+    call_data: true,
+    logs: true,
+    |
+    info: CallInfo,
+    call_data: exchange_underlying_1Call,
+    log: CurveTriCryptoPoolExchange_underlying_1CallLogs,
+    db_tx: &DB|{
+        let log = log.token_exchange_field?;
+        tricrypto_swap(&info, log, call_data.receiver, db_tx)
+    }
+);
+
+fn tricrypto_swap<DB: LibmdbxReader>(
+    info: &CallInfo,
+    log: TokenExchange,
+    recipient: Address,
+    db_tx: &DB,
+) -> eyre::Result<NormalizedSwap> {
+    let details = db_tx.get_protocol_details_at(info.target_address, info.block)?;
+
+    let coin = |id: usize| match id {
+        0 => Ok(details.token0),
+        1 => Ok(details.token1),
+        2 => details
+            .token2
+            .ok_or(eyre::eyre!("Expected token2 for tricrypto pool, found None")),
+        _ => Err(eyre::eyre!("invalid coin index {id} for tricrypto pool")),
+    };
+
+    let token_in = db_tx.try_fetch_token_info(coin(log.sold_id.saturating_to())?)?;
+    let token_out = db_tx.try_fetch_token_info(coin(log.bought_id.saturating_to())?)?;
+
+    let amount_in = log.tokens_sold.to_scaled_rational(token_in.decimals);
+    let amount_out = log.tokens_bought.to_scaled_rational(token_out.decimals);
+
+    Ok(NormalizedSwap {
+        protocol: details.protocol,
+        pool: info.target_address,
+        trace_index: info.trace_idx,
+        from: info.msg_sender,
+        recipient,
+        token_in,
+        token_out,
+        amount_in,
+        amount_out,
+        msg_value: info.msg_value,
+    })
+}
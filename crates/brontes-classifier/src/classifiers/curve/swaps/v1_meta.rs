@@ -17,7 +17,7 @@ action_impl!(
     db_tx: &DB|{
         let log = log.token_exchange_field?;
 
-        let details = db_tx.get_protocol_details(info.from_address)?;
+        let details = db_tx.get_protocol_details_at(info.from_address, info.block)?;
 
         let token_in_addr = match log.sold_id {
             0 => details.token0,
@@ -65,7 +65,7 @@ action_impl!(
     db_tx: &DB|{
         let log = log.token_exchange_field?;
 
-        let details = db_tx.get_protocol_details(info.from_address)?;
+        let details = db_tx.get_protocol_details_at(info.from_address, info.block)?;
 
         let token_in_addr = match log.sold_id {
             0 => details.token0,
@@ -114,7 +114,7 @@ action_impl!(
     db_tx: &DB|{
         let log = log.token_exchange_underlying_field?;
 
-        let details = db_tx.get_protocol_details(info.from_address)?;
+        let details = db_tx.get_protocol_details_at(info.from_address, info.block)?;
 
         let token_in_addr = match log.sold_id {
             0 => details.token0,
@@ -168,7 +168,7 @@ action_impl!(
     db_tx: &DB|{
         let log = log.token_exchange_underlying_field?;
 
-        let details = db_tx.get_protocol_details(info.from_address)?;
+        let details = db_tx.get_protocol_details_at(info.from_address, info.block)?;
 
         let token_in_addr = match log.sold_id {
             0 => details.token0,
@@ -16,7 +16,7 @@ action_impl!(
     db_tx: &DB|{
         let log = log.token_exchange_field?;
 
-        let details = db_tx.get_protocol_details(info.from_address)?;
+        let details = db_tx.get_protocol_details_at(info.from_address, info.block)?;
 
         let token_in_addr = match log.sold_id {
             0 => details.token0,
@@ -63,7 +63,7 @@ action_impl!(
     db_tx: &DB|{
         let log = log.token_exchange_field?;
 
-        let details = db_tx.get_protocol_details(info.from_address)?;
+        let details = db_tx.get_protocol_details_at(info.from_address, info.block)?;
 
         let token_in_addr = match log.sold_id {
             0 => details.token0,
@@ -110,7 +110,7 @@ action_impl!(
     db_tx: &DB|{
         let log = log.token_exchange_underlying_field?;
 
-        let details = db_tx.get_protocol_details(info.from_address)?;
+        let details = db_tx.get_protocol_details_at(info.from_address, info.block)?;
 
         let token_in_addr = match log.sold_id {
             0 => details.token0,
@@ -164,7 +164,7 @@ action_impl!(
     db_tx: &DB|{
         let log = log.token_exchange_underlying_field?;
 
-        let details = db_tx.get_protocol_details(info.from_address)?;
+        let details = db_tx.get_protocol_details_at(info.from_address, info.block)?;
 
         let token_in_addr = match log.sold_id {
             0 => details.token0,
@@ -59,13 +59,33 @@ action_impl!(
     }
 );
 
+action_impl!(
+    Protocol::Dodo,
+    crate::DodoZoo::breedDODOCall,
+    NewPool,
+    [DODOBirth],
+    logs: true,
+    |info: CallInfo, log_data: DodoBreedDODOCallLogs, _| {
+        let logs = log_data.d_o_d_o_birth_field?;
+
+        Ok(NormalizedNewPool {
+            trace_index: info.trace_idx,
+            protocol: Protocol::Dodo,
+            pool_address: logs.newBorn,
+            tokens: vec![logs.baseToken, logs.quoteToken],
+        })
+    }
+);
+
 #[cfg(test)]
 mod tests {
-    use alloy_primitives::{hex, Address, B256};
+    use alloy_primitives::{hex, Address, Bytes, Log, B256, U256};
+    use alloy_sol_types::{SolCall, SolEvent};
     use brontes_classifier::test_utils::ClassifierTestUtils;
     use brontes_types::{normalized_actions::Action, TreeSearchBuilder};
 
     use super::*;
+    use crate::DodoZoo::{breedDODOCall, DODOBirth};
 
     #[brontes_macros::test]
     async fn test_dodo_dvm_discovery() {
@@ -147,4 +167,65 @@ mod tests {
             .await
             .unwrap();
     }
+
+    #[brontes_macros::test]
+    async fn test_dodo_zoo_breed() {
+        let classifier_utils = ClassifierTestUtils::new().await;
+        let zoo = Address::new(hex!("3A97247DF274a17C59A3bd12735ea3FcDFb49950"));
+        let new_born = Address::repeat_byte(0xd0);
+        let base_token = Address::new(hex!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2"));
+        let quote_token = Address::new(hex!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"));
+        classifier_utils.ensure_protocol(
+            Protocol::Dodo,
+            zoo,
+            Address::ZERO,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+
+        let call_data = breedDODOCall {
+            maintainer:    Address::repeat_byte(0x01),
+            baseToken:     base_token,
+            quoteToken:    quote_token,
+            oracle:        Address::repeat_byte(0x02),
+            lpFeeRate:     U256::ZERO,
+            mtFeeRate:     U256::ZERO,
+            k:             U256::ZERO,
+            gasPriceLimit: U256::ZERO,
+        }
+        .abi_encode();
+        let log = Log {
+            address: zoo,
+            data:    DODOBirth {
+                newBorn:    new_born,
+                baseToken:  base_token,
+                quoteToken: quote_token,
+            }
+            .encode_log_data(),
+        };
+
+        let new_pool = classifier_utils
+            .dispatch_call(
+                zoo,
+                Address::repeat_byte(0x01),
+                call_data.into(),
+                Bytes::new(),
+                &[log],
+                0,
+            )
+            .expect("breedDODO wasn't classified");
+
+        assert_eq!(
+            new_pool,
+            Action::NewPool(NormalizedNewPool {
+                trace_index:  0,
+                protocol:     Protocol::Dodo,
+                pool_address: new_born,
+                tokens:       vec![base_token, quote_token],
+            })
+        );
+    }
 }
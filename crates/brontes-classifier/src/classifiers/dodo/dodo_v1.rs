@@ -0,0 +1,180 @@
+use brontes_macros::action_impl;
+use brontes_types::{
+    normalized_actions::NormalizedSwap, structured_trace::CallInfo, Protocol, ToScaledRational,
+};
+
+action_impl!(
+    Protocol::Dodo,
+    crate::DodoV1Pool::sellBaseTokenCall,
+    Swap,
+    [SellBaseToken],
+    logs: true,
+    |info: CallInfo, log_data: DodoSellBaseTokenCallLogs, db: &DB| {
+        let logs = log_data.sell_base_token_field?;
+
+        // v1 pools are registered with tokens in (base, quote) order
+        let details = db.get_protocol_details_at(info.target_address, info.block)?;
+        let token_in = db.try_fetch_token_info(details.token0)?;
+        let token_out = db.try_fetch_token_info(details.token1)?;
+
+        let amount_in = logs.payBase.to_scaled_rational(token_in.decimals);
+        let amount_out = logs.receiveQuote.to_scaled_rational(token_out.decimals);
+
+        Ok(NormalizedSwap {
+            protocol: Protocol::Dodo,
+            trace_index: info.trace_idx,
+            from: logs.seller,
+            recipient: logs.seller,
+            pool: info.target_address,
+            token_in,
+            token_out,
+            amount_in,
+            amount_out,
+            msg_value: info.msg_value
+        })
+    }
+);
+
+action_impl!(
+    Protocol::Dodo,
+    crate::DodoV1Pool::buyBaseTokenCall,
+    Swap,
+    [BuyBaseToken],
+    logs: true,
+    |info: CallInfo, log_data: DodoBuyBaseTokenCallLogs, db: &DB| {
+        let logs = log_data.buy_base_token_field?;
+
+        let details = db.get_protocol_details_at(info.target_address, info.block)?;
+        let token_in = db.try_fetch_token_info(details.token1)?;
+        let token_out = db.try_fetch_token_info(details.token0)?;
+
+        let amount_in = logs.payQuote.to_scaled_rational(token_in.decimals);
+        let amount_out = logs.receiveBase.to_scaled_rational(token_out.decimals);
+
+        Ok(NormalizedSwap {
+            protocol: Protocol::Dodo,
+            trace_index: info.trace_idx,
+            from: logs.buyer,
+            recipient: logs.buyer,
+            pool: info.target_address,
+            token_in,
+            token_out,
+            amount_in,
+            amount_out,
+            msg_value: info.msg_value
+        })
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{Address, Bytes, Log, U256};
+    use alloy_sol_types::{SolCall, SolEvent};
+    use brontes_classifier::test_utils::ClassifierTestUtils;
+    use brontes_types::{db::token_info::TokenInfoWithAddress, normalized_actions::Action};
+
+    use super::*;
+    use crate::DodoV1Pool::{buyBaseTokenCall, sellBaseTokenCall, BuyBaseToken, SellBaseToken};
+
+    const BLOCK: u64 = 18_000_000;
+
+    fn setup(classifier_utils: &ClassifierTestUtils, pool: Address) {
+        classifier_utils.ensure_token(TokenInfoWithAddress::weth());
+        classifier_utils.ensure_token(TokenInfoWithAddress::usdc());
+        classifier_utils.ensure_protocol(
+            Protocol::Dodo,
+            pool,
+            TokenInfoWithAddress::weth().address,
+            Some(TokenInfoWithAddress::usdc().address),
+            None,
+            None,
+            None,
+            None,
+        );
+    }
+
+    #[brontes_macros::test]
+    async fn test_dodo_v1_sell_base_token() {
+        let classifier_utils = ClassifierTestUtils::new().await;
+        let pool = Address::repeat_byte(0xd0);
+        let seller = Address::repeat_byte(0x5e);
+        setup(&classifier_utils, pool);
+
+        let pay_base = U256::from(2_000_000_000_000_000_000u128);
+        let receive_quote = U256::from(6_000_000_000u64);
+        let call_data = sellBaseTokenCall {
+            amount:          pay_base,
+            minReceiveQuote: U256::ZERO,
+            data:            Bytes::new(),
+        }
+        .abi_encode();
+        let log = Log {
+            address: pool,
+            data:    SellBaseToken { seller, payBase: pay_base, receiveQuote: receive_quote }
+                .encode_log_data(),
+        };
+
+        let swap = classifier_utils
+            .dispatch_call(pool, seller, call_data.into(), Bytes::new(), &[log], BLOCK)
+            .expect("sellBaseToken wasn't classified");
+
+        assert_eq!(
+            swap,
+            Action::Swap(NormalizedSwap {
+                protocol: Protocol::Dodo,
+                trace_index: 0,
+                from: seller,
+                recipient: seller,
+                pool,
+                token_in: TokenInfoWithAddress::weth(),
+                token_out: TokenInfoWithAddress::usdc(),
+                amount_in: pay_base.to_scaled_rational(18),
+                amount_out: receive_quote.to_scaled_rational(6),
+                msg_value: U256::ZERO,
+            })
+        );
+    }
+
+    #[brontes_macros::test]
+    async fn test_dodo_v1_buy_base_token() {
+        let classifier_utils = ClassifierTestUtils::new().await;
+        let pool = Address::repeat_byte(0xd1);
+        let buyer = Address::repeat_byte(0xb0);
+        setup(&classifier_utils, pool);
+
+        let receive_base = U256::from(1_000_000_000_000_000_000u128);
+        let pay_quote = U256::from(3_010_000_000u64);
+        let call_data = buyBaseTokenCall {
+            amount:      receive_base,
+            maxPayQuote: pay_quote,
+            data:        Bytes::new(),
+        }
+        .abi_encode();
+        let log = Log {
+            address: pool,
+            data:    BuyBaseToken { buyer, receiveBase: receive_base, payQuote: pay_quote }
+                .encode_log_data(),
+        };
+
+        let swap = classifier_utils
+            .dispatch_call(pool, buyer, call_data.into(), Bytes::new(), &[log], BLOCK)
+            .expect("buyBaseToken wasn't classified");
+
+        // the quote token goes in and the base token comes out
+        assert_eq!(
+            swap,
+            Action::Swap(NormalizedSwap {
+                protocol: Protocol::Dodo,
+                trace_index: 0,
+                from: buyer,
+                recipient: buyer,
+                pool,
+                token_in: TokenInfoWithAddress::usdc(),
+                token_out: TokenInfoWithAddress::weth(),
+                amount_in: pay_quote.to_scaled_rational(6),
+                amount_out: receive_base.to_scaled_rational(18),
+                msg_value: U256::ZERO,
+            })
+        );
+    }
+}
@@ -78,7 +78,7 @@ action_impl!(
     |info: CallInfo, log_data: DodoFlashLoanCallLogs, db: &DB| {
         let logs = log_data.d_o_d_o_flash_loan_field?;
 
-        let details = db.get_protocol_details_sorted(info.target_address)?;
+        let details = db.get_protocol_details_sorted_at(info.target_address, info.block)?;
         let [token_0, token_1] = [details.token0, details.token1];
 
         let token_one = db.try_fetch_token_info(token_0)?;
@@ -114,7 +114,7 @@ action_impl!(
     return_data: true,
     call_data: true,
     |info: CallInfo, call_data: buySharesCall, return_data: buySharesReturn, db: &DB| {
-        let details = db.get_protocol_details_sorted(info.target_address)?;
+        let details = db.get_protocol_details_sorted_at(info.target_address, info.block)?;
         let [token_0, token_1] = [details.token0, details.token1];
 
         let mut token = vec![];
@@ -154,7 +154,7 @@ action_impl!(
     call_data: true,
     return_data: true,
     |info: CallInfo, call_data: sellSharesCall, return_data: sellSharesReturn, db: &DB| {
-        let details = db.get_protocol_details_sorted(info.target_address)?;
+        let details = db.get_protocol_details_sorted_at(info.target_address, info.block)?;
         let [token_0, token_1] = [details.token0, details.token1];
 
         let mut token = vec![];
@@ -1,5 +1,7 @@
 mod discovery;
+mod dodo_v1;
 mod dodo_v2;
 
 pub use discovery::*;
+pub use dodo_v1::*;
 pub use dodo_v2::*;
@@ -2,23 +2,60 @@ use std::sync::Arc;
 
 use alloy_primitives::{Address, Bytes};
 use alloy_sol_types::SolCall;
-use brontes_core::missing_token_info::load_missing_token_info;
+use brontes_core::missing_token_info::load_missing_token_info_with_hint;
 use brontes_types::{
     db::traits::{DBWriter, LibmdbxReader},
-    normalized_actions::NormalizedTransfer,
+    normalized_actions::{ApprovalKind, NormalizedApproval, NormalizedTransfer},
     traits::TracingProvider,
     ToScaledRational,
 };
 use malachite::{num::basic::traits::Zero, Rational};
 use reth_primitives::U256;
 
+use crate::tree_builder::utils::transfer_decimal_hint;
+
 alloy_sol_macro::sol!(
     function transfer(address, uint) returns(bool);
     function transferFrom(address, address, uint) returns(bool);
     function withdraw(uint wad);
     function deposit();
+    function approve(address, uint) returns(bool);
+    function increaseAllowance(address, uint) returns(bool);
+    function permit(address, address, uint, uint, uint8, bytes32, bytes32);
+);
+
+// dai's permit predates eip-2612 and only approves all or nothing
+alloy_sol_macro::sol!(
+    interface Dai {
+        function permit(address, address, uint, uint, bool, uint8, bytes32, bytes32);
+    }
 );
 
+/// Decodes an `approve`, `increaseAllowance` or permit call made to `token`
+pub fn try_decode_approval(
+    idx: u64,
+    calldata: &[u8],
+    from: Address,
+    token: Address,
+) -> Option<NormalizedApproval> {
+    let approval = |kind, owner, spender, amount| {
+        Some(NormalizedApproval { trace_index: idx, kind, token, owner, spender, amount })
+    };
+
+    if let Ok(call) = approveCall::abi_decode(calldata, false) {
+        approval(ApprovalKind::Approve, from, call._0, call._1)
+    } else if let Ok(call) = increaseAllowanceCall::abi_decode(calldata, false) {
+        approval(ApprovalKind::IncreaseAllowance, from, call._0, call._1)
+    } else if let Ok(call) = permitCall::abi_decode(calldata, false) {
+        approval(ApprovalKind::Permit, call._0, call._1, call._2)
+    } else if let Ok(call) = Dai::permitCall::abi_decode(calldata, false) {
+        let amount = if call._4 { U256::MAX } else { U256::ZERO };
+        approval(ApprovalKind::Permit, call._0, call._1, amount)
+    } else {
+        None
+    }
+}
+
 pub async fn try_decode_transfer<T: TracingProvider, DB: LibmdbxReader + DBWriter>(
     idx: u64,
     calldata: Bytes,
@@ -47,7 +84,8 @@ pub async fn try_decode_transfer<T: TracingProvider, DB: LibmdbxReader + DBWrite
     };
 
     if db.try_fetch_token_info(token).is_err() {
-        load_missing_token_info(provider, db, block, token).await
+        let hint = transfer_decimal_hint(db, token, from_addr, to_addr, amount);
+        load_missing_token_info_with_hint(provider, db, block, token, hint).await
     }
 
     let token_info = db.try_fetch_token_info(token)?;
@@ -62,3 +100,57 @@ pub async fn try_decode_transfer<T: TracingProvider, DB: LibmdbxReader + DBWrite
         fee:         Rational::ZERO,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::B256;
+
+    use super::*;
+
+    #[test]
+    fn test_decode_approvals() {
+        let token = Address::with_last_byte(1);
+        let owner = Address::with_last_byte(2);
+        let spender = Address::with_last_byte(3);
+
+        let approve = approveCall { _0: spender, _1: U256::MAX }.abi_encode();
+        let approval = try_decode_approval(0, &approve, owner, token).unwrap();
+        assert_eq!(approval.kind, ApprovalKind::Approve);
+        assert_eq!(approval.owner, owner);
+        assert_eq!(approval.spender, spender);
+        assert!(approval.is_unlimited());
+
+        // a permit is submitted by anyone on the owner's behalf
+        let permit = permitCall {
+            _0: owner,
+            _1: spender,
+            _2: U256::from(10),
+            _3: U256::MAX,
+            _4: 27,
+            _5: B256::ZERO,
+            _6: B256::ZERO,
+        }
+        .abi_encode();
+        let approval = try_decode_approval(1, &permit, spender, token).unwrap();
+        assert_eq!(approval.kind, ApprovalKind::Permit);
+        assert_eq!(approval.owner, owner);
+        assert_eq!(approval.amount, U256::from(10));
+
+        let dai_permit = Dai::permitCall {
+            _0: owner,
+            _1: spender,
+            _2: U256::ZERO,
+            _3: U256::MAX,
+            _4: false,
+            _5: 27,
+            _6: B256::ZERO,
+            _7: B256::ZERO,
+        }
+        .abi_encode();
+        let approval = try_decode_approval(2, &dai_permit, spender, token).unwrap();
+        assert!(approval.is_revoke());
+
+        let transfer = transferCall { _0: spender, _1: U256::from(1) }.abi_encode();
+        assert!(try_decode_approval(3, &transfer, owner, token).is_none());
+    }
+}
@@ -0,0 +1,43 @@
+use alloy_primitives::Address;
+use brontes_macros::discovery_impl;
+use brontes_pricing::Protocol;
+
+discovery_impl!(
+    FraxswapDiscovery,
+    crate::FraxswapFactory::createPair_0Call,
+    0x43eC799eAdd63848443E2347C49f5f52e8Fe0F6f,
+    |deployed_address: Address, trace_index: u64, call_data: createPair_0Call, _| async move {
+        let mut token_a = call_data.tokenA;
+        let mut token_b = call_data.tokenB;
+        if token_a > token_b {
+            std::mem::swap(&mut token_a, &mut token_b)
+        }
+
+        vec![NormalizedNewPool {
+            pool_address: deployed_address,
+            trace_index,
+            protocol: Protocol::Fraxswap,
+            tokens: vec![token_a, token_b],
+        }]
+    }
+);
+
+discovery_impl!(
+    FraxswapWithFeeDiscovery,
+    crate::FraxswapFactory::createPair_1Call,
+    0x43eC799eAdd63848443E2347C49f5f52e8Fe0F6f,
+    |deployed_address: Address, trace_index: u64, call_data: createPair_1Call, _| async move {
+        let mut token_a = call_data.tokenA;
+        let mut token_b = call_data.tokenB;
+        if token_a > token_b {
+            std::mem::swap(&mut token_a, &mut token_b)
+        }
+
+        vec![NormalizedNewPool {
+            pool_address: deployed_address,
+            trace_index,
+            protocol: Protocol::Fraxswap,
+            tokens: vec![token_a, token_b],
+        }]
+    }
+);
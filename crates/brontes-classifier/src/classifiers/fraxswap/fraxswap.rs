@@ -0,0 +1,279 @@
+use alloy_primitives::U256;
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{
+    normalized_actions::{
+        NormalizedBurn, NormalizedMint, NormalizedSwap, NormalizedTwammOrder, TwammOrderAction,
+    },
+    structured_trace::CallInfo,
+    ToScaledRational,
+};
+use malachite::{num::basic::traits::Zero, Rational};
+
+action_impl!(
+    Protocol::Fraxswap,
+    crate::FraxswapPair::swapCall,
+    Swap,
+    [..Swap],
+    call_data: true,
+    logs: true,
+    |
+    info: CallInfo,
+    call_data: swapCall,
+    logs: FraxswapSwapCallLogs,
+    db_tx: &DB| {
+        let logs = logs.swap_field?;
+
+        let recipient = call_data.to;
+        let details = db_tx.get_protocol_details_sorted_at(info.target_address, info.block)?;
+        let [token_0, token_1] = [details.token0, details.token1];
+
+        let t0_info = db_tx.try_fetch_token_info(token_0)?;
+        let t1_info = db_tx.try_fetch_token_info(token_1)?;
+
+        if logs.amount0In == U256::ZERO {
+            let amount_in = logs.amount1In.to_scaled_rational(t1_info.decimals);
+            let amount_out = logs.amount0Out.to_scaled_rational(t0_info.decimals);
+
+            return Ok(NormalizedSwap {
+                protocol: Protocol::Fraxswap,
+                pool: info.target_address,
+                trace_index: info.trace_idx,
+                from: info.from_address,
+                recipient,
+                token_in: t1_info,
+                token_out: t0_info,
+                amount_in,
+                amount_out,
+                msg_value: info.msg_value
+            })
+        } else {
+            let amount_in = logs.amount0In.to_scaled_rational(t0_info.decimals);
+            let amount_out = logs.amount1Out.to_scaled_rational(t1_info.decimals);
+            return Ok(NormalizedSwap {
+                protocol: Protocol::Fraxswap,
+                pool: info.target_address,
+                trace_index: info.trace_idx,
+                from: info.from_address,
+                recipient,
+                token_in: t0_info,
+                token_out: t1_info,
+                amount_in,
+                amount_out,
+                msg_value: info.msg_value
+            })
+        }
+    }
+);
+
+action_impl!(
+    Protocol::Fraxswap,
+    crate::FraxswapPair::mintCall,
+    Mint,
+    // can be a double transfer if the pool has no liquidity
+    [..Mint],
+    logs: true,
+    call_data: true,
+    |
+        info: CallInfo,
+     call_data: mintCall,
+     log_data: FraxswapMintCallLogs,
+     db_tx: &DB| {
+        let log_data = log_data.mint_field?;
+
+        let details = db_tx.get_protocol_details_sorted_at(info.target_address, info.block)?;
+        let [token_0, token_1] = [details.token0, details.token1];
+
+        let t0_info = db_tx.try_fetch_token_info(token_0)?;
+        let t1_info = db_tx.try_fetch_token_info(token_1)?;
+
+        let am0 = log_data.amount0.to_scaled_rational(t0_info.decimals);
+        let am1 = log_data.amount1.to_scaled_rational(t1_info.decimals);
+
+        Ok(NormalizedMint {
+            protocol: Protocol::Fraxswap,
+            recipient: call_data.to,
+            from: info.from_address,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            token: vec![t0_info, t1_info],
+            amount: vec![am0, am1],
+        })
+    }
+);
+
+action_impl!(
+    Protocol::Fraxswap,
+    crate::FraxswapPair::burnCall,
+    Burn,
+    [..Burn],
+    call_data: true,
+    logs: true,
+    |
+     info: CallInfo,
+     call_data: burnCall,
+     log_data: FraxswapBurnCallLogs,
+     db_tx: &DB| {
+        let log_data = log_data.burn_field?;
+        let details = db_tx.get_protocol_details_sorted_at(info.target_address, info.block)?;
+        let [token_0, token_1] = [details.token0, details.token1];
+
+        let t0_info = db_tx.try_fetch_token_info(token_0)?;
+        let t1_info = db_tx.try_fetch_token_info(token_1)?;
+
+        let am0 = log_data.amount0.to_scaled_rational(t0_info.decimals);
+        let am1 = log_data.amount1.to_scaled_rational(t1_info.decimals);
+
+        Ok(NormalizedBurn {
+            protocol: Protocol::Fraxswap,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            recipient: call_data.to,
+            pool: info.target_address,
+            token: vec![t0_info, t1_info],
+            amount: vec![am0, am1],
+        })
+    }
+);
+
+action_impl!(
+    Protocol::Fraxswap,
+    crate::FraxswapPair::longTermSwapFrom0To1Call,
+    TwammOrder,
+    [..LongTermSwap0To1],
+    logs: true,
+    |
+    info: CallInfo,
+    log_data: FraxswapLongTermSwapFrom0To1CallLogs,
+    db_tx: &DB| {
+        let log_data = log_data.long_term_swap0_to1_field?;
+        let details = db_tx.get_protocol_details_sorted_at(info.target_address, info.block)?;
+
+        let token_in = db_tx.try_fetch_token_info(details.token0)?;
+        let token_out = db_tx.try_fetch_token_info(details.token1)?;
+        let amount_in = log_data.amount0In.to_scaled_rational(token_in.decimals);
+
+        Ok(NormalizedTwammOrder {
+            protocol: Protocol::Fraxswap,
+            trace_index: info.trace_idx,
+            action: TwammOrderAction::Place,
+            pool: info.target_address,
+            from: info.from_address,
+            order_id: log_data.orderId,
+            token_in,
+            token_out,
+            amount_in,
+            amount_out: Rational::ZERO,
+            time_intervals: log_data.numberOfTimeIntervals,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::Fraxswap,
+    crate::FraxswapPair::longTermSwapFrom1To0Call,
+    TwammOrder,
+    [..LongTermSwap1To0],
+    logs: true,
+    |
+    info: CallInfo,
+    log_data: FraxswapLongTermSwapFrom1To0CallLogs,
+    db_tx: &DB| {
+        let log_data = log_data.long_term_swap1_to0_field?;
+        let details = db_tx.get_protocol_details_sorted_at(info.target_address, info.block)?;
+
+        let token_in = db_tx.try_fetch_token_info(details.token1)?;
+        let token_out = db_tx.try_fetch_token_info(details.token0)?;
+        let amount_in = log_data.amount1In.to_scaled_rational(token_in.decimals);
+
+        Ok(NormalizedTwammOrder {
+            protocol: Protocol::Fraxswap,
+            trace_index: info.trace_idx,
+            action: TwammOrderAction::Place,
+            pool: info.target_address,
+            from: info.from_address,
+            order_id: log_data.orderId,
+            token_in,
+            token_out,
+            amount_in,
+            amount_out: Rational::ZERO,
+            time_intervals: log_data.numberOfTimeIntervals,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::Fraxswap,
+    crate::FraxswapPair::cancelLongTermSwapCall,
+    TwammOrder,
+    [..CancelLongTermOrder],
+    logs: true,
+    |
+    info: CallInfo,
+    log_data: FraxswapCancelLongTermSwapCallLogs,
+    db_tx: &DB| {
+        let log_data = log_data.cancel_long_term_order_field?;
+
+        let token_in = db_tx.try_fetch_token_info(log_data.sellToken)?;
+        let token_out = db_tx.try_fetch_token_info(log_data.buyToken)?;
+        let amount_in = log_data.unsoldAmount.to_scaled_rational(token_in.decimals);
+        let amount_out = log_data.purchasedAmount.to_scaled_rational(token_out.decimals);
+
+        Ok(NormalizedTwammOrder {
+            protocol: Protocol::Fraxswap,
+            trace_index: info.trace_idx,
+            action: TwammOrderAction::Cancel,
+            pool: info.target_address,
+            from: log_data.addr,
+            order_id: log_data.orderId,
+            token_in,
+            token_out,
+            amount_in,
+            amount_out,
+            time_intervals: U256::ZERO,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::Fraxswap,
+    crate::FraxswapPair::withdrawProceedsFromLongTermSwapCall,
+    TwammOrder,
+    [..WithdrawProceedsFromLongTermOrder],
+    logs: true,
+    |
+    info: CallInfo,
+    log_data: FraxswapWithdrawProceedsFromLongTermSwapCallLogs,
+    db_tx: &DB| {
+        let log_data = log_data.withdraw_proceeds_from_long_term_order_field?;
+        let details = db_tx.get_protocol_details_sorted_at(info.target_address, info.block)?;
+
+        // the sold token isn't part of the event, its the other side of the pair
+        let token_in = if log_data.proceedToken == details.token0 {
+            details.token1
+        } else {
+            details.token0
+        };
+        let token_in = db_tx.try_fetch_token_info(token_in)?;
+        let token_out = db_tx.try_fetch_token_info(log_data.proceedToken)?;
+        let amount_out = log_data.proceeds.to_scaled_rational(token_out.decimals);
+
+        Ok(NormalizedTwammOrder {
+            protocol: Protocol::Fraxswap,
+            trace_index: info.trace_idx,
+            action: TwammOrderAction::Withdraw,
+            pool: info.target_address,
+            from: log_data.addr,
+            order_id: log_data.orderId,
+            token_in,
+            token_out,
+            amount_in: Rational::ZERO,
+            amount_out,
+            time_intervals: U256::ZERO,
+            msg_value: info.msg_value,
+        })
+    }
+);
@@ -0,0 +1,5 @@
+mod discovery;
+mod fraxswap;
+
+pub use discovery::*;
+pub use fraxswap::*;
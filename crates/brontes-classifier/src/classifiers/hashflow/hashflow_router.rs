@@ -0,0 +1,61 @@
+//! Hashflow trades are RFQ fills against a market maker's pool, routed
+//! through the router. The pool in the swap is the market maker's pool, so the
+//! maker of each fill can be recovered from the normalized swap.
+use alloy_primitives::{Address, U256};
+use brontes_database::libmdbx::LibmdbxReader;
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{
+    db::token_info::TokenInfoWithAddress, normalized_actions::NormalizedSwap,
+    structured_trace::CallInfo, ToScaledRational,
+};
+
+action_impl!(
+    Protocol::Hashflow,
+    crate::HashflowRouter::tradeRFQTCall,
+    Swap,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: tradeRFQTCall,
+    db_tx: &DB| {
+        let quote = call_data.quote;
+
+        let token_in = hashflow_token(quote.baseToken, db_tx)?;
+        let token_out = hashflow_token(quote.quoteToken, db_tx)?;
+
+        // quotes can be partially filled, in which case the maker pays out pro
+        // rata to the amount of the base token that was actually sent
+        let amount_out = if quote.baseTokenAmount.is_zero() {
+            U256::ZERO
+        } else {
+            quote.quoteTokenAmount * quote.effectiveBaseTokenAmount / quote.baseTokenAmount
+        };
+
+        Ok(NormalizedSwap {
+            protocol: Protocol::Hashflow,
+            trace_index: info.trace_idx,
+            from: info.msg_sender,
+            recipient: quote.effectiveTrader,
+            pool: quote.pool,
+            amount_in: quote.effectiveBaseTokenAmount.to_scaled_rational(token_in.decimals),
+            amount_out: amount_out.to_scaled_rational(token_out.decimals),
+            token_in,
+            token_out,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+/// Hashflow uses the zero address for native eth
+fn hashflow_token<DB: LibmdbxReader>(
+    token: Address,
+    db_tx: &DB,
+) -> eyre::Result<TokenInfoWithAddress> {
+    if token == Address::ZERO {
+        return Ok(TokenInfoWithAddress::native_eth())
+    }
+
+    db_tx.try_fetch_token_info(token)
+}
@@ -0,0 +1,3 @@
+mod hashflow_router;
+
+pub use hashflow_router::*;
@@ -0,0 +1,226 @@
+use alloy_primitives::{hex, Address};
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{
+    db::token_info::TokenInfoWithAddress,
+    normalized_actions::{
+        GovernanceAction, NormalizedCollect, NormalizedGovernance, NormalizedSwap,
+    },
+    structured_trace::CallInfo,
+    ToScaledRational,
+};
+use malachite::Rational;
+
+pub const STETH_ADDRESS: Address = Address::new(hex!("ae7ab96520DE3A18E5e111B5EaAb095312D7fE84"));
+
+// stETH balances are shares scaled by the pooled eth per share, so the shares
+// returned by `submit` aren't the amount minted. The amount is taken from the
+// transfer event, which stETH emits with the shares already converted
+action_impl!(
+    Protocol::Lido,
+    crate::Lido::submitCall,
+    Swap,
+    [..Submitted, ..Transfer],
+    logs: true,
+    include_delegated_logs: true,
+    |
+    info: CallInfo,
+    log_data: LidoSubmitCallLogs,
+    db_tx: &DB| {
+        let submitted = log_data.submitted_field?;
+        let minted = log_data.transfer_field?;
+        let steth = db_tx.try_fetch_token_info(info.target_address)?;
+
+        Ok(NormalizedSwap {
+            protocol: Protocol::Lido,
+            trace_index: info.trace_idx,
+            from: submitted.sender,
+            recipient: minted.to,
+            pool: info.target_address,
+            token_in: TokenInfoWithAddress::native_eth(),
+            token_out: steth.clone(),
+            amount_in: submitted.amount.to_scaled_rational(18),
+            amount_out: minted.value.to_scaled_rational(steth.decimals),
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+// wstETH is a non rebasing wrapper holding stETH shares, one wstETH is one
+// share. Both directions return the amount received, converted by the stETH
+// contract at the current share rate
+action_impl!(
+    Protocol::Lido,
+    crate::WstETH::wrapCall,
+    Swap,
+    [],
+    call_data: true,
+    return_data: true,
+    |
+    info: CallInfo,
+    call_data: wrapCall,
+    return_data: wrapReturn,
+    db_tx: &DB| {
+        let steth = db_tx.try_fetch_token_info(STETH_ADDRESS)?;
+        let wsteth = db_tx.try_fetch_token_info(info.target_address)?;
+
+        Ok(NormalizedSwap {
+            protocol: Protocol::Lido,
+            trace_index: info.trace_idx,
+            from: info.msg_sender,
+            recipient: info.msg_sender,
+            pool: info.target_address,
+            amount_in: call_data._stETHAmount.to_scaled_rational(steth.decimals),
+            amount_out: return_data._0.to_scaled_rational(wsteth.decimals),
+            token_in: steth,
+            token_out: wsteth,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::Lido,
+    crate::WstETH::unwrapCall,
+    Swap,
+    [],
+    call_data: true,
+    return_data: true,
+    |
+    info: CallInfo,
+    call_data: unwrapCall,
+    return_data: unwrapReturn,
+    db_tx: &DB| {
+        let steth = db_tx.try_fetch_token_info(STETH_ADDRESS)?;
+        let wsteth = db_tx.try_fetch_token_info(info.target_address)?;
+
+        Ok(NormalizedSwap {
+            protocol: Protocol::Lido,
+            trace_index: info.trace_idx,
+            from: info.msg_sender,
+            recipient: info.msg_sender,
+            pool: info.target_address,
+            amount_in: call_data._wstETHAmount.to_scaled_rational(wsteth.decimals),
+            amount_out: return_data._0.to_scaled_rational(steth.decimals),
+            token_in: wsteth,
+            token_out: steth,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+// The stETH of a withdrawal request is locked in the queue when the request is
+// made, claiming only pays out the eth it was finalized at. A batch of claims
+// is collected as one, all requests of a claim are paid to the same receiver
+action_impl!(
+    Protocol::Lido,
+    crate::LidoWithdrawalQueue::claimWithdrawalCall,
+    Collect,
+    [..WithdrawalClaimed],
+    logs: true,
+    include_delegated_logs: true,
+    |
+    info: CallInfo,
+    log_data: LidoClaimWithdrawalCallLogs,
+    _| {
+        let claimed = log_data.withdrawal_claimed_field?;
+
+        Ok(NormalizedCollect {
+            protocol: Protocol::Lido,
+            trace_index: info.trace_idx,
+            from: info.msg_sender,
+            recipient: claimed.receiver,
+            pool: info.target_address,
+            token: vec![TokenInfoWithAddress::native_eth()],
+            amount: vec![claimed.amountOfETH.to_scaled_rational(18)],
+        })
+    }
+);
+
+action_impl!(
+    Protocol::Lido,
+    crate::LidoWithdrawalQueue::claimWithdrawalsCall,
+    Collect,
+    [..WithdrawalClaimed*],
+    logs: true,
+    include_delegated_logs: true,
+    |
+    info: CallInfo,
+    log_data: LidoClaimWithdrawalsCallLogs,
+    _| {
+        let claimed = log_data.withdrawal_claimed_field?;
+        collect_claims(info, &claimed)
+    }
+);
+
+action_impl!(
+    Protocol::Lido,
+    crate::LidoWithdrawalQueue::claimWithdrawalsToCall,
+    Collect,
+    [..WithdrawalClaimed*],
+    logs: true,
+    include_delegated_logs: true,
+    |
+    info: CallInfo,
+    log_data: LidoClaimWithdrawalsToCallLogs,
+    _| {
+        let claimed = log_data.withdrawal_claimed_field?;
+        collect_claims(info, &claimed)
+    }
+);
+
+fn collect_claims(
+    info: CallInfo,
+    claimed: &[crate::LidoWithdrawalQueue::WithdrawalClaimed],
+) -> eyre::Result<NormalizedCollect> {
+    let recipient = claimed
+        .first()
+        .map(|claim| claim.receiver)
+        .ok_or_else(|| eyre::eyre!("withdrawal claim without a claimed request"))?;
+
+    let amount = claimed
+        .iter()
+        .map(|claim| claim.amountOfETH.to_scaled_rational(18))
+        .sum::<Rational>();
+
+    Ok(NormalizedCollect {
+        protocol: Protocol::Lido,
+        trace_index: info.trace_idx,
+        from: info.msg_sender,
+        recipient,
+        pool: info.target_address,
+        token: vec![TokenInfoWithAddress::native_eth()],
+        amount: vec![amount],
+    })
+}
+
+fn steth_governance(info: &CallInfo, action: GovernanceAction) -> NormalizedGovernance {
+    NormalizedGovernance {
+        protocol: Protocol::Lido,
+        trace_index: info.trace_idx,
+        action,
+        target: info.target_address,
+        from: info.msg_sender,
+    }
+}
+
+// Stopping the protocol pauses stETH transfers as well as staking
+action_impl!(Protocol::Lido, crate::Lido::stopCall, Governance, [], |info: CallInfo, _| {
+    Ok(steth_governance(&info, GovernanceAction::Pause))
+});
+
+action_impl!(Protocol::Lido, crate::Lido::resumeCall, Governance, [], |info: CallInfo, _| {
+    Ok(steth_governance(&info, GovernanceAction::Unpause))
+});
+
+action_impl!(Protocol::Lido, crate::Lido::pauseStakingCall, Governance, [], |info: CallInfo, _| {
+    Ok(steth_governance(&info, GovernanceAction::Pause))
+});
+
+action_impl!(
+    Protocol::Lido,
+    crate::Lido::resumeStakingCall,
+    Governance,
+    [],
+    |info: CallInfo, _| { Ok(steth_governance(&info, GovernanceAction::Unpause)) }
+);
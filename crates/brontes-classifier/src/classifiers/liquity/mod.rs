@@ -0,0 +1,111 @@
+use alloy_primitives::{hex, Address};
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{
+    db::token_info::TokenInfoWithAddress,
+    normalized_actions::{NormalizedLiquidation, NormalizedRedemption},
+    structured_trace::CallInfo,
+    ToScaledRational,
+};
+
+pub const LUSD_ADDRESS: Address = Address::new(hex!("5f98805A4E8be255a32880FDeC7F6728C6568bA0"));
+
+// Troves are liquidated against the stability pool, which takes on the debt and
+// the collateral. The liquidator only receives the gas compensation. A
+// `liquidate` is a batch of one, batches are reported as a single liquidation
+// of the first trove with the totals of the batch
+action_impl!(
+    Protocol::Liquity,
+    crate::LiquityTroveManager::liquidateCall,
+    Liquidation,
+    [..TroveLiquidated*, ..Liquidation],
+    call_data: true,
+    logs: true,
+    |
+    info: CallInfo,
+    call_data: liquidateCall,
+    log_data: LiquityLiquidateCallLogs,
+    db_tx: &DB| {
+        let logs = log_data.liquidation_field?;
+        let debt_asset = db_tx.try_fetch_token_info(LUSD_ADDRESS)?;
+
+        Ok(NormalizedLiquidation {
+            protocol: Protocol::Liquity,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            liquidator: info.msg_sender,
+            debtor: call_data._borrower,
+            collateral_asset: TokenInfoWithAddress::native_eth(),
+            debt_asset,
+            covered_debt: logs._liquidatedDebt.to_scaled_rational(18),
+            liquidated_collateral: logs._liquidatedColl.to_scaled_rational(18),
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::Liquity,
+    crate::LiquityTroveManager::batchLiquidateTrovesCall,
+    Liquidation,
+    [..TroveLiquidated*, ..Liquidation],
+    logs: true,
+    |
+    info: CallInfo,
+    log_data: LiquityBatchLiquidateTrovesCallLogs,
+    db_tx: &DB| {
+        let debtor = log_data
+            .trove_liquidated_field?
+            .first()
+            .map(|trove| trove._borrower)
+            .ok_or_else(|| eyre::eyre!("batch liquidation without a liquidated trove"))?;
+        let logs = log_data.liquidation_field?;
+        let debt_asset = db_tx.try_fetch_token_info(LUSD_ADDRESS)?;
+
+        Ok(NormalizedLiquidation {
+            protocol: Protocol::Liquity,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            liquidator: info.msg_sender,
+            debtor,
+            collateral_asset: TokenInfoWithAddress::native_eth(),
+            debt_asset,
+            covered_debt: logs._liquidatedDebt.to_scaled_rational(18),
+            liquidated_collateral: logs._liquidatedColl.to_scaled_rational(18),
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+// LUSD is always redeemable for a dollar of ETH from the riskiest troves, less
+// the redemption fee. The redeemer gets the ETH drawn minus the fee
+action_impl!(
+    Protocol::Liquity,
+    crate::LiquityTroveManager::redeemCollateralCall,
+    Redemption,
+    [..Redemption],
+    logs: true,
+    |
+    info: CallInfo,
+    log_data: LiquityRedeemCollateralCallLogs,
+    db_tx: &DB| {
+        let logs = log_data.redemption_field?;
+        let token_in = db_tx.try_fetch_token_info(LUSD_ADDRESS)?;
+
+        let eth_drawn = logs._ETHSent.to_scaled_rational(18);
+        let fee = logs._ETHFee.to_scaled_rational(18);
+
+        Ok(NormalizedRedemption {
+            protocol: Protocol::Liquity,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            redeemer: info.msg_sender,
+            token_in,
+            amount_in: logs._actualLUSDAmount.to_scaled_rational(18),
+            token_out: TokenInfoWithAddress::native_eth(),
+            amount_out: eth_drawn - &fee,
+            fee,
+            msg_value: info.msg_value,
+        })
+    }
+);
@@ -0,0 +1,229 @@
+use brontes_macros::action_impl;
+use brontes_types::{
+    normalized_actions::NormalizedLiquidation, structured_trace::CallInfo, Protocol,
+    ToScaledRational,
+};
+
+// Vat accounting units. Debt is a rad, collateral a wad no matter the decimals
+// of the gem and prices a ray
+const RAD: u8 = 45;
+const RAY: u8 = 27;
+const WAD: u8 = 18;
+
+// Liquidations 2.0 start when the Dog kicks off an auction on the Clipper of
+// the ilk, the keeper that barked gets the incentive in internal DAI. Clippers
+// are configured with DAI as token0 and the gem of the ilk as token1
+action_impl!(
+    Protocol::MakerClipper,
+    crate::MakerClipper::kickCall,
+    Liquidation,
+    [Kick],
+    logs: true,
+    |
+    info: CallInfo,
+    log_data: MakerClipperKickCallLogs,
+    db_tx: &DB| {
+        let logs = log_data.kick_field?;
+
+        let details = db_tx.get_protocol_details_at(info.target_address, info.block)?;
+        let debt_asset = db_tx.try_fetch_token_info(details.token0)?;
+        let collateral_asset = db_tx.try_fetch_token_info(details.token1)?;
+
+        Ok(NormalizedLiquidation {
+            protocol: Protocol::MakerClipper,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            liquidator: logs.kpr,
+            debtor: logs.usr,
+            collateral_asset,
+            debt_asset,
+            covered_debt: logs.tab.to_scaled_rational(RAD),
+            liquidated_collateral: logs.lot.to_scaled_rational(WAD),
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+// A take buys a slice of the lot at the current auction price. The event only
+// has what was paid, the slice of collateral is the DAI owed over the price
+action_impl!(
+    Protocol::MakerClipper,
+    crate::MakerClipper::takeCall,
+    Liquidation,
+    [..Take],
+    logs: true,
+    |
+    info: CallInfo,
+    log_data: MakerClipperTakeCallLogs,
+    db_tx: &DB| {
+        let logs = log_data.take_field?;
+        if logs.price.is_zero() {
+            eyre::bail!("clipper take at a zero price");
+        }
+
+        let details = db_tx.get_protocol_details_at(info.target_address, info.block)?;
+        let debt_asset = db_tx.try_fetch_token_info(details.token0)?;
+        let collateral_asset = db_tx.try_fetch_token_info(details.token1)?;
+
+        let covered_debt = logs.owe.to_scaled_rational(RAD);
+        let liquidated_collateral = &covered_debt / logs.price.to_scaled_rational(RAY);
+
+        Ok(NormalizedLiquidation {
+            protocol: Protocol::MakerClipper,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            liquidator: info.msg_sender,
+            debtor: logs.usr,
+            collateral_asset,
+            debt_asset,
+            covered_debt,
+            liquidated_collateral,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{Address, Bytes, Log, U256};
+    use alloy_sol_types::{SolCall, SolEvent};
+    use brontes_classifier::test_utils::ClassifierTestUtils;
+    use brontes_types::{
+        constants::DAI_ADDRESS,
+        db::token_info::{TokenInfo, TokenInfoWithAddress},
+        normalized_actions::Action,
+    };
+    use malachite::Rational;
+
+    use super::*;
+    use crate::MakerClipper::{kickCall, takeCall, Kick, Take};
+
+    const CLIPPER: Address = Address::repeat_byte(0xc1);
+    const KEEPER: Address = Address::repeat_byte(0x01);
+    const VAULT: Address = Address::repeat_byte(0x02);
+
+    fn dai() -> TokenInfoWithAddress {
+        TokenInfoWithAddress {
+            address: DAI_ADDRESS,
+            inner:   TokenInfo { decimals: 18, symbol: "DAI".to_string() },
+        }
+    }
+
+    fn setup(classifier_utils: &ClassifierTestUtils) {
+        classifier_utils.ensure_token(dai());
+        classifier_utils.ensure_token(TokenInfoWithAddress::weth());
+        classifier_utils.ensure_protocol(
+            Protocol::MakerClipper,
+            CLIPPER,
+            DAI_ADDRESS,
+            Some(TokenInfoWithAddress::weth().address),
+            None,
+            None,
+            None,
+            None,
+        );
+    }
+
+    fn units(amount: u64, decimals: u8) -> U256 {
+        U256::from(amount) * U256::from(10).pow(U256::from(decimals))
+    }
+
+    #[brontes_macros::test]
+    async fn test_clipper_kick() {
+        let classifier_utils = ClassifierTestUtils::new().await;
+        setup(&classifier_utils);
+
+        let (tab, lot) = (units(3_000, RAD), units(2, WAD));
+        let call_data = kickCall { tab, lot, usr: VAULT, kpr: KEEPER }.abi_encode();
+        let log = Log {
+            address: CLIPPER,
+            data:    Kick {
+                id: U256::from(1),
+                top: units(2_400, RAY),
+                tab,
+                lot,
+                usr: VAULT,
+                kpr: KEEPER,
+                coin: U256::ZERO,
+            }
+            .encode_log_data(),
+        };
+
+        // kicked by the dog, the keeper is the one that barked
+        let liquidation = classifier_utils
+            .dispatch_call(
+                CLIPPER,
+                Address::repeat_byte(0xd0),
+                call_data.into(),
+                Bytes::new(),
+                &[log],
+                0,
+            )
+            .expect("kick wasn't classified");
+
+        assert_eq!(
+            liquidation,
+            Action::Liquidation(NormalizedLiquidation {
+                protocol:              Protocol::MakerClipper,
+                trace_index:           0,
+                pool:                  CLIPPER,
+                liquidator:            KEEPER,
+                debtor:                VAULT,
+                collateral_asset:      TokenInfoWithAddress::weth(),
+                debt_asset:            dai(),
+                covered_debt:          Rational::from(3_000),
+                liquidated_collateral: Rational::from(2),
+                msg_value:             U256::ZERO,
+            })
+        );
+    }
+
+    #[brontes_macros::test]
+    async fn test_clipper_take() {
+        let classifier_utils = ClassifierTestUtils::new().await;
+        setup(&classifier_utils);
+
+        let call_data = takeCall {
+            id:   U256::from(1),
+            amt:  units(2, WAD),
+            max:  units(2_100, RAY),
+            who:  KEEPER,
+            data: Bytes::new(),
+        }
+        .abi_encode();
+        let log = Log {
+            address: CLIPPER,
+            data:    Take {
+                id:    U256::from(1),
+                max:   units(2_100, RAY),
+                price: units(2_000, RAY),
+                owe:   units(3_000, RAD),
+                tab:   U256::ZERO,
+                lot:   units(1, WAD) / U256::from(2),
+                usr:   VAULT,
+            }
+            .encode_log_data(),
+        };
+
+        let liquidation = classifier_utils
+            .dispatch_call(CLIPPER, KEEPER, call_data.into(), Bytes::new(), &[log], 0)
+            .expect("take wasn't classified");
+
+        // 3000 DAI owed at 2000 DAI per unit buys 1.5 of the lot
+        assert_eq!(
+            liquidation,
+            Action::Liquidation(NormalizedLiquidation {
+                protocol:              Protocol::MakerClipper,
+                trace_index:           0,
+                pool:                  CLIPPER,
+                liquidator:            KEEPER,
+                debtor:                VAULT,
+                collateral_asset:      TokenInfoWithAddress::weth(),
+                debt_asset:            dai(),
+                covered_debt:          Rational::from(3_000),
+                liquidated_collateral: Rational::from(3) / Rational::from(2),
+                msg_value:             U256::ZERO,
+            })
+        );
+    }
+}
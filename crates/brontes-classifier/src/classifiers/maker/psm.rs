@@ -27,7 +27,7 @@ action_impl!(
     db_tx: &DB| {
 
         // For the PSM, the token0 should always be set to DAI and token1 is the gem (USDC or USDP)
-        let details = db_tx.get_protocol_details(info.target_address)?;
+        let details = db_tx.get_protocol_details_at(info.target_address, info.block)?;
         let [token_0, token_1] = [details.token0, details.token1];
 
         let t0_info = db_tx.try_fetch_token_info(token_0)?;
@@ -74,7 +74,7 @@ action_impl!(
     log_data: MakerPSMSellGemCallLogs,
     db_tx: &DB| {
         // For the PSM, the token0 is DAI and token1 is the gem (USDC or USDP)
-        let details = db_tx.get_protocol_details(info.target_address)?;
+        let details = db_tx.get_protocol_details_at(info.target_address, info.block)?;
         let [token_0, token_1] = [details.token0, details.token1];
 
         let t0_info = db_tx.try_fetch_token_info(token_0)?;
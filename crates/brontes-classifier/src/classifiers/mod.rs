@@ -38,9 +38,53 @@ pub use oneinch::*;
 pub mod clipper;
 pub use clipper::*;
 
+pub mod bancor;
+pub use bancor::*;
+
 pub mod dodo;
 pub use dodo::*;
 
+pub mod fraxswap;
+pub use fraxswap::*;
+
+pub mod solidly;
+pub use solidly::*;
+
+pub mod algebra;
+pub use algebra::*;
+
+pub mod paraswap;
+pub use paraswap::*;
+
+pub mod hashflow;
+pub use hashflow::*;
+
+pub mod bebop;
+pub use bebop::*;
+
+pub mod weth;
+pub use weth::*;
+
+pub mod liquity;
+pub use liquity::*;
+
+pub mod morpho;
+pub use morpho::*;
+
+pub mod lido;
+pub use lido::*;
+
+pub mod convex;
+pub use convex::*;
+
+// fulfillBasicOrder_efficient_6GL6yc isn't camel case
+#[allow(non_camel_case_types)]
+pub mod seaport;
+pub use seaport::*;
+
+pub mod blur;
+pub use blur::*;
+
 discovery_dispatch!(
     DiscoveryClassifier,
     SushiSwapV2Discovery,
@@ -63,7 +107,14 @@ discovery_dispatch!(
     CurveCryptoSwapDiscovery,
     CurveTriCryptoDiscovery,
     BalancerV1CoreDiscovery,
-    BalancerV1SmartPoolDiscovery
+    BalancerV1SmartPoolDiscovery,
+    BalancerV2WeightedPoolDiscovery,
+    BalancerV2ComposableStablePoolDiscovery,
+    FraxswapDiscovery,
+    FraxswapWithFeeDiscovery,
+    CurveV2GaugeDiscovery,
+    CurvecrvUSDGaugeDiscovery,
+    CurveTriCryptoGaugeDiscovery
 );
 
 action_dispatch!(
@@ -81,6 +132,7 @@ action_dispatch!(
     UniswapV3MintCall,
     UniswapV3BurnCall,
     UniswapV3CollectCall,
+    UniswapV3FlashCall,
     SushiSwapV3SwapCall,
     SushiSwapV3MintCall,
     SushiSwapV3BurnCall,
@@ -93,6 +145,11 @@ action_dispatch!(
     UniswapXExecuteBatchCall,
     UniswapXExecuteBatchWithCallbackCall,
     UniswapXExecuteWithCallbackCall,
+    UniswapUniversalRouterExecute_0Call,
+    UniswapUniversalRouterExecute_1Call,
+    UniswapSwapRouter02Multicall_0Call,
+    UniswapSwapRouter02Multicall_1Call,
+    UniswapSwapRouter02Multicall_2Call,
     CurveBasePool2ExchangeCall,
     CurveBasePool3ExchangeCall,
     CurveBasePool4ExchangeCall,
@@ -142,14 +199,38 @@ action_dispatch!(
     CurveV2PlainPoolImplRemove_liquidity_imbalance_1Call,
     CurveV2PlainPoolImplRemove_liquidity_one_coin_0Call,
     CurveV2PlainPoolImplRemove_liquidity_one_coin_1Call,
+    CurveTriCryptoPoolExchange_0Call,
+    CurveTriCryptoPoolExchange_1Call,
+    CurveTriCryptoPoolExchange_2Call,
+    CurveTriCryptoPoolExchange_underlying_0Call,
+    CurveTriCryptoPoolExchange_underlying_1Call,
     MakerPSMBuyGemCall,
     MakerPSMSellGemCall,
     MakerDssFlashFlashLoanCall,
+    MakerClipperKickCall,
+    MakerClipperTakeCall,
     AaveV2LiquidationCallCall,
     AaveV3LiquidationCallCall,
     AaveV2FlashLoanCall,
     AaveV3FlashLoanCall,
     AaveV3FlashLoanSimpleCall,
+    AaveV2DepositCall,
+    AaveV2WithdrawCall,
+    AaveV2BorrowCall,
+    AaveV2RepayCall,
+    AaveV3SupplyCall,
+    AaveV3DepositCall,
+    AaveV3WithdrawCall,
+    AaveV3BorrowCall,
+    AaveV3RepayCall,
+    SparkLiquidationCallCall,
+    SparkFlashLoanCall,
+    SparkFlashLoanSimpleCall,
+    SparkSupplyCall,
+    SparkDepositCall,
+    SparkWithdrawCall,
+    SparkBorrowCall,
+    SparkRepayCall,
     BalancerV1SwapExactAmountInCall,
     BalancerV1SwapExactAmountOutCall,
     BalancerV1BindCall,
@@ -159,9 +240,23 @@ action_dispatch!(
     BalancerV2JoinPoolCall,
     BalancerV2ExitPoolCall,
     BalancerV2RegisterTokensCall,
+    BalancerV2SetPausedCall,
     CompoundV2LiquidateBorrowCall,
     CompoundV2Initialize_0Call,
     CompoundV2Initialize_1Call,
+    CompoundV3SupplyCall,
+    CompoundV3WithdrawCall,
+    CompoundV3AbsorbCall,
+    CompoundV3BuyCollateralCall,
+    CompoundV3PauseCall,
+    LiquityLiquidateCall,
+    LiquityBatchLiquidateTrovesCall,
+    LiquityRedeemCollateralCall,
+    MorphoBlueCreateMarketCall,
+    MorphoBlueSupplyCall,
+    MorphoBlueBorrowCall,
+    MorphoBlueRepayCall,
+    MorphoBlueLiquidateCall,
     OneInchV5SwapCall,
     OneInchV5ClipperSwapCall,
     OneInchV5ClipperSwapToCall,
@@ -170,12 +265,74 @@ action_dispatch!(
     OneInchV5UnoswapToWithPermitCall,
     OneInchV5UniswapV3SwapToCall,
     OneInchV5UniswapV3SwapToWithPermitCall,
+    OneInchV6SwapCall,
+    OneInchV6UnoswapCall,
+    OneInchV6UnoswapToCall,
+    OneInchV6Unoswap2Call,
+    OneInchV6UnoswapTo2Call,
+    OneInchV6Unoswap3Call,
+    OneInchV6UnoswapTo3Call,
+    OneInchV6EthUnoswapCall,
+    OneInchV6EthUnoswapToCall,
+    OneInchV6EthUnoswap2Call,
+    OneInchV6EthUnoswapTo2Call,
+    OneInchV6EthUnoswap3Call,
+    OneInchV6EthUnoswapTo3Call,
+    OneInchV6ClipperSwapCall,
+    OneInchV6ClipperSwapToCall,
     OneInchFusionSettleOrdersCall,
     ClipperExchangeSwapCall,
     ClipperExchangeSellEthForTokenCall,
     ClipperExchangeSellTokenForEthCall,
+    ClipperExchangeSellTokenForTokenCall,
     ClipperExchangeTransmitAndSwapCall,
     ClipperExchangeTransmitAndSellTokenForEthCall,
+    HashflowTradeRFQTCall,
+    BebopSwapSingleCall,
+    BebopSwapMultiCall,
+    BebopSwapAggregateCall,
+    BebopSettleCall,
+    WethDepositCall,
+    WethWithdrawCall,
+    LidoSubmitCall,
+    LidoWrapCall,
+    LidoUnwrapCall,
+    LidoClaimWithdrawalCall,
+    LidoClaimWithdrawalsCall,
+    LidoClaimWithdrawalsToCall,
+    LidoStopCall,
+    LidoResumeCall,
+    LidoPauseStakingCall,
+    LidoResumeStakingCall,
+    CurveGaugeDeposit_0Call,
+    CurveGaugeDeposit_1Call,
+    CurveGaugeDeposit_2Call,
+    CurveGaugeWithdraw_0Call,
+    CurveGaugeWithdraw_1Call,
+    ConvexDepositCall,
+    ConvexDepositAllCall,
+    ConvexWithdrawCall,
+    ConvexWithdrawAllCall,
+    ConvexWithdrawToCall,
+    ConvexShutdownSystemCall,
+    ConvexShutdownPoolCall,
+    SeaportFulfillBasicOrderCall,
+    SeaportFulfillBasicOrder_efficient_6GL6ycCall,
+    SeaportFulfillOrderCall,
+    SeaportFulfillAdvancedOrderCall,
+    BlurExecuteCall,
+    Blur_executeCall,
+    BlurTakeAskSingleCall,
+    BlurTakeAskSinglePoolCall,
+    BlurTakeBidSingleCall,
+    BlurBlendBorrowCall,
+    BlurBlendRepayCall,
+    BlurBlendStartAuctionCall,
+    BlurBlendSeizeCall,
+    BlurBlendRefinanceCall,
+    BlurBlendRefinanceAuctionCall,
+    BancorV3TradeBySourceAmountCall,
+    BancorV3TradeByTargetAmountCall,
     CowswapSettleCall,
     CowswapSwapCall,
     ZeroXSellToUniswapCall,
@@ -201,12 +358,42 @@ action_dispatch!(
     ZeroXFillRfqOrderCall,
     ZeroXFillOrKillLimitOrderCall,
     ZeroXFillOrKillRfqOrderCall,
+    ZeroX_fillLimitOrderCall,
+    ZeroX_fillRfqOrderCall,
+    ZeroX_fillOtcOrderCall,
+    ZeroXBatchFillLimitOrdersCall,
+    ZeroXBatchFillRfqOrdersCall,
     DodoCreateDODOVendingMachineCall,
     DodoCreateDODOStablePoolCall,
     DodoInitDODOPrivatePoolCall,
+    DodoBreedDODOCall,
     DodoBuySharesCall,
     DodoSellSharesCall,
     DodoSellBaseCall,
     DodoSellQuoteCall,
-    DodoFlashLoanCall
+    DodoSellBaseTokenCall,
+    DodoBuyBaseTokenCall,
+    DodoFlashLoanCall,
+    FraxswapSwapCall,
+    FraxswapMintCall,
+    FraxswapBurnCall,
+    FraxswapLongTermSwapFrom0To1Call,
+    FraxswapLongTermSwapFrom1To0Call,
+    FraxswapCancelLongTermSwapCall,
+    FraxswapWithdrawProceedsFromLongTermSwapCall,
+    SolidlyV1SwapCall,
+    SolidlyV1MintCall,
+    SolidlyV1BurnCall,
+    SolidlyV2SwapCall,
+    SolidlyV2MintCall,
+    SolidlyV2BurnCall,
+    AlgebraV1SwapCall,
+    AlgebraV1SwapSupportingFeeOnInputTokensCall,
+    AlgebraV1MintCall,
+    AlgebraV1BurnCall,
+    AlgebraV1CollectCall,
+    ParaswapV5SimpleSwapCall,
+    ParaswapV5MultiSwapCall,
+    ParaswapV5MegaSwapCall,
+    ParaswapV5SwapOnUniswapV2ForkCall
 );
@@ -0,0 +1,168 @@
+use alloy_primitives::{Address, FixedBytes};
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{
+    normalized_actions::{
+        LendingAction, NormalizedLending, NormalizedLiquidation, NormalizedPoolConfigUpdate,
+    },
+    structured_trace::CallInfo,
+    ToScaledRational,
+};
+
+// Morpho Blue is a single contract holding every market, a market is identified
+// by the hash of its parameters. The markets are stored in the protocol details
+// under the address made of the first 20 bytes of their id, with the loan token
+// as token0 and the collateral token as token1. Markets are resolved once their
+// creation has been classified
+fn market_id_to_address(market_id: FixedBytes<32>) -> Address {
+    Address::from_slice(&market_id[0..20])
+}
+
+action_impl!(
+    Protocol::MorphoBlue,
+    crate::MorphoBlue::createMarketCall,
+    PoolConfigUpdate,
+    [..CreateMarket],
+    logs: true,
+    |info: CallInfo, log_data: MorphoBlueCreateMarketCallLogs, _| {
+        let logs = log_data.create_market_field?;
+
+        Ok(NormalizedPoolConfigUpdate {
+            trace_index: info.trace_idx,
+            protocol: Protocol::MorphoBlue,
+            pool_address: market_id_to_address(logs.id),
+            tokens: vec![logs.marketParams.loanToken, logs.marketParams.collateralToken],
+        })
+    }
+);
+
+action_impl!(
+    Protocol::MorphoBlue,
+    crate::MorphoBlue::supplyCall,
+    Lending,
+    [..Supply],
+    logs: true,
+    |
+    info: CallInfo,
+    log_data: MorphoBlueSupplyCallLogs,
+    db_tx: &DB | {
+        let logs = log_data.supply_field?;
+        let market = market_id_to_address(logs.id);
+
+        let details = db_tx.get_protocol_details(market)?;
+        let token = db_tx.try_fetch_token_info(details.token0)?;
+        let amount = logs.assets.to_scaled_rational(token.decimals);
+
+        Ok(NormalizedLending {
+            protocol: Protocol::MorphoBlue,
+            trace_index: info.trace_idx,
+            action: LendingAction::Deposit,
+            market,
+            from: logs.caller,
+            account: logs.onBehalf,
+            token,
+            amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::MorphoBlue,
+    crate::MorphoBlue::borrowCall,
+    Lending,
+    [..Borrow],
+    logs: true,
+    |
+    info: CallInfo,
+    log_data: MorphoBlueBorrowCallLogs,
+    db_tx: &DB | {
+        let logs = log_data.borrow_field?;
+        let market = market_id_to_address(logs.id);
+
+        let details = db_tx.get_protocol_details(market)?;
+        let token = db_tx.try_fetch_token_info(details.token0)?;
+        let amount = logs.assets.to_scaled_rational(token.decimals);
+
+        Ok(NormalizedLending {
+            protocol: Protocol::MorphoBlue,
+            trace_index: info.trace_idx,
+            action: LendingAction::Borrow,
+            market,
+            from: logs.caller,
+            account: logs.onBehalf,
+            token,
+            amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::MorphoBlue,
+    crate::MorphoBlue::repayCall,
+    Lending,
+    [..Repay],
+    logs: true,
+    |
+    info: CallInfo,
+    log_data: MorphoBlueRepayCallLogs,
+    db_tx: &DB | {
+        let logs = log_data.repay_field?;
+        let market = market_id_to_address(logs.id);
+
+        let details = db_tx.get_protocol_details(market)?;
+        let token = db_tx.try_fetch_token_info(details.token0)?;
+        let amount = logs.assets.to_scaled_rational(token.decimals);
+
+        Ok(NormalizedLending {
+            protocol: Protocol::MorphoBlue,
+            trace_index: info.trace_idx,
+            action: LendingAction::Repay,
+            market,
+            from: logs.caller,
+            account: logs.onBehalf,
+            token,
+            amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+// The liquidator repays the debt in the loan token and seizes the collateral
+// with the liquidation incentive of the market. Bad debt that is socialized to
+// the suppliers isn't part of the covered debt
+action_impl!(
+    Protocol::MorphoBlue,
+    crate::MorphoBlue::liquidateCall,
+    Liquidation,
+    [..Liquidate],
+    logs: true,
+    |
+    info: CallInfo,
+    log_data: MorphoBlueLiquidateCallLogs,
+    db_tx: &DB | {
+        let logs = log_data.liquidate_field?;
+        let market = market_id_to_address(logs.id);
+
+        let details = db_tx.get_protocol_details(market)?;
+        let debt_asset = db_tx.try_fetch_token_info(details.token0)?;
+        let collateral_asset = db_tx.try_fetch_token_info(details.token1)?;
+
+        let covered_debt = logs.repaidAssets.to_scaled_rational(debt_asset.decimals);
+        let liquidated_collateral = logs.seizedAssets.to_scaled_rational(collateral_asset.decimals);
+
+        Ok(NormalizedLiquidation {
+            protocol: Protocol::MorphoBlue,
+            trace_index: info.trace_idx,
+            pool: market,
+            liquidator: logs.caller,
+            debtor: logs.borrower,
+            collateral_asset,
+            debt_asset,
+            covered_debt,
+            liquidated_collateral,
+            msg_value: info.msg_value,
+        })
+    }
+);
@@ -1,5 +1,7 @@
 mod one_inch_aggregation_router_v5;
+mod one_inch_aggregation_router_v6;
 mod one_inch_fusion;
 
 pub use one_inch_aggregation_router_v5::*;
+pub use one_inch_aggregation_router_v6::*;
 pub use one_inch_fusion::*;
@@ -0,0 +1,320 @@
+//! The v6 router packs the recipient, tokens and pools of the unoswap
+//! entrypoints into `uint256` words, with the address in the low 20 bytes.
+use alloy_primitives::{Address, B256, U256};
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{normalized_actions::NormalizedAggregator, structured_trace::CallInfo};
+
+action_impl!(
+    Protocol::OneInchV6,
+    crate::OneInchAggregationRouterV6::swapCall,
+    Aggregator,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: swapCall,
+    _db_tx: &DB | {
+        let recipient = call_data.desc.dstReceiver;
+        return Ok(NormalizedAggregator {
+            protocol: Protocol::OneInchV6,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient,
+            child_actions: vec![],
+            msg_value: info.msg_value
+        })
+    }
+);
+
+action_impl!(
+    Protocol::OneInchV6,
+    crate::OneInchAggregationRouterV6::unoswapCall,
+    Aggregator,
+    [],
+    |info: CallInfo, _db_tx: &DB| {
+        return Ok(NormalizedAggregator {
+            protocol:      Protocol::OneInchV6,
+            trace_index:   info.trace_idx,
+            from:          info.from_address,
+            to:            info.target_address,
+            recipient:     info.msg_sender,
+            child_actions: vec![],
+            msg_value:     info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::OneInchV6,
+    crate::OneInchAggregationRouterV6::unoswapToCall,
+    Aggregator,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: unoswapToCall,
+    _db_tx: &DB | {
+        let recipient = address_from_word(call_data.to);
+        return Ok(NormalizedAggregator {
+            protocol: Protocol::OneInchV6,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient,
+            child_actions: vec![],
+            msg_value: info.msg_value
+        })
+    }
+);
+
+action_impl!(
+    Protocol::OneInchV6,
+    crate::OneInchAggregationRouterV6::unoswap2Call,
+    Aggregator,
+    [],
+    |info: CallInfo, _db_tx: &DB| {
+        return Ok(NormalizedAggregator {
+            protocol:      Protocol::OneInchV6,
+            trace_index:   info.trace_idx,
+            from:          info.from_address,
+            to:            info.target_address,
+            recipient:     info.msg_sender,
+            child_actions: vec![],
+            msg_value:     info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::OneInchV6,
+    crate::OneInchAggregationRouterV6::unoswapTo2Call,
+    Aggregator,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: unoswapTo2Call,
+    _db_tx: &DB | {
+        let recipient = address_from_word(call_data.to);
+        return Ok(NormalizedAggregator {
+            protocol: Protocol::OneInchV6,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient,
+            child_actions: vec![],
+            msg_value: info.msg_value
+        })
+    }
+);
+
+action_impl!(
+    Protocol::OneInchV6,
+    crate::OneInchAggregationRouterV6::unoswap3Call,
+    Aggregator,
+    [],
+    |info: CallInfo, _db_tx: &DB| {
+        return Ok(NormalizedAggregator {
+            protocol:      Protocol::OneInchV6,
+            trace_index:   info.trace_idx,
+            from:          info.from_address,
+            to:            info.target_address,
+            recipient:     info.msg_sender,
+            child_actions: vec![],
+            msg_value:     info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::OneInchV6,
+    crate::OneInchAggregationRouterV6::unoswapTo3Call,
+    Aggregator,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: unoswapTo3Call,
+    _db_tx: &DB | {
+        let recipient = address_from_word(call_data.to);
+        return Ok(NormalizedAggregator {
+            protocol: Protocol::OneInchV6,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient,
+            child_actions: vec![],
+            msg_value: info.msg_value
+        })
+    }
+);
+
+action_impl!(
+    Protocol::OneInchV6,
+    crate::OneInchAggregationRouterV6::ethUnoswapCall,
+    Aggregator,
+    [],
+    |info: CallInfo, _db_tx: &DB| {
+        return Ok(NormalizedAggregator {
+            protocol:      Protocol::OneInchV6,
+            trace_index:   info.trace_idx,
+            from:          info.from_address,
+            to:            info.target_address,
+            recipient:     info.msg_sender,
+            child_actions: vec![],
+            msg_value:     info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::OneInchV6,
+    crate::OneInchAggregationRouterV6::ethUnoswapToCall,
+    Aggregator,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: ethUnoswapToCall,
+    _db_tx: &DB | {
+        let recipient = address_from_word(call_data.to);
+        return Ok(NormalizedAggregator {
+            protocol: Protocol::OneInchV6,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient,
+            child_actions: vec![],
+            msg_value: info.msg_value
+        })
+    }
+);
+
+action_impl!(
+    Protocol::OneInchV6,
+    crate::OneInchAggregationRouterV6::ethUnoswap2Call,
+    Aggregator,
+    [],
+    |info: CallInfo, _db_tx: &DB| {
+        return Ok(NormalizedAggregator {
+            protocol:      Protocol::OneInchV6,
+            trace_index:   info.trace_idx,
+            from:          info.from_address,
+            to:            info.target_address,
+            recipient:     info.msg_sender,
+            child_actions: vec![],
+            msg_value:     info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::OneInchV6,
+    crate::OneInchAggregationRouterV6::ethUnoswapTo2Call,
+    Aggregator,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: ethUnoswapTo2Call,
+    _db_tx: &DB | {
+        let recipient = address_from_word(call_data.to);
+        return Ok(NormalizedAggregator {
+            protocol: Protocol::OneInchV6,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient,
+            child_actions: vec![],
+            msg_value: info.msg_value
+        })
+    }
+);
+
+action_impl!(
+    Protocol::OneInchV6,
+    crate::OneInchAggregationRouterV6::ethUnoswap3Call,
+    Aggregator,
+    [],
+    |info: CallInfo, _db_tx: &DB| {
+        return Ok(NormalizedAggregator {
+            protocol:      Protocol::OneInchV6,
+            trace_index:   info.trace_idx,
+            from:          info.from_address,
+            to:            info.target_address,
+            recipient:     info.msg_sender,
+            child_actions: vec![],
+            msg_value:     info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::OneInchV6,
+    crate::OneInchAggregationRouterV6::ethUnoswapTo3Call,
+    Aggregator,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: ethUnoswapTo3Call,
+    _db_tx: &DB | {
+        let recipient = address_from_word(call_data.to);
+        return Ok(NormalizedAggregator {
+            protocol: Protocol::OneInchV6,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient,
+            child_actions: vec![],
+            msg_value: info.msg_value
+        })
+    }
+);
+
+action_impl!(
+    Protocol::OneInchV6,
+    crate::OneInchAggregationRouterV6::clipperSwapCall,
+    Aggregator,
+    [],
+    |info: CallInfo, _db_tx: &DB| {
+        return Ok(NormalizedAggregator {
+            protocol:      Protocol::OneInchV6,
+            trace_index:   info.trace_idx,
+            from:          info.from_address,
+            to:            info.target_address,
+            recipient:     info.msg_sender,
+            child_actions: vec![],
+            msg_value:     info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::OneInchV6,
+    crate::OneInchAggregationRouterV6::clipperSwapToCall,
+    Aggregator,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: clipperSwapToCall,
+    _db_tx: &DB | {
+        let recipient = call_data.recipient;
+        return Ok(NormalizedAggregator {
+            protocol: Protocol::OneInchV6,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient,
+            child_actions: vec![],
+            msg_value: info.msg_value
+        })
+    }
+);
+
+fn address_from_word(word: U256) -> Address {
+    Address::from_word(B256::from(word))
+}
@@ -0,0 +1,3 @@
+mod paraswap_v5;
+
+pub use paraswap_v5::*;
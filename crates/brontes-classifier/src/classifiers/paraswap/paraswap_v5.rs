@@ -0,0 +1,170 @@
+//! Paraswap's Augustus swapper routes every swap through the router contract
+//! registered for the selector, so the swaps are filled in by the aggregator
+//! multi frame classifier from the calls the router makes.
+use alloy_primitives::Address;
+use brontes_macros::action_impl;
+use brontes_types::{
+    normalized_actions::NormalizedAggregator, structured_trace::CallInfo, Protocol,
+};
+
+action_impl!(
+    Protocol::ParaswapV5,
+    crate::ParaswapAugustusV5::simpleSwapCall,
+    Aggregator,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: simpleSwapCall, _| {
+        Ok(paraswap_aggregator(&info, call_data.data.beneficiary))
+    }
+);
+
+action_impl!(
+    Protocol::ParaswapV5,
+    crate::ParaswapAugustusV5::multiSwapCall,
+    Aggregator,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: multiSwapCall, _| {
+        Ok(paraswap_aggregator(&info, call_data.data.beneficiary))
+    }
+);
+
+action_impl!(
+    Protocol::ParaswapV5,
+    crate::ParaswapAugustusV5::megaSwapCall,
+    Aggregator,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: megaSwapCall, _| {
+        Ok(paraswap_aggregator(&info, call_data.data.beneficiary))
+    }
+);
+
+action_impl!(
+    Protocol::ParaswapV5,
+    crate::ParaswapAugustusV5::swapOnUniswapV2ForkCall,
+    Aggregator,
+    [],
+    |info: CallInfo, _| { Ok(paraswap_aggregator(&info, Address::ZERO)) }
+);
+
+/// A zero beneficiary has the output sent back to the caller
+fn paraswap_aggregator(info: &CallInfo, beneficiary: Address) -> NormalizedAggregator {
+    let recipient = if beneficiary.is_zero() { info.msg_sender } else { beneficiary };
+
+    NormalizedAggregator {
+        protocol: Protocol::ParaswapV5,
+        trace_index: info.trace_idx,
+        from: info.from_address,
+        to: info.target_address,
+        recipient,
+        child_actions: vec![],
+        msg_value: info.msg_value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{hex, Bytes, FixedBytes, U256};
+    use alloy_sol_types::SolCall;
+    use brontes_classifier::test_utils::ClassifierTestUtils;
+    use brontes_types::normalized_actions::Action;
+
+    use super::*;
+    use crate::ParaswapAugustusV5::{simpleSwapCall, swapOnUniswapV2ForkCall, SimpleData};
+
+    const AUGUSTUS: Address = Address::new(hex!("DEF171Fe48CF0115B1d80b88dc8eAB59176FEe57"));
+    const USER: Address = Address::repeat_byte(0x01);
+
+    fn simple_swap(beneficiary: Address) -> Bytes {
+        simpleSwapCall {
+            data: SimpleData {
+                fromToken: Address::repeat_byte(0x10),
+                toToken: Address::repeat_byte(0x11),
+                fromAmount: U256::from(1_000),
+                toAmount: U256::from(990),
+                expectedAmount: U256::from(1_000),
+                callees: vec![],
+                exchangeData: Bytes::new(),
+                startIndexes: vec![],
+                values: vec![],
+                beneficiary,
+                partner: Address::ZERO,
+                feePercent: U256::ZERO,
+                permit: Bytes::new(),
+                deadline: U256::MAX,
+                uuid: FixedBytes::ZERO,
+            },
+        }
+        .abi_encode()
+        .into()
+    }
+
+    fn aggregator(recipient: Address) -> Action {
+        Action::Aggregator(NormalizedAggregator {
+            protocol: Protocol::ParaswapV5,
+            trace_index: 0,
+            from: USER,
+            to: AUGUSTUS,
+            recipient,
+            child_actions: vec![],
+            msg_value: U256::ZERO,
+        })
+    }
+
+    #[brontes_macros::test]
+    async fn test_paraswap_simple_swap() {
+        let classifier_utils = ClassifierTestUtils::new().await;
+        classifier_utils.ensure_protocol(
+            Protocol::ParaswapV5,
+            AUGUSTUS,
+            Address::ZERO,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+
+        // a zero beneficiary sends the output back to the caller
+        let to_caller = classifier_utils
+            .dispatch_call(AUGUSTUS, USER, simple_swap(Address::ZERO), Bytes::new(), &[], 0)
+            .expect("simpleSwap wasn't classified");
+        assert_eq!(to_caller, aggregator(USER));
+
+        let beneficiary = Address::repeat_byte(0x02);
+        let to_beneficiary = classifier_utils
+            .dispatch_call(AUGUSTUS, USER, simple_swap(beneficiary), Bytes::new(), &[], 0)
+            .expect("simpleSwap wasn't classified");
+        assert_eq!(to_beneficiary, aggregator(beneficiary));
+    }
+
+    #[brontes_macros::test]
+    async fn test_paraswap_swap_on_uniswap_v2_fork() {
+        let classifier_utils = ClassifierTestUtils::new().await;
+        classifier_utils.ensure_protocol(
+            Protocol::ParaswapV5,
+            AUGUSTUS,
+            Address::ZERO,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+
+        let call_data = swapOnUniswapV2ForkCall {
+            tokenIn:      Address::repeat_byte(0x10),
+            amountIn:     U256::from(1_000),
+            amountOutMin: U256::from(990),
+            weth:         Address::repeat_byte(0x11),
+            pools:        vec![U256::from(1)],
+        }
+        .abi_encode();
+
+        let swap = classifier_utils
+            .dispatch_call(AUGUSTUS, USER, call_data.into(), Bytes::new(), &[], 0)
+            .expect("swapOnUniswapV2Fork wasn't classified");
+        assert_eq!(swap, aggregator(USER));
+    }
+}
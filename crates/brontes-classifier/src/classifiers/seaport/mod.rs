@@ -0,0 +1,173 @@
+//! Seaport 1.5 and 1.6 share the same fulfillment functions and both emit
+//! `OrderFulfilled` with the items that actually changed hands, after partial
+//! fills and criteria are resolved. A trade is built from the event, so the
+//! order parameters in the call data don't have to be decoded.
+//!
+//! Only orders trading a single nft for one payment token are classified,
+//! orders bundling several nfts or mixing payment tokens are left unclassified.
+use alloy_primitives::{Address, U256};
+use brontes_database::libmdbx::LibmdbxReader;
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{
+    db::token_info::TokenInfoWithAddress,
+    normalized_actions::{NftOrderSide, NormalizedNftTrade},
+    structured_trace::CallInfo,
+    ToScaledRational,
+};
+use malachite::Rational;
+
+use crate::Seaport::{OrderFulfilled, ReceivedItem, SpentItem};
+
+action_impl!(
+    Protocol::Seaport,
+    crate::Seaport::fulfillBasicOrderCall,
+    NftTrade,
+    [..OrderFulfilled],
+    logs: true,
+    |info: CallInfo, log_data: SeaportFulfillBasicOrderCallLogs, db_tx: &DB| {
+        nft_trade(&info, log_data.order_fulfilled_field?, db_tx)
+    }
+);
+
+action_impl!(
+    Protocol::Seaport,
+    crate::Seaport::fulfillBasicOrder_efficient_6GL6ycCall,
+    NftTrade,
+    [..OrderFulfilled],
+    logs: true,
+    |
+    info: CallInfo,
+    log_data: SeaportFulfillBasicOrder_efficient_6GL6ycCallLogs,
+    db_tx: &DB| {
+        nft_trade(&info, log_data.order_fulfilled_field?, db_tx)
+    }
+);
+
+action_impl!(
+    Protocol::Seaport,
+    crate::Seaport::fulfillOrderCall,
+    NftTrade,
+    [..OrderFulfilled],
+    logs: true,
+    |info: CallInfo, log_data: SeaportFulfillOrderCallLogs, db_tx: &DB| {
+        nft_trade(&info, log_data.order_fulfilled_field?, db_tx)
+    }
+);
+
+action_impl!(
+    Protocol::Seaport,
+    crate::Seaport::fulfillAdvancedOrderCall,
+    NftTrade,
+    [..OrderFulfilled],
+    logs: true,
+    |info: CallInfo, log_data: SeaportFulfillAdvancedOrderCallLogs, db_tx: &DB| {
+        nft_trade(&info, log_data.order_fulfilled_field?, db_tx)
+    }
+);
+
+/// Seaport's `ItemType`
+const NATIVE: u8 = 0;
+const ERC20: u8 = 1;
+
+/// An offer or consideration item of a fulfilled order
+struct Item {
+    item_type:  u8,
+    token:      Address,
+    identifier: U256,
+    amount:     U256,
+}
+
+impl Item {
+    fn is_payment(&self) -> bool {
+        self.item_type == NATIVE || self.item_type == ERC20
+    }
+}
+
+impl From<&SpentItem> for Item {
+    fn from(item: &SpentItem) -> Self {
+        Self {
+            item_type:  item.itemType,
+            token:      item.token,
+            identifier: item.identifier,
+            amount:     item.amount,
+        }
+    }
+}
+
+impl From<&ReceivedItem> for Item {
+    fn from(item: &ReceivedItem) -> Self {
+        Self {
+            item_type:  item.itemType,
+            token:      item.token,
+            identifier: item.identifier,
+            amount:     item.amount,
+        }
+    }
+}
+
+/// The offerer signed the order and is the maker. A listing offers the nft
+/// and the buyer pays every consideration item, the seller's proceeds and the
+/// fees. An offer (bid) offers the payment, the fees are paid out of it
+fn nft_trade<DB: LibmdbxReader>(
+    info: &CallInfo,
+    order: OrderFulfilled,
+    db_tx: &DB,
+) -> eyre::Result<NormalizedNftTrade> {
+    let offer = order.offer.iter().map(Item::from).collect::<Vec<_>>();
+    let consideration = order
+        .consideration
+        .iter()
+        .map(Item::from)
+        .collect::<Vec<_>>();
+
+    let (side, nfts, payments) = if offer.iter().any(|item| !item.is_payment()) {
+        (NftOrderSide::Ask, offer, consideration)
+    } else {
+        (NftOrderSide::Bid, consideration, offer)
+    };
+
+    let [nft] = nfts
+        .into_iter()
+        .filter(|item| !item.is_payment())
+        .collect::<Vec<_>>()
+        .try_into()
+        .map_err(|_| eyre::eyre!("seaport order doesn't trade a single nft"))?;
+
+    let payments = payments
+        .into_iter()
+        .filter(Item::is_payment)
+        .collect::<Vec<_>>();
+    let Some(first) = payments.first() else { eyre::bail!("seaport order without a payment") };
+    if payments
+        .iter()
+        .any(|p| p.item_type != first.item_type || p.token != first.token)
+    {
+        eyre::bail!("seaport order paid in more than one token")
+    }
+
+    let payment_token = if first.item_type == NATIVE {
+        TokenInfoWithAddress::native_eth()
+    } else {
+        db_tx.try_fetch_token_info(first.token)?
+    };
+    let price = payments
+        .iter()
+        .map(|p| p.amount.to_scaled_rational(payment_token.decimals))
+        .sum::<Rational>();
+
+    Ok(NormalizedNftTrade {
+        protocol: Protocol::Seaport,
+        trace_index: info.trace_idx,
+        marketplace: info.target_address,
+        side,
+        maker: order.offerer,
+        taker: order.recipient,
+        collection: nft.token,
+        token_id: nft.identifier,
+        amount: nft.amount,
+        payment_token,
+        price,
+        msg_value: info.msg_value,
+    })
+}
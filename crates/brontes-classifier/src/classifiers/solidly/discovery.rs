@@ -0,0 +1,92 @@
+use alloy_primitives::{Address, Log};
+use alloy_sol_types::SolEvent;
+use brontes_pricing::Protocol;
+use brontes_types::normalized_actions::pool::NormalizedNewPool;
+
+use crate::SolidlyFactory::{PairCreated, PoolCreated};
+
+/// Solidly forks (Velodrome, Aerodrome, ...) are deployed from many different
+/// factories, so instead of keying off a factory address we look for the pair
+/// creation event the factory emits in the frame that deployed the pair. The
+/// event signature also tells us which pair implementation was deployed:
+/// - `PairCreated` is emitted by Solidly / Velodrome V1 style factories.
+/// - `PoolCreated` is emitted by Velodrome V2 / Aerodrome style factories.
+///
+/// Stable and volatile pairs share the same implementation, so both are
+/// classified the same way.
+pub fn discover_solidly_pool(
+    deployed_address: Address,
+    trace_index: u64,
+    factory_logs: &[Log],
+) -> Option<NormalizedNewPool> {
+    factory_logs.iter().find_map(|log| {
+        if let Ok(created) = PairCreated::decode_log_data(&log.data, true) {
+            (created.pair == deployed_address).then(|| NormalizedNewPool {
+                trace_index,
+                protocol: Protocol::SolidlyV1,
+                pool_address: deployed_address,
+                tokens: vec![created.token0, created.token1],
+            })
+        } else if let Ok(created) = PoolCreated::decode_log_data(&log.data, true) {
+            (created.pool == deployed_address).then(|| NormalizedNewPool {
+                trace_index,
+                protocol: Protocol::SolidlyV2,
+                pool_address: deployed_address,
+                tokens: vec![created.token0, created.token1],
+            })
+        } else {
+            None
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::U256;
+
+    use super::*;
+
+    const TOKEN0: Address = Address::repeat_byte(0x10);
+    const TOKEN1: Address = Address::repeat_byte(0x11);
+    const PAIR: Address = Address::repeat_byte(0x20);
+
+    #[test]
+    fn test_discover_solidly_pool() {
+        let factory = Address::repeat_byte(0xfa);
+        let pair_created = Log {
+            address: factory,
+            data:    PairCreated {
+                token0: TOKEN0,
+                token1: TOKEN1,
+                stable: false,
+                pair:   PAIR,
+                length: U256::from(1),
+            }
+            .encode_log_data(),
+        };
+        let pool_created = Log {
+            address: factory,
+            data:    PoolCreated {
+                token0: TOKEN0,
+                token1: TOKEN1,
+                stable: true,
+                pool:   PAIR,
+                length: U256::from(1),
+            }
+            .encode_log_data(),
+        };
+
+        let v1 = discover_solidly_pool(PAIR, 3, &[pair_created.clone()]).unwrap();
+        assert_eq!(v1.protocol, Protocol::SolidlyV1);
+        assert_eq!(v1.pool_address, PAIR);
+        assert_eq!(v1.tokens, vec![TOKEN0, TOKEN1]);
+        assert_eq!(v1.trace_index, 3);
+
+        let v2 = discover_solidly_pool(PAIR, 3, &[pool_created]).unwrap();
+        assert_eq!(v2.protocol, Protocol::SolidlyV2);
+        assert_eq!(v2.tokens, vec![TOKEN0, TOKEN1]);
+
+        // the event of another pair created in the same frame
+        assert!(discover_solidly_pool(Address::repeat_byte(0x21), 3, &[pair_created]).is_none());
+    }
+}
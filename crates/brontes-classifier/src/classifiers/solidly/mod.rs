@@ -0,0 +1,7 @@
+mod discovery;
+mod solidly_v1;
+mod solidly_v2;
+
+pub use discovery::*;
+pub use solidly_v1::*;
+pub use solidly_v2::*;
@@ -0,0 +1,209 @@
+use alloy_primitives::U256;
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{
+    normalized_actions::{NormalizedBurn, NormalizedMint, NormalizedSwap},
+    structured_trace::CallInfo,
+    ToScaledRational,
+};
+
+action_impl!(
+    Protocol::SolidlyV1,
+    crate::SolidlyPair::swapCall,
+    Swap,
+    [..Swap],
+    call_data: true,
+    logs: true,
+    |
+    info: CallInfo,
+    call_data: swapCall,
+    logs: SolidlyV1SwapCallLogs,
+    db_tx: &DB| {
+        let logs = logs.swap_field?;
+
+        let recipient = call_data.to;
+        let details = db_tx.get_protocol_details_sorted_at(info.target_address, info.block)?;
+        let [token_0, token_1] = [details.token0, details.token1];
+
+        let t0_info = db_tx.try_fetch_token_info(token_0)?;
+        let t1_info = db_tx.try_fetch_token_info(token_1)?;
+
+        if logs.amount0In == U256::ZERO {
+            let amount_in = logs.amount1In.to_scaled_rational(t1_info.decimals);
+            let amount_out = logs.amount0Out.to_scaled_rational(t0_info.decimals);
+
+            return Ok(NormalizedSwap {
+                protocol: Protocol::SolidlyV1,
+                pool: info.target_address,
+                trace_index: info.trace_idx,
+                from: info.from_address,
+                recipient,
+                token_in: t1_info,
+                token_out: t0_info,
+                amount_in,
+                amount_out,
+                msg_value: info.msg_value
+            })
+        } else {
+            let amount_in = logs.amount0In.to_scaled_rational(t0_info.decimals);
+            let amount_out = logs.amount1Out.to_scaled_rational(t1_info.decimals);
+            return Ok(NormalizedSwap {
+                protocol: Protocol::SolidlyV1,
+                pool: info.target_address,
+                trace_index: info.trace_idx,
+                from: info.from_address,
+                recipient,
+                token_in: t0_info,
+                token_out: t1_info,
+                amount_in,
+                amount_out,
+                msg_value: info.msg_value
+            })
+        }
+    }
+);
+
+action_impl!(
+    Protocol::SolidlyV1,
+    crate::SolidlyPair::mintCall,
+    Mint,
+    // can be a double transfer if the pool has no liquidity
+    [..Mint],
+    logs: true,
+    call_data: true,
+    |
+        info: CallInfo,
+     call_data: mintCall,
+     log_data: SolidlyV1MintCallLogs,
+     db_tx: &DB| {
+        let log_data = log_data.mint_field?;
+
+        let details = db_tx.get_protocol_details_sorted_at(info.target_address, info.block)?;
+        let [token_0, token_1] = [details.token0, details.token1];
+
+        let t0_info = db_tx.try_fetch_token_info(token_0)?;
+        let t1_info = db_tx.try_fetch_token_info(token_1)?;
+
+        let am0 = log_data.amount0.to_scaled_rational(t0_info.decimals);
+        let am1 = log_data.amount1.to_scaled_rational(t1_info.decimals);
+
+        Ok(NormalizedMint {
+            protocol: Protocol::SolidlyV1,
+            recipient: call_data.to,
+            from: info.from_address,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            token: vec![t0_info, t1_info],
+            amount: vec![am0, am1],
+        })
+    }
+);
+
+action_impl!(
+    Protocol::SolidlyV1,
+    crate::SolidlyPair::burnCall,
+    Burn,
+    [..Burn],
+    call_data: true,
+    logs: true,
+    |
+     info: CallInfo,
+     call_data: burnCall,
+     log_data: SolidlyV1BurnCallLogs,
+     db_tx: &DB| {
+        let log_data = log_data.burn_field?;
+        let details = db_tx.get_protocol_details_sorted_at(info.target_address, info.block)?;
+        let [token_0, token_1] = [details.token0, details.token1];
+
+        let t0_info = db_tx.try_fetch_token_info(token_0)?;
+        let t1_info = db_tx.try_fetch_token_info(token_1)?;
+
+        let am0 = log_data.amount0.to_scaled_rational(t0_info.decimals);
+        let am1 = log_data.amount1.to_scaled_rational(t1_info.decimals);
+
+        Ok(NormalizedBurn {
+            protocol: Protocol::SolidlyV1,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            recipient: call_data.to,
+            pool: info.target_address,
+            token: vec![t0_info, t1_info],
+            amount: vec![am0, am1],
+        })
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{Address, Bytes, Log};
+    use alloy_sol_types::{SolCall, SolEvent};
+    use brontes_classifier::test_utils::ClassifierTestUtils;
+    use brontes_types::{db::token_info::TokenInfoWithAddress, normalized_actions::Action};
+
+    use super::*;
+    use crate::SolidlyPair::{swapCall, Swap};
+
+    #[brontes_macros::test]
+    async fn test_solidly_v1_swap() {
+        let classifier_utils = ClassifierTestUtils::new().await;
+        let pool = Address::repeat_byte(0x51);
+        let router = Address::repeat_byte(0x01);
+        let recipient = Address::repeat_byte(0x02);
+        let (usdc, weth) = (TokenInfoWithAddress::usdc(), TokenInfoWithAddress::weth());
+        classifier_utils.ensure_token(usdc.clone());
+        classifier_utils.ensure_token(weth.clone());
+        // registered unsorted, the pair orders its tokens by address
+        classifier_utils.ensure_protocol(
+            Protocol::SolidlyV1,
+            pool,
+            weth.address,
+            Some(usdc.address),
+            None,
+            None,
+            None,
+            None,
+        );
+
+        let weth_in = U256::from(1_000_000_000_000_000_000u128);
+        let usdc_out = U256::from(2_990_000_000u64);
+        let call_data = swapCall {
+            amount0Out: usdc_out,
+            amount1Out: U256::ZERO,
+            to:         recipient,
+            data:       Bytes::new(),
+        }
+        .abi_encode();
+        let log = Log {
+            address: pool,
+            data:    Swap {
+                sender:     router,
+                to:         recipient,
+                amount0In:  U256::ZERO,
+                amount1In:  weth_in,
+                amount0Out: usdc_out,
+                amount1Out: U256::ZERO,
+            }
+            .encode_log_data(),
+        };
+
+        let swap = classifier_utils
+            .dispatch_call(pool, router, call_data.into(), Bytes::new(), &[log], 0)
+            .expect("swap wasn't classified");
+
+        assert_eq!(
+            swap,
+            Action::Swap(NormalizedSwap {
+                protocol: Protocol::SolidlyV1,
+                pool,
+                trace_index: 0,
+                from: router,
+                recipient,
+                token_in: weth,
+                token_out: usdc,
+                amount_in: weth_in.to_scaled_rational(18),
+                amount_out: usdc_out.to_scaled_rational(6),
+                msg_value: U256::ZERO,
+            })
+        );
+    }
+}
@@ -22,7 +22,7 @@ action_impl!(
         let logs = logs.swap_field?;
 
         let recipient = call_data.to;
-        let details = db_tx.get_protocol_details_sorted(info.target_address)?;
+        let details = db_tx.get_protocol_details_sorted_at(info.target_address, info.block)?;
         let [token_0, token_1] = [details.token0, details.token1];
 
         let t0_info = db_tx.try_fetch_token_info(token_0)?;
@@ -78,7 +78,7 @@ action_impl!(
      db_tx: &DB| {
         let log_data = log_data.mint_field?;
 
-        let details = db_tx.get_protocol_details_sorted(info.target_address)?;
+        let details = db_tx.get_protocol_details_sorted_at(info.target_address, info.block)?;
         let [token_0, token_1] = [details.token0, details.token1];
 
         let t0_info = db_tx.try_fetch_token_info(token_0)?;
@@ -112,7 +112,7 @@ action_impl!(
      log_data: SushiSwapV2BurnCallLogs,
      db_tx: &DB| {
         let log_data = log_data.burn_field?;
-        let details = db_tx.get_protocol_details_sorted(info.target_address)?;
+        let details = db_tx.get_protocol_details_sorted_at(info.target_address, info.block)?;
         let [token_0, token_1] = [details.token0, details.token1];
 
         let t0_info = db_tx.try_fetch_token_info(token_0)?;
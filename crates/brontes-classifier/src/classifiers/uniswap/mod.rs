@@ -1,4 +1,6 @@
 mod discovery;
+#[allow(non_camel_case_types)]
+mod router;
 #[allow(non_snake_case)]
 mod uniswap_v2;
 #[allow(non_snake_case)]
@@ -7,6 +9,7 @@ mod uniswap_v3;
 mod uniswap_x;
 
 pub use discovery::*;
+pub use router::*;
 pub use uniswap_v2::*;
 pub use uniswap_v3::*;
 pub use uniswap_x::*;
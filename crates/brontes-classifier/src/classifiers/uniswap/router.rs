@@ -0,0 +1,376 @@
+//! The universal router and SwapRouter02 batch a route into a single call, as
+//! a sequence of commands or a multicall of router functions. The call is
+//! classified as an aggregator holding the pool swaps the calldata asks for, in
+//! the order of the route. They are placeholders until the multi frame
+//! classifier swaps them for the pool swaps found under the router call.
+use alloy_primitives::{Address, Bytes};
+use alloy_sol_types::{sol, SolInterface, SolType};
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{
+    db::token_info::TokenInfoWithAddress,
+    normalized_actions::{Action, NormalizedAggregator, NormalizedSwap},
+    structured_trace::CallInfo,
+};
+
+sol! {
+    /// The input of the v3 swap commands, the amount is the exact side of the
+    /// swap and the limit the other
+    struct V3SwapInput {
+        address recipient;
+        uint256 amount;
+        uint256 amountLimit;
+        bytes path;
+        bool payerIsUser;
+    }
+
+    struct V2SwapInput {
+        address recipient;
+        uint256 amount;
+        uint256 amountLimit;
+        address[] path;
+        bool payerIsUser;
+    }
+
+    struct SubPlanInput {
+        bytes commands;
+        bytes[] inputs;
+    }
+
+    interface ISwapRouter02 {
+        struct ExactInputSingleParams {
+            address tokenIn;
+            address tokenOut;
+            uint24 fee;
+            address recipient;
+            uint256 amountIn;
+            uint256 amountOutMinimum;
+            uint160 sqrtPriceLimitX96;
+        }
+        struct ExactInputParams {
+            bytes path;
+            address recipient;
+            uint256 amountIn;
+            uint256 amountOutMinimum;
+        }
+        struct ExactOutputSingleParams {
+            address tokenIn;
+            address tokenOut;
+            uint24 fee;
+            address recipient;
+            uint256 amountOut;
+            uint256 amountInMaximum;
+            uint160 sqrtPriceLimitX96;
+        }
+        struct ExactOutputParams {
+            bytes path;
+            address recipient;
+            uint256 amountOut;
+            uint256 amountInMaximum;
+        }
+
+        function swapExactTokensForTokens(uint256 amountIn, uint256 amountOutMin, address[] path, address to);
+        function swapTokensForExactTokens(uint256 amountOut, uint256 amountInMax, address[] path, address to);
+        function exactInputSingle(ExactInputSingleParams params);
+        function exactInput(ExactInputParams params);
+        function exactOutputSingle(ExactOutputSingleParams params);
+        function exactOutput(ExactOutputParams params);
+    }
+}
+
+action_impl!(
+    Protocol::UniswapUniversalRouter,
+    crate::UniswapUniversalRouter::execute_0Call,
+    Aggregator,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: execute_0Call, _| {
+        let hops = universal_router_hops(&call_data.commands, &call_data.inputs);
+        Ok(router_aggregator(Protocol::UniswapUniversalRouter, &info, hops))
+    }
+);
+
+action_impl!(
+    Protocol::UniswapUniversalRouter,
+    crate::UniswapUniversalRouter::execute_1Call,
+    Aggregator,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: execute_1Call, _| {
+        let hops = universal_router_hops(&call_data.commands, &call_data.inputs);
+        Ok(router_aggregator(Protocol::UniswapUniversalRouter, &info, hops))
+    }
+);
+
+action_impl!(
+    Protocol::UniswapSwapRouter02,
+    crate::UniswapSwapRouter02::multicall_0Call,
+    Aggregator,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: multicall_0Call, _| {
+        let hops = swap_router_02_hops(&call_data.data);
+        Ok(router_aggregator(Protocol::UniswapSwapRouter02, &info, hops))
+    }
+);
+
+action_impl!(
+    Protocol::UniswapSwapRouter02,
+    crate::UniswapSwapRouter02::multicall_1Call,
+    Aggregator,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: multicall_1Call, _| {
+        let hops = swap_router_02_hops(&call_data.data);
+        Ok(router_aggregator(Protocol::UniswapSwapRouter02, &info, hops))
+    }
+);
+
+action_impl!(
+    Protocol::UniswapSwapRouter02,
+    crate::UniswapSwapRouter02::multicall_2Call,
+    Aggregator,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: multicall_2Call, _| {
+        let hops = swap_router_02_hops(&call_data.data);
+        Ok(router_aggregator(Protocol::UniswapSwapRouter02, &info, hops))
+    }
+);
+
+fn router_aggregator(
+    protocol: Protocol,
+    info: &CallInfo,
+    hops: Vec<RouterHop>,
+) -> NormalizedAggregator {
+    NormalizedAggregator {
+        protocol,
+        trace_index: info.trace_idx,
+        from: info.from_address,
+        to: info.target_address,
+        recipient: info.msg_sender,
+        child_actions: hops.into_iter().map(RouterHop::into_action).collect(),
+        msg_value: info.msg_value,
+    }
+}
+
+/// A pool swap the router's calldata asks for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RouterHop {
+    pub protocol:  Protocol,
+    pub token_in:  Address,
+    pub token_out: Address,
+}
+
+impl RouterHop {
+    fn new(protocol: Protocol, token_in: Address, token_out: Address) -> Self {
+        Self { protocol, token_in, token_out }
+    }
+
+    /// Only the protocol and the tokens are known until the hop is matched to
+    /// the pool swap it made
+    fn into_action(self) -> Action {
+        Action::Swap(NormalizedSwap {
+            protocol: self.protocol,
+            token_in: TokenInfoWithAddress { address: self.token_in, ..Default::default() },
+            token_out: TokenInfoWithAddress { address: self.token_out, ..Default::default() },
+            ..Default::default()
+        })
+    }
+}
+
+const V3_SWAP_EXACT_IN: u8 = 0x00;
+const V3_SWAP_EXACT_OUT: u8 = 0x01;
+const V2_SWAP_EXACT_IN: u8 = 0x08;
+const V2_SWAP_EXACT_OUT: u8 = 0x09;
+const EXECUTE_SUB_PLAN: u8 = 0x21;
+/// the top bit of a command flags that it is allowed to revert
+const COMMAND_TYPE_MASK: u8 = 0x3f;
+
+/// Decodes the swap commands of a universal router call, sub plans are
+/// unrolled in place. Commands that don't swap or can't be decoded are skipped
+pub fn universal_router_hops(commands: &[u8], inputs: &[Bytes]) -> Vec<RouterHop> {
+    commands
+        .iter()
+        .zip(inputs)
+        .flat_map(|(command, input)| match command & COMMAND_TYPE_MASK {
+            V3_SWAP_EXACT_IN => V3SwapInput::abi_decode_params(input, false)
+                .map(|input| v3_path_hops(&input.path, false))
+                .unwrap_or_default(),
+            V3_SWAP_EXACT_OUT => V3SwapInput::abi_decode_params(input, false)
+                .map(|input| v3_path_hops(&input.path, true))
+                .unwrap_or_default(),
+            V2_SWAP_EXACT_IN | V2_SWAP_EXACT_OUT => V2SwapInput::abi_decode_params(input, false)
+                .map(|input| v2_path_hops(&input.path))
+                .unwrap_or_default(),
+            EXECUTE_SUB_PLAN => SubPlanInput::abi_decode_params(input, false)
+                .map(|plan| universal_router_hops(&plan.commands, &plan.inputs))
+                .unwrap_or_default(),
+            _ => vec![],
+        })
+        .collect()
+}
+
+/// Decodes the swaps of a SwapRouter02 multicall, calls that don't swap such
+/// as unwrapping weth or refunding eth are skipped
+pub fn swap_router_02_hops(data: &[Bytes]) -> Vec<RouterHop> {
+    use ISwapRouter02::ISwapRouter02Calls as Router;
+
+    data.iter()
+        .filter_map(|call| Router::abi_decode(call, false).ok())
+        .flat_map(|call| match call {
+            Router::swapExactTokensForTokens(c) => v2_path_hops(&c.path),
+            Router::swapTokensForExactTokens(c) => v2_path_hops(&c.path),
+            Router::exactInputSingle(c) => {
+                vec![RouterHop::new(Protocol::UniswapV3, c.params.tokenIn, c.params.tokenOut)]
+            }
+            Router::exactOutputSingle(c) => {
+                vec![RouterHop::new(Protocol::UniswapV3, c.params.tokenIn, c.params.tokenOut)]
+            }
+            Router::exactInput(c) => v3_path_hops(&c.params.path, false),
+            Router::exactOutput(c) => v3_path_hops(&c.params.path, true),
+        })
+        .collect()
+}
+
+/// The router swaps through every pair of the path in order, both for exact in
+/// and exact out swaps
+fn v2_path_hops(path: &[Address]) -> Vec<RouterHop> {
+    path.windows(2)
+        .map(|pair| RouterHop::new(Protocol::UniswapV2, pair[0], pair[1]))
+        .collect()
+}
+
+/// A v3 path is the first token followed by the fee and the next token of each
+/// hop. Exact out paths are encoded from the output token back, so they are
+/// reversed into the order of the route
+fn v3_path_hops(path: &[u8], exact_out: bool) -> Vec<RouterHop> {
+    if path.len() < 20 || (path.len() - 20) % 23 != 0 {
+        return vec![]
+    }
+
+    let tokens = path
+        .chunks(23)
+        .map(|chunk| Address::from_slice(&chunk[..20]))
+        .collect::<Vec<_>>();
+
+    if exact_out {
+        tokens
+            .windows(2)
+            .rev()
+            .map(|pair| RouterHop::new(Protocol::UniswapV3, pair[1], pair[0]))
+            .collect()
+    } else {
+        tokens
+            .windows(2)
+            .map(|pair| RouterHop::new(Protocol::UniswapV3, pair[0], pair[1]))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::U256;
+    use alloy_sol_types::{SolCall, SolValue};
+
+    use super::*;
+
+    const WETH: Address = Address::repeat_byte(1);
+    const USDC: Address = Address::repeat_byte(2);
+    const PEPE: Address = Address::repeat_byte(3);
+
+    fn v3_path(tokens: &[Address]) -> Bytes {
+        let mut path = tokens[0].to_vec();
+        for token in &tokens[1..] {
+            path.extend_from_slice(&[0, 0x0b, 0xb8]);
+            path.extend_from_slice(token.as_slice());
+        }
+        path.into()
+    }
+
+    fn v3_input(path: Bytes) -> Bytes {
+        V3SwapInput {
+            recipient: Address::ZERO,
+            amount: U256::from(1),
+            amountLimit: U256::ZERO,
+            path,
+            payerIsUser: true,
+        }
+        .abi_encode_params()
+        .into()
+    }
+
+    #[test]
+    fn test_universal_router_hops() {
+        let v2: Bytes = V2SwapInput {
+            recipient:   Address::ZERO,
+            amount:      U256::from(1),
+            amountLimit: U256::ZERO,
+            path:        vec![PEPE, WETH],
+            payerIsUser: true,
+        }
+        .abi_encode_params()
+        .into();
+        let exact_out = v3_input(v3_path(&[PEPE, WETH, USDC]));
+
+        // wrap eth, swap it exact in for usdc then for pepe, sell the pepe on v2
+        // and buy it back exact out. The sub plan and the allow revert flag are
+        // unrolled
+        let sub_plan = SubPlanInput { commands: vec![V2_SWAP_EXACT_IN].into(), inputs: vec![v2] }
+            .abi_encode_params()
+            .into();
+        let commands = [0x0b, V3_SWAP_EXACT_IN, 0x80 | EXECUTE_SUB_PLAN, V3_SWAP_EXACT_OUT];
+        let inputs =
+            vec![Bytes::new(), v3_input(v3_path(&[WETH, USDC, PEPE])), sub_plan, exact_out];
+
+        assert_eq!(
+            universal_router_hops(&commands, &inputs),
+            vec![
+                RouterHop::new(Protocol::UniswapV3, WETH, USDC),
+                RouterHop::new(Protocol::UniswapV3, USDC, PEPE),
+                RouterHop::new(Protocol::UniswapV2, PEPE, WETH),
+                RouterHop::new(Protocol::UniswapV3, USDC, WETH),
+                RouterHop::new(Protocol::UniswapV3, WETH, PEPE),
+            ]
+        );
+
+        // a malformed path is skipped rather than guessed at
+        let broken = v3_input(Bytes::from(vec![1; 30]));
+        assert!(universal_router_hops(&[V3_SWAP_EXACT_IN], &[broken]).is_empty());
+    }
+
+    #[test]
+    fn test_swap_router_02_hops() {
+        let exact_in: Bytes = ISwapRouter02::exactInputSingleCall {
+            params: ISwapRouter02::ExactInputSingleParams {
+                tokenIn:           WETH,
+                tokenOut:          USDC,
+                fee:               3000,
+                recipient:         Address::ZERO,
+                amountIn:          U256::from(1),
+                amountOutMinimum:  U256::ZERO,
+                sqrtPriceLimitX96: Default::default(),
+            },
+        }
+        .abi_encode()
+        .into();
+        let v2: Bytes = ISwapRouter02::swapExactTokensForTokensCall {
+            amountIn:     U256::from(1),
+            amountOutMin: U256::ZERO,
+            path:         vec![USDC, PEPE],
+            to:           Address::ZERO,
+        }
+        .abi_encode()
+        .into();
+        // unwrapWETH9(uint256,address), which doesn't swap
+        let unwrap = Bytes::from(vec![0x49, 0x40, 0x4b, 0x7c]);
+
+        assert_eq!(
+            swap_router_02_hops(&[exact_in, v2, unwrap]),
+            vec![
+                RouterHop::new(Protocol::UniswapV3, WETH, USDC),
+                RouterHop::new(Protocol::UniswapV2, USDC, PEPE),
+            ]
+        );
+    }
+}
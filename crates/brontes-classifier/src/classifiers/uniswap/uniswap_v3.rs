@@ -2,7 +2,9 @@ use alloy_primitives::U256;
 use brontes_macros::action_impl;
 use brontes_pricing::Protocol;
 use brontes_types::{
-    normalized_actions::{NormalizedBurn, NormalizedCollect, NormalizedMint, NormalizedSwap},
+    normalized_actions::{
+        NormalizedBurn, NormalizedCollect, NormalizedFlashLoan, NormalizedMint, NormalizedSwap,
+    },
     structured_trace::CallInfo,
     ToScaledRational,
 };
@@ -22,7 +24,7 @@ action_impl!(
         let token_0_delta = return_data.amount0;
         let token_1_delta = return_data.amount1;
         let recipient = call_data.recipient;
-        let details = db_tx.get_protocol_details_sorted(info.target_address)?;
+        let details = db_tx.get_protocol_details_sorted_at(info.target_address, info.block)?;
         let [token_0, token_1] = [details.token0, details.token1];
 
         let t0_info = db_tx.try_fetch_token_info(token_0)?;
@@ -73,7 +75,7 @@ action_impl!(
 
         let token_0_delta = return_data.amount0;
         let token_1_delta = return_data.amount1;
-        let details = db_tx.get_protocol_details_sorted(info.target_address)?;
+        let details = db_tx.get_protocol_details_sorted_at(info.target_address, info.block)?;
         let [token_0, token_1] = [details.token0, details.token1];
 
         let t0_info = db_tx.try_fetch_token_info(token_0)?;
@@ -105,7 +107,7 @@ action_impl!(
     db_tx: &DB| {
         let token_0_delta: U256 = return_data.amount0;
         let token_1_delta: U256 = return_data.amount1;
-        let details = db_tx.get_protocol_details_sorted(info.target_address)?;
+        let details = db_tx.get_protocol_details_sorted_at(info.target_address, info.block)?;
         let [token_0, token_1] = [details.token0, details.token1];
 
         let t0_info = db_tx.try_fetch_token_info(token_0)?;
@@ -138,7 +140,7 @@ action_impl!(
     return_data: collectReturn,
     db_tx: &DB
     | {
-        let details = db_tx.get_protocol_details_sorted(info.target_address)?;
+        let details = db_tx.get_protocol_details_sorted_at(info.target_address, info.block)?;
         let [token_0, token_1] = [details.token0, details.token1];
 
         let t0_info = db_tx.try_fetch_token_info(token_0)?;
@@ -159,6 +161,56 @@ action_impl!(
     }
 );
 
+action_impl!(
+    Protocol::UniswapV3,
+    crate::UniswapV3::flashCall,
+    FlashLoan,
+    [Flash],
+    call_data: true,
+    logs: true,
+    |
+    info: CallInfo,
+    call_data: flashCall,
+    logs: UniswapV3FlashCallLogs,
+    db_tx: &DB| {
+        let flash = logs.flash_field?;
+        let details = db_tx.get_protocol_details_sorted_at(info.target_address, info.block)?;
+
+        let mut assets = Vec::new();
+        let mut amounts = Vec::new();
+        let mut fees_paid = Vec::new();
+
+        // only the tokens that were actually borrowed are part of the loan
+        for (token, amount, paid) in [
+            (details.token0, call_data.amount0, flash.paid0),
+            (details.token1, call_data.amount1, flash.paid1),
+        ] {
+            if amount.is_zero() {
+                continue
+            }
+            let token = db_tx.try_fetch_token_info(token)?;
+            amounts.push(amount.to_scaled_rational(token.decimals));
+            fees_paid.push(paid.to_scaled_rational(token.decimals));
+            assets.push(token);
+        }
+
+        Ok(NormalizedFlashLoan {
+            protocol: Protocol::UniswapV3,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            pool: info.target_address,
+            receiver_contract: call_data.recipient,
+            assets,
+            amounts,
+            aave_mode: None,
+            child_actions: vec![],
+            repayments: vec![],
+            fees_paid,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
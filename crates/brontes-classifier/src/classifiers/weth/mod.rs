@@ -0,0 +1,55 @@
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{
+    normalized_actions::{NormalizedWrap, WrapAction},
+    structured_trace::CallInfo,
+    ToScaledRational,
+};
+
+action_impl!(
+    Protocol::Weth,
+    crate::WETH9::depositCall,
+    Wrap,
+    [Deposit],
+    logs: true,
+    |info: CallInfo, logs: WethDepositCallLogs, db_tx: &DB| {
+        let logs = logs.deposit_field?;
+        let token = db_tx.try_fetch_token_info(info.target_address)?;
+        let amount = logs.wad.to_scaled_rational(token.decimals);
+
+        Ok(NormalizedWrap {
+            protocol: Protocol::Weth,
+            trace_index: info.trace_idx,
+            action: WrapAction::Wrap,
+            weth: info.target_address,
+            from: logs.dst,
+            token,
+            amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::Weth,
+    crate::WETH9::withdrawCall,
+    Wrap,
+    [Withdrawal],
+    logs: true,
+    |info: CallInfo, logs: WethWithdrawCallLogs, db_tx: &DB| {
+        let logs = logs.withdrawal_field?;
+        let token = db_tx.try_fetch_token_info(info.target_address)?;
+        let amount = logs.wad.to_scaled_rational(token.decimals);
+
+        Ok(NormalizedWrap {
+            protocol: Protocol::Weth,
+            trace_index: info.trace_idx,
+            action: WrapAction::Unwrap,
+            weth: info.target_address,
+            from: logs.src,
+            token,
+            amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
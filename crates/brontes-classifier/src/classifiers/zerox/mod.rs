@@ -600,6 +600,202 @@ action_impl!(
     }
 );
 
+// Native order fills made by the exchange proxy on behalf of another feature,
+// e.g multiplex routes and meta transactions. The taker given to these is the
+// original caller, not the proxy itself.
+action_impl!(
+    Protocol::ZeroX,
+    crate::ZeroXInterface::_fillLimitOrderCall,
+    Swap,
+    [LimitOrderFilled],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, logs: ZeroX_fillLimitOrderCallLogs, db: &DB| {
+        let logs = logs.limit_order_filled_field?;
+
+        let token_in = db.try_fetch_token_info(logs.takerToken)?;
+        let token_out = db.try_fetch_token_info(logs.makerToken)?;
+
+        let amount_in = U256::from(logs.takerTokenFilledAmount)
+            .to_scaled_rational(token_in.decimals);
+        let amount_out = U256::from(logs.makerTokenFilledAmount)
+            .to_scaled_rational(token_out.decimals);
+
+        Ok(NormalizedSwap {
+            protocol: Protocol::ZeroX,
+            trace_index: info.trace_idx,
+            from: logs.taker,
+            recipient: logs.taker,
+            msg_value: info.msg_value,
+            pool: info.target_address,
+            token_in,
+            token_out,
+            amount_in,
+            amount_out
+        })
+    }
+);
+
+action_impl!(
+    Protocol::ZeroX,
+    crate::ZeroXInterface::_fillRfqOrderCall,
+    Swap,
+    [RfqOrderFilled],
+    call_data: true,
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, call_data: _fillRfqOrderCall, logs: ZeroX_fillRfqOrderCallLogs, db: &DB| {
+        let logs = logs.rfq_order_filled_field?;
+
+        let token_in = db.try_fetch_token_info(logs.takerToken)?;
+        let token_out = db.try_fetch_token_info(logs.makerToken)?;
+
+        let amount_in = U256::from(logs.takerTokenFilledAmount)
+            .to_scaled_rational(token_in.decimals);
+        let amount_out = U256::from(logs.makerTokenFilledAmount)
+            .to_scaled_rational(token_out.decimals);
+
+        Ok(NormalizedSwap {
+            protocol: Protocol::ZeroX,
+            trace_index: info.trace_idx,
+            from: logs.taker,
+            recipient: call_data.recipient,
+            msg_value: info.msg_value,
+            pool: info.target_address,
+            token_in,
+            token_out,
+            amount_in,
+            amount_out
+        })
+    }
+);
+
+action_impl!(
+    Protocol::ZeroX,
+    crate::ZeroXInterface::_fillOtcOrderCall,
+    Swap,
+    [OtcOrderFilled],
+    call_data: true,
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, call_data: _fillOtcOrderCall, logs: ZeroX_fillOtcOrderCallLogs, db: &DB| {
+        let logs = logs.otc_order_filled_field?;
+
+        let token_in = db.try_fetch_token_info(logs.takerToken)?;
+        let token_out = db.try_fetch_token_info(logs.makerToken)?;
+
+        let amount_in = U256::from(logs.takerTokenFilledAmount)
+            .to_scaled_rational(token_in.decimals);
+        let amount_out = U256::from(logs.makerTokenFilledAmount)
+            .to_scaled_rational(token_out.decimals);
+
+        Ok(NormalizedSwap {
+            protocol: Protocol::ZeroX,
+            trace_index: info.trace_idx,
+            from: logs.taker,
+            recipient: call_data.recipient,
+            msg_value: info.msg_value,
+            pool: info.target_address,
+            token_in,
+            token_out,
+            amount_in,
+            amount_out
+        })
+    }
+);
+
+action_impl!(
+    Protocol::ZeroX,
+    crate::ZeroXInterface::batchFillLimitOrdersCall,
+    Batch,
+    [..LimitOrderFilled*],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, logs: ZeroXBatchFillLimitOrdersCallLogs, db: &DB| {
+        let logs = logs.limit_order_filled_field?;
+
+        let mut user_swaps = vec![];
+        for log in logs {
+            let token_in = db.try_fetch_token_info(log.takerToken)?;
+            let token_out = db.try_fetch_token_info(log.makerToken)?;
+
+            let amount_in = U256::from(log.takerTokenFilledAmount)
+                .to_scaled_rational(token_in.decimals);
+            let amount_out = U256::from(log.makerTokenFilledAmount)
+                .to_scaled_rational(token_out.decimals);
+
+            user_swaps.push(NormalizedSwap {
+                protocol: Protocol::ZeroX,
+                trace_index: info.trace_idx,
+                from: log.taker,
+                recipient: log.taker,
+                msg_value: U256::ZERO,
+                pool: info.target_address,
+                token_in,
+                token_out,
+                amount_in,
+                amount_out
+            });
+        }
+
+        Ok(NormalizedBatch {
+            protocol: Protocol::ZeroX,
+            trace_index: info.trace_idx,
+            solver: info.from_address,
+            settlement_contract: info.target_address,
+            solver_swaps: None,
+            user_swaps,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::ZeroX,
+    crate::ZeroXInterface::batchFillRfqOrdersCall,
+    Batch,
+    [..RfqOrderFilled*],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, logs: ZeroXBatchFillRfqOrdersCallLogs, db: &DB| {
+        let logs = logs.rfq_order_filled_field?;
+
+        let mut user_swaps = vec![];
+        for log in logs {
+            let token_in = db.try_fetch_token_info(log.takerToken)?;
+            let token_out = db.try_fetch_token_info(log.makerToken)?;
+
+            let amount_in = U256::from(log.takerTokenFilledAmount)
+                .to_scaled_rational(token_in.decimals);
+            let amount_out = U256::from(log.makerTokenFilledAmount)
+                .to_scaled_rational(token_out.decimals);
+
+            user_swaps.push(NormalizedSwap {
+                protocol: Protocol::ZeroX,
+                trace_index: info.trace_idx,
+                from: log.taker,
+                recipient: log.taker,
+                msg_value: U256::ZERO,
+                pool: info.target_address,
+                token_in,
+                token_out,
+                amount_in,
+                amount_out
+            });
+        }
+
+        Ok(NormalizedBatch {
+            protocol: Protocol::ZeroX,
+            trace_index: info.trace_idx,
+            solver: info.from_address,
+            settlement_contract: info.target_address,
+            solver_swaps: None,
+            user_swaps,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use alloy_primitives::Log;
-use brontes_core::missing_token_info::load_missing_token_info;
+use brontes_core::missing_token_info::load_missing_token_info_with_hint;
 use brontes_database::libmdbx::{DBWriter, LibmdbxReader};
 use brontes_pricing::types::DexPriceMsg;
 use brontes_types::{
@@ -17,8 +17,12 @@ use tracing::{error, trace};
 
 use self::erc20::try_decode_transfer;
 use crate::{
-    classifiers::*, tree_builder::utils::decode_transfer, ActionCollection,
-    FactoryDiscoveryDispatch,
+    classifiers::*,
+    tree_builder::utils::{
+        decode_transfer, get_parent_trace, is_callback_frame, resolve_msg_senders, reverted_frames,
+        transfer_decimal_hint, DecodedTransfer,
+    },
+    ActionCollection, FactoryDiscoveryDispatch,
 };
 
 #[derive(Debug)]
@@ -59,6 +63,7 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> DiscoveryOnlyClassif
                         return
                     }
 
+                    resolve_msg_senders(&mut trace.trace);
                     let root_trace = trace.trace.remove(0);
                     let address = root_trace.get_from_addr();
                     let trace_idx = root_trace.trace_idx;
@@ -89,19 +94,30 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> DiscoveryOnlyClassif
                             effective_gas_price: trace.effective_price,
                             priority_fee:        trace.effective_price
                                 - (header.base_fee_per_gas.unwrap_or_default() as u128),
+                            blob_gas_paid:       trace.blob_gas_paid,
                         },
                         data_store: NodeData(vec![Some(action)]),
+                        action_kinds: None,
+                        aggregator_route: None,
                     };
 
                     let tx_trace = &trace.trace;
-                    for trace in &trace.trace {
+                    let reverted = reverted_frames(tx_trace);
+                    for (trace, reverted) in trace.trace.iter().zip(reverted) {
                         let from_addr = trace.get_from_addr();
 
-                        let node = Node::new(
+                        let mut node = Node::new(
                             trace.trace_idx,
                             from_addr,
                             trace.trace.trace_address.clone(),
                         );
+                        node.reverted = reverted;
+
+                        // pools created in a reverted frame were never deployed
+                        if reverted {
+                            tx_root.insert(node, vec![Action::Unclassified(trace.clone())]);
+                            continue
+                        }
 
                         self.process_classification(
                             header.number,
@@ -173,6 +189,7 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> DiscoveryOnlyClassif
                     node_data_store,
                     tx_idx,
                     trace.clone(),
+                    full_trace,
                     trace_index,
                 )
                 .await
@@ -259,9 +276,19 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> DiscoveryOnlyClassif
         .is_err()
         {
             for log in &trace.logs {
-                if let Some((addr, ..)) = decode_transfer(log) {
+                if let Some(DecodedTransfer::Erc20 { token: addr, from, to, amount }) =
+                    decode_transfer(log)
+                {
                     if self.libmdbx.try_fetch_token_info(addr).is_err() {
-                        load_missing_token_info(&self.provider, self.libmdbx, block, addr).await
+                        let hint = transfer_decimal_hint(self.libmdbx, addr, from, to, amount);
+                        load_missing_token_info_with_hint(
+                            &self.provider,
+                            self.libmdbx,
+                            block,
+                            addr,
+                            hint,
+                        )
+                        .await
                     }
                 }
             }
@@ -275,10 +302,24 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> DiscoveryOnlyClassif
         node_data_store: &NodeData<Action>,
         _tx_idx: u64,
         trace: TransactionTraceWithLogs,
+        full_trace: &[TransactionTraceWithLogs],
         trace_index: u64,
     ) {
         let created_addr = trace.get_create_output();
 
+        if let Some(pool) = get_parent_trace(full_trace, &trace).and_then(|parent| {
+            discover_solidly_pool(created_addr, trace_index, &parent.logs).or_else(|| {
+                // algebra factories create their pools through a separate pool deployer
+                let factory = get_parent_trace(full_trace, parent)?;
+                discover_algebra_pool(created_addr, trace_index, &factory.logs)
+            })
+        }) {
+            if !self.contains_pool(pool.pool_address) {
+                self.insert_new_pool(block, pool).await;
+            }
+            return
+        }
+
         // get the immediate parent node of this create action so that we can decode the
         // deployment function params
         let mut all_nodes = Vec::new();
@@ -348,6 +389,11 @@ fn collect_delegated_traces<'a>(
         if subtrace_address.starts_with(parent_trace_address)
             && subtrace_address.len() == parent_trace_address.len() + 1
         {
+            // the caller re-entering the contract in a callback makes its own
+            // calls, their logs aren't the contract's
+            if is_callback_frame(traces, trace) {
+                continue
+            }
             delegated_traces.push(trace);
             collect_delegated_traces(traces, subtrace_address, delegated_traces);
         }
@@ -43,6 +43,7 @@ sol!(CurveBase4, "./classifier-abis/CurveBase4.json");
 sol!(CurveV1MetapoolImpl, "./classifier-abis/CurveV1MetapoolImpl.json");
 sol!(CurveV2MetapoolImpl, "./classifier-abis/CurveV2MetapoolImpl.json");
 sol!(CurveV2PlainImpl, "./classifier-abis/CurveV2PlainImpl.json");
+sol!(CurveTriCryptoNG, "./classifier-abis/CurveTriCryptoNG.json");
 sol!(CurvecrvUSDPlainImpl, "./classifier-abis/CurvecrvUSDPlainImpl.json");
 sol!(CurveCryptoSwap, "./classifier-abis/CurveCryptoSwap.json");
 sol!(BalancerV1, "./classifier-abis/balancer/BalancerV1Pool.json");
@@ -50,12 +51,32 @@ sol!(BalancerV2Vault, "./classifier-abis/balancer/BalancerV2Vault.json");
 sol!(AaveV2, "./classifier-abis/AaveV2Pool.json");
 sol!(AaveV3, "./classifier-abis/AaveV3Pool.json");
 sol!(UniswapX, "./classifier-abis/UniswapXExclusiveDutchOrderReactor.json");
+sol!(UniswapUniversalRouter, "./classifier-abis/uniswap/UniversalRouter.json");
+sol!(UniswapSwapRouter02, "./classifier-abis/uniswap/SwapRouter02.json");
 sol!(MakerPSM, "./classifier-abis/maker/MakerPSM.json");
 sol!(MakerDssFlash, "./classifier-abis/maker/MakerDssFlash.json");
+sol!(MakerClipper, "./classifier-abis/maker/MakerClipper.json");
 sol!(CompoundV2CToken, "./classifier-abis/CompoundV2CToken.json");
+sol!(CompoundV3Comet, "./classifier-abis/CompoundV3Comet.json");
+sol!(LiquityTroveManager, "./classifier-abis/LiquityTroveManager.json");
+sol!(MorphoBlue, "./classifier-abis/MorphoBlue.json");
 sol!(OneInchAggregationRouterV5, "./classifier-abis/OneInchAggregationRouterV5.json");
+sol!(OneInchAggregationRouterV6, "./classifier-abis/OneInchAggregationRouterV6.json");
 sol!(OneInchFusionSettlement, "./classifier-abis/OneInchFusionSettlement.json");
 sol!(ClipperExchange, "./classifier-abis/ClipperExchange.json");
+sol!(HashflowRouter, "./classifier-abis/HashflowRouter.json");
+sol!(BebopSettlement, "./classifier-abis/BebopSettlement.json");
+sol!(BebopJamSettlement, "./classifier-abis/BebopJamSettlement.json");
+sol!(WETH9, "./classifier-abis/WETH9.json");
+sol!(Lido, "./classifier-abis/lido/Lido.json");
+sol!(WstETH, "./classifier-abis/lido/WstETH.json");
+sol!(LidoWithdrawalQueue, "./classifier-abis/lido/LidoWithdrawalQueue.json");
+sol!(CurveLiquidityGauge, "./classifier-abis/CurveLiquidityGauge.json");
+sol!(ConvexBooster, "./classifier-abis/convex/ConvexBooster.json");
+sol!(Seaport, "./classifier-abis/seaport/Seaport.json");
+sol!(BlurExchange, "./classifier-abis/blur/BlurExchange.json");
+sol!(BlurExchangeV2, "./classifier-abis/blur/BlurExchangeV2.json");
+sol!(BlurBlend, "./classifier-abis/blur/Blend.json");
 sol!(CowswapGPv2Settlement, "./classifier-abis/cowswap/GPv2Settlement.json");
 sol!(ZeroXUniswapFeaure, "./classifier-abis/zero-x/ZeroXUniswapFeature.json");
 sol!(ZeroXUniswapV3Feature, "./classifier-abis/zero-x/ZeroXUniswapV3Feature.json");
@@ -66,6 +87,12 @@ sol!(ZeroXLiquidityProviderFeature, "./classifier-abis/zero-x/ZeroXLiquidityProv
 sol!(ZeroXInterface, "./classifier-abis/zero-x/ZeroXInterface.json");
 sol!(DodoDPPPool, "./classifier-abis/dodo/DPPPool.json");
 sol!(DodoDSPPool, "./classifier-abis/dodo/DSPPool.json");
+sol!(DodoV1Pool, "./classifier-abis/dodo/DODOV1Pool.json");
+sol!(BancorNetwork, "./classifier-abis/bancor/BancorNetwork.json");
+sol!(FraxswapPair, "./classifier-abis/fraxswap/FraxswapPair.json");
+sol!(SolidlyPair, "./classifier-abis/solidly/SolidlyPair.json");
+sol!(SolidlyV2Pool, "./classifier-abis/solidly/SolidlyV2Pool.json");
+sol!(AlgebraPool, "./classifier-abis/algebra/AlgebraPool.json");
 
 // Discovery
 sol!(UniswapV2Factory, "./classifier-abis/UniswapV2Factory.json");
@@ -80,9 +107,21 @@ sol!(CompoundV2Comptroller, "./classifier-abis/CompoundV2Comptroller.json");
 sol!(CErc20Delegate, "./classifier-abis/CErc20Delegate.json");
 sol!(BalancerV1CorePoolFactory, "./classifier-abis/balancer/BalancerV1Factory.json");
 sol!(BalancerV1SmartPoolFactory, "./classifier-abis/balancer/BalancerV1CrpFactory.json");
+sol!(
+    BalancerV2WeightedPoolFactory,
+    "./classifier-abis/balancer/BalancerV2WeightedPoolFactory.json"
+);
+sol!(
+    BalancerV2ComposableStablePoolFactory,
+    "./classifier-abis/balancer/BalancerV2ComposableStablePoolFactory.json"
+);
 sol!(DodoDVMFactory, "./classifier-abis/dodo/DVMFactory.json");
 sol!(DodoDPPFactory, "./classifier-abis/dodo/DPPFactory.json");
 sol!(DodoDSPFactory, "./classifier-abis/dodo/DSPFactory.json");
+sol!(DodoZoo, "./classifier-abis/dodo/DODOZoo.json");
+sol!(FraxswapFactory, "./classifier-abis/fraxswap/FraxswapFactory.json");
+sol!(SolidlyFactory, "./classifier-abis/solidly/SolidlyFactory.json");
+sol!(AlgebraFactory, "./classifier-abis/algebra/AlgebraFactory.json");
 
 // Balancer Pool Interfaces
 sol! {
@@ -122,6 +161,96 @@ sol! {
     }
 }
 
+// Paraswap Augustus V5. The swap functions are implemented by routers the
+// swapper delegate calls into by selector
+sol! {
+    interface ParaswapAugustusV5 {
+        struct SimpleData {
+            address fromToken;
+            address toToken;
+            uint256 fromAmount;
+            uint256 toAmount;
+            uint256 expectedAmount;
+            address[] callees;
+            bytes exchangeData;
+            uint256[] startIndexes;
+            uint256[] values;
+            address beneficiary;
+            address partner;
+            uint256 feePercent;
+            bytes permit;
+            uint256 deadline;
+            bytes16 uuid;
+        }
+
+        struct Route {
+            uint256 index;
+            address targetExchange;
+            uint256 percent;
+            bytes payload;
+            uint256 networkFee;
+        }
+
+        struct Adapter {
+            address adapter;
+            uint256 percent;
+            uint256 networkFee;
+            Route[] route;
+        }
+
+        struct Path {
+            address to;
+            uint256 totalNetworkFee;
+            Adapter[] adapters;
+        }
+
+        struct MegaSwapPath {
+            uint256 fromAmountPercent;
+            Path[] path;
+        }
+
+        struct SellData {
+            address fromToken;
+            uint256 fromAmount;
+            uint256 toAmount;
+            uint256 expectedAmount;
+            address beneficiary;
+            Path[] path;
+            address partner;
+            uint256 feePercent;
+            bytes permit;
+            uint256 deadline;
+            bytes16 uuid;
+        }
+
+        struct MegaSwapSellData {
+            address fromToken;
+            uint256 fromAmount;
+            uint256 toAmount;
+            uint256 expectedAmount;
+            address beneficiary;
+            MegaSwapPath[] path;
+            address partner;
+            uint256 feePercent;
+            bytes permit;
+            uint256 deadline;
+            bytes16 uuid;
+        }
+
+        function simpleSwap(SimpleData memory data)
+            external payable returns (uint256 receivedAmount);
+        function multiSwap(SellData memory data) external payable returns (uint256);
+        function megaSwap(MegaSwapSellData memory data) external payable returns (uint256);
+        function swapOnUniswapV2Fork(
+            address tokenIn,
+            uint256 amountIn,
+            uint256 amountOutMin,
+            address weth,
+            uint256[] calldata pools
+        ) external payable;
+    }
+}
+
 sol! {
     event Transfer(address indexed from, address indexed to, uint256 value);
     function name() public view returns (string);
@@ -2,3 +2,7 @@ pub mod one_inch;
 pub use one_inch::*;
 pub mod zero_x;
 pub use zero_x::*;
+pub mod paraswap;
+pub use paraswap::*;
+pub mod uniswap_router;
+pub use uniswap_router::*;
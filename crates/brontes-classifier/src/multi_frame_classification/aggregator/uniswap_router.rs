@@ -0,0 +1,94 @@
+use brontes_types::{
+    normalized_actions::{
+        Action, MultiCallFrameClassification, MultiFrameAction, MultiFrameRequest,
+    },
+    Protocol, TreeSearchBuilder,
+};
+
+use crate::multi_frame_classification::MultiCallFrameClassifier;
+
+pub struct UniswapUniversalRouterAgg;
+
+impl MultiCallFrameClassifier for UniswapUniversalRouterAgg {
+    const KEY: [u8; 2] =
+        [Protocol::UniswapUniversalRouter as u8, MultiFrameAction::Aggregator as u8];
+
+    fn create_classifier(
+        request: MultiFrameRequest,
+    ) -> Option<MultiCallFrameClassification<Action>> {
+        Some(router_classifier(request))
+    }
+}
+
+pub struct UniswapSwapRouter02Agg;
+
+impl MultiCallFrameClassifier for UniswapSwapRouter02Agg {
+    const KEY: [u8; 2] = [Protocol::UniswapSwapRouter02 as u8, MultiFrameAction::Aggregator as u8];
+
+    fn create_classifier(
+        request: MultiFrameRequest,
+    ) -> Option<MultiCallFrameClassification<Action>> {
+        Some(router_classifier(request))
+    }
+}
+
+/// The router aggregator comes with a placeholder swap for each hop decoded
+/// from its calldata, in the order of the route. Each placeholder is replaced
+/// by the first pool swap under the router call on the same protocol and
+/// tokens, so a swap made by a token the router calls into, such as a tax token
+/// selling its fees, isn't taken as part of the route. If nothing could be
+/// decoded every swap is taken, in the order of the traces.
+fn router_classifier(request: MultiFrameRequest) -> MultiCallFrameClassification<Action> {
+    MultiCallFrameClassification {
+        trace_index:         request.trace_idx,
+        tree_search_builder: TreeSearchBuilder::new().with_actions([
+            Action::is_swap,
+            Action::is_transfer,
+            Action::is_eth_transfer,
+        ]),
+        parse_fn:            Box::new(|this_action, mut child_nodes| {
+            let this = this_action.try_aggregator_mut().unwrap();
+            let hops = std::mem::take(&mut this.child_actions);
+            child_nodes.sort_by_key(|(index, _)| index.trace_index);
+
+            let mut claimed = vec![false; child_nodes.len()];
+            if hops.is_empty() {
+                for (i, (_, action)) in child_nodes.iter().enumerate() {
+                    if action.try_swaps_merged_ref().is_some() {
+                        claimed[i] = true;
+                        this.child_actions.push(action.clone());
+                    }
+                }
+            }
+
+            for hop in hops.iter().filter_map(Action::try_swaps_merged_ref) {
+                let Some(i) = (0..child_nodes.len()).find(|&i| {
+                    !claimed[i]
+                        && child_nodes[i].1.try_swaps_merged_ref().is_some_and(|swap| {
+                            swap.protocol == hop.protocol
+                                && swap.token_in.address == hop.token_in.address
+                                && swap.token_out.address == hop.token_out.address
+                        })
+                }) else {
+                    continue
+                };
+                claimed[i] = true;
+                this.child_actions.push(child_nodes[i].1.clone());
+            }
+
+            // the transfers in and out of the route follow the swaps
+            for (i, (_, action)) in child_nodes.iter().enumerate() {
+                if action.is_transfer() || action.is_eth_transfer() {
+                    claimed[i] = true;
+                    this.child_actions.push(action.clone());
+                }
+            }
+
+            child_nodes
+                .into_iter()
+                .zip(claimed)
+                .filter_map(|((trace_index, _), claimed)| claimed.then_some(trace_index))
+                .collect()
+        }),
+    }
+}
@@ -60,27 +60,21 @@ impl MultiCallFrameClassifier for UniswapX {
                                 }
                             }
                         }
+                        // the filler's own swaps used to source the order outputs. These
+                        // are kept on the batch so the filler's arbitrage is attributed to
+                        // them, and we keep going as a fill can be sourced from multiple
+                        // pools
                         Action::Swap(s) => {
-                            if let Some(swaps) = &mut this.solver_swaps {
-                                swaps.push(s.clone());
-                                nodes_to_prune.push(trace_index);
-                                break
-                            } else {
-                                this.solver_swaps = Some(vec![s.clone()]);
-                                nodes_to_prune.push(trace_index);
-                                break
-                            }
+                            this.solver_swaps
+                                .get_or_insert_with(Vec::new)
+                                .push(s.clone());
+                            nodes_to_prune.push(trace_index);
                         }
                         Action::SwapWithFee(s) => {
-                            if let Some(swaps) = &mut this.solver_swaps {
-                                swaps.push(s.swap.clone());
-                                nodes_to_prune.push(trace_index);
-                                break
-                            } else {
-                                this.solver_swaps = Some(vec![s.swap.clone()]);
-                                nodes_to_prune.push(trace_index);
-                                break
-                            }
+                            this.solver_swaps
+                                .get_or_insert_with(Vec::new)
+                                .push(s.swap.clone());
+                            nodes_to_prune.push(trace_index);
                         }
                         _ => {
                             error!(
@@ -0,0 +1,122 @@
+use brontes_types::{
+    normalized_actions::{
+        Action, MultiCallFrameClassification, MultiFrameAction, MultiFrameRequest, NodeDataIndex,
+    },
+    Protocol, TreeSearchBuilder,
+};
+use malachite::{num::basic::traits::Zero, Rational};
+use reth_primitives::Address;
+use tracing::warn;
+
+use crate::multi_frame_classification::MultiCallFrameClassifier;
+
+pub struct AaveV2FlashLoan;
+pub struct AaveV3FlashLoan;
+pub struct SparkFlashLoan;
+
+impl MultiCallFrameClassifier for AaveV2FlashLoan {
+    const KEY: [u8; 2] = [Protocol::AaveV2 as u8, MultiFrameAction::FlashLoan as u8];
+
+    fn create_classifier(
+        request: MultiFrameRequest,
+    ) -> Option<MultiCallFrameClassification<Action>> {
+        Some(MultiCallFrameClassification {
+            trace_index:         request.trace_idx,
+            tree_search_builder: search_builder(),
+            parse_fn:            Box::new(parse_v2_v3),
+        })
+    }
+}
+
+impl MultiCallFrameClassifier for AaveV3FlashLoan {
+    const KEY: [u8; 2] = [Protocol::AaveV3 as u8, MultiFrameAction::FlashLoan as u8];
+
+    fn create_classifier(
+        request: MultiFrameRequest,
+    ) -> Option<MultiCallFrameClassification<Action>> {
+        Some(MultiCallFrameClassification {
+            trace_index:         request.trace_idx,
+            tree_search_builder: search_builder(),
+            parse_fn:            Box::new(parse_v2_v3),
+        })
+    }
+}
+
+impl MultiCallFrameClassifier for SparkFlashLoan {
+    const KEY: [u8; 2] = [Protocol::Spark as u8, MultiFrameAction::FlashLoan as u8];
+
+    fn create_classifier(
+        request: MultiFrameRequest,
+    ) -> Option<MultiCallFrameClassification<Action>> {
+        Some(MultiCallFrameClassification {
+            trace_index:         request.trace_idx,
+            tree_search_builder: search_builder(),
+            parse_fn:            Box::new(parse_v2_v3),
+        })
+    }
+}
+
+fn search_builder() -> TreeSearchBuilder<Action> {
+    TreeSearchBuilder::new().with_actions([
+        Action::is_swap,
+        Action::is_transfer,
+        Action::is_eth_transfer,
+    ])
+}
+
+/// The loaned funds are sent to the receiver from the reserve's aToken, which
+/// then pulls the amount plus the premium back. When the loan is opened as
+/// debt (a non zero aave mode) there is no repayment at all.
+fn parse_v2_v3(this: &mut Action, child_nodes: Vec<(NodeDataIndex, Action)>) -> Vec<NodeDataIndex> {
+    let this = this.try_flash_loan_mut().unwrap();
+
+    // the aToken each asset is loaned out of
+    let reserves = this
+        .assets
+        .iter()
+        .zip(&this.amounts)
+        .map(|(asset, amount)| {
+            child_nodes.iter().find_map(|(_, action)| {
+                let Action::Transfer(t) = action else { return None };
+                (t.to == this.receiver_contract && t.token == *asset && t.amount == *amount)
+                    .then_some(t.from)
+            })
+        })
+        .collect::<Vec<Option<Address>>>();
+
+    let mut nodes_to_prune = Vec::new();
+    let mut repay_transfers = Vec::new();
+    let mut fees_paid = vec![Rational::ZERO; this.assets.len()];
+
+    for (index, action) in child_nodes.into_iter() {
+        match &action {
+            Action::Swap(_) | Action::SwapWithFee(_) | Action::EthTransfer(_) => {
+                this.child_actions.push(action);
+                nodes_to_prune.push(index);
+            }
+            Action::Transfer(t) => {
+                if t.from == this.receiver_contract {
+                    if let Some(i) = this.assets.iter().position(|x| *x == t.token) {
+                        if reserves[i] == Some(t.to) && t.amount >= this.amounts[i] {
+                            fees_paid[i] = &t.amount - &this.amounts[i];
+                            repay_transfers.push(t.clone());
+                            nodes_to_prune.push(index);
+                            continue
+                        }
+                    }
+                }
+                this.child_actions.push(action);
+                nodes_to_prune.push(index);
+            }
+            _ => {
+                warn!("Aave flashloan, unknown call");
+                continue
+            }
+        }
+    }
+
+    this.fees_paid = fees_paid;
+    this.repayments = repay_transfers;
+
+    nodes_to_prune
+}
@@ -54,8 +54,7 @@ impl MultiCallFrameClassifier for BalancerV2 {
                     }
                 }
 
-                // no fee
-                this.fees_paid = vec![];
+                // fees are set from the flash loan events on classification
                 this.repayments = repay_transfers;
 
                 nodes_to_prune
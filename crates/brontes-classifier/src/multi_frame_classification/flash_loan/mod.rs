@@ -1,6 +1,10 @@
+pub mod aave;
+pub use aave::*;
 pub mod balancer;
 pub use balancer::*;
 pub mod maker_dss;
 pub use maker_dss::*;
 pub mod dodo;
 pub use dodo::*;
+pub mod uniswap_v3;
+pub use uniswap_v3::*;
@@ -0,0 +1,45 @@
+use brontes_types::{
+    normalized_actions::{
+        Action, MultiCallFrameClassification, MultiFrameAction, MultiFrameRequest, NodeDataIndex,
+    },
+    Protocol, TreeSearchBuilder,
+};
+
+use crate::multi_frame_classification::MultiCallFrameClassifier;
+
+pub struct CompoundV2;
+
+impl MultiCallFrameClassifier for CompoundV2 {
+    const KEY: [u8; 2] = [Protocol::CompoundV2 as u8, MultiFrameAction::Liquidation as u8];
+
+    fn create_classifier(
+        request: MultiFrameRequest,
+    ) -> Option<MultiCallFrameClassification<Action>> {
+        Some(MultiCallFrameClassification {
+            trace_index:         request.trace_idx,
+            tree_search_builder: TreeSearchBuilder::new().with_action(Action::is_transfer),
+            parse_fn:            Box::new(parse_v2),
+        })
+    }
+}
+
+/// The liquidate borrow event only has the repaid amount, in the underlying of
+/// the borrowed ctoken. The underlying is taken from the repayment into the
+/// ctoken
+fn parse_v2(this: &mut Action, child_nodes: Vec<(NodeDataIndex, Action)>) -> Vec<NodeDataIndex> {
+    let this = this.try_liquidation_mut().unwrap();
+    // cEther is repaid with the call value, which is set on classification
+    if !this.msg_value.is_zero() {
+        return vec![]
+    }
+
+    if let Some(repayment) = child_nodes.into_iter().find_map(|(_, action)| {
+        let Action::Transfer(transfer) = action else { return None };
+        (transfer.to == this.pool && transfer.token.address != this.pool).then_some(transfer)
+    }) {
+        this.debt_asset = repayment.token;
+        this.covered_debt = repayment.amount;
+    }
+
+    vec![]
+}
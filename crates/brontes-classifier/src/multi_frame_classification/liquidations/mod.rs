@@ -1,2 +1,4 @@
 pub mod aave;
 pub use aave::*;
+pub mod compound;
+pub use compound::*;
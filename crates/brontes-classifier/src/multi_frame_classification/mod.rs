@@ -8,13 +8,20 @@ pub mod aggregator;
 pub mod batch;
 pub mod flash_loan;
 pub mod liquidations;
+pub mod stake;
 
-use aggregator::{OneInchAggregator, OneInchFusion, ZeroXAgg};
+use aggregator::{
+    OneInchAggregator, OneInchAggregatorV6, OneInchFusion, ParaswapAgg, UniswapSwapRouter02Agg,
+    UniswapUniversalRouterAgg, ZeroXAgg,
+};
 use batch::{Cowswap, UniswapX, ZeroXBatch};
 use brontes_types::normalized_actions::{Action, MultiCallFrameClassification, MultiFrameRequest};
-use flash_loan::{BalancerV2, MakerDss};
+use flash_loan::{
+    AaveV2FlashLoan, AaveV3FlashLoan, BalancerV2, MakerDss, SparkFlashLoan, UniswapV3Flash,
+};
 use itertools::Itertools;
-use liquidations::{AaveV2, AaveV3};
+use liquidations::{AaveV2, AaveV3, CompoundV2, Spark};
+use stake::{Convex, CurveGauge};
 use tracing::debug;
 
 use self::flash_loan::Dodo;
@@ -38,16 +45,28 @@ pub fn parse_multi_frame_requests(
         .into_iter()
         .filter_map(|request| match request.make_key() {
             OneInchAggregator::KEY => OneInchAggregator::create_classifier(request),
+            OneInchAggregatorV6::KEY => OneInchAggregatorV6::create_classifier(request),
             OneInchFusion::KEY => OneInchFusion::create_classifier(request),
             UniswapX::KEY => UniswapX::create_classifier(request),
             Cowswap::KEY => Cowswap::create_classifier(request),
             BalancerV2::KEY => BalancerV2::create_classifier(request),
             AaveV2::KEY => AaveV2::create_classifier(request),
             AaveV3::KEY => AaveV3::create_classifier(request),
+            Spark::KEY => Spark::create_classifier(request),
+            CompoundV2::KEY => CompoundV2::create_classifier(request),
+            AaveV2FlashLoan::KEY => AaveV2FlashLoan::create_classifier(request),
+            AaveV3FlashLoan::KEY => AaveV3FlashLoan::create_classifier(request),
+            SparkFlashLoan::KEY => SparkFlashLoan::create_classifier(request),
             ZeroXAgg::KEY => ZeroXAgg::create_classifier(request),
+            ParaswapAgg::KEY => ParaswapAgg::create_classifier(request),
+            UniswapUniversalRouterAgg::KEY => UniswapUniversalRouterAgg::create_classifier(request),
+            UniswapSwapRouter02Agg::KEY => UniswapSwapRouter02Agg::create_classifier(request),
             ZeroXBatch::KEY => ZeroXBatch::create_classifier(request),
             MakerDss::KEY => MakerDss::create_classifier(request),
             Dodo::KEY => Dodo::create_classifier(request),
+            UniswapV3Flash::KEY => UniswapV3Flash::create_classifier(request),
+            CurveGauge::KEY => CurveGauge::create_classifier(request),
+            Convex::KEY => Convex::create_classifier(request),
             _ => {
                 debug!(?request, "no multi frame classification impl for this request");
                 None
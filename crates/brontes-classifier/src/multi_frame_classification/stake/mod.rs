@@ -0,0 +1,87 @@
+use brontes_types::{
+    normalized_actions::{
+        Action, MultiCallFrameClassification, MultiFrameAction, MultiFrameRequest, NodeDataIndex,
+    },
+    Protocol, TreeSearchBuilder,
+};
+
+use crate::multi_frame_classification::MultiCallFrameClassifier;
+
+pub struct CurveGauge;
+pub struct Convex;
+
+impl MultiCallFrameClassifier for CurveGauge {
+    const KEY: [u8; 2] = [Protocol::CurveGauge as u8, MultiFrameAction::Stake as u8];
+
+    fn create_classifier(
+        request: MultiFrameRequest,
+    ) -> Option<MultiCallFrameClassification<Action>> {
+        Some(MultiCallFrameClassification {
+            trace_index:         request.trace_idx,
+            tree_search_builder: TreeSearchBuilder::new().with_action(Action::is_transfer),
+            parse_fn:            Box::new(parse_gauge),
+        })
+    }
+}
+
+impl MultiCallFrameClassifier for Convex {
+    const KEY: [u8; 2] = [Protocol::Convex as u8, MultiFrameAction::Stake as u8];
+
+    fn create_classifier(
+        request: MultiFrameRequest,
+    ) -> Option<MultiCallFrameClassification<Action>> {
+        Some(MultiCallFrameClassification {
+            trace_index:         request.trace_idx,
+            tree_search_builder: TreeSearchBuilder::new()
+                .with_actions([Action::is_transfer, Action::is_stake]),
+            parse_fn:            Box::new(parse_convex),
+        })
+    }
+}
+
+/// The staked tokens moving in or out of the gauge are part of the stake.
+/// Rewards claimed along with it are left as transfers
+fn parse_gauge(this: &mut Action, child_nodes: Vec<(NodeDataIndex, Action)>) -> Vec<NodeDataIndex> {
+    let this = this.try_stake_mut().unwrap();
+    child_nodes
+        .into_iter()
+        .filter_map(|(index, action)| {
+            let Action::Transfer(transfer) = action else { return None };
+            (transfer.token == this.token
+                && (transfer.to == this.staking_contract || transfer.from == this.staking_contract))
+                .then_some(index)
+        })
+        .collect()
+}
+
+/// The LP token is pulled from the caller on a deposit and sent to the account
+/// on a withdrawal. On its way it passes the voter proxy and the stake of the
+/// proxy in the Curve gauge, which all belong to this stake
+fn parse_convex(
+    this: &mut Action,
+    child_nodes: Vec<(NodeDataIndex, Action)>,
+) -> Vec<NodeDataIndex> {
+    let this = this.try_stake_mut().unwrap();
+
+    let Some(lp_transfer) = child_nodes.iter().find_map(|(_, action)| {
+        let Action::Transfer(transfer) = action else { return None };
+        if this.is_deposit() {
+            (transfer.from == this.from).then_some(transfer)
+        } else {
+            (transfer.to == this.account).then_some(transfer)
+        }
+    }) else {
+        return vec![]
+    };
+    this.token = lp_transfer.token.clone();
+    this.amount = lp_transfer.amount.clone();
+
+    child_nodes
+        .into_iter()
+        .filter_map(|(index, action)| match action {
+            Action::Transfer(transfer) if transfer.token == this.token => Some(index),
+            Action::Stake(stake) if stake.token == this.token => Some(index),
+            _ => None,
+        })
+        .collect()
+}
@@ -6,7 +6,7 @@ use std::{
     },
 };
 
-use alloy_primitives::{Address, TxHash};
+use alloy_primitives::{Address, Bytes, Log, TxHash, U256};
 use brontes_core::{
     decoding::TracingProvider, BlockTracesWithHeaderAnd, TraceLoader, TraceLoaderError,
     TxTracesWithHeaderAnd,
@@ -24,7 +24,7 @@ use brontes_types::{
         address_to_protocol_info::ProtocolInfo, dex::DexQuotes, token_info::TokenInfoWithAddress,
     },
     normalized_actions::{pool::NormalizedNewPool, NormalizedTransfer},
-    structured_trace::TraceActions,
+    structured_trace::{CallFrameInfo, TraceActions},
     tree::BlockTree,
     BrontesTaskManager, FastHashMap, TreeCollector, TreeSearchBuilder, UnboundedYapperReceiver,
 };
@@ -34,7 +34,10 @@ use serde_json::Value;
 use thiserror::Error;
 use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
 
-use crate::{Action, ActionCollection, Classifier, ProtocolClassifier};
+use crate::{
+    Action, ActionCollection, Classifier, DiscoveryClassifier, FactoryDiscoveryDispatch,
+    ProtocolClassifier,
+};
 
 pub struct ClassifierTestUtils {
     pub trace_loader: TraceLoader,
@@ -584,6 +587,48 @@ impl ClassifierTestUtils {
         Ok(())
     }
 
+    /// Runs a call built by the test through the protocol dispatch, the way
+    /// the classifier does for a trace of the target at `block`
+    pub fn dispatch_call(
+        &self,
+        target: Address,
+        msg_sender: Address,
+        call_data: Bytes,
+        return_data: Bytes,
+        logs: &[Log],
+        block: u64,
+    ) -> Option<Action> {
+        let call_info = CallFrameInfo {
+            trace_idx: 0,
+            call_data,
+            return_data,
+            target_address: target,
+            from_address: msg_sender,
+            logs,
+            delegate_logs: vec![],
+            msg_sender,
+            msg_value: U256::ZERO,
+            protocol: None,
+        };
+
+        ProtocolClassifier::default()
+            .dispatch(call_info, self.trace_loader.libmdbx, block, 0)
+            .map(|(_, action)| action)
+    }
+
+    /// Runs a factory call built by the test through the discovery dispatch,
+    /// as if the call deployed `deployed_address`
+    pub async fn dispatch_discovery(
+        &self,
+        factory: Address,
+        call_data: Bytes,
+        deployed_address: Address,
+    ) -> Vec<NormalizedNewPool> {
+        DiscoveryClassifier::default()
+            .dispatch(self.get_provider(), vec![(factory, call_data)], deployed_address, 0)
+            .await
+    }
+
     pub async fn test_discovery_classification(
         &self,
         txes: TxHash,
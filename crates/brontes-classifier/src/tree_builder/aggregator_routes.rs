@@ -0,0 +1,19 @@
+use brontes_types::{
+    normalized_actions::Action,
+    tree::{AggregatorRoute, BlockTree},
+};
+
+/// Labels the transactions that were sent straight to an aggregator router or
+/// a batch settlement contract with the protocol and entrypoint, so that the
+/// inspectors can tell retail flow routed by an aggregator apart from a
+/// searcher calling its own contract. Only the head of the transaction is
+/// looked at, a searcher contract that calls into a router isn't labelled.
+pub(crate) fn label_aggregator_routes(tree: &mut BlockTree<Action>) {
+    tracing::debug!("label aggregator routes");
+    tree.tx_roots.iter_mut().for_each(|root| {
+        root.aggregator_route = root
+            .data_store
+            .get_ref(root.head.data)
+            .and_then(|actions| actions.iter().find_map(AggregatorRoute::from_action));
+    });
+}
@@ -0,0 +1,46 @@
+use brontes_types::{
+    normalized_actions::{Action, NormalizedCollateralSwap},
+    tree::{BlockTree, Node},
+    TreeSearchBuilder,
+};
+use itertools::Itertools;
+
+/// Groups the lending actions of each transaction by the account they are
+/// performed for. When an account moves its position from one market to
+/// another (withdraw + deposit, repay + borrow) in a single transaction, we
+/// tag the transaction with a [`NormalizedCollateralSwap`] so that the
+/// inspectors don't mistake the refinancing for mev.
+pub(crate) fn classify_collateral_swaps(tree: &mut BlockTree<Action>) {
+    tracing::debug!("classify collateral swaps");
+    tree.tx_roots.iter_mut().for_each(|root| {
+        let lending = root
+            .collect(&TreeSearchBuilder::default().with_action(Action::is_lending))
+            .into_iter()
+            .filter_map(Action::try_lending)
+            .into_group_map_by(|lending| lending.account);
+
+        for (account, actions) in lending {
+            let Some(collateral_swap) = NormalizedCollateralSwap::try_new(account, actions) else {
+                continue
+            };
+
+            let Some(data_idx) = find_node_data(&root.head, collateral_swap.trace_index) else {
+                continue
+            };
+
+            if let Some(data) = root.data_store.get_mut(data_idx) {
+                data.push(Action::CollateralSwap(collateral_swap));
+            }
+        }
+    });
+}
+
+fn find_node_data(node: &Node, trace_index: u64) -> Option<usize> {
+    if node.index == trace_index {
+        return Some(node.data)
+    }
+
+    node.inner
+        .iter()
+        .find_map(|inner| find_node_data(inner, trace_index))
+}
@@ -0,0 +1,314 @@
+use brontes_types::{
+    normalized_actions::{
+        Action, MultiCallFrameClassification, NodeDataIndex, NormalizedFlashLoan,
+        NormalizedTransfer,
+    },
+    structured_trace::TraceActions,
+    tree::{root::NodeData, BlockTree, Node, Root},
+    Protocol, TreeSearchBuilder,
+};
+use malachite::{num::basic::traits::Zero, Rational};
+
+/// Flash loans taken from lenders we don't have a classifier for are left as
+/// unclassified calls, so the swaps made with the loaned funds sit next to the
+/// borrow and the repayment and the whole loan ends up counted as the
+/// searcher's balance changes. Here we look for the shape every flash loan
+/// shares, whoever provides it: a call from a contract into the lender, the
+/// lender sending it tokens and calling back into it, and the same tokens
+/// going back to the lender with a fee on top. The call is turned into a
+/// [`NormalizedFlashLoan`] that the actions in it are nested under.
+///
+/// This runs after the known flash loans have been classified, so any loan
+/// they already took the transfers of isn't picked up again.
+pub(crate) fn classify_unknown_flash_loans(tree: &mut BlockTree<Action>) {
+    tracing::debug!("classify unknown flash loans");
+    tree.tx_roots.iter_mut().for_each(classify_root);
+}
+
+fn classify_root(root: &mut Root<Action>) {
+    // a classified loan is no longer an unclassified call, so each loop looks
+    // at the ones left. The outer loan goes first and takes the transfers of
+    // any nested in it
+    while let Some((data_idx, flash_loan)) = find_flash_loan(&root.head, &root.data_store) {
+        let trace_index = flash_loan.trace_index;
+        let Some(action) = root
+            .data_store
+            .get_mut(data_idx)
+            .and_then(|data| data.first_mut())
+        else {
+            break
+        };
+        *action = Action::FlashLoan(flash_loan);
+
+        root.collect_child_traces_and_classify(&[MultiCallFrameClassification {
+            trace_index,
+            tree_search_builder: TreeSearchBuilder::new().with_actions([
+                Action::is_swap,
+                Action::is_transfer,
+                Action::is_eth_transfer,
+            ]),
+            parse_fn: Box::new(parse_flash_loan),
+        }]);
+    }
+}
+
+fn find_flash_loan(node: &Node, data: &NodeData<Action>) -> Option<(usize, NormalizedFlashLoan)> {
+    try_flash_loan(node, data)
+        .map(|loan| (node.data, loan))
+        .or_else(|| {
+            node.inner
+                .iter()
+                .find_map(|inner| find_flash_loan(inner, data))
+        })
+}
+
+fn try_flash_loan(node: &Node, data: &NodeData<Action>) -> Option<NormalizedFlashLoan> {
+    let Some(Action::Unclassified(trace)) = data.get_ref(node.data)?.first() else { return None };
+    if trace.is_delegate_call() || trace.is_static_call() {
+        return None
+    }
+    let receiver = trace.get_from_addr();
+    let lender = trace.get_to_address();
+
+    // the lender sends the funds before calling back into the receiver
+    let callback = node.inner.iter().position(|inner| inner.callback)?;
+    let borrows = node.inner[..callback]
+        .iter()
+        .filter_map(|inner| match data.get_ref(inner.data)?.first()? {
+            Action::Transfer(t) if t.from == lender && t.to == receiver => Some(t),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    if borrows.is_empty() {
+        return None
+    }
+
+    // either repaid by the receiver in the callback or pulled by the lender
+    // once it returns
+    let mut transfers = Vec::new();
+    node.inner[callback..]
+        .iter()
+        .for_each(|inner| collect_transfers(inner, data, &mut transfers));
+
+    let repaid = borrows.iter().all(|borrow| {
+        transfers.iter().any(|t| {
+            t.from == receiver
+                && t.to == lender
+                && t.token == borrow.token
+                && t.amount >= borrow.amount
+        })
+    });
+    if !repaid {
+        return None
+    }
+
+    Some(NormalizedFlashLoan {
+        protocol:          Protocol::Unknown,
+        trace_index:       trace.trace_idx,
+        from:              receiver,
+        pool:              lender,
+        receiver_contract: receiver,
+        assets:            borrows.iter().map(|t| t.token.clone()).collect(),
+        amounts:           borrows.iter().map(|t| t.amount.clone()).collect(),
+        aave_mode:         None,
+        child_actions:     vec![],
+        repayments:        vec![],
+        fees_paid:         vec![],
+        msg_value:         trace.get_msg_value(),
+    })
+}
+
+fn collect_transfers<'a>(
+    node: &Node,
+    data: &'a NodeData<Action>,
+    transfers: &mut Vec<&'a NormalizedTransfer>,
+) {
+    if let Some(actions) = data.get_ref(node.data) {
+        transfers.extend(actions.iter().filter_map(|action| match action {
+            Action::Transfer(t) => Some(t),
+            _ => None,
+        }));
+    }
+    node.inner
+        .iter()
+        .for_each(|inner| collect_transfers(inner, data, transfers));
+}
+
+/// The first transfer of each asset back to the lender for at least the
+/// borrowed amount is the repayment, anything above the amount being the fee.
+/// Everything else, the borrow included, is done with the loan.
+fn parse_flash_loan(
+    this: &mut Action,
+    child_nodes: Vec<(NodeDataIndex, Action)>,
+) -> Vec<NodeDataIndex> {
+    let this = this.try_flash_loan_mut().unwrap();
+    let mut fees_paid = vec![Rational::ZERO; this.assets.len()];
+    let mut repaid = vec![false; this.assets.len()];
+
+    let mut nodes_to_prune = Vec::new();
+    for (index, action) in child_nodes {
+        nodes_to_prune.push(index);
+
+        if let Action::Transfer(t) = &action {
+            if t.from == this.receiver_contract && t.to == this.pool {
+                if let Some(i) = this
+                    .assets
+                    .iter()
+                    .position(|asset| *asset == t.token)
+                    .filter(|&i| !repaid[i] && t.amount >= this.amounts[i])
+                {
+                    repaid[i] = true;
+                    fees_paid[i] = &t.amount - &this.amounts[i];
+                    this.repayments.push(t.clone());
+                    continue
+                }
+            }
+        }
+        this.child_actions.push(action);
+    }
+    this.fees_paid = fees_paid;
+
+    nodes_to_prune
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{Bytes, U64};
+    use brontes_types::{
+        db::token_info::TokenInfoWithAddress,
+        normalized_actions::{NormalizedAction, NormalizedSwap},
+        structured_trace::TransactionTraceWithLogs,
+        tree::GasDetails,
+    };
+    use reth_primitives::{Address, B256, U256};
+    use reth_rpc_types::trace::parity::{
+        Action as TraceAction, CallAction, CallType, TransactionTrace,
+    };
+
+    use super::*;
+
+    const EOA: Address = Address::repeat_byte(1);
+    const BOT: Address = Address::repeat_byte(2);
+    const LENDER: Address = Address::repeat_byte(3);
+    const TOKEN: Address = Address::repeat_byte(4);
+    const POOL: Address = Address::repeat_byte(5);
+
+    fn call(trace_idx: u64, from: Address, to: Address, trace_address: Vec<usize>) -> Action {
+        Action::Unclassified(TransactionTraceWithLogs {
+            trace: TransactionTrace {
+                action: TraceAction::Call(CallAction {
+                    from,
+                    to,
+                    call_type: CallType::Call,
+                    gas: U64::ZERO,
+                    input: Bytes::from_static(&[0xab, 0xcd, 0xef, 0x01]),
+                    value: U256::ZERO,
+                }),
+                error: None,
+                result: None,
+                subtraces: 0,
+                trace_address,
+            },
+            logs: vec![],
+            msg_sender: from,
+            trace_idx,
+            decoded_data: None,
+        })
+    }
+
+    fn transfer(trace_index: u64, from: Address, to: Address, amount: u64) -> Action {
+        Action::Transfer(NormalizedTransfer {
+            trace_index,
+            from,
+            to,
+            token: TokenInfoWithAddress { address: TOKEN, ..Default::default() },
+            amount: Rational::from(amount),
+            ..Default::default()
+        })
+    }
+
+    /// the bot borrows from the lender, swaps in the callback and repays
+    /// `repay` tokens
+    fn flash_loan_root(repay: u64) -> Root<Action> {
+        let mut root = Root {
+            position: 0,
+            head: Node::new(0, EOA, vec![]),
+            tx_hash: B256::ZERO,
+            private: false,
+            total_msg_value_transfers: vec![],
+            gas_details: GasDetails::default(),
+            data_store: NodeData(vec![Some(vec![call(0, EOA, BOT, vec![])])]),
+            action_kinds: None,
+            aggregator_route: None,
+        };
+
+        let mut insert = |index: u64, from: Address, trace_address: Vec<usize>, action| {
+            let mut node = Node::new(index, from, trace_address);
+            node.callback = index == 3;
+            root.insert(node, vec![action]);
+        };
+        insert(1, BOT, vec![0], call(1, BOT, LENDER, vec![0]));
+        insert(2, LENDER, vec![0, 0], transfer(2, LENDER, BOT, 100));
+        insert(3, LENDER, vec![0, 1], call(3, LENDER, BOT, vec![0, 1]));
+        insert(
+            4,
+            BOT,
+            vec![0, 1, 0],
+            Action::Swap(NormalizedSwap { trace_index: 4, pool: POOL, ..Default::default() }),
+        );
+        insert(5, BOT, vec![0, 1, 1], transfer(5, BOT, LENDER, repay));
+
+        root
+    }
+
+    #[test]
+    fn test_unknown_flash_loan() {
+        let mut root = flash_loan_root(101);
+        classify_root(&mut root);
+
+        let loans = root
+            .collect(&TreeSearchBuilder::default().with_action(Action::is_flash_loan))
+            .into_iter()
+            .filter_map(Action::try_flash_loan)
+            .collect::<Vec<_>>();
+        assert_eq!(loans.len(), 1);
+
+        let loan = &loans[0];
+        assert_eq!(loan.protocol, Protocol::Unknown);
+        assert_eq!(loan.trace_index, 1);
+        assert_eq!(loan.pool, LENDER);
+        assert_eq!(loan.receiver_contract, BOT);
+        assert_eq!(loan.amounts, vec![Rational::from(100)]);
+        assert_eq!(loan.fees_paid, vec![Rational::from(1)]);
+        assert_eq!(loan.repayments.len(), 1);
+        assert_eq!(
+            loan.child_actions
+                .iter()
+                .map(|action| action.get_trace_index())
+                .collect::<Vec<_>>(),
+            vec![2, 4]
+        );
+
+        // the nested actions are only counted through the loan
+        assert!(root
+            .collect(
+                &TreeSearchBuilder::default().with_actions([Action::is_swap, Action::is_transfer])
+            )
+            .is_empty());
+    }
+
+    #[test]
+    fn test_unrepaid_loan_not_classified() {
+        let mut root = flash_loan_root(99);
+        classify_root(&mut root);
+
+        assert!(root
+            .collect(&TreeSearchBuilder::default().with_action(Action::is_flash_loan))
+            .is_empty());
+        assert_eq!(
+            root.collect(&TreeSearchBuilder::default().with_action(Action::is_transfer))
+                .len(),
+            2
+        );
+    }
+}
@@ -1,39 +1,53 @@
 use std::{cmp::min, sync::Arc};
 
-use alloy_primitives::{Log, U256};
-use brontes_core::missing_token_info::load_missing_token_info;
+use alloy_primitives::{Log, Selector, U256};
+use brontes_core::missing_token_info::load_missing_token_info_with_hint;
 use brontes_pricing::types::PoolUpdate;
 use brontes_types::{
+    db::token_info::TransferFee,
     normalized_actions::{
         pool::NormalizedNewPool, MultiCallFrameClassification, MultiFrameRequest, NormalizedAction,
         NormalizedEthTransfer, NormalizedTransfer,
     },
     tree::root::NodeData,
-    ToScaledRational,
+    FastHashMap, Protocol, ToScaledRational,
 };
 use malachite::{num::basic::traits::Zero, Rational};
+use parking_lot::Mutex;
 
+mod aggregator_routes;
+mod collateral_swaps;
+mod flash_loans;
+mod spill;
 mod tree_pruning;
 pub(crate) mod utils;
+use aggregator_routes::label_aggregator_routes;
 use brontes_database::libmdbx::{DBWriter, LibmdbxReader};
 use brontes_pricing::types::DexPriceMsg;
 use brontes_types::{
     normalized_actions::{Action, SelfdestructWithIndex},
     structured_trace::{TraceActions, TransactionTraceWithLogs, TxTrace},
     traits::TracingProvider,
-    tree::{BlockTree, GasDetails, Node, Root},
+    tree::{BlockTree, GasDetails, Node, RevertedTx, Root},
 };
+use collateral_swaps::classify_collateral_swaps;
+use flash_loans::classify_unknown_flash_loans;
 use futures::future::join_all;
 use itertools::Itertools;
 use malachite::num::arithmetic::traits::Abs;
 use reth_primitives::{Address, Header};
 use reth_rpc_types::trace::parity::{Action as TraceAction, CallType};
+use spill::TraceSpill;
 use tokio::sync::mpsc::UnboundedSender;
 use tracing::{error, trace};
 use tree_pruning::{account_for_tax_tokens, remove_possible_transfer_double_counts};
-use utils::{decode_transfer, get_coinbase_transfer};
+use utils::{
+    decode_transfer, diamond_facet, direct_delegate_call, get_coinbase_transfer, get_parent_trace,
+    is_callback_frame, proxy_implementation, resolve_msg_senders, reverted_frames,
+    transfer_decimal_hint, DecodedTransfer,
+};
 
-use self::erc20::try_decode_transfer;
+use self::erc20::{try_decode_approval, try_decode_transfer};
 use crate::{
     classifiers::*, multi_frame_classification::parse_multi_frame_requests, ActionCollection,
     FactoryDiscoveryDispatch,
@@ -45,6 +59,9 @@ pub struct Classifier<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> {
     libmdbx:               &'db DB,
     provider:              Arc<T>,
     pricing_update_sender: UnboundedSender<DexPriceMsg>,
+    /// proxy implementations resolved while classifying, by proxy, selector
+    /// and block. Entries are dropped once their block's tree is built
+    proxies:               Arc<Mutex<FastHashMap<(Address, Selector, u64), Option<Address>>>>,
 }
 
 impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> Classifier<'db, T, DB> {
@@ -53,7 +70,7 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> Classifier<'db, T, D
         pricing_update_sender: UnboundedSender<DexPriceMsg>,
         provider: Arc<T>,
     ) -> Self {
-        Self { libmdbx, pricing_update_sender, provider }
+        Self { libmdbx, pricing_update_sender, provider, proxies: Arc::default() }
     }
 
     pub fn block_load_failure(&self, number: u64) {
@@ -75,8 +92,19 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> Classifier<'db, T, D
                 .unwrap();
         }
 
+        let base_fee = header.base_fee_per_gas.unwrap_or_default() as u128;
+        let reverted_txs = traces
+            .iter()
+            .filter(|trace| !trace.is_success && !trace.trace.is_empty())
+            .map(|trace| RevertedTx::new(trace, base_fee))
+            .collect_vec();
+
         let tx_roots = self.build_tx_trees(traces, &header).await;
+        self.proxies
+            .lock()
+            .retain(|(_, _, block), _| *block != block_number);
         let mut tree = BlockTree::new(header, tx_roots.len());
+        tree.reverted_txs = reverted_txs;
 
         // send out all updates
         let further_classification_requests =
@@ -86,6 +114,9 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> Classifier<'db, T, D
         remove_possible_transfer_double_counts(&mut tree);
 
         self.finish_classification(&mut tree, further_classification_requests);
+        classify_unknown_flash_loans(&mut tree);
+        classify_collateral_swaps(&mut tree);
+        label_aggregator_routes(&mut tree);
         tree.finalize_tree();
 
         tree
@@ -131,138 +162,23 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> Classifier<'db, T, D
         traces: Vec<TxTrace>,
         header: &Header,
     ) -> Vec<TxTreeResult> {
+        if spill::should_spill(&traces) {
+            match TraceSpill::new(&traces) {
+                Ok(spill) => {
+                    drop(traces);
+                    return self.build_spilled_tx_trees(spill, header).await
+                }
+                Err(e) => {
+                    error!(block = header.number, err = %e, "failed to spill block traces to disk")
+                }
+            }
+        }
+
         join_all(
             traces
                 .into_iter()
                 .enumerate()
-                .map(|(tx_idx, mut trace)| async move {
-                    // here only traces where the root tx failed are filtered out
-                    if trace.trace.is_empty() || !trace.is_success {
-                        tracing::trace!(
-                            empty = trace.trace.is_empty(),
-                            is_success = trace.is_success
-                        );
-                        return None
-                    }
-                    // post classification processing collectors
-                    let mut further_classification_requests = Vec::new();
-                    let mut pool_updates: Vec<DexPriceMsg> = Vec::new();
-
-                    let root_trace = trace.trace.remove(0);
-
-                    let address = root_trace.get_from_addr();
-                    let trace_idx = root_trace.trace_idx;
-
-                    let classification = self
-                        .process_classification(
-                            header.number,
-                            None,
-                            &NodeData(vec![]),
-                            tx_idx as u64,
-                            trace_idx,
-                            root_trace,
-                            &trace.trace,
-                            &mut further_classification_requests,
-                            &mut pool_updates,
-                        )
-                        .await;
-
-                    let node = Node::new(trace_idx, address, vec![]);
-
-                    let total_msg_value_transfers = classification
-                        .iter()
-                        .filter_map(|s| s.get_msg_value_not_eth_transfer())
-                        .collect::<Vec<NormalizedEthTransfer>>();
-
-                    let mut tx_root = Root {
-                        position: tx_idx,
-                        head: node,
-                        tx_hash: trace.tx_hash,
-                        private: false,
-                        total_msg_value_transfers,
-                        gas_details: GasDetails {
-                            coinbase_transfer:   None,
-                            gas_used:            trace.gas_used,
-                            effective_gas_price: trace.effective_price,
-                            priority_fee:        trace.effective_price
-                                - (header.base_fee_per_gas.unwrap_or_default() as u128),
-                        },
-                        data_store: NodeData(vec![Some(classification)]),
-                    };
-
-                    let tx_trace = &trace.trace;
-                    for trace in &trace.trace {
-                        let from_addr = trace.get_from_addr();
-
-                        let node = Node::new(
-                            trace.trace_idx,
-                            from_addr,
-                            trace.trace.trace_address.clone(),
-                        );
-
-                        if trace.trace.error.is_none() {
-                            if let Some(coinbase_transfer) =
-                                get_coinbase_transfer(header.beneficiary, &trace.trace.action)
-                            {
-                                if let Some(coinbase) = &mut tx_root.gas_details.coinbase_transfer {
-                                    *coinbase += coinbase_transfer;
-                                } else {
-                                    tx_root.gas_details.coinbase_transfer = Some(coinbase_transfer);
-                                }
-
-                                let classification = Action::EthTransfer(NormalizedEthTransfer {
-                                    from:              from_addr,
-                                    to:                trace.get_to_address(),
-                                    value:             trace.get_msg_value(),
-                                    trace_index:       trace.trace_idx,
-                                    coinbase_transfer: true,
-                                });
-
-                                tx_root.insert(node, vec![classification]);
-                                continue
-                            }
-                        }
-
-                        let classification = self
-                            .process_classification(
-                                header.number,
-                                Some(&tx_root.head),
-                                &tx_root.data_store,
-                                tx_idx as u64,
-                                trace.trace_idx,
-                                trace.clone(),
-                                tx_trace,
-                                &mut further_classification_requests,
-                                &mut pool_updates,
-                            )
-                            .await;
-
-                        tx_root.total_msg_value_transfers.extend(
-                            classification
-                                .iter()
-                                .filter_map(|s| s.get_msg_value_not_eth_transfer()),
-                        );
-
-                        tx_root.insert(node, classification);
-                    }
-
-                    // Here we reverse the requests to ensure that we always classify the most
-                    // nested action & its children first. This is to prevent the
-                    // case where we classify a parent action where its children also require
-                    // further classification.
-                    let tx_classification_requests = if !further_classification_requests.is_empty()
-                    {
-                        further_classification_requests.reverse();
-                        Some((tx_idx, further_classification_requests))
-                    } else {
-                        None
-                    };
-                    Some(TxTreeResult {
-                        root: tx_root,
-                        further_classification_requests: tx_classification_requests,
-                        pool_updates,
-                    })
-                }),
+                .map(|(tx_idx, trace)| self.build_tx_tree(tx_idx, trace, header)),
         )
         .await
         .into_iter()
@@ -270,6 +186,164 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> Classifier<'db, T, D
         .collect_vec()
     }
 
+    /// Builds the trees one transaction at a time, only loading the trace of
+    /// the transaction being classified out of the spill.
+    async fn build_spilled_tx_trees(
+        &self,
+        spill: TraceSpill,
+        header: &Header,
+    ) -> Vec<TxTreeResult> {
+        let mut results = Vec::with_capacity(spill.len());
+        for tx_idx in 0..spill.len() {
+            if let Some(result) = self.build_tx_tree(tx_idx, spill.get(tx_idx), header).await {
+                results.push(result);
+            }
+        }
+
+        results
+    }
+
+    async fn build_tx_tree(
+        &self,
+        tx_idx: usize,
+        mut trace: TxTrace,
+        header: &Header,
+    ) -> Option<TxTreeResult> {
+        // here only traces where the root tx failed are filtered out
+        if trace.trace.is_empty() || !trace.is_success {
+            tracing::trace!(empty = trace.trace.is_empty(), is_success = trace.is_success);
+            return None
+        }
+        // post classification processing collectors
+        let mut further_classification_requests = Vec::new();
+        let mut pool_updates: Vec<DexPriceMsg> = Vec::new();
+
+        resolve_msg_senders(&mut trace.trace);
+        let root_trace = trace.trace.remove(0);
+
+        let address = root_trace.get_from_addr();
+        let trace_idx = root_trace.trace_idx;
+
+        let classification = self
+            .process_classification(
+                header.number,
+                None,
+                &NodeData(vec![]),
+                tx_idx as u64,
+                trace_idx,
+                root_trace,
+                &trace.trace,
+                &mut further_classification_requests,
+                &mut pool_updates,
+            )
+            .await;
+
+        let node = Node::new(trace_idx, address, vec![]);
+
+        let total_msg_value_transfers = classification
+            .iter()
+            .filter_map(|s| s.get_msg_value_not_eth_transfer())
+            .collect::<Vec<NormalizedEthTransfer>>();
+
+        let mut tx_root = Root {
+            position: tx_idx,
+            head: node,
+            tx_hash: trace.tx_hash,
+            private: false,
+            total_msg_value_transfers,
+            gas_details: GasDetails {
+                coinbase_transfer:   None,
+                gas_used:            trace.gas_used,
+                effective_gas_price: trace.effective_price,
+                priority_fee:        trace.effective_price
+                    - (header.base_fee_per_gas.unwrap_or_default() as u128),
+                blob_gas_paid:       trace.blob_gas_paid,
+            },
+            data_store: NodeData(vec![Some(classification)]),
+            action_kinds: None,
+            aggregator_route: None,
+        };
+
+        let tx_trace = &trace.trace;
+        let reverted = reverted_frames(tx_trace);
+        for (trace, reverted) in trace.trace.iter().zip(reverted) {
+            let from_addr = trace.get_from_addr();
+
+            let mut node = Node::new(trace.trace_idx, from_addr, trace.trace.trace_address.clone());
+            node.callback = is_callback_frame(tx_trace, trace);
+            node.reverted = reverted;
+
+            // none of the actions of a reverted frame happened, so it isn't
+            // classified and neither pool updates nor discovered pools come out
+            // of it
+            if reverted {
+                tx_root.insert(node, vec![Action::Revert]);
+                continue
+            }
+
+            if let Some(coinbase_transfer) =
+                get_coinbase_transfer(header.beneficiary, &trace.trace.action)
+            {
+                if let Some(coinbase) = &mut tx_root.gas_details.coinbase_transfer {
+                    *coinbase += coinbase_transfer;
+                } else {
+                    tx_root.gas_details.coinbase_transfer = Some(coinbase_transfer);
+                }
+
+                // a selfdestruct's value is the contract's balance, not a msg
+                // value, so the payment is taken from what was matched
+                let classification = Action::EthTransfer(NormalizedEthTransfer {
+                    from:              from_addr,
+                    to:                header.beneficiary,
+                    value:             U256::from(coinbase_transfer),
+                    trace_index:       trace.trace_idx,
+                    coinbase_transfer: true,
+                });
+
+                tx_root.insert(node, vec![classification]);
+                continue
+            }
+
+            let classification = self
+                .process_classification(
+                    header.number,
+                    Some(&tx_root.head),
+                    &tx_root.data_store,
+                    tx_idx as u64,
+                    trace.trace_idx,
+                    trace.clone(),
+                    tx_trace,
+                    &mut further_classification_requests,
+                    &mut pool_updates,
+                )
+                .await;
+
+            tx_root.total_msg_value_transfers.extend(
+                classification
+                    .iter()
+                    .filter_map(|s| s.get_msg_value_not_eth_transfer()),
+            );
+
+            tx_root.insert(node, classification);
+        }
+
+        // Here we reverse the requests to ensure that we always classify the most
+        // nested action & its children first. This is to prevent the
+        // case where we classify a parent action where its children also require
+        // further classification.
+        let tx_classification_requests = if !further_classification_requests.is_empty() {
+            further_classification_requests.reverse();
+            Some((tx_idx, further_classification_requests))
+        } else {
+            None
+        };
+        Some(TxTreeResult {
+            root: tx_root,
+            further_classification_requests: tx_classification_requests,
+            pool_updates,
+        })
+    }
+
     async fn process_classification(
         &self,
         block_number: u64,
@@ -342,6 +416,7 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> Classifier<'db, T, D
                     node_data_store,
                     tx_idx,
                     trace.clone(),
+                    full_trace,
                     trace_index,
                 )
                 .await
@@ -367,14 +442,13 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> Classifier<'db, T, D
             return (vec![], vec![Action::Unclassified(trace)])
         }
         let mut call_info = trace.get_callframe_info();
+        let mut delegated_traces = Vec::new();
+        collect_delegated_traces(full_trace, &trace.trace.trace_address, &mut delegated_traces);
 
         // Add logs of delegated calls to the root trace, only if the delegated call is
         // from the same address / in the same call frame.
         if let TraceAction::Call(root_call) = &trace.trace.action {
-            let mut delegated_traces = Vec::new();
-            collect_delegated_traces(full_trace, &trace.trace.trace_address, &mut delegated_traces);
-
-            for delegated_trace in delegated_traces {
+            for delegated_trace in &delegated_traces {
                 if let TraceAction::Call(delegated_call) = &delegated_trace.trace.action {
                     if let CallType::DelegateCall = delegated_call.call_type {
                         if delegated_call.from == root_call.to {
@@ -386,9 +460,42 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> Classifier<'db, T, D
             }
         }
 
-        if let Some(results) =
-            ProtocolClassifier::default().dispatch(call_info, self.libmdbx, block, tx_idx)
+        // A proxy or diamond that isn't mapped is classified as the implementation it
+        // delegates to. The delegate call into the implementation is then left to the
+        // proxy. The protocol is resolved once here and handed to the dispatch
+        let protocol = self
+            .libmdbx
+            .get_protocol_at(call_info.target_address, block)
+            .ok();
+        if protocol.is_some()
+            && trace.is_delegate_call()
+            && self
+                .libmdbx
+                .get_protocol_at(call_info.from_address, block)
+                .is_err()
+            && self
+                .resolve_implementation(block, call_info.from_address, &call_info.call_data)
+                .await
+                == Some(call_info.target_address)
         {
+            return (vec![], vec![Action::Unclassified(trace)])
+        }
+        call_info.protocol = match protocol {
+            Some(protocol) => Some(protocol),
+            None => {
+                self.proxied_protocol(block, &trace, &delegated_traces)
+                    .await
+            }
+        };
+
+        let dispatched = match call_info.protocol {
+            Some(_) => {
+                ProtocolClassifier::default().dispatch(call_info, self.libmdbx, block, tx_idx)
+            }
+            None => None,
+        };
+
+        if let Some(results) = dispatched {
             if results.1.is_new_pool() {
                 let Action::NewPool(p) = &results.1 else { unreachable!() };
                 self.insert_new_pool(block, p).await;
@@ -420,6 +527,78 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> Classifier<'db, T, D
         }
     }
 
+    /// Flags the token of a transfer that delivered less than was sent. The
+    /// swaps it was part of are corrected by [`account_for_tax_tokens`] once
+    /// the tree is built
+    async fn record_transfer_fee(&self, transfer: &NormalizedTransfer) {
+        let fee = TransferFee::observed(&(&transfer.amount + &transfer.fee), &transfer.fee);
+        // rebasing tokens are often off by a few wei, which isn't a fee
+        if fee.fee_bps == 0
+            || self
+                .libmdbx
+                .try_fetch_transfer_fee(transfer.token.address)
+                .is_ok_and(|known| known == Some(fee))
+        {
+            return
+        }
+
+        if self
+            .libmdbx
+            .write_transfer_fee(transfer.token.address, fee)
+            .await
+            .is_err()
+        {
+            error!(token=?transfer.token.address, "failed to record the transfer fee of the token");
+        }
+    }
+
+    /// The protocol of the mapped implementation the call's target delegates
+    /// it to, if the target is an EIP-1967 proxy or a diamond routing the call
+    /// to it. Only delegate calls the target makes directly are looked at, and
+    /// the proxy is only queried for an implementation that is mapped, so
+    /// unmapped proxies such as most tokens cost nothing
+    async fn proxied_protocol(
+        &self,
+        block: u64,
+        trace: &TransactionTraceWithLogs,
+        delegated_traces: &[&TransactionTraceWithLogs],
+    ) -> Option<Protocol> {
+        let (proxy, implementation) = direct_delegate_call(trace, delegated_traces)?;
+        let protocol = self.libmdbx.get_protocol_at(implementation, block).ok()?;
+
+        let proxied = self
+            .resolve_implementation(block, proxy, &trace.get_calldata())
+            .await;
+
+        (proxied == Some(implementation)).then_some(protocol)
+    }
+
+    /// The contract `proxy` delegates a call with the given calldata to at the
+    /// block, from its EIP-1967 slot or, for a diamond, the facet of the
+    /// selector. Each proxy and selector is only looked up once per block
+    async fn resolve_implementation(
+        &self,
+        block: u64,
+        proxy: Address,
+        call_data: &[u8],
+    ) -> Option<Address> {
+        let selector = Selector::try_from(call_data.get(..4)?).ok()?;
+        if let Some(implementation) = self.proxies.lock().get(&(proxy, selector, block)) {
+            return *implementation
+        }
+
+        let implementation = match proxy_implementation(self.provider.as_ref(), block, proxy).await
+        {
+            Some(implementation) => Some(implementation),
+            None => diamond_facet(&self.provider, block, proxy, selector).await,
+        };
+        self.proxies
+            .lock()
+            .insert((proxy, selector, block), implementation);
+
+        implementation
+    }
+
     async fn classify_transfer(
         &self,
         tx_idx: u64,
@@ -431,6 +610,41 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> Classifier<'db, T, D
             return None
         };
 
+        // erc721 `transferFrom` has the same selector as the erc20 one, so nft
+        // transfers are told apart by the events of the collection
+        let nft_transfers = trace
+            .logs
+            .iter()
+            .filter(|log| log.address == trace.get_to_address())
+            .filter_map(decode_transfer)
+            .filter_map(|transfer| transfer.into_nft_transfer(trace_idx, trace.get_msg_value()))
+            .map(Action::NftTransfer)
+            .collect_vec();
+
+        if !nft_transfers.is_empty() {
+            let mut result = nft_transfers;
+            if trace.get_msg_value() != U256::ZERO {
+                result.push(Action::EthTransfer(NormalizedEthTransfer {
+                    coinbase_transfer: false,
+                    trace_index:       trace_idx,
+                    to:                trace.get_to_address(),
+                    from:              trace.get_from_addr(),
+                    value:             trace.get_msg_value(),
+                }));
+            }
+
+            return Some((vec![], result))
+        }
+
+        if let Some(approval) = try_decode_approval(
+            trace_idx,
+            &trace.get_calldata(),
+            trace.get_from_addr(),
+            trace.get_to_address(),
+        ) {
+            return Some((vec![], vec![Action::Approval(approval)]))
+        }
+
         // Attempt to decode the transfer
         match try_decode_transfer(
             trace_idx,
@@ -447,7 +661,9 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> Classifier<'db, T, D
             Ok(mut transfer) => {
                 // go through the log to look for discrepancy of transfer amount
                 for log in &trace.logs {
-                    if let Some((addr, from, to, amount)) = decode_transfer(log) {
+                    if let Some(DecodedTransfer::Erc20 { token: addr, from, to, amount }) =
+                        decode_transfer(log)
+                    {
                         if addr != transfer.token.address
                             || transfer.from != from
                             || transfer.to != to
@@ -468,6 +684,10 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> Classifier<'db, T, D
                     }
                 }
 
+                if transfer.fee != Rational::ZERO {
+                    self.record_transfer_fee(&transfer).await;
+                }
+
                 let mut result = vec![Action::Transfer(transfer.clone())];
                 if trace.get_msg_value() != U256::ZERO {
                     result.push(Action::EthTransfer(NormalizedEthTransfer {
@@ -492,9 +712,19 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> Classifier<'db, T, D
             }
             Err(_) => {
                 for log in &trace.logs {
-                    if let Some((addr, from, to, amount)) = decode_transfer(log) {
+                    if let Some(DecodedTransfer::Erc20 { token: addr, from, to, amount }) =
+                        decode_transfer(log)
+                    {
                         if self.libmdbx.try_fetch_token_info(addr).is_err() {
-                            load_missing_token_info(&self.provider, self.libmdbx, block, addr).await
+                            let hint = transfer_decimal_hint(self.libmdbx, addr, from, to, amount);
+                            load_missing_token_info_with_hint(
+                                &self.provider,
+                                self.libmdbx,
+                                block,
+                                addr,
+                                hint,
+                            )
+                            .await
                         }
 
                         let token_info = self.libmdbx.try_fetch_token_info(addr).ok()?;
@@ -548,6 +778,7 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> Classifier<'db, T, D
         node_data_store: &NodeData<Action>,
         _tx_idx: u64,
         trace: TransactionTraceWithLogs,
+        full_trace: &[TransactionTraceWithLogs],
         trace_index: u64,
     ) -> (Vec<DexPriceMsg>, Vec<Action>) {
         let created_addr = trace.get_create_output();
@@ -557,6 +788,31 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> Classifier<'db, T, D
             return (vec![], vec![Action::Unclassified(trace)])
         }
 
+        // solidly and algebra forks are deployed by many factories, so they are
+        // discovered through the event their factory emits instead of its address
+        if let Some(pool) = get_parent_trace(full_trace, &trace).and_then(|parent| {
+            discover_solidly_pool(created_addr, trace_index, &parent.logs).or_else(|| {
+                // algebra factories create their pools through a separate pool deployer
+                let factory = get_parent_trace(full_trace, parent)?;
+                discover_algebra_pool(created_addr, trace_index, &factory.logs)
+            })
+        }) {
+            trace!(
+                target: "brontes_classifier::discovery",
+                "Discovered new {} pool: Address:{}",
+                pool.protocol,
+                pool.pool_address,
+            );
+            self.insert_new_pool(block, &pool).await;
+
+            return match pool.clone().try_into() {
+                Ok(config) => {
+                    (vec![DexPriceMsg::DiscoveredPool(config)], vec![Action::NewPool(pool)])
+                }
+                Err(_) => (vec![], vec![Action::NewPool(pool)]),
+            }
+        }
+
         // get the immediate parent node of this create action so that we can decode the
         // deployment function params
         let mut all_nodes = Vec::new();
@@ -634,6 +890,9 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> Classifier<'db, T, D
         .unzip()
     }
 
+    /// Discovered pools are written to `AddressToProtocolInfo` and
+    /// `PoolCreationBlocks`, so later runs load them from the db instead of
+    /// having to rediscover them
     async fn insert_new_pool(&self, block: u64, pool: &NormalizedNewPool) {
         if self
             .libmdbx
@@ -677,6 +936,11 @@ fn collect_delegated_traces<'a>(
         if subtrace_address.starts_with(parent_trace_address)
             && subtrace_address.len() == parent_trace_address.len() + 1
         {
+            // the caller re-entering the contract in a callback makes its own
+            // calls, their logs aren't the contract's
+            if is_callback_frame(traces, trace) {
+                continue
+            }
             delegated_traces.push(trace);
             collect_delegated_traces(traces, subtrace_address, delegated_traces);
         }
@@ -0,0 +1,102 @@
+//! Blocks with an enormous amount of call frames (airdrop claims,
+//! inscriptions) can't have all of their transactions classified at once
+//! without running out of memory. For these, the traces are written to an
+//! unlinked temp file that is memory mapped, and transactions are then
+//! classified one at a time, only deserializing the trace that is being worked
+//! on.
+use std::io::{BufWriter, Write};
+
+use brontes_types::{db::traces::TxTraceRedefined, structured_trace::TxTrace};
+use memmap2::Mmap;
+use redefined::RedefinedConvert;
+
+/// Total amount of call frames in a block past which we spill to disk.
+pub(crate) const SPILL_FRAME_THRESHOLD: usize = 500_000;
+
+/// rkyv archives need to be aligned when they are read back
+const ENTRY_ALIGNMENT: usize = 16;
+
+pub(crate) fn should_spill(traces: &[TxTrace]) -> bool {
+    traces.iter().map(|trace| trace.trace.len()).sum::<usize>() > SPILL_FRAME_THRESHOLD
+}
+
+pub(crate) struct TraceSpill {
+    map:     Mmap,
+    /// offset and length of each transactions archived trace
+    entries: Vec<(usize, usize)>,
+}
+
+impl TraceSpill {
+    pub(crate) fn new(traces: &[TxTrace]) -> eyre::Result<Self> {
+        let file = tempfile::tempfile()?;
+        let mut writer = BufWriter::new(&file);
+
+        let mut entries = Vec::with_capacity(traces.len());
+        let mut offset = 0;
+
+        for trace in traces {
+            let padding = (ENTRY_ALIGNMENT - offset % ENTRY_ALIGNMENT) % ENTRY_ALIGNMENT;
+            writer.write_all(&[0u8; ENTRY_ALIGNMENT][..padding])?;
+            offset += padding;
+
+            let redefined = TxTraceRedefined::from_source(trace.clone());
+            let bytes = rkyv::to_bytes::<_, 256>(&redefined)
+                .map_err(|e| eyre::eyre!("failed to archive trace: {e:?}"))?;
+            writer.write_all(&bytes)?;
+
+            entries.push((offset, bytes.len()));
+            offset += bytes.len();
+        }
+        writer.flush()?;
+        drop(writer);
+
+        // Safety: the file is unlinked and only owned by us, so nothing else can
+        // modify it while it's mapped
+        let map = unsafe { Mmap::map(&file)? };
+
+        Ok(Self { map, entries })
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub(crate) fn get(&self, tx_idx: usize) -> TxTrace {
+        let (offset, len) = self.entries[tx_idx];
+        let archived =
+            unsafe { rkyv::archived_root::<TxTraceRedefined>(&self.map[offset..offset + len]) };
+
+        let trace: TxTraceRedefined =
+            rkyv::Deserialize::deserialize(archived, &mut rkyv::Infallible).unwrap();
+
+        trace.to_source()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::B256;
+
+    use super::*;
+
+    #[test]
+    fn test_spilled_traces_round_trip() {
+        let traces = (0..10u64)
+            .map(|i| TxTrace {
+                block_number: 1,
+                tx_hash: B256::with_last_byte(i as u8),
+                gas_used: i as u128 * 21_000,
+                tx_index: i,
+                is_success: i % 2 == 0,
+                ..Default::default()
+            })
+            .collect::<Vec<_>>();
+
+        let spill = TraceSpill::new(&traces).unwrap();
+        assert_eq!(spill.len(), traces.len());
+
+        for (i, trace) in traces.iter().enumerate() {
+            assert_eq!(&spill.get(i), trace);
+        }
+    }
+}
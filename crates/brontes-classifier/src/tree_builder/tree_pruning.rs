@@ -10,6 +10,11 @@ use malachite::{num::basic::traits::Zero, Rational};
 /// stable token like eth before taking the fee. However this creates an
 /// accounting inaccuracy as we will register this fee swap as
 /// part of the mev messing up our profit accounting.
+///
+/// Swaps of fee on transfer tokens are corrected with the transfers under them,
+/// whose fee the classifier takes from the transfer logs. The amount in is what
+/// the swapper paid, fee included, and the amount out what the recipient
+/// received. The fee is noted on the [`NormalizedSwapWithFee`].
 pub(crate) fn account_for_tax_tokens(tree: &mut BlockTree<Action>) {
     // adjusts the amount in of the swap and notes the fee on the normalized type.
     // This is needed when swapping into the tax token as the amount out of the swap
@@ -21,6 +26,9 @@ pub(crate) fn account_for_tax_tokens(tree: &mut BlockTree<Action>) {
         |span, data| {
             let (swaps, mut transfers): (Vec<_>, Vec<_>) = span
                 .into_iter()
+                // transfers in a callback are made by the caller of the swap, so they
+                // can't be the pool's side of it
+                .filter(|node| !node.callback)
                 .filter_map(|action| Some((action.data, data.get_ref(action.data)?)))
                 .filter_map(|(idx, data)| {
                     let (mut swaps, mut transfers, mut eth_transfers): (Vec<_>, Vec<_>, Vec<_>) =
@@ -128,7 +136,9 @@ pub(crate) fn remove_possible_transfer_double_counts(tree: &mut BlockTree<Action
             let mut inner_transfers = Vec::new();
             node.collect(
                 &mut inner_transfers,
-                &TreeSearchBuilder::default().with_action(Action::is_transfer),
+                &TreeSearchBuilder::default()
+                    .with_action(Action::is_transfer)
+                    .skip_callback_frames(),
                 &|node| node.node.clone(),
                 data,
             );
@@ -160,3 +170,136 @@ pub(crate) fn remove_possible_transfer_double_counts(tree: &mut BlockTree<Action
         },
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{Bytes, U64};
+    use brontes_types::{
+        db::token_info::TokenInfoWithAddress,
+        normalized_actions::{NormalizedSwap, NormalizedTransfer},
+        structured_trace::TransactionTraceWithLogs,
+        tree::{GasDetails, Node, NodeData, Root},
+    };
+    use reth_primitives::{Address, Header, B256, U256};
+    use reth_rpc_types::trace::parity::{
+        Action as TraceAction, CallAction, CallType, TransactionTrace,
+    };
+
+    use super::*;
+
+    const EOA: Address = Address::repeat_byte(1);
+    const POOL: Address = Address::repeat_byte(2);
+    const TAX_TOKEN: Address = Address::repeat_byte(3);
+    const WETH: Address = Address::repeat_byte(4);
+
+    fn token(address: Address) -> TokenInfoWithAddress {
+        TokenInfoWithAddress { address, ..Default::default() }
+    }
+
+    fn swap(token_in: Address, amount_in: u64, token_out: Address, amount_out: u64) -> Action {
+        Action::Swap(NormalizedSwap {
+            trace_index: 1,
+            from: EOA,
+            recipient: EOA,
+            pool: POOL,
+            token_in: token(token_in),
+            token_out: token(token_out),
+            amount_in: Rational::from(amount_in),
+            amount_out: Rational::from(amount_out),
+            ..Default::default()
+        })
+    }
+
+    fn taxed_transfer(from: Address, to: Address, amount: u64, fee: u64) -> Action {
+        Action::Transfer(NormalizedTransfer {
+            trace_index: 2,
+            from,
+            to,
+            token: token(TAX_TOKEN),
+            amount: Rational::from(amount),
+            fee: Rational::from(fee),
+            ..Default::default()
+        })
+    }
+
+    /// the eoa swaps with the pool, which moves the tax token in the nested
+    /// transfer
+    fn tree(swap: Action, transfer: Action) -> BlockTree<Action> {
+        let call = Action::Unclassified(TransactionTraceWithLogs {
+            trace:        TransactionTrace {
+                action:        TraceAction::Call(CallAction {
+                    from:      EOA,
+                    to:        POOL,
+                    call_type: CallType::Call,
+                    gas:       U64::ZERO,
+                    input:     Bytes::new(),
+                    value:     U256::ZERO,
+                }),
+                error:         None,
+                result:        None,
+                subtraces:     0,
+                trace_address: vec![],
+            },
+            logs:         vec![],
+            msg_sender:   EOA,
+            trace_idx:    0,
+            decoded_data: None,
+        });
+
+        let mut root = Root {
+            position: 0,
+            head: Node::new(0, EOA, vec![]),
+            tx_hash: B256::ZERO,
+            private: false,
+            total_msg_value_transfers: vec![],
+            gas_details: GasDetails::default(),
+            data_store: NodeData(vec![Some(vec![call])]),
+            action_kinds: None,
+            aggregator_route: None,
+        };
+        root.insert(Node::new(1, EOA, vec![0]), vec![swap]);
+        root.insert(Node::new(2, POOL, vec![0, 0]), vec![transfer]);
+
+        let mut tree = BlockTree::new(Header::default(), 1);
+        tree.insert_root(root);
+        tree
+    }
+
+    fn swap_with_fee(mut tree: BlockTree<Action>) -> NormalizedSwapWithFee {
+        account_for_tax_tokens(&mut tree);
+
+        let swaps = tree.roots()[0]
+            .collect(&TreeSearchBuilder::default().with_action(Action::is_swap_with_fee))
+            .into_iter()
+            .filter_map(Action::try_swap_with_fee)
+            .collect::<Vec<_>>();
+        assert_eq!(swaps.len(), 1);
+
+        swaps.into_iter().next().unwrap()
+    }
+
+    #[test]
+    fn test_tax_token_out() {
+        // the pool sends 100 of which the recipient gets 95
+        let swap =
+            swap_with_fee(tree(swap(WETH, 10, TAX_TOKEN, 100), taxed_transfer(POOL, EOA, 95, 5)));
+
+        assert_eq!(swap.amount_in, Rational::from(10));
+        assert_eq!(swap.amount_out, Rational::from(95));
+        assert_eq!(swap.fee_token.address, TAX_TOKEN);
+        assert_eq!(swap.fee_amount, Rational::from(5));
+    }
+
+    #[test]
+    fn test_tax_token_in() {
+        // the eoa pays 100 of which the pool gets 95, and the pool reports what
+        // it received
+        let swap =
+            swap_with_fee(tree(swap(TAX_TOKEN, 95, WETH, 10), taxed_transfer(EOA, POOL, 95, 5)));
+
+        assert_eq!(swap.amount_in, Rational::from(100));
+        assert_eq!(swap.amount_out, Rational::from(10));
+        assert_eq!(swap.fee_token.address, TAX_TOKEN);
+        assert_eq!(swap.fee_amount, Rational::from(5));
+    }
+}
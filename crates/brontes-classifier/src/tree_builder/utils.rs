@@ -1,33 +1,540 @@
-use alloy_primitives::{Address, FixedBytes, Log, B256, U256};
+use std::{collections::HashMap, sync::Arc};
+
+use alloy_primitives::{Address, FixedBytes, Log, Selector, B256, U256};
+use alloy_sol_types::SolEvent;
+use brontes_core::missing_token_info::DecimalHint;
+use brontes_types::{
+    db::traits::LibmdbxReader,
+    make_call_request,
+    normalized_actions::{NftStandard, NormalizedNftTransfer},
+    structured_trace::{TraceActions, TransactionTraceWithLogs},
+    traits::TracingProvider,
+};
 use hex_literal::hex;
-use reth_rpc_types::trace::parity::Action;
+use reth_rpc_types::trace::parity::{Action, CallType};
 
+/// The eth a frame paid the block's builder, at whatever depth of the
+/// transaction it sits. Searchers mostly pay from their contract rather than in
+/// the root call, either with a plain call or by selfdestructing a contract
+/// with the builder as the beneficiary. Delegate, static and callcode frames
+/// carry the value of their context without moving any eth to the callee, so
+/// they are never a payment.
 pub(crate) fn get_coinbase_transfer(builder: Address, action: &Action) -> Option<u128> {
-    match action {
-        Action::Call(action) => {
-            if action.to == builder && !action.value.is_zero() {
-                return Some(action.value.to());
-            }
-            None
-        }
-        _ => None,
-    }
+    let value = match action {
+        Action::Call(call) if call.call_type == CallType::Call && call.to == builder => call.value,
+        Action::Selfdestruct(destruct) if destruct.refund_address == builder => destruct.balance,
+        _ => return None,
+    };
+
+    (!value.is_zero()).then(|| value.to())
 }
 
 const TRANSFER_TOPIC: B256 =
     FixedBytes(hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"));
 
-pub(crate) fn decode_transfer(log: &Log) -> Option<(Address, Address, Address, U256)> {
-    if log.topics().len() != 3 {
-        return None;
+alloy_sol_macro::sol!(
+    event TransferSingle(
+        address indexed operator,
+        address indexed from,
+        address indexed to,
+        uint256 id,
+        uint256 value
+    );
+    event TransferBatch(
+        address indexed operator,
+        address indexed from,
+        address indexed to,
+        uint256[] ids,
+        uint256[] values
+    );
+);
+
+/// A token transfer decoded from its event
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum DecodedTransfer {
+    Erc20 {
+        token:  Address,
+        from:   Address,
+        to:     Address,
+        amount: U256,
+    },
+    Nft {
+        collection: Address,
+        standard:   NftStandard,
+        from:       Address,
+        to:         Address,
+        token_ids:  Vec<U256>,
+        amounts:    Vec<U256>,
+    },
+}
+
+impl DecodedTransfer {
+    pub(crate) fn into_nft_transfer(
+        self,
+        trace_index: u64,
+        msg_value: U256,
+    ) -> Option<NormalizedNftTransfer> {
+        let Self::Nft { collection, standard, from, to, token_ids, amounts } = self else {
+            return None
+        };
+
+        Some(NormalizedNftTransfer {
+            trace_index,
+            collection,
+            standard,
+            from,
+            to,
+            token_ids,
+            amounts,
+            msg_value,
+        })
+    }
+}
+
+/// Erc20 and erc721 share the `Transfer` event, an erc721 indexes the token id
+/// so it has a topic more
+pub(crate) fn decode_transfer(log: &Log) -> Option<DecodedTransfer> {
+    let topics = log.topics();
+    let topic = topics.first()?;
+
+    if *topic == TRANSFER_TOPIC {
+        let from = Address::from_slice(&topics.get(1)?[12..]);
+        let to = Address::from_slice(&topics.get(2)?[12..]);
+
+        return match topics.len() {
+            3 => Some(DecodedTransfer::Erc20 {
+                token: log.address,
+                from,
+                to,
+                amount: U256::try_from_be_slice(&log.data.data[..])?,
+            }),
+            4 => Some(DecodedTransfer::Nft {
+                collection: log.address,
+                standard: NftStandard::Erc721,
+                from,
+                to,
+                token_ids: vec![U256::from_be_bytes(topics[3].0)],
+                amounts: vec![U256::from(1)],
+            }),
+            _ => None,
+        }
     }
 
-    if log.topics().first() == Some(&TRANSFER_TOPIC) {
-        let from = Address::from_slice(&log.topics()[1][12..]);
-        let to = Address::from_slice(&log.topics()[2][12..]);
-        let data = U256::try_from_be_slice(&log.data.data[..]).unwrap();
-        return Some((log.address, from, to, data));
+    if *topic == TransferSingle::SIGNATURE_HASH {
+        let transfer = TransferSingle::decode_log_data(&log.data, true).ok()?;
+        return Some(DecodedTransfer::Nft {
+            collection: log.address,
+            standard:   NftStandard::Erc1155,
+            from:       transfer.from,
+            to:         transfer.to,
+            token_ids:  vec![transfer.id],
+            amounts:    vec![transfer.value],
+        })
+    }
+
+    if *topic == TransferBatch::SIGNATURE_HASH {
+        let transfer = TransferBatch::decode_log_data(&log.data, true).ok()?;
+        if transfer.ids.len() != transfer.values.len() {
+            return None
+        }
+
+        return Some(DecodedTransfer::Nft {
+            collection: log.address,
+            standard:   NftStandard::Erc1155,
+            from:       transfer.from,
+            to:         transfer.to,
+            token_ids:  transfer.ids,
+            amounts:    transfer.values,
+        })
     }
 
     None
 }
+
+/// Builds the hint used to infer the decimals of a token that doesn't
+/// properly implement `decimals()`. If either side of the transfer is a known
+/// pool, the decimals of the token it is paired with are used as a prior.
+pub(crate) fn transfer_decimal_hint<DB: LibmdbxReader>(
+    db: &DB,
+    token: Address,
+    from: Address,
+    to: Address,
+    amount: U256,
+) -> DecimalHint {
+    let paired_decimals = [from, to]
+        .into_iter()
+        .filter_map(|pool| db.get_protocol_details(pool).ok())
+        .flat_map(|info| info.get_tokens())
+        .filter(|paired| *paired != token)
+        .find_map(|paired| db.try_fetch_token_decimals(paired).ok());
+
+    DecimalHint::new(vec![amount], paired_decimals)
+}
+
+/// the trace of the frame that made the given call or create
+pub(crate) fn get_parent_trace<'a>(
+    traces: &'a [TransactionTraceWithLogs],
+    trace: &TransactionTraceWithLogs,
+) -> Option<&'a TransactionTraceWithLogs> {
+    let (_, parent_trace_address) = trace.trace.trace_address.split_last()?;
+    traces
+        .iter()
+        .find(|parent| parent.trace.trace_address == parent_trace_address)
+}
+
+/// A callback is a call a contract makes back into its caller while it is still
+/// executing, e.g. a v3 pool calling `uniswapV3SwapCallback` on the router that
+/// is swapping through it. Whatever happens in the callback is done by the
+/// caller, so it isn't part of the action of the contract calling back.
+///
+/// Delegate calls run in the frame of the contract that made them, so they are
+/// skipped when looking for the caller.
+pub(crate) fn is_callback_frame(
+    traces: &[TransactionTraceWithLogs],
+    trace: &TransactionTraceWithLogs,
+) -> bool {
+    let Action::Call(call) = &trace.trace.action else { return false };
+    if call.call_type != CallType::Call || call.input.len() < 4 {
+        return false
+    }
+
+    let mut frame = trace;
+    loop {
+        let Some(parent) = get_parent_trace(traces, frame) else { return false };
+        if !parent.is_delegate_call() {
+            return parent.get_from_addr() == call.to
+        }
+        frame = parent;
+    }
+}
+
+/// Whether each frame reverted, either by erroring itself or by being under a
+/// frame that did. A call that succeeded inside a call that then reverted had
+/// its effects undone all the same. The traces are in call order, so the
+/// frames under a reverted one directly follow it.
+pub(crate) fn reverted_frames(traces: &[TransactionTraceWithLogs]) -> Vec<bool> {
+    let mut reverted_head: Option<&[usize]> = None;
+
+    traces
+        .iter()
+        .map(|trace| {
+            let trace_address = trace.trace.trace_address.as_slice();
+            if reverted_head.is_some_and(|head| trace_address.starts_with(head)) {
+                return true
+            }
+
+            reverted_head = trace.trace.error.is_some().then_some(trace_address);
+            reverted_head.is_some()
+        })
+        .collect()
+}
+
+/// Sets the `msg.sender` of each frame to the logical caller. A delegate call
+/// runs the code of its target with the `msg.sender` of the frame that made it,
+/// so going up the chain of delegate calls it's whoever called the first
+/// contract that isn't delegating. The tracer takes it from the last frame it
+/// recorded that isn't a delegate call instead, which is a sibling whenever the
+/// delegating contract made a call before, attributing the actions in the
+/// delegated code, like a router swapping for its caller, to the contract.
+///
+/// The traces are in call order, so each parent is resolved before its
+/// children.
+pub(crate) fn resolve_msg_senders(traces: &mut [TransactionTraceWithLogs]) {
+    let mut senders: HashMap<Vec<usize>, Address> = HashMap::with_capacity(traces.len());
+
+    for trace in traces.iter_mut() {
+        let trace_address = &trace.trace.trace_address;
+        match &trace.trace.action {
+            Action::Call(call) if call.call_type == CallType::DelegateCall => {
+                if let Some(sender) = trace_address
+                    .split_last()
+                    .and_then(|(_, parent)| senders.get(parent))
+                {
+                    trace.msg_sender = *sender;
+                }
+            }
+            Action::Call(_) | Action::Create(_) => trace.msg_sender = trace.get_from_addr(),
+            _ => {}
+        }
+        senders.insert(trace_address.clone(), trace.msg_sender);
+    }
+}
+
+/// `bytes32(uint256(keccak256("eip1967.proxy.implementation")) - 1)`
+const EIP1967_IMPLEMENTATION_SLOT: B256 =
+    FixedBytes(hex!("360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc"));
+
+/// The implementation `proxy` points at through its EIP-1967 slot
+pub(crate) async fn proxy_implementation<T: TracingProvider>(
+    provider: &T,
+    block: u64,
+    proxy: Address,
+) -> Option<Address> {
+    provider
+        .get_storage(Some(block), proxy, EIP1967_IMPLEMENTATION_SLOT)
+        .await
+        .ok()
+        .flatten()
+        .map(|slot| Address::from_word(slot.into()))
+        .filter(|implementation| !implementation.is_zero())
+}
+
+alloy_sol_macro::sol!(
+    function facetAddress(bytes4 _functionSelector) external view returns (address facetAddress_);
+);
+
+/// The facet an EIP-2535 diamond routes the selector to, `None` if `diamond`
+/// isn't one or has no facet for the selector
+pub(crate) async fn diamond_facet<T: TracingProvider>(
+    provider: &Arc<T>,
+    block: u64,
+    diamond: Address,
+    selector: Selector,
+) -> Option<Address> {
+    let call = facetAddressCall { _functionSelector: selector };
+
+    make_call_request(call, provider, diamond, Some(block))
+        .await
+        .ok()
+        .map(|facet| facet.facetAddress_)
+        .filter(|facet| !facet.is_zero())
+}
+
+/// The first delegate call the target of `trace` makes itself, as
+/// `(target, implementation)`. `delegated_traces` are the frames below `trace`
+pub(crate) fn direct_delegate_call(
+    trace: &TransactionTraceWithLogs,
+    delegated_traces: &[&TransactionTraceWithLogs],
+) -> Option<(Address, Address)> {
+    let Action::Call(call) = &trace.trace.action else { return None };
+    let depth = trace.trace.trace_address.len() + 1;
+
+    delegated_traces.iter().find_map(|child| {
+        let Action::Call(delegated) = &child.trace.action else { return None };
+
+        (delegated.call_type == CallType::DelegateCall
+            && delegated.from == call.to
+            && child.trace.trace_address.len() == depth)
+            .then_some((call.to, delegated.to))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{Bytes, U64};
+    use alloy_sol_types::SolCall;
+    use reth_rpc_types::trace::parity::{CallAction, SelfdestructAction, TransactionTrace};
+
+    use super::*;
+
+    const ROUTER: Address = Address::repeat_byte(1);
+    const POOL: Address = Address::repeat_byte(2);
+    const TOKEN: Address = Address::repeat_byte(3);
+    const PROXY: Address = Address::repeat_byte(4);
+    const IMPL: Address = Address::repeat_byte(5);
+    const BUILDER: Address = Address::repeat_byte(6);
+
+    fn call(
+        from: Address,
+        to: Address,
+        call_type: CallType,
+        input: &'static [u8],
+        trace_address: Vec<usize>,
+    ) -> TransactionTraceWithLogs {
+        TransactionTraceWithLogs {
+            trace:        TransactionTrace {
+                action: Action::Call(CallAction {
+                    from,
+                    to,
+                    call_type,
+                    gas: U64::ZERO,
+                    input: Bytes::from_static(input),
+                    value: U256::ZERO,
+                }),
+                error: None,
+                result: None,
+                subtraces: 0,
+                trace_address,
+            },
+            logs:         vec![],
+            msg_sender:   from,
+            trace_idx:    0,
+            decoded_data: None,
+        }
+    }
+
+    #[test]
+    fn test_coinbase_transfers() {
+        let paid = U256::from(1_000);
+        let pay = |call_type| {
+            let mut frame = call(PROXY, BUILDER, call_type, &[], vec![0, 1]);
+            if let Action::Call(call) = &mut frame.trace.action {
+                call.value = paid;
+            }
+            frame.trace.action
+        };
+
+        // paid from the searcher's contract, a few calls deep
+        assert_eq!(get_coinbase_transfer(BUILDER, &pay(CallType::Call)), Some(1_000));
+        // a delegate call keeps the value where it is
+        assert_eq!(get_coinbase_transfer(BUILDER, &pay(CallType::DelegateCall)), None);
+        assert_eq!(get_coinbase_transfer(POOL, &pay(CallType::Call)), None);
+
+        let destruct = |refund_address| {
+            Action::Selfdestruct(SelfdestructAction {
+                address: PROXY,
+                balance: paid,
+                refund_address,
+            })
+        };
+        assert_eq!(get_coinbase_transfer(BUILDER, &destruct(BUILDER)), Some(1_000));
+        assert_eq!(get_coinbase_transfer(BUILDER, &destruct(ROUTER)), None);
+    }
+
+    #[test]
+    fn test_callback_frames() {
+        let selector: &'static [u8] = &[0xfa, 0x46, 0x1e, 0x33];
+        let traces = vec![
+            call(ROUTER, POOL, CallType::Call, selector, vec![0]),
+            call(POOL, TOKEN, CallType::Call, selector, vec![0, 0]),
+            call(POOL, ROUTER, CallType::Call, selector, vec![0, 1]),
+            call(ROUTER, TOKEN, CallType::Call, selector, vec![0, 1, 0]),
+            // eth refund to the router
+            call(POOL, ROUTER, CallType::Call, &[], vec![0, 2]),
+            call(ROUTER, PROXY, CallType::Call, selector, vec![1]),
+            call(PROXY, IMPL, CallType::DelegateCall, selector, vec![1, 0]),
+            call(PROXY, ROUTER, CallType::Call, selector, vec![1, 0, 0]),
+        ];
+
+        let callbacks = traces
+            .iter()
+            .map(|trace| is_callback_frame(&traces, trace))
+            .collect::<Vec<_>>();
+
+        assert_eq!(callbacks, vec![false, false, true, false, false, false, false, true]);
+    }
+
+    #[test]
+    fn test_reverted_frames() {
+        let selector: &'static [u8] = &[0xfa, 0x46, 0x1e, 0x33];
+        let mut traces = vec![
+            call(ROUTER, POOL, CallType::Call, selector, vec![0]),
+            call(POOL, TOKEN, CallType::Call, selector, vec![0, 0]),
+            call(POOL, ROUTER, CallType::Call, selector, vec![0, 1]),
+            call(ROUTER, TOKEN, CallType::Call, selector, vec![0, 1, 0]),
+            call(POOL, TOKEN, CallType::Call, selector, vec![0, 2]),
+            call(ROUTER, PROXY, CallType::Call, selector, vec![1]),
+            call(PROXY, IMPL, CallType::DelegateCall, selector, vec![1, 0]),
+        ];
+        traces[1].trace.error = Some("Reverted".to_string());
+        traces[2].trace.error = Some("Reverted".to_string());
+        traces[6].trace.error = Some("Reverted".to_string());
+
+        assert_eq!(reverted_frames(&traces), vec![false, true, true, true, false, false, true]);
+    }
+
+    #[test]
+    fn test_direct_delegate_call() {
+        let selector: &'static [u8] = &[0xfa, 0x46, 0x1e, 0x33];
+        let traces = vec![
+            call(ROUTER, PROXY, CallType::Call, selector, vec![0]),
+            call(PROXY, TOKEN, CallType::Call, selector, vec![0, 0]),
+            // a delegate call made further down isn't the proxy's
+            call(TOKEN, POOL, CallType::DelegateCall, selector, vec![0, 0, 0]),
+            call(PROXY, IMPL, CallType::DelegateCall, selector, vec![0, 1]),
+        ];
+        let delegated = traces[1..].iter().collect::<Vec<_>>();
+
+        assert_eq!(direct_delegate_call(&traces[0], &delegated), Some((PROXY, IMPL)));
+        assert_eq!(direct_delegate_call(&traces[0], &delegated[..2]), None);
+        assert_eq!(direct_delegate_call(&traces[1], &delegated[1..2]), Some((TOKEN, POOL)));
+    }
+
+    #[test]
+    fn test_facet_address_call() {
+        // `facetAddress(bytes4)` of the EIP-2535 loupe
+        assert_eq!(facetAddressCall::SELECTOR, hex!("cdffacc6"));
+
+        let selector = Selector::new(hex!("fa461e33"));
+        let encoded = facetAddressCall { _functionSelector: selector }.abi_encode();
+        assert_eq!(&encoded[4..8], selector.as_slice());
+    }
+
+    #[test]
+    fn test_delegate_call_msg_senders() {
+        let selector: &'static [u8] = &[0xfa, 0x46, 0x1e, 0x33];
+        let mut traces = vec![
+            call(ROUTER, PROXY, CallType::Call, selector, vec![]),
+            call(PROXY, TOKEN, CallType::Call, selector, vec![0]),
+            call(PROXY, IMPL, CallType::DelegateCall, selector, vec![1]),
+            call(PROXY, POOL, CallType::Call, selector, vec![1, 0]),
+            call(PROXY, IMPL, CallType::DelegateCall, selector, vec![1, 1]),
+        ];
+        // what the tracer sets, the sender of the previous call
+        traces[2].msg_sender = PROXY;
+        traces[4].msg_sender = PROXY;
+
+        resolve_msg_senders(&mut traces);
+
+        let senders = traces
+            .iter()
+            .map(|trace| trace.msg_sender)
+            .collect::<Vec<_>>();
+        assert_eq!(senders, vec![ROUTER, PROXY, ROUTER, PROXY, ROUTER]);
+    }
+
+    #[test]
+    fn test_decode_transfers() {
+        let amount = U256::from(1_000);
+        let erc20 = Log::new_unchecked(
+            TOKEN,
+            vec![TRANSFER_TOPIC, ROUTER.into_word(), POOL.into_word()],
+            amount.to_be_bytes_vec().into(),
+        );
+        assert_eq!(
+            decode_transfer(&erc20),
+            Some(DecodedTransfer::Erc20 { token: TOKEN, from: ROUTER, to: POOL, amount })
+        );
+
+        let token_id = U256::from(7);
+        let erc721 = Log::new_unchecked(
+            TOKEN,
+            vec![TRANSFER_TOPIC, ROUTER.into_word(), POOL.into_word(), token_id.into()],
+            Bytes::new(),
+        );
+        assert_eq!(
+            decode_transfer(&erc721),
+            Some(DecodedTransfer::Nft {
+                collection: TOKEN,
+                standard:   NftStandard::Erc721,
+                from:       ROUTER,
+                to:         POOL,
+                token_ids:  vec![token_id],
+                amounts:    vec![U256::from(1)],
+            })
+        );
+
+        let batch = Log {
+            address: TOKEN,
+            data:    TransferBatch {
+                operator: ROUTER,
+                from:     ROUTER,
+                to:       POOL,
+                ids:      vec![token_id, U256::from(8)],
+                values:   vec![U256::from(2), U256::from(3)],
+            }
+            .encode_log_data(),
+        };
+        assert_eq!(
+            decode_transfer(&batch),
+            Some(DecodedTransfer::Nft {
+                collection: TOKEN,
+                standard:   NftStandard::Erc1155,
+                from:       ROUTER,
+                to:         POOL,
+                token_ids:  vec![token_id, U256::from(8)],
+                amounts:    vec![U256::from(2), U256::from(3)],
+            })
+        );
+    }
+}
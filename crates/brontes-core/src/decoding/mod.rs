@@ -1,10 +1,10 @@
 use std::{pin::Pin, sync::Arc};
 
 use brontes_database::libmdbx::{DBWriter, LibmdbxReader};
-use brontes_types::structured_trace::TxTrace;
 pub use brontes_types::traits::TracingProvider;
+use brontes_types::{state_diff::TxStateDiff, structured_trace::TxTrace};
 use futures::Future;
-use reth_primitives::{BlockNumberOrTag, Header, B256};
+use reth_primitives::{BlockNumberOrTag, Header, Withdrawal, B256};
 use tokio::sync::mpsc::UnboundedSender;
 
 use self::parser::TraceParser;
@@ -63,6 +63,26 @@ impl<T: TracingProvider, DB: LibmdbxReader + DBWriter> Parser<T, DB> {
         self.parser.tracer.block_hash_for_id(block_num).await
     }
 
+    pub async fn get_withdrawals(&self, block_num: u64) -> eyre::Result<Vec<Withdrawal>> {
+        Ok(self
+            .parser
+            .tracer
+            .block_withdrawals(block_num)
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// the exact per transaction state changes of the block, empty if the
+    /// tracer can't re-execute blocks
+    pub async fn get_state_diffs(&self, block_num: u64) -> eyre::Result<Vec<TxStateDiff>> {
+        Ok(self
+            .parser
+            .tracer
+            .replay_block_state_diffs(BlockId::Number(BlockNumberOrTag::Number(block_num)))
+            .await?
+            .unwrap_or_default())
+    }
+
     /// executes the tracing of a given block
     pub fn execute(
         &self,
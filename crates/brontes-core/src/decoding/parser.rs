@@ -302,6 +302,8 @@ impl<T: TracingProvider, DB: LibmdbxReader + DBWriter> TraceParser<T, DB> {
                         receipt.transaction_index.unwrap(),
                         receipt.gas_used,
                         receipt.effective_gas_price,
+                        receipt.blob_gas_used.unwrap_or_default()
+                            * receipt.blob_gas_price.unwrap_or_default(),
                     )
                 },
             ))
@@ -333,6 +335,7 @@ impl<T: TracingProvider, DB: LibmdbxReader + DBWriter> TraceParser<T, DB> {
         tx_idx: u64,
         gas_used: u128,
         effective_gas_price: u128,
+        blob_gas_paid: u128,
     ) -> (TxTrace, TransactionStats) {
         let stats = TransactionStats {
             block_num,
@@ -357,6 +360,7 @@ impl<T: TracingProvider, DB: LibmdbxReader + DBWriter> TraceParser<T, DB> {
 
         tx_trace.effective_price = effective_gas_price;
         tx_trace.gas_used = gas_used;
+        tx_trace.blob_gas_paid = blob_gas_paid;
 
         (tx_trace, stats)
     }
@@ -7,7 +7,7 @@ use brontes_types::{structured_trace::TxTrace, traits::TracingProvider};
 use itertools::Itertools;
 use reth_primitives::{
     Address, BlockId, BlockNumber, BlockNumberOrTag, Bytecode, Bytes, Header, StorageValue, TxHash,
-    B256,
+    Withdrawal, B256,
 };
 use reth_rpc_types::{
     state::StateOverride, BlockOverrides, Log, TransactionReceipt, TransactionRequest,
@@ -138,6 +138,33 @@ impl TracingProvider for LocalProvider {
         Ok(Some(header))
     }
 
+    async fn block_withdrawals(
+        &self,
+        number: BlockNumber,
+    ) -> eyre::Result<Option<Vec<Withdrawal>>> {
+        let Some(block) = self
+            .provider
+            .get_block(BlockId::Number(BlockNumberOrTag::Number(number)), false)
+            .await?
+        else {
+            return Ok(None)
+        };
+
+        Ok(Some(
+            block
+                .withdrawals
+                .unwrap_or_default()
+                .into_iter()
+                .map(|w| Withdrawal {
+                    index:           w.index,
+                    validator_index: w.validator_index,
+                    address:         w.address,
+                    amount:          w.amount,
+                })
+                .collect(),
+        ))
+    }
+
     async fn get_storage(
         &self,
         block_number: Option<u64>,
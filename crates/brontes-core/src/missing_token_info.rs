@@ -1,12 +1,12 @@
 use std::sync::Arc;
 
-use alloy_primitives::Address;
+use alloy_primitives::{Address, U256};
 use alloy_sol_macro::sol;
 use alloy_sol_types::SolCall;
 use brontes_database::libmdbx::DBWriter;
 use brontes_types::make_call_request;
 use futures::{join, stream::FuturesUnordered, StreamExt};
-use tracing::error;
+use tracing::{error, warn};
 
 use crate::decoding::TracingProvider;
 
@@ -22,14 +22,80 @@ sol!(
     }
 );
 
+/// decimals that are commonly used by erc20 tokens, most common first.
+const COMMON_DECIMALS: [u8; 4] = [18, 6, 8, 9];
+/// largest amount of whole tokens we expect a single transfer to move.
+const MAX_WHOLE_TOKEN_DIGITS: usize = 12;
+/// smallest fraction of a token we expect a single transfer to move.
+const MIN_FRACTION_DIGITS: usize = 6;
+
+/// What we have seen of a token when its `decimals()` call fails. Used to
+/// infer a best-effort scale so that classification can still proceed.
+#[derive(Debug, Clone, Default)]
+pub struct DecimalHint {
+    /// raw amounts that were transferred of the token
+    pub observed_amounts: Vec<U256>,
+    /// decimals of a known token that this token is paired with in a pool
+    pub paired_decimals:  Option<u8>,
+}
+
+impl DecimalHint {
+    pub fn new(observed_amounts: Vec<U256>, paired_decimals: Option<u8>) -> Self {
+        Self { observed_amounts, paired_decimals }
+    }
+
+    /// Picks the decimals for which every observed transfer has a plausible
+    /// size, i.e no more than [`MAX_WHOLE_TOKEN_DIGITS`] whole tokens and no
+    /// less than [`MIN_FRACTION_DIGITS`] fractional digits. If multiple
+    /// candidates fit, the decimals of the paired token are preferred,
+    /// followed by the most common scale.
+    pub fn infer_decimals(&self) -> u8 {
+        let digits = self
+            .observed_amounts
+            .iter()
+            .filter(|amount| !amount.is_zero())
+            .map(|amount| amount.to_string().len())
+            .collect::<Vec<_>>();
+
+        let default = self.paired_decimals.unwrap_or(COMMON_DECIMALS[0]);
+        let (Some(min), Some(max)) = (digits.iter().min(), digits.iter().max()) else {
+            return default
+        };
+
+        let fits = |decimals: u8| {
+            let decimals = decimals as usize;
+            *max <= decimals + MAX_WHOLE_TOKEN_DIGITS && *min + MIN_FRACTION_DIGITS > decimals
+        };
+
+        self.paired_decimals
+            .into_iter()
+            .chain(COMMON_DECIMALS)
+            .find(|decimals| fits(*decimals))
+            .unwrap_or(default)
+    }
+}
+
 pub async fn load_missing_token_info<T: TracingProvider, W: DBWriter>(
     provider: &Arc<T>,
     db: &W,
     block: u64,
     missing_address: Address,
+) {
+    load_missing_token_info_with_hint(provider, db, block, missing_address, DecimalHint::default())
+        .await
+}
+
+/// Same as [`load_missing_token_info`] but uses the given hint to infer the
+/// decimals of the token if they can't be queried.
+pub async fn load_missing_token_info_with_hint<T: TracingProvider, W: DBWriter>(
+    provider: &Arc<T>,
+    db: &W,
+    block: u64,
+    missing_address: Address,
+    hint: DecimalHint,
 ) {
     let data = query_missing_data(provider, block, missing_address).await;
-    on_decimal_query_resolution(db, data).await;
+    on_decimal_query_resolution(db, data, hint).await;
 }
 
 pub async fn load_missing_token_infos<T: TracingProvider, W: DBWriter>(
@@ -44,7 +110,7 @@ pub async fn load_missing_token_infos<T: TracingProvider, W: DBWriter>(
         .for_each(|addr| pending_decimals.push(query_missing_data(provider, block, addr)));
 
     while let Some(res) = pending_decimals.next().await {
-        on_decimal_query_resolution(db, res).await;
+        on_decimal_query_resolution(db, res, DecimalHint::default()).await;
     }
 }
 
@@ -52,38 +118,78 @@ async fn query_missing_data<T: TracingProvider>(
     provider: &Arc<T>,
     block: u64,
     missing_address: Address,
-) -> eyre::Result<(Address, u8, String)> {
+) -> eyre::Result<(Address, Option<u8>, String)> {
     let (decimals, symbol, symbol_autistic) = join!(
         make_call_request(normal::decimalsCall::new(()), provider, missing_address, Some(block)),
         make_call_request(normal::symbolCall::new(()), provider, missing_address, Some(block)),
         make_call_request(autistic::symbolCall::new(()), provider, missing_address, Some(block))
     );
 
-    Ok(decimals.map(|d| d._0).unwrap_or_default()).map(|d| {
-        (
-            missing_address,
-            d,
-            symbol.map(|s| s._0).unwrap_or_else(|_| {
-                symbol_autistic
-                    .map(|s| String::from_utf8((s._0).to_vec()).unwrap_or_default())
-                    .unwrap_or_default()
-            }),
-        )
-    })
+    // a u256 can't hold more than 77 digits, anything above is garbage
+    let decimals = decimals.ok().map(|d| d._0).filter(|d| *d <= 77);
+
+    Ok((
+        missing_address,
+        decimals,
+        symbol.map(|s| s._0).unwrap_or_else(|_| {
+            symbol_autistic
+                .map(|s| String::from_utf8((s._0).to_vec()).unwrap_or_default())
+                .unwrap_or_default()
+        }),
+    ))
 }
 
 async fn on_decimal_query_resolution<W: DBWriter>(
     database: &W,
-    result: eyre::Result<(Address, u8, String)>,
+    result: eyre::Result<(Address, Option<u8>, String)>,
+    hint: DecimalHint,
 ) {
     match result {
-        Ok((address, decimals, symbol)) => {
+        Ok((address, Some(decimals), symbol)) => {
             if let Err(e) = database.write_token_info(address, decimals, symbol).await {
                 error!(error= %e, "failed to write token info into database");
             }
         }
+        Ok((address, None, symbol)) => {
+            let decimals = hint.infer_decimals();
+            warn!(?address, decimals, "token decimals query failed, using inferred decimals");
+            if let Err(e) = database
+                .write_inferred_token_info(address, decimals, symbol)
+                .await
+            {
+                error!(error= %e, "failed to write inferred token info into database");
+            }
+        }
         Err(e) => {
             error!(error= %e, "token info request failed");
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::U256;
+
+    use super::DecimalHint;
+
+    #[test]
+    fn test_infer_decimals() {
+        // 150 tokens with 18 decimals
+        let hint = DecimalHint::new(vec![U256::from(150u128 * 10u128.pow(18))], None);
+        assert_eq!(hint.infer_decimals(), 18);
+
+        // 2.5 tokens and 1,000,000 tokens with 6 decimals
+        let hint = DecimalHint::new(
+            vec![U256::from(2_500_000u128), U256::from(1_000_000u128 * 10u128.pow(6))],
+            None,
+        );
+        assert_eq!(hint.infer_decimals(), 6);
+
+        // ambiguous amounts take the decimals of the paired token
+        let hint = DecimalHint::new(vec![U256::from(10u128.pow(12))], Some(9));
+        assert_eq!(hint.infer_decimals(), 9);
+
+        // nothing observed
+        assert_eq!(DecimalHint::default().infer_decimals(), 18);
+    }
+}
@@ -0,0 +1,148 @@
+//! Binance spot `bookTicker` and `trade` streams, subscribed to over a single
+//! combined stream connection.
+use brontes_types::db::cex::{
+    quotes::RawCexQuotes,
+    trades::{RawCexTrades, TradeType},
+    CexExchange,
+};
+use serde::Deserialize;
+use serde_with::{serde_as, DisplayFromStr};
+
+use super::{stream_messages, CexFeedEvent, FeedStream};
+
+const BINANCE_STREAM_URL: &str = "wss://stream.binance.com:9443/stream?streams=";
+
+#[derive(Debug, Deserialize)]
+struct CombinedMessage {
+    stream: String,
+    data:   serde_json::Value,
+}
+
+/// The best bid and ask. Binance doesn't timestamp spot book tickers, so
+/// they're stamped with the time they're received
+#[serde_as]
+#[derive(Debug, Deserialize)]
+struct BookTicker {
+    #[serde(rename = "s")]
+    symbol:     String,
+    #[serde(rename = "b")]
+    #[serde_as(as = "DisplayFromStr")]
+    bid_price:  f64,
+    #[serde(rename = "B")]
+    #[serde_as(as = "DisplayFromStr")]
+    bid_amount: f64,
+    #[serde(rename = "a")]
+    #[serde_as(as = "DisplayFromStr")]
+    ask_price:  f64,
+    #[serde(rename = "A")]
+    #[serde_as(as = "DisplayFromStr")]
+    ask_amount: f64,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize)]
+struct Trade {
+    #[serde(rename = "s")]
+    symbol:         String,
+    #[serde(rename = "p")]
+    #[serde_as(as = "DisplayFromStr")]
+    price:          f64,
+    #[serde(rename = "q")]
+    #[serde_as(as = "DisplayFromStr")]
+    amount:         f64,
+    /// trade time in milliseconds
+    #[serde(rename = "T")]
+    time:           u64,
+    #[serde(rename = "m")]
+    buyer_is_maker: bool,
+}
+
+/// Streams the quotes and trades of `symbols`, named as binance names them
+pub(super) async fn subscribe(symbols: &[String]) -> eyre::Result<FeedStream> {
+    let streams = symbols
+        .iter()
+        .flat_map(|symbol| {
+            let symbol = symbol.to_lowercase();
+            [format!("{symbol}@bookTicker"), format!("{symbol}@trade")]
+        })
+        .collect::<Vec<_>>()
+        .join("/");
+
+    // the streams are picked in the url, so nothing needs to be sent
+    stream_messages(&format!("{BINANCE_STREAM_URL}{streams}"), vec![], None, parse_message).await
+}
+
+/// Parses a combined stream message, which holds no events if it is neither a
+/// book ticker nor a trade
+fn parse_message(text: &str, received_at: u64) -> eyre::Result<Vec<CexFeedEvent>> {
+    let message: CombinedMessage = serde_json::from_str(text)?;
+
+    if message.stream.ends_with("@bookTicker") {
+        let ticker: BookTicker = serde_json::from_value(message.data)?;
+        Ok(vec![CexFeedEvent::Quote(RawCexQuotes {
+            exchange:   CexExchange::Binance,
+            symbol:     ticker.symbol,
+            timestamp:  received_at,
+            ask_amount: ticker.ask_amount,
+            ask_price:  ticker.ask_price,
+            bid_price:  ticker.bid_price,
+            bid_amount: ticker.bid_amount,
+            depth:      Default::default(),
+        })])
+    } else if message.stream.ends_with("@trade") {
+        let trade: Trade = serde_json::from_value(message.data)?;
+        Ok(vec![CexFeedEvent::Trade(RawCexTrades {
+            exchange:   CexExchange::Binance,
+            trade_type: TradeType::Taker,
+            symbol:     trade.symbol,
+            timestamp:  trade.time * 1_000,
+            // the taker is the side that isn't the maker
+            side:       if trade.buyer_is_maker { "sell" } else { "buy" }.to_string(),
+            price:      trade.price,
+            amount:     trade.amount,
+        })])
+    } else {
+        Ok(vec![])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_book_ticker() {
+        let text = concat!(
+            r#"{"stream":"ethusdt@bookTicker","data":{"u":400900217,"s":"ETHUSDT","#,
+            r#""b":"3012.51","B":"31.21","a":"3012.52","A":"40.66"}}"#
+        );
+
+        let [CexFeedEvent::Quote(quote)] = &parse_message(text, 7).unwrap()[..] else {
+            panic!("expected a quote")
+        };
+        assert_eq!(quote.symbol, "ETHUSDT");
+        assert_eq!(quote.timestamp, 7);
+        assert_eq!(quote.bid_price, 3012.51);
+        assert_eq!(quote.ask_price, 3012.52);
+        assert_eq!(quote.ask_amount, 40.66);
+    }
+
+    #[test]
+    fn test_parse_trade() {
+        let text = concat!(
+            r#"{"stream":"ethusdt@trade","data":{"e":"trade","E":1718000000001,"s":"ETHUSDT","#,
+            r#""t":12345,"p":"3012.50","q":"0.25","T":1718000000000,"m":true,"M":true}}"#
+        );
+
+        let [CexFeedEvent::Trade(trade)] = &parse_message(text, 0).unwrap()[..] else {
+            panic!("expected a trade")
+        };
+        assert_eq!(trade.timestamp, 1_718_000_000_000_000);
+        assert_eq!(trade.side, "sell");
+        assert_eq!(trade.price, 3012.5);
+        assert_eq!(trade.amount, 0.25);
+
+        let unknown = r#"{"stream":"ethusdt@depth","data":{}}"#;
+        assert!(parse_message(unknown, 0).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,163 @@
+//! Bybit spot `orderbook.1` and `publicTrade` topics on the v5 public
+//! websocket. Bybit names spot pairs like Binance, e.g. `ETHUSDT`.
+use brontes_types::db::cex::{
+    quotes::RawCexQuotes,
+    trades::{RawCexTrades, TradeType},
+    CexExchange,
+};
+use serde::Deserialize;
+use serde_with::{serde_as, DisplayFromStr};
+
+use super::{stream_messages, CexFeedEvent, FeedStream};
+
+const BYBIT_STREAM_URL: &str = "wss://stream.bybit.com/v5/public/spot";
+/// Bybit drops connections it doesn't hear from, and answers this with a
+/// `pong` op
+const BYBIT_PING: &str = r#"{"op":"ping"}"#;
+/// The most topics a single spot subscribe request can hold
+const MAX_TOPICS_PER_REQUEST: usize = 10;
+
+/// Subscription acks and pongs carry an op rather than a topic
+#[derive(Debug, Deserialize)]
+struct TopicMessage {
+    #[serde(default)]
+    topic: String,
+    /// in milliseconds
+    #[serde(default)]
+    ts:    u64,
+    #[serde(default)]
+    data:  serde_json::Value,
+}
+
+/// The top of the book, each side as `[price, size]`. Level one spot books are
+/// always pushed whole
+#[serde_as]
+#[derive(Debug, Deserialize)]
+struct Book {
+    #[serde(rename = "s")]
+    symbol: String,
+    #[serde(rename = "a")]
+    #[serde_as(as = "Vec<Vec<DisplayFromStr>>")]
+    asks:   Vec<Vec<f64>>,
+    #[serde(rename = "b")]
+    #[serde_as(as = "Vec<Vec<DisplayFromStr>>")]
+    bids:   Vec<Vec<f64>>,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize)]
+struct Trade {
+    #[serde(rename = "s")]
+    symbol: String,
+    #[serde(rename = "p")]
+    #[serde_as(as = "DisplayFromStr")]
+    price:  f64,
+    #[serde(rename = "v")]
+    #[serde_as(as = "DisplayFromStr")]
+    amount: f64,
+    /// the side of the taker, `Buy` or `Sell`
+    #[serde(rename = "S")]
+    side:   String,
+    /// in milliseconds
+    #[serde(rename = "T")]
+    time:   u64,
+}
+
+/// Streams the quotes and trades of `symbols`, named as bybit names them
+pub(super) async fn subscribe(symbols: &[String]) -> eyre::Result<FeedStream> {
+    let topics = symbols
+        .iter()
+        .flat_map(|symbol| [format!("orderbook.1.{symbol}"), format!("publicTrade.{symbol}")])
+        .collect::<Vec<_>>();
+    let subscriptions = topics
+        .chunks(MAX_TOPICS_PER_REQUEST)
+        .map(|args| serde_json::json!({ "op": "subscribe", "args": args }).to_string())
+        .collect();
+
+    stream_messages(BYBIT_STREAM_URL, subscriptions, Some(BYBIT_PING), parse_message).await
+}
+
+fn parse_message(text: &str, _received_at: u64) -> eyre::Result<Vec<CexFeedEvent>> {
+    let message: TopicMessage = serde_json::from_str(text)?;
+
+    if message.topic.starts_with("orderbook.") {
+        let book: Book = serde_json::from_value(message.data)?;
+        // both sides are needed for a quote
+        let (Some(ask), Some(bid)) = (book.asks.first(), book.bids.first()) else {
+            return Ok(vec![])
+        };
+        let ([ask_price, ask_amount, ..], [bid_price, bid_amount, ..]) = (&ask[..], &bid[..])
+        else {
+            eyre::bail!("malformed bybit book level")
+        };
+
+        Ok(vec![CexFeedEvent::Quote(RawCexQuotes {
+            exchange:   CexExchange::BybitSpot,
+            symbol:     book.symbol,
+            timestamp:  message.ts * 1_000,
+            ask_amount: *ask_amount,
+            ask_price:  *ask_price,
+            bid_price:  *bid_price,
+            bid_amount: *bid_amount,
+            depth:      Default::default(),
+        })])
+    } else if message.topic.starts_with("publicTrade.") {
+        Ok(serde_json::from_value::<Vec<Trade>>(message.data)?
+            .into_iter()
+            .map(|trade| {
+                CexFeedEvent::Trade(RawCexTrades {
+                    exchange:   CexExchange::BybitSpot,
+                    trade_type: TradeType::Taker,
+                    symbol:     trade.symbol,
+                    timestamp:  trade.time * 1_000,
+                    side:       trade.side.to_lowercase(),
+                    price:      trade.price,
+                    amount:     trade.amount,
+                })
+            })
+            .collect())
+    } else {
+        Ok(vec![])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_book() {
+        let text = concat!(
+            r#"{"topic":"orderbook.1.ETHUSDT","type":"snapshot","ts":1718000000000,"#,
+            r#""data":{"s":"ETHUSDT","b":[["3012.51","31.21"]],"a":[["3012.52","40.66"]],"#,
+            r#""u":1,"seq":2},"cts":1717999999999}"#
+        );
+
+        let [CexFeedEvent::Quote(quote)] = &parse_message(text, 0).unwrap()[..] else {
+            panic!("expected a quote")
+        };
+        assert_eq!(quote.symbol, "ETHUSDT");
+        assert_eq!(quote.timestamp, 1_718_000_000_000_000);
+        assert_eq!(quote.bid_price, 3012.51);
+        assert_eq!(quote.ask_amount, 40.66);
+    }
+
+    #[test]
+    fn test_parse_trade() {
+        let text = concat!(
+            r#"{"topic":"publicTrade.ETHUSDT","type":"snapshot","ts":1718000000001,"data":[{"#,
+            r#""T":1718000000000,"s":"ETHUSDT","S":"Sell","v":"0.25","p":"3012.50","#,
+            r#""L":"MinusTick","i":"2290000000067","BT":false}]}"#
+        );
+
+        let [CexFeedEvent::Trade(trade)] = &parse_message(text, 0).unwrap()[..] else {
+            panic!("expected a trade")
+        };
+        assert_eq!(trade.timestamp, 1_718_000_000_000_000);
+        assert_eq!(trade.side, "sell");
+        assert_eq!(trade.amount, 0.25);
+
+        let pong = r#"{"success":true,"ret_msg":"pong","conn_id":"1","op":"ping"}"#;
+        assert!(parse_message(pong, 0).unwrap().is_empty());
+    }
+}
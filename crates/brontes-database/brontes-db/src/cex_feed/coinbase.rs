@@ -0,0 +1,137 @@
+//! Coinbase Exchange `ticker` and `matches` channels. Coinbase names products
+//! with a dash between the base and quote, e.g. `ETH-USD`.
+use brontes_types::db::cex::{
+    quotes::RawCexQuotes,
+    trades::{RawCexTrades, TradeType},
+    CexExchange,
+};
+use chrono::DateTime;
+use serde::Deserialize;
+use serde_with::{serde_as, DisplayFromStr};
+
+use super::{stream_messages, CexFeedEvent, FeedStream};
+
+const COINBASE_STREAM_URL: &str = "wss://ws-feed.exchange.coinbase.com";
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum Message {
+    Ticker(Ticker),
+    Match(Match),
+    /// subscription acks, heartbeats and the last match sent on subscribing
+    #[serde(other)]
+    Other,
+}
+
+/// Sent for every match, along with the best bid and ask after it
+#[serde_as]
+#[derive(Debug, Deserialize)]
+struct Ticker {
+    product_id:    String,
+    #[serde_as(as = "DisplayFromStr")]
+    best_bid:      f64,
+    #[serde_as(as = "DisplayFromStr")]
+    best_bid_size: f64,
+    #[serde_as(as = "DisplayFromStr")]
+    best_ask:      f64,
+    #[serde_as(as = "DisplayFromStr")]
+    best_ask_size: f64,
+    time:          String,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize)]
+struct Match {
+    product_id: String,
+    #[serde_as(as = "DisplayFromStr")]
+    price:      f64,
+    #[serde_as(as = "DisplayFromStr")]
+    size:       f64,
+    /// the side of the maker's order
+    side:       String,
+    time:       String,
+}
+
+/// Streams the quotes and trades of `symbols`, named as coinbase names them
+pub(super) async fn subscribe(symbols: &[String]) -> eyre::Result<FeedStream> {
+    let subscription = serde_json::json!({
+        "type": "subscribe",
+        "product_ids": symbols,
+        "channels": ["ticker", "matches"],
+    });
+
+    stream_messages(COINBASE_STREAM_URL, vec![subscription.to_string()], None, parse_message).await
+}
+
+fn parse_message(text: &str, _received_at: u64) -> eyre::Result<Vec<CexFeedEvent>> {
+    match serde_json::from_str(text)? {
+        Message::Ticker(ticker) => Ok(vec![CexFeedEvent::Quote(RawCexQuotes {
+            exchange:   CexExchange::Coinbase,
+            symbol:     ticker.product_id,
+            timestamp:  parse_time(&ticker.time)?,
+            ask_amount: ticker.best_ask_size,
+            ask_price:  ticker.best_ask,
+            bid_price:  ticker.best_bid,
+            bid_amount: ticker.best_bid_size,
+            depth:      Default::default(),
+        })]),
+        Message::Match(trade) => Ok(vec![CexFeedEvent::Trade(RawCexTrades {
+            exchange:   CexExchange::Coinbase,
+            trade_type: TradeType::Taker,
+            symbol:     trade.product_id,
+            timestamp:  parse_time(&trade.time)?,
+            // the taker is on the other side of the maker
+            side:       if trade.side == "buy" { "sell" } else { "buy" }.to_string(),
+            price:      trade.price,
+            amount:     trade.size,
+        })]),
+        Message::Other => Ok(vec![]),
+    }
+}
+
+/// Coinbase timestamps are RFC 3339 strings, cex data is in microseconds
+fn parse_time(time: &str) -> eyre::Result<u64> {
+    Ok(DateTime::parse_from_rfc3339(time)?.timestamp_micros() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ticker() {
+        let text = concat!(
+            r#"{"type":"ticker","sequence":37475248783,"product_id":"ETH-USD","price":"3012.51","#,
+            r#""best_bid":"3012.50","best_bid_size":"1.25","best_ask":"3012.52","#,
+            r#""best_ask_size":"0.40","side":"buy","time":"2024-06-10T06:13:20.000001Z","#,
+            r#""trade_id":370843401,"last_size":"0.01"}"#
+        );
+
+        let [CexFeedEvent::Quote(quote)] = &parse_message(text, 0).unwrap()[..] else {
+            panic!("expected a quote")
+        };
+        assert_eq!(quote.symbol, "ETH-USD");
+        assert_eq!(quote.timestamp, 1_718_000_000_000_001);
+        assert_eq!(quote.bid_amount, 1.25);
+        assert_eq!(quote.ask_price, 3012.52);
+    }
+
+    #[test]
+    fn test_parse_match() {
+        let text = concat!(
+            r#"{"type":"match","trade_id":10,"sequence":50,"maker_order_id":"ac928c66","#,
+            r#""taker_order_id":"132fb6ae","time":"2024-06-10T06:13:20Z","#,
+            r#""product_id":"ETH-USD","size":"0.25","price":"3012.50","side":"sell"}"#
+        );
+
+        let [CexFeedEvent::Trade(trade)] = &parse_message(text, 0).unwrap()[..] else {
+            panic!("expected a trade")
+        };
+        assert_eq!(trade.timestamp, 1_718_000_000_000_000);
+        assert_eq!(trade.side, "buy");
+        assert_eq!(trade.amount, 0.25);
+
+        let ack = r#"{"type":"subscriptions","channels":[]}"#;
+        assert!(parse_message(ack, 0).unwrap().is_empty());
+    }
+}
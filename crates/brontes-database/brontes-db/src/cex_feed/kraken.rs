@@ -0,0 +1,152 @@
+//! Kraken spot `ticker` and `trade` channels on the v2 websocket api. Kraken
+//! names pairs with a slash between the base and quote, e.g. `ETH/USD`, and
+//! calls bitcoin `BTC` rather than the `XBT` of its rest api.
+use brontes_types::db::cex::{
+    quotes::RawCexQuotes,
+    trades::{RawCexTrades, TradeType},
+    CexExchange,
+};
+use chrono::DateTime;
+use serde::Deserialize;
+
+use super::{stream_messages, CexFeedEvent, FeedStream};
+
+const KRAKEN_STREAM_URL: &str = "wss://ws.kraken.com/v2";
+
+/// Subscription acks carry no channel, and heartbeats and status updates no
+/// data we use
+#[derive(Debug, Deserialize)]
+struct ChannelMessage {
+    #[serde(default)]
+    channel: String,
+    #[serde(default)]
+    data:    serde_json::Value,
+}
+
+/// The best bid and ask. Kraken doesn't timestamp tickers, so they're stamped
+/// with the time they're received
+#[derive(Debug, Deserialize)]
+struct Ticker {
+    symbol:  String,
+    bid:     f64,
+    bid_qty: f64,
+    ask:     f64,
+    ask_qty: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct Trade {
+    symbol:    String,
+    /// the side of the taker
+    side:      String,
+    price:     f64,
+    qty:       f64,
+    timestamp: String,
+}
+
+/// Streams the quotes and trades of `symbols`, named as kraken names them
+pub(super) async fn subscribe(symbols: &[String]) -> eyre::Result<FeedStream> {
+    let subscriptions = vec![
+        // `bbo` pushes the ticker whenever the best bid or ask changes rather
+        // than on every trade
+        serde_json::json!({
+            "method": "subscribe",
+            "params": { "channel": "ticker", "symbol": symbols, "event_trigger": "bbo" },
+        })
+        .to_string(),
+        // the snapshot holds trades from before the feed started
+        serde_json::json!({
+            "method": "subscribe",
+            "params": { "channel": "trade", "symbol": symbols, "snapshot": false },
+        })
+        .to_string(),
+    ];
+
+    stream_messages(KRAKEN_STREAM_URL, subscriptions, None, parse_message).await
+}
+
+fn parse_message(text: &str, received_at: u64) -> eyre::Result<Vec<CexFeedEvent>> {
+    let message: ChannelMessage = serde_json::from_str(text)?;
+
+    match message.channel.as_str() {
+        "ticker" => Ok(serde_json::from_value::<Vec<Ticker>>(message.data)?
+            .into_iter()
+            .map(|ticker| {
+                CexFeedEvent::Quote(RawCexQuotes {
+                    exchange:   CexExchange::Kraken,
+                    symbol:     ticker.symbol,
+                    timestamp:  received_at,
+                    ask_amount: ticker.ask_qty,
+                    ask_price:  ticker.ask,
+                    bid_price:  ticker.bid,
+                    bid_amount: ticker.bid_qty,
+                    depth:      Default::default(),
+                })
+            })
+            .collect()),
+        "trade" => serde_json::from_value::<Vec<Trade>>(message.data)?
+            .into_iter()
+            .map(|trade| -> eyre::Result<_> {
+                Ok(CexFeedEvent::Trade(RawCexTrades {
+                    exchange:   CexExchange::Kraken,
+                    trade_type: TradeType::Taker,
+                    symbol:     trade.symbol,
+                    timestamp:  DateTime::parse_from_rfc3339(&trade.timestamp)?.timestamp_micros()
+                        as u64,
+                    side:       trade.side,
+                    price:      trade.price,
+                    amount:     trade.qty,
+                }))
+            })
+            .collect(),
+        _ => Ok(vec![]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ticker() {
+        let text = concat!(
+            r#"{"channel":"ticker","type":"update","data":[{"symbol":"ETH/USD","bid":3012.5,"#,
+            r#""bid_qty":4.2,"ask":3012.51,"ask_qty":1.1,"last":3012.5,"volume":1200.0,"#,
+            r#""vwap":3000.0,"low":2950.0,"high":3050.0,"change":12.5,"change_pct":0.42}]}"#
+        );
+
+        let [CexFeedEvent::Quote(quote)] = &parse_message(text, 7).unwrap()[..] else {
+            panic!("expected a quote")
+        };
+        assert_eq!(quote.symbol, "ETH/USD");
+        assert_eq!(quote.timestamp, 7);
+        assert_eq!(quote.bid_amount, 4.2);
+        assert_eq!(quote.ask_price, 3012.51);
+    }
+
+    #[test]
+    fn test_parse_trades() {
+        let text = concat!(
+            r#"{"channel":"trade","type":"update","data":["#,
+            r#"{"symbol":"ETH/USD","side":"sell","price":3012.5,"qty":0.25,"ord_type":"market","#,
+            r#""trade_id":1,"timestamp":"2024-06-10T06:13:20.000000Z"},"#,
+            r#"{"symbol":"ETH/USD","side":"buy","price":3012.6,"qty":1.5,"ord_type":"limit","#,
+            r#""trade_id":2,"timestamp":"2024-06-10T06:13:20.500000Z"}]}"#
+        );
+
+        let events = parse_message(text, 0).unwrap();
+        let [CexFeedEvent::Trade(first), CexFeedEvent::Trade(second)] = &events[..] else {
+            panic!("expected two trades")
+        };
+        assert_eq!(first.timestamp, 1_718_000_000_000_000);
+        assert_eq!(first.side, "sell");
+        assert_eq!(second.timestamp, 1_718_000_000_500_000);
+        assert_eq!(second.amount, 1.5);
+
+        let ack = r#"{"method":"subscribe","result":{"channel":"trade"},"success":true}"#;
+        assert!(parse_message(ack, 0).unwrap().is_empty());
+        assert!(parse_message(r#"{"channel":"heartbeat"}"#, 0)
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,385 @@
+//! Live cex quotes and trades for running at tip. Historical runs read the
+//! `CexPrice` and `CexTrades` tables that `brontes init` fills from
+//! Clickhouse, which lags the chain. The feed instead streams quotes and trades
+//! straight from the exchanges, and once a block's trade window has passed,
+//! converts what it has buffered around the block's timestamp the same way the
+//! Clickhouse data is converted, and writes it to both tables.
+//!
+//! The symbols streamed, along with the tokens they trade, are loaded from a
+//! file laid out like `config/cex_feed_config.toml`. Symbols are named the way
+//! their exchange names them, so the same pair is `ETHUSDT` on Binance and
+//! Bybit, `ETH-USD` on Coinbase, `ETH/USD` on Kraken and `ETH-USDT` on OKX.
+use std::{
+    collections::VecDeque,
+    path::Path,
+    pin::Pin,
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use alloy_primitives::Address;
+use brontes_types::{
+    db::{
+        block_times::BlockTimes,
+        cex::{
+            quotes::{CexPriceMap, CexQuotesConverter, RawCexQuotes},
+            trades::{CexTradeMap, CexTradesConverter, RawCexTrades},
+            BestCexPerPair, CexExchange, CexSymbols,
+        },
+        traits::DBWriter,
+    },
+    pair::Pair,
+    traits::TracingProvider,
+};
+use futures::{SinkExt, Stream, StreamExt};
+use serde::Deserialize;
+use tokio::{
+    sync::mpsc::{unbounded_channel, UnboundedSender},
+    time::interval,
+};
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+use tracing::{debug, warn};
+
+mod binance;
+mod bybit;
+mod coinbase;
+mod kraken;
+mod okx;
+
+/// How long after a block its trades are collected for, matching the window
+/// trades are assigned to blocks with when converted from Clickhouse
+pub(crate) const TRADE_WINDOW_US: u64 = 6_000_000;
+/// How long quotes are kept around, the quote converter looks 301 seconds
+/// either side of a block
+pub(crate) const QUOTE_WINDOW_US: u64 = 301_000_000;
+/// Blocks can show up a little after their timestamp, so the buffers are kept
+/// for this much longer than the windows they cover
+const BUFFER_SLACK_US: u64 = 60_000_000;
+/// How often venues that drop quiet connections are pinged
+const PING_INTERVAL: Duration = Duration::from_secs(20);
+
+/// The exchanges quotes and trades can be streamed from
+const SUPPORTED_EXCHANGES: [CexExchange; 5] = [
+    CexExchange::Binance,
+    CexExchange::BybitSpot,
+    CexExchange::Coinbase,
+    CexExchange::Kraken,
+    CexExchange::Okex,
+];
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CexFeedConfig {
+    pub symbols: Vec<FeedSymbol>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FeedSymbol {
+    pub exchange: CexExchange,
+    /// the symbol as the exchange names it, e.g. `ETHUSDT` on Binance or
+    /// `ETH-USD` on Coinbase
+    pub symbol:   String,
+    pub base:     Address,
+    pub quote:    Address,
+}
+
+impl CexFeedConfig {
+    pub fn load(path: impl AsRef<Path>) -> eyre::Result<Self> {
+        let config: Self = toml::from_str(&std::fs::read_to_string(path)?)?;
+        if let Some(symbol) = config
+            .symbols
+            .iter()
+            .find(|s| !SUPPORTED_EXCHANGES.contains(&s.exchange))
+        {
+            eyre::bail!("{} can't be streamed from {}", symbol.symbol, symbol.exchange)
+        }
+
+        Ok(config)
+    }
+
+    /// The symbols configured on each exchange that has any
+    fn symbols_by_exchange(&self) -> Vec<(CexExchange, Vec<String>)> {
+        SUPPORTED_EXCHANGES
+            .into_iter()
+            .map(|exchange| {
+                let symbols = self
+                    .symbols
+                    .iter()
+                    .filter(|s| s.exchange == exchange)
+                    .map(|s| s.symbol.clone())
+                    .collect::<Vec<_>>();
+                (exchange, symbols)
+            })
+            .filter(|(_, symbols)| !symbols.is_empty())
+            .collect()
+    }
+}
+
+/// A quote or trade taken off an exchange's stream
+#[derive(Debug, Clone, PartialEq)]
+pub enum CexFeedEvent {
+    Quote(RawCexQuotes),
+    Trade(RawCexTrades),
+}
+
+type FeedStream = Pin<Box<dyn Stream<Item = eyre::Result<CexFeedEvent>> + Send>>;
+
+/// Buffers the quotes and trades streamed from the exchanges and writes them to
+/// the database per block
+pub struct LiveCexFeed<T: TracingProvider, DB: DBWriter> {
+    config:            CexFeedConfig,
+    provider:          Arc<T>,
+    db:                &'static DB,
+    symbols:           Vec<CexSymbols>,
+    best_cex_per_pair: Vec<BestCexPerPair>,
+    quotes:            VecDeque<RawCexQuotes>,
+    trades:            VecDeque<RawCexTrades>,
+    /// blocks seen whose trade window hasn't passed yet
+    pending_blocks:    VecDeque<BlockTimes>,
+    next_block:        u64,
+}
+
+impl<T: TracingProvider, DB: DBWriter> LiveCexFeed<T, DB> {
+    /// `start_block` is the first block data is written for, generally the
+    /// block after the chain's tip
+    pub fn new(config: CexFeedConfig, provider: Arc<T>, db: &'static DB, start_block: u64) -> Self {
+        let symbols = config
+            .symbols
+            .iter()
+            .map(|s| CexSymbols {
+                exchange:     s.exchange,
+                symbol_pair:  s.symbol.clone(),
+                address_pair: Pair(s.base, s.quote),
+            })
+            .collect::<Vec<_>>();
+        let best_cex_per_pair = best_cex_per_pair(&symbols);
+
+        Self {
+            config,
+            provider,
+            db,
+            symbols,
+            best_cex_per_pair,
+            quotes: VecDeque::new(),
+            trades: VecDeque::new(),
+            pending_blocks: VecDeque::new(),
+            next_block: start_block,
+        }
+    }
+
+    pub async fn run(mut self) {
+        // each exchange is streamed on its own task so that one dropping its
+        // connection doesn't hold up the others
+        let (events, mut rx) = unbounded_channel();
+        for (exchange, symbols) in self.config.symbols_by_exchange() {
+            tokio::spawn(stream_exchange(exchange, symbols, events.clone()));
+        }
+        let mut tick = interval(Duration::from_secs(1));
+
+        loop {
+            tokio::select! {
+                Some(event) = rx.recv() => self.on_event(event),
+                _ = tick.tick() => {
+                    self.poll_new_blocks().await;
+                    self.write_finished_blocks(now_micros()).await;
+                }
+            }
+        }
+    }
+
+    fn on_event(&mut self, event: CexFeedEvent) {
+        match event {
+            CexFeedEvent::Quote(quote) => self.quotes.push_back(quote),
+            CexFeedEvent::Trade(trade) => self.trades.push_back(trade),
+        }
+    }
+
+    async fn poll_new_blocks(&mut self) {
+        while let Ok(Some(header)) = self.provider.header_by_number(self.next_block).await {
+            self.pending_blocks.push_back(BlockTimes {
+                block_number: self.next_block,
+                timestamp:    header.timestamp * 1_000_000,
+            });
+            self.next_block += 1;
+        }
+    }
+
+    /// Writes every block whose trade window has passed by `now`, then drops
+    /// what no block still pending can use
+    async fn write_finished_blocks(&mut self, now: u64) {
+        while self
+            .pending_blocks
+            .front()
+            .is_some_and(|block| block.timestamp + TRADE_WINDOW_US <= now)
+        {
+            let block = self.pending_blocks.pop_front().unwrap();
+            let block_number = block.block_number;
+            let (quotes, trades) = self.convert_block(block);
+
+            debug!(target: "brontes::cex_feed", %block_number, "writing live cex data");
+            if let Err(e) = self.db.write_cex_data(block_number, quotes, trades).await {
+                warn!(target: "brontes::cex_feed", %e, %block_number, "failed to write cex data");
+            }
+        }
+
+        // exchanges stamp their own messages, so the buffers are only in order
+        // per exchange
+        let quote_cutoff = now.saturating_sub(QUOTE_WINDOW_US + BUFFER_SLACK_US);
+        self.quotes.retain(|quote| quote.timestamp >= quote_cutoff);
+
+        let trade_cutoff = now.saturating_sub(TRADE_WINDOW_US + BUFFER_SLACK_US);
+        self.trades.retain(|trade| trade.timestamp >= trade_cutoff);
+    }
+
+    fn convert_block(&self, block: BlockTimes) -> (CexPriceMap, CexTradeMap) {
+        // the converters expect data sorted by time, as it comes from Clickhouse
+        let mut quotes = self.quotes.iter().cloned().collect::<Vec<_>>();
+        quotes.sort_by_key(|quote| quote.timestamp);
+        let mut trades = self.trades.iter().cloned().collect::<Vec<_>>();
+        trades.sort_by_key(|trade| trade.timestamp);
+
+        let quotes = CexQuotesConverter::new(
+            vec![block.clone()],
+            self.symbols.clone(),
+            quotes,
+            self.best_cex_per_pair.clone(),
+        )
+        .convert_to_prices()
+        .pop()
+        .map(|(_, quotes)| quotes)
+        .unwrap_or_default();
+
+        let trades = CexTradesConverter::new(vec![block], self.symbols.clone(), trades)
+            .convert_to_trades()
+            .pop()
+            .map(|(_, trades)| trades)
+            .unwrap_or_default();
+
+        (quotes, trades)
+    }
+}
+
+/// The exchanges to take each pair's quotes from. A pair with symbols on
+/// several exchanges ranks them in the order they're listed in
+pub(crate) fn best_cex_per_pair(symbols: &[CexSymbols]) -> Vec<BestCexPerPair> {
+    let mut best_cex_per_pair: Vec<(Pair, BestCexPerPair)> = Vec::new();
+    for s in symbols {
+        match best_cex_per_pair
+            .iter_mut()
+            .find(|(pair, _)| *pair == s.address_pair)
+        {
+            Some((_, best)) => best.exchange.push(s.exchange),
+            None => best_cex_per_pair.push((
+                s.address_pair,
+                BestCexPerPair {
+                    symbol:    s.symbol_pair.clone(),
+                    exchange:  vec![s.exchange],
+                    timestamp: 0,
+                },
+            )),
+        }
+    }
+
+    best_cex_per_pair
+        .into_iter()
+        .map(|(_, best)| best)
+        .collect()
+}
+
+/// Streams the quotes and trades of `symbols` on `exchange` into `events`,
+/// reconnecting whenever the connection drops
+async fn stream_exchange(
+    exchange: CexExchange,
+    symbols: Vec<String>,
+    events: UnboundedSender<CexFeedEvent>,
+) {
+    loop {
+        match subscribe(exchange, &symbols).await {
+            Ok(mut stream) => {
+                while let Some(event) = stream.next().await {
+                    match event {
+                        Ok(event) => {
+                            if events.send(event).is_err() {
+                                return
+                            }
+                        }
+                        Err(e) => {
+                            warn!(
+                                target: "brontes::cex_feed",
+                                %e, %exchange, "bad cex feed message"
+                            )
+                        }
+                    }
+                }
+                warn!(
+                    target: "brontes::cex_feed",
+                    %exchange, "cex feed disconnected, reconnecting"
+                );
+            }
+            Err(e) => warn!(target: "brontes::cex_feed", %e, %exchange, "failed to connect"),
+        }
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+}
+
+async fn subscribe(exchange: CexExchange, symbols: &[String]) -> eyre::Result<FeedStream> {
+    match exchange {
+        CexExchange::Binance => binance::subscribe(symbols).await,
+        CexExchange::BybitSpot => bybit::subscribe(symbols).await,
+        CexExchange::Coinbase => coinbase::subscribe(symbols).await,
+        CexExchange::Kraken => kraken::subscribe(symbols).await,
+        CexExchange::Okex => okx::subscribe(symbols).await,
+        _ => eyre::bail!("no live feed for {exchange}"),
+    }
+}
+
+/// Connects to `url` and sends it each of `subscriptions`. Venues that drop
+/// connections they haven't heard from are sent `ping` every
+/// [`PING_INTERVAL`]. Each text message received is parsed into the events it
+/// holds by `parse`, which is passed the time it was received at
+async fn stream_messages(
+    url: &str,
+    subscriptions: Vec<String>,
+    ping: Option<&'static str>,
+    parse: fn(&str, u64) -> eyre::Result<Vec<CexFeedEvent>>,
+) -> eyre::Result<FeedStream> {
+    let (mut socket, _) = connect_async(url).await?;
+    for subscription in subscriptions {
+        socket.send(Message::Text(subscription)).await?;
+    }
+
+    let (mut sink, stream) = socket.split();
+    if let Some(ping) = ping {
+        tokio::spawn(async move {
+            let mut tick = interval(PING_INTERVAL);
+            loop {
+                tick.tick().await;
+                // fails once the connection has closed
+                if sink.send(Message::Text(ping.to_string())).await.is_err() {
+                    break
+                }
+            }
+        });
+    }
+
+    Ok(Box::pin(stream.flat_map(move |message| {
+        let events = match message {
+            Ok(Message::Text(text)) => match parse(&text, now_micros()) {
+                Ok(events) => events.into_iter().map(Ok).collect(),
+                Err(e) => vec![Err(e)],
+            },
+            Ok(_) => vec![],
+            Err(e) => vec![Err(e.into())],
+        };
+        futures::stream::iter(events)
+    })))
+}
+
+/// The current time in microseconds, the unit cex data is timestamped in
+pub(crate) fn now_micros() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_micros() as u64
+}
@@ -0,0 +1,175 @@
+//! OKX spot `books5` and `trades` channels on the v5 public websocket. OKX
+//! names instruments with a dash between the base and quote, e.g. `ETH-USDT`.
+use brontes_types::db::cex::{
+    quotes::RawCexQuotes,
+    trades::{RawCexTrades, TradeType},
+    CexExchange,
+};
+use serde::Deserialize;
+use serde_with::{serde_as, DisplayFromStr};
+
+use super::{stream_messages, CexFeedEvent, FeedStream};
+
+const OKX_STREAM_URL: &str = "wss://ws.okx.com:8443/ws/v5/public";
+/// OKX closes connections that go 30 seconds without a message, and answers
+/// this with `pong`
+const OKX_PING: &str = "ping";
+
+/// Subscription acks and errors carry an event rather than data
+#[derive(Debug, Deserialize)]
+struct PushMessage {
+    arg:  Arg,
+    #[serde(default)]
+    data: serde_json::Value,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Arg {
+    channel: String,
+    #[serde(default)]
+    inst_id: String,
+}
+
+/// The top five levels of each side, best first, each as
+/// `[price, size, _, orders]`. Always pushed whole
+#[serde_as]
+#[derive(Debug, Deserialize)]
+struct Book {
+    #[serde_as(as = "Vec<Vec<DisplayFromStr>>")]
+    asks: Vec<Vec<f64>>,
+    #[serde_as(as = "Vec<Vec<DisplayFromStr>>")]
+    bids: Vec<Vec<f64>>,
+    /// in milliseconds
+    #[serde_as(as = "DisplayFromStr")]
+    ts:   u64,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Trade {
+    inst_id: String,
+    #[serde_as(as = "DisplayFromStr")]
+    px:      f64,
+    #[serde_as(as = "DisplayFromStr")]
+    sz:      f64,
+    /// the side of the taker
+    side:    String,
+    /// in milliseconds
+    #[serde_as(as = "DisplayFromStr")]
+    ts:      u64,
+}
+
+/// Streams the quotes and trades of `symbols`, named as okx names them
+pub(super) async fn subscribe(symbols: &[String]) -> eyre::Result<FeedStream> {
+    let args = symbols
+        .iter()
+        .flat_map(|symbol| {
+            [
+                serde_json::json!({ "channel": "books5", "instId": symbol }),
+                serde_json::json!({ "channel": "trades", "instId": symbol }),
+            ]
+        })
+        .collect::<Vec<_>>();
+    let subscription = serde_json::json!({ "op": "subscribe", "args": args });
+
+    stream_messages(OKX_STREAM_URL, vec![subscription.to_string()], Some(OKX_PING), parse_message)
+        .await
+}
+
+fn parse_message(text: &str, _received_at: u64) -> eyre::Result<Vec<CexFeedEvent>> {
+    if text == "pong" {
+        return Ok(vec![])
+    }
+    let message: PushMessage = serde_json::from_str(text)?;
+    if message.data.is_null() {
+        return Ok(vec![])
+    }
+
+    match message.arg.channel.as_str() {
+        "books5" => Ok(serde_json::from_value::<Vec<Book>>(message.data)?
+            .into_iter()
+            // both sides are needed for a quote
+            .filter_map(|book| {
+                let (ask, bid) = (book.asks.first()?, book.bids.first()?);
+                Some(CexFeedEvent::Quote(RawCexQuotes {
+                    exchange:   CexExchange::Okex,
+                    symbol:     message.arg.inst_id.clone(),
+                    timestamp:  book.ts * 1_000,
+                    ask_amount: *ask.get(1)?,
+                    ask_price:  *ask.first()?,
+                    bid_price:  *bid.first()?,
+                    bid_amount: *bid.get(1)?,
+                    depth:      (levels(&book.bids), levels(&book.asks)),
+                }))
+            })
+            .collect()),
+        "trades" => Ok(serde_json::from_value::<Vec<Trade>>(message.data)?
+            .into_iter()
+            .map(|trade| {
+                CexFeedEvent::Trade(RawCexTrades {
+                    exchange:   CexExchange::Okex,
+                    trade_type: TradeType::Taker,
+                    symbol:     trade.inst_id,
+                    timestamp:  trade.ts * 1_000,
+                    side:       trade.side,
+                    price:      trade.px,
+                    amount:     trade.sz,
+                })
+            })
+            .collect()),
+        _ => Ok(vec![]),
+    }
+}
+
+fn levels(side: &[Vec<f64>]) -> Vec<(f64, f64)> {
+    side.iter()
+        .filter_map(|level| Some((*level.first()?, *level.get(1)?)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_book() {
+        let text = concat!(
+            r#"{"arg":{"channel":"books5","instId":"ETH-USDT"},"data":[{"#,
+            r#""asks":[["3012.52","40.66","0","12"],["3012.6","2.5","0","3"]],"#,
+            r#""bids":[["3012.51","31.21","0","9"]],"instId":"ETH-USDT","#,
+            r#""ts":"1718000000000","seqId":1}]}"#
+        );
+
+        let [CexFeedEvent::Quote(quote)] = &parse_message(text, 0).unwrap()[..] else {
+            panic!("expected a quote")
+        };
+        assert_eq!(quote.symbol, "ETH-USDT");
+        assert_eq!(quote.timestamp, 1_718_000_000_000_000);
+        assert_eq!(quote.ask_price, 3012.52);
+        assert_eq!(quote.bid_amount, 31.21);
+        assert_eq!(quote.depth.0, vec![(3012.51, 31.21)]);
+        assert_eq!(quote.depth.1, vec![(3012.52, 40.66), (3012.6, 2.5)]);
+    }
+
+    #[test]
+    fn test_parse_trade() {
+        let text = concat!(
+            r#"{"arg":{"channel":"trades","instId":"ETH-USDT"},"data":[{"instId":"ETH-USDT","#,
+            r#""tradeId":"130639474","px":"3012.5","sz":"0.25","side":"sell","#,
+            r#""ts":"1718000000000","count":"1"}]}"#
+        );
+
+        let [CexFeedEvent::Trade(trade)] = &parse_message(text, 0).unwrap()[..] else {
+            panic!("expected a trade")
+        };
+        assert_eq!(trade.timestamp, 1_718_000_000_000_000);
+        assert_eq!(trade.side, "sell");
+        assert_eq!(trade.price, 3012.5);
+
+        let ack = r#"{"event":"subscribe","arg":{"channel":"trades","instId":"ETH-USDT"}}"#;
+        assert!(parse_message(ack, 0).unwrap().is_empty());
+        assert!(parse_message("pong", 0).unwrap().is_empty());
+    }
+}
@@ -22,6 +22,7 @@ impl Default for CexDownloadConfig {
                 CexExchange::Okex,
                 CexExchange::BybitSpot,
                 CexExchange::Kucoin,
+                CexExchange::Kraken,
             ],
         }
     }
@@ -184,6 +184,13 @@ impl Clickhouse {
                     BundleData::Liquidation(s) => {
                         tx.send(vec![(s, self.tip, self.run_id).into()])?
                     }
+                    BundleData::NftMev(s) => tx.send(vec![(s, self.tip, self.run_id).into()])?,
+                    BundleData::OracleManipulation(s) => {
+                        tx.send(vec![(s, self.tip, self.run_id).into()])?
+                    }
+                    BundleData::LaunchSnipe(s) => {
+                        tx.send(vec![(s, self.tip, self.run_id).into()])?
+                    }
                     BundleData::Unknown(s) => tx.send(vec![(s, self.tip, self.run_id).into()])?,
                 };
 
@@ -843,8 +850,8 @@ mod tests {
         init_thread_pools,
         mev::{
             ArbDetails, AtomicArb, BundleHeader, CexDex, CexDexQuote, JitLiquidity,
-            JitLiquiditySandwich, Liquidation, OptimisticTrade, PossibleMev, PossibleMevCollection,
-            Sandwich,
+            JitLiquiditySandwich, LaunchSnipe, Liquidation, NftMev, NftMevKind, NftMevLeg,
+            OptimisticTrade, OracleManipulation, PossibleMev, PossibleMevCollection, Sandwich,
         },
         normalized_actions::{
             NormalizedBurn, NormalizedLiquidation, NormalizedMint, NormalizedSwap,
@@ -991,6 +998,7 @@ mod tests {
                 priority_fee:        0,
                 gas_used:            271686,
                 effective_gas_price: 8875282233,
+                blob_gas_paid:       0,
             },
         };
 
@@ -1051,6 +1059,42 @@ mod tests {
             .unwrap();
     }
 
+    async fn nft_mev(db: &ClickhouseTestClient<BrontesClickhouseTables>) {
+        let case0 = NftMev {
+            kind: NftMevKind::MintSnipe,
+            tx_hashes: vec![Default::default()],
+            gas_details: vec![GasDetails::default()],
+            acquisitions: vec![NftMevLeg::default()],
+            sales: vec![NftMevLeg::default()],
+            ..NftMev::default()
+        };
+
+        db.insert_one::<MevNft_Mev>(&DbDataWithRunId::new_with_run_id(case0, 0))
+            .await
+            .unwrap();
+    }
+
+    async fn oracle_manipulation(db: &ClickhouseTestClient<BrontesClickhouseTables>) {
+        let case0 = OracleManipulation {
+            manipulation_swaps: vec![NormalizedSwap::default()],
+            gas_details: vec![GasDetails::default()],
+            ..OracleManipulation::default()
+        };
+
+        db.insert_one::<MevOracle_Manipulations>(&DbDataWithRunId::new_with_run_id(case0, 0))
+            .await
+            .unwrap();
+    }
+
+    async fn launch_snipe(db: &ClickhouseTestClient<BrontesClickhouseTables>) {
+        let case0 =
+            LaunchSnipe { swaps: vec![NormalizedSwap::default()], ..LaunchSnipe::default() };
+
+        db.insert_one::<MevLaunch_Snipes>(&DbDataWithRunId::new_with_run_id(case0, 0))
+            .await
+            .unwrap();
+    }
+
     async fn bundle_header(db: &ClickhouseTestClient<BrontesClickhouseTables>) {
         let case0 = BundleHeader::default();
 
@@ -1139,6 +1183,9 @@ mod tests {
         sandwich(database).await;
         bundle_header(database).await;
         liquidations(database).await;
+        nft_mev(database).await;
+        oracle_manipulation(database).await;
+        launch_snipe(database).await;
         jit_sandwich(database).await;
         jit(database).await;
         cex_dex(database).await;
@@ -17,9 +17,12 @@ clickhouse_dbms!(
         MevMev_Blocks,
         MevBundle_Header,
         MevSearcher_Tx,
+        MevLaunch_Snipes,
+        MevOracle_Manipulations,
         MevCex_Dex_Quotes,
         MevCex_Dex,
         MevLiquidations,
+        MevNft_Mev,
         MevJit_Sandwich,
         MevJit,
         MevSandwiches,
@@ -76,6 +79,20 @@ remote_clickhouse_table!(
     "crates/brontes-database/brontes-db/src/clickhouse/tables/"
 );
 
+remote_clickhouse_table!(
+    BrontesClickhouseTables,
+    [Mev, Oracle_Manipulations],
+    DbDataWithRunId<OracleManipulation>,
+    "crates/brontes-database/brontes-db/src/clickhouse/tables/"
+);
+
+remote_clickhouse_table!(
+    BrontesClickhouseTables,
+    [Mev, Launch_Snipes],
+    DbDataWithRunId<LaunchSnipe>,
+    "crates/brontes-database/brontes-db/src/clickhouse/tables/"
+);
+
 remote_clickhouse_table!(
     BrontesClickhouseTables,
     [Mev, Cex_Dex],
@@ -97,6 +114,13 @@ remote_clickhouse_table!(
     "crates/brontes-database/brontes-db/src/clickhouse/tables/"
 );
 
+remote_clickhouse_table!(
+    BrontesClickhouseTables,
+    [Mev, Nft_Mev],
+    DbDataWithRunId<NftMev>,
+    "crates/brontes-database/brontes-db/src/clickhouse/tables/"
+);
+
 remote_clickhouse_table!(
     BrontesClickhouseTables,
     [Mev, Jit_Sandwich],
@@ -233,9 +257,12 @@ db_types!(
     (MevBlock, MevMev_Blocks, true),
     (BundleHeader, MevBundle_Header, true),
     (SearcherTx, MevSearcher_Tx, true),
+    (LaunchSnipe, MevLaunch_Snipes, true),
+    (OracleManipulation, MevOracle_Manipulations, true),
     (CexDex, MevCex_Dex, true),
     (CexDexQuote, MevCex_Dex_Quotes, true),
     (Liquidation, MevLiquidations, true),
+    (NftMev, MevNft_Mev, true),
     (JitLiquiditySandwich, MevJit_Sandwich, true),
     (JitLiquidity, MevJit, true),
     (Sandwich, MevSandwiches, true),
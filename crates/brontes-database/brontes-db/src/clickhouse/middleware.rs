@@ -4,14 +4,17 @@ use alloy_primitives::Address;
 use brontes_types::{
     db::{
         address_metadata::AddressMetadata,
-        address_to_protocol_info::ProtocolInfo,
+        address_to_protocol_info::{ProtocolInfo, ProtocolInfoHistory},
+        arb_index::PairArbSummary,
         block_analysis::BlockAnalysis,
-        builder::BuilderInfo,
+        builder::{BuilderInfo, BuilderRevenue},
+        cex::{quotes::CexPriceMap, trades::CexTradeMap, CexTokenListings},
+        commitment::BlockCommitment,
         dex::DexQuotes,
         metadata::Metadata,
         mev_block::MevBlockWithClassified,
         searcher::SearcherInfo,
-        token_info::TokenInfoWithAddress,
+        token_info::{TokenInfoWithAddress, TransferFee},
         traits::{DBWriter, LibmdbxReader, ProtocolCreatedRange},
     },
     mev::{Bundle, MevBlock},
@@ -81,6 +84,23 @@ impl<I: DBWriter + Send + Sync> DBWriter for ClickhouseMiddleware<I> {
             .await
     }
 
+    // clickhouse's token info has no column for the inferred flag, it is only
+    // kept by the inner writer
+    async fn write_inferred_token_info(
+        &self,
+        address: Address,
+        decimals: u8,
+        symbol: String,
+    ) -> eyre::Result<()> {
+        self.client
+            .write_token_info(address, decimals, symbol.clone())
+            .await?;
+
+        self.inner()
+            .write_inferred_token_info(address, decimals, symbol)
+            .await
+    }
+
     async fn save_mev_blocks(
         &self,
         block_number: u64,
@@ -313,6 +333,10 @@ impl<I: LibmdbxInit> LibmdbxReader for ClickhouseMiddleware<I> {
         self.inner.fetch_all_builder_info()
     }
 
+    fn try_fetch_builder_payments(&self, builder: Address) -> eyre::Result<Option<BuilderRevenue>> {
+        self.inner.try_fetch_builder_payments(builder)
+    }
+
     //TODO: JOE
     fn try_fetch_mev_blocks(
         &self,
@@ -329,6 +353,22 @@ impl<I: LibmdbxInit> LibmdbxReader for ClickhouseMiddleware<I> {
         todo!("Joe");
     }
 
+    fn fetch_pair_arb_index(
+        &self,
+        start_block: Option<u64>,
+        end_block: u64,
+    ) -> eyre::Result<Vec<(u64, PairArbSummary)>> {
+        self.inner.fetch_pair_arb_index(start_block, end_block)
+    }
+
+    fn fetch_block_commitments(
+        &self,
+        start_block: Option<u64>,
+        end_block: u64,
+    ) -> eyre::Result<Vec<(u64, BlockCommitment)>> {
+        self.inner.fetch_block_commitments(start_block, end_block)
+    }
+
     fn get_metadata(&self, block_num: u64, quote_asset: Address) -> eyre::Result<Metadata> {
         self.inner.get_metadata(block_num, quote_asset)
     }
@@ -351,6 +391,10 @@ impl<I: LibmdbxInit> LibmdbxReader for ClickhouseMiddleware<I> {
         self.inner.fetch_all_address_metadata()
     }
 
+    fn try_fetch_cex_listings(&self, token: Address) -> eyre::Result<Option<CexTokenListings>> {
+        self.inner.try_fetch_cex_listings(token)
+    }
+
     fn get_dex_quotes(&self, block: u64) -> eyre::Result<DexQuotes> {
         self.inner.get_dex_quotes(block)
     }
@@ -359,6 +403,10 @@ impl<I: LibmdbxInit> LibmdbxReader for ClickhouseMiddleware<I> {
         self.inner.try_fetch_token_info(address)
     }
 
+    fn try_fetch_transfer_fee(&self, token: Address) -> eyre::Result<Option<TransferFee>> {
+        self.inner.try_fetch_transfer_fee(token)
+    }
+
     fn protocols_created_before(
         &self,
         start_block: u64,
@@ -378,6 +426,10 @@ impl<I: LibmdbxInit> LibmdbxReader for ClickhouseMiddleware<I> {
         self.inner.get_protocol_details(address)
     }
 
+    fn get_protocol_history(&self, address: Address) -> eyre::Result<ProtocolInfoHistory> {
+        self.inner.get_protocol_history(address)
+    }
+
     fn load_trace(&self, block_num: u64) -> eyre::Result<Vec<TxTrace>> {
         self.inner.load_trace(block_num)
     }
@@ -427,6 +479,35 @@ impl<I: DBWriter + Send + Sync> DBWriter for ReadOnlyMiddleware<I> {
             .await
     }
 
+    // clickhouse's token info has no column for the inferred flag, it is only
+    // kept by the inner writer
+    async fn write_inferred_token_info(
+        &self,
+        address: Address,
+        decimals: u8,
+        symbol: String,
+    ) -> eyre::Result<()> {
+        self.client
+            .write_token_info(address, decimals, symbol.clone())
+            .await?;
+
+        self.inner
+            .write_inferred_token_info(address, decimals, symbol)
+            .await
+    }
+
+    async fn write_transfer_fee(&self, _: Address, _: TransferFee) -> eyre::Result<()> {
+        Ok(())
+    }
+
+    async fn write_cex_data(&self, _: u64, _: CexPriceMap, _: CexTradeMap) -> eyre::Result<()> {
+        Ok(())
+    }
+
+    async fn write_builder_payments(&self, _: Address, _: BuilderRevenue) -> eyre::Result<()> {
+        Ok(())
+    }
+
     async fn save_mev_blocks(
         &self,
         block_number: u64,
@@ -631,6 +712,10 @@ impl<I: LibmdbxInit> LibmdbxReader for ReadOnlyMiddleware<I> {
         self.inner.fetch_all_builder_info()
     }
 
+    fn try_fetch_builder_payments(&self, builder: Address) -> eyre::Result<Option<BuilderRevenue>> {
+        self.inner.try_fetch_builder_payments(builder)
+    }
+
     //TODO: JOE
     fn try_fetch_mev_blocks(
         &self,
@@ -647,6 +732,22 @@ impl<I: LibmdbxInit> LibmdbxReader for ReadOnlyMiddleware<I> {
         todo!("Joe");
     }
 
+    fn fetch_pair_arb_index(
+        &self,
+        start_block: Option<u64>,
+        end_block: u64,
+    ) -> eyre::Result<Vec<(u64, PairArbSummary)>> {
+        self.inner.fetch_pair_arb_index(start_block, end_block)
+    }
+
+    fn fetch_block_commitments(
+        &self,
+        start_block: Option<u64>,
+        end_block: u64,
+    ) -> eyre::Result<Vec<(u64, BlockCommitment)>> {
+        self.inner.fetch_block_commitments(start_block, end_block)
+    }
+
     fn get_metadata(&self, block_num: u64, quote_asset: Address) -> eyre::Result<Metadata> {
         self.inner.get_metadata(block_num, quote_asset)
     }
@@ -669,6 +770,10 @@ impl<I: LibmdbxInit> LibmdbxReader for ReadOnlyMiddleware<I> {
         self.inner.fetch_all_address_metadata()
     }
 
+    fn try_fetch_cex_listings(&self, token: Address) -> eyre::Result<Option<CexTokenListings>> {
+        self.inner.try_fetch_cex_listings(token)
+    }
+
     fn get_dex_quotes(&self, block: u64) -> eyre::Result<DexQuotes> {
         self.inner.get_dex_quotes(block)
     }
@@ -677,6 +782,10 @@ impl<I: LibmdbxInit> LibmdbxReader for ReadOnlyMiddleware<I> {
         self.inner.try_fetch_token_info(address)
     }
 
+    fn try_fetch_transfer_fee(&self, token: Address) -> eyre::Result<Option<TransferFee>> {
+        self.inner.try_fetch_transfer_fee(token)
+    }
+
     fn protocols_created_before(
         &self,
         start_block: u64,
@@ -696,6 +805,10 @@ impl<I: LibmdbxInit> LibmdbxReader for ReadOnlyMiddleware<I> {
         self.inner.get_protocol_details(address)
     }
 
+    fn get_protocol_history(&self, address: Address) -> eyre::Result<ProtocolInfoHistory> {
+        self.inner.get_protocol_history(address)
+    }
+
     fn load_trace(&self, block_num: u64) -> eyre::Result<Vec<TxTrace>> {
         self.inner.load_trace(block_num)
     }
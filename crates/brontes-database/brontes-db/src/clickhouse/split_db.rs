@@ -110,11 +110,14 @@ impl ClickhouseBuffered {
             (MevCex_Dex_Quotes, CexDexQuote),
             (MevCex_Dex, CexDex),
             (MevSearcher_Tx, SearcherTx),
+            (MevLaunch_Snipes, LaunchSnipe),
+            (MevOracle_Manipulations, OracleManipulation),
             (MevJit, JitLiquidity),
             (MevJit_Sandwich, JitLiquiditySandwich),
             (MevSandwiches, Sandwich),
             (MevAtomic_Arbs, AtomicArb),
             (MevLiquidations, Liquidation),
+            (MevNft_Mev, NftMev),
             (BrontesDex_Price_Mapping, DexQuotesWithBlockNumber),
             (BrontesToken_Info, TokenInfoWithAddress),
             (EthereumPools, ProtocolInfoClickhouse),
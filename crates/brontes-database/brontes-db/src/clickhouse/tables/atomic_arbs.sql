@@ -11,15 +11,21 @@ CREATE TABLE mev.atomic_arbs ON CLUSTER eth_cluster0
         `token_in` Tuple(String, String),
         `token_out` Tuple(String, String),
         `amount_in` Tuple(UInt256, UInt256),
-        `amount_out` Tuple(UInt256, UInt256)
+        `amount_out` Tuple(UInt256, UInt256),
+        `amount_in_float` Float64,
+        `amount_out_float` Float64,
+        `protocol` Tuple(String, String, String)
     ),
     `gas_details` Tuple(
         `coinbase_transfer` Nullable(UInt128), 
         `priority_fee` UInt128,
         `gas_used` UInt128,
-        `effective_gas_price` UInt128
+        `effective_gas_price` UInt128,
+        `blob_gas_paid` UInt128
     ),
     `arb_type` String,
+    `pool_path` Array(String),
+    `token_path` Array(String),
     `run_id` UInt64
 ) 
 ENGINE = ReplicatedReplacingMergeTree('/clickhouse/eth_cluster0/tables/all/mev/atomic_arbs', '{replica}', `run_id`)
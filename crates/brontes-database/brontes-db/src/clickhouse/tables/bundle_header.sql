@@ -10,6 +10,7 @@ CREATE TABLE mev.bundle_header ON CLUSTER eth_cluster0
     `bribe_usd` Float64,
     `mev_type` String,
     `no_pricing_calculated` Bool DEFAULT false,
+    `governance_event_in_block` Bool DEFAULT false,
     `balance_deltas` Nested (
         `tx_hash` String,
         `address` String,
@@ -12,7 +12,10 @@ CREATE TABLE mev.cex_dex ON CLUSTER eth_cluster0
         `token_in` Tuple(String, String),
         `token_out` Tuple(String, String),
         `amount_in` Tuple(UInt256, UInt256),
-        `amount_out` Tuple(UInt256, UInt256)
+        `amount_out` Tuple(UInt256, UInt256),
+        `amount_in_float` Float64,
+        `amount_out_float` Float64,
+        `protocol` Tuple(String, String, String)
     ),
     `global_vmap_details` Nested(
         `pairs` Array(Tuple(String, String)),
@@ -84,11 +87,22 @@ CREATE TABLE mev.cex_dex ON CLUSTER eth_cluster0
         `pnl_maker` Tuple(UInt256, UInt256),
         `pnl_taker` Tuple(UInt256, UInt256)
     ),
+    `venues_considered` Array(String),
+    `markout_details` Nested(
+        `swap_idx` UInt64,
+        `markout_us` UInt64,
+        `window_start` UInt64,
+        `window_end` UInt64,
+        `cex_price` Tuple(UInt256, UInt256),
+        `taker_fee` Tuple(UInt256, UInt256),
+        `pnl` Tuple(UInt256, UInt256)
+    ),
     `gas_details` Tuple(
         `coinbase_transfer` Nullable(UInt128),
         `priority_fee` UInt128,
         `gas_used` UInt128,
-        `effective_gas_price` UInt128
+        `effective_gas_price` UInt128,
+        `blob_gas_paid` UInt128
     ),
     `run_id` UInt64
 )
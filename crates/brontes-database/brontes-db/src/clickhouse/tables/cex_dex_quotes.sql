@@ -11,7 +11,10 @@ CREATE TABLE mev.cex_dex_quotes ON CLUSTER eth_cluster0
         `token_in` Tuple(String, String),
         `token_out` Tuple(String, String),
         `amount_in` Tuple(UInt256, UInt256),
-        `amount_out` Tuple(UInt256, UInt256)
+        `amount_out` Tuple(UInt256, UInt256),
+        `amount_in_float` Float64,
+        `amount_out_float` Float64,
+        `protocol` Tuple(String, String, String)
     ),
     `instant_mid_price` Array(Float64),
     `t2_mid_price` Array(Float64),
@@ -25,7 +28,8 @@ CREATE TABLE mev.cex_dex_quotes ON CLUSTER eth_cluster0
         `coinbase_transfer` Nullable(UInt128),
         `priority_fee` UInt128,
         `gas_used` UInt128,
-        `effective_gas_price` UInt128
+        `effective_gas_price` UInt128,
+        `blob_gas_paid` UInt128
     ),
     `run_id` UInt64
 )
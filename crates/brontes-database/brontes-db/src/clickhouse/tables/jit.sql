@@ -8,13 +8,15 @@ CREATE TABLE mev.jit ON CLUSTER eth_cluster0
         `pool` String,
         `recipient` String,
         `tokens` Array(Tuple(String, String)),
-        `amounts` Array(Tuple(UInt256, UInt256))
+        `amounts` Array(Tuple(UInt256, UInt256)),
+        `amounts_float` Array(Float64)
     ),
     `frontrun_mint_gas_details` Tuple(
         `coinbase_transfer` Nullable(UInt128), 
         `priority_fee` UInt128,
         `gas_used` UInt128,
-        `effective_gas_price` UInt128
+        `effective_gas_price` UInt128,
+        `blob_gas_paid` UInt128
     ),
     `victim_swaps` Nested(
         `tx_hash` String,
@@ -25,14 +27,18 @@ CREATE TABLE mev.jit ON CLUSTER eth_cluster0
         `token_in` String,
         `token_out` String,
         `amount_in` Tuple(UInt256, UInt256),
-        `amount_out` Tuple(UInt256, UInt256)
+        `amount_out` Tuple(UInt256, UInt256),
+        `amount_in_float` Float64,
+        `amount_out_float` Float64,
+        `protocol` Tuple(String, String, String)
     ),
     `victim_gas_details` Nested(
         `tx_hash` String,
         `coinbase_transfer` Nullable(UInt128), 
         `priority_fee` UInt128,
         `gas_used` UInt128,
-        `effective_gas_price` UInt128
+        `effective_gas_price` UInt128,
+        `blob_gas_paid` UInt128
     ),
     `backrun_burn_tx_hash` String,
     `backrun_burns` Nested(
@@ -41,13 +47,15 @@ CREATE TABLE mev.jit ON CLUSTER eth_cluster0
         `pool` String,
         `recipient` String,
         `tokens` Array(Tuple(String, String)),
-        `amounts` Array(Tuple(UInt256, UInt256))
+        `amounts` Array(Tuple(UInt256, UInt256)),
+        `amounts_float` Array(Float64)
     ),
     `backrun_burn_gas_details` Tuple(
         `coinbase_transfer` Nullable(UInt128), 
         `priority_fee` UInt128,
         `gas_used` UInt128,
-        `effective_gas_price` UInt128
+        `effective_gas_price` UInt128,
+        `blob_gas_paid` UInt128
     ),
     `run_id` UInt64
 ) 
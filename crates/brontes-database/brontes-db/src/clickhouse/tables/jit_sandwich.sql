@@ -11,7 +11,10 @@ CREATE TABLE mev.jit_sandwich ON CLUSTER eth_cluster0
         `token_in` Tuple(String, String),
         `token_out` Tuple(String, String),
         `amount_in` Tuple(UInt256, UInt256),
-        `amount_out` Tuple(UInt256, UInt256)
+        `amount_out` Tuple(UInt256, UInt256),
+        `amount_in_float` Float64,
+        `amount_out_float` Float64,
+        `protocol` Tuple(String, String, String)
     ),
     `frontrun_mints` Nested(
         `tx_hash` String,
@@ -20,14 +23,16 @@ CREATE TABLE mev.jit_sandwich ON CLUSTER eth_cluster0
         `pool` String,
         `recipient` String,
         `tokens` Array(Tuple(String, String)),
-        `amounts` Array(Tuple(UInt256, UInt256))
+        `amounts` Array(Tuple(UInt256, UInt256)),
+        `amounts_float` Array(Float64)
     ),
     `frontrun_gas_details` Nested(
         `tx_hash` String,
         `coinbase_transfer` Nullable(UInt128), 
         `priority_fee` UInt128,
         `gas_used` UInt128,
-        `effective_gas_price` UInt128
+        `effective_gas_price` UInt128,
+        `blob_gas_paid` UInt128
     ),
     `victim_swaps` Nested(
         `tx_hash` String,
@@ -38,14 +43,18 @@ CREATE TABLE mev.jit_sandwich ON CLUSTER eth_cluster0
         `token_in` Tuple(String, String),
         `token_out` Tuple(String, String),
         `amount_in` Tuple(UInt256, UInt256),
-        `amount_out` Tuple(UInt256, UInt256)
+        `amount_out` Tuple(UInt256, UInt256),
+        `amount_in_float` Float64,
+        `amount_out_float` Float64,
+        `protocol` Tuple(String, String, String)
     ),
     `victim_gas_details` Nested(
         `tx_hash` String,
         `coinbase_transfer` Nullable(UInt128), 
         `priority_fee` UInt128,
         `gas_used` UInt128,
-        `effective_gas_price` UInt128
+        `effective_gas_price` UInt128,
+        `blob_gas_paid` UInt128
     ),
     `backrun_tx_hash` String,
     `backrun_swaps` Nested(
@@ -57,7 +66,10 @@ CREATE TABLE mev.jit_sandwich ON CLUSTER eth_cluster0
         `token_in` Tuple(String, String),
         `token_out` Tuple(String, String),
         `amount_in` Tuple(UInt256, UInt256),
-        `amount_out` Tuple(UInt256, UInt256)
+        `amount_out` Tuple(UInt256, UInt256),
+        `amount_in_float` Float64,
+        `amount_out_float` Float64,
+        `protocol` Tuple(String, String, String)
     ),
     `backrun_burns` Nested(
         `tx_hash` String,
@@ -66,14 +78,16 @@ CREATE TABLE mev.jit_sandwich ON CLUSTER eth_cluster0
         `pool` String,
         `recipient` String,
         `tokens` Array(Tuple(String, String)),
-        `amounts` Array(Tuple(UInt256, UInt256))
+        `amounts` Array(Tuple(UInt256, UInt256)),
+        `amounts_float` Array(Float64)
     ),
     `backrun_gas_details` Nested(
         `tx_hash` String,
         `coinbase_transfer` Nullable(UInt128), 
         `priority_fee` UInt128,
         `gas_used` UInt128,
-        `effective_gas_price` UInt128
+        `effective_gas_price` UInt128,
+        `blob_gas_paid` UInt128
     ),
     `run_id` UInt64
 ) 
@@ -0,0 +1,35 @@
+CREATE TABLE mev.launch_snipes ON CLUSTER eth_cluster0
+(
+    `block_number` UInt64,
+    `tx_hash` String,
+    `sniper` String,
+    `token` String,
+    `pool` String,
+    `protocol` String,
+    `pool_creation_block` UInt64,
+    `blocks_after_launch` UInt64,
+    `swaps` Nested(
+        `trace_idx` UInt64,
+        `from` String,
+        `recipient` String,
+        `pool` String,
+        `token_in` Tuple(String, String),
+        `token_out` Tuple(String, String),
+        `amount_in` Tuple(UInt256, UInt256),
+        `amount_out` Tuple(UInt256, UInt256),
+        `amount_in_float` Float64,
+        `amount_out_float` Float64,
+        `protocol` Tuple(String, String, String)
+    ),
+    `gas_details` Tuple(
+        `coinbase_transfer` Nullable(UInt128), 
+        `priority_fee` UInt128,
+        `gas_used` UInt128,
+        `effective_gas_price` UInt128,
+        `blob_gas_paid` UInt128
+    ),
+    `run_id` UInt64
+) 
+ENGINE = ReplicatedReplacingMergeTree('/clickhouse/eth_cluster0/tables/all/mev/launch_snipes', '{replica}', `run_id`)
+PRIMARY KEY (`block_number`, `tx_hash`)
+ORDER BY (`block_number`, `tx_hash`)
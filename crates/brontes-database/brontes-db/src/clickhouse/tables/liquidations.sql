@@ -10,7 +10,10 @@ CREATE TABLE mev.liquidations ON CLUSTER eth_cluster0
         `token_in` Tuple(String, String),
         `token_out` Tuple(String, String),
         `amount_in` Tuple(UInt256, UInt256),
-        `amount_out` Tuple(UInt256, UInt256)
+        `amount_out` Tuple(UInt256, UInt256),
+        `amount_in_float` Float64,
+        `amount_out_float` Float64,
+        `protocol` Tuple(String, String, String)
     ),
     `liquidations` Nested(
         `trace_idx` UInt64,
@@ -20,13 +23,24 @@ CREATE TABLE mev.liquidations ON CLUSTER eth_cluster0
         `collateral_asset` Tuple(String, String),
         `debt_asset` Tuple(String, String),
         `covered_debt` Tuple(UInt256, UInt256),
-        `liquidated_collateral` Tuple(UInt256, UInt256)
+        `liquidated_collateral` Tuple(UInt256, UInt256),
+        `covered_debt_float` Float64,
+        `liquidated_collateral_float` Float64,
+        `protocol` Tuple(String, String, String)
       ),
     `gas_details` Tuple(
         `coinbase_transfer` Nullable(UInt128), 
         `priority_fee` UInt128,
         `gas_used` UInt128,
-        `effective_gas_price` UInt128
+        `effective_gas_price` UInt128,
+        `blob_gas_paid` UInt128
+    ),
+    `competition` Tuple(
+        `competitor_tx_hashes` Array(String),
+        `competing_searchers` UInt64,
+        `winner_bribe_per_gas` Float64,
+        `max_competitor_bribe_per_gas` Float64,
+        `bribe_escalation` Float64
     ),
     `run_id` UInt64
 ) 
@@ -31,6 +31,7 @@ CREATE TABLE mev.mev_blocks ON CLUSTER eth_cluster0
     `proposer_fee_recipient` Nullable(String),
     `proposer_mev_reward` Nullable(UInt128),
     `proposer_profit_usd` Nullable(Float64),
+    `fee_recipient_withdrawals` UInt128,
     `total_mev_profit_usd` Float64,
     `possible_mev` Nested (
         `tx_hash` String,
@@ -39,6 +40,7 @@ CREATE TABLE mev.mev_blocks ON CLUSTER eth_cluster0
         `gas_details.priority_fee` UInt128,
         `gas_details.gas_used` UInt128,
         `gas_details.effective_gas_price` UInt128,
+        `gas_details.blob_gas_paid` UInt128,
         `triggers.is_private` Bool,
         `triggers.coinbase_transfer` Bool,
         `triggers.high_priority_fee` Bool
@@ -0,0 +1,40 @@
+CREATE TABLE mev.nft_mev ON CLUSTER eth_cluster0
+(
+    `block_number` UInt64,
+    `kind` String,
+    `tx_hashes` Array(String),
+    `gas_details` Nested(
+        `tx_hash` String,
+        `coinbase_transfer` Nullable(UInt128), 
+        `priority_fee` UInt128,
+        `gas_used` UInt128,
+        `effective_gas_price` UInt128,
+        `blob_gas_paid` UInt128
+    ),
+    `acquisitions` Nested(
+        `tx_hash` String,
+        `trace_idx` UInt64,
+        `protocol` String,
+        `marketplace` String,
+        `collection` String,
+        `token_id` UInt256,
+        `counterparty` String,
+        `payment_token` String,
+        `price` Float64
+    ),
+    `sales` Nested(
+        `tx_hash` String,
+        `trace_idx` UInt64,
+        `protocol` String,
+        `marketplace` String,
+        `collection` String,
+        `token_id` UInt256,
+        `counterparty` String,
+        `payment_token` String,
+        `price` Float64
+    ),
+    `run_id` UInt64
+) 
+ENGINE = ReplicatedReplacingMergeTree('/clickhouse/eth_cluster0/tables/all/mev/nft_mev', '{replica}', `run_id`)
+PRIMARY KEY (`block_number`, `tx_hashes`)
+ORDER BY (`block_number`, `tx_hashes`)
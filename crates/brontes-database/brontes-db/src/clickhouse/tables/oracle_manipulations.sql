@@ -0,0 +1,36 @@
+CREATE TABLE mev.oracle_manipulations ON CLUSTER eth_cluster0
+(
+    `block_number` UInt64,
+    `manipulation_tx_hash` String,
+    `manipulated_pool` String,
+    `manipulation_swaps` Nested(
+        `trace_idx` UInt64,
+        `from` String,
+        `recipient` String,
+        `pool` String,
+        `token_in` Tuple(String, String),
+        `token_out` Tuple(String, String),
+        `amount_in` Tuple(UInt256, UInt256),
+        `amount_out` Tuple(UInt256, UInt256),
+        `amount_in_float` Float64,
+        `amount_out_float` Float64,
+        `protocol` Tuple(String, String, String)
+    ),
+    `price_impact` Float64,
+    `exploit_tx_hash` String,
+    `exploited_protocol` String,
+    `exploited_market` String,
+    `exploit_trace_idx` UInt64,
+    `gas_details` Nested(
+        `tx_hash` String,
+        `coinbase_transfer` Nullable(UInt128), 
+        `priority_fee` UInt128,
+        `gas_used` UInt128,
+        `effective_gas_price` UInt128,
+        `blob_gas_paid` UInt128
+    ),
+    `run_id` UInt64
+) 
+ENGINE = ReplicatedReplacingMergeTree('/clickhouse/eth_cluster0/tables/all/mev/oracle_manipulations', '{replica}', `run_id`)
+PRIMARY KEY (`block_number`, `manipulation_tx_hash`)
+ORDER BY (`block_number`, `manipulation_tx_hash`)
@@ -11,14 +11,18 @@ CREATE TABLE mev.sandwiches ON CLUSTER eth_cluster0
         `token_in` Tuple(String, String),
         `token_out` Tuple(String, String),
         `amount_in` Tuple(UInt256, UInt256),
-        `amount_out` Tuple(UInt256, UInt256)
+        `amount_out` Tuple(UInt256, UInt256),
+        `amount_in_float` Float64,
+        `amount_out_float` Float64,
+        `protocol` Tuple(String, String, String)
     ),
     `frontrun_gas_details` Nested(
         `tx_hash` String,
         `coinbase_transfer` Nullable(UInt128), 
         `priority_fee` UInt128,
         `gas_used` UInt128,
-        `effective_gas_price` UInt128
+        `effective_gas_price` UInt128,
+        `blob_gas_paid` UInt128
     ),
     `victim_swaps` Nested(
         `tx_hash` String,
@@ -29,14 +33,18 @@ CREATE TABLE mev.sandwiches ON CLUSTER eth_cluster0
         `token_in` Tuple(String, String),
         `token_out` Tuple(String, String),
         `amount_in` Tuple(UInt256, UInt256),
-        `amount_out` Tuple(UInt256, UInt256)
+        `amount_out` Tuple(UInt256, UInt256),
+        `amount_in_float` Float64,
+        `amount_out_float` Float64,
+        `protocol` Tuple(String, String, String)
     ),
     `victim_gas_details` Nested(
         `tx_hash` String,
         `coinbase_transfer` Nullable(UInt128), 
         `priority_fee` UInt128,
         `gas_used` UInt128,
-        `effective_gas_price` UInt128
+        `effective_gas_price` UInt128,
+        `blob_gas_paid` UInt128
     ),
     `backrun_tx_hash` String,
     `backrun_swaps` Nested(
@@ -48,14 +56,36 @@ CREATE TABLE mev.sandwiches ON CLUSTER eth_cluster0
         `token_in` Tuple(String, String),
         `token_out` Tuple(String, String),
         `amount_in` Tuple(UInt256, UInt256),
-        `amount_out` Tuple(UInt256, UInt256)
+        `amount_out` Tuple(UInt256, UInt256),
+        `amount_in_float` Float64,
+        `amount_out_float` Float64,
+        `protocol` Tuple(String, String, String)
     ),
     `backrun_gas_details` Nested(
         `tx_hash` String,
         `coinbase_transfer` Nullable(UInt128), 
         `priority_fee` UInt128,
         `gas_used` UInt128,
-        `effective_gas_price` UInt128
+        `effective_gas_price` UInt128,
+        `blob_gas_paid` UInt128
+    ),
+    `victim_slippage` Nested(
+        `tx_hash` String,
+        `token` String,
+        `exact_in` Bool,
+        `limit` Float64,
+        `realized` Float64,
+        `sqrt_price_limit` UInt256,
+        `remaining_slippage` Float64,
+        `consumed_slippage` Nullable(Float64)
+    ),
+    `victim_losses` Nested(
+        `tx_hash` String,
+        `victim_eoa` String,
+        `token` String,
+        `token_amount_lost` Float64,
+        `amount_lost_usd` Float64,
+        `price_degradation` Float64
     ),
     `run_id` UInt64
 ) 
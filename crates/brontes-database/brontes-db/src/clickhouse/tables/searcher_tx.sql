@@ -10,9 +10,11 @@ CREATE TABLE mev.searcher_tx ON CLUSTER eth_cluster0
         `token` Tuple(String, String),
         `amount` Tuple(UInt256, UInt256),
         `fee` Tuple(UInt256, UInt256),
+        `amount_float` Float64,
+        `fee_float` Float64,
         `msg_value` UInt256
     ),
-    `gas_details` Tuple(Nullable(UInt128), UInt128, UInt128, UInt128),
+    `gas_details` Tuple(Nullable(UInt128), UInt128, UInt128, UInt128, UInt128),
     `run_id` UInt64
 ) 
 ENGINE = ReplicatedReplacingMergeTree('/clickhouse/eth_cluster0/tables/all/mev/searcher_tx', '{replica}', `run_id`)
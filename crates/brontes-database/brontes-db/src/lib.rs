@@ -3,9 +3,11 @@
 #![feature(const_trait_impl)]
 #![feature(noop_waker)]
 
+pub mod cex_feed;
 pub mod clickhouse;
 pub mod libmdbx;
 pub mod parquet;
+pub mod tardis;
 pub use libmdbx::{
     tables::*,
     types::{CompressedTable, IntoTableKey},
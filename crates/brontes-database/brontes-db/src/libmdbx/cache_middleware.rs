@@ -3,8 +3,10 @@ use std::sync::Arc;
 use alloy_primitives::Address;
 use brontes_metrics::db_cache::CacheData;
 use brontes_types::db::{
-    address_metadata::AddressMetadata, address_to_protocol_info::ProtocolInfo,
-    searcher::SearcherInfo, token_info::TokenInfo,
+    address_metadata::AddressMetadata,
+    address_to_protocol_info::ProtocolInfo,
+    searcher::SearcherInfo,
+    token_info::{TokenInfo, TransferFee},
 };
 use moka::{policy::EvictionPolicy, sync::SegmentedCache};
 
@@ -17,6 +19,7 @@ pub struct ReadWriteCache {
     searcher_contract: Arc<SegmentedCache<Address, Option<SearcherInfo>, ahash::RandomState>>,
     protocol_info:     Arc<SegmentedCache<Address, Option<ProtocolInfo>, ahash::RandomState>>,
     token_info:        Arc<SegmentedCache<Address, Option<TokenInfo>, ahash::RandomState>>,
+    transfer_fee:      Arc<SegmentedCache<Address, Option<TransferFee>, ahash::RandomState>>,
 
     pub metrics: Option<CacheData>,
 }
@@ -65,6 +68,14 @@ impl ReadWriteCache {
                 )
                 .build_with_hasher(ahash::RandomState::new())
                 .into(),
+
+            transfer_fee: SegmentedCache::builder(200)
+                .eviction_policy(EvictionPolicy::lru())
+                .max_capacity(
+                    ((memory_per_table_mb * MEGABYTE) / std::mem::size_of::<TransferFee>()) as u64,
+                )
+                .build_with_hasher(ahash::RandomState::new())
+                .into(),
         }
     }
 
@@ -130,4 +141,12 @@ impl ReadWriteCache {
     ) -> R {
         self.record_metrics::<R, _, TokenInfo>(read, "token_info", &*self.token_info, f)
     }
+
+    pub fn transfer_fee<R>(
+        &self,
+        read: bool,
+        f: impl FnOnce(&SegmentedCache<Address, Option<TransferFee>, ahash::RandomState>) -> R,
+    ) -> R {
+        self.record_metrics::<R, _, TransferFee>(read, "transfer_fee", &*self.transfer_fee, f)
+    }
 }
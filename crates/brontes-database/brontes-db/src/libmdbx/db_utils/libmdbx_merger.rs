@@ -32,7 +32,9 @@ pub fn merge_libmdbx_dbs(
             SearcherContracts,
             Builder,
             AddressToProtocolInfo,
+            AddressToProtocolHistory,
             TokenDecimals,
+            InferredTokenDecimals,
             DexPrice
             );
 
@@ -129,7 +129,9 @@ impl LibmdbxPartitioner {
             SearcherContracts,
             Builder,
             AddressToProtocolInfo,
-            TokenDecimals
+            AddressToProtocolHistory,
+            TokenDecimals,
+            InferredTokenDecimals
         );
 
         Ok(())
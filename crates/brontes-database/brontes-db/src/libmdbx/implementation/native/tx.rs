@@ -87,6 +87,10 @@ impl LibmdbxTx<RW> {
 }
 
 impl<K: TransactionKind> LibmdbxTx<K> {
+    pub(crate) fn has_table(&self, table: &Tables) -> bool {
+        self.inner.open_db(Some(table.name())).is_ok()
+    }
+
     /// Gets a table database handle if it exists, otherwise creates it.
     pub(crate) fn get_dbi<T: Table>(&self) -> Result<DBI, DatabaseError> {
         let mut handles = self.db_handles.write();
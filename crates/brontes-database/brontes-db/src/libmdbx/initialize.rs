@@ -349,47 +349,13 @@ impl<TP: TracingProvider, CH: ClickhouseHandle> LibmdbxInitializer<TP, CH> {
         let mut workspace_dir = workspace_dir();
         workspace_dir.push(CLASSIFIER_CONFIG_FILE);
 
-        let Ok(config) = toml::from_str::<tomlTable>(&{
-            let Ok(path) = std::fs::read_to_string(workspace_dir) else {
-                tracing::error!(target: "brontes::init", "failed to read classifier_config");
-                return;
-            };
-            path
-        }) else {
-            tracing::error!(target: "brontes::init", "failed to load toml");
+        let Ok(config) = std::fs::read_to_string(workspace_dir) else {
+            tracing::error!(target: "brontes::init", "failed to read classifier_config");
             return;
         };
 
-        for (protocol, inner) in config {
-            let protocol: Protocol = protocol.parse().unwrap();
-            for (address, table) in inner.as_table().unwrap() {
-                let token_addr: Address = address.parse().unwrap();
-                let init_block = table.get("init_block").unwrap().as_integer().unwrap() as u64;
-
-                let table: Vec<TokenInfoWithAddressToml> = table
-                    .get("token_info")
-                    .map(|i| i.clone().try_into())
-                    .unwrap_or(Ok(vec![]))
-                    .unwrap_or(vec![]);
-
-                for t_info in &table {
-                    self.libmdbx
-                        .write_token_info(t_info.address, t_info.decimals, t_info.symbol.clone())
-                        .await
-                        .unwrap();
-                }
-
-                let token_addrs = if table.len() < 2 {
-                    [Address::default(), Address::default()]
-                } else {
-                    [table[0].address, table[1].address]
-                };
-
-                self.libmdbx
-                    .insert_pool(init_block, token_addr, &token_addrs, None, protocol)
-                    .await
-                    .unwrap();
-            }
+        if let Err(e) = load_classifier_config(self.libmdbx, &config).await {
+            tracing::error!(target: "brontes::init", err=%e, "failed to load classifier_config");
         }
     }
 
@@ -520,6 +486,89 @@ impl<TP: TracingProvider, CH: ClickhouseHandle> LibmdbxInitializer<TP, CH> {
     }
 }
 
+/// A protocol address listed in a classifier config. The table it is listed
+/// under is the protocol whose classifiers its calls are dispatched to
+#[derive(Debug)]
+pub struct ClassifierConfigEntry {
+    pub protocol:   Protocol,
+    pub address:    Address,
+    pub init_block: u64,
+    /// the first two tokens are stored as the pair of the pool
+    pub token_info: Vec<TokenInfoWithAddressToml>,
+}
+
+/// Parses a classifier config laid out like `config/classifier_config.toml`:
+///
+/// ```toml
+/// [UniswapV2."0xB4e16d0168e52d35CaCD2c6185b44281Ec28C9Dc"]
+/// init_block = 10008355
+///
+/// [[UniswapV2."0xB4e16d0168e52d35CaCD2c6185b44281Ec28C9Dc".token_info]]
+/// address = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"
+/// decimals = 6
+/// symbol = "USDC"
+/// ```
+pub fn parse_classifier_config(config: &str) -> eyre::Result<Vec<ClassifierConfigEntry>> {
+    let config = toml::from_str::<tomlTable>(config)?;
+
+    let mut entries = Vec::new();
+    for (protocol_name, addresses) in config {
+        let protocol: Protocol = protocol_name
+            .parse()
+            .map_err(|_| eyre::eyre!("unknown protocol {protocol_name}"))?;
+        let addresses = addresses
+            .as_table()
+            .ok_or_else(|| eyre::eyre!("{protocol_name} isn't a table of addresses"))?;
+
+        for (address, table) in addresses {
+            let init_block = table
+                .get("init_block")
+                .and_then(|block| block.as_integer())
+                .ok_or_else(|| eyre::eyre!("{protocol_name} {address} has no init_block"))?;
+
+            let token_info = table
+                .get("token_info")
+                .map(|info| info.clone().try_into())
+                .transpose()?
+                .unwrap_or_default();
+
+            entries.push(ClassifierConfigEntry {
+                protocol,
+                address: address.parse()?,
+                init_block: init_block as u64,
+                token_info,
+            });
+        }
+    }
+
+    Ok(entries)
+}
+
+/// Writes the protocol addresses and token info of a classifier config to the
+/// database, returning the number of addresses registered. Addresses that are
+/// already mapped are overwritten
+pub async fn load_classifier_config<DB: DBWriter>(db: &DB, config: &str) -> eyre::Result<usize> {
+    let entries = parse_classifier_config(config)?;
+
+    for entry in &entries {
+        for t_info in &entry.token_info {
+            db.write_token_info(t_info.address, t_info.decimals, t_info.symbol.clone())
+                .await?;
+        }
+
+        let tokens = if entry.token_info.len() < 2 {
+            [Address::default(), Address::default()]
+        } else {
+            [entry.token_info[0].address, entry.token_info[1].address]
+        };
+
+        db.insert_pool(entry.init_block, entry.address, &tokens, None, entry.protocol)
+            .await?;
+    }
+
+    Ok(entries.len())
+}
+
 fn workspace_dir() -> path::PathBuf {
     let output = std::process::Command::new(env!("CARGO"))
         .arg("locate-project")
@@ -617,13 +666,32 @@ mod tests {
 
     use brontes_core::test_utils::{get_db_handle, init_trace_parser};
     use brontes_database::libmdbx::{
-        initialize::LibmdbxInitializer, tables::*, test_utils::load_clickhouse,
+        initialize::{parse_classifier_config, LibmdbxInitializer},
+        tables::*,
+        test_utils::load_clickhouse,
     };
     use brontes_types::init_thread_pools;
     use indicatif::MultiProgress;
     use itertools::Itertools;
     use tokio::sync::mpsc::unbounded_channel;
 
+    #[test]
+    fn test_parse_classifier_config() {
+        let entries =
+            parse_classifier_config(include_str!("../../../../../config/classifier_config.toml"))
+                .unwrap();
+        assert!(!entries.is_empty());
+
+        let err = parse_classifier_config(
+            r#"
+            [NotAProtocol."0xB4e16d0168e52d35CaCD2c6185b44281Ec28C9Dc"]
+            init_block = 10008355
+            "#,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("unknown protocol"));
+    }
+
     #[brontes_macros::test]
     async fn test_intialize_clickhouse_tables() {
         let block_range = (19000000, 19000010);
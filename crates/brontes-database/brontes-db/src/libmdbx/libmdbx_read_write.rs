@@ -1,15 +1,18 @@
 use std::{ops::RangeInclusive, path::Path, sync::Arc};
 
 use alloy_primitives::Address;
+use brontes_libmdbx::RO;
 use brontes_metrics::db_reads::LibmdbxMetrics;
 use brontes_pricing::Protocol;
 use brontes_types::{
     constants::{ETH_ADDRESS, WETH_ADDRESS},
     db::{
         address_metadata::AddressMetadata,
-        address_to_protocol_info::ProtocolInfo,
-        builder::BuilderInfo,
-        cex::{quotes::CexPriceMap, trades::CexTradeMap},
+        address_to_protocol_info::{ProtocolInfo, ProtocolInfoHistory},
+        arb_index::PairArbSummary,
+        builder::{BuilderInfo, BuilderRevenue},
+        cex::{quotes::CexPriceMap, trades::CexTradeMap, CexTokenListings},
+        commitment::BlockCommitment,
         dex::{make_filter_key_range, DexPrices, DexQuotes},
         initialized_state::{
             InitializedStateMeta, CEX_QUOTES_FLAG, CEX_TRADES_FLAG, DATA_NOT_PRESENT_NOT_AVAILABLE,
@@ -18,7 +21,7 @@ use brontes_types::{
         metadata::{BlockMetadata, BlockMetadataInner, Metadata},
         mev_block::MevBlockWithClassified,
         searcher::SearcherInfo,
-        token_info::{TokenInfo, TokenInfoWithAddress},
+        token_info::{TokenInfo, TokenInfoWithAddress, TransferFee},
         traits::{DBWriter, LibmdbxReader},
     },
     mev::{Bundle, MevBlock},
@@ -39,6 +42,7 @@ use tokio::sync::mpsc::{unbounded_channel, UnboundedSender};
 use tracing::{info, instrument};
 
 use super::{
+    implementation::compressed_wrappers::tx::CompressedLibmdbxTx,
     libmdbx_writer::{LibmdbxWriter, StampedWriterMessage, WriterMessage},
     types::ReturnKV,
     ReadWriteCache,
@@ -154,6 +158,10 @@ impl LibmdbxInit for LibmdbxReadWriter {
         progress_bar: Arc<Vec<(Tables, ProgressBar)>>,
         metrics: bool,
     ) -> eyre::Result<()> {
+        if !self.db.profile().stores(tables) {
+            eyre::bail!("{} database doesn't have a {} table", self.db.profile(), tables)
+        }
+
         let initializer = LibmdbxInitializer::new(self, clickhouse, tracer, metrics);
         initializer
             .initialize(tables, clear_tables, block_range, progress_bar)
@@ -172,6 +180,10 @@ impl LibmdbxInit for LibmdbxReadWriter {
         progress_bar: Arc<Vec<(Tables, ProgressBar)>>,
         metrics: bool,
     ) -> eyre::Result<()> {
+        if !self.db.profile().stores(tables) {
+            eyre::bail!("{} database doesn't have a {} table", self.db.profile(), tables)
+        }
+
         let block_range = Box::leak(Box::new(block_range));
 
         let initializer = LibmdbxInitializer::new(self, clickhouse, tracer, metrics);
@@ -423,6 +435,10 @@ impl LibmdbxReader for LibmdbxReadWriter {
 
     #[brontes_macros::metrics_call(ptr=metrics,scope,db_read,"load_trace")]
     fn load_trace(&self, block_num: u64) -> eyre::Result<Vec<TxTrace>> {
+        if !self.db.profile().stores(Tables::TxTraces) {
+            eyre::bail!("{} database doesn't store traces", self.db.profile())
+        }
+
         self.db.view_db(|tx| {
             tx.get::<TxTraces>(block_num)?
                 .ok_or_else(|| eyre::eyre!("missing trace for block: {}", block_num))
@@ -458,6 +474,15 @@ impl LibmdbxReader for LibmdbxReadWriter {
         })
     }
 
+    #[brontes_macros::metrics_call(ptr=metrics,scope,db_read,"protocol_history")]
+    fn get_protocol_history(&self, address: Address) -> eyre::Result<ProtocolInfoHistory> {
+        self.db.view_db(|tx| {
+            tx.get::<AddressToProtocolHistory>(address)?.ok_or_else(|| {
+                eyre::eyre!("entry for key {:?} in AddressToProtocolHistory", address)
+            })
+        })
+    }
+
     #[brontes_macros::metrics_call(ptr=metrics, scope, db_read,"metadata_no_dex_price")]
     fn get_metadata_no_dex_price(
         &self,
@@ -702,9 +727,9 @@ impl LibmdbxReader for LibmdbxReadWriter {
         let mut map = FastHashMap::default();
 
         for result in cursor.walk_range(0..=block_num)? {
-            let res = result?.1;
+            let (block, res) = result?;
             for addr in res.0.into_iter() {
-                let Some(protocol_info) = tx.get::<AddressToProtocolInfo>(addr)? else {
+                let Some(protocol_info) = protocol_info_at(tx, addr, block)? else {
                     continue;
                 };
 
@@ -734,7 +759,7 @@ impl LibmdbxReader for LibmdbxReadWriter {
             let result = result?;
             let (block, res) = (result.0, result.1);
             for addr in res.0.into_iter() {
-                let Some(protocol_info) = tx.get::<AddressToProtocolInfo>(addr)? else {
+                let Some(protocol_info) = protocol_info_at(tx, addr, block)? else {
                     continue;
                 };
                 map.entry(block).or_insert(vec![]).push((
@@ -801,6 +826,33 @@ impl LibmdbxReader for LibmdbxReadWriter {
         }
     }
 
+    #[brontes_macros::metrics_call(ptr=metrics,scope,db_read,"try_fetch_cex_listings")]
+    fn try_fetch_cex_listings(&self, token: Address) -> eyre::Result<Option<CexTokenListings>> {
+        self.db
+            .view_db(|tx| tx.get::<CexListings>(token).map_err(ErrReport::from))
+    }
+
+    #[brontes_macros::metrics_call(ptr=metrics,scope,db_read,"try_fetch_transfer_fee")]
+    fn try_fetch_transfer_fee(&self, token: Address) -> eyre::Result<Option<TransferFee>> {
+        if let Some(fee) = self.cache.transfer_fee(true, |lock| lock.get(&token)) {
+            return Ok(fee)
+        }
+
+        let fee = self
+            .db
+            .view_db(|tx| tx.get::<TransferFees>(token).map_err(ErrReport::from))?;
+        self.cache
+            .transfer_fee(false, |lock| lock.insert(token, fee));
+
+        Ok(fee)
+    }
+
+    #[brontes_macros::metrics_call(ptr=metrics,scope,db_read,"try_fetch_builder_payments")]
+    fn try_fetch_builder_payments(&self, builder: Address) -> eyre::Result<Option<BuilderRevenue>> {
+        self.db
+            .view_db(|tx| tx.get::<BuilderPayments>(builder).map_err(ErrReport::from))
+    }
+
     #[brontes_macros::metrics_call(ptr=metrics,scope,db_read,"try_fetch_builder_info")]
     fn try_fetch_builder_info(
         &self,
@@ -880,6 +932,36 @@ impl LibmdbxReader for LibmdbxReadWriter {
         )
     }
 
+    #[instrument(level = "error", skip_all)]
+    fn fetch_pair_arb_index(
+        &self,
+        start_block: Option<u64>,
+        end_block: u64,
+    ) -> eyre::Result<Vec<(u64, PairArbSummary)>> {
+        self.db.view_db(|tx| {
+            let mut cursor = tx.cursor_read::<PairArbIndex>()?;
+            cursor
+                .walk_range(start_block.unwrap_or_default()..=end_block)?
+                .map(|entry| entry.map_err(ErrReport::from))
+                .collect()
+        })
+    }
+
+    #[instrument(level = "error", skip_all)]
+    fn fetch_block_commitments(
+        &self,
+        start_block: Option<u64>,
+        end_block: u64,
+    ) -> eyre::Result<Vec<(u64, BlockCommitment)>> {
+        self.db.view_db(|tx| {
+            let mut cursor = tx.cursor_read::<BlockCommitments>()?;
+            cursor
+                .walk_range(start_block.unwrap_or_default()..=end_block)?
+                .map(|entry| entry.map_err(ErrReport::from))
+                .collect()
+        })
+    }
+
     #[instrument(level = "error", skip_all)]
     fn fetch_all_address_metadata(&self) -> eyre::Result<Vec<(Address, AddressMetadata)>> {
         self.db.export_db(
@@ -993,6 +1075,17 @@ impl DBWriter for LibmdbxReadWriter {
             .send(WriterMessage::MevBlocks { block_number, block: Box::new(block), mev }.stamp())?)
     }
 
+    async fn save_mev_blocks_staging(
+        &self,
+        block_number: u64,
+        block: MevBlock,
+        mev: Vec<Bundle>,
+    ) -> eyre::Result<()> {
+        Ok(self.tx.send(
+            WriterMessage::MevBlocksStaging { block_number, block: Box::new(block), mev }.stamp(),
+        )?)
+    }
+
     async fn write_dex_quotes(
         &self,
         block_number: u64,
@@ -1019,6 +1112,42 @@ impl DBWriter for LibmdbxReadWriter {
             .send(WriterMessage::TokenInfo { address, decimals, symbol }.stamp())?)
     }
 
+    async fn write_inferred_token_info(
+        &self,
+        address: Address,
+        decimals: u8,
+        symbol: String,
+    ) -> eyre::Result<()> {
+        self.cache.token_info(false, |handle| {
+            let token_info = TokenInfo::new(decimals, symbol.clone());
+            handle.insert(address, Some(token_info.clone()));
+        });
+
+        Ok(self
+            .tx
+            .send(WriterMessage::InferredTokenInfo { address, decimals, symbol }.stamp())?)
+    }
+
+    async fn write_transfer_fee(&self, token: Address, fee: TransferFee) -> eyre::Result<()> {
+        self.cache
+            .transfer_fee(false, |handle| handle.insert(token, Some(fee)));
+
+        Ok(self
+            .tx
+            .send(WriterMessage::TransferFee { token, fee }.stamp())?)
+    }
+
+    async fn write_cex_data(
+        &self,
+        block_number: u64,
+        quotes: CexPriceMap,
+        trades: CexTradeMap,
+    ) -> eyre::Result<()> {
+        Ok(self
+            .tx
+            .send(WriterMessage::CexData { block_number, quotes, trades }.stamp())?)
+    }
+
     async fn insert_pool(
         &self,
         block: u64,
@@ -1040,6 +1169,12 @@ impl DBWriter for LibmdbxReadWriter {
                 token4: tokens_i.next().cloned(),
                 curve_lp_token,
             };
+            // an older mapping for the address doesn't replace the current one
+            if let Some(Some(current)) = handle.get(&address) {
+                if current.init_block > block {
+                    return
+                }
+            }
             handle.insert(address, Some(details.clone()));
         });
 
@@ -1056,6 +1191,10 @@ impl DBWriter for LibmdbxReadWriter {
     }
 
     async fn save_traces(&self, block: u64, traces: Vec<TxTrace>) -> eyre::Result<()> {
+        if !self.db.profile().stores(Tables::TxTraces) {
+            return Ok(())
+        }
+
         Ok(self
             .tx
             .send(WriterMessage::Traces { block, traces }.stamp())?)
@@ -1072,6 +1211,16 @@ impl DBWriter for LibmdbxReadWriter {
         )?)
     }
 
+    async fn write_builder_payments(
+        &self,
+        builder_address: Address,
+        revenue: BuilderRevenue,
+    ) -> eyre::Result<()> {
+        Ok(self.tx.send(
+            WriterMessage::BuilderPayments { builder_address, revenue: Box::new(revenue) }.stamp(),
+        )?)
+    }
+
     /// only for internal functionality (i.e. clickhouse)
     async fn insert_tree(&self, _tree: BlockTree<Action>) -> eyre::Result<()> {
         Ok(())
@@ -1296,12 +1445,15 @@ impl LibmdbxReadWriter {
     }
 
     pub fn get_highest_block_number(&self) -> eyre::Result<u64> {
-        self.db
-            .ro_tx()?
-            .cursor_read::<TxTraces>()?
-            .last()?
-            .map(|v| v.0)
-            .ok_or_else(|| eyre::eyre!("no max block found"))
+        let tx = self.db.ro_tx()?;
+        // lite databases don't keep traces, so fall back to the processed blocks
+        let highest = if self.db.profile().stores(Tables::TxTraces) {
+            tx.cursor_read::<TxTraces>()?.last()?.map(|v| v.0)
+        } else {
+            tx.cursor_read::<MevBlocks>()?.last()?.map(|v| v.0)
+        };
+
+        highest.ok_or_else(|| eyre::eyre!("no max block found"))
     }
 }
 
@@ -1322,6 +1474,27 @@ pub fn determine_eth_prices(
     )
 }
 
+/// the mapping of a pool that was created at the given block. If the address
+/// has since been re-used, the replaced mapping is returned instead
+fn protocol_info_at(
+    tx: &CompressedLibmdbxTx<RO>,
+    address: Address,
+    block: u64,
+) -> eyre::Result<Option<ProtocolInfo>> {
+    let current = tx.get::<AddressToProtocolInfo>(address)?;
+    if current
+        .as_ref()
+        .is_some_and(|info| info.init_block <= block)
+    {
+        return Ok(current)
+    }
+
+    Ok(tx
+        .get::<AddressToProtocolHistory>(address)?
+        .and_then(|history| history.at_block(block).cloned())
+        .or(current))
+}
+
 fn default_tables_to_init() -> Vec<Tables> {
     vec![Tables::BlockInfo, Tables::DexPrice, Tables::CexPrice, Tables::CexTrades]
 }
@@ -1334,3 +1507,73 @@ pub fn tables_to_initialize(data: InitializedStateMeta) -> Vec<(Tables, bool)> {
         (Tables::CexTrades, data.is_initialized(CEX_TRADES_FLAG)),
     ]
 }
+
+#[cfg(test)]
+mod tests {
+    use brontes_types::db::address_to_protocol_info::ProtocolInfoSnapshot;
+
+    use super::*;
+
+    #[test]
+    fn test_protocol_details_for_remapped_address() {
+        let dir = tempfile::tempdir().unwrap();
+        let db = LibmdbxReadWriter::init_db_tests(dir.path()).unwrap();
+
+        let address = Address::with_last_byte(1);
+        let old = ProtocolInfo {
+            protocol: Protocol::UniswapV2,
+            token0: Address::with_last_byte(3),
+            token1: Address::with_last_byte(2),
+            init_block: 100,
+            ..Default::default()
+        };
+        let current = ProtocolInfo {
+            protocol: Protocol::UniswapV3,
+            token0: Address::with_last_byte(4),
+            token1: Address::with_last_byte(5),
+            init_block: 200,
+            ..Default::default()
+        };
+
+        db.db
+            .write_table::<AddressToProtocolInfo, AddressToProtocolInfoData>(&[
+                AddressToProtocolInfoData::new(address, current.clone()),
+            ])
+            .unwrap();
+        db.db
+            .write_table::<AddressToProtocolHistory, AddressToProtocolHistoryData>(&[
+                AddressToProtocolHistoryData::new(
+                    address,
+                    ProtocolInfoHistory(vec![ProtocolInfoSnapshot {
+                        end_block: 200,
+                        info:      old.clone(),
+                    }]),
+                ),
+            ])
+            .unwrap();
+
+        assert_eq!(db.get_protocol_details_at(address, 150).unwrap(), old);
+        assert_eq!(db.get_protocol_details_at(address, 250).unwrap(), current);
+        // before any known mapping the current one is used
+        assert_eq!(db.get_protocol_details_at(address, 50).unwrap(), current);
+        assert_eq!(
+            db.get_protocol_details_sorted_at(address, 150)
+                .unwrap()
+                .token0,
+            Address::with_last_byte(2)
+        );
+
+        for (block, expected) in [(150, &old), (250, &current), (50, &current)] {
+            let info = db
+                .db
+                .view_db(|tx| protocol_info_at(tx, address, block))
+                .unwrap();
+            assert_eq!(info.as_ref(), Some(expected));
+        }
+        assert!(db
+            .db
+            .view_db(|tx| protocol_info_at(tx, Address::with_last_byte(9), 150))
+            .unwrap()
+            .is_none());
+    }
+}
@@ -10,14 +10,17 @@ use brontes_metrics::db_writer::WriterMetrics;
 use brontes_types::{
     db::{
         address_metadata::AddressMetadata,
-        address_to_protocol_info::ProtocolInfo,
-        builder::BuilderInfo,
+        address_to_protocol_info::{ProtocolInfo, ProtocolInfoSnapshot},
+        builder::{BuilderInfo, BuilderRevenue},
+        cex::{quotes::CexPriceMap, trades::CexTradeMap},
         dex::{make_key, DexQuoteWithIndex, DexQuotes},
-        initialized_state::{DATA_PRESENT, DEX_PRICE_FLAG, TRACE_FLAG},
+        initialized_state::{
+            CEX_QUOTES_FLAG, CEX_TRADES_FLAG, DATA_PRESENT, DEX_PRICE_FLAG, TRACE_FLAG,
+        },
         mev_block::MevBlockWithClassified,
         pool_creation_block::PoolsToAddresses,
         searcher::SearcherInfo,
-        token_info::TokenInfo,
+        token_info::{TokenInfo, TransferFee},
         traces::TxTracesInner,
     },
     mev::{Bundle, MevBlock},
@@ -59,11 +62,30 @@ pub enum WriterMessage {
         decimals: u8,
         symbol:   String,
     },
+    InferredTokenInfo {
+        address:  Address,
+        decimals: u8,
+        symbol:   String,
+    },
+    TransferFee {
+        token: Address,
+        fee:   TransferFee,
+    },
+    CexData {
+        block_number: u64,
+        quotes:       CexPriceMap,
+        trades:       CexTradeMap,
+    },
     MevBlocks {
         block_number: u64,
         block:        Box<MevBlock>,
         mev:          Vec<Bundle>,
     },
+    MevBlocksStaging {
+        block_number: u64,
+        block:        Box<MevBlock>,
+        mev:          Vec<Bundle>,
+    },
     SearcherInfo {
         eoa_address:      Address,
         contract_address: Option<Address>,
@@ -82,6 +104,10 @@ pub enum WriterMessage {
         builder_address: Address,
         builder_info:    Box<BuilderInfo>,
     },
+    BuilderPayments {
+        builder_address: Address,
+        revenue:         Box<BuilderRevenue>,
+    },
     AddressMeta {
         address:  Address,
         metadata: Box<AddressMetadata>,
@@ -202,7 +228,9 @@ macro_rules! init {
 }
 init!(
     TokenDecimals,
+    InferredTokenDecimals,
     AddressToProtocolInfo,
+    AddressToProtocolHistory,
     PoolCreationBlocks,
     Builder,
     AddressMeta,
@@ -212,6 +240,7 @@ init!(
     CexTrades,
     DexPrice,
     MevBlocks,
+    MevBlocksStaging,
     SearcherEOAs,
     SearcherContracts,
     InitializedState
@@ -254,14 +283,34 @@ impl LibmdbxWriter {
                 self.write_token_info(address, decimals, symbol)?;
                 "tokeninfo"
             }
+            WriterMessage::InferredTokenInfo { address, decimals, symbol } => {
+                self.write_inferred_token_info(address, decimals, symbol)?;
+                "inferredtokeninfo"
+            }
+            WriterMessage::TransferFee { token, fee } => {
+                self.write_transfer_fee(token, fee)?;
+                "transferfee"
+            }
+            WriterMessage::CexData { block_number, quotes, trades } => {
+                self.write_cex_data(block_number, quotes, trades)?;
+                "cexdata"
+            }
             WriterMessage::MevBlocks { block_number, block, mev } => {
                 self.save_mev_blocks(block_number, *block, mev)?;
                 "mevblocks"
             }
+            WriterMessage::MevBlocksStaging { block_number, block, mev } => {
+                self.save_mev_blocks_staging(block_number, *block, mev)?;
+                "mevblocksstaging"
+            }
             WriterMessage::BuilderInfo { builder_address, builder_info } => {
                 self.write_builder_info(builder_address, *builder_info)?;
                 "builderinfo"
             }
+            WriterMessage::BuilderPayments { builder_address, revenue } => {
+                self.write_builder_payments(builder_address, *revenue)?;
+                "builderpayments"
+            }
             WriterMessage::AddressMeta { address, metadata } => {
                 self.write_address_meta(address, *metadata)?;
                 "addressmeta"
@@ -422,10 +471,35 @@ impl LibmdbxWriter {
         Ok(())
     }
 
+    #[instrument(target = "libmdbx_read_write::save_mev_blocks_staging", skip_all, level = "warn")]
+    fn save_mev_blocks_staging(
+        &mut self,
+        block_number: u64,
+        block: MevBlock,
+        mev: Vec<Bundle>,
+    ) -> eyre::Result<()> {
+        let data = MevBlocksStagingData::new(block_number, MevBlockWithClassified { block, mev })
+            .into_key_val();
+        let (key, value) = Self::convert_into_save_bytes(data);
+
+        let entry = self
+            .insert_queue
+            .entry(Tables::MevBlocksStaging)
+            .or_default();
+        entry.push((key.to_vec(), value));
+
+        if entry.len() > CLEAR_AM {
+            let data = std::mem::take(entry);
+            self.insert_batched_data::<MevBlocksStaging>(data)?;
+        }
+
+        Ok(())
+    }
+
     #[instrument(target = "libmdbx_read_write::write_dex_quotes", skip_all, level = "warn")]
     fn write_dex_quotes(&mut self, block_num: u64, quotes: Option<DexQuotes>) -> eyre::Result<()> {
         if let Some(quotes) = quotes {
-            self.init_state_updating(block_num, DEX_PRICE_FLAG)
+            self.init_state_updating(block_num, &[DEX_PRICE_FLAG])
                 .expect("libmdbx write failure");
 
             let entry = self.insert_queue.entry(Tables::DexPrice).or_default();
@@ -467,6 +541,82 @@ impl LibmdbxWriter {
         Ok(())
     }
 
+    #[instrument(
+        target = "libmdbx_read_write::write_inferred_token_info",
+        skip_all,
+        level = "warn"
+    )]
+    fn write_inferred_token_info(
+        &self,
+        address: Address,
+        decimals: u8,
+        symbol: String,
+    ) -> eyre::Result<()> {
+        self.write_token_info(address, decimals, symbol.clone())?;
+        self.instrumented_write::<InferredTokenDecimals, InferredTokenDecimalsData>(&[
+            InferredTokenDecimalsData::new(address, TokenInfo::new(decimals, symbol)),
+        ])
+        .expect("libmdbx write failure");
+        Ok(())
+    }
+
+    #[instrument(target = "libmdbx_read_write::write_transfer_fee", skip_all, level = "warn")]
+    fn write_transfer_fee(&self, token: Address, fee: TransferFee) -> eyre::Result<()> {
+        self.instrumented_write::<TransferFees, TransferFeesData>(&[TransferFeesData::new(
+            token, fee,
+        )])
+        .expect("libmdbx write failure");
+        Ok(())
+    }
+
+    #[instrument(target = "libmdbx_read_write::write_cex_data", skip_all, level = "warn")]
+    fn write_cex_data(
+        &mut self,
+        block_num: u64,
+        quotes: CexPriceMap,
+        trades: CexTradeMap,
+    ) -> eyre::Result<()> {
+        self.instrumented_write::<CexPrice, CexPriceData>(&[CexPriceData::new(block_num, quotes)])
+            .expect("libmdbx write failure");
+        self.instrumented_write::<CexTrades, CexTradesData>(&[CexTradesData::new(
+            block_num, trades,
+        )])
+        .expect("libmdbx write failure");
+
+        self.init_state_updating(block_num, &[CEX_QUOTES_FLAG, CEX_TRADES_FLAG])
+            .expect("libmdbx write failure");
+        Ok(())
+    }
+
+    fn write_protocol_info(&self, address: Address, details: ProtocolInfo) {
+        self.instrumented_write::<AddressToProtocolInfo, AddressToProtocolInfoData>(&[
+            AddressToProtocolInfoData::new(address, details),
+        ])
+        .expect("libmdbx write failure");
+    }
+
+    fn insert_protocol_snapshot(
+        &self,
+        address: Address,
+        end_block: u64,
+        info: ProtocolInfo,
+    ) -> eyre::Result<()> {
+        self.db.view_db(|tx| {
+            let mut history = tx
+                .get::<AddressToProtocolHistory>(address)
+                .expect("libmdbx write failure")
+                .unwrap_or_default();
+
+            history.insert(ProtocolInfoSnapshot { end_block, info });
+            self.instrumented_write::<AddressToProtocolHistory, AddressToProtocolHistoryData>(&[
+                AddressToProtocolHistoryData::new(address, history),
+            ])
+            .expect("libmdbx write failure");
+
+            Ok(())
+        })
+    }
+
     #[instrument(target = "libmdbx_read_write::insert_pool", skip_all, level = "warn")]
     fn insert_pool(
         &self,
@@ -476,25 +626,40 @@ impl LibmdbxWriter {
         curve_lp_token: Option<Address>,
         classifier_name: Protocol,
     ) -> eyre::Result<()> {
-        // add to default table
         let mut tokens = tokens.iter();
         let default = Address::ZERO;
-        self.instrumented_write::<AddressToProtocolInfo, AddressToProtocolInfoData>(&[
-            AddressToProtocolInfoData::new(
-                address,
-                ProtocolInfo {
-                    protocol: classifier_name,
-                    init_block: block,
-                    token0: *tokens.next().unwrap_or(&default),
-                    token1: *tokens.next().unwrap_or(&default),
-                    token2: tokens.next().cloned(),
-                    token3: tokens.next().cloned(),
-                    token4: tokens.next().cloned(),
-                    curve_lp_token,
-                },
-            ),
-        ])
-        .expect("libmdbx write failure");
+        let details = ProtocolInfo {
+            protocol: classifier_name,
+            init_block: block,
+            token0: *tokens.next().unwrap_or(&default),
+            token1: *tokens.next().unwrap_or(&default),
+            token2: tokens.next().cloned(),
+            token3: tokens.next().cloned(),
+            token4: tokens.next().cloned(),
+            curve_lp_token,
+        };
+
+        let current = self
+            .db
+            .view_db(|tx| Ok(tx.get::<AddressToProtocolInfo>(address)?))?;
+
+        match current {
+            // the address was re-used or migrated. The newest mapping stays in the default
+            // table and the older one is kept around for the blocks it was valid for
+            Some(current)
+                if current.init_block != block
+                    && (current.protocol != details.protocol
+                        || current.get_tokens() != details.get_tokens()) =>
+            {
+                if current.init_block < block {
+                    self.insert_protocol_snapshot(address, block, current)?;
+                    self.write_protocol_info(address, details);
+                } else {
+                    self.insert_protocol_snapshot(address, current.init_block, details)?;
+                }
+            }
+            _ => self.write_protocol_info(address, details),
+        }
 
         // add to pool creation block
         self.db.view_db(|tx| {
@@ -527,7 +692,7 @@ impl LibmdbxWriter {
             let data = std::mem::take(entry);
             self.insert_batched_data::<TxTraces>(data)?;
         }
-        self.init_state_updating(block, TRACE_FLAG)
+        self.init_state_updating(block, &[TRACE_FLAG])
     }
 
     #[instrument(target = "libmdbx_read_write::write_builder_info", skip_all, level = "warn")]
@@ -542,11 +707,25 @@ impl LibmdbxWriter {
         Ok(())
     }
 
+    #[instrument(target = "libmdbx_read_write::write_builder_payments", skip_all, level = "warn")]
+    fn write_builder_payments(
+        &self,
+        builder_address: Address,
+        revenue: BuilderRevenue,
+    ) -> eyre::Result<()> {
+        let data = BuilderPaymentsData::new(builder_address, revenue);
+        self.instrumented_write::<BuilderPayments, BuilderPaymentsData>(&[data])
+            .expect("libmdbx write failure");
+        Ok(())
+    }
+
     #[instrument(target = "libmdbx_read_write::init_state_updating", skip_all, level = "warn")]
-    fn init_state_updating(&mut self, block: u64, flag: u16) -> eyre::Result<()> {
+    fn init_state_updating(&mut self, block: u64, flags: &[u16]) -> eyre::Result<()> {
         let tx = self.db.ro_tx()?;
         let mut state = tx.get::<InitializedState>(block)?.unwrap_or_default();
-        state.set(flag, DATA_PRESENT);
+        for flag in flags {
+            state.set(*flag, DATA_PRESENT);
+        }
         let data = InitializedStateData::new(block, state).into_key_val();
 
         let (key, value) = Self::convert_into_save_bytes(data);
@@ -645,6 +824,10 @@ impl LibmdbxWriter {
                     Tables::MevBlocks => {
                         self.insert_batched_data::<MevBlocks>(values).unwrap();
                     }
+                    Tables::MevBlocksStaging => {
+                        self.insert_batched_data::<MevBlocksStaging>(values)
+                            .unwrap();
+                    }
                     Tables::TxTraces => {
                         self.insert_batched_data::<TxTraces>(values).unwrap();
                     }
@@ -18,6 +18,7 @@ pub mod libmdbx_writer;
 
 pub mod initialize;
 mod libmdbx_read_write;
+mod profile;
 use brontes_libmdbx::{RO, RW};
 use env::{DatabaseArguments, DatabaseEnv, DatabaseEnvKind};
 use eyre::Context;
@@ -26,6 +27,7 @@ use initialize::LibmdbxInitializer;
 pub use libmdbx_read_write::{
     determine_eth_prices, LibmdbxInit, LibmdbxReadWriter, StateToInitialize,
 };
+pub use profile::DbProfile;
 use reth_db::{
     is_database_empty,
     models::client_version::ClientVersion,
@@ -45,6 +47,7 @@ use self::{
 pub mod implementation;
 pub use implementation::compressed_wrappers::*;
 pub mod tables;
+pub mod tree_file;
 pub mod types;
 pub mod utils;
 
@@ -54,7 +57,7 @@ pub mod test_utils;
 const GIGABYTE: u64 = 1024 * 1024 * 1024;
 
 #[derive(Debug)]
-pub struct Libmdbx(DatabaseEnv);
+pub struct Libmdbx(DatabaseEnv, DbProfile);
 
 #[inline]
 pub(crate) fn mdbx_result(err_code: c_int) -> eyre::Result<bool> {
@@ -67,9 +70,11 @@ pub(crate) fn mdbx_result(err_code: c_int) -> eyre::Result<bool> {
 
 impl Libmdbx {
     /// Opens up an existing database or creates a new one at the specified
-    /// path. Creates tables if necessary. Opens in read/write mode.
+    /// path. Creates the tables of the databases profile if necessary. Opens
+    /// in read/write mode.
     pub fn init_db<P: AsRef<Path>>(path: P, log_level: Option<LogLevel>) -> eyre::Result<Self> {
         let rpath = path.as_ref();
+        let profile = DbProfile::read(rpath)?;
         if is_database_empty(rpath) {
             std::fs::create_dir_all(rpath).wrap_err_with(|| {
                 format!("Could not create database directory {}", rpath.display())
@@ -98,18 +103,31 @@ impl Libmdbx {
             ))
         })?;
 
-        let this = Self(db);
+        let this = Self(db, profile);
         this.create_tables()?;
 
         Ok(this)
     }
 
-    /// Creates all the defined tables, opens if already created
-    fn create_tables(&self) -> Result<(), DatabaseError> {
+    pub fn profile(&self) -> DbProfile {
+        self.1
+    }
+
+    /// Creates all the tables of the profile, opens if already created. Fails
+    /// if the database has tables that the profile shouldn't
+    fn create_tables(&self) -> eyre::Result<()> {
         let tx = CompressedLibmdbxTx::new_rw_tx(&self.0)?;
 
         for table in Tables::ALL {
-            tx.0.create_table(&table)?;
+            if self.1.stores(table) {
+                tx.0.create_table(&table)?;
+            } else if tx.0.has_table(&table) {
+                eyre::bail!(
+                    "{} database has a {} table, it was likely opened without its profile",
+                    self.1,
+                    table
+                );
+            }
         }
 
         tx.commit()?;
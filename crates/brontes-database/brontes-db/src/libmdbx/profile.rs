@@ -0,0 +1,157 @@
+//! The table set a database is created with. A lite database doesn't keep the
+//! raw traces, only the classified results, which is all most users need and
+//! is an order of magnitude smaller on disk. The profile is chosen when the
+//! database is created and written next to it, every later open is checked
+//! against it.
+use std::{
+    fmt::{self, Display},
+    fs,
+    path::Path,
+    str::FromStr,
+};
+
+use crate::Tables;
+
+const PROFILE_FILE_NAME: &str = "brontes-profile";
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DbProfile {
+    #[default]
+    Full,
+    Lite,
+}
+
+impl DbProfile {
+    /// Whether databases with this profile contain the table
+    pub fn stores(&self, table: Tables) -> bool {
+        match self {
+            DbProfile::Full => true,
+            DbProfile::Lite => !matches!(table, Tables::TxTraces),
+        }
+    }
+
+    pub fn tables(&self) -> Vec<Tables> {
+        Tables::ALL
+            .into_iter()
+            .filter(|table| self.stores(*table))
+            .collect()
+    }
+
+    /// The profile the database at the path was created with. Databases from
+    /// before profiles existed are full
+    pub fn read(path: &Path) -> eyre::Result<Self> {
+        let file = path.join(PROFILE_FILE_NAME);
+        if !file.exists() {
+            return Ok(DbProfile::Full)
+        }
+
+        fs::read_to_string(file)?.trim().parse()
+    }
+
+    /// Sets the profile for a database that is yet to be created. Fails if a
+    /// database with another profile already exists at the path
+    pub fn select(self, path: &Path) -> eyre::Result<()> {
+        let file = path.join(PROFILE_FILE_NAME);
+        if file.exists() || !reth_db::is_database_empty(path) {
+            let current = Self::read(path)?;
+            if current != self {
+                eyre::bail!(
+                    "database at {} was created with the {} profile, can't switch it to {}",
+                    path.display(),
+                    current,
+                    self
+                );
+            }
+            return Ok(())
+        }
+
+        fs::create_dir_all(path)?;
+        fs::write(file, self.to_string())?;
+
+        Ok(())
+    }
+}
+
+impl Display for DbProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DbProfile::Full => write!(f, "full"),
+            DbProfile::Lite => write!(f, "lite"),
+        }
+    }
+}
+
+impl FromStr for DbProfile {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "full" => Ok(DbProfile::Full),
+            "lite" => Ok(DbProfile::Lite),
+            _ => Err(eyre::eyre!("unknown database profile: {}", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::libmdbx::Libmdbx;
+
+    #[test]
+    fn test_lite_skips_traces() {
+        assert!(!DbProfile::Lite.tables().contains(&Tables::TxTraces));
+        assert!(DbProfile::Lite.tables().contains(&Tables::MevBlocks));
+        assert_eq!(DbProfile::Full.tables().len(), Tables::ALL.len());
+    }
+
+    #[test]
+    fn test_profile_round_trip() {
+        for profile in [DbProfile::Full, DbProfile::Lite] {
+            assert_eq!(profile.to_string().parse::<DbProfile>().unwrap(), profile);
+        }
+        assert!("tiny".parse::<DbProfile>().is_err());
+    }
+
+    #[test]
+    fn test_read_profile() {
+        let dir = tempfile::tempdir().unwrap();
+        // databases without a profile file are full
+        assert_eq!(DbProfile::read(dir.path()).unwrap(), DbProfile::Full);
+
+        fs::write(dir.path().join(PROFILE_FILE_NAME), "lite\n").unwrap();
+        assert_eq!(DbProfile::read(dir.path()).unwrap(), DbProfile::Lite);
+
+        fs::write(dir.path().join(PROFILE_FILE_NAME), "tiny").unwrap();
+        assert!(DbProfile::read(dir.path()).is_err());
+    }
+
+    #[test]
+    fn test_select_profile() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("db");
+
+        DbProfile::Lite.select(&path).unwrap();
+        assert_eq!(DbProfile::read(&path).unwrap(), DbProfile::Lite);
+
+        // selecting the same profile again is fine, switching isn't
+        DbProfile::Lite.select(&path).unwrap();
+        assert!(DbProfile::Full.select(&path).is_err());
+        assert_eq!(DbProfile::read(&path).unwrap(), DbProfile::Lite);
+    }
+
+    #[test]
+    fn test_lite_rejects_trace_table() {
+        let dir = tempfile::tempdir().unwrap();
+
+        // a full database has the trace table, opening it as lite has to fail
+        drop(Libmdbx::init_db(dir.path(), None).unwrap());
+        fs::write(dir.path().join(PROFILE_FILE_NAME), DbProfile::Lite.to_string()).unwrap();
+        assert!(Libmdbx::init_db(dir.path(), None).is_err());
+
+        let lite = tempfile::tempdir().unwrap();
+        DbProfile::Lite.select(lite.path()).unwrap();
+        let db = Libmdbx::init_db(lite.path(), None).unwrap();
+        assert_eq!(db.profile(), DbProfile::Lite);
+    }
+}
@@ -9,13 +9,19 @@ use std::{
 use brontes_types::{
     db::{
         address_metadata::{AddressMetadata, AddressMetadataRedefined},
-        address_to_protocol_info::{ProtocolInfo, ProtocolInfoRedefined},
-        builder::{BuilderInfo, BuilderInfoRedefined},
+        address_to_protocol_info::{
+            ProtocolInfo, ProtocolInfoHistory, ProtocolInfoHistoryRedefined, ProtocolInfoRedefined,
+        },
+        arb_index::{PairArbSummary, PairArbSummaryRedefined},
+        audit::AuditEntry,
+        builder::{BuilderInfo, BuilderInfoRedefined, BuilderRevenue, BuilderRevenueRedefined},
         cex::{
             quotes::{CexPriceMap, CexPriceMapRedefined},
             trades::{CexTradeMap, CexTradeMapRedefined},
+            CexTokenListings, CexTokenListingsRedefined,
         },
         clickhouse_serde::tx_trace::tx_traces_inner,
+        commitment::{BlockCommitment, BlockCommitmentRedefined},
         dex::{DexKey, DexQuoteWithIndex, DexQuoteWithIndexRedefined},
         initialized_state::{
             InitializedStateMeta, CEX_QUOTES_FLAG, CEX_TRADES_FLAG, DEX_PRICE_FLAG, META_FLAG,
@@ -25,7 +31,7 @@ use brontes_types::{
         mev_block::{MevBlockWithClassified, MevBlockWithClassifiedRedefined},
         pool_creation_block::{PoolsToAddresses, PoolsToAddressesRedefined},
         searcher::{SearcherInfo, SearcherInfoRedefined},
-        token_info::TokenInfo,
+        token_info::{TokenInfo, TransferFee, TransferFeeRedefined},
         traces::{TxTracesInner, TxTracesInnerRedefined},
         traits::LibmdbxReader,
     },
@@ -58,7 +64,7 @@ use super::{
     CompressedTable,
 };
 
-pub const NUM_TABLES: usize = 14;
+pub const NUM_TABLES: usize = 23;
 
 macro_rules! tables {
     ($($table:ident),*) => {
@@ -252,7 +258,14 @@ impl Tables {
                     )
                     .await
             }
-            Tables::MevBlocks => Ok(()),
+            Tables::MevBlocks
+            | Tables::MevBlocksStaging
+            | Tables::AuditLog
+            | Tables::PairArbIndex
+            | Tables::BlockCommitments
+            | Tables::CexListings
+            | Tables::TransferFees
+            | Tables::BuilderPayments => Ok(()),
             Tables::TxTraces => {
                 initializer
                     .initialize_table_from_clickhouse::<TxTraces, TxTracesData>(
@@ -276,7 +289,11 @@ impl Tables {
                     )
                     .await
             }
-            Tables::SearcherEOAs | Tables::SearcherContracts | Tables::InitializedState => Ok(()),
+            Tables::SearcherEOAs
+            | Tables::SearcherContracts
+            | Tables::InitializedState
+            | Tables::InferredTokenDecimals
+            | Tables::AddressToProtocolHistory => Ok(()),
             _ => unimplemented!("'initialize_table' not implemented for {:?}", self),
         }
     }
@@ -373,6 +390,8 @@ impl Tables {
             Self::MevBlocks => exporter.export_mev_blocks().await,
             Self::SearcherContracts | Self::SearcherEOAs => exporter.export_searcher_info().await,
             Self::Builder => exporter.export_builder_info().await,
+            Self::PairArbIndex => exporter.export_pair_arb_index().await,
+            Self::BlockCommitments => exporter.export_block_commitments().await,
             _ => unreachable!("Parquet export not yet supported for this table"),
         }
     }
@@ -525,19 +544,28 @@ impl Tables {
 
 tables!(
     TokenDecimals,
+    InferredTokenDecimals,
     AddressToProtocolInfo,
+    AddressToProtocolHistory,
     CexPrice,
     BlockInfo,
     DexPrice,
     PoolCreationBlocks,
     MevBlocks,
+    MevBlocksStaging,
     TxTraces,
     Builder,
     AddressMeta,
     SearcherEOAs,
     SearcherContracts,
     InitializedState,
-    CexTrades
+    CexTrades,
+    AuditLog,
+    PairArbIndex,
+    BlockCommitments,
+    CexListings,
+    TransferFees,
+    BuilderPayments
 );
 
 /// Must be in this order when defining
@@ -955,6 +983,28 @@ compressed_table!(
     }
 );
 
+// mappings that were replaced in `AddressToProtocolInfo` because the address
+// was migrated or re-used by a different protocol
+compressed_table!(
+    Table AddressToProtocolHistory {
+        #[serde_as]
+        Data {
+            #[serde(with = "address_string")]
+            key: Address,
+            value: ProtocolInfoHistory,
+            compressed_value: ProtocolInfoHistoryRedefined
+        },
+        Init {
+            init_size: None,
+            init_method: Other,
+            http_endpoint: None
+        },
+        CLI {
+            can_insert: False
+        }
+    }
+);
+
 compressed_table!(
     Table TokenDecimals {
         #[serde_as]
@@ -975,6 +1025,27 @@ compressed_table!(
     }
 );
 
+// tokens whose `decimals()` call failed. The decimals here were inferred from
+// the observed transfer amounts and pool pairings rather than read on chain
+compressed_table!(
+    Table InferredTokenDecimals {
+        #[serde_as]
+        Data {
+            #[serde(with = "address_string")]
+            key: Address,
+            value: TokenInfo
+        },
+        Init {
+            init_size: None,
+            init_method: Other,
+            http_endpoint: None
+        },
+        CLI {
+            can_insert: False
+        }
+    }
+);
+
 compressed_table!(
     Table MevBlocks {
         Data {
@@ -993,6 +1064,26 @@ compressed_table!(
     }
 );
 
+// results of single inspector re-runs. kept apart from `MevBlocks` so they
+// can be compared against production before being promoted
+compressed_table!(
+    Table MevBlocksStaging {
+        Data {
+            key: u64,
+            value: MevBlockWithClassified,
+            compressed_value: MevBlockWithClassifiedRedefined
+        },
+        Init {
+            init_size: None,
+            init_method: Other,
+            http_endpoint: None
+        },
+        CLI {
+            can_insert: False
+        }
+    }
+);
+
 compressed_table!(
     Table PoolCreationBlocks {
         #[serde_as]
@@ -1014,6 +1105,129 @@ compressed_table!(
     }
 );
 
+// append only log of the state changing cli commands run against the db
+compressed_table!(
+    Table AuditLog {
+        Data {
+            key: u64,
+            value: AuditEntry,
+            compressed_value: AuditEntry
+        },
+        Init {
+            init_size: None,
+            init_method: Other,
+            http_endpoint: None
+        },
+        CLI {
+            can_insert: False
+        }
+    }
+);
+
+// hashes of the results of each block, built from the mev blocks by `analyze
+// commitments` so datasets can be compared without replaying blocks
+compressed_table!(
+    Table BlockCommitments {
+        Data {
+            key: u64,
+            value: BlockCommitment,
+            compressed_value: BlockCommitmentRedefined
+        },
+        Init {
+            init_size: None,
+            init_method: Other,
+            http_endpoint: None
+        },
+        CLI {
+            can_insert: False
+        }
+    }
+);
+
+// per pair arb counts and sizes over block ranges, built from the mev blocks by
+// `analyze arb-index`
+compressed_table!(
+    Table PairArbIndex {
+        Data {
+            key: u64,
+            value: PairArbSummary,
+            compressed_value: PairArbSummaryRedefined
+        },
+        Init {
+            init_size: None,
+            init_method: Other,
+            http_endpoint: None
+        },
+        CLI {
+            can_insert: False
+        }
+    }
+);
+
+// the venues listing each token, filled in by hand through `db insert` as no
+// source covers every venue
+compressed_table!(
+    Table CexListings {
+        Data {
+            #[serde(with = "address_string")]
+            key: Address,
+            value: CexTokenListings,
+            compressed_value: CexTokenListingsRedefined
+        },
+        Init {
+            init_size: None,
+            init_method: Other,
+            http_endpoint: None
+        },
+        CLI {
+            can_insert: True
+        }
+    }
+);
+
+// tokens that take a fee on their transfers, written by the classifier as it
+// sees transfers deliver less than was sent. Known ones can be added through
+// `db insert` ahead of time
+compressed_table!(
+    Table TransferFees {
+        Data {
+            #[serde(with = "address_string")]
+            key: Address,
+            value: TransferFee,
+            compressed_value: TransferFeeRedefined
+        },
+        Init {
+            init_size: None,
+            init_method: Other,
+            http_endpoint: None
+        },
+        CLI {
+            can_insert: True
+        }
+    }
+);
+
+// running totals of what searchers paid each builder, keyed by the builder's
+// address and updated with every block it builds
+compressed_table!(
+    Table BuilderPayments {
+        Data {
+            #[serde(with = "address_string")]
+            key: Address,
+            value: BuilderRevenue,
+            compressed_value: BuilderRevenueRedefined
+        },
+        Init {
+            init_size: None,
+            init_method: Other,
+            http_endpoint: None
+        },
+        CLI {
+            can_insert: False
+        }
+    }
+);
+
 compressed_table!(
     Table InitializedState {
         Data {
@@ -0,0 +1,133 @@
+//! File format for moving classified trees between databases. A tree is built
+//! from the traces of its block, so the file holds the traces in the encoding
+//! of the [`TxTraces`] table and the importing side only has to classify them
+//! again, without a node to trace the blocks.
+//!
+//! The classified [`BlockTree`](brontes_types::BlockTree) itself isn't
+//! written: most normalized actions have no stable (rkyv) encoding and no
+//! table holds trees to import them into. The importing db needs the protocol
+//! and token tables of the range for the trees to classify the same way.
+//!
+//! The file is a magic and a version, followed by a record for every block:
+//! the block number and the length of the encoded traces, both little endian
+//! u64s, then the encoded traces.
+use std::io::{self, ErrorKind, Read, Write};
+
+use brontes_types::db::traces::TxTracesInner;
+use reth_db::table::{Compress, Decompress, Table};
+
+use super::tables::TxTraces;
+
+const MAGIC: &[u8; 8] = b"BRNTREES";
+const VERSION: u16 = 2;
+
+type EncodedTraces = <TxTraces as Table>::Value;
+
+pub struct TreeFileWriter<W: Write> {
+    out:    W,
+    blocks: u64,
+}
+
+impl<W: Write> TreeFileWriter<W> {
+    pub fn new(mut out: W) -> io::Result<Self> {
+        out.write_all(MAGIC)?;
+        out.write_all(&VERSION.to_le_bytes())?;
+
+        Ok(Self { out, blocks: 0 })
+    }
+
+    pub fn write_block(&mut self, block: u64, traces: TxTracesInner) -> io::Result<()> {
+        let encoded = EncodedTraces::from(traces).compress();
+
+        self.out.write_all(&block.to_le_bytes())?;
+        self.out.write_all(&(encoded.len() as u64).to_le_bytes())?;
+        self.out.write_all(&encoded)?;
+        self.blocks += 1;
+
+        Ok(())
+    }
+
+    /// Flushes the file, returning the amount of blocks written
+    pub fn finish(mut self) -> io::Result<u64> {
+        self.out.flush()?;
+        Ok(self.blocks)
+    }
+}
+
+pub struct TreeFileReader<R: Read> {
+    input: R,
+}
+
+impl<R: Read> TreeFileReader<R> {
+    pub fn new(mut input: R) -> eyre::Result<Self> {
+        let mut magic = [0u8; 8];
+        input.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            eyre::bail!("not a brontes tree file")
+        }
+
+        let mut version = [0u8; 2];
+        input.read_exact(&mut version)?;
+        let version = u16::from_le_bytes(version);
+        if version != VERSION {
+            eyre::bail!("unsupported tree file version {}, expected {}", version, VERSION)
+        }
+
+        Ok(Self { input })
+    }
+
+    /// Reads the next block, `None` once the file is exhausted
+    pub fn read_block(&mut self) -> eyre::Result<Option<(u64, TxTracesInner)>> {
+        let mut block = [0u8; 8];
+        match self.input.read_exact(&mut block) {
+            Ok(()) => {}
+            Err(e) if e.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+            Err(e) => return Err(e.into()),
+        }
+
+        let mut len = [0u8; 8];
+        self.input.read_exact(&mut len)?;
+        let mut encoded = vec![0u8; u64::from_le_bytes(len) as usize];
+        self.input.read_exact(&mut encoded)?;
+
+        let traces = EncodedTraces::decompress(encoded)?.into();
+
+        Ok(Some((u64::from_le_bytes(block), traces)))
+    }
+}
+
+impl<R: Read> Iterator for TreeFileReader<R> {
+    type Item = eyre::Result<(u64, TxTracesInner)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_block().transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tree_file_round_trip() {
+        let mut writer = TreeFileWriter::new(Vec::new()).unwrap();
+        writer
+            .write_block(1, TxTracesInner::new(Some(vec![])))
+            .unwrap();
+        writer.write_block(2, TxTracesInner::new(None)).unwrap();
+        let blocks = writer.blocks;
+        let file = writer.out;
+        assert_eq!(blocks, 2);
+
+        let read = TreeFileReader::new(file.as_slice())
+            .unwrap()
+            .collect::<eyre::Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(
+            read,
+            vec![(1, TxTracesInner::new(Some(vec![]))), (2, TxTracesInner::new(None))]
+        );
+
+        assert!(TreeFileReader::new(&b"not a tree file"[..]).is_err());
+    }
+}
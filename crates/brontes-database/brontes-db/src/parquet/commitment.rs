@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use arrow::{
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use brontes_types::db::commitment::BlockCommitment;
+
+use super::utils::{build_record_batch, build_string_array, build_uint64_array};
+
+/// One row per block
+pub fn block_commitments_to_record_batch(
+    commitments: Vec<(u64, BlockCommitment)>,
+) -> Result<RecordBatch, ArrowError> {
+    let hashes = |f: fn(&BlockCommitment) -> String| {
+        Arc::new(build_string_array(commitments.iter().map(|(_, c)| f(c)).collect()))
+    };
+
+    let schema = Schema::new(vec![
+        Field::new("block_number", DataType::UInt64, false),
+        Field::new("root", DataType::Utf8, false),
+        Field::new("summary_hash", DataType::Utf8, false),
+        Field::new("bundles_root", DataType::Utf8, false),
+        Field::new("bundle_count", DataType::UInt64, false),
+    ]);
+
+    build_record_batch(
+        schema,
+        vec![
+            Arc::new(build_uint64_array(commitments.iter().map(|(block, _)| *block).collect())),
+            hashes(|c| c.root.to_string()),
+            hashes(|c| c.summary_hash.to_string()),
+            hashes(|c| c.bundles_root.to_string()),
+            Arc::new(build_uint64_array(commitments.iter().map(|(_, c)| c.bundle_count).collect())),
+        ],
+    )
+}
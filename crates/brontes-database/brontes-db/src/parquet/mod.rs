@@ -24,17 +24,21 @@ use crate::Tables;
 mod address_meta;
 mod builder;
 mod bundle_header;
+mod commitment;
 mod mev_block;
 mod mev_data;
 mod normalized_actions;
+mod pair_arb_index;
 mod searcher;
 pub mod utils;
 
 use address_meta::address_metadata_to_record_batch;
 use builder::builder_info_to_record_batch;
 use bundle_header::bundle_headers_to_record_batch;
+use commitment::block_commitments_to_record_batch;
 use mev_block::mev_block_to_record_batch;
 use mev_data::*;
+use pair_arb_index::pair_arb_index_to_record_batch;
 use searcher::searcher_info_to_record_batch;
 
 pub struct ParquetExporter<DB: LibmdbxReader> {
@@ -334,6 +338,54 @@ where
 
         Ok(())
     }
+
+    pub async fn export_pair_arb_index(&self) -> Result<(), Error> {
+        let index = self
+            .db
+            .fetch_pair_arb_index(self.start_block, self.end_block.unwrap_or(u64::MAX))
+            .wrap_err("Failed to query the pair arb index table")?;
+
+        if index.is_empty() {
+            error!("Pair arb index table is empty, run `brontes analyze arb-index` first.");
+            return Err(Error::msg("No pair arb index"))
+        }
+
+        let index_batch = pair_arb_index_to_record_batch(index)
+            .wrap_err("Failed to convert pair arb index to record batch")?;
+
+        write_parquet(
+            index_batch,
+            get_path(self.base_dir_path.clone(), Tables::PairArbIndex, None)?,
+        )
+        .await
+        .wrap_err("Failed to write pair arb index to parquet file")?;
+
+        Ok(())
+    }
+
+    pub async fn export_block_commitments(&self) -> Result<(), Error> {
+        let commitments = self
+            .db
+            .fetch_block_commitments(self.start_block, self.end_block.unwrap_or(u64::MAX))
+            .wrap_err("Failed to query the block commitments table")?;
+
+        if commitments.is_empty() {
+            error!("Block commitments table is empty, run `brontes analyze commitments` first.");
+            return Err(Error::msg("No block commitments"))
+        }
+
+        let commitments_batch = block_commitments_to_record_batch(commitments)
+            .wrap_err("Failed to convert block commitments to record batch")?;
+
+        write_parquet(
+            commitments_batch,
+            get_path(self.base_dir_path.clone(), Tables::BlockCommitments, None)?,
+        )
+        .await
+        .wrap_err("Failed to write block commitments to parquet file")?;
+
+        Ok(())
+    }
 }
 
 async fn write_parquet(record_batch: RecordBatch, file_path: PathBuf) -> Result<()> {
@@ -424,6 +476,8 @@ impl Tables {
             Tables::SearcherEOAs => DEFAULT_SEARCHER_INFO_DIR,
             Tables::SearcherContracts => DEFAULT_SEARCHER_INFO_DIR,
             Tables::Builder => DEFAULT_BUILDER_INFO_DIR,
+            Tables::PairArbIndex => DEFAULT_PAIR_ARB_INDEX_DIR,
+            Tables::BlockCommitments => DEFAULT_BLOCK_COMMITMENTS_DIR,
             _ => panic!("Unsupported table type"),
         }
     }
@@ -433,3 +487,5 @@ pub const DEFAULT_BLOCK_DIR: &str = "mev";
 pub const DEFAULT_METADATA_DIR: &str = "address_metadata";
 pub const DEFAULT_SEARCHER_INFO_DIR: &str = "searcher_info";
 pub const DEFAULT_BUILDER_INFO_DIR: &str = "builder-info";
+pub const DEFAULT_PAIR_ARB_INDEX_DIR: &str = "pair_arb_index";
+pub const DEFAULT_BLOCK_COMMITMENTS_DIR: &str = "block_commitments";
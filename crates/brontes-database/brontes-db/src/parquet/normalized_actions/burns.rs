@@ -52,7 +52,7 @@ pub fn get_normalized_burn_list_array(
             struct_builder
                 .field_builder::<StringBuilder>(0)
                 .unwrap()
-                .append_value(burn.protocol.to_string());
+                .append_value(burn.protocol.version().to_string());
 
             struct_builder
                 .field_builder::<UInt16Builder>(1)
@@ -45,7 +45,7 @@ pub fn get_normalized_liquidation_list_array(
             struct_builder
                 .field_builder::<StringBuilder>(0)
                 .unwrap()
-                .append_value(liquidation.protocol.to_string());
+                .append_value(liquidation.protocol.version().to_string());
             struct_builder
                 .field_builder::<UInt16Builder>(1)
                 .unwrap()
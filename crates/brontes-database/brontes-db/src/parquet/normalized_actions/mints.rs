@@ -27,7 +27,7 @@ pub fn get_normalized_mint_list_array(
             struct_builder
                 .field_builder::<StringBuilder>(0)
                 .unwrap()
-                .append_value(normalized_mint.protocol.to_string());
+                .append_value(normalized_mint.protocol.version().to_string());
 
             struct_builder
                 .field_builder::<UInt64Builder>(1)
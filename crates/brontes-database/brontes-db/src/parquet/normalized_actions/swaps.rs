@@ -21,7 +21,7 @@ pub fn get_normalized_swap_list_array(
             struct_builder
                 .field_builder::<StringBuilder>(0)
                 .unwrap()
-                .append_value(swap.protocol.to_string());
+                .append_value(swap.protocol.version().to_string());
 
             struct_builder
                 .field_builder::<UInt16Builder>(1)
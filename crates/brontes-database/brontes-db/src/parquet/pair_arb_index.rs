@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use arrow::{
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use brontes_types::db::arb_index::{PairArbStats, PairArbSummary};
+use itertools::Itertools;
+
+use super::utils::{
+    build_float64_array, build_record_batch, build_string_array, build_uint64_array,
+};
+
+/// One row per pair and block range
+pub fn pair_arb_index_to_record_batch(
+    index: Vec<(u64, PairArbSummary)>,
+) -> Result<RecordBatch, ArrowError> {
+    let rows = index
+        .iter()
+        .flat_map(|(start_block, range)| {
+            range
+                .pairs
+                .iter()
+                .map(move |stats| (*start_block, range.end_block, stats))
+        })
+        .collect_vec();
+
+    let column = |f: fn(&PairArbStats) -> f64| {
+        Arc::new(build_float64_array(rows.iter().map(|(_, _, stats)| f(stats)).collect()))
+    };
+
+    let schema = Schema::new(vec![
+        Field::new("start_block", DataType::UInt64, false),
+        Field::new("end_block", DataType::UInt64, false),
+        Field::new("token0", DataType::Utf8, false),
+        Field::new("token1", DataType::Utf8, false),
+        Field::new("atomic_count", DataType::UInt64, false),
+        Field::new("atomic_volume", DataType::Float64, false),
+        Field::new("atomic_profit_usd", DataType::Float64, false),
+        Field::new("cex_dex_count", DataType::UInt64, false),
+        Field::new("cex_dex_volume", DataType::Float64, false),
+        Field::new("cex_dex_profit_usd", DataType::Float64, false),
+    ]);
+
+    build_record_batch(
+        schema,
+        vec![
+            Arc::new(build_uint64_array(rows.iter().map(|(start, ..)| *start).collect())),
+            Arc::new(build_uint64_array(rows.iter().map(|(_, end, _)| *end).collect())),
+            Arc::new(build_string_array(
+                rows.iter()
+                    .map(|(_, _, stats)| stats.pair.0.to_string())
+                    .collect(),
+            )),
+            Arc::new(build_string_array(
+                rows.iter()
+                    .map(|(_, _, stats)| stats.pair.1.to_string())
+                    .collect(),
+            )),
+            Arc::new(build_uint64_array(
+                rows.iter()
+                    .map(|(_, _, stats)| stats.atomic.count)
+                    .collect(),
+            )),
+            column(|stats| stats.atomic.volume),
+            column(|stats| stats.atomic.profit_usd),
+            Arc::new(build_uint64_array(
+                rows.iter()
+                    .map(|(_, _, stats)| stats.cex_dex.count)
+                    .collect(),
+            )),
+            column(|stats| stats.cex_dex.volume),
+            column(|stats| stats.cex_dex.profit_usd),
+        ],
+    )
+}
@@ -0,0 +1,522 @@
+//! Historical cex quotes and trades from Tardis.dev, for backfilling the
+//! `CexPrice` and `CexTrades` tables without access to the Clickhouse they're
+//! usually initialized from. Tardis publishes a gzipped csv per exchange,
+//! symbol, day and data type, holding the same top of book quotes and trades
+//! the Clickhouse tables are built from, so they're converted to blocks the
+//! same way.
+//!
+//! The symbols imported, along with the tokens they trade, are loaded from a
+//! file laid out like `config/tardis_config.toml`. Downloads other than the
+//! first day of each month need an api key.
+//!
+//! Quotes can instead be taken from Tardis' snapshots of the top 25 levels of
+//! each book, which are much larger to download but keep the depth the
+//! cex-dex inspector walks when pricing a hedge.
+use std::{collections::BTreeMap, io::BufRead, path::Path, sync::Arc};
+
+use alloy_primitives::Address;
+use brontes_types::{
+    db::{
+        block_times::BlockTimes,
+        cex::{
+            quotes::{CexQuotesConverter, RawCexQuotes},
+            trades::{CexTradesConverter, RawCexTrades, TradeType},
+            BestCexPerPair, CexExchange, CexSymbols,
+        },
+        initialized_state::{InitializedStateMeta, CEX_QUOTES_FLAG, CEX_TRADES_FLAG, DATA_PRESENT},
+    },
+    pair::Pair,
+    traits::TracingProvider,
+};
+use chrono::{DateTime, NaiveDate};
+use flate2::read::GzDecoder;
+use serde::Deserialize;
+use tokio::sync::Notify;
+use tracing::info;
+
+use crate::{
+    cex_feed::{best_cex_per_pair, QUOTE_WINDOW_US, TRADE_WINDOW_US},
+    libmdbx::{
+        libmdbx_writer::{InitTables, WriterMessage},
+        LibmdbxReadWriter,
+    },
+    CexPriceData, CexTradesData, InitializedStateData,
+};
+
+const TARDIS_DATASETS_URL: &str = "https://datasets.tardis.dev/v1";
+/// Blocks converted and written at a time, around two hours worth
+const IMPORT_CHUNK_SIZE: usize = 600;
+/// Levels per side in tardis' deepest book snapshots
+const SNAPSHOT_LEVELS: usize = 25;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TardisConfig {
+    pub symbols: Vec<TardisSymbol>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TardisSymbol {
+    pub exchange: CexExchange,
+    /// the symbol as tardis names it, which is the exchange's own name, e.g.
+    /// `ETHUSDT` on Binance or `XBT/USD` on Kraken
+    pub symbol:   String,
+    pub base:     Address,
+    pub quote:    Address,
+}
+
+impl TardisConfig {
+    pub fn load(path: impl AsRef<Path>) -> eyre::Result<Self> {
+        let config: Self = toml::from_str(&std::fs::read_to_string(path)?)?;
+        for symbol in &config.symbols {
+            tardis_exchange(symbol.exchange)?;
+        }
+
+        Ok(config)
+    }
+}
+
+/// The quotes and trades of every symbol on a single day
+#[derive(Debug, Default)]
+struct DayData {
+    quotes: Vec<RawCexQuotes>,
+    trades: Vec<RawCexTrades>,
+}
+
+pub struct TardisImporter<T: TracingProvider> {
+    tracer:            Arc<T>,
+    db:                &'static LibmdbxReadWriter,
+    client:            reqwest::Client,
+    api_key:           Option<String>,
+    /// whether quotes are taken from book snapshots, keeping their depth
+    depth:             bool,
+    symbols:           Vec<CexSymbols>,
+    best_cex_per_pair: Vec<BestCexPerPair>,
+    /// the days downloaded that the chunk being imported needs, consecutive
+    /// chunks mostly share their days
+    days:              BTreeMap<NaiveDate, DayData>,
+}
+
+impl<T: TracingProvider> TardisImporter<T> {
+    pub fn new(
+        config: TardisConfig,
+        tracer: Arc<T>,
+        db: &'static LibmdbxReadWriter,
+        api_key: Option<String>,
+    ) -> Self {
+        let symbols = config
+            .symbols
+            .into_iter()
+            .map(|s| CexSymbols {
+                exchange:     s.exchange,
+                symbol_pair:  s.symbol,
+                address_pair: Pair(s.base, s.quote),
+            })
+            .collect::<Vec<_>>();
+        let best_cex_per_pair = best_cex_per_pair(&symbols);
+
+        Self {
+            tracer,
+            db,
+            client: reqwest::Client::new(),
+            api_key,
+            depth: false,
+            symbols,
+            best_cex_per_pair,
+            days: BTreeMap::new(),
+        }
+    }
+
+    /// Takes quotes from book snapshots rather than the top of the book
+    pub fn with_depth(mut self, depth: bool) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    /// Imports the quotes and trades of `start_block` through `end_block`,
+    /// overwriting any the database already has
+    pub async fn import(&mut self, start_block: u64, end_block: u64) -> eyre::Result<()> {
+        let blocks = (start_block..=end_block).collect::<Vec<_>>();
+        for chunk in blocks.chunks(IMPORT_CHUNK_SIZE) {
+            let block_times = self.block_times(chunk).await?;
+            self.import_chunk(block_times).await?;
+            info!(
+                target: "brontes::tardis",
+                "imported cex data for blocks {} to {}",
+                chunk[0],
+                chunk[chunk.len() - 1]
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn block_times(&self, blocks: &[u64]) -> eyre::Result<Vec<BlockTimes>> {
+        let mut block_times = Vec::with_capacity(blocks.len());
+        for &block_number in blocks {
+            let header = self
+                .tracer
+                .header_by_number(block_number)
+                .await?
+                .ok_or_else(|| eyre::eyre!("no header for block {block_number}"))?;
+            block_times.push(BlockTimes { block_number, timestamp: header.timestamp * 1_000_000 });
+        }
+
+        Ok(block_times)
+    }
+
+    async fn import_chunk(&mut self, block_times: Vec<BlockTimes>) -> eyre::Result<()> {
+        let (first, last) = (&block_times[0], &block_times[block_times.len() - 1]);
+        let (first_block, last_block) = (first.block_number, last.block_number);
+        let start = first.timestamp.saturating_sub(QUOTE_WINDOW_US);
+        let end = last.timestamp + QUOTE_WINDOW_US;
+        let (first_day, last_day) = (day_of(start)?, day_of(end)?);
+
+        self.days.retain(|day, _| *day >= first_day);
+        let mut quotes = Vec::new();
+        let mut trades = Vec::new();
+        for day in first_day.iter_days().take_while(|day| *day <= last_day) {
+            if !self.days.contains_key(&day) {
+                let data = self.download_day(day).await?;
+                self.days.insert(day, data);
+            }
+
+            let data = &self.days[&day];
+            quotes.extend(
+                data.quotes
+                    .iter()
+                    .filter(|quote| (start..=end).contains(&quote.timestamp))
+                    .cloned(),
+            );
+            trades.extend(
+                data.trades
+                    .iter()
+                    .filter(|trade| {
+                        trade.timestamp + TRADE_WINDOW_US >= first.timestamp
+                            && trade.timestamp <= last.timestamp + TRADE_WINDOW_US
+                    })
+                    .cloned(),
+            );
+        }
+        // symbols are downloaded one after the other, the converters expect
+        // everything sorted by time
+        quotes.sort_by_key(|quote| quote.timestamp);
+        trades.sort_by_key(|trade| trade.timestamp);
+
+        let prices = CexQuotesConverter::new(
+            block_times.clone(),
+            self.symbols.clone(),
+            quotes,
+            self.best_cex_per_pair.clone(),
+        )
+        .convert_to_prices()
+        .into_iter()
+        .map(|(block, prices)| CexPriceData::new(block, prices))
+        .collect::<Vec<_>>();
+        let trades = CexTradesConverter::new(block_times, self.symbols.clone(), trades)
+            .convert_to_trades()
+            .into_iter()
+            .map(|(block, trades)| CexTradesData::new(block, trades))
+            .collect::<Vec<_>>();
+
+        let mut state = InitializedStateMeta::default();
+        state.set(CEX_QUOTES_FLAG, DATA_PRESENT);
+        state.set(CEX_TRADES_FLAG, DATA_PRESENT);
+        let inited = (first_block..=last_block)
+            .map(|block| InitializedStateData::new(block, state))
+            .collect::<Vec<_>>();
+
+        self.write(prices.into()).await?;
+        self.write(trades.into()).await?;
+        self.write(inited.into()).await
+    }
+
+    /// Writes through the db's writer, the same way tables are initialized
+    async fn write(&self, data: InitTables) -> eyre::Result<()> {
+        let written = Arc::new(Notify::new());
+        self.db
+            .send_message(WriterMessage::Init(data, written.clone()))?;
+        written.notified().await;
+
+        Ok(())
+    }
+
+    async fn download_day(&self, day: NaiveDate) -> eyre::Result<DayData> {
+        let mut data = DayData::default();
+        for symbol in &self.symbols {
+            if self.depth {
+                let snapshots = self
+                    .download(symbol, &format!("book_snapshot_{SNAPSHOT_LEVELS}"), day)
+                    .await?;
+                data.quotes.extend(parse_book_snapshots(
+                    symbol.exchange,
+                    &symbol.symbol_pair,
+                    snapshots,
+                )?);
+            } else {
+                let quotes = self.download(symbol, "quotes", day).await?;
+                data.quotes
+                    .extend(parse_quotes(symbol.exchange, &symbol.symbol_pair, quotes)?);
+            }
+
+            let trades = self.download(symbol, "trades", day).await?;
+            data.trades
+                .extend(parse_trades(symbol.exchange, &symbol.symbol_pair, trades)?);
+        }
+        info!(target: "brontes::tardis", %day, "downloaded cex data");
+
+        Ok(data)
+    }
+
+    /// Downloads a day's `data_type` csv for `symbol`
+    async fn download(
+        &self,
+        symbol: &CexSymbols,
+        data_type: &str,
+        day: NaiveDate,
+    ) -> eyre::Result<impl BufRead> {
+        // symbols in dataset urls are uppercase, with `/` and `:` replaced
+        let url = format!(
+            "{TARDIS_DATASETS_URL}/{}/{data_type}/{}/{}.csv.gz",
+            tardis_exchange(symbol.exchange)?,
+            day.format("%Y/%m/%d"),
+            symbol.symbol_pair.to_uppercase().replace(['/', ':'], "-")
+        );
+
+        let mut request = self.client.get(&url);
+        if let Some(api_key) = &self.api_key {
+            request = request.bearer_auth(api_key);
+        }
+        let body = request.send().await?.error_for_status()?.bytes().await?;
+
+        Ok(std::io::BufReader::new(GzDecoder::new(std::io::Cursor::new(body))))
+    }
+}
+
+/// The name tardis gives an exchange in its dataset urls
+fn tardis_exchange(exchange: CexExchange) -> eyre::Result<&'static str> {
+    Ok(match exchange {
+        CexExchange::Binance => "binance",
+        CexExchange::Bitmex => "bitmex",
+        CexExchange::Deribit => "deribit",
+        CexExchange::Okex => "okex",
+        CexExchange::Coinbase => "coinbase",
+        CexExchange::Kraken => "kraken",
+        CexExchange::BybitSpot => "bybit-spot",
+        CexExchange::Kucoin => "kucoin",
+        CexExchange::Upbit => "upbit",
+        CexExchange::Huobi => "huobi",
+        CexExchange::GateIo => "gate-io",
+        CexExchange::Bitstamp => "bitstamp",
+        CexExchange::Gemini => "gemini",
+        _ => eyre::bail!("tardis has no data for {exchange}"),
+    })
+}
+
+/// The UTC day the microsecond timestamp falls on
+fn day_of(timestamp: u64) -> eyre::Result<NaiveDate> {
+    Ok(DateTime::from_timestamp((timestamp / 1_000_000) as i64, 0)
+        .ok_or_else(|| eyre::eyre!("timestamp {timestamp} is out of range"))?
+        .date_naive())
+}
+
+/// Parses a quotes csv, with columns `exchange, symbol, timestamp,
+/// local_timestamp, ask_amount, ask_price, bid_price, bid_amount`. The
+/// converter only looks at quotes as of whole seconds after a block, so only
+/// the last quote up to each second is kept
+fn parse_quotes(
+    exchange: CexExchange,
+    symbol: &str,
+    csv: impl BufRead,
+) -> eyre::Result<Vec<RawCexQuotes>> {
+    let mut quotes: Vec<RawCexQuotes> = Vec::new();
+    for line in csv.lines().skip(1) {
+        let line = line?;
+        let columns = line.split(',').collect::<Vec<_>>();
+        let [_, _, timestamp, _, ask_amount, ask_price, bid_price, bid_amount] = columns[..] else {
+            eyre::bail!("malformed tardis quote: {line}")
+        };
+        // a side of the book was empty
+        if [ask_amount, ask_price, bid_price, bid_amount].contains(&"") {
+            continue
+        }
+
+        let quote = RawCexQuotes {
+            exchange,
+            symbol: symbol.to_string(),
+            timestamp: timestamp.parse()?,
+            ask_amount: ask_amount.parse()?,
+            ask_price: ask_price.parse()?,
+            bid_price: bid_price.parse()?,
+            bid_amount: bid_amount.parse()?,
+            depth: Default::default(),
+        };
+        push_quote(&mut quotes, quote);
+    }
+
+    Ok(quotes)
+}
+
+/// Parses a book snapshot csv, with columns `exchange, symbol, timestamp,
+/// local_timestamp` followed by `asks[i].price, asks[i].amount, bids[i].price,
+/// bids[i].amount` for each level, best first. Levels past the end of a side
+/// are left empty. Like quotes, only the last snapshot up to each second is
+/// kept
+fn parse_book_snapshots(
+    exchange: CexExchange,
+    symbol: &str,
+    csv: impl BufRead,
+) -> eyre::Result<Vec<RawCexQuotes>> {
+    let mut quotes: Vec<RawCexQuotes> = Vec::new();
+    for line in csv.lines().skip(1) {
+        let line = line?;
+        let columns = line.split(',').collect::<Vec<_>>();
+        let [_, _, timestamp, _, levels @ ..] = &columns[..] else {
+            eyre::bail!("malformed tardis book snapshot: {line}")
+        };
+        if levels.len() % 4 != 0 {
+            eyre::bail!("malformed tardis book snapshot: {line}")
+        }
+
+        let (mut bids, mut asks) = (Vec::new(), Vec::new());
+        for level in levels.chunks(4) {
+            let [ask_price, ask_amount, bid_price, bid_amount] = level else { unreachable!() };
+            if !ask_price.is_empty() && !ask_amount.is_empty() {
+                asks.push((ask_price.parse()?, ask_amount.parse()?));
+            }
+            if !bid_price.is_empty() && !bid_amount.is_empty() {
+                bids.push((bid_price.parse()?, bid_amount.parse()?));
+            }
+        }
+        // a side of the book was empty
+        let (Some(&(ask_price, ask_amount)), Some(&(bid_price, bid_amount))) =
+            (asks.first(), bids.first())
+        else {
+            continue
+        };
+
+        let quote = RawCexQuotes {
+            exchange,
+            symbol: symbol.to_string(),
+            timestamp: timestamp.parse()?,
+            ask_amount,
+            ask_price,
+            bid_price,
+            bid_amount,
+            depth: (bids, asks),
+        };
+        push_quote(&mut quotes, quote);
+    }
+
+    Ok(quotes)
+}
+
+/// Pushes `quote`, replacing the last quote if they fall before the same whole
+/// second
+fn push_quote(quotes: &mut Vec<RawCexQuotes>, quote: RawCexQuotes) {
+    match quotes.last_mut() {
+        Some(last) if last.timestamp.div_ceil(1_000_000) == quote.timestamp.div_ceil(1_000_000) => {
+            *last = quote
+        }
+        _ => quotes.push(quote),
+    }
+}
+
+/// Parses a trades csv, with columns `exchange, symbol, timestamp,
+/// local_timestamp, id, side, price, amount`. The side is that of the taker
+fn parse_trades(
+    exchange: CexExchange,
+    symbol: &str,
+    csv: impl BufRead,
+) -> eyre::Result<Vec<RawCexTrades>> {
+    csv.lines()
+        .skip(1)
+        .map(|line| {
+            let line = line?;
+            let columns = line.split(',').collect::<Vec<_>>();
+            let [_, _, timestamp, _, _, side, price, amount] = columns[..] else {
+                eyre::bail!("malformed tardis trade: {line}")
+            };
+
+            Ok(RawCexTrades {
+                exchange,
+                trade_type: TradeType::Taker,
+                symbol: symbol.to_string(),
+                timestamp: timestamp.parse()?,
+                side: side.to_string(),
+                price: price.parse()?,
+                amount: amount.parse()?,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_quotes() {
+        let csv = concat!(
+            "exchange,symbol,timestamp,local_timestamp,ask_amount,ask_price,bid_price,bid_amount\n",
+            "binance,ETHUSDT,1718000000100000,1718000000100500,40.66,3012.52,3012.51,31.21\n",
+            "binance,ETHUSDT,1718000001000000,1718000001000500,2.5,3012.53,3012.52,1.0\n",
+            "binance,ETHUSDT,1718000001000001,1718000001000501,,,3012.52,1.0\n",
+            "binance,ETHUSDT,1718000001500000,1718000001500500,3.0,3012.55,3012.54,4.0\n",
+        );
+
+        let quotes = parse_quotes(CexExchange::Binance, "ETHUSDT", csv.as_bytes()).unwrap();
+        // the first two fall before the same whole second, and the third has
+        // no asks
+        assert_eq!(quotes.len(), 2);
+        assert_eq!(quotes[0].timestamp, 1_718_000_001_000_000);
+        assert_eq!(quotes[0].ask_price, 3012.53);
+        assert_eq!(quotes[1].bid_amount, 4.0);
+        assert_eq!(quotes[1].symbol, "ETHUSDT");
+    }
+
+    #[test]
+    fn test_parse_book_snapshots() {
+        let csv = concat!(
+            "exchange,symbol,timestamp,local_timestamp,asks[0].price,asks[0].amount,",
+            "bids[0].price,bids[0].amount,asks[1].price,asks[1].amount,bids[1].price,",
+            "bids[1].amount\n",
+            "okex,ETH-USDT,1718000000100000,1718000000100500,3012.52,40.66,3012.51,31.21,",
+            "3012.6,2.5,,\n",
+            "okex,ETH-USDT,1718000001500000,1718000001500500,,,3012.54,4.0,,,,\n",
+        );
+
+        let quotes = parse_book_snapshots(CexExchange::Okex, "ETH-USDT", csv.as_bytes()).unwrap();
+        // the second has no asks
+        assert_eq!(quotes.len(), 1);
+        assert_eq!(quotes[0].ask_price, 3012.52);
+        assert_eq!(quotes[0].bid_amount, 31.21);
+        assert_eq!(quotes[0].depth.0, vec![(3012.51, 31.21)]);
+        assert_eq!(quotes[0].depth.1, vec![(3012.52, 40.66), (3012.6, 2.5)]);
+    }
+
+    #[test]
+    fn test_parse_trades() {
+        let csv = concat!(
+            "exchange,symbol,timestamp,local_timestamp,id,side,price,amount\n",
+            "kraken,XBT/USD,1718000000000000,1718000000000500,1,sell,67000.1,0.25\n",
+            "kraken,XBT/USD,1718000000500000,1718000000500500,2,buy,67000.2,1.5\n",
+        );
+
+        let trades = parse_trades(CexExchange::Kraken, "XBT/USD", csv.as_bytes()).unwrap();
+        assert_eq!(trades.len(), 2);
+        assert_eq!(trades[0].side, "sell");
+        assert_eq!(trades[0].timestamp, 1_718_000_000_000_000);
+        assert_eq!(trades[1].price, 67000.2);
+        assert_eq!(trades[1].exchange, CexExchange::Kraken);
+    }
+
+    #[test]
+    fn test_day_of() {
+        assert_eq!(
+            day_of(1_718_000_000_000_000).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 6, 10).unwrap()
+        );
+    }
+}
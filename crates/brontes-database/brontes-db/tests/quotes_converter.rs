@@ -56,6 +56,7 @@ fn create_test_cex_quotes() -> Vec<CexQuote> {
                 Rational::try_from_float_simplest(0.0256).unwrap(),
                 Rational::try_from_float_simplest(0.4466).unwrap(),
             ),
+            depth:     Default::default(),
         },
         CexQuote {
             exchange:  CexExchange::Binance,
@@ -68,6 +69,7 @@ fn create_test_cex_quotes() -> Vec<CexQuote> {
                 Rational::try_from_float_simplest(0.3615).unwrap(),
                 Rational::try_from_float_simplest(8.378).unwrap(),
             ),
+            depth:     Default::default(),
         },
         CexQuote {
             exchange:  CexExchange::Binance,
@@ -80,6 +82,7 @@ fn create_test_cex_quotes() -> Vec<CexQuote> {
                 Rational::try_from_float_simplest(17.4219).unwrap(),
                 Rational::try_from_float_simplest(2.0796).unwrap(),
             ),
+            depth:     Default::default(),
         },
         CexQuote {
             exchange:  CexExchange::Binance,
@@ -92,6 +95,7 @@ fn create_test_cex_quotes() -> Vec<CexQuote> {
                 Rational::try_from_float_simplest(0.8695).unwrap(),
                 Rational::try_from_float_simplest(0.1945).unwrap(),
             ),
+            depth:     Default::default(),
         },
         CexQuote {
             exchange:  CexExchange::Binance,
@@ -104,6 +108,7 @@ fn create_test_cex_quotes() -> Vec<CexQuote> {
                 Rational::try_from_float_simplest(4.6408).unwrap(),
                 Rational::try_from_float_simplest(0.7662).unwrap(),
             ),
+            depth:     Default::default(),
         },
         CexQuote {
             exchange:  CexExchange::Binance,
@@ -116,6 +121,7 @@ fn create_test_cex_quotes() -> Vec<CexQuote> {
                 Rational::try_from_float_simplest(0.0268).unwrap(),
                 Rational::try_from_float_simplest(41.2785).unwrap(),
             ),
+            depth:     Default::default(),
         },
     ]
 }
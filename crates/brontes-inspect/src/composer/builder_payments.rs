@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use alloy_primitives::Address;
+use brontes_types::{
+    db::{builder::BuilderRevenue, dex::PriceAt, metadata::Metadata, traits::LibmdbxReader},
+    mev::{Bundle, Mev},
+    normalized_actions::Action,
+    tree::BlockTree,
+    ToFloatNearest, TreeSearchBuilder,
+};
+use malachite::{num::basic::traits::Zero, Rational};
+
+use crate::shared_utils::SharedInspectorUtils;
+
+/// What a single bundle paid the block's builder
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(crate) struct BundlePayment {
+    pub priority_fee:         u128,
+    pub coinbase_transfer:    u128,
+    /// Tokens the bundle sent to the builder's address, in usd
+    pub direct_transfers_usd: Rational,
+}
+
+/// Sums up what the block's mev bundles paid its builder, as the block's entry
+/// to be merged into the builder's running totals
+pub(crate) fn calculate_builder_payments<DB: LibmdbxReader>(
+    tree: Arc<BlockTree<Action>>,
+    metadata: &Arc<Metadata>,
+    bundles: &[Bundle],
+    quote_token: Address,
+    db: &'static DB,
+) -> BuilderRevenue {
+    let utils = SharedInspectorUtils::new(quote_token, db, None);
+    let builder = tree.header.beneficiary;
+    let base_fee = tree.header.base_fee_per_gas.unwrap_or_default() as u128;
+
+    let payments = bundles
+        .iter()
+        .map(|bundle| bundle_payment(&tree, metadata, &utils, builder, base_fee, bundle))
+        .collect::<Vec<_>>();
+
+    let priority_fees = payments.iter().map(|p| p.priority_fee).sum::<u128>();
+    let coinbase_transfers = payments.iter().map(|p| p.coinbase_transfer).sum::<u128>();
+    let direct_transfers_usd = payments
+        .iter()
+        .fold(Rational::ZERO, |acc, p| acc + &p.direct_transfers_usd);
+    let total_usd = metadata.get_gas_price_usd(priority_fees + coinbase_transfers, quote_token)
+        + &direct_transfers_usd;
+
+    BuilderRevenue {
+        blocks: u64::from(!bundles.is_empty()),
+        bundles: bundles.len() as u64,
+        priority_fees,
+        coinbase_transfers,
+        direct_transfers_usd: direct_transfers_usd.to_float(),
+        total_usd: total_usd.to_float(),
+        last_block: metadata.block_num,
+    }
+}
+
+fn bundle_payment<DB: LibmdbxReader>(
+    tree: &Arc<BlockTree<Action>>,
+    metadata: &Arc<Metadata>,
+    utils: &SharedInspectorUtils<'_, DB>,
+    builder: Address,
+    base_fee: u128,
+    bundle: &Bundle,
+) -> BundlePayment {
+    let direct_transfers_usd = bundle
+        .data
+        .mev_transaction_hashes()
+        .into_iter()
+        .filter_map(|hash| {
+            let tx_index = tree.get_root(hash)?.position;
+            let paid = tree
+                .clone()
+                .collect(
+                    &hash,
+                    TreeSearchBuilder::default()
+                        .with_action(Action::is_transfer)
+                        .with_to_address(vec![builder]),
+                )
+                .filter_map(|action| {
+                    let transfer = action.try_transfer()?;
+                    // a token without a price can't be counted
+                    utils.get_token_value_dex(
+                        tx_index,
+                        PriceAt::After,
+                        transfer.token.address,
+                        &transfer.amount,
+                        metadata,
+                    )
+                })
+                .fold(Rational::ZERO, |acc, usd| acc + usd);
+
+            Some(paid)
+        })
+        .fold(Rational::ZERO, |acc, usd| acc + usd);
+
+    BundlePayment {
+        priority_fee: bundle.data.total_priority_fee_paid(base_fee),
+        coinbase_transfer: bundle.data.bribe(),
+        direct_transfers_usd,
+    }
+}
@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use brontes_types::{
     db::traits::LibmdbxReader,
-    mev::{AtomicArbType, Bundle, BundleData, MevType},
+    mev::{AtomicArbType, Bundle, BundleData, Mev, MevType},
     normalized_actions::Action,
     BlockTree,
 };
@@ -64,6 +64,7 @@ pub type FilterFn = Option<
 pub fn get_filter_fn(mev_type: MevType) -> FilterFn {
     match mev_type {
         MevType::AtomicArb => Some(Box::new(atomic_dedup_fn)),
+        MevType::Sandwich => Some(Box::new(sandwich_dedup_fn)),
         _ => None,
     }
 }
@@ -100,6 +101,25 @@ pub fn atomic_dedup_fn(
     true
 }
 
+/// returns true if should dedup. Only mev in the frontruns or the backrun of
+/// the sandwich is the sandwich itself, e.g. its backrun also being picked up
+/// as an atomic arb. Its victims can be mev of their own, an arb that landed
+/// between the frontrun and backrun isn't part of the sandwich.
+pub fn sandwich_dedup_fn(
+    _tree: Arc<BlockTree<Action>>,
+    _db: Arc<Box<dyn LibmdbxReader>>,
+    bundles: [&Bundle; 2],
+) -> bool {
+    let [sandwich, other] = bundles;
+    let BundleData::Sandwich(sandwich) = &sandwich.data else { return true };
+
+    other
+        .data
+        .mev_transaction_hashes()
+        .iter()
+        .any(|hash| *hash == sandwich.backrun_tx_hash || sandwich.frontrun_tx_hash.contains(hash))
+}
+
 define_mev_precedence!(
     // will filter out unless function says otherwise
     CexDexTrades => AtomicArb;
@@ -111,7 +131,12 @@ define_mev_precedence!(
     Unknown, SearcherTx => AtomicArb;
     Unknown, SearcherTx, AtomicArb => Jit;
     Unknown, SearcherTx, AtomicArb, CexDexQuotes,CexDexTrades  => Liquidation;
+    Unknown, SearcherTx => NftMev;
+    Unknown, SearcherTx, AtomicArb, CexDexQuotes, CexDexTrades, Liquidation => OracleManipulation;
+    Unknown, SearcherTx => LaunchSnipe;
     Unknown, SearcherTx, AtomicArb, CexDexQuotes,CexDexTrades  => Sandwich;
     Unknown, SearcherTx, AtomicArb, Jit, CexDexQuotes, CexDexTrades=> JitCexDex;
     Unknown, SearcherTx, AtomicArb, CexDexQuotes, CexDexTrades, Jit, Sandwich => JitSandwich;
+    // an unknown bundle is only left once nothing else claimed its transaction
+    SearcherTx => Unknown;
 );
@@ -13,6 +13,10 @@
 //!   defined using the `mev_composability` and `define_mev_precedence` macros,
 //!   respectively, establish rules for composing multiple MEV types and setting
 //!   precedence among them for deduplication.
+//! - Overlap removal: Once the precedence rules are applied, any transaction
+//!   still claimed by more than one bundle is left to a single one of them, so
+//!   each transaction ends up in at most one bundle. A sandwich's victims don't
+//!   count as its transactions.
 //! - Utility Functions: A collection of functions designed to assist in the
 //!   composition and deduplication processes of MEV data.
 //!
@@ -31,13 +35,17 @@ use std::sync::Arc;
 
 use alloy_primitives::Address;
 use brontes_types::{
-    db::{block_analysis::BlockAnalysis, traits::LibmdbxReader},
+    db::{
+        block_analysis::BlockAnalysis, builder::BuilderRevenue, normalized_actions::ActionKind,
+        traits::LibmdbxReader,
+    },
     mev::Mev,
-    BlockData, FastHashMap, MultiBlockData,
+    BlockData, FastHashMap, MultiBlockData, TreeSearchBuilder,
 };
 use itertools::Itertools;
 use tracing::{span, Level};
 
+mod builder_payments;
 mod composer_filters;
 mod mev_filters;
 mod utils;
@@ -47,17 +55,19 @@ use brontes_types::{
     normalized_actions::Action,
     tree::BlockTree,
 };
+use builder_payments::calculate_builder_payments;
 use composer_filters::{ComposeFunction, MEV_COMPOSABILITY_FILTER};
 use mev_filters::{FilterFn, MEV_DEDUPLICATION_FILTER};
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+pub use utils::refresh_bundle_summary;
 use utils::{
-    build_mev_header, filter_and_count_bundles, find_mev_with_matching_tx_hashes, sort_mev_by_type,
-    try_deduping_mev,
+    build_mev_header, filter_and_count_bundles, find_mev_with_matching_tx_hashes, is_kept,
+    remove_overlapping_bundles, sort_mev_by_type, try_deduping_mev,
 };
 
 const DISCOVERY_PRIORITY_FEE_MULTIPLIER: f64 = 2.0;
 
-use crate::{discovery::DiscoveryInspector, Inspector};
+use crate::{discovery::DiscoveryInspector, Inspector, Inspectors, MAX_BLOCK_WINDOW};
 
 #[derive(Debug)]
 pub struct ComposerResults {
@@ -66,12 +76,19 @@ pub struct ComposerResults {
     /// all txes with coinbase.transfers that weren't classified
     pub possible_mev_txes: PossibleMevCollection,
     pub block_analysis:    BlockAnalysis,
+    /// what the block's mev paid its builder, to be added to the builder's
+    /// totals
+    pub builder_payments:  BuilderRevenue,
 }
 
+/// Runs the inspectors over the block and composes their bundles. With
+/// `track_losses` losing sandwiches and atomic arbs are kept, it should match
+/// what the inspectors were built with
 pub fn run_block_inspection<DB: LibmdbxReader>(
     orchestra: &[&dyn Inspector<Result = Vec<Bundle>>],
     data: MultiBlockData,
     db: &'static DB,
+    track_losses: bool,
 ) -> ComposerResults {
     let this_data = data.get_most_recent_block().clone();
     let BlockData { metadata, tree } = this_data;
@@ -82,12 +99,44 @@ pub fn run_block_inspection<DB: LibmdbxReader>(
 
     let quote_token = orchestra[0].get_quote_token();
 
-    let (block_details, mev_details) =
-        on_orchestra_resolution(tree, possible_mev_txes, metadata, classified_mev, quote_token, db);
+    let (block_details, mev_details) = on_orchestra_resolution(
+        tree.clone(),
+        possible_mev_txes,
+        metadata.clone(),
+        classified_mev,
+        quote_token,
+        db,
+        |bundle| is_kept(bundle, orchestra, track_losses),
+    );
 
     let block_analysis = BlockAnalysis::new(&block_details, &mev_details);
+    let builder_payments =
+        calculate_builder_payments(tree, &metadata, &mev_details, quote_token, db);
+
+    ComposerResults {
+        block_details,
+        mev_details,
+        possible_mev_txes: possible_arbs,
+        block_analysis,
+        builder_payments,
+    }
+}
+
+/// Runs only the inspectors added through [`Inspectors::register`] over the
+/// block and composes their bundles, for downstream crates that want their
+/// own inspectors' results without running the built in ones. `None` if none
+/// have been registered
+pub fn run_registered_inspection<DB: LibmdbxReader>(
+    data: MultiBlockData,
+    db: &'static DB,
+    track_losses: bool,
+) -> Option<ComposerResults> {
+    let orchestra = Inspectors::registered();
+    if orchestra.is_empty() {
+        return None
+    }
 
-    ComposerResults { block_details, mev_details, possible_mev_txes: possible_arbs, block_analysis }
+    Some(run_block_inspection(&orchestra, data, db, track_losses))
 }
 
 fn run_inspectors(
@@ -102,7 +151,7 @@ fn run_inspectors(
     let results = orchestra
         .par_iter()
         .flat_map(|inspector| {
-            let window = inspector.block_window();
+            let window = inspector.block_window().min(MAX_BLOCK_WINDOW);
             // not sufficient size yet
             if data.blocks < window {
                 return vec![]
@@ -141,6 +190,7 @@ fn on_orchestra_resolution<DB: LibmdbxReader>(
     orchestra_data: Vec<Bundle>,
     quote_token: Address,
     db: &'static DB,
+    keep: impl Fn(&Bundle) -> bool,
 ) -> (MevBlock, Vec<Bundle>) {
     let mut sorted_mev = sort_mev_by_type(orchestra_data);
 
@@ -162,8 +212,15 @@ fn on_orchestra_resolution<DB: LibmdbxReader>(
             );
         },
     );
+    remove_overlapping_bundles(&mut sorted_mev, &keep);
 
-    let (mev_count, mut filtered_bundles) = filter_and_count_bundles(sorted_mev);
+    let (mev_count, mut filtered_bundles) = filter_and_count_bundles(sorted_mev, keep);
+
+    if has_governance_event(tree.clone()) {
+        filtered_bundles
+            .iter_mut()
+            .for_each(|bundle| bundle.header.governance_event_in_block = true);
+    }
 
     let header = build_mev_header(
         &metadata,
@@ -180,6 +237,19 @@ fn on_orchestra_resolution<DB: LibmdbxReader>(
     (header, filtered_bundles)
 }
 
+/// Whether a protocol was paused or had its funds pulled in the block. Mev next
+/// to one is often part of an exploit, so it is flagged to be treated
+/// separately
+fn has_governance_event(tree: Arc<BlockTree<Action>>) -> bool {
+    tree.collect_all(
+        TreeSearchBuilder::default()
+            .with_action(Action::is_governance)
+            .with_tx_action_kinds([ActionKind::Governance])
+            .with_max_results(1),
+    )
+    .any(|(_, actions)| !actions.is_empty())
+}
+
 fn deduplicate_mev<DB: LibmdbxReader>(
     tree: Arc<BlockTree<Action>>,
     db: &'static DB,
@@ -1,16 +1,16 @@
 use std::sync::Arc;
 
-use alloy_primitives::{Address, FixedBytes};
+use alloy_primitives::{Address, FixedBytes, B256};
 use brontes_types::{
     db::{builder::BuilderInfo, metadata::Metadata, traits::LibmdbxReader},
-    mev::{Bundle, Mev, MevBlock, MevCount, MevType, PossibleMevCollection},
+    mev::{Bundle, BundleData, Mev, MevBlock, MevCount, MevType, PossibleMevCollection},
     normalized_actions::Action,
     tree::BlockTree,
-    FastHashMap, GasDetails, ToFloatNearest, ToScaledRational, TreeSearchBuilder,
+    FastHashMap, FastHashSet, GasDetails, ToFloatNearest, ToScaledRational, TreeSearchBuilder,
 };
 use malachite::{num::conversion::traits::RoundingFrom, rounding_modes::RoundingMode};
 
-use crate::composer::FilterFn;
+use crate::{composer::FilterFn, Inspector};
 
 pub(crate) fn build_mev_header<DB: LibmdbxReader>(
     metadata: &Arc<Metadata>,
@@ -77,6 +77,7 @@ pub(crate) fn build_mev_header<DB: LibmdbxReader>(
         proposer_fee_recipient,
         proposer_mev_reward,
         proposer_profit_usd,
+        fee_recipient_withdrawals: block_pnl.fee_recipient_withdrawals,
         total_mev_profit_usd,
         possible_mev,
     }
@@ -148,21 +149,14 @@ pub(crate) fn try_deduping_mev<'a>(
 
 pub fn filter_and_count_bundles(
     sorted_mev: FastHashMap<MevType, Vec<Bundle>>,
+    keep: impl Fn(&Bundle) -> bool,
 ) -> (MevCount, Vec<Bundle>) {
     let mut mev_count = MevCount::default();
     let mut all_filtered_bundles = Vec::new();
 
     for (mev_type, bundles) in sorted_mev {
-        let filtered_bundles: Vec<Bundle> = bundles
-            .into_iter()
-            .filter(|bundle| {
-                if matches!(mev_type, MevType::Sandwich | MevType::AtomicArb) {
-                    bundle.header.profit_usd > 0.0 || bundle.header.no_pricing_calculated
-                } else {
-                    true
-                }
-            })
-            .collect();
+        let filtered_bundles: Vec<Bundle> =
+            bundles.into_iter().filter(|bundle| keep(bundle)).collect();
 
         // Update  for this MEV type
         let count = filtered_bundles.len() as u64;
@@ -179,6 +173,81 @@ pub fn filter_and_count_bundles(
     (mev_count, all_filtered_bundles)
 }
 
+/// Sandwiches and atomic arbs that lost money are dropped from the results,
+/// unless losses are being tracked, as is any bundle one of the inspectors
+/// doesn't keep
+pub(crate) fn is_kept(
+    bundle: &Bundle,
+    orchestra: &[&dyn Inspector<Result = Vec<Bundle>>],
+    track_losses: bool,
+) -> bool {
+    if !orchestra.iter().all(|inspector| inspector.keeps(bundle)) {
+        return false
+    }
+
+    if matches!(bundle.header.mev_type, MevType::Sandwich | MevType::AtomicArb) {
+        bundle.header.profit_usd > 0.0 || bundle.header.no_pricing_calculated || track_losses
+    } else {
+        true
+    }
+}
+
+/// The transactions the searcher sent in the bundle. A sandwich's victims are
+/// part of its hashes, but aren't the searcher's and can be mev of their own
+fn searcher_tx_hashes(bundle: &Bundle) -> Vec<B256> {
+    let victims = match &bundle.data {
+        BundleData::Sandwich(s) => s.victim_swaps_tx_hashes.concat(),
+        BundleData::JitSandwich(s) => s.victim_swaps_tx_hashes.concat(),
+        _ => return bundle.data.mev_transaction_hashes(),
+    };
+
+    bundle
+        .data
+        .mev_transaction_hashes()
+        .into_iter()
+        .filter(|hash| !victims.contains(hash))
+        .collect()
+}
+
+/// Makes sure no transaction is in more than one bundle once the precedence
+/// rules have been applied. The overlaps left are between types no rule
+/// covers, or bundles of the same type. Of the overlapping bundles the one
+/// with the most transactions is kept, as it explains more of the block, then
+/// the most profitable one
+pub(crate) fn remove_overlapping_bundles(
+    sorted_mev: &mut FastHashMap<MevType, Vec<Bundle>>,
+    keep: impl Fn(&Bundle) -> bool,
+) {
+    let mut bundles = sorted_mev
+        .drain()
+        .flat_map(|(_, bundles)| bundles)
+        .filter(|bundle| keep(bundle))
+        .map(|bundle| (searcher_tx_hashes(&bundle), bundle))
+        .collect::<Vec<_>>();
+
+    bundles.sort_by(|(a_txs, a), (b_txs, b)| {
+        b_txs
+            .len()
+            .cmp(&a_txs.len())
+            .then_with(|| b.header.profit_usd.total_cmp(&a.header.profit_usd))
+            .then_with(|| a.header.tx_hash.cmp(&b.header.tx_hash))
+    });
+
+    let mut claimed = FastHashSet::default();
+    let bundles = bundles
+        .into_iter()
+        .filter_map(|(txs, bundle)| {
+            if txs.iter().any(|tx| claimed.contains(tx)) {
+                return None
+            }
+            claimed.extend(txs);
+            Some(bundle)
+        })
+        .collect::<Vec<_>>();
+
+    *sorted_mev = sort_mev_by_type(bundles);
+}
+
 fn update_mev_count(mev_count: &mut MevCount, mev_type: MevType, count: u64) {
     match mev_type {
         MevType::Sandwich => mev_count.sandwich_count = Some(count),
@@ -191,27 +260,32 @@ fn update_mev_count(mev_count: &mut MevCount, mev_type: MevType, count: u64) {
         MevType::AtomicArb => mev_count.atomic_backrun_count = Some(count),
         MevType::Liquidation => mev_count.liquidation_count = Some(count),
         MevType::SearcherTx => mev_count.searcher_tx_count = Some(count),
-        MevType::Unknown => (),
+        MevType::NftMev | MevType::OracleManipulation | MevType::LaunchSnipe | MevType::Unknown => {
+            ()
+        }
     }
 }
 
 #[derive(Debug)]
 pub struct BlockPnL {
     // ETH profit made by the block builder (in wei)
-    pub builder_eth_profit:      i128,
+    pub builder_eth_profit:        i128,
     // Amount of ETH paid by the builder to sponsor transactions in the block
-    pub builder_sponsorship:     i128,
+    pub builder_sponsorship:       i128,
     // USD profit of the builders searchers
-    pub builder_mev_profit_usd:  f64,
+    pub builder_mev_profit_usd:    f64,
     // ETH reward paid to the proposer (in wei)
-    pub mev_reward:              Option<u128>,
+    pub mev_reward:                Option<u128>,
     // Address of the proposer fee recipient
-    pub proposer_fee_recipient:  Option<Address>,
+    pub proposer_fee_recipient:    Option<Address>,
     // Gas & Tips paid to the builder by it's own vertically integrated
     // searchers
-    pub builder_searcher_tip:    u128,
+    pub builder_searcher_tip:      u128,
     // If the block was bid adjusted using ultrasound's bid adjustment
-    pub ultrasound_bid_adjusted: bool,
+    pub ultrasound_bid_adjusted:   bool,
+    // Withdrawals credited to the builder or proposer fee recipient (in wei),
+    // these are never counted as a builder payment
+    pub fee_recipient_withdrawals: u128,
 }
 
 impl BlockPnL {
@@ -223,6 +297,7 @@ impl BlockPnL {
         proposer_fee_recipient: Option<Address>,
         builder_searcher_tip: u128,
         ultrasound_bid_adjusted: bool,
+        fee_recipient_withdrawals: u128,
     ) -> Self {
         Self {
             builder_eth_profit,
@@ -232,6 +307,7 @@ impl BlockPnL {
             proposer_fee_recipient,
             builder_searcher_tip,
             ultrasound_bid_adjusted,
+            fee_recipient_withdrawals,
         }
     }
 }
@@ -239,7 +315,9 @@ impl BlockPnL {
 /// Calculate builder's block PnL
 ///
 /// Accounts for ultrasound relay bid adjustments, builder transaction
-/// sponsorship & vertically integrated searcher builder profit
+/// sponsorship & vertically integrated searcher builder profit. The builder's
+/// payments are taken from the transactions in the block only, so withdrawals
+/// to the fee recipient are kept out of the PnL and reported separately.
 pub fn calculate_builder_profit(
     tree: Arc<BlockTree<Action>>,
     metadata: &Arc<Metadata>,
@@ -293,6 +371,9 @@ pub fn calculate_builder_profit(
         proposer_fee_recipient,
     );
 
+    let fee_recipient_withdrawals =
+        calculate_fee_recipient_withdrawals(metadata, builder_address, proposer_fee_recipient);
+
     BlockPnL::new(
         builder_payments - builder_sponsorship_amount - proposer_mev_reward,
         builder_sponsorship_amount,
@@ -301,9 +382,28 @@ pub fn calculate_builder_profit(
         proposer_fee_recipient,
         vertically_integrated_searcher_tip,
         bid_adjusted,
+        fee_recipient_withdrawals,
     )
 }
 
+/// Sums the withdrawals paid to the builder and the proposer fee recipient.
+/// Both can be validator withdrawal addresses, in which case their balance
+/// increase over the block isn't only from block payments.
+fn calculate_fee_recipient_withdrawals(
+    metadata: &Metadata,
+    builder_address: Address,
+    proposer_fee_recipient: Option<Address>,
+) -> u128 {
+    let builder_withdrawals = metadata.withdrawals_to(builder_address);
+
+    match proposer_fee_recipient {
+        Some(recipient) if recipient != builder_address => {
+            builder_withdrawals + metadata.withdrawals_to(recipient)
+        }
+        _ => builder_withdrawals,
+    }
+}
+
 fn proposer_payment(
     tree: &Arc<BlockTree<Action>>,
     builder_address: Address,
@@ -471,3 +571,159 @@ fn calculate_block_mev_stats(orchestra_data: &[Bundle], base_fee: u128) -> (u128
         },
     )
 }
+
+/// Recounts the bundles of a block and sums their profit and bribes again, for
+/// when its bundles were swapped out after the block was composed, as
+/// `analyze promote` does. What the bundles paid in priority fees and what the
+/// builder and proposer made are left as they were, they need the block's tree
+pub fn refresh_bundle_summary(block: &mut MevBlock, bundles: &[Bundle]) {
+    let (mev_count, _) = filter_and_count_bundles(sort_mev_by_type(bundles.to_vec()), |_| true);
+
+    block.mev_count = mev_count;
+    block.total_mev_profit_usd = bundles
+        .iter()
+        .filter(|bundle| bundle.mev_type() != MevType::SearcherTx)
+        .map(|bundle| bundle.header.profit_usd)
+        .sum();
+    block.total_mev_bribe = bundles.iter().map(|bundle| bundle.data.bribe()).sum();
+}
+
+#[cfg(test)]
+mod tests {
+    use brontes_types::{
+        mev::{AtomicArb, BundleHeader, CexDex, Sandwich},
+        MultiBlockData,
+    };
+
+    use super::*;
+    use crate::{
+        thresholds::{BundleThresholds, ThresholdConfig},
+        Inspectors,
+    };
+
+    struct ThresholdInspector(BundleThresholds);
+
+    impl Inspector for ThresholdInspector {
+        type Result = Vec<Bundle>;
+
+        fn get_id(&self) -> &str {
+            "Threshold"
+        }
+
+        fn inspect_block(&self, _: MultiBlockData) -> Self::Result {
+            vec![]
+        }
+
+        fn get_quote_token(&self) -> Address {
+            Address::ZERO
+        }
+
+        fn keeps(&self, bundle: &Bundle) -> bool {
+            self.0.keeps(&bundle.header)
+        }
+    }
+
+    fn bundle(mev_type: MevType, profit_usd: f64, data: BundleData) -> Bundle {
+        Bundle { header: BundleHeader { mev_type, profit_usd, ..Default::default() }, data }
+    }
+
+    #[test]
+    fn test_remove_overlapping_bundles() {
+        let arb_tx = B256::repeat_byte(1);
+        let victim_tx = B256::repeat_byte(2);
+
+        let sandwich = bundle(
+            MevType::Sandwich,
+            10.0,
+            BundleData::Sandwich(Sandwich {
+                frontrun_tx_hash: vec![B256::repeat_byte(3)],
+                victim_swaps_tx_hashes: vec![vec![victim_tx]],
+                backrun_tx_hash: B256::repeat_byte(4),
+                ..Default::default()
+            }),
+        );
+        // mev of its own landing between the frontrun and backrun
+        let victim_arb = bundle(
+            MevType::AtomicArb,
+            5.0,
+            BundleData::AtomicArb(AtomicArb { tx_hash: victim_tx, ..Default::default() }),
+        );
+        // no precedence rule between the two, the more profitable one is kept
+        let arb = bundle(
+            MevType::AtomicArb,
+            1.0,
+            BundleData::AtomicArb(AtomicArb { tx_hash: arb_tx, ..Default::default() }),
+        );
+        let cex_dex = bundle(
+            MevType::CexDexTrades,
+            2.0,
+            BundleData::CexDex(CexDex { tx_hash: arb_tx, ..Default::default() }),
+        );
+
+        let mut sorted_mev = sort_mev_by_type(vec![sandwich, victim_arb, arb, cex_dex]);
+        remove_overlapping_bundles(&mut sorted_mev, |bundle| is_kept(bundle, &[], false));
+
+        assert_eq!(sorted_mev[&MevType::Sandwich].len(), 1);
+        assert_eq!(sorted_mev[&MevType::CexDexTrades].len(), 1);
+        let arbs = &sorted_mev[&MevType::AtomicArb];
+        assert_eq!(arbs.len(), 1);
+        assert_eq!(arbs[0].data.mev_transaction_hashes(), vec![victim_tx]);
+    }
+
+    #[test]
+    fn test_is_kept() {
+        let config = ThresholdConfig::parse("[AtomicArb]\nmin_profit_usd = 10.0").unwrap();
+        let inspector = ThresholdInspector(config.for_inspector(Inspectors::AtomicArb));
+        let orchestra: [&dyn Inspector<Result = Vec<Bundle>>; 1] = [&inspector];
+
+        let arb = |profit_usd| {
+            bundle(MevType::AtomicArb, profit_usd, BundleData::AtomicArb(Default::default()))
+        };
+        assert!(is_kept(&arb(20.0), &orchestra, false));
+        // under the inspector's threshold
+        assert!(!is_kept(&arb(5.0), &orchestra, false));
+
+        // losing sandwiches are only kept when tracking losses
+        let sandwich = bundle(MevType::Sandwich, -5.0, BundleData::Sandwich(Default::default()));
+        assert!(!is_kept(&sandwich, &orchestra, false));
+        assert!(is_kept(&sandwich, &orchestra, true));
+        // tracking losses doesn't get around the thresholds
+        assert!(!is_kept(&arb(-5.0), &orchestra, true));
+    }
+
+    #[test]
+    fn test_refresh_bundle_summary() {
+        let mut block = MevBlock {
+            mev_count: MevCount { bundle_count: 1, sandwich_count: Some(1), ..Default::default() },
+            total_mev_profit_usd: 10.0,
+            total_mev_bribe: 100,
+            ..Default::default()
+        };
+
+        // the sandwich was replaced by two arbs
+        let arb = |profit_usd, bribe| {
+            bundle(
+                MevType::AtomicArb,
+                profit_usd,
+                BundleData::AtomicArb(AtomicArb {
+                    gas_details: GasDetails {
+                        coinbase_transfer: Some(bribe),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                }),
+            )
+        };
+        let searcher_tx =
+            bundle(MevType::SearcherTx, 50.0, BundleData::Unknown(Default::default()));
+        refresh_bundle_summary(&mut block, &[arb(2.0, 20), arb(3.0, 30), searcher_tx]);
+
+        assert_eq!(block.mev_count.bundle_count, 3);
+        assert_eq!(block.mev_count.sandwich_count, None);
+        assert_eq!(block.mev_count.atomic_backrun_count, Some(2));
+        assert_eq!(block.mev_count.searcher_tx_count, Some(1));
+        // a searcher transaction's profit isn't mev
+        assert_eq!(block.total_mev_profit_usd, 5.0);
+        assert_eq!(block.total_mev_bribe, 50);
+    }
+}
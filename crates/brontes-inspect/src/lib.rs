@@ -38,6 +38,27 @@
 //! Each inspector implements the `Inspector` trait and provides its own
 //! implementation of the `inspect_block` method.
 //!
+//! ## Multi Block Inspectors
+//!
+//! An inspector that needs to see across blocks, like the
+//! `MultiBlockSandwichInspector` for sandwiches whose frontrun lands a block
+//! before the backrun, sets `Inspector::block_window`. It is then given the
+//! last `block_window` blocks' trees and metadata together, up to
+//! `MAX_BLOCK_WINDOW`, and isn't run until that many blocks have been seen.
+//!
+//! ## Custom Inspectors
+//!
+//! Crates depending on `brontes_inspect` can add their own inspectors without
+//! forking it. An inspector registered through `Inspectors::register` runs
+//! over the same block tree as the built in ones and its bundles are composed
+//! and deduplicated with theirs. `composer::run_registered_inspection` runs
+//! the registered inspectors on their own.
+//!
+//! ```ignore
+//! Inspectors::register(Box::new(MyInspector::new(quote_token, db)));
+//! let results = run_registered_inspection(data, db, false);
+//! ```
+//!
 //! ## Composer
 //!
 //! The `Composer` is a special type of inspector that combines the results of
@@ -60,6 +81,7 @@
 pub mod composer;
 pub mod discovery;
 pub mod mev_inspectors;
+pub mod thresholds;
 use brontes_metrics::inspectors::OutlierMetrics;
 use mev_inspectors::searcher_activity::SearcherActivity;
 pub use mev_inspectors::*;
@@ -67,6 +89,8 @@ pub use mev_inspectors::*;
 #[cfg(feature = "tests")]
 pub mod test_utils;
 
+use std::sync::RwLock;
+
 use alloy_primitives::Address;
 use atomic_arb::AtomicArbInspector;
 use brontes_types::{
@@ -75,22 +99,30 @@ use brontes_types::{
         metadata::Metadata,
         traits::LibmdbxReader,
     },
-    mev::{Bundle, BundleData},
+    mev::{Bundle, BundleData, MevType},
     normalized_actions::Action,
     tree::BlockTree,
     MultiBlockData,
 };
 use cex_dex::{markout::CexDexMarkoutInspector, quotes::CexDexQuotesInspector};
 use jit::JitCexDex;
+use launch_snipe::LaunchSnipeInspector;
 use liquidations::LiquidationInspector;
-use sandwich::SandwichInspector;
+use long_tail::LongTailInspector;
+use nft::NftInspector;
+use oracle_manipulation::OracleManipulationInspector;
+use sandwich::{MultiBlockSandwichInspector, SandwichInspector};
+use thresholds::ThresholdConfig;
 
 use crate::jit::jit_liquidity::JitInspector;
 
 pub trait Inspector: Send + Sync {
     type Result: Send + Sync;
 
-    /// default is 1
+    /// How many consecutive blocks, ending with the one being inspected, the
+    /// inspector is given. Inspectors keep no state of their own between
+    /// blocks; the earlier blocks in the window are how they see across
+    /// them. Capped at [`MAX_BLOCK_WINDOW`], default is 1
     fn block_window(&self) -> usize {
         1
     }
@@ -98,6 +130,13 @@ pub trait Inspector: Send + Sync {
     fn get_id(&self) -> &str;
     fn inspect_block(&self, data: MultiBlockData) -> Self::Result;
     fn get_quote_token(&self) -> Address;
+    /// Whether the bundle passes the thresholds the inspector was built with.
+    /// Checked by the composer once the block's bundles are deduplicated, so
+    /// it's asked about every bundle, including the types it doesn't produce.
+    /// Default keeps all of them
+    fn keeps(&self, _bundle: &Bundle) -> bool {
+        true
+    }
 }
 
 #[derive(
@@ -112,49 +151,79 @@ pub enum Inspectors {
     SearcherActivity,
     CexDexMarkout,
     JitCexDex,
+    NftMev,
+    OracleManipulation,
+    LongTail,
+    LaunchSnipe,
+    MultiBlockSandwich,
 }
 
+/// The most blocks an inspector can be given at once, see
+/// [`Inspector::block_window`]. Every block in the largest window is held in
+/// memory, so one inspector asking for a long history would slow down all of
+/// them
+pub const MAX_BLOCK_WINDOW: usize = 8;
+
 type DynMevInspector = &'static (dyn Inspector<Result = Vec<Bundle>> + 'static);
 
+/// Inspectors from outside this crate, see [`Inspectors::register`]
+static REGISTERED_INSPECTORS: RwLock<Vec<DynMevInspector>> = RwLock::new(Vec::new());
+
 impl Inspectors {
+    /// Builds the inspector with its `thresholds`. With `track_losses` the
+    /// inspectors keep bundles that lost money after gas instead of dropping
+    /// them, so failed and negative ev searcher activity can be studied, the
+    /// composer has to be given the same flag
     pub fn init_mev_inspector<DB: LibmdbxReader>(
         &self,
         quote_token: Address,
         db: &'static DB,
         cex_exchanges: &[CexExchange],
         trade_config: CexDexTradeConfig,
+        thresholds: &ThresholdConfig,
+        track_losses: bool,
         metrics: Option<OutlierMetrics>,
     ) -> DynMevInspector {
+        let thresholds = thresholds.for_inspector(*self);
         match &self {
-            Self::AtomicArb => {
-                static_object(AtomicArbInspector::new(quote_token, db, metrics)) as DynMevInspector
-            }
-            Self::Jit => {
-                static_object(JitInspector::new(quote_token, db, metrics)) as DynMevInspector
-            }
+            Self::AtomicArb => static_object(AtomicArbInspector::new(
+                quote_token,
+                db,
+                thresholds,
+                track_losses,
+                metrics,
+            )) as DynMevInspector,
+            Self::Jit => static_object(JitInspector::new(quote_token, db, thresholds, metrics))
+                as DynMevInspector,
 
             Self::CexDex => static_object(CexDexQuotesInspector::new(
                 quote_token,
                 db,
                 cex_exchanges,
                 trade_config.quote_offset_from_block_us,
+                thresholds,
+                track_losses,
                 metrics,
             )) as DynMevInspector,
             Self::Sandwich => {
-                static_object(SandwichInspector::new(quote_token, db, metrics)) as DynMevInspector
+                static_object(SandwichInspector::new(quote_token, db, thresholds, metrics))
+                    as DynMevInspector
             }
             Self::Liquidations => {
-                static_object(LiquidationInspector::new(quote_token, db, metrics))
+                static_object(LiquidationInspector::new(quote_token, db, thresholds, metrics))
                     as DynMevInspector
             }
             Self::SearcherActivity => {
-                static_object(SearcherActivity::new(quote_token, db, metrics)) as DynMevInspector
+                static_object(SearcherActivity::new(quote_token, db, thresholds, metrics))
+                    as DynMevInspector
             }
             Self::CexDexMarkout => static_object(CexDexMarkoutInspector::new(
                 quote_token,
                 db,
                 cex_exchanges,
                 trade_config,
+                thresholds,
+                track_losses,
                 metrics,
             )) as DynMevInspector,
             Self::JitCexDex => static_object(JitCexDex {
@@ -163,10 +232,73 @@ impl Inspectors {
                     db,
                     cex_exchanges,
                     trade_config,
+                    thresholds.clone(),
+                    track_losses,
                     metrics.clone(),
                 ),
-                jit:     JitInspector::new(quote_token, db, metrics),
+                jit:     JitInspector::new(quote_token, db, thresholds, metrics),
             }) as DynMevInspector,
+            Self::NftMev => static_object(NftInspector::new(quote_token, db, thresholds, metrics))
+                as DynMevInspector,
+            Self::OracleManipulation => static_object(OracleManipulationInspector::new(
+                quote_token,
+                db,
+                thresholds,
+                metrics,
+            )) as DynMevInspector,
+            Self::LongTail => static_object(LongTailInspector::new(
+                quote_token,
+                db,
+                thresholds,
+                track_losses,
+                metrics,
+            )) as DynMevInspector,
+            Self::LaunchSnipe => {
+                static_object(LaunchSnipeInspector::new(quote_token, db, thresholds, metrics))
+                    as DynMevInspector
+            }
+            Self::MultiBlockSandwich => static_object(MultiBlockSandwichInspector::new(
+                quote_token,
+                db,
+                thresholds,
+                metrics,
+            )) as DynMevInspector,
+        }
+    }
+
+    /// Adds an inspector defined outside this crate. It runs over every block
+    /// after the built in ones and its bundles go through the composer with
+    /// theirs, deduplicated by the bundle types it produces. Registering has to
+    /// happen before the inspectors are initialized for it to be picked up
+    pub fn register(inspector: Box<dyn Inspector<Result = Vec<Bundle>>>) {
+        REGISTERED_INSPECTORS
+            .write()
+            .unwrap()
+            .push(Box::leak(inspector));
+    }
+
+    /// The inspectors added through [`Inspectors::register`], in the order
+    /// they were registered
+    pub fn registered() -> Vec<DynMevInspector> {
+        REGISTERED_INSPECTORS.read().unwrap().clone()
+    }
+
+    /// The bundle types this inspector produces when run on its own
+    pub fn mev_types(&self) -> &'static [MevType] {
+        match self {
+            Self::AtomicArb => &[MevType::AtomicArb],
+            Self::CexDex => &[MevType::CexDexQuotes],
+            Self::Jit => &[MevType::Jit],
+            Self::Liquidations => &[MevType::Liquidation],
+            Self::Sandwich => &[MevType::Sandwich],
+            Self::SearcherActivity => &[MevType::SearcherTx],
+            Self::CexDexMarkout => &[MevType::CexDexTrades, MevType::CexDexRfq],
+            Self::JitCexDex => &[MevType::JitCexDex],
+            Self::NftMev => &[MevType::NftMev],
+            Self::OracleManipulation => &[MevType::OracleManipulation],
+            Self::LongTail => &[MevType::Unknown],
+            Self::LaunchSnipe => &[MevType::LaunchSnipe],
+            Self::MultiBlockSandwich => &[MevType::Sandwich],
         }
     }
 }
@@ -174,3 +306,35 @@ impl Inspectors {
 fn static_object<T>(obj: T) -> &'static T {
     &*Box::leak(Box::new(obj))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct CustomInspector;
+
+    impl Inspector for CustomInspector {
+        type Result = Vec<Bundle>;
+
+        fn get_id(&self) -> &str {
+            "Custom"
+        }
+
+        fn inspect_block(&self, _: MultiBlockData) -> Self::Result {
+            vec![]
+        }
+
+        fn get_quote_token(&self) -> Address {
+            Address::ZERO
+        }
+    }
+
+    #[test]
+    fn test_register_inspector() {
+        Inspectors::register(Box::new(CustomInspector));
+
+        assert!(Inspectors::registered()
+            .iter()
+            .any(|inspector| inspector.get_id() == "Custom"));
+    }
+}
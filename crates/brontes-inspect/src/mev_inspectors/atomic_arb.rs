@@ -7,8 +7,8 @@ use brontes_types::{
     db::dex::PriceAt,
     mev::{AtomicArb, AtomicArbType, Bundle, BundleData, MevType},
     normalized_actions::{
-        accounting::ActionAccounting, Action, NormalizedEthTransfer, NormalizedSwap,
-        NormalizedTransfer,
+        accounting::ActionAccounting, Action, NormalizedEthTransfer, NormalizedRedemption,
+        NormalizedSwap, NormalizedTransfer, NormalizedWrap,
     },
     BlockData, FastHashSet, IntoZip, MultiBlockData, ToFloatNearest, TreeBase, TreeCollector,
     TreeSearchBuilder, TxInfo,
@@ -17,18 +17,32 @@ use itertools::Itertools;
 use malachite::{num::basic::traits::Zero, Rational};
 use reth_primitives::{Address, B256};
 
-use crate::{shared_utils::SharedInspectorUtils, BlockTree, Inspector, Metadata, MAX_PROFIT};
+use crate::{
+    shared_utils::SharedInspectorUtils, thresholds::BundleThresholds, BlockTree, Inspector,
+    Metadata, MAX_PROFIT,
+};
 
 const MAX_PRICE_DIFF: Rational = Rational::const_from_unsigneds(99995, 100000);
 
 // figure out why
 pub struct AtomicArbInspector<'db, DB: LibmdbxReader> {
-    utils: SharedInspectorUtils<'db, DB>,
+    utils:        SharedInspectorUtils<'db, DB>,
+    /// keep arbs that lost money, see `Inspectors::init_mev_inspector`
+    track_losses: bool,
 }
 
 impl<'db, DB: LibmdbxReader> AtomicArbInspector<'db, DB> {
-    pub fn new(quote: Address, db: &'db DB, metrics: Option<OutlierMetrics>) -> Self {
-        Self { utils: SharedInspectorUtils::new(quote, db, metrics) }
+    pub fn new(
+        quote: Address,
+        db: &'db DB,
+        thresholds: BundleThresholds,
+        track_losses: bool,
+        metrics: Option<OutlierMetrics>,
+    ) -> Self {
+        Self {
+            utils: SharedInspectorUtils::new(quote, db, metrics).with_thresholds(thresholds),
+            track_losses,
+        }
     }
 }
 
@@ -48,6 +62,10 @@ impl<DB: LibmdbxReader> Inspector for AtomicArbInspector<'_, DB> {
         self.utils.quote
     }
 
+    fn keeps(&self, bundle: &Bundle) -> bool {
+        self.utils.thresholds.keeps(&bundle.header)
+    }
+
     fn inspect_block(&self, data: MultiBlockData) -> Self::Result {
         let BlockData { metadata, tree } = data.get_most_recent_block();
 
@@ -57,6 +75,8 @@ impl<DB: LibmdbxReader> Inspector for AtomicArbInspector<'_, DB> {
                     Action::is_swap,
                     Action::is_transfer,
                     Action::is_eth_transfer,
+                    Action::is_wrap,
+                    Action::is_redemption,
                     Action::is_nested_action,
                 ]))
                 .t_full_map(|(tree, v)| {
@@ -64,8 +84,15 @@ impl<DB: LibmdbxReader> Inspector for AtomicArbInspector<'_, DB> {
                     (
                         tree.get_tx_info_batch(&tx_hashes, self.utils.db),
                         v.into_iter().map(|v| {
+                            // redemption arbs are usually funded by a flash loan
                             self.utils
-                                .flatten_nested_actions_default(v.into_iter())
+                                .flatten_nested_actions(v.into_iter(), &|action| {
+                                    action.is_swap()
+                                        || action.is_transfer()
+                                        || action.is_eth_transfer()
+                                        || action.is_wrap()
+                                        || action.is_redemption()
+                                })
                                 .collect::<Vec<_>>()
                         }),
                     )
@@ -75,6 +102,12 @@ impl<DB: LibmdbxReader> Inspector for AtomicArbInspector<'_, DB> {
                     let info = info??;
                     let actions = action?;
 
+                    // refinancing a lending position can route through swaps that look
+                    // like an arb, these are never mev.
+                    if tree.tx_must_contain_action(info.tx_hash, Action::is_collateral_swap)? {
+                        return None
+                    }
+
                     self.process_swaps(
                         data.per_block_data
                             .iter()
@@ -84,10 +117,12 @@ impl<DB: LibmdbxReader> Inspector for AtomicArbInspector<'_, DB> {
                         metadata.clone(),
                         actions
                             .into_iter()
-                            .split_actions::<(Vec<_>, Vec<_>, Vec<_>), _>((
+                            .split_actions::<(Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>), _>((
                                 Action::try_swaps_merged,
                                 Action::try_transfer,
                                 Action::try_eth_transfer,
+                                Action::try_wrap,
+                                Action::try_redemption,
                             )),
                     )
                 })
@@ -107,10 +142,18 @@ impl<DB: LibmdbxReader> AtomicArbInspector<'_, DB> {
         trees: Vec<Arc<BlockTree<Action>>>,
         info: TxInfo,
         metadata: Arc<Metadata>,
-        data: (Vec<NormalizedSwap>, Vec<NormalizedTransfer>, Vec<NormalizedEthTransfer>),
+        data: (
+            Vec<NormalizedSwap>,
+            Vec<NormalizedTransfer>,
+            Vec<NormalizedEthTransfer>,
+            Vec<NormalizedWrap>,
+            Vec<NormalizedRedemption>,
+        ),
     ) -> Option<Bundle> {
         tracing::trace!(?info, "trying atomic");
-        let (mut swaps, transfers, eth_transfers) = data;
+        let (mut swaps, transfers, eth_transfers, wraps, redemptions) = data;
+        // redeeming a stablecoin bought below peg closes the arb like a swap would
+        swaps.extend(redemptions.iter().map(NormalizedRedemption::as_swap));
         let mev_addresses: FastHashSet<Address> = info.collect_address_set_for_accounting();
 
         let mut ignore_addresses = mev_addresses.clone();
@@ -127,6 +170,7 @@ impl<DB: LibmdbxReader> AtomicArbInspector<'_, DB> {
             .into_iter()
             .map(Action::from)
             .chain(eth_transfers.into_iter().map(Action::from))
+            .chain(wraps.into_iter().map(Action::from))
             .chain(info.get_total_eth_value().iter().cloned().map(Action::from))
             .account_for_actions();
 
@@ -173,7 +217,8 @@ impl<DB: LibmdbxReader> AtomicArbInspector<'_, DB> {
             profit = Rational::ZERO;
         }
 
-        let is_profitable = profit > Rational::ZERO;
+        // when tracking losses an arb that lost money is still an arb
+        let is_profitable = profit > Rational::ZERO || self.track_losses;
 
         let requirement_multiplier = if has_dex_price { 1 } else { 2 };
 
@@ -198,6 +243,7 @@ impl<DB: LibmdbxReader> AtomicArbInspector<'_, DB> {
         // given we have a atomic arb now, we will go and try to find the trigger
         // transaction that lead to this arb.
         let trigger_tx = self.find_trigger_tx(&info, trees, &swaps);
+        let (pool_path, token_path) = AtomicArb::token_cycle(&swaps);
 
         let backrun = AtomicArb {
             block_number: metadata.block_num,
@@ -206,6 +252,8 @@ impl<DB: LibmdbxReader> AtomicArbInspector<'_, DB> {
             gas_details: info.gas_details,
             swaps,
             arb_type: possible_arb_type,
+            pool_path,
+            token_path,
         };
         let data = BundleData::AtomicArb(backrun);
 
@@ -16,7 +16,7 @@ use brontes_types::{
         CexExchange,
     },
     display::utils::format_etherscan_url,
-    mev::{Bundle, BundleData, MevType, OptimisticTrade},
+    mev::{Bundle, BundleData, MevType, OptimisticTrade, StatArbDetails},
     normalized_actions::{
         accounting::{ActionAccounting, AddressDeltas},
         Action, NormalizedBatch, NormalizedSwap,
@@ -39,19 +39,25 @@ use tracing::trace;
 
 use super::{
     log_cex_trade_price_delta, ArbLeg, CexDexProcessing, CexPricesForSwaps, ExchangeLegCexPrice,
-    OptimisticDetails, PossibleCexDex, PriceCalcType,
+    OptimisticDetails, PossibleCexDex, PriceCalcType, TakerFees,
 };
 
 // The threshold for the number of CEX-DEX trades an address is required to make
 // to classify a a negative pnl cex-dex trade as a CEX-DEX trade
 pub const FILTER_THRESHOLD: u64 = 20;
 
-use crate::{shared_utils::SharedInspectorUtils, Inspector, Metadata};
+use crate::{
+    shared_utils::SharedInspectorUtils, thresholds::BundleThresholds, Inspector, Metadata,
+};
 
 pub struct CexDexMarkoutInspector<'db, DB: LibmdbxReader> {
     pub utils:     SharedInspectorUtils<'db, DB>,
     trade_config:  CexDexTradeConfig,
     cex_exchanges: Vec<CexExchange>,
+    taker_fees:    TakerFees,
+    /// keep the losing trades of known cex-dex bots, see
+    /// `Inspectors::init_mev_inspector`
+    track_losses:  bool,
 }
 
 impl<'db, DB: LibmdbxReader> CexDexMarkoutInspector<'db, DB> {
@@ -60,12 +66,16 @@ impl<'db, DB: LibmdbxReader> CexDexMarkoutInspector<'db, DB> {
         db: &'db DB,
         cex_exchanges: &[CexExchange],
         trade_config: CexDexTradeConfig,
+        thresholds: BundleThresholds,
+        track_losses: bool,
         metrics: Option<OutlierMetrics>,
     ) -> Self {
         Self {
-            utils: SharedInspectorUtils::new(quote, db, metrics),
+            utils: SharedInspectorUtils::new(quote, db, metrics).with_thresholds(thresholds),
             trade_config,
             cex_exchanges: cex_exchanges.to_owned(),
+            taker_fees: TakerFees::load_from_config(),
+            track_losses,
         }
     }
 }
@@ -81,6 +91,10 @@ impl<DB: LibmdbxReader> Inspector for CexDexMarkoutInspector<'_, DB> {
         self.utils.quote
     }
 
+    fn keeps(&self, bundle: &Bundle) -> bool {
+        self.utils.thresholds.keeps(&bundle.header)
+    }
+
     fn inspect_block(&self, data: MultiBlockData) -> Self::Result {
         let block = data.get_most_recent_block();
         let BlockData { metadata, tree } = block;
@@ -300,6 +314,7 @@ impl<DB: LibmdbxReader> CexDexMarkoutInspector<'_, DB> {
             self.cex_prices_for_swaps(dex_swaps, metadata, marked_cex_dex, tx_info.tx_hash);
 
         let merged_swaps = cex_prices.dex_swaps.clone();
+        let venues_considered = cex_prices.venues_considered.clone();
 
         let global_vwam: Option<PossibleCexDex> =
             self.process_global_vwam(&cex_prices, metadata, tx_info);
@@ -309,7 +324,76 @@ impl<DB: LibmdbxReader> CexDexMarkoutInspector<'_, DB> {
         let optimstic_res: Option<OptimisticDetails> =
             self.process_optimistic(cex_prices, metadata, tx_info);
 
-        CexDexProcessing::new(merged_swaps, global_vwam, per_exchange_pnl, optimstic_res)
+        let mut processing = CexDexProcessing::new(
+            merged_swaps,
+            global_vwam,
+            per_exchange_pnl,
+            optimstic_res,
+            venues_considered,
+        )?;
+        processing.markout_details = self.markout_details(&processing, metadata, tx_info);
+
+        Some(processing)
+    }
+
+    /// Prices each swap against the cex trades in windows at the configured
+    /// markouts after the block, and what closing the swap's position at those
+    /// prices would have made after taker fees. The usd price of a swap's input
+    /// is taken from its leg of the optimal route, swaps without one aren't
+    /// marked out
+    fn markout_details(
+        &self,
+        processing: &CexDexProcessing,
+        metadata: &Metadata,
+        tx_info: &TxInfo,
+    ) -> Vec<StatArbDetails> {
+        let (Some(trades), Some(best)) = (&metadata.cex_trades, &processing.max_profit) else {
+            return vec![]
+        };
+        let block_timestamp = metadata.microseconds_block_timestamp();
+        let searchers = tx_info
+            .mev_contract
+            .into_iter()
+            .chain([tx_info.eoa])
+            .collect::<Vec<_>>();
+        let searchers = searchers.as_slice();
+
+        processing
+            .dex_swaps
+            .iter()
+            .zip(&best.arb_legs)
+            .enumerate()
+            .filter_map(|(idx, (swap, leg))| Some((idx, swap, leg.as_ref()?)))
+            .flat_map(|(idx, swap, leg)| {
+                let pair = Pair(swap.token_in.address, swap.token_out.address);
+                self.trade_config
+                    .markouts_us()
+                    .filter_map(move |markout_us| {
+                        let (window_start, window_end) = self
+                            .trade_config
+                            .markout_window(block_timestamp, markout_us);
+                        let (cex_price, taker_fee) = trades.markout_price(
+                            &self.cex_exchanges,
+                            pair,
+                            window_start,
+                            window_end,
+                            |exchange| self.taker_fees.taker_fee(exchange, searchers),
+                        )?;
+                        let proceeds = &cex_price * &swap.amount_out * (Rational::ONE - &taker_fee);
+                        let pnl = (proceeds - &swap.amount_in) * &leg.token_price.price0;
+
+                        Some(StatArbDetails {
+                            swap_idx: idx as u64,
+                            markout_us,
+                            window_start,
+                            window_end,
+                            cex_price,
+                            taker_fee,
+                            pnl,
+                        })
+                    })
+            })
+            .collect()
     }
 
     fn process_global_vwam(
@@ -347,7 +431,7 @@ impl<DB: LibmdbxReader> CexDexMarkoutInspector<'_, DB> {
         tx_info: &TxInfo,
     ) -> Vec<Option<PossibleCexDex>> {
         cex_prices
-            .per_exchange_trades(self.cex_exchanges.as_slice())
+            .per_exchange_trades(cex_prices.venues_considered.as_slice())
             .into_iter()
             .map(|(exchange, exchange_paths)| {
                 let arb_legs: Vec<Option<ArbLeg>> = cex_prices
@@ -517,24 +601,78 @@ impl<DB: LibmdbxReader> CexDexMarkoutInspector<'_, DB> {
     ) -> CexPricesForSwaps {
         let merged_swaps = SharedInspectorUtils::<DB>::cex_merge_possible_swaps(dex_swaps);
 
+        let mut feasible = Vec::new();
         let (time_window_vwam, optimistic): (Vec<_>, Vec<_>) = merged_swaps
             .clone()
             .iter()
             .filter(|swap| swap.amount_out != Rational::ZERO)
-            .map(|swap| self.calculate_cex_price(swap, metadata, marked_cex_dex, tx_hash))
+            .map(|swap| {
+                let venues = self.feasible_venues(swap);
+                let prices =
+                    self.calculate_cex_price(swap, &venues, metadata, marked_cex_dex, tx_hash);
+                feasible.extend(venues);
+                prices
+            })
             .unzip();
 
-        CexPricesForSwaps { dex_swaps: merged_swaps, time_window_vwam, optimistic }
+        let venues_considered = self
+            .cex_exchanges
+            .iter()
+            .filter(|exchange| feasible.contains(exchange))
+            .copied()
+            .collect();
+
+        CexPricesForSwaps {
+            dex_swaps: merged_swaps,
+            time_window_vwam,
+            optimistic,
+            venues_considered,
+        }
+    }
+
+    /// The venues the swap could have been hedged on, those listing both of its
+    /// tokens in a size they accept. Tokens without listings don't restrict the
+    /// venues.
+    fn feasible_venues(&self, swap: &NormalizedSwap) -> Vec<CexExchange> {
+        let listings = |token| self.utils.db.try_fetch_cex_listings(token).ok().flatten();
+        let token_in = listings(swap.token_in.address);
+        let token_out = listings(swap.token_out.address);
+
+        let amount_in = swap.amount_in.clone().to_float();
+        let amount_out = swap.amount_out.clone().to_float();
+
+        self.cex_exchanges
+            .iter()
+            .copied()
+            .filter(|exchange| {
+                token_in
+                    .as_ref()
+                    .map_or(true, |l| l.is_tradable(*exchange, amount_in))
+                    && token_out
+                        .as_ref()
+                        .map_or(true, |l| l.is_tradable(*exchange, amount_out))
+            })
+            .collect()
     }
 
     fn calculate_cex_price(
         &self,
         swap: &NormalizedSwap,
+        venues: &[CexExchange],
         metadata: &Metadata,
         marked_cex_dex: bool,
         tx_hash: FixedBytes<32>,
     ) -> (Option<WindowExchangePrice>, Option<OptimisticPrice>) {
         let pair = Pair(swap.token_in.address, swap.token_out.address);
+        if venues.is_empty() {
+            trace!(
+                target: "brontes::cex-dex-markout",
+                "no venue lists {:?} in the size of the swap\n Tx: {}",
+                pair,
+                format_etherscan_url(&tx_hash)
+            );
+            return (None, None)
+        }
 
         let window_fn = || {
             metadata
@@ -543,7 +681,7 @@ impl<DB: LibmdbxReader> CexDexMarkoutInspector<'_, DB> {
                 .unwrap()
                 .calculate_time_window_vwam(
                     self.trade_config,
-                    &self.cex_exchanges,
+                    venues,
                     pair,
                     &swap.amount_out,
                     metadata.microseconds_block_timestamp(),
@@ -562,7 +700,7 @@ impl<DB: LibmdbxReader> CexDexMarkoutInspector<'_, DB> {
         let optimistic = || {
             metadata.cex_trades.as_ref().unwrap().get_optimistic_vmap(
                 self.trade_config,
-                &self.cex_exchanges,
+                venues,
                 pair,
                 &swap.amount_out,
                 metadata.microseconds_block_timestamp(),
@@ -669,8 +807,14 @@ impl<DB: LibmdbxReader> CexDexMarkoutInspector<'_, DB> {
                     .as_ref()
                     .map_or(false, |contract_type| contract_type.could_be_mev_contract()));
 
-        let is_cex_dex_based_on_historical_activity =
-            is_cex_dex_bot_with_significant_activity || is_labelled_cex_dex_bot;
+        // the pnl checks above only pass profitable trades, when tracking
+        // losses any trade of a bot that has done cex-dex before is kept
+        let is_known_cex_dex_bot_tracking_losses = self.track_losses
+            && (info.is_searcher_of_type(MevType::CexDexTrades) || is_labelled_cex_dex_bot);
+
+        let is_cex_dex_based_on_historical_activity = is_cex_dex_bot_with_significant_activity
+            || is_labelled_cex_dex_bot
+            || is_known_cex_dex_bot_tracking_losses;
 
         if should_include_based_on_pnl
             || is_cex_dex_based_on_historical_activity
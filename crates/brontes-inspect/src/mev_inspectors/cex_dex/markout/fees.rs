@@ -0,0 +1,118 @@
+//! Taker fees paid hedging a cex-dex arb on each exchange. Exchanges charge
+//! less the more a trader does with them, so searchers are assumed to be at
+//! each exchange's best tier, the rate [`CexExchange::fees`] gives, unless
+//! they're known to pay more.
+//!
+//! Tiers are loaded from `config/cex_fee_config.toml`, with the taker fee of
+//! each exchange's tiers and the tier known searchers trade at, keyed by their
+//! eoa or contract.
+use std::path::Path;
+
+use alloy_primitives::Address;
+use brontes_types::{db::cex::CexExchange, FastHashMap};
+use malachite::Rational;
+use serde::Deserialize;
+use tracing::warn;
+
+const CEX_FEE_CONFIG_FILE: &str =
+    concat!(env!("CARGO_MANIFEST_DIR"), "/../../config/cex_fee_config.toml");
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CexFeeConfig {
+    /// the taker fee of each tier, by exchange
+    #[serde(default)]
+    tiers:     FastHashMap<CexExchange, FastHashMap<String, f64>>,
+    /// the tier a searcher is at on each exchange
+    #[serde(default)]
+    searchers: FastHashMap<Address, FastHashMap<CexExchange, String>>,
+}
+
+/// The taker fee of each searcher with a known tier, by exchange
+#[derive(Debug, Clone, Default)]
+pub struct TakerFees(FastHashMap<Address, FastHashMap<CexExchange, Rational>>);
+
+impl TakerFees {
+    pub fn load(path: impl AsRef<Path>) -> eyre::Result<Self> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+
+    pub fn parse(config: &str) -> eyre::Result<Self> {
+        let config: CexFeeConfig = toml::from_str(config)?;
+
+        config
+            .searchers
+            .into_iter()
+            .map(|(searcher, tiers)| {
+                let fees = tiers
+                    .into_iter()
+                    .map(|(exchange, tier)| {
+                        let fee = config
+                            .tiers
+                            .get(&exchange)
+                            .and_then(|tiers| tiers.get(&tier))
+                            .and_then(|fee| Rational::try_from_float_simplest(*fee))
+                            .ok_or_else(|| {
+                                eyre::eyre!("{searcher:?} is at unknown tier {tier} on {exchange}")
+                            })?;
+                        Ok((exchange, fee))
+                    })
+                    .collect::<eyre::Result<_>>()?;
+                Ok((searcher, fees))
+            })
+            .collect::<eyre::Result<_>>()
+            .map(Self)
+    }
+
+    /// loads the tiers from `config/cex_fee_config.toml`, if the file can't be
+    /// loaded every searcher is assumed to be at the best tier
+    pub fn load_from_config() -> Self {
+        Self::load(CEX_FEE_CONFIG_FILE)
+            .inspect_err(|e| warn!(?e, "failed to load cex fee tiers, using the best tiers"))
+            .unwrap_or_default()
+    }
+
+    /// The taker fee on `exchange` of the first of `searchers` with a known
+    /// tier there, or the exchange's best
+    pub fn taker_fee(&self, exchange: CexExchange, searchers: &[Address]) -> Rational {
+        searchers
+            .iter()
+            .find_map(|searcher| self.0.get(searcher)?.get(&exchange).cloned())
+            .unwrap_or_else(|| exchange.fees().1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_taker_fee() {
+        let searcher = Address::repeat_byte(1);
+        let config = format!(
+            r#"
+            [tiers.binance]
+            vip0 = 0.001
+            vip9 = 0.00024
+
+            [searchers."{searcher}"]
+            binance = "vip0"
+            "#
+        );
+        let fees = TakerFees::parse(&config).unwrap();
+
+        assert_eq!(
+            fees.taker_fee(CexExchange::Binance, &[Address::ZERO, searcher]),
+            Rational::from_signeds(1, 1000)
+        );
+        // without a tier the best is assumed
+        assert_eq!(fees.taker_fee(CexExchange::Okex, &[searcher]), CexExchange::Okex.fees().1);
+        assert_eq!(
+            fees.taker_fee(CexExchange::Binance, &[Address::ZERO]),
+            CexExchange::Binance.fees().1
+        );
+
+        let unknown = format!("[searchers.\"{searcher}\"]\nbinance = \"vip1\"");
+        assert!(TakerFees::parse(&unknown).is_err());
+    }
+}
@@ -1,7 +1,9 @@
 mod cex_dex_markout;
+mod fees;
 mod types;
 
 pub use cex_dex_markout::CexDexMarkoutInspector;
+pub use fees::TakerFees;
 pub use types::{
     log_cex_trade_price_delta, ArbDetailsWithPrices, ArbLeg, CexDexProcessing, CexPricesForSwaps,
     ExchangeLegCexPrice, OptimisticDetails, PossibleCexDex, PriceCalcType,
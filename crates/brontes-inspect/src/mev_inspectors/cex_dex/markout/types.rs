@@ -9,7 +9,7 @@ use brontes_types::{
         },
         CexExchange,
     },
-    mev::{ArbDetails, BundleData, CexDex, CexMethodology, OptimisticTrade},
+    mev::{ArbDetails, BundleData, CexDex, CexMethodology, OptimisticTrade, StatArbDetails},
     normalized_actions::NormalizedSwap,
     pair::Pair,
     ToFloatNearest, TxInfo,
@@ -30,9 +30,11 @@ type PerExchangePrices<'a> = Vec<(&'a CexExchange, Vec<Option<(&'a ExchangePath,
 
 #[derive(Debug, Clone)]
 pub struct CexPricesForSwaps {
-    pub dex_swaps:        Vec<NormalizedSwap>,
-    pub time_window_vwam: Vec<Option<WindowExchangePrice>>,
-    pub optimistic:       Vec<Option<OptimisticPrice>>,
+    pub dex_swaps:         Vec<NormalizedSwap>,
+    pub time_window_vwam:  Vec<Option<WindowExchangePrice>>,
+    pub optimistic:        Vec<Option<OptimisticPrice>>,
+    /// Venues that could have hedged at least one of the swaps
+    pub venues_considered: Vec<CexExchange>,
 }
 
 impl CexPricesForSwaps {
@@ -115,6 +117,8 @@ pub struct CexDexProcessing {
     pub per_exchange_pnl:    Vec<Option<PossibleCexDex>>,
     pub max_profit:          Option<PossibleCexDex>,
     pub optimistic_details:  Option<OptimisticDetails>,
+    pub venues_considered:   Vec<CexExchange>,
+    pub markout_details:     Vec<StatArbDetails>,
 }
 
 impl CexDexProcessing {
@@ -123,6 +127,7 @@ impl CexDexProcessing {
         global_vmam_cex_dex: Option<PossibleCexDex>,
         per_exchange_pnl: Vec<Option<PossibleCexDex>>,
         optimistic_details: Option<OptimisticDetails>,
+        venues_considered: Vec<CexExchange>,
     ) -> Option<Self> {
         let mut this = Self {
             per_exchange_pnl,
@@ -130,6 +135,8 @@ impl CexDexProcessing {
             max_profit: None,
             global_vmam_cex_dex,
             optimistic_details,
+            venues_considered,
+            markout_details: Vec::new(),
         };
         this.construct_max_profit_route()?;
         Some(this)
@@ -318,6 +325,8 @@ impl CexDexProcessing {
                     .filter_map(|p| p.as_ref().map(|p| p.generate_arb_details(&self.dex_swaps)))
                     .collect(),
 
+                venues_considered: self.venues_considered,
+                markout_details: self.markout_details,
                 gas_details: tx_info.gas_details,
                 swaps: self.dex_swaps,
             }),
@@ -73,11 +73,16 @@ pub const FILTER_THRESHOLD: u64 = 20;
 
 use itertools::Itertools;
 
-use crate::{shared_utils::SharedInspectorUtils, Inspector, Metadata};
+use crate::{
+    shared_utils::SharedInspectorUtils, thresholds::BundleThresholds, Inspector, Metadata,
+};
 pub struct CexDexQuotesInspector<'db, DB: LibmdbxReader> {
     utils:                SharedInspectorUtils<'db, DB>,
     _quotes_fetch_offset: u64,
     _cex_exchanges:       Vec<CexExchange>,
+    /// keep the losing trades of known cex-dex bots, see
+    /// `Inspectors::init_mev_inspector`
+    track_losses:         bool,
 }
 
 impl<'db, DB: LibmdbxReader> CexDexQuotesInspector<'db, DB> {
@@ -89,17 +94,22 @@ impl<'db, DB: LibmdbxReader> CexDexQuotesInspector<'db, DB> {
     /// * `db` - Database reader to our local libmdbx database
     /// * `cex_exchanges` - List of centralized exchanges to consider for
     ///   arbitrage.
+    /// * `thresholds` - Thresholds the composer checks the bundles against
+    /// * `track_losses` - Whether to keep the losing trades of known bots
     pub fn new(
         quote: Address,
         db: &'db DB,
         cex_exchanges: &[CexExchange],
         quotes_fetch_offset: u64,
+        thresholds: BundleThresholds,
+        track_losses: bool,
         metrics: Option<OutlierMetrics>,
     ) -> Self {
         Self {
-            utils:                SharedInspectorUtils::new(quote, db, metrics),
+            utils: SharedInspectorUtils::new(quote, db, metrics).with_thresholds(thresholds),
             _quotes_fetch_offset: quotes_fetch_offset,
-            _cex_exchanges:       cex_exchanges.to_owned(),
+            _cex_exchanges: cex_exchanges.to_owned(),
+            track_losses,
         }
     }
 }
@@ -115,6 +125,10 @@ impl<DB: LibmdbxReader> Inspector for CexDexQuotesInspector<'_, DB> {
         self.utils.quote
     }
 
+    fn keeps(&self, bundle: &Bundle) -> bool {
+        self.utils.thresholds.keeps(&bundle.header)
+    }
+
     fn inspect_block(&self, data: MultiBlockData) -> Self::Result {
         let block = data.get_most_recent_block();
         let BlockData { metadata, tree } = block;
@@ -319,8 +333,14 @@ impl<DB: LibmdbxReader> CexDexQuotesInspector<'_, DB> {
         tx_info: &TxInfo,
     ) -> Option<(ExchangeLeg, ExchangeLegCexPrice)> {
         let maker_taker_mid = cex_quote.maker_taker_mid();
+        // Large swaps can't be hedged at the top of the book, so walk it when
+        // the exchange's depth is known
+        let hedge_price = match cex_quote.book_slippage(&swap.amount_out) {
+            Some(slippage) => &maker_taker_mid.0 * slippage,
+            None => maker_taker_mid.0,
+        };
 
-        let output_of_cex_trade_maker = &maker_taker_mid.0 * &swap.amount_out;
+        let output_of_cex_trade_maker = &hedge_price * &swap.amount_out;
 
         let smaller = min(&swap.amount_in, &output_of_cex_trade_maker);
         let larger = max(&swap.amount_in, &output_of_cex_trade_maker);
@@ -347,7 +367,7 @@ impl<DB: LibmdbxReader> CexDexQuotesInspector<'_, DB> {
             token_price.clone().reciprocal()
         };
 
-        if maker_taker_mid.0 == Rational::ZERO || swap.amount_out == Rational::ZERO {
+        if hedge_price == Rational::ZERO || swap.amount_out == Rational::ZERO {
             return None
         }
 
@@ -355,7 +375,7 @@ impl<DB: LibmdbxReader> CexDexQuotesInspector<'_, DB> {
             token0: swap.token_in.address,
             price0: base_to_quote.clone(),
             token1: swap.token_out.address,
-            price1: (&token_price * maker_taker_mid.0.clone().reciprocal()).reciprocal(),
+            price1: (&token_price * hedge_price.clone().reciprocal()).reciprocal(),
         };
 
         let pnl_mid = &maker_token_delta * &base_to_quote;
@@ -382,7 +402,7 @@ impl<DB: LibmdbxReader> CexDexQuotesInspector<'_, DB> {
         Some((
             ExchangeLeg {
                 pnl:           pnl_mid.to_float(),
-                cex_mid_price: maker_taker_mid.0.to_float(),
+                cex_mid_price: hedge_price.to_float(),
                 exchange:      cex_quote.exchange,
             },
             pairs_price,
@@ -458,7 +478,8 @@ impl<DB: LibmdbxReader> CexDexQuotesInspector<'_, DB> {
 
         let should_include_based_on_pnl = possible_cex_dex.pnl.aggregate_pnl > 1.5;
 
-        let should_include_if_know_cex_dex = possible_cex_dex.pnl.aggregate_pnl > 0.0;
+        let should_include_if_know_cex_dex =
+            possible_cex_dex.pnl.aggregate_pnl > 0.0 || self.track_losses;
 
         let is_cex_dex_based_on_historical_activity = (is_cex_dex_bot_with_significant_activity
             || is_labelled_cex_dex_bot)
@@ -43,6 +43,11 @@ impl<DB: LibmdbxReader> Inspector for JitCexDex<'_, DB> {
         self.jit.utils.quote
     }
 
+    // both halves are built with the thresholds of the combined inspector
+    fn keeps(&self, bundle: &Bundle) -> bool {
+        self.jit.utils.thresholds.keeps(&bundle.header)
+    }
+
     fn inspect_block(&self, mut data: MultiBlockData) -> Self::Result {
         let block = data.per_block_data.pop().expect("no blocks");
         let BlockData { metadata, tree } = block;
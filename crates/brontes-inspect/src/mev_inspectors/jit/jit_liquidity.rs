@@ -19,8 +19,8 @@ use reth_primitives::TxHash;
 
 use super::types::{PossibleJit, PossibleJitWithInfo};
 use crate::{
-    shared_utils::SharedInspectorUtils, Action, BlockTree, BundleData, Inspector, Metadata,
-    MAX_PROFIT,
+    shared_utils::SharedInspectorUtils, thresholds::BundleThresholds, Action, BlockTree,
+    BundleData, Inspector, Metadata, MAX_PROFIT,
 };
 
 pub struct JitInspector<'db, DB: LibmdbxReader> {
@@ -28,8 +28,13 @@ pub struct JitInspector<'db, DB: LibmdbxReader> {
 }
 
 impl<'db, DB: LibmdbxReader> JitInspector<'db, DB> {
-    pub fn new(quote: Address, db: &'db DB, metrics: Option<OutlierMetrics>) -> Self {
-        Self { utils: SharedInspectorUtils::new(quote, db, metrics) }
+    pub fn new(
+        quote: Address,
+        db: &'db DB,
+        thresholds: BundleThresholds,
+        metrics: Option<OutlierMetrics>,
+    ) -> Self {
+        Self { utils: SharedInspectorUtils::new(quote, db, metrics).with_thresholds(thresholds) }
     }
 }
 
@@ -44,6 +49,10 @@ impl<DB: LibmdbxReader> Inspector for JitInspector<'_, DB> {
         self.utils.quote
     }
 
+    fn keeps(&self, bundle: &Bundle) -> bool {
+        self.utils.thresholds.keeps(&bundle.header)
+    }
+
     fn inspect_block(&self, data: MultiBlockData) -> Self::Result {
         let BlockData { metadata, tree } = data.get_most_recent_block();
 
@@ -0,0 +1,336 @@
+use std::sync::Arc;
+
+use brontes_database::libmdbx::LibmdbxReader;
+use brontes_metrics::inspectors::OutlierMetrics;
+use brontes_types::{
+    db::{dex::PriceAt, normalized_actions::ActionKind, token_info::TokenInfoWithAddress},
+    mev::{Bundle, BundleData, LaunchSnipe, MevType},
+    normalized_actions::{accounting::ActionAccounting, Action, NormalizedSwap},
+    pair::Pair,
+    tree::collect_address_set_for_accounting,
+    BlockData, FastHashMap, MultiBlockData, Protocol, ToFloatNearest, TreeSearchBuilder, TxInfo,
+};
+use malachite::{num::basic::traits::Zero, Rational};
+use reth_primitives::Address;
+
+use super::MAX_PROFIT;
+use crate::{
+    shared_utils::SharedInspectorUtils, thresholds::BundleThresholds, Inspector, Metadata,
+};
+
+/// How many blocks after its pool was created a buy still counts as sniping
+/// the launch
+const MAX_BLOCKS_AFTER_LAUNCH: u64 = 5;
+/// How much a transaction has to spend on the launched token
+const MIN_BUY_USD: Rational = Rational::const_from_unsigned(1_000);
+
+/// Finds searcher contracts buying a token within
+/// [`MAX_BLOCKS_AFTER_LAUNCH`] blocks of the token's first pool being created
+/// and paying the builder to do so. Launch pools are the pools created in that
+/// window, as stored in `PoolCreationBlocks`, that pair a new token with weth
+/// or a stablecoin.
+pub struct LaunchSnipeInspector<'db, DB: LibmdbxReader> {
+    utils: SharedInspectorUtils<'db, DB>,
+}
+
+impl<'db, DB: LibmdbxReader> LaunchSnipeInspector<'db, DB> {
+    pub fn new(
+        quote: Address,
+        db: &'db DB,
+        thresholds: BundleThresholds,
+        metrics: Option<OutlierMetrics>,
+    ) -> Self {
+        Self { utils: SharedInspectorUtils::new(quote, db, metrics).with_thresholds(thresholds) }
+    }
+}
+
+impl<DB: LibmdbxReader> Inspector for LaunchSnipeInspector<'_, DB> {
+    type Result = Vec<Bundle>;
+
+    fn get_id(&self) -> &str {
+        "LaunchSnipe"
+    }
+
+    fn get_quote_token(&self) -> Address {
+        self.utils.quote
+    }
+
+    fn keeps(&self, bundle: &Bundle) -> bool {
+        self.utils.thresholds.keeps(&bundle.header)
+    }
+
+    fn inspect_block(&self, mut data: MultiBlockData) -> Self::Result {
+        let block = data.per_block_data.pop().expect("no blocks");
+        let BlockData { metadata, tree } = block;
+
+        let ex = || {
+            let launches = self.recent_launches(metadata.block_num);
+            if launches.is_empty() {
+                return vec![]
+            }
+
+            let (tx, actions): (Vec<_>, Vec<_>) = tree
+                .clone()
+                .collect_all(
+                    TreeSearchBuilder::default()
+                        .with_actions([
+                            Action::is_swap,
+                            Action::is_transfer,
+                            Action::is_eth_transfer,
+                            Action::is_nested_action,
+                        ])
+                        .with_tx_action_kinds([ActionKind::Swap]),
+                )
+                .unzip();
+            let tx_info = tree.get_tx_info_batch(&tx, self.utils.db);
+
+            actions
+                .into_iter()
+                .zip(tx_info)
+                .filter_map(|(actions, info)| {
+                    let info = info?;
+                    // snipers land first by paying the builder directly
+                    if info.mev_contract.is_none()
+                        || info.gas_details.coinbase_transfer.unwrap_or_default() == 0
+                    {
+                        return None
+                    }
+                    let actions = self
+                        .utils
+                        .flatten_nested_actions(actions.into_iter(), &|action| {
+                            action.is_swap() || action.is_transfer() || action.is_eth_transfer()
+                        })
+                        .collect::<Vec<_>>();
+
+                    self.calculate_snipe(info, actions, &launches, metadata.clone())
+                })
+                .collect::<Vec<_>>()
+        };
+        self.utils
+            .get_metrics()
+            .map(|m| m.run_inspector(MevType::LaunchSnipe, ex))
+            .unwrap_or_else(ex)
+    }
+}
+
+impl<DB: LibmdbxReader> LaunchSnipeInspector<'_, DB> {
+    /// The launch pools created in the last [`MAX_BLOCKS_AFTER_LAUNCH`] blocks,
+    /// this one included
+    fn recent_launches(&self, block_number: u64) -> FastHashMap<Address, Launch> {
+        let created = match self.utils.db.protocols_created_range(
+            block_number.saturating_sub(MAX_BLOCKS_AFTER_LAUNCH),
+            block_number + 1,
+        ) {
+            Ok(created) => created,
+            Err(e) => {
+                tracing::error!(block=%block_number, "failed to load created pools: {}", e);
+                return FastHashMap::default()
+            }
+        };
+
+        created
+            .into_iter()
+            .flat_map(|(block, pools)| {
+                pools.into_iter().filter_map(move |(pool, protocol, pair)| {
+                    let (token, base) = launched_token(pair)?;
+                    Some((pool, Launch { block, protocol, token, base }))
+                })
+            })
+            .collect()
+    }
+
+    fn calculate_snipe(
+        &self,
+        info: TxInfo,
+        actions: Vec<Action>,
+        launches: &FastHashMap<Address, Launch>,
+        metadata: Arc<Metadata>,
+    ) -> Option<Bundle> {
+        let swaps = actions
+            .iter()
+            .filter_map(Action::try_swaps_merged_ref)
+            .cloned()
+            .collect::<Vec<_>>();
+
+        // the launch the transaction spent the most on
+        let (pool, swaps, _) = launch_buys(&swaps, launches)
+            .into_iter()
+            .filter_map(|(pool, swaps)| {
+                let spent = swaps.iter().try_fold(Rational::ZERO, |spent, swap| {
+                    Some(
+                        spent
+                            + self.utils.get_token_value_dex(
+                                info.tx_index as usize,
+                                PriceAt::Before,
+                                swap.token_in.address,
+                                &swap.amount_in,
+                                &metadata,
+                            )?,
+                    )
+                })?;
+                (spent >= MIN_BUY_USD).then_some((pool, swaps, spent))
+            })
+            .max_by(|a, b| a.2.cmp(&b.2))?;
+        let launch = &launches[&pool];
+
+        let mev_addresses = collect_address_set_for_accounting(std::slice::from_ref(&info));
+        let deltas = actions
+            .into_iter()
+            .filter(|a| a.is_eth_transfer() || a.is_transfer())
+            .chain(info.get_total_eth_value().iter().cloned().map(Action::from))
+            .account_for_actions();
+
+        let (rev, mut has_dex_price) = if let Some(rev) = self.utils.get_deltas_usd(
+            info.tx_index,
+            PriceAt::After,
+            &mev_addresses,
+            &deltas,
+            metadata.clone(),
+            false,
+        ) {
+            (Some(rev), true)
+        } else {
+            (Some(Rational::ZERO), false)
+        };
+
+        let gas_finalized =
+            metadata.get_gas_price_usd(info.gas_details.gas_paid(), self.utils.quote);
+
+        let mut profit_usd = rev
+            .map(|rev| rev - &gas_finalized)
+            .filter(|_| has_dex_price)
+            .unwrap_or_default();
+
+        if profit_usd >= MAX_PROFIT || profit_usd <= -MAX_PROFIT {
+            has_dex_price = false;
+            profit_usd = Rational::ZERO;
+        }
+
+        let header = self.utils.build_bundle_header(
+            vec![deltas],
+            vec![info.tx_hash],
+            &info,
+            profit_usd.to_float(),
+            &[info.gas_details],
+            metadata.clone(),
+            MevType::LaunchSnipe,
+            !has_dex_price,
+            |this, token, amount| {
+                this.get_token_value_dex(
+                    info.tx_index as usize,
+                    PriceAt::Average,
+                    token,
+                    &amount,
+                    &metadata,
+                )
+            },
+        );
+
+        let launch_snipe = LaunchSnipe {
+            block_number: metadata.block_num,
+            tx_hash: info.tx_hash,
+            sniper: info.mev_contract?,
+            token: launch.token,
+            pool,
+            protocol: launch.protocol,
+            pool_creation_block: launch.block,
+            swaps,
+            gas_details: info.gas_details,
+        };
+
+        Some(Bundle { header, data: BundleData::LaunchSnipe(launch_snipe) })
+    }
+}
+
+/// A pool pairing a newly launched token with the token it is bought with
+struct Launch {
+    block:    u64,
+    protocol: Protocol,
+    token:    Address,
+    base:     Address,
+}
+
+/// A launch pool pairs the new token with weth or a stablecoin. Returns the
+/// new token and the one it is paired with, `None` if the pair doesn't look
+/// like a launch
+fn launched_token(pair: Pair) -> Option<(Address, Address)> {
+    let bases = [
+        TokenInfoWithAddress::weth().address,
+        TokenInfoWithAddress::usdc().address,
+        TokenInfoWithAddress::usdt().address,
+    ];
+
+    match (bases.contains(&pair.0), bases.contains(&pair.1)) {
+        (true, false) => Some((pair.1, pair.0)),
+        (false, true) => Some((pair.0, pair.1)),
+        _ => None,
+    }
+}
+
+/// The swaps paying the base token for the launched token, by launch pool
+fn launch_buys(
+    swaps: &[NormalizedSwap],
+    launches: &FastHashMap<Address, Launch>,
+) -> FastHashMap<Address, Vec<NormalizedSwap>> {
+    let mut buys: FastHashMap<Address, Vec<NormalizedSwap>> = FastHashMap::default();
+    for swap in swaps {
+        let Some(launch) = launches.get(&swap.pool) else { continue };
+        if swap.token_in.address == launch.base && swap.token_out.address == launch.token {
+            buys.entry(swap.pool).or_default().push(swap.clone());
+        }
+    }
+
+    buys
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOKEN: Address = Address::repeat_byte(1);
+    const POOL: Address = Address::repeat_byte(2);
+    const OTHER_POOL: Address = Address::repeat_byte(3);
+
+    fn swap(pool: Address, token_in: Address, token_out: Address) -> NormalizedSwap {
+        NormalizedSwap {
+            pool,
+            token_in: TokenInfoWithAddress { address: token_in, ..Default::default() },
+            token_out: TokenInfoWithAddress { address: token_out, ..Default::default() },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_launched_token() {
+        let weth = TokenInfoWithAddress::weth().address;
+        let usdc = TokenInfoWithAddress::usdc().address;
+
+        assert_eq!(launched_token(Pair(weth, TOKEN)), Some((TOKEN, weth)));
+        assert_eq!(launched_token(Pair(TOKEN, usdc)), Some((TOKEN, usdc)));
+        // a new pool for two established tokens isn't a launch
+        assert_eq!(launched_token(Pair(weth, usdc)), None);
+    }
+
+    #[test]
+    fn test_launch_buys() {
+        let weth = TokenInfoWithAddress::weth().address;
+        let launches = [(
+            POOL,
+            Launch { block: 0, protocol: Protocol::UniswapV2, token: TOKEN, base: weth },
+        )]
+        .into_iter()
+        .collect();
+
+        let swaps = [
+            swap(POOL, weth, TOKEN),
+            // selling the launched token isn't sniping it
+            swap(POOL, TOKEN, weth),
+            swap(OTHER_POOL, weth, TOKEN),
+        ];
+
+        let buys = launch_buys(&swaps, &launches);
+        assert_eq!(buys.len(), 1);
+        assert_eq!(buys[&POOL].len(), 1);
+        assert_eq!(buys[&POOL][0].token_in.address, weth);
+    }
+}
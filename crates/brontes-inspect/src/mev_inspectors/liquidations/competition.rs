@@ -0,0 +1,124 @@
+//! Finds the reverted attempts at a liquidation, so we can tell how many
+//! keepers were going after the same account and how much the winner had to
+//! outbid them by.
+use alloy_primitives::Address;
+use alloy_sol_types::{sol, SolInterface};
+use brontes_types::{
+    mev::LiquidationCompetition, normalized_actions::NormalizedLiquidation, tree::RevertedTx,
+    FastHashSet, GasDetails,
+};
+
+sol! {
+    interface ILiquidate {
+        function liquidationCall(address collateralAsset, address debtAsset, address user, uint256 debtToCover, bool receiveAToken);
+        function liquidateBorrow(address borrower, uint256 repayAmount, address cTokenCollateral);
+        function liquidateBorrow(address borrower, address cTokenCollateral);
+    }
+}
+
+/// The account a call is trying to liquidate
+fn liquidated_account(calldata: &[u8]) -> Option<Address> {
+    match ILiquidate::ILiquidateCalls::abi_decode(calldata, false).ok()? {
+        ILiquidate::ILiquidateCalls::liquidationCall(c) => Some(c.user),
+        ILiquidate::ILiquidateCalls::liquidateBorrow_0(c) => Some(c.borrower),
+        ILiquidate::ILiquidateCalls::liquidateBorrow_1(c) => Some(c.borrower),
+    }
+}
+
+pub(crate) fn liquidation_competition(
+    eoa: Address,
+    gas_details: &GasDetails,
+    liquidations: &[NormalizedLiquidation],
+    reverted_txs: &[RevertedTx],
+) -> LiquidationCompetition {
+    let debtors = liquidations
+        .iter()
+        .map(|liquidation| liquidation.debtor)
+        .collect::<FastHashSet<_>>();
+
+    let competitors = reverted_txs
+        .iter()
+        .filter(|tx| {
+            tx.calls.iter().any(|(_, calldata)| {
+                liquidated_account(calldata).is_some_and(|account| debtors.contains(&account))
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let winner_bribe_per_gas = gas_details.bribe_per_gas();
+    let max_competitor_bribe_per_gas = competitors
+        .iter()
+        .map(|tx| tx.gas_details.bribe_per_gas())
+        .fold(0.0, f64::max);
+
+    let bribe_escalation = if max_competitor_bribe_per_gas > 0.0 {
+        winner_bribe_per_gas / max_competitor_bribe_per_gas
+    } else {
+        0.0
+    };
+
+    LiquidationCompetition {
+        competitor_tx_hashes: competitors.iter().map(|tx| tx.tx_hash).collect(),
+        competing_searchers: competitors
+            .iter()
+            .map(|tx| tx.from)
+            .filter(|from| *from != eoa)
+            .collect::<FastHashSet<_>>()
+            .len() as u64,
+        winner_bribe_per_gas,
+        max_competitor_bribe_per_gas,
+        bribe_escalation,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{Bytes, B256, U256};
+    use alloy_sol_types::SolCall;
+
+    use super::*;
+
+    fn reverted_attempt(from: Address, user: Address, priority_fee: u128) -> RevertedTx {
+        let calldata = ILiquidate::liquidationCallCall {
+            collateralAsset: Address::ZERO,
+            debtAsset: Address::ZERO,
+            user,
+            debtToCover: U256::MAX,
+            receiveAToken: false,
+        }
+        .abi_encode();
+
+        RevertedTx {
+            tx_hash: B256::with_last_byte(from.0[19]),
+            position: 0,
+            from,
+            gas_details: GasDetails { priority_fee, gas_used: 100_000, ..Default::default() },
+            calls: vec![(Address::ZERO, Bytes::from(calldata))],
+        }
+    }
+
+    #[test]
+    fn test_competition_for_same_account() {
+        let debtor = Address::with_last_byte(1);
+        let winner = Address::with_last_byte(2);
+
+        let gas_details =
+            GasDetails { priority_fee: 3_000_000_000, gas_used: 100_000, ..Default::default() };
+        let liquidations = vec![NormalizedLiquidation { debtor, ..Default::default() }];
+
+        let reverted = vec![
+            reverted_attempt(Address::with_last_byte(3), debtor, 2_000_000_000),
+            reverted_attempt(Address::with_last_byte(4), debtor, 1_000_000_000),
+            // a different account
+            reverted_attempt(Address::with_last_byte(5), Address::with_last_byte(9), 1),
+        ];
+
+        let competition = liquidation_competition(winner, &gas_details, &liquidations, &reverted);
+
+        assert_eq!(competition.competitor_tx_hashes.len(), 2);
+        assert_eq!(competition.competing_searchers, 2);
+        assert_eq!(competition.winner_bribe_per_gas, 3.0);
+        assert_eq!(competition.max_competitor_bribe_per_gas, 2.0);
+        assert_eq!(competition.bribe_escalation, 1.5);
+    }
+}
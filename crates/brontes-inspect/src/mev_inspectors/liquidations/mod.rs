@@ -3,9 +3,10 @@ use std::sync::Arc;
 use brontes_database::libmdbx::LibmdbxReader;
 use brontes_metrics::inspectors::OutlierMetrics;
 use brontes_types::{
-    db::dex::PriceAt,
+    db::{dex::PriceAt, normalized_actions::ActionKind},
     mev::{Bundle, BundleData, Liquidation, MevType},
     normalized_actions::{accounting::ActionAccounting, Action},
+    tree::RevertedTx,
     ActionIter, BlockData, FastHashSet, MultiBlockData, ToFloatNearest, TreeSearchBuilder, TxInfo,
 };
 use itertools::multizip;
@@ -13,15 +14,25 @@ use malachite::{num::basic::traits::Zero, Rational};
 use reth_primitives::{b256, Address};
 
 use super::MAX_PROFIT;
-use crate::{shared_utils::SharedInspectorUtils, Inspector, Metadata};
+use crate::{
+    shared_utils::SharedInspectorUtils, thresholds::BundleThresholds, Inspector, Metadata,
+};
+
+mod competition;
+use competition::liquidation_competition;
 
 pub struct LiquidationInspector<'db, DB: LibmdbxReader> {
     utils: SharedInspectorUtils<'db, DB>,
 }
 
 impl<'db, DB: LibmdbxReader> LiquidationInspector<'db, DB> {
-    pub fn new(quote: Address, db: &'db DB, metrics: Option<OutlierMetrics>) -> Self {
-        Self { utils: SharedInspectorUtils::new(quote, db, metrics) }
+    pub fn new(
+        quote: Address,
+        db: &'db DB,
+        thresholds: BundleThresholds,
+        metrics: Option<OutlierMetrics>,
+    ) -> Self {
+        Self { utils: SharedInspectorUtils::new(quote, db, metrics).with_thresholds(thresholds) }
     }
 }
 
@@ -36,6 +47,10 @@ impl<DB: LibmdbxReader> Inspector for LiquidationInspector<'_, DB> {
         self.utils.quote
     }
 
+    fn keeps(&self, bundle: &Bundle) -> bool {
+        self.utils.thresholds.keeps(&bundle.header)
+    }
+
     fn inspect_block(&self, mut data: MultiBlockData) -> Self::Result {
         let block = data.per_block_data.pop().expect("no blocks");
         let BlockData { metadata, tree } = block;
@@ -43,13 +58,23 @@ impl<DB: LibmdbxReader> Inspector for LiquidationInspector<'_, DB> {
         let ex = || {
             let (tx, liq): (Vec<_>, Vec<_>) = tree
                 .clone()
-                .collect_all(TreeSearchBuilder::default().with_actions([
-                    Action::is_swap,
-                    Action::is_liquidation,
-                    Action::is_transfer,
-                    Action::is_eth_transfer,
-                    Action::is_aggregator,
-                ]))
+                .collect_all(
+                    TreeSearchBuilder::default()
+                        .with_actions([
+                            Action::is_swap,
+                            Action::is_liquidation,
+                            Action::is_transfer,
+                            Action::is_eth_transfer,
+                            Action::is_aggregator,
+                            Action::is_flash_loan,
+                        ])
+                        // liquidations can be nested in flashloans and aggregators
+                        .with_tx_action_kinds([
+                            ActionKind::Liquidation,
+                            ActionKind::FlashLoan,
+                            ActionKind::Aggregator,
+                        ]),
+                )
                 .unzip();
             let tx_info = tree.get_tx_info_batch(&tx, self.utils.db);
 
@@ -61,7 +86,7 @@ impl<DB: LibmdbxReader> Inspector for LiquidationInspector<'_, DB> {
                         .flatten_nested_actions_default(liq.into_iter())
                         .collect::<Vec<_>>();
 
-                    self.calculate_liquidation(info, metadata.clone(), actions)
+                    self.calculate_liquidation(info, metadata.clone(), actions, &tree.reverted_txs)
                 })
                 .collect::<Vec<_>>()
         };
@@ -78,6 +103,7 @@ impl<DB: LibmdbxReader> LiquidationInspector<'_, DB> {
         info: TxInfo,
         metadata: Arc<Metadata>,
         actions: Vec<Action>,
+        reverted_txs: &[RevertedTx],
     ) -> Option<Bundle> {
         let (swaps, liqs): (Vec<_>, Vec<_>) = actions
             .clone()
@@ -143,13 +169,16 @@ impl<DB: LibmdbxReader> LiquidationInspector<'_, DB> {
             },
         );
 
+        let competition = liquidation_competition(info.eoa, &info.gas_details, &liqs, reverted_txs);
+
         let new_liquidation = Liquidation {
-            block_number:        metadata.block_num,
+            block_number: metadata.block_num,
             liquidation_tx_hash: info.tx_hash,
-            trigger:             b256!(),
-            liquidation_swaps:   swaps,
-            liquidations:        liqs,
-            gas_details:         info.gas_details,
+            trigger: b256!(),
+            liquidation_swaps: swaps,
+            liquidations: liqs,
+            gas_details: info.gas_details,
+            competition,
         };
 
         Some(Bundle { header, data: BundleData::Liquidation(new_liquidation) })
@@ -0,0 +1,278 @@
+use std::sync::Arc;
+
+use brontes_database::libmdbx::LibmdbxReader;
+use brontes_metrics::inspectors::OutlierMetrics;
+use brontes_types::{
+    db::{dex::PriceAt, normalized_actions::ActionKind},
+    mev::{Bundle, BundleData, MevType, SearcherTx},
+    normalized_actions::{accounting::ActionAccounting, Action},
+    ActionIter, BlockData, FastHashSet, MultiBlockData, ToFloatNearest, TreeSearchBuilder, TxInfo,
+};
+use malachite::{num::basic::traits::Zero, Rational};
+use reth_primitives::Address;
+
+use super::MAX_PROFIT;
+use crate::{
+    shared_utils::SharedInspectorUtils, thresholds::BundleThresholds, Inspector, Metadata,
+};
+
+/// Catch-all for strategies none of the other inspectors know about. Flags
+/// every transaction whose sender, together with its mev contract, both paid
+/// and received tokens and came out ahead after gas, or lost money when losses
+/// are tracked. How much it has to make is up to the inspector's thresholds,
+/// by default $1000. The bundles are [`MevType::Unknown`], which any other
+/// bundle on the same transaction takes precedence over, so what is left are
+/// the transactions worth looking at by hand.
+pub struct LongTailInspector<'db, DB: LibmdbxReader> {
+    utils:        SharedInspectorUtils<'db, DB>,
+    /// keep transactions that lost money, see `Inspectors::init_mev_inspector`
+    track_losses: bool,
+}
+
+impl<'db, DB: LibmdbxReader> LongTailInspector<'db, DB> {
+    pub fn new(
+        quote: Address,
+        db: &'db DB,
+        thresholds: BundleThresholds,
+        track_losses: bool,
+        metrics: Option<OutlierMetrics>,
+    ) -> Self {
+        Self {
+            utils: SharedInspectorUtils::new(quote, db, metrics).with_thresholds(thresholds),
+            track_losses,
+        }
+    }
+}
+
+impl<DB: LibmdbxReader> Inspector for LongTailInspector<'_, DB> {
+    type Result = Vec<Bundle>;
+
+    fn get_id(&self) -> &str {
+        "LongTail"
+    }
+
+    fn get_quote_token(&self) -> Address {
+        self.utils.quote
+    }
+
+    fn keeps(&self, bundle: &Bundle) -> bool {
+        self.utils.thresholds.keeps(&bundle.header)
+    }
+
+    fn inspect_block(&self, mut data: MultiBlockData) -> Self::Result {
+        let block = data.per_block_data.pop().expect("no blocks");
+        let BlockData { metadata, tree } = block;
+
+        let ex = || {
+            let (tx, actions): (Vec<_>, Vec<_>) = tree
+                .clone()
+                .collect_all(
+                    TreeSearchBuilder::default()
+                        .with_actions([
+                            Action::is_transfer,
+                            Action::is_eth_transfer,
+                            Action::is_nested_action,
+                        ])
+                        .with_tx_action_kinds([ActionKind::Transfer, ActionKind::EthTransfer]),
+                )
+                .unzip();
+            let tx_info = tree.get_tx_info_batch(&tx, self.utils.db);
+
+            actions
+                .into_iter()
+                .zip(tx_info)
+                .filter_map(|(actions, info)| {
+                    let info = info?;
+                    let actions = self
+                        .utils
+                        .flatten_nested_actions(actions.into_iter(), &|action| {
+                            action.is_transfer() || action.is_eth_transfer()
+                        })
+                        .collect::<Vec<_>>();
+
+                    self.calculate_long_tail(info, actions, metadata.clone())
+                })
+                .collect::<Vec<_>>()
+        };
+        self.utils
+            .get_metrics()
+            .map(|m| m.run_inspector(MevType::Unknown, ex))
+            .unwrap_or_else(ex)
+    }
+}
+
+impl<DB: LibmdbxReader> LongTailInspector<'_, DB> {
+    fn calculate_long_tail(
+        &self,
+        info: TxInfo,
+        actions: Vec<Action>,
+        metadata: Arc<Metadata>,
+    ) -> Option<Bundle> {
+        let searcher = searcher_addresses(&info);
+        if !has_traded(&searcher, &actions) {
+            return None
+        }
+
+        let deltas = actions
+            .iter()
+            .cloned()
+            .chain(info.get_total_eth_value().iter().cloned().map(Action::from))
+            .account_for_actions();
+
+        // without a price there is no telling whether the transaction made
+        // enough to be worth a look
+        let rev = self.utils.get_deltas_usd(
+            info.tx_index,
+            PriceAt::After,
+            &searcher,
+            &deltas,
+            metadata.clone(),
+            false,
+        )?;
+        let gas_paid = metadata.get_gas_price_usd(info.gas_details.gas_paid(), self.utils.quote);
+        let profit_usd = rev - gas_paid;
+
+        if !keeps_profit(&profit_usd, self.track_losses) {
+            return None
+        }
+
+        let header = self.utils.build_bundle_header(
+            vec![deltas],
+            vec![info.tx_hash],
+            &info,
+            profit_usd.to_float(),
+            &[info.gas_details],
+            metadata.clone(),
+            MevType::Unknown,
+            false,
+            |this, token, amount| {
+                this.get_token_value_dex(
+                    info.tx_index as usize,
+                    PriceAt::Average,
+                    token,
+                    &amount,
+                    &metadata,
+                )
+            },
+        );
+
+        Some(Bundle {
+            header,
+            data: BundleData::Unknown(SearcherTx {
+                tx_hash:      info.tx_hash,
+                block_number: metadata.block_num,
+                transfers:    actions.into_iter().collect_action_vec(Action::try_transfer),
+                gas_details:  info.gas_details,
+            }),
+        })
+    }
+}
+
+/// The sender and its mev contract. The contract the transaction called isn't
+/// included unless it's a known mev contract, as that would count a router or
+/// a pool's balance as the searcher's
+fn searcher_addresses(info: &TxInfo) -> FastHashSet<Address> {
+    std::iter::once(info.eoa).chain(info.mev_contract).collect()
+}
+
+/// Whether a transaction's profit after gas is worth flagging. Anything at
+/// [`MAX_PROFIT`] or above is a mispriced token, not a trade
+fn keeps_profit(profit_usd: &Rational, track_losses: bool) -> bool {
+    (track_losses || *profit_usd > Rational::ZERO) && *profit_usd < MAX_PROFIT
+}
+
+/// Whether the searcher both sent and received value, so that a transaction
+/// that only pays someone, or only claims something, isn't mistaken for a
+/// profitable trade
+fn has_traded(searcher: &FastHashSet<Address>, actions: &[Action]) -> bool {
+    let mut sent = false;
+    let mut received = false;
+
+    for action in actions {
+        let (from, to) = match action {
+            Action::Transfer(t) => (t.from, t.to),
+            Action::EthTransfer(t) => (t.from, t.to),
+            _ => continue,
+        };
+        // moving funds between the sender and its contract isn't either
+        if searcher.contains(&from) && searcher.contains(&to) {
+            continue
+        }
+        sent |= searcher.contains(&from);
+        received |= searcher.contains(&to);
+    }
+
+    sent && received
+}
+
+#[cfg(test)]
+mod tests {
+    use brontes_types::{normalized_actions::NormalizedTransfer, GasDetails};
+
+    use super::*;
+
+    const EOA: Address = Address::repeat_byte(1);
+    const BOT: Address = Address::repeat_byte(2);
+    const POOL: Address = Address::repeat_byte(3);
+    const ROUTER: Address = Address::repeat_byte(4);
+
+    fn transfer(from: Address, to: Address) -> Action {
+        Action::Transfer(NormalizedTransfer { from, to, ..Default::default() })
+    }
+
+    #[test]
+    fn test_keeps_profit() {
+        let loss = Rational::from(-50);
+        assert!(!keeps_profit(&loss, false));
+        assert!(keeps_profit(&loss, true));
+
+        assert!(keeps_profit(&Rational::from(50), false));
+        // a mispriced token is never kept, losses tracked or not
+        assert!(!keeps_profit(&MAX_PROFIT, true));
+    }
+
+    #[test]
+    fn test_has_traded() {
+        let searcher: FastHashSet<Address> = [EOA, BOT].into_iter().collect();
+
+        let trade = [transfer(BOT, POOL), transfer(POOL, BOT), transfer(BOT, EOA)];
+        assert!(has_traded(&searcher, &trade));
+
+        // getting paid without paying anything in isn't a trade
+        let claim = [transfer(POOL, BOT), transfer(BOT, EOA)];
+        assert!(!has_traded(&searcher, &claim));
+    }
+
+    fn tx_info(mev_contract: Option<Address>) -> TxInfo {
+        TxInfo::new(
+            0,
+            0,
+            EOA,
+            mev_contract,
+            None,
+            Default::default(),
+            GasDetails::default(),
+            false,
+            false,
+            false,
+            false,
+            None,
+            None,
+            vec![],
+            None,
+        )
+    }
+
+    #[test]
+    fn test_searcher_addresses() {
+        let with_bot = searcher_addresses(&tx_info(Some(BOT)));
+        assert_eq!(with_bot, [EOA, BOT].into_iter().collect());
+
+        // a router the sender called isn't part of the searcher, so the pool
+        // paying the router and the router paying the pool isn't a trade
+        let searcher = searcher_addresses(&tx_info(None));
+        assert_eq!(searcher, [EOA].into_iter().collect());
+        let routed = [transfer(ROUTER, POOL), transfer(POOL, ROUTER)];
+        assert!(!has_traded(&searcher, &routed));
+    }
+}
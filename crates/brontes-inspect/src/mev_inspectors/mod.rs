@@ -2,7 +2,11 @@ pub mod atomic_arb;
 pub mod cex_dex;
 
 pub mod jit;
+pub mod launch_snipe;
 pub mod liquidations;
+pub mod long_tail;
+pub mod nft;
+pub mod oracle_manipulation;
 pub mod sandwich;
 pub mod searcher_activity;
 pub mod shared_utils;
@@ -0,0 +1,392 @@
+use std::sync::Arc;
+
+use brontes_database::libmdbx::LibmdbxReader;
+use brontes_metrics::inspectors::OutlierMetrics;
+use brontes_types::{
+    db::{dex::PriceAt, normalized_actions::ActionKind},
+    mev::{Bundle, BundleData, MevType, NftMev, NftMevKind, NftMevLeg},
+    normalized_actions::{accounting::ActionAccounting, Action, NormalizedNftTrade},
+    tree::collect_address_set_for_accounting,
+    BlockData, FastHashMap, FastHashSet, MultiBlockData, Protocol, ToFloatNearest,
+    TreeSearchBuilder, TxInfo,
+};
+use malachite::{num::basic::traits::Zero, Rational};
+use reth_primitives::{Address, B256, U256};
+
+use super::MAX_PROFIT;
+use crate::{
+    shared_utils::SharedInspectorUtils, thresholds::BundleThresholds, Inspector, Metadata,
+};
+
+/// Finds searchers that sold nfts in the same block they got them in, either
+/// by buying them on a marketplace (arbitrage) or by minting them (mint
+/// sniping). The buy and the sale don't have to be in the same transaction,
+/// all transactions of a searcher in the block are looked at together.
+pub struct NftInspector<'db, DB: LibmdbxReader> {
+    utils: SharedInspectorUtils<'db, DB>,
+}
+
+impl<'db, DB: LibmdbxReader> NftInspector<'db, DB> {
+    pub fn new(
+        quote: Address,
+        db: &'db DB,
+        thresholds: BundleThresholds,
+        metrics: Option<OutlierMetrics>,
+    ) -> Self {
+        Self { utils: SharedInspectorUtils::new(quote, db, metrics).with_thresholds(thresholds) }
+    }
+}
+
+impl<DB: LibmdbxReader> Inspector for NftInspector<'_, DB> {
+    type Result = Vec<Bundle>;
+
+    fn get_id(&self) -> &str {
+        "NftMev"
+    }
+
+    fn get_quote_token(&self) -> Address {
+        self.utils.quote
+    }
+
+    fn keeps(&self, bundle: &Bundle) -> bool {
+        self.utils.thresholds.keeps(&bundle.header)
+    }
+
+    fn inspect_block(&self, mut data: MultiBlockData) -> Self::Result {
+        let block = data.per_block_data.pop().expect("no blocks");
+        let BlockData { metadata, tree } = block;
+
+        let ex = || {
+            let (tx, actions): (Vec<_>, Vec<_>) = tree
+                .clone()
+                .collect_all(
+                    TreeSearchBuilder::default()
+                        .with_actions([
+                            Action::is_nft_trade,
+                            Action::is_nft_transfer,
+                            Action::is_transfer,
+                            Action::is_eth_transfer,
+                            Action::is_aggregator,
+                            Action::is_flash_loan,
+                        ])
+                        .with_tx_action_kinds([ActionKind::NftTrade, ActionKind::NftTransfer]),
+                )
+                .unzip();
+            let tx_info = tree.get_tx_info_batch(&tx, self.utils.db);
+
+            let mut searchers: FastHashMap<Address, Vec<(TxInfo, Vec<Action>)>> =
+                FastHashMap::default();
+            for (actions, info) in actions.into_iter().zip(tx_info) {
+                let Some(info) = info else { continue };
+                let actions = self
+                    .utils
+                    .flatten_nested_actions(actions.into_iter(), &|action| {
+                        action.is_nft_trade()
+                            || action.is_nft_transfer()
+                            || action.is_transfer()
+                            || action.is_eth_transfer()
+                    })
+                    .collect::<Vec<_>>();
+                searchers.entry(info.eoa).or_default().push((info, actions));
+            }
+
+            searchers
+                .into_values()
+                .filter_map(|mut txs| {
+                    txs.sort_by_key(|(info, _)| info.tx_index);
+                    self.calculate_nft_mev(txs, metadata.clone())
+                })
+                .collect::<Vec<_>>()
+        };
+        self.utils
+            .get_metrics()
+            .map(|m| m.run_inspector(MevType::NftMev, ex))
+            .unwrap_or_else(ex)
+    }
+}
+
+impl<DB: LibmdbxReader> NftInspector<'_, DB> {
+    fn calculate_nft_mev(
+        &self,
+        txs: Vec<(TxInfo, Vec<Action>)>,
+        metadata: Arc<Metadata>,
+    ) -> Option<Bundle> {
+        let infos = txs.iter().map(|(info, _)| info.clone()).collect::<Vec<_>>();
+        let mev_addresses = collect_address_set_for_accounting(&infos);
+
+        let (kind, acquisitions, sales) = match_flips(
+            txs.iter()
+                .map(|(info, actions)| (info.tx_hash, actions.as_slice())),
+            &mev_addresses,
+        )?;
+
+        // only the transactions the nfts were acquired or sold in are part of
+        // the bundle
+        let bundle_txs: FastHashSet<B256> = acquisitions
+            .iter()
+            .chain(&sales)
+            .map(|leg| leg.tx_hash)
+            .collect();
+        let (infos, actions): (Vec<_>, Vec<_>) = txs
+            .into_iter()
+            .filter(|(info, _)| bundle_txs.contains(&info.tx_hash))
+            .unzip();
+        let last_tx = infos.last()?;
+
+        let deltas = actions
+            .into_iter()
+            .flatten()
+            .filter(|a| a.is_eth_transfer() || a.is_transfer())
+            .chain(
+                infos
+                    .iter()
+                    .flat_map(|info| info.get_total_eth_value())
+                    .cloned()
+                    .map(Action::from),
+            )
+            .account_for_actions();
+
+        let (rev, mut has_dex_price) = if let Some(rev) = self.utils.get_deltas_usd(
+            last_tx.tx_index,
+            PriceAt::After,
+            &mev_addresses,
+            &deltas,
+            metadata.clone(),
+            false,
+        ) {
+            (Some(rev), true)
+        } else {
+            (Some(Rational::ZERO), false)
+        };
+
+        let gas_details = infos
+            .iter()
+            .map(|info| info.gas_details)
+            .collect::<Vec<_>>();
+        let gas_finalized = metadata.get_gas_price_usd(
+            gas_details.iter().map(|gas| gas.gas_paid()).sum(),
+            self.utils.quote,
+        );
+
+        let mut profit_usd = rev
+            .map(|rev| rev - &gas_finalized)
+            .filter(|_| has_dex_price)
+            .unwrap_or_default();
+
+        if profit_usd >= MAX_PROFIT || profit_usd <= -MAX_PROFIT {
+            has_dex_price = false;
+            profit_usd = Rational::ZERO;
+        }
+
+        let tx_hashes = infos.iter().map(|info| info.tx_hash).collect::<Vec<_>>();
+        let header = self.utils.build_bundle_header(
+            vec![deltas],
+            tx_hashes.clone(),
+            last_tx,
+            profit_usd.to_float(),
+            &gas_details,
+            metadata.clone(),
+            MevType::NftMev,
+            !has_dex_price,
+            |this, token, amount| {
+                this.get_token_value_dex(
+                    last_tx.tx_index as usize,
+                    PriceAt::Average,
+                    token,
+                    &amount,
+                    &metadata,
+                )
+            },
+        );
+
+        let nft_mev = NftMev {
+            block_number: metadata.block_num,
+            kind,
+            tx_hashes,
+            gas_details,
+            acquisitions,
+            sales,
+        };
+
+        Some(Bundle { header, data: BundleData::NftMev(nft_mev) })
+    }
+}
+
+/// Walks the searcher's actions in block order, holding on to every nft they
+/// buy or mint until they sell that same nft. Nfts that aren't sold again in
+/// the block aren't mev, so only the matched pairs are returned. The bundle is
+/// a mint snipe if all of the sold nfts were minted, an arbitrage otherwise.
+fn match_flips<'a>(
+    txs: impl Iterator<Item = (B256, &'a [Action])>,
+    searcher: &FastHashSet<Address>,
+) -> Option<(NftMevKind, Vec<NftMevLeg>, Vec<NftMevLeg>)> {
+    let mut held: FastHashMap<(Address, U256), NftMevLeg> = FastHashMap::default();
+    let mut acquisitions = Vec::new();
+    let mut sales = Vec::new();
+
+    for (tx_hash, actions) in txs {
+        for action in actions {
+            match action {
+                Action::NftTrade(trade)
+                    if searcher.contains(&trade.buyer()) && !searcher.contains(&trade.seller()) =>
+                {
+                    held.insert(
+                        (trade.collection, trade.token_id),
+                        trade_leg(tx_hash, trade, trade.seller()),
+                    );
+                }
+                Action::NftTrade(trade)
+                    if searcher.contains(&trade.seller()) && !searcher.contains(&trade.buyer()) =>
+                {
+                    let Some(acquisition) = held.remove(&(trade.collection, trade.token_id)) else {
+                        continue
+                    };
+                    acquisitions.push(acquisition);
+                    sales.push(trade_leg(tx_hash, trade, trade.buyer()));
+                }
+                Action::NftTransfer(transfer)
+                    if transfer.from == Address::ZERO && searcher.contains(&transfer.to) =>
+                {
+                    for token_id in &transfer.token_ids {
+                        held.insert(
+                            (transfer.collection, *token_id),
+                            NftMevLeg {
+                                tx_hash,
+                                trace_idx: transfer.trace_index,
+                                protocol: Protocol::Unknown,
+                                marketplace: transfer.collection,
+                                collection: transfer.collection,
+                                token_id: *token_id,
+                                ..Default::default()
+                            },
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    if sales.is_empty() {
+        return None
+    }
+
+    let kind = if acquisitions
+        .iter()
+        .all(|leg| leg.counterparty == Address::ZERO)
+    {
+        NftMevKind::MintSnipe
+    } else {
+        NftMevKind::Arbitrage
+    };
+
+    Some((kind, acquisitions, sales))
+}
+
+fn trade_leg(tx_hash: B256, trade: &NormalizedNftTrade, counterparty: Address) -> NftMevLeg {
+    NftMevLeg {
+        tx_hash,
+        trace_idx: trade.trace_index,
+        protocol: trade.protocol,
+        marketplace: trade.marketplace,
+        collection: trade.collection,
+        token_id: trade.token_id,
+        counterparty,
+        payment_token: trade.payment_token.address,
+        price: trade.price.clone().to_float(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use brontes_types::{
+        db::token_info::TokenInfoWithAddress,
+        normalized_actions::{NftOrderSide, NormalizedNftTransfer},
+    };
+
+    use super::*;
+
+    const BOT: Address = Address::repeat_byte(1);
+    const SELLER: Address = Address::repeat_byte(2);
+    const BIDDER: Address = Address::repeat_byte(3);
+    const COLLECTION: Address = Address::repeat_byte(4);
+    const SEAPORT: Address = Address::repeat_byte(5);
+    const BLUR: Address = Address::repeat_byte(6);
+
+    fn trade(
+        protocol: Protocol,
+        marketplace: Address,
+        side: NftOrderSide,
+        maker: Address,
+        taker: Address,
+        price: u64,
+    ) -> Action {
+        Action::NftTrade(NormalizedNftTrade {
+            protocol,
+            marketplace,
+            side,
+            maker,
+            taker,
+            collection: COLLECTION,
+            token_id: U256::from(7),
+            amount: U256::from(1),
+            payment_token: TokenInfoWithAddress::weth(),
+            price: Rational::from(price),
+            ..Default::default()
+        })
+    }
+
+    fn searcher() -> FastHashSet<Address> {
+        [BOT].into_iter().collect()
+    }
+
+    #[test]
+    fn test_cross_marketplace_arbitrage() {
+        let buy = [trade(Protocol::Seaport, SEAPORT, NftOrderSide::Ask, SELLER, BOT, 10)];
+        let sell = [trade(Protocol::Blur, BLUR, NftOrderSide::Bid, BIDDER, BOT, 12)];
+        let txs = [(B256::repeat_byte(1), &buy[..]), (B256::repeat_byte(2), &sell[..])];
+
+        let (kind, acquisitions, sales) = match_flips(txs.into_iter(), &searcher()).unwrap();
+        assert_eq!(kind, NftMevKind::Arbitrage);
+        assert_eq!(acquisitions.len(), 1);
+        assert_eq!(acquisitions[0].counterparty, SELLER);
+        assert_eq!(acquisitions[0].marketplace, SEAPORT);
+        assert_eq!(acquisitions[0].tx_hash, B256::repeat_byte(1));
+        assert_eq!(sales.len(), 1);
+        assert_eq!(sales[0].counterparty, BIDDER);
+        assert_eq!(sales[0].marketplace, BLUR);
+        assert_eq!(sales[0].price, 12.0);
+    }
+
+    #[test]
+    fn test_mint_snipe() {
+        let actions = [
+            Action::NftTransfer(NormalizedNftTransfer {
+                collection: COLLECTION,
+                from: Address::ZERO,
+                to: BOT,
+                token_ids: vec![U256::from(7)],
+                amounts: vec![U256::from(1)],
+                ..Default::default()
+            }),
+            trade(Protocol::Seaport, SEAPORT, NftOrderSide::Bid, BIDDER, BOT, 3),
+        ];
+        let txs = [(B256::repeat_byte(1), &actions[..])];
+
+        let (kind, acquisitions, sales) = match_flips(txs.into_iter(), &searcher()).unwrap();
+        assert_eq!(kind, NftMevKind::MintSnipe);
+        assert_eq!(acquisitions[0].counterparty, Address::ZERO);
+        assert_eq!(acquisitions[0].marketplace, COLLECTION);
+        assert_eq!(sales[0].counterparty, BIDDER);
+    }
+
+    #[test]
+    fn test_unsold_nft_is_not_mev() {
+        let buy = [trade(Protocol::Seaport, SEAPORT, NftOrderSide::Ask, SELLER, BOT, 10)];
+        // someone else sells the same nft, the bot keeps theirs
+        let other = [trade(Protocol::Blur, BLUR, NftOrderSide::Bid, BIDDER, SELLER, 12)];
+        let txs = [(B256::repeat_byte(1), &buy[..]), (B256::repeat_byte(2), &other[..])];
+
+        assert!(match_flips(txs.into_iter(), &searcher()).is_none());
+    }
+}
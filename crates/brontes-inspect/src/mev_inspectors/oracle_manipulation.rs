@@ -0,0 +1,515 @@
+use std::sync::Arc;
+
+use brontes_database::libmdbx::LibmdbxReader;
+use brontes_metrics::inspectors::OutlierMetrics;
+use brontes_types::{
+    db::{dex::PriceAt, normalized_actions::ActionKind},
+    mev::{Bundle, BundleData, MevType, OracleManipulation},
+    normalized_actions::{accounting::ActionAccounting, Action, LendingAction, NormalizedSwap},
+    structured_trace::TraceActions,
+    tree::{collect_address_set_for_accounting, root::NodeData, Node, Root},
+    BlockData, FastHashMap, FastHashSet, MultiBlockData, Protocol, ToFloatNearest,
+    TreeSearchBuilder, TxInfo,
+};
+use malachite::{num::basic::traits::Zero, Rational};
+use reth_primitives::Address;
+
+use super::MAX_PROFIT;
+use crate::{
+    shared_utils::SharedInspectorUtils, thresholds::BundleThresholds, Inspector, Metadata,
+};
+
+/// How much worse than the pre-transaction price the swaps on a pool have to
+/// be filled at for the pool's price to count as manipulated
+const MIN_PRICE_IMPACT: f64 = 0.1;
+
+/// Finds swaps that move a pool's price far enough that a lending market
+/// reading its price from that pool can be borrowed from, withdrawn from or
+/// liquidated against the skewed price. The lending action has to come after
+/// the swaps, either in the same transaction or in a later one sent by the
+/// same searcher, and has to read the manipulated pool while it runs.
+pub struct OracleManipulationInspector<'db, DB: LibmdbxReader> {
+    utils: SharedInspectorUtils<'db, DB>,
+}
+
+impl<'db, DB: LibmdbxReader> OracleManipulationInspector<'db, DB> {
+    pub fn new(
+        quote: Address,
+        db: &'db DB,
+        thresholds: BundleThresholds,
+        metrics: Option<OutlierMetrics>,
+    ) -> Self {
+        Self { utils: SharedInspectorUtils::new(quote, db, metrics).with_thresholds(thresholds) }
+    }
+}
+
+impl<DB: LibmdbxReader> Inspector for OracleManipulationInspector<'_, DB> {
+    type Result = Vec<Bundle>;
+
+    fn get_id(&self) -> &str {
+        "OracleManipulation"
+    }
+
+    fn get_quote_token(&self) -> Address {
+        self.utils.quote
+    }
+
+    fn keeps(&self, bundle: &Bundle) -> bool {
+        self.utils.thresholds.keeps(&bundle.header)
+    }
+
+    fn inspect_block(&self, mut data: MultiBlockData) -> Self::Result {
+        let block = data.per_block_data.pop().expect("no blocks");
+        let BlockData { metadata, tree } = block;
+
+        let ex = || {
+            let (tx, actions): (Vec<_>, Vec<_>) = tree
+                .clone()
+                .collect_all(
+                    TreeSearchBuilder::default()
+                        .with_actions([
+                            Action::is_swap,
+                            Action::is_transfer,
+                            Action::is_eth_transfer,
+                            Action::is_aggregator,
+                            Action::is_flash_loan,
+                        ])
+                        .with_tx_action_kinds([
+                            ActionKind::Swap,
+                            ActionKind::Lending,
+                            ActionKind::Liquidation,
+                        ]),
+                )
+                .unzip();
+            let tx_info = tree.get_tx_info_batch(&tx, self.utils.db);
+
+            let mut searchers: FastHashMap<Address, Vec<SearcherTx>> = FastHashMap::default();
+            for (actions, info) in actions.into_iter().zip(tx_info) {
+                let Some(info) = info else { continue };
+                let Some(root) = tree.get_root(info.tx_hash) else { continue };
+                let actions = self
+                    .utils
+                    .flatten_nested_actions(actions.into_iter(), &|action| {
+                        action.is_swap() || action.is_transfer() || action.is_eth_transfer()
+                    })
+                    .collect::<Vec<_>>();
+                let reads = lending_reads(root);
+                searchers
+                    .entry(info.eoa)
+                    .or_default()
+                    .push(SearcherTx { info, actions, reads });
+            }
+
+            searchers
+                .into_values()
+                .flat_map(|mut txs| {
+                    txs.sort_by_key(|tx| tx.info.tx_index);
+                    (0..txs.len())
+                        .filter_map(|i| self.calculate_manipulation(&txs, i, metadata.clone()))
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>()
+        };
+        self.utils
+            .get_metrics()
+            .map(|m| m.run_inspector(MevType::OracleManipulation, ex))
+            .unwrap_or_else(ex)
+    }
+}
+
+struct SearcherTx {
+    info:    TxInfo,
+    actions: Vec<Action>,
+    reads:   Vec<LendingRead>,
+}
+
+impl<DB: LibmdbxReader> OracleManipulationInspector<'_, DB> {
+    /// Looks for the exploit of a pool manipulated in `txs[idx]`. When the
+    /// swaps moved several pools, the one moved the most that is also read by
+    /// a lending action is taken.
+    fn calculate_manipulation(
+        &self,
+        txs: &[SearcherTx],
+        idx: usize,
+        metadata: Arc<Metadata>,
+    ) -> Option<Bundle> {
+        let manipulation = &txs[idx];
+        let swaps = manipulation
+            .actions
+            .iter()
+            .filter_map(Action::try_swaps_merged_ref)
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let (pool, price_impact, manipulation_swaps, exploit_idx, exploit) =
+            price_impacts(&swaps, |token, amount| {
+                self.utils.get_token_value_dex(
+                    manipulation.info.tx_index as usize,
+                    PriceAt::Before,
+                    token,
+                    amount,
+                    &metadata,
+                )
+            })
+            .into_iter()
+            .find_map(|(pool, impact, swaps)| {
+                let swapped_at = swaps.first()?.trace_index;
+                let (exploit_idx, exploit) = find_exploit(txs, idx, pool, swapped_at)?;
+                Some((pool, impact, swaps, exploit_idx, exploit.clone()))
+            })?;
+
+        let bundle_txs = if exploit_idx == idx {
+            vec![manipulation]
+        } else {
+            vec![manipulation, &txs[exploit_idx]]
+        };
+        let infos = bundle_txs
+            .iter()
+            .map(|tx| tx.info.clone())
+            .collect::<Vec<_>>();
+        let last_tx = infos.last()?;
+        let mev_addresses = collect_address_set_for_accounting(&infos);
+
+        let deltas = bundle_txs
+            .iter()
+            .flat_map(|tx| tx.actions.iter().cloned())
+            .filter(|a| a.is_eth_transfer() || a.is_transfer())
+            .chain(
+                infos
+                    .iter()
+                    .flat_map(|info| info.get_total_eth_value())
+                    .cloned()
+                    .map(Action::from),
+            )
+            .account_for_actions();
+
+        let (rev, mut has_dex_price) = if let Some(rev) = self.utils.get_deltas_usd(
+            last_tx.tx_index,
+            PriceAt::After,
+            &mev_addresses,
+            &deltas,
+            metadata.clone(),
+            false,
+        ) {
+            (Some(rev), true)
+        } else {
+            (Some(Rational::ZERO), false)
+        };
+
+        let gas_details = infos
+            .iter()
+            .map(|info| info.gas_details)
+            .collect::<Vec<_>>();
+        let gas_finalized = metadata.get_gas_price_usd(
+            gas_details.iter().map(|gas| gas.gas_paid()).sum(),
+            self.utils.quote,
+        );
+
+        let mut profit_usd = rev
+            .map(|rev| rev - &gas_finalized)
+            .filter(|_| has_dex_price)
+            .unwrap_or_default();
+
+        if profit_usd >= MAX_PROFIT || profit_usd <= -MAX_PROFIT {
+            has_dex_price = false;
+            profit_usd = Rational::ZERO;
+        }
+
+        let header = self.utils.build_bundle_header(
+            vec![deltas],
+            infos.iter().map(|info| info.tx_hash).collect(),
+            last_tx,
+            profit_usd.to_float(),
+            &gas_details,
+            metadata.clone(),
+            MevType::OracleManipulation,
+            !has_dex_price,
+            |this, token, amount| {
+                this.get_token_value_dex(
+                    last_tx.tx_index as usize,
+                    PriceAt::Average,
+                    token,
+                    &amount,
+                    &metadata,
+                )
+            },
+        );
+
+        let oracle_manipulation = OracleManipulation {
+            block_number: metadata.block_num,
+            manipulation_tx_hash: manipulation.info.tx_hash,
+            manipulated_pool: pool,
+            manipulation_swaps,
+            price_impact,
+            exploit_tx_hash: txs[exploit_idx].info.tx_hash,
+            exploited_protocol: exploit.protocol,
+            exploited_market: exploit.market,
+            exploit_trace_idx: exploit.trace_index,
+            gas_details,
+        };
+
+        Some(Bundle { header, data: BundleData::OracleManipulation(oracle_manipulation) })
+    }
+}
+
+/// The first lending action reading `pool` after it was swapped on at
+/// `swapped_at` in `txs[idx]`, along with the index of its transaction
+fn find_exploit(
+    txs: &[SearcherTx],
+    idx: usize,
+    pool: Address,
+    swapped_at: u64,
+) -> Option<(usize, &LendingRead)> {
+    txs.iter().enumerate().skip(idx).find_map(|(i, tx)| {
+        tx.reads
+            .iter()
+            .filter(|read| i != idx || read.trace_index > swapped_at)
+            .find(|read| read.reads.contains(&pool))
+            .map(|read| (i, read))
+    })
+}
+
+/// Groups the swaps by pool and prices what went into and came out of each
+/// pool at the price before the transaction. Only the swaps in the direction
+/// of the first swap on a pool are counted, the ones after are usually the
+/// searcher swapping back once the price has been used. Returns the pools
+/// moved by at least [`MIN_PRICE_IMPACT`], the most moved first.
+fn price_impacts(
+    swaps: &[NormalizedSwap],
+    value: impl Fn(Address, &Rational) -> Option<Rational>,
+) -> Vec<(Address, f64, Vec<NormalizedSwap>)> {
+    let mut pools: Vec<(Address, Vec<NormalizedSwap>)> = Vec::new();
+    for swap in swaps {
+        match pools.iter_mut().find(|(pool, _)| *pool == swap.pool) {
+            Some((_, pool_swaps)) => {
+                if pool_swaps[0].token_in.address == swap.token_in.address {
+                    pool_swaps.push(swap.clone());
+                }
+            }
+            None => pools.push((swap.pool, vec![swap.clone()])),
+        }
+    }
+
+    let mut impacts = pools
+        .into_iter()
+        .filter_map(|(pool, swaps)| {
+            let (value_in, value_out) = swaps.iter().try_fold(
+                (Rational::ZERO, Rational::ZERO),
+                |(value_in, value_out), swap| {
+                    Some((
+                        value_in + value(swap.token_in.address, &swap.amount_in)?,
+                        value_out + value(swap.token_out.address, &swap.amount_out)?,
+                    ))
+                },
+            )?;
+            if value_in == Rational::ZERO {
+                return None
+            }
+
+            let impact = 1.0 - (value_out / value_in).to_float();
+            (impact >= MIN_PRICE_IMPACT).then_some((pool, impact, swaps))
+        })
+        .collect::<Vec<_>>();
+    impacts.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+    impacts
+}
+
+/// A borrow, withdrawal or liquidation, which all depend on the price of the
+/// collateral, along with every contract it read from while running
+#[derive(Debug, Clone)]
+struct LendingRead {
+    trace_index: u64,
+    protocol:    Protocol,
+    market:      Address,
+    reads:       FastHashSet<Address>,
+}
+
+/// Oracles are read with static calls, so the targets of all static calls made
+/// under a lending action are the contracts its price could have come from.
+/// The oracle often reads the pool itself, so the whole subtree is searched.
+fn lending_reads(root: &Root<Action>) -> Vec<LendingRead> {
+    let mut reads = Vec::new();
+    collect_lending_reads(&root.head, &root.data_store, &mut reads);
+    reads
+}
+
+fn collect_lending_reads(node: &Node, data: &NodeData<Action>, reads: &mut Vec<LendingRead>) {
+    let lending = data.get_ref(node.data).and_then(|actions| {
+        actions.iter().find_map(|action| match action {
+            Action::Lending(l)
+                if matches!(l.action, LendingAction::Borrow | LendingAction::Withdraw) =>
+            {
+                Some((l.trace_index, l.protocol, l.market))
+            }
+            Action::Liquidation(l) => Some((l.trace_index, l.protocol, l.pool)),
+            _ => None,
+        })
+    });
+
+    if let Some((trace_index, protocol, market)) = lending {
+        let mut targets = FastHashSet::default();
+        collect_static_calls(node, data, &mut targets);
+        reads.push(LendingRead { trace_index, protocol, market, reads: targets });
+    }
+
+    node.inner
+        .iter()
+        .for_each(|inner| collect_lending_reads(inner, data, reads));
+}
+
+fn collect_static_calls(node: &Node, data: &NodeData<Action>, targets: &mut FastHashSet<Address>) {
+    if let Some(actions) = data.get_ref(node.data) {
+        targets.extend(actions.iter().filter_map(|action| match action {
+            Action::Unclassified(trace) if trace.is_static_call() => Some(trace.get_to_address()),
+            _ => None,
+        }));
+    }
+    node.inner
+        .iter()
+        .for_each(|inner| collect_static_calls(inner, data, targets));
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{Bytes, U64};
+    use brontes_types::{
+        db::token_info::TokenInfoWithAddress, normalized_actions::NormalizedLending,
+        structured_trace::TransactionTraceWithLogs, tree::GasDetails,
+    };
+    use reth_primitives::{B256, U256};
+    use reth_rpc_types::trace::parity::{
+        Action as TraceAction, CallAction, CallType, TransactionTrace,
+    };
+
+    use super::*;
+
+    const EOA: Address = Address::repeat_byte(1);
+    const BOT: Address = Address::repeat_byte(2);
+    const MARKET: Address = Address::repeat_byte(3);
+    const ORACLE: Address = Address::repeat_byte(4);
+    const POOL: Address = Address::repeat_byte(5);
+    const OTHER_POOL: Address = Address::repeat_byte(6);
+    const WETH: Address = Address::repeat_byte(7);
+    const TOKEN: Address = Address::repeat_byte(8);
+
+    fn call(
+        trace_idx: u64,
+        from: Address,
+        to: Address,
+        call_type: CallType,
+        trace_address: Vec<usize>,
+    ) -> Action {
+        Action::Unclassified(TransactionTraceWithLogs {
+            trace: TransactionTrace {
+                action: TraceAction::Call(CallAction {
+                    from,
+                    to,
+                    call_type,
+                    gas: U64::ZERO,
+                    input: Bytes::from_static(&[0xab, 0xcd, 0xef, 0x01]),
+                    value: U256::ZERO,
+                }),
+                error: None,
+                result: None,
+                subtraces: 0,
+                trace_address,
+            },
+            logs: vec![],
+            msg_sender: from,
+            trace_idx,
+            decoded_data: None,
+        })
+    }
+
+    fn swap(pool: Address, token_in: Address, amount_in: u64, amount_out: u64) -> NormalizedSwap {
+        NormalizedSwap {
+            pool,
+            token_in: TokenInfoWithAddress { address: token_in, ..Default::default() },
+            token_out: TokenInfoWithAddress {
+                address: if token_in == WETH { TOKEN } else { WETH },
+                ..Default::default()
+            },
+            amount_in: Rational::from(amount_in),
+            amount_out: Rational::from(amount_out),
+            ..Default::default()
+        }
+    }
+
+    /// one token is worth one weth before the transaction
+    fn value(_: Address, amount: &Rational) -> Option<Rational> {
+        Some(amount.clone())
+    }
+
+    #[test]
+    fn test_price_impact() {
+        let swaps = [
+            swap(POOL, WETH, 100, 60),
+            swap(OTHER_POOL, WETH, 100, 98),
+            // the swap back isn't part of the manipulation
+            swap(POOL, TOKEN, 60, 100),
+            swap(POOL, WETH, 100, 40),
+        ];
+
+        let impacts = price_impacts(&swaps, value);
+        assert_eq!(impacts.len(), 1);
+
+        let (pool, impact, swaps) = &impacts[0];
+        assert_eq!(*pool, POOL);
+        assert_eq!(*impact, 0.5);
+        assert_eq!(swaps.len(), 2);
+    }
+
+    #[test]
+    fn test_lending_reads() {
+        let mut root = Root {
+            position: 0,
+            head: Node::new(0, EOA, vec![]),
+            tx_hash: B256::ZERO,
+            private: false,
+            total_msg_value_transfers: vec![],
+            gas_details: GasDetails::default(),
+            data_store: NodeData(vec![Some(vec![call(0, EOA, BOT, CallType::Call, vec![])])]),
+            action_kinds: None,
+            aggregator_route: None,
+        };
+
+        let mut insert = |index: u64, from: Address, trace_address: Vec<usize>, action| {
+            root.insert(Node::new(index, from, trace_address), vec![action]);
+        };
+        // the bot swaps on the pool, then borrows from a market priced by an
+        // oracle that reads the pool
+        insert(
+            1,
+            BOT,
+            vec![0],
+            Action::Swap(NormalizedSwap { trace_index: 1, pool: POOL, ..Default::default() }),
+        );
+        insert(
+            2,
+            BOT,
+            vec![1],
+            Action::Lending(NormalizedLending {
+                trace_index: 2,
+                action: LendingAction::Borrow,
+                market: MARKET,
+                ..Default::default()
+            }),
+        );
+        insert(3, MARKET, vec![1, 0], call(3, MARKET, ORACLE, CallType::StaticCall, vec![1, 0]));
+        insert(
+            4,
+            ORACLE,
+            vec![1, 0, 0],
+            call(4, ORACLE, POOL, CallType::StaticCall, vec![1, 0, 0]),
+        );
+        // a call that changes state isn't a price read
+        insert(5, MARKET, vec![1, 1], call(5, MARKET, OTHER_POOL, CallType::Call, vec![1, 1]));
+
+        let reads = lending_reads(&root);
+        assert_eq!(reads.len(), 1);
+        assert_eq!(reads[0].trace_index, 2);
+        assert_eq!(reads[0].market, MARKET);
+        assert_eq!(reads[0].reads, [ORACLE, POOL].into_iter().collect());
+    }
+}
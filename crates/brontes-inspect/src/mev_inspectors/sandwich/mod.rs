@@ -5,12 +5,14 @@ use std::{
 
 use alloy_primitives::TxHash;
 use tracing::trace;
+mod multi_block;
+mod slippage;
 mod types;
 use brontes_database::libmdbx::LibmdbxReader;
 use brontes_metrics::inspectors::OutlierMetrics;
 use brontes_types::{
     db::dex::PriceAt,
-    mev::{Bundle, BundleData, MevType, Sandwich},
+    mev::{Bundle, BundleData, MevType, Sandwich, VictimLossAmount},
     normalized_actions::{
         accounting::ActionAccounting, Action, NormalizedSwap, NormalizedTransfer,
     },
@@ -20,11 +22,14 @@ use brontes_types::{
 };
 use itertools::Itertools;
 use malachite::{num::basic::traits::Zero, Rational};
+pub use multi_block::MultiBlockSandwichInspector;
 use reth_primitives::{Address, B256};
 use types::{PossibleSandwich, PossibleSandwichWithTxInfo};
 
 use super::MAX_PROFIT;
-use crate::{shared_utils::SharedInspectorUtils, Inspector, Metadata};
+use crate::{
+    shared_utils::SharedInspectorUtils, thresholds::BundleThresholds, Inspector, Metadata,
+};
 
 type GroupedVictims<'a> = HashMap<Address, Vec<&'a (Vec<NormalizedSwap>, Vec<NormalizedTransfer>)>>;
 
@@ -41,8 +46,13 @@ pub struct SandwichInspector<'db, DB: LibmdbxReader> {
 }
 
 impl<'db, DB: LibmdbxReader> SandwichInspector<'db, DB> {
-    pub fn new(quote: Address, db: &'db DB, metrics: Option<OutlierMetrics>) -> Self {
-        Self { utils: SharedInspectorUtils::new(quote, db, metrics) }
+    pub fn new(
+        quote: Address,
+        db: &'db DB,
+        thresholds: BundleThresholds,
+        metrics: Option<OutlierMetrics>,
+    ) -> Self {
+        Self { utils: SharedInspectorUtils::new(quote, db, metrics).with_thresholds(thresholds) }
     }
 }
 
@@ -57,6 +67,10 @@ impl<DB: LibmdbxReader> Inspector for SandwichInspector<'_, DB> {
         self.utils.quote
     }
 
+    fn keeps(&self, bundle: &Bundle) -> bool {
+        self.utils.thresholds.keeps(&bundle.header)
+    }
+
     fn inspect_block(&self, data: MultiBlockData) -> Self::Result {
         let BlockData { metadata, tree } = data.get_most_recent_block();
 
@@ -422,6 +436,63 @@ impl<DB: LibmdbxReader> SandwichInspector<'_, DB> {
         );
 
         let victim_swaps = victim_swaps.into_iter().map(|(s, _)| s).collect_vec();
+        let victim_slippage = victim_swaps_tx_hashes
+            .iter()
+            .flatten()
+            .zip(&victim_swaps)
+            .filter_map(|(tx_hash, swaps)| {
+                slippage::victim_slippage(&tree, *tx_hash, swaps, front_run_swaps.iter().flatten())
+            })
+            .collect();
+
+        // the rate each victim swap would have gotten had the frontrun not moved
+        // the pool
+        let first_frontrun_idx = possible_front_runs_info[0].tx_index as usize;
+        let pre_frontrun_rate = |swap: &NormalizedSwap| {
+            let price = |token| {
+                self.utils.get_token_price_on_dex(
+                    first_frontrun_idx,
+                    PriceAt::Before,
+                    token,
+                    &metadata,
+                )
+            };
+            let price_out = price(swap.token_out.address).filter(|p| *p != Rational::ZERO)?;
+            Some(price(swap.token_in.address)? / price_out)
+        };
+
+        let victim_losses = victim_info
+            .iter()
+            .flatten()
+            .zip(&victim_swaps)
+            .flat_map(|(info, swaps)| {
+                slippage::victim_token_losses(
+                    swaps,
+                    front_run_swaps.iter().flatten(),
+                    pre_frontrun_rate,
+                )
+                .into_iter()
+                .map(|loss| VictimLossAmount {
+                    tx_hash:           info.tx_hash,
+                    victim_eoa:        info.eoa,
+                    token:             loss.token,
+                    amount_lost_usd:   self
+                        .utils
+                        .get_token_value_dex(
+                            info.tx_index as usize,
+                            PriceAt::After,
+                            loss.token,
+                            &loss.lost,
+                            &metadata,
+                        )
+                        .unwrap_or_default()
+                        .to_float(),
+                    token_amount_lost: loss.lost.to_float(),
+                    price_degradation: loss.price_degradation().to_float(),
+                })
+                .collect_vec()
+            })
+            .collect();
 
         let sandwich = Sandwich {
             block_number: metadata.block_num,
@@ -434,6 +505,8 @@ impl<DB: LibmdbxReader> SandwichInspector<'_, DB> {
             backrun_tx_hash: backrun_info.tx_hash,
             backrun_swaps: back_run_swaps,
             backrun_gas_details: backrun_info.gas_details,
+            victim_slippage,
+            victim_losses,
         };
         tracing::debug!("{:#?}\n{:#?}", header, sandwich);
 
@@ -865,9 +938,12 @@ impl<DB: LibmdbxReader> SandwichInspector<'_, DB> {
         let result_contracts = get_possible_sandwich_duplicate_contracts(tree_clone_for_contracts);
 
         // Combine and deduplicate results
-        let set = Itertools::unique(result_senders.into_iter().chain(result_contracts))
-            .flat_map(Self::partition_into_gaps)
-            .collect::<Vec<_>>();
+        let set = strip_nested_sandwiches(
+            Itertools::unique(result_senders.into_iter().chain(result_contracts)).collect(),
+        )
+        .into_iter()
+        .flat_map(Self::partition_into_gaps)
+        .collect::<Vec<_>>();
 
         let tx_set = set
             .iter()
@@ -1135,6 +1211,52 @@ fn get_possible_sandwich_duplicate_contracts(
     possible_sandwiches.into_values().collect()
 }
 
+/// A searcher can sandwich a victim inside of another searcher's sandwich,
+/// in which case the inner frontrun and backrun show up as victims of the
+/// outer one. They are taken out of its victims so that each sandwich is
+/// checked against its own victims, and the inner one isn't dropped for
+/// overlapping with the outer one.
+fn strip_nested_sandwiches(sandwiches: Vec<PossibleSandwich>) -> Vec<PossibleSandwich> {
+    let searcher_txs = sandwiches
+        .iter()
+        .map(|ps| {
+            ps.possible_frontruns
+                .iter()
+                .copied()
+                .chain(std::iter::once(ps.possible_backrun))
+                .collect_vec()
+        })
+        .collect_vec();
+
+    sandwiches
+        .into_iter()
+        .enumerate()
+        .map(|(i, mut ps)| {
+            let victims = ps
+                .victims
+                .iter()
+                .flatten()
+                .copied()
+                .collect::<FastHashSet<_>>();
+
+            let nested = searcher_txs
+                .iter()
+                .enumerate()
+                .filter(|(j, txs)| *j != i && txs.iter().all(|tx| victims.contains(tx)))
+                .flat_map(|(_, txs)| txs.iter().copied())
+                .collect::<FastHashSet<_>>();
+
+            if !nested.is_empty() {
+                ps.victims
+                    .iter_mut()
+                    .for_each(|victims| victims.retain(|tx| !nested.contains(tx)));
+            }
+
+            ps
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -1147,6 +1269,29 @@ mod tests {
         Inspectors,
     };
 
+    #[test]
+    fn test_strip_nested_sandwiches() {
+        let tx = B256::with_last_byte;
+        let sandwich = |frontrun: u8, victims: Vec<u8>, backrun: u8| PossibleSandwich {
+            eoa:                   Address::ZERO,
+            possible_frontruns:    vec![tx(frontrun)],
+            possible_backrun:      tx(backrun),
+            mev_executor_contract: Address::ZERO,
+            victims:               vec![victims.into_iter().map(tx).collect()],
+        };
+
+        // 1 and 5 sandwich 2, 3 and 4, which are another sandwich of 3
+        let stripped = strip_nested_sandwiches(vec![
+            sandwich(1, vec![2, 3, 4], 5),
+            sandwich(2, vec![3], 4),
+            sandwich(6, vec![7], 8),
+        ]);
+
+        assert_eq!(stripped[0].victims, vec![vec![tx(3)]]);
+        assert_eq!(stripped[1].victims, vec![vec![tx(3)]]);
+        assert_eq!(stripped[2].victims, vec![vec![tx(7)]]);
+    }
+
     #[brontes_macros::test]
     async fn test_sandwich_different_eoa() {
         let inspector_util = InspectorTestUtils::new(USDC_ADDRESS, 1.0).await;
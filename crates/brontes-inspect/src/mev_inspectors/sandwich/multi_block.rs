@@ -0,0 +1,370 @@
+use brontes_database::libmdbx::LibmdbxReader;
+use brontes_metrics::inspectors::OutlierMetrics;
+use brontes_types::{
+    db::dex::PriceAt,
+    mev::{Bundle, BundleData, MevType, Sandwich, VictimLossAmount},
+    normalized_actions::{accounting::ActionAccounting, Action, NormalizedSwap},
+    tree::collect_address_set_for_accounting,
+    BlockData, FastHashSet, MultiBlockData, ToFloatNearest, TreeSearchBuilder, TxInfo,
+};
+use itertools::Itertools;
+use malachite::{num::basic::traits::Zero, Rational};
+use reth_primitives::Address;
+
+use super::{super::MAX_PROFIT, slippage};
+use crate::{shared_utils::SharedInspectorUtils, thresholds::BundleThresholds, Inspector};
+
+/// Sandwiches split over two consecutive blocks: the searcher's frontrun is in
+/// the previous block and the backrun in this one, with the victims trading
+/// the same pool in the frontrun's direction between the two. Searchers that
+/// build or bribe both blocks can do this, which [`super::SandwichInspector`]
+/// can't see as it only looks at one block.
+pub struct MultiBlockSandwichInspector<'db, DB: LibmdbxReader> {
+    utils: SharedInspectorUtils<'db, DB>,
+}
+
+impl<'db, DB: LibmdbxReader> MultiBlockSandwichInspector<'db, DB> {
+    pub fn new(
+        quote: Address,
+        db: &'db DB,
+        thresholds: BundleThresholds,
+        metrics: Option<OutlierMetrics>,
+    ) -> Self {
+        Self { utils: SharedInspectorUtils::new(quote, db, metrics).with_thresholds(thresholds) }
+    }
+}
+
+impl<DB: LibmdbxReader> Inspector for MultiBlockSandwichInspector<'_, DB> {
+    type Result = Vec<Bundle>;
+
+    // the previous block for the frontrun, this one for the backrun
+    fn block_window(&self) -> usize {
+        2
+    }
+
+    fn get_id(&self) -> &str {
+        "MultiBlockSandwich"
+    }
+
+    fn get_quote_token(&self) -> Address {
+        self.utils.quote
+    }
+
+    fn keeps(&self, bundle: &Bundle) -> bool {
+        self.utils.thresholds.keeps(&bundle.header)
+    }
+
+    fn inspect_block(&self, data: MultiBlockData) -> Self::Result {
+        let Some(prev) = data.previous_blocks().last() else { return vec![] };
+        let cur = data.get_most_recent_block();
+
+        let ex = || {
+            let prev_txs = self.block_txs(prev);
+            let cur_txs = self.block_txs(cur);
+            let mut used_backruns = FastHashSet::default();
+
+            prev_txs
+                .iter()
+                .filter_map(|frontrun| {
+                    let (backrun, pool) = cur_txs.iter().find_map(|backrun| {
+                        if used_backruns.contains(&backrun.info.tx_hash)
+                            || !same_searcher(&frontrun.info, &backrun.info)
+                        {
+                            return None
+                        }
+                        Some((backrun, crossed_pool(&frontrun.swaps, &backrun.swaps)?))
+                    })?;
+
+                    let victims = prev_txs
+                        .iter()
+                        .filter(|tx| tx.info.tx_index > frontrun.info.tx_index)
+                        .map(|tx| (tx, prev))
+                        .chain(
+                            cur_txs
+                                .iter()
+                                .filter(|tx| tx.info.tx_index < backrun.info.tx_index)
+                                .map(|tx| (tx, cur)),
+                        )
+                        .filter(|(tx, _)| {
+                            !same_searcher(&tx.info, &frontrun.info)
+                                && tx.swaps.iter().any(|swap| pool.matches(swap))
+                        })
+                        .collect_vec();
+                    if victims.is_empty() {
+                        return None
+                    }
+
+                    used_backruns.insert(backrun.info.tx_hash);
+                    Some(self.calculate_sandwich((frontrun, prev), (backrun, cur), victims))
+                })
+                .collect_vec()
+        };
+
+        self.utils
+            .get_metrics()
+            .map(|m| m.run_inspector(MevType::Sandwich, ex))
+            .unwrap_or_else(ex)
+    }
+}
+
+impl<DB: LibmdbxReader> MultiBlockSandwichInspector<'_, DB> {
+    /// The transactions in the block that swapped, with their transfers
+    fn block_txs(&self, block: &BlockData) -> Vec<BlockTx> {
+        let (tx, actions): (Vec<_>, Vec<_>) = block
+            .tree
+            .clone()
+            .collect_all(TreeSearchBuilder::default().with_actions([
+                Action::is_swap,
+                Action::is_transfer,
+                Action::is_eth_transfer,
+                Action::is_nested_action,
+            ]))
+            .unzip();
+        let tx_info = block.tree.get_tx_info_batch(&tx, self.utils.db);
+
+        actions
+            .into_iter()
+            .zip(tx_info)
+            .filter_map(|(actions, info)| {
+                let actions = self
+                    .utils
+                    .flatten_nested_actions_default(actions.into_iter())
+                    .collect_vec();
+                let swaps = actions
+                    .iter()
+                    .filter_map(Action::try_swaps_merged_ref)
+                    .cloned()
+                    .collect_vec();
+                if swaps.is_empty() {
+                    return None
+                }
+
+                Some(BlockTx { info: info?, actions, swaps })
+            })
+            .collect()
+    }
+
+    fn calculate_sandwich(
+        &self,
+        (frontrun, frontrun_block): (&BlockTx, &BlockData),
+        (backrun, backrun_block): (&BlockTx, &BlockData),
+        victims: Vec<(&BlockTx, &BlockData)>,
+    ) -> Bundle {
+        let metadata = &backrun_block.metadata;
+        let infos = [frontrun.info.clone(), backrun.info.clone()];
+        let mev_addresses = collect_address_set_for_accounting(&infos);
+
+        let deltas = frontrun
+            .actions
+            .iter()
+            .chain(&backrun.actions)
+            .filter(|a| a.is_transfer() || a.is_eth_transfer())
+            .cloned()
+            .chain(
+                infos
+                    .iter()
+                    .flat_map(|info| info.get_total_eth_value())
+                    .cloned()
+                    .map(Action::from),
+            )
+            .account_for_actions();
+
+        // each transaction's gas at the eth price of the block it landed in
+        let gas_used = frontrun_block
+            .metadata
+            .get_gas_price_usd(frontrun.info.gas_details.gas_paid(), self.utils.quote)
+            + metadata.get_gas_price_usd(backrun.info.gas_details.gas_paid(), self.utils.quote);
+
+        let rev = self.utils.get_deltas_usd(
+            backrun.info.tx_index,
+            PriceAt::After,
+            &mev_addresses,
+            &deltas,
+            metadata.clone(),
+            true,
+        );
+        let mut has_dex_price = rev.is_some();
+        let mut profit_usd = rev.map(|rev| rev - gas_used).unwrap_or_default();
+
+        if profit_usd >= MAX_PROFIT {
+            has_dex_price = false;
+            profit_usd = Rational::ZERO;
+        }
+
+        let victim_hashes = victims.iter().map(|(tx, _)| tx.info.tx_hash).collect_vec();
+        let bundle_hashes = std::iter::once(frontrun.info.tx_hash)
+            .chain(victim_hashes.iter().copied())
+            .chain(std::iter::once(backrun.info.tx_hash))
+            .collect_vec();
+
+        let header = self.utils.build_bundle_header(
+            vec![deltas],
+            bundle_hashes,
+            &backrun.info,
+            profit_usd.to_float(),
+            &[frontrun.info.gas_details, backrun.info.gas_details],
+            metadata.clone(),
+            MevType::Sandwich,
+            !has_dex_price,
+            |this, token, amount| {
+                this.get_token_value_dex(
+                    backrun.info.tx_index as usize,
+                    PriceAt::Average,
+                    token,
+                    &amount,
+                    metadata,
+                )
+            },
+        );
+
+        // the rate the victims would have gotten had the frontrun not moved the
+        // pool, from the previous block's prices
+        let pre_frontrun_rate = |swap: &NormalizedSwap| {
+            let price = |token| {
+                self.utils.get_token_price_on_dex(
+                    frontrun.info.tx_index as usize,
+                    PriceAt::Before,
+                    token,
+                    &frontrun_block.metadata,
+                )
+            };
+            let price_out = price(swap.token_out.address).filter(|p| *p != Rational::ZERO)?;
+            Some(price(swap.token_in.address)? / price_out)
+        };
+
+        let victim_slippage = victims
+            .iter()
+            .filter_map(|(tx, block)| {
+                slippage::victim_slippage(
+                    &block.tree,
+                    tx.info.tx_hash,
+                    &tx.swaps,
+                    frontrun.swaps.iter(),
+                )
+            })
+            .collect();
+
+        let victim_losses = victims
+            .iter()
+            .flat_map(|(tx, block)| {
+                slippage::victim_token_losses(&tx.swaps, frontrun.swaps.iter(), pre_frontrun_rate)
+                    .into_iter()
+                    .map(|loss| VictimLossAmount {
+                        tx_hash:           tx.info.tx_hash,
+                        victim_eoa:        tx.info.eoa,
+                        token:             loss.token,
+                        amount_lost_usd:   self
+                            .utils
+                            .get_token_value_dex(
+                                tx.info.tx_index as usize,
+                                PriceAt::After,
+                                loss.token,
+                                &loss.lost,
+                                &block.metadata,
+                            )
+                            .unwrap_or_default()
+                            .to_float(),
+                        token_amount_lost: loss.lost.to_float(),
+                        price_degradation: loss.price_degradation().to_float(),
+                    })
+                    .collect_vec()
+            })
+            .collect();
+
+        let sandwich = Sandwich {
+            block_number: metadata.block_num,
+            frontrun_tx_hash: vec![frontrun.info.tx_hash],
+            frontrun_swaps: vec![frontrun.swaps.clone()],
+            frontrun_gas_details: vec![frontrun.info.gas_details],
+            victim_swaps_tx_hashes: vec![victim_hashes],
+            victim_swaps: victims.iter().map(|(tx, _)| tx.swaps.clone()).collect(),
+            victim_swaps_gas_details: victims.iter().map(|(tx, _)| tx.info.gas_details).collect(),
+            backrun_tx_hash: backrun.info.tx_hash,
+            backrun_swaps: backrun.swaps.clone(),
+            backrun_gas_details: backrun.info.gas_details,
+            victim_slippage,
+            victim_losses,
+        };
+
+        Bundle { header, data: BundleData::Sandwich(sandwich) }
+    }
+}
+
+/// A transaction that swapped, flattened down to its swaps and transfers
+struct BlockTx {
+    info:    TxInfo,
+    actions: Vec<Action>,
+    swaps:   Vec<NormalizedSwap>,
+}
+
+/// A pool the frontrun traded in, and the direction it traded it
+#[derive(Debug, PartialEq)]
+struct CrossedPool {
+    pool:      Address,
+    token_in:  Address,
+    token_out: Address,
+}
+
+impl CrossedPool {
+    /// Whether the swap trades the pool in the frontrun's direction
+    fn matches(&self, swap: &NormalizedSwap) -> bool {
+        swap.pool == self.pool
+            && swap.token_in.address == self.token_in
+            && swap.token_out.address == self.token_out
+    }
+}
+
+/// Both transactions came from the same searcher, by sender or by the
+/// contract they went through
+fn same_searcher(a: &TxInfo, b: &TxInfo) -> bool {
+    a.eoa == b.eoa || (a.mev_contract.is_some() && a.mev_contract == b.mev_contract)
+}
+
+/// The first pool the frontrun swapped in that the backrun swapped back out
+/// of, in the opposite direction
+fn crossed_pool(frontrun: &[NormalizedSwap], backrun: &[NormalizedSwap]) -> Option<CrossedPool> {
+    frontrun.iter().find_map(|front| {
+        backrun
+            .iter()
+            .any(|back| {
+                back.pool == front.pool
+                    && back.token_in.address == front.token_out.address
+                    && back.token_out.address == front.token_in.address
+            })
+            .then_some(CrossedPool {
+                pool:      front.pool,
+                token_in:  front.token_in.address,
+                token_out: front.token_out.address,
+            })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POOL: Address = Address::repeat_byte(1);
+    const WETH: Address = Address::repeat_byte(2);
+    const USDC: Address = Address::repeat_byte(3);
+
+    fn swap(pool: Address, token_in: Address, token_out: Address) -> NormalizedSwap {
+        let mut swap = NormalizedSwap { pool, ..Default::default() };
+        swap.token_in.address = token_in;
+        swap.token_out.address = token_out;
+        swap
+    }
+
+    #[test]
+    fn test_crossed_pool() {
+        let frontrun = [swap(POOL, WETH, USDC)];
+
+        let crossed = crossed_pool(&frontrun, &[swap(POOL, USDC, WETH)]).unwrap();
+        assert_eq!(crossed, CrossedPool { pool: POOL, token_in: WETH, token_out: USDC });
+        assert!(crossed.matches(&swap(POOL, WETH, USDC)));
+        assert!(!crossed.matches(&swap(POOL, USDC, WETH)));
+
+        // trading further in the same direction doesn't close the position
+        assert_eq!(crossed_pool(&frontrun, &[swap(POOL, WETH, USDC)]), None);
+        assert_eq!(crossed_pool(&frontrun, &[swap(Address::ZERO, USDC, WETH)]), None);
+    }
+}
@@ -0,0 +1,504 @@
+//! Decodes the slippage limits victims set on their router calls so that we
+//! can report how close each victim was filled to their limit and how much of
+//! the slippage they allowed was eaten by the frontrun, along with what the
+//! victims lost to it.
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::{sol, SolInterface};
+use brontes_types::{
+    mev::VictimSlippage,
+    normalized_actions::{Action, NormalizedSwap},
+    structured_trace::TraceActions,
+    tree::BlockTree,
+    ToFloatNearest, ToScaledRational,
+};
+use malachite::{num::basic::traits::Zero, Rational};
+use reth_primitives::B256;
+
+sol! {
+    interface IUniswapV2Router {
+        function swapExactTokensForTokens(uint256 amountIn, uint256 amountOutMin, address[] path, address to, uint256 deadline);
+        function swapExactTokensForTokensSupportingFeeOnTransferTokens(uint256 amountIn, uint256 amountOutMin, address[] path, address to, uint256 deadline);
+        function swapExactETHForTokens(uint256 amountOutMin, address[] path, address to, uint256 deadline);
+        function swapExactETHForTokensSupportingFeeOnTransferTokens(uint256 amountOutMin, address[] path, address to, uint256 deadline);
+        function swapExactTokensForETH(uint256 amountIn, uint256 amountOutMin, address[] path, address to, uint256 deadline);
+        function swapExactTokensForETHSupportingFeeOnTransferTokens(uint256 amountIn, uint256 amountOutMin, address[] path, address to, uint256 deadline);
+        function swapTokensForExactTokens(uint256 amountOut, uint256 amountInMax, address[] path, address to, uint256 deadline);
+        function swapTokensForExactETH(uint256 amountOut, uint256 amountInMax, address[] path, address to, uint256 deadline);
+        function swapETHForExactTokens(uint256 amountOut, address[] path, address to, uint256 deadline);
+    }
+
+    interface IUniswapV3SwapRouter {
+        struct ExactInputSingleParams {
+            address tokenIn;
+            address tokenOut;
+            uint24 fee;
+            address recipient;
+            uint256 deadline;
+            uint256 amountIn;
+            uint256 amountOutMinimum;
+            uint160 sqrtPriceLimitX96;
+        }
+        struct ExactInputParams {
+            bytes path;
+            address recipient;
+            uint256 deadline;
+            uint256 amountIn;
+            uint256 amountOutMinimum;
+        }
+        struct ExactOutputSingleParams {
+            address tokenIn;
+            address tokenOut;
+            uint24 fee;
+            address recipient;
+            uint256 deadline;
+            uint256 amountOut;
+            uint256 amountInMaximum;
+            uint160 sqrtPriceLimitX96;
+        }
+        struct ExactOutputParams {
+            bytes path;
+            address recipient;
+            uint256 deadline;
+            uint256 amountOut;
+            uint256 amountInMaximum;
+        }
+
+        function exactInputSingle(ExactInputSingleParams params);
+        function exactInput(ExactInputParams params);
+        function exactOutputSingle(ExactOutputSingleParams params);
+        function exactOutput(ExactOutputParams params);
+        function multicall(bytes[] data);
+        function multicall(uint256 deadline, bytes[] data);
+    }
+
+    /// SwapRouter02 dropped the deadline from the params
+    interface IUniswapV3SwapRouter02 {
+        struct ExactInputSingleParams {
+            address tokenIn;
+            address tokenOut;
+            uint24 fee;
+            address recipient;
+            uint256 amountIn;
+            uint256 amountOutMinimum;
+            uint160 sqrtPriceLimitX96;
+        }
+        struct ExactInputParams {
+            bytes path;
+            address recipient;
+            uint256 amountIn;
+            uint256 amountOutMinimum;
+        }
+        struct ExactOutputSingleParams {
+            address tokenIn;
+            address tokenOut;
+            uint24 fee;
+            address recipient;
+            uint256 amountOut;
+            uint256 amountInMaximum;
+            uint160 sqrtPriceLimitX96;
+        }
+        struct ExactOutputParams {
+            bytes path;
+            address recipient;
+            uint256 amountOut;
+            uint256 amountInMaximum;
+        }
+
+        function exactInputSingle(ExactInputSingleParams params);
+        function exactInput(ExactInputParams params);
+        function exactOutputSingle(ExactOutputSingleParams params);
+        function exactOutput(ExactOutputParams params);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SlippageLimit {
+    /// output token for exact in swaps, input token for exact out swaps
+    pub token:            Address,
+    pub exact_in:         bool,
+    pub limit:            U256,
+    pub sqrt_price_limit: U256,
+}
+
+impl SlippageLimit {
+    fn exact_in(token: Address, limit: U256) -> Self {
+        Self { token, exact_in: true, limit, sqrt_price_limit: U256::ZERO }
+    }
+
+    fn exact_out(token: Address, limit: U256) -> Self {
+        Self { token, exact_in: false, limit, sqrt_price_limit: U256::ZERO }
+    }
+
+    fn with_sqrt_price_limit(mut self, sqrt_price_limit: U256) -> Self {
+        self.sqrt_price_limit = sqrt_price_limit;
+        self
+    }
+}
+
+/// Both for exact in and exact out uniswap v3 paths, the last token in the
+/// path is the one the limit is denominated in, as exact out paths are
+/// encoded in reverse
+fn v3_path_limit_token(path: &[u8]) -> Option<Address> {
+    (path.len() >= 20).then(|| Address::from_slice(&path[path.len() - 20..]))
+}
+
+pub(crate) fn decode_slippage_limit(calldata: &[u8], msg_value: U256) -> Option<SlippageLimit> {
+    use IUniswapV2Router::IUniswapV2RouterCalls as V2;
+    use IUniswapV3SwapRouter::IUniswapV3SwapRouterCalls as V3;
+    use IUniswapV3SwapRouter02::IUniswapV3SwapRouter02Calls as V3Router02;
+
+    if let Ok(call) = V2::abi_decode(calldata, false) {
+        return match call {
+            V2::swapExactTokensForTokens(c) => {
+                Some(SlippageLimit::exact_in(*c.path.last()?, c.amountOutMin))
+            }
+            V2::swapExactTokensForTokensSupportingFeeOnTransferTokens(c) => {
+                Some(SlippageLimit::exact_in(*c.path.last()?, c.amountOutMin))
+            }
+            V2::swapExactETHForTokens(c) => {
+                Some(SlippageLimit::exact_in(*c.path.last()?, c.amountOutMin))
+            }
+            V2::swapExactETHForTokensSupportingFeeOnTransferTokens(c) => {
+                Some(SlippageLimit::exact_in(*c.path.last()?, c.amountOutMin))
+            }
+            V2::swapExactTokensForETH(c) => {
+                Some(SlippageLimit::exact_in(*c.path.last()?, c.amountOutMin))
+            }
+            V2::swapExactTokensForETHSupportingFeeOnTransferTokens(c) => {
+                Some(SlippageLimit::exact_in(*c.path.last()?, c.amountOutMin))
+            }
+            V2::swapTokensForExactTokens(c) => {
+                Some(SlippageLimit::exact_out(*c.path.first()?, c.amountInMax))
+            }
+            V2::swapTokensForExactETH(c) => {
+                Some(SlippageLimit::exact_out(*c.path.first()?, c.amountInMax))
+            }
+            // the most the user is willing to pay is the eth they sent along
+            V2::swapETHForExactTokens(c) => {
+                Some(SlippageLimit::exact_out(*c.path.first()?, msg_value))
+            }
+        }
+    }
+
+    if let Ok(call) = V3::abi_decode(calldata, false) {
+        return match call {
+            V3::exactInputSingle(c) => Some(
+                SlippageLimit::exact_in(c.params.tokenOut, c.params.amountOutMinimum)
+                    .with_sqrt_price_limit(U256::from(c.params.sqrtPriceLimitX96)),
+            ),
+            V3::exactInput(c) => Some(SlippageLimit::exact_in(
+                v3_path_limit_token(&c.params.path)?,
+                c.params.amountOutMinimum,
+            )),
+            V3::exactOutputSingle(c) => Some(
+                SlippageLimit::exact_out(c.params.tokenIn, c.params.amountInMaximum)
+                    .with_sqrt_price_limit(U256::from(c.params.sqrtPriceLimitX96)),
+            ),
+            V3::exactOutput(c) => Some(SlippageLimit::exact_out(
+                v3_path_limit_token(&c.params.path)?,
+                c.params.amountInMaximum,
+            )),
+            // router swaps are commonly wrapped in a multicall, the first swap
+            // with a limit is the one we care about
+            V3::multicall_0(c) => c
+                .data
+                .iter()
+                .find_map(|data| decode_slippage_limit(data, msg_value)),
+            V3::multicall_1(c) => c
+                .data
+                .iter()
+                .find_map(|data| decode_slippage_limit(data, msg_value)),
+        }
+    }
+
+    match V3Router02::abi_decode(calldata, false).ok()? {
+        V3Router02::exactInputSingle(c) => Some(
+            SlippageLimit::exact_in(c.params.tokenOut, c.params.amountOutMinimum)
+                .with_sqrt_price_limit(U256::from(c.params.sqrtPriceLimitX96)),
+        ),
+        V3Router02::exactInput(c) => Some(SlippageLimit::exact_in(
+            v3_path_limit_token(&c.params.path)?,
+            c.params.amountOutMinimum,
+        )),
+        V3Router02::exactOutputSingle(c) => Some(
+            SlippageLimit::exact_out(c.params.tokenIn, c.params.amountInMaximum)
+                .with_sqrt_price_limit(U256::from(c.params.sqrtPriceLimitX96)),
+        ),
+        V3Router02::exactOutput(c) => Some(SlippageLimit::exact_out(
+            v3_path_limit_token(&c.params.path)?,
+            c.params.amountInMaximum,
+        )),
+    }
+}
+
+/// Compares the slippage limit of the victim transaction against its swaps. If
+/// the frontrun traded the same pool in the same direction as the victim, its
+/// execution price is used as an estimate of the price the victim was quoted,
+/// which lets us work out how much of the allowed slippage was consumed.
+pub(crate) fn victim_slippage<'a>(
+    tree: &BlockTree<Action>,
+    tx_hash: B256,
+    victim_swaps: &[NormalizedSwap],
+    frontrun_swaps: impl Iterator<Item = &'a NormalizedSwap>,
+) -> Option<VictimSlippage> {
+    let Action::Unclassified(root) = tree.get_root(tx_hash)?.get_root_action() else { return None };
+    let limit = decode_slippage_limit(&root.get_calldata(), root.get_msg_value())?;
+
+    let limit_swaps = victim_swaps
+        .iter()
+        .filter(|swap| {
+            if limit.exact_in {
+                swap.token_out.address == limit.token
+            } else {
+                swap.token_in.address == limit.token
+            }
+        })
+        .collect::<Vec<_>>();
+    let edge = limit_swaps.first()?;
+
+    let (decimals, realized) = if limit.exact_in {
+        (
+            edge.token_out.decimals,
+            limit_swaps
+                .iter()
+                .map(|swap| &swap.amount_out)
+                .sum::<Rational>(),
+        )
+    } else {
+        (
+            edge.token_in.decimals,
+            limit_swaps
+                .iter()
+                .map(|swap| &swap.amount_in)
+                .sum::<Rational>(),
+        )
+    };
+
+    if realized == Rational::ZERO {
+        return None
+    }
+
+    let limit_amount = limit.limit.to_scaled_rational(decimals);
+    let remaining = if limit.exact_in {
+        (&realized - &limit_amount) / &realized
+    } else {
+        (&limit_amount - &realized) / &realized
+    };
+
+    let consumed = frontrun_swaps
+        .filter(|swap| {
+            swap.pool == edge.pool
+                && swap.token_in.address == edge.token_in.address
+                && swap.token_out.address == edge.token_out.address
+                && swap.amount_in != Rational::ZERO
+                && swap.amount_out != Rational::ZERO
+        })
+        .map(|frontrun| &frontrun.amount_out / &frontrun.amount_in)
+        .next()
+        .and_then(|quoted_rate| {
+            let (consumed, allowed) = if limit.exact_in {
+                let quoted = &realized - &edge.amount_out + &edge.amount_in * quoted_rate;
+                (&quoted - &realized, &quoted - &limit_amount)
+            } else {
+                let quoted = &realized - &edge.amount_in + &edge.amount_out / quoted_rate;
+                (&realized - &quoted, &limit_amount - &quoted)
+            };
+
+            (allowed > Rational::ZERO).then(|| (consumed / allowed).to_float().clamp(0.0, 1.0))
+        });
+
+    Some(VictimSlippage {
+        tx_hash,
+        token: limit.token,
+        exact_in: limit.exact_in,
+        limit: limit_amount.to_float(),
+        realized: realized.to_float(),
+        sqrt_price_limit: limit.sqrt_price_limit,
+        remaining_slippage: remaining.to_float(),
+        consumed_slippage: consumed,
+    })
+}
+
+/// What a victim lost in one of the tokens they received
+#[derive(Debug, PartialEq)]
+pub(crate) struct TokenLoss {
+    pub token:    Address,
+    /// What they would have received at the price before the frontrun
+    pub expected: Rational,
+    pub lost:     Rational,
+}
+
+impl TokenLoss {
+    /// The share of the expected amount that was lost
+    pub fn price_degradation(&self) -> Rational {
+        if self.expected == Rational::ZERO {
+            return Rational::ZERO
+        }
+        &self.lost / &self.expected
+    }
+}
+
+/// What the victim lost on each pool the frontrun traded in the same
+/// direction before them, in the token they received, summed per token. The
+/// loss is what they would have received at the rate before the frontrun less
+/// what they did. `pre_frontrun_rate` gives that rate in tokens out per token
+/// in; where it has none the frontrun's execution price is used, as the price
+/// the victim would have gotten without it.
+pub(crate) fn victim_token_losses<'a>(
+    victim_swaps: &[NormalizedSwap],
+    frontrun_swaps: impl Iterator<Item = &'a NormalizedSwap> + Clone,
+    pre_frontrun_rate: impl Fn(&NormalizedSwap) -> Option<Rational>,
+) -> Vec<TokenLoss> {
+    let mut losses: Vec<TokenLoss> = Vec::new();
+
+    for swap in victim_swaps {
+        let Some(frontrun) = frontrun_swaps.clone().find(|frontrun| {
+            frontrun.pool == swap.pool
+                && frontrun.token_in.address == swap.token_in.address
+                && frontrun.token_out.address == swap.token_out.address
+                && frontrun.amount_in != Rational::ZERO
+        }) else {
+            continue
+        };
+        let rate =
+            pre_frontrun_rate(swap).unwrap_or_else(|| &frontrun.amount_out / &frontrun.amount_in);
+
+        let expected = &swap.amount_in * rate;
+        let lost = &expected - &swap.amount_out;
+        if lost <= Rational::ZERO {
+            continue
+        }
+
+        match losses
+            .iter_mut()
+            .find(|loss| loss.token == swap.token_out.address)
+        {
+            Some(total) => {
+                total.expected += expected;
+                total.lost += lost;
+            }
+            None => losses.push(TokenLoss { token: swap.token_out.address, expected, lost }),
+        }
+    }
+
+    losses
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{hex, Bytes};
+    use alloy_sol_types::SolCall;
+
+    use super::*;
+
+    #[test]
+    fn test_victim_token_losses() {
+        let pool = Address::repeat_byte(1);
+        let weth = Address::repeat_byte(2);
+        let usdc = Address::repeat_byte(3);
+        let swap = |token_in: Address, token_out: Address, amount_in: u64, amount_out: u64| {
+            let mut swap = NormalizedSwap {
+                pool,
+                amount_in: Rational::from(amount_in),
+                amount_out: Rational::from(amount_out),
+                ..Default::default()
+            };
+            swap.token_in.address = token_in;
+            swap.token_out.address = token_out;
+            swap
+        };
+
+        // the frontrun got 2 usdc per weth, the victim only 1.5
+        let frontrun = [swap(weth, usdc, 10, 20), swap(usdc, weth, 20, 10)];
+        let victims = [swap(weth, usdc, 4, 6), swap(weth, usdc, 2, 3)];
+
+        let losses = victim_token_losses(&victims, frontrun.iter(), |_| None);
+        assert_eq!(
+            losses,
+            vec![TokenLoss {
+                token:    usdc,
+                expected: Rational::from(12),
+                lost:     Rational::from(3),
+            }]
+        );
+        assert_eq!(losses[0].price_degradation(), Rational::from_unsigneds(1u8, 4));
+        // nothing lost trading against the frontrun
+        assert!(victim_token_losses(&[swap(usdc, weth, 10, 5)], frontrun[..1].iter(), |_| None)
+            .is_empty());
+
+        // before the frontrun the pool gave 2.5 usdc per weth
+        let losses = victim_token_losses(&victims, frontrun.iter(), |_| {
+            Some(Rational::from_unsigneds(5u8, 2))
+        });
+        assert_eq!(losses[0].expected, Rational::from(15));
+        assert_eq!(losses[0].lost, Rational::from(6));
+    }
+
+    #[test]
+    fn test_decode_v2_exact_in() {
+        let token_in = Address::new(hex!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"));
+        let token_out = Address::new(hex!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"));
+        let calldata = IUniswapV2Router::swapExactTokensForTokensCall {
+            amountIn:     U256::from(10),
+            amountOutMin: U256::from(9),
+            path:         vec![token_in, token_out],
+            to:           Address::ZERO,
+            deadline:     U256::MAX,
+        }
+        .abi_encode();
+
+        assert_eq!(
+            decode_slippage_limit(&calldata, U256::ZERO),
+            Some(SlippageLimit::exact_in(token_out, U256::from(9)))
+        );
+    }
+
+    #[test]
+    fn test_decode_v2_eth_for_exact_tokens_uses_msg_value() {
+        let weth = Address::new(hex!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"));
+        let token_out = Address::new(hex!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"));
+        let calldata = IUniswapV2Router::swapETHForExactTokensCall {
+            amountOut: U256::from(100),
+            path:      vec![weth, token_out],
+            to:        Address::ZERO,
+            deadline:  U256::MAX,
+        }
+        .abi_encode();
+
+        assert_eq!(
+            decode_slippage_limit(&calldata, U256::from(42)),
+            Some(SlippageLimit::exact_out(weth, U256::from(42)))
+        );
+    }
+
+    #[test]
+    fn test_decode_v3_multicall_exact_output() {
+        let token_in = Address::new(hex!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"));
+        let token_out = Address::new(hex!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"));
+
+        // exact output paths are encoded from the output token to the input token
+        let mut path = token_out.to_vec();
+        path.extend([0x00, 0x01, 0xf4]);
+        path.extend(token_in.to_vec());
+
+        let swap = IUniswapV3SwapRouter02::exactOutputCall {
+            params: IUniswapV3SwapRouter02::ExactOutputParams {
+                path:            Bytes::from(path),
+                recipient:       Address::ZERO,
+                amountOut:       U256::from(100),
+                amountInMaximum: U256::from(7),
+            },
+        }
+        .abi_encode();
+        let calldata = IUniswapV3SwapRouter::multicall_1Call {
+            deadline: U256::MAX,
+            data:     vec![Bytes::from(swap)],
+        }
+        .abi_encode();
+
+        assert_eq!(
+            decode_slippage_limit(&calldata, U256::ZERO),
+            Some(SlippageLimit::exact_out(token_in, U256::from(7)))
+        );
+    }
+}
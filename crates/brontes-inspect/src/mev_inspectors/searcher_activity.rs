@@ -14,15 +14,22 @@ use malachite::{num::basic::traits::Zero, Rational};
 use reth_primitives::Address;
 
 use super::MAX_PROFIT;
-use crate::{shared_utils::SharedInspectorUtils, Inspector, Metadata};
+use crate::{
+    shared_utils::SharedInspectorUtils, thresholds::BundleThresholds, Inspector, Metadata,
+};
 
 pub struct SearcherActivity<'db, DB: LibmdbxReader> {
     utils: SharedInspectorUtils<'db, DB>,
 }
 
 impl<'db, DB: LibmdbxReader> SearcherActivity<'db, DB> {
-    pub fn new(quote: Address, db: &'db DB, metrics: Option<OutlierMetrics>) -> Self {
-        Self { utils: SharedInspectorUtils::new(quote, db, metrics) }
+    pub fn new(
+        quote: Address,
+        db: &'db DB,
+        thresholds: BundleThresholds,
+        metrics: Option<OutlierMetrics>,
+    ) -> Self {
+        Self { utils: SharedInspectorUtils::new(quote, db, metrics).with_thresholds(thresholds) }
     }
 }
 
@@ -37,6 +44,10 @@ impl<DB: LibmdbxReader> Inspector for SearcherActivity<'_, DB> {
         self.utils.quote
     }
 
+    fn keeps(&self, bundle: &Bundle) -> bool {
+        self.utils.thresholds.keeps(&bundle.header)
+    }
+
     fn inspect_block(&self, mut data: MultiBlockData) -> Self::Result {
         let block = data.per_block_data.pop().expect("no blocks");
         let BlockData { metadata, tree } = block;
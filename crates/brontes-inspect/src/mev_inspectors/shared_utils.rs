@@ -1,9 +1,11 @@
 use std::sync::Arc;
 
-use alloy_primitives::{Address, FixedBytes};
+use alloy_primitives::{Address, FixedBytes, U256};
 use brontes_database::libmdbx::LibmdbxReader;
 use brontes_metrics::inspectors::OutlierMetrics;
+use brontes_pricing::price_overrides::PriceRules;
 use brontes_types::{
+    constants::ETH_ADDRESS,
     db::{
         dex::{BlockPrice, PriceAt},
         metadata::Metadata,
@@ -19,9 +21,10 @@ use brontes_types::{
     },
     pair::Pair,
     utils::ToFloatNearest,
-    ActionIter, FastHashMap, FastHashSet, GasDetails, TxInfo,
+    ActionIter, FastHashMap, FastHashSet, GasDetails, ToScaledRational, TxInfo,
 };
 use itertools::Itertools;
+use lazy_static::lazy_static;
 use malachite::{
     num::{
         arithmetic::traits::Reciprocal,
@@ -31,22 +34,36 @@ use malachite::{
 };
 use reth_primitives::TxHash;
 
+use crate::thresholds::BundleThresholds;
+
 #[derive(Debug)]
 pub struct SharedInspectorUtils<'db, DB: LibmdbxReader> {
-    pub(crate) quote: Address,
-    pub(crate) db:    &'db DB,
-    pub metrics:      Option<OutlierMetrics>,
+    pub(crate) quote:      Address,
+    pub(crate) db:         &'db DB,
+    pub metrics:           Option<OutlierMetrics>,
+    /// the thresholds of the inspector these utils belong to
+    pub(crate) thresholds: BundleThresholds,
 }
 
 impl<'db, DB: LibmdbxReader> SharedInspectorUtils<'db, DB> {
     pub fn new(quote_address: Address, db: &'db DB, metrics: Option<OutlierMetrics>) -> Self {
-        SharedInspectorUtils { quote: quote_address, db, metrics }
+        SharedInspectorUtils { quote: quote_address, db, metrics, thresholds: Default::default() }
+    }
+
+    pub fn with_thresholds(mut self, thresholds: BundleThresholds) -> Self {
+        self.thresholds = thresholds;
+        self
     }
 }
 type TokenDeltas = FastHashMap<Address, Rational>;
 type AddressDeltas = FastHashMap<Address, TokenDeltas>;
 type PossibleSwapDetails = Vec<(TokenInfoWithAddress, bool, Rational, Address, u64)>;
 
+lazy_static! {
+    /// The price rules also map interest bearing tokens to their underlying
+    static ref PRICE_RULES: PriceRules = PriceRules::load_from_config();
+}
+
 impl<DB: LibmdbxReader> SharedInspectorUtils<'_, DB> {
     pub fn get_metrics(&self) -> Option<&OutlierMetrics> {
         self.metrics.as_ref()
@@ -63,8 +80,9 @@ impl<DB: LibmdbxReader> SharedInspectorUtils<'_, DB> {
         at_or_before: bool,
     ) -> Option<FastHashMap<Address, Rational>> {
         let mut usd_deltas = FastHashMap::default();
+        let deltas = self.unwrap_interest_bearing(tx_position, at, deltas, &metadata);
 
-        for (address, token_deltas) in deltas {
+        for (address, token_deltas) in &deltas {
             for (token_addr, amount) in token_deltas {
                 if amount == &Rational::ZERO {
                     continue
@@ -106,6 +124,42 @@ impl<DB: LibmdbxReader> SharedInspectorUtils<'_, DB> {
         Some(usd_deltas)
     }
 
+    /// Converts the deltas of interest bearing tokens such as aTokens and
+    /// cTokens into the amount of the underlying they are redeemable for, so
+    /// they net out against the underlying and can be priced through it.
+    ///
+    /// The exchange rate is the fixed rate of the token's price rule. For rules
+    /// that read the rate from chain it is taken from the stored dex quotes,
+    /// where the token is priced as the underlying times the rate. Tokens
+    /// without a rate at the transaction are left as is
+    pub fn unwrap_interest_bearing(
+        &self,
+        tx_position: u64,
+        at: PriceAt,
+        deltas: &AddressDeltas,
+        metadata: &Metadata,
+    ) -> AddressDeltas {
+        unwrap_deltas(deltas, |token| {
+            let rule = PRICE_RULES.0.get(&token)?;
+            if let Some(rate) = rule.fixed_rate() {
+                return Some((rule.underlying, rate))
+            }
+
+            let quotes = metadata.dex_quotes.as_ref()?;
+            let price = |token| {
+                quotes
+                    .price_at_or_before(Pair(token, self.quote), tx_position as usize)
+                    .map(|price| price.get_price(at))
+            };
+            let underlying_price = price(rule.underlying)?;
+            if underlying_price == Rational::ZERO {
+                return None
+            }
+
+            Some((rule.underlying, price(token)? / underlying_price))
+        })
+    }
+
     // will flatten nested and filter out actions that aren't swap, transfer or
     // eth_transfer
     pub fn flatten_nested_actions_default<'a>(
@@ -368,6 +422,8 @@ impl<DB: LibmdbxReader> SharedInspectorUtils<'_, DB> {
             bribe_usd,
             mev_type,
             no_pricing_calculated,
+            // set by the composer, which sees the whole block
+            governance_event_in_block: false,
             balance_deltas,
         }
     }
@@ -388,6 +444,17 @@ impl<DB: LibmdbxReader> SharedInspectorUtils<'_, DB> {
             profit_usd = 0.0;
         }
 
+        if let Some(verified) =
+            self.verify_eth_deltas(&bundle_deltas, &bundle_txes, info, gas_details, &metadata)
+        {
+            if !verified {
+                tracing::debug!(?mev_type, tx_hash=?info.tx_hash, "eth deltas don't match state diffs");
+            }
+            self.metrics
+                .as_ref()
+                .inspect(|m| m.profit_verification(mev_type, verified));
+        }
+
         let balance_deltas = self.get_bundle_accounting(bundle_txes, bundle_deltas, price_f);
 
         let bribe_usd = gas_details
@@ -422,10 +489,46 @@ impl<DB: LibmdbxReader> SharedInspectorUtils<'_, DB> {
             bribe_usd,
             mev_type,
             no_pricing_calculated,
+            // set by the composer, which sees the whole block
+            governance_event_in_block: false,
             balance_deltas,
         }
     }
 
+    /// Checks the searchers native eth delta over the bundle against the exact
+    /// state changes of the bundle's transactions. Returns `None` when the
+    /// block wasn't re-executed.
+    ///
+    /// When there is no mev contract, the eoa is the searcher and the gas it
+    /// paid is part of its balance change
+    pub fn verify_eth_deltas(
+        &self,
+        bundle_deltas: &[AddressDeltas],
+        bundle_txes: &[TxHash],
+        info: &TxInfo,
+        gas_details: &[GasDetails],
+        metadata: &Metadata,
+    ) -> Option<bool> {
+        let searcher = info.mev_contract.unwrap_or(info.eoa);
+
+        let mut actual = Rational::ZERO;
+        for tx_hash in bundle_txes {
+            actual += metadata.state_diff(*tx_hash)?.eth_delta(searcher);
+        }
+
+        let mut expected = bundle_deltas
+            .iter()
+            .filter_map(|deltas| deltas.get(&searcher)?.get(&ETH_ADDRESS))
+            .fold(Rational::ZERO, |acc, delta| acc + delta);
+
+        if info.mev_contract.is_none() {
+            let gas_fees = gas_details.iter().map(GasDetails::fees_paid).sum::<u128>();
+            expected -= U256::from(gas_fees).to_scaled_rational(18);
+        }
+
+        Some(actual == expected)
+    }
+
     pub fn get_full_block_price(
         &self,
         price_type: BlockPrice,
@@ -788,6 +891,32 @@ impl<DB: LibmdbxReader> SharedInspectorUtils<'_, DB> {
     }
 }
 
+/// Moves the deltas of every token that has an underlying and exchange rate
+/// onto the underlying
+fn unwrap_deltas(
+    deltas: &AddressDeltas,
+    underlying: impl Fn(Address) -> Option<(Address, Rational)>,
+) -> AddressDeltas {
+    let mut rates: FastHashMap<Address, Option<(Address, Rational)>> = FastHashMap::default();
+
+    deltas
+        .iter()
+        .map(|(address, token_deltas)| {
+            let mut unwrapped = TokenDeltas::default();
+            for (token, amount) in token_deltas {
+                match rates.entry(*token).or_insert_with(|| underlying(*token)) {
+                    Some((underlying, rate)) => {
+                        *unwrapped.entry(*underlying).or_default() += amount * &*rate;
+                    }
+                    None => *unwrapped.entry(*token).or_default() += amount,
+                }
+            }
+
+            (*address, unwrapped)
+        })
+        .collect()
+}
+
 #[cfg(test)]
 pub mod test {
     use brontes_core::LibmdbxReadWriter;
@@ -797,7 +926,7 @@ pub mod test {
     };
     use malachite::Rational;
 
-    use super::SharedInspectorUtils;
+    use super::{unwrap_deltas, SharedInspectorUtils};
 
     #[test]
     pub fn test_multi_hop_cex_merge_swap() {
@@ -903,4 +1032,28 @@ pub mod test {
         let res = SharedInspectorUtils::<LibmdbxReadWriter>::cex_merge_possible_swaps(swaps);
         assert_eq!(res.len(), 2, "{:#?}", res);
     }
+
+    #[test]
+    pub fn test_unwrap_interest_bearing_deltas() {
+        let searcher = alloy_primitives::address!("76F36d497b51e48A288f03b4C1d7461e92247d5e");
+        let c_usdc = alloy_primitives::address!("39AA39c021dfbaE8faC545936693aC917d5E7563");
+
+        // minted cUSDC with USDC and kept some USDC
+        let deltas = [(
+            searcher,
+            [(c_usdc, Rational::from(50)), (USDC_ADDRESS, Rational::from(-90))]
+                .into_iter()
+                .collect(),
+        )]
+        .into_iter()
+        .collect();
+
+        let unwrapped = unwrap_deltas(&deltas, |token| {
+            (token == c_usdc).then(|| (USDC_ADDRESS, Rational::from_unsigneds(1u8, 50u8)))
+        });
+
+        let searcher_deltas = unwrapped.get(&searcher).unwrap();
+        assert_eq!(searcher_deltas.len(), 1);
+        assert_eq!(searcher_deltas.get(&USDC_ADDRESS), Some(&Rational::from(-89)));
+    }
 }
@@ -12,7 +12,7 @@ use brontes_types::{
 use criterion::{black_box, Criterion};
 
 use super::InspectorTestUtilsError;
-use crate::{composer::run_block_inspection, Inspectors};
+use crate::{composer::run_block_inspection, thresholds::ThresholdConfig, Inspectors};
 
 pub struct InspectorBenchUtils {
     classifier_inspector: ClassifierTestUtils,
@@ -48,6 +48,8 @@ impl InspectorBenchUtils {
                     self.classifier_inspector.libmdbx,
                     &[CexExchange::Binance],
                     CexDexTradeConfig::default(),
+                    &ThresholdConfig::default(),
+                    false,
                     None,
                 )
             })
@@ -99,6 +101,8 @@ impl InspectorBenchUtils {
             self.classifier_inspector.libmdbx,
             &[CexExchange::Binance],
             CexDexTradeConfig::default(),
+            &ThresholdConfig::default(),
+            false,
             None,
         );
 
@@ -162,6 +166,8 @@ impl InspectorBenchUtils {
             self.classifier_inspector.libmdbx,
             &[CexExchange::Binance],
             CexDexTradeConfig::default(),
+            &ThresholdConfig::default(),
+            false,
             None,
         );
 
@@ -216,6 +222,8 @@ impl InspectorBenchUtils {
             self.classifier_inspector.libmdbx,
             &[CexExchange::Binance],
             CexDexTradeConfig::default(),
+            &ThresholdConfig::default(),
+            false,
             None,
         );
 
@@ -263,6 +271,8 @@ impl InspectorBenchUtils {
                     self.classifier_inspector.libmdbx,
                     &[CexExchange::Binance],
                     CexDexTradeConfig::default(),
+                    &ThresholdConfig::default(),
+                    false,
                     None,
                 )
             })
@@ -297,7 +307,12 @@ impl InspectorBenchUtils {
         c.bench_function(bench_name, move |b| {
             b.iter(|| {
                 for _ in 0..=iters {
-                    black_box(run_block_inspection(inspectors.as_slice(), multi.clone(), db));
+                    black_box(run_block_inspection(
+                        inspectors.as_slice(),
+                        multi.clone(),
+                        db,
+                        false,
+                    ));
                 }
             });
         });
@@ -322,6 +337,8 @@ impl InspectorBenchUtils {
                     self.classifier_inspector.libmdbx,
                     &[CexExchange::Binance],
                     CexDexTradeConfig::default(),
+                    &ThresholdConfig::default(),
+                    false,
                     None,
                 )
             })
@@ -348,7 +365,12 @@ impl InspectorBenchUtils {
         c.bench_function(bench_name, move |b| {
             b.iter(|| {
                 for _ in 0..=iters {
-                    black_box(run_block_inspection(inspectors.as_slice(), multi.clone(), db));
+                    black_box(run_block_inspection(
+                        inspectors.as_slice(),
+                        multi.clone(),
+                        db,
+                        false,
+                    ));
                 }
             });
         });
@@ -38,7 +38,7 @@ use brontes_types::{
 };
 use thiserror::Error;
 
-use crate::{composer::run_block_inspection, Inspectors};
+use crate::{composer::run_block_inspection, thresholds::ThresholdConfig, Inspectors};
 
 type StateTests = Option<Box<dyn for<'a> Fn(&'a Bundle)>>;
 
@@ -168,6 +168,8 @@ impl InspectorTestUtils {
                 CexExchange::Kucoin,
             ],
             CexDexTradeConfig::default(),
+            &ThresholdConfig::default(),
+            false,
             None,
         );
         let data = BlockData { metadata: metadata.into(), tree: tree.into() };
@@ -270,6 +272,8 @@ impl InspectorTestUtils {
                 CexExchange::Upbit,
             ],
             cex_trade_config,
+            &ThresholdConfig::default(),
+            false,
             None,
         );
 
@@ -390,6 +394,8 @@ impl InspectorTestUtils {
                     self.classifier_inspector.libmdbx,
                     &[CexExchange::Binance],
                     CexDexTradeConfig::default(),
+                    &ThresholdConfig::default(),
+                    false,
                     None,
                 )
             })
@@ -398,7 +404,7 @@ impl InspectorTestUtils {
         let data = BlockData { metadata: metadata.into(), tree: tree.into() };
         let multi = MultiBlockData { blocks: 1, per_block_data: vec![data] };
 
-        let results = run_block_inspection(inspector.as_slice(), multi, db);
+        let results = run_block_inspection(inspector.as_slice(), multi, db, false);
 
         let mut results = results
             .mev_details
@@ -0,0 +1,213 @@
+//! Per inspector filters on the bundles that make it into the results, so that
+//! small, unpriced or unwanted bundles can be left out of a run instead of
+//! being filtered out of Clickhouse afterwards.
+//!
+//! Thresholds are loaded from a TOML file with a table per inspector, laid out
+//! like `config/inspector_thresholds.toml`. Each inspector is built with its
+//! own, see [`ThresholdConfig::for_inspector`], and the composer checks them
+//! after deduplication through [`Inspector::keeps`](crate::Inspector::keeps).
+//! A bundle type produced by more than one inspector has to pass the
+//! thresholds of each of them that is configured.
+use std::path::Path;
+
+use alloy_primitives::Address;
+use brontes_types::{
+    mev::{BundleHeader, MevType},
+    FastHashMap, FastHashSet,
+};
+use serde::Deserialize;
+
+use crate::Inspectors;
+
+/// What an unknown strategy has to make after gas and bribes to be flagged
+/// when no thresholds are configured for [`Inspectors::LongTail`]
+const LONG_TAIL_MIN_PROFIT_USD: f64 = 1_000.0;
+
+/// How much a bundle's profit can be trusted
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Confidence {
+    /// the profit couldn't be priced
+    #[default]
+    Low,
+    /// priced, but in a block where a protocol was paused or had its funds
+    /// pulled, where mev is often part of an exploit
+    Medium,
+    /// priced, in an ordinary block
+    High,
+}
+
+impl Confidence {
+    pub fn of(header: &BundleHeader) -> Self {
+        if header.no_pricing_calculated {
+            Self::Low
+        } else if header.governance_event_in_block {
+            Self::Medium
+        } else {
+            Self::High
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct InspectorThresholds {
+    /// Bundles making less than this are dropped. Unpriced bundles have no
+    /// profit to compare, whether they are kept is up to `min_confidence`
+    pub min_profit_usd: Option<f64>,
+    #[serde(default)]
+    pub min_confidence: Confidence,
+    /// Bundles that moved any of these tokens are dropped
+    #[serde(default)]
+    pub denied_tokens:  FastHashSet<Address>,
+}
+
+impl InspectorThresholds {
+    pub fn keeps(&self, header: &BundleHeader) -> bool {
+        if Confidence::of(header) < self.min_confidence {
+            return false
+        }
+
+        if let Some(min_profit) = self.min_profit_usd {
+            if !header.no_pricing_calculated && header.profit_usd < min_profit {
+                return false
+            }
+        }
+
+        !header
+            .balance_deltas
+            .iter()
+            .flat_map(|tx| &tx.address_deltas)
+            .flat_map(|address| &address.token_deltas)
+            .any(|delta| self.denied_tokens.contains(&delta.token.address))
+    }
+}
+
+/// The thresholds of each configured inspector. Inspectors without an entry
+/// use their defaults, see [`ThresholdConfig::for_inspector`]
+#[derive(Debug, Clone, Default)]
+pub struct ThresholdConfig(pub FastHashMap<Inspectors, InspectorThresholds>);
+
+impl ThresholdConfig {
+    pub fn load(path: impl AsRef<Path>) -> eyre::Result<Self> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+
+    pub fn parse(config: &str) -> eyre::Result<Self> {
+        let tables: FastHashMap<String, InspectorThresholds> = toml::from_str(config)?;
+
+        tables
+            .into_iter()
+            .map(|(name, thresholds)| {
+                let inspector = name
+                    .parse::<Inspectors>()
+                    .map_err(|_| eyre::eyre!("thresholds given for unknown inspector {name}"))?;
+                Ok((inspector, thresholds))
+            })
+            .collect::<eyre::Result<_>>()
+            .map(Self)
+    }
+
+    /// The thresholds `inspector` is built with. Without an entry only
+    /// [`Inspectors::LongTail`] has limits, as it would otherwise flag every
+    /// transaction that made anything, the others keep all their bundles
+    pub fn for_inspector(&self, inspector: Inspectors) -> BundleThresholds {
+        let defaults = || match inspector {
+            Inspectors::LongTail => Some(InspectorThresholds {
+                min_profit_usd: Some(LONG_TAIL_MIN_PROFIT_USD),
+                ..Default::default()
+            }),
+            _ => None,
+        };
+
+        BundleThresholds {
+            mev_types: inspector.mev_types(),
+            limits:    self.0.get(&inspector).cloned().or_else(defaults),
+        }
+    }
+}
+
+/// The thresholds an inspector applies to the bundles it produces. Without
+/// limits every bundle is kept
+#[derive(Debug, Clone, Default)]
+pub struct BundleThresholds {
+    mev_types: &'static [MevType],
+    limits:    Option<InspectorThresholds>,
+}
+
+impl BundleThresholds {
+    pub fn keeps(&self, header: &BundleHeader) -> bool {
+        !self.mev_types.contains(&header.mev_type)
+            || self
+                .limits
+                .as_ref()
+                .map_or(true, |limits| limits.keeps(header))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use brontes_types::{
+        db::token_info::TokenInfoWithAddress,
+        mev::{AddressBalanceDeltas, TokenBalanceDelta, TransactionAccounting},
+    };
+
+    use super::*;
+
+    const DENIED: Address = Address::repeat_byte(1);
+
+    fn header(mev_type: MevType, profit_usd: f64, token: Address) -> BundleHeader {
+        let delta = TokenBalanceDelta {
+            token: TokenInfoWithAddress { address: token, ..Default::default() },
+            ..Default::default()
+        };
+
+        BundleHeader {
+            mev_type,
+            profit_usd,
+            balance_deltas: vec![TransactionAccounting {
+                address_deltas: vec![AddressBalanceDeltas {
+                    token_deltas: vec![delta],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_thresholds() {
+        let config = ThresholdConfig::parse(&format!(
+            r#"
+            [AtomicArb]
+            min_profit_usd = 10.0
+            min_confidence = "high"
+            denied_tokens = ["{DENIED:?}"]
+            "#
+        ))
+        .unwrap();
+        let atomic_arb = config.for_inspector(Inspectors::AtomicArb);
+
+        assert!(atomic_arb.keeps(&header(MevType::AtomicArb, 20.0, Address::ZERO)));
+        assert!(!atomic_arb.keeps(&header(MevType::AtomicArb, 5.0, Address::ZERO)));
+        assert!(!atomic_arb.keeps(&header(MevType::AtomicArb, 20.0, DENIED)));
+
+        let mut unpriced = header(MevType::AtomicArb, 0.0, Address::ZERO);
+        unpriced.no_pricing_calculated = true;
+        assert!(!atomic_arb.keeps(&unpriced));
+
+        // other inspectors' bundles aren't touched
+        assert!(atomic_arb.keeps(&header(MevType::Sandwich, 5.0, DENIED)));
+        // and inspectors without thresholds keep everything
+        let sandwich = config.for_inspector(Inspectors::Sandwich);
+        assert!(sandwich.keeps(&header(MevType::Sandwich, 5.0, DENIED)));
+
+        // long tail falls back to its minimum profit when not configured
+        let long_tail = config.for_inspector(Inspectors::LongTail);
+        assert!(long_tail.keeps(&header(MevType::Unknown, 2_000.0, Address::ZERO)));
+        assert!(!long_tail.keeps(&header(MevType::Unknown, 500.0, Address::ZERO)));
+
+        assert!(ThresholdConfig::parse("[NotAnInspector]").is_err());
+    }
+}
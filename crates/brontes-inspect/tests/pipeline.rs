@@ -0,0 +1,185 @@
+//! Replays the whole pipeline over a fixed set of blocks from the recorded test
+//! data. Each block is traced, classified into a tree, priced, run through the
+//! composer and encoded into the mev blocks table. The bundles each block
+//! produces are pinned, so a change to the tree layout, the classifier dispatch
+//! or pricing that alters them fails here rather than in a release.
+use alloy_primitives::{hex, Address};
+use brontes_inspect::{
+    composer::run_block_inspection,
+    test_utils::{InspectorTestUtils, USDC_ADDRESS, USDT_ADDRESS, WETH_ADDRESS},
+    thresholds::ThresholdConfig,
+    Inspectors,
+};
+use brontes_types::{
+    db::{
+        cex::{trades::CexDexTradeConfig, CexExchange},
+        mev_block::{MevBlockWithClassified, MevBlockWithClassifiedRedefined},
+    },
+    mev::MevType,
+    BlockData, MultiBlockData,
+};
+use redefined::RedefinedConvert;
+use reth_db::table::{Compress, Decompress};
+
+/// A bundle a fixture block is expected to produce, in tx order
+struct ExpectedBundle {
+    mev_type:   MevType,
+    profit_usd: f64,
+    bribe_usd:  f64,
+}
+
+struct Fixture {
+    block:          u64,
+    inspectors:     Vec<Inspectors>,
+    quote:          Address,
+    /// tokens the block's pools don't price on their own
+    needs_tokens:   Vec<Address>,
+    bundles:        Vec<ExpectedBundle>,
+    max_difference: f64,
+}
+
+async fn replay(fixture: Fixture) {
+    let utils = InspectorTestUtils::new(fixture.quote, fixture.max_difference).await;
+    let classifier = &utils.classifier_inspector;
+
+    let (tree, quotes) = classifier
+        .build_block_tree_with_pricing(fixture.block, fixture.quote, fixture.needs_tokens)
+        .await
+        .unwrap();
+    let mut metadata = classifier
+        .get_metadata(fixture.block, false)
+        .await
+        .unwrap_or_default();
+    metadata.dex_quotes = Some(quotes.expect("no dex quotes for the fixture block"));
+
+    let inspectors = fixture
+        .inspectors
+        .iter()
+        .map(|inspector| {
+            inspector.init_mev_inspector(
+                fixture.quote,
+                classifier.libmdbx,
+                &[CexExchange::Binance],
+                CexDexTradeConfig::default(),
+                &ThresholdConfig::default(),
+                false,
+                None,
+            )
+        })
+        .collect::<Vec<_>>();
+    let data = BlockData { metadata: metadata.into(), tree: tree.into() };
+    let multi = MultiBlockData { blocks: 1, per_block_data: vec![data] };
+    let results = run_block_inspection(inspectors.as_slice(), multi, classifier.libmdbx, false);
+
+    // the results are stored the same way the writer does, and have to come
+    // back out of the table unchanged
+    let stored =
+        MevBlockWithClassified { block: results.block_details, mev: results.mev_details };
+    let encoded = MevBlockWithClassifiedRedefined::from_source(stored.clone()).compress();
+    let decoded = MevBlockWithClassifiedRedefined::decompress(&encoded)
+        .unwrap()
+        .to_source();
+    assert_eq!(decoded, stored, "mev block changed when stored");
+
+    assert_eq!(decoded.block.block_number, fixture.block);
+    let mut bundles = decoded.mev;
+    bundles.sort_by_key(|bundle| bundle.header.tx_index);
+    assert_eq!(
+        bundles.len(),
+        fixture.bundles.len(),
+        "block {} produced a different number of bundles {:#?}",
+        fixture.block,
+        bundles
+    );
+
+    for (bundle, expected) in bundles.iter().zip(&fixture.bundles) {
+        assert_eq!(bundle.header.mev_type, expected.mev_type, "{:#?}", bundle);
+        assert!(
+            (bundle.header.profit_usd - expected.profit_usd).abs() < fixture.max_difference,
+            "{} profit changed, {} != {}",
+            bundle.header.tx_hash,
+            bundle.header.profit_usd,
+            expected.profit_usd
+        );
+        assert!(
+            (bundle.header.bribe_usd - expected.bribe_usd).abs() < fixture.max_difference,
+            "{} bribe changed, {} != {}",
+            bundle.header.tx_hash,
+            bundle.header.bribe_usd,
+            expected.bribe_usd
+        );
+    }
+}
+
+#[brontes_macros::test]
+async fn replay_jit_sandwich_block() {
+    replay(Fixture {
+        block:          18674873,
+        inspectors:     vec![Inspectors::Sandwich, Inspectors::Jit],
+        quote:          USDC_ADDRESS,
+        needs_tokens:   vec![
+            hex!("50d1c9771902476076ecfc8b2a83ad6b9355a4c9").into(),
+            hex!("b17548c7b510427baac4e267bea62e800b247173").into(),
+        ],
+        bundles:        vec![ExpectedBundle {
+            mev_type:   MevType::JitSandwich,
+            profit_usd: 18.1,
+            bribe_usd:  273.9,
+        }],
+        max_difference: 0.2,
+    })
+    .await
+}
+
+#[brontes_macros::test]
+async fn replay_jit_block() {
+    replay(Fixture {
+        block:          18539312,
+        inspectors:     vec![Inspectors::Jit],
+        quote:          USDC_ADDRESS,
+        needs_tokens:   vec![
+            WETH_ADDRESS,
+            hex!("b17548c7b510427baac4e267bea62e800b247173").into(),
+            hex!("ed4e879087ebd0e8a77d66870012b5e0dffd0fa4").into(),
+            hex!("50d1c9771902476076ecfc8b2a83ad6b9355a4c9").into(),
+        ],
+        bundles:        vec![ExpectedBundle {
+            mev_type:   MevType::Jit,
+            profit_usd: 13.58,
+            bribe_usd:  90.875025,
+        }],
+        max_difference: 2.0,
+    })
+    .await
+}
+
+#[brontes_macros::test]
+async fn replay_sandwich_block() {
+    replay(Fixture {
+        block:          19668569,
+        inspectors:     vec![Inspectors::Sandwich],
+        quote:          USDT_ADDRESS,
+        needs_tokens:   vec![],
+        bundles:        vec![ExpectedBundle {
+            mev_type:   MevType::Sandwich,
+            profit_usd: 415.59,
+            bribe_usd:  273.17,
+        }],
+        max_difference: 1.0,
+    })
+    .await
+}
+
+/// only a jit in this block, which the sandwich inspector mustn't pick up
+#[brontes_macros::test]
+async fn replay_block_without_sandwich() {
+    replay(Fixture {
+        block:          19000056,
+        inspectors:     vec![Inspectors::Sandwich],
+        quote:          USDT_ADDRESS,
+        needs_tokens:   vec![],
+        bundles:        vec![],
+        max_difference: 1.0,
+    })
+    .await
+}
@@ -56,8 +56,12 @@ impl ActionDispatch {
                             )> {
 
 
-                            let protocol_byte = db_tx.get_protocol(call_info.target_address)
-                                .ok()?.to_byte();
+                            let protocol_byte = match call_info.protocol {
+                                Some(protocol) => protocol,
+                                None => db_tx
+                                    .get_protocol_at(call_info.target_address, block)
+                                    .ok()?,
+                            }.to_byte();
 
                             if call_info.call_data.len() < 4 {
                                 return None
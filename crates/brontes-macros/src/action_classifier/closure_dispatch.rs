@@ -31,7 +31,7 @@ impl ToTokens for ClosureDispatch {
         let log_data = self.logs.then_some(quote!(log_data,)).unwrap_or_default();
 
         tokens.extend(quote!(
-            let fixed_fields = call_info.get_fixed_fields();
+            let fixed_fields = call_info.get_fixed_fields(block);
             let result: ::eyre::Result<_> = (#closure)
             (
                 fixed_fields,
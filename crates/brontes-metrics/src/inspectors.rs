@@ -15,6 +15,7 @@ pub struct OutlierMetrics {
     pub dex_bad_pricing:           IntCounterVec,
     pub inspector_100x_price_type: IntCounterVec,
     pub branch_filtering_trigger:  IntCounterVec,
+    pub profit_verification:       IntCounterVec,
     // runtimes
     inspector_runtime:             HistogramVec,
     cex_dex_price_speed:           HistogramVec,
@@ -56,6 +57,13 @@ impl OutlierMetrics {
         )
         .unwrap();
 
+        let profit_verification = prometheus::register_int_counter_vec!(
+            "brontes_profit_verification",
+            "the amount of bundles whose eth deltas did or didn't match the block state diffs",
+            &["mev_type", "result"]
+        )
+        .unwrap();
+
         let buckets = prometheus::exponential_buckets(1.0, 2.0, 22).unwrap();
 
         let inspector_runtime = prometheus::register_histogram_vec!(
@@ -77,6 +85,7 @@ impl OutlierMetrics {
         Self {
             inspector_runtime,
             branch_filtering_trigger,
+            profit_verification,
             inspector_100x_price_type,
             dex_bad_pricing,
             cex_pair_symbols,
@@ -159,6 +168,16 @@ impl OutlierMetrics {
             .unwrap()
             .inc();
     }
+
+    pub fn profit_verification(&self, mev_type: MevType, verified: bool) {
+        let t = mev_type.to_string();
+        let result = if verified { "verified" } else { "mismatch" };
+
+        self.profit_verification
+            .get_metric_with_label_values(&[&t, result])
+            .unwrap()
+            .inc();
+    }
 }
 
 impl std::fmt::Debug for OutlierMetrics {
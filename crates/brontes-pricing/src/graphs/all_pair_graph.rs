@@ -203,6 +203,7 @@ impl AllPairGraph {
         timeout: Duration,
         is_extension: bool,
         possible_extensions: Vec<Pair>,
+        max_hops: usize,
     ) -> (Vec<Vec<Vec<SubGraphEdge>>>, Option<Pair>) {
         if pair.0 == pair.1 {
             error!("Invalid pair, both tokens have the same address");
@@ -296,6 +297,8 @@ impl AllPairGraph {
                 })
                 .collect_vec()
         })
+        // paths longer than this are too likely to run through a thin pool
+        .filter(|path| path.len() <= max_hops)
         .collect_vec();
 
         let extends = results.last().and_then(|n| {
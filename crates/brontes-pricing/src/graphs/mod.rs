@@ -27,6 +27,7 @@ pub use self::{
 };
 use super::PoolUpdate;
 use crate::{
+    path_config::PathConfig,
     types::{PairWithFirstPoolHop, PoolState},
     Protocol,
 };
@@ -64,6 +65,8 @@ pub struct GraphManager {
     pub(crate) subgraph_verifier: SubgraphVerifier,
     /// tracks all state needed for our subgraphs
     graph_state:                  StateTracker,
+    /// limits on the paths that pairs are priced through
+    path_config:                  PathConfig,
 }
 
 impl GraphManager {
@@ -80,9 +83,14 @@ impl GraphManager {
             all_pair_graph: graph,
             sub_graph_registry: registry,
             subgraph_verifier,
+            path_config: PathConfig::default(),
         }
     }
 
+    pub fn set_path_config(&mut self, config: PathConfig) {
+        self.path_config = config;
+    }
+
     /// used for testing and benching
     pub fn snapshot_state(&self) -> (SubGraphRegistry, SubgraphVerifier, StateTracker) {
         (self.sub_graph_registry.clone(), self.subgraph_verifier.clone(), self.graph_state.clone())
@@ -151,6 +159,7 @@ impl GraphManager {
             timeout,
             is_extension,
             possible_exts,
+            self.path_config.max_hops,
         );
 
         (path.into_iter().flatten().flatten().collect_vec(), extends)
@@ -229,6 +238,7 @@ impl GraphManager {
                 pair,
                 goes_through,
                 &self.graph_state.finalized_state(),
+                &self.path_config,
             )
         })
     }
@@ -266,7 +276,7 @@ impl GraphManager {
                         .map(|jump_pair| {
                             (
                                 self.sub_graph_registry
-                                    .get_price_all(jump_pair.flip(), &state)
+                                    .get_price_all(jump_pair.flip(), &state, &self.path_config)
                                     .unwrap_or(Rational::ONE),
                                 jump_pair.0,
                             )
@@ -319,6 +329,7 @@ impl GraphManager {
                                     .get_price_all(
                                         jump_pair.flip(),
                                         &self.graph_state.finalized_state(),
+                                        &self.path_config,
                                     )
                                     .unwrap_or(Rational::ONE),
                                 jump_pair.0,
@@ -13,7 +13,10 @@ use malachite::{
 };
 
 use super::{subgraph::PairSubGraph, PoolState};
-use crate::types::{PairWithFirstPoolHop, ProtocolState};
+use crate::{
+    path_config::PathConfig,
+    types::{PairWithFirstPoolHop, ProtocolState},
+};
 
 /// Manages subgraphs in the BrontesBatchPricer module, crucial for DEX pricing.
 ///
@@ -270,12 +273,13 @@ impl SubGraphRegistry {
         unordered_pair: Pair,
         goes_through: Pair,
         edge_state: &FastHashMap<Address, &PoolState>,
+        config: &PathConfig,
     ) -> Option<Rational> {
         let (next, complete_pair, default_price) =
-            self.get_price_once(unordered_pair, goes_through, edge_state)?;
+            self.get_price_once(unordered_pair, goes_through, edge_state, config)?;
 
         if let Some(next) = next {
-            let next_price = self.get_price_all(next, edge_state)?;
+            let next_price = self.get_price_all(next, edge_state, config)?;
 
             let price = next_price * &default_price;
             if unordered_pair.eq_unordered(&complete_pair) {
@@ -293,6 +297,7 @@ impl SubGraphRegistry {
         unordered_pair: Pair,
         goes_through: Pair,
         edge_state: &FastHashMap<Address, &PoolState>,
+        config: &PathConfig,
     ) -> Option<(Option<Pair>, Pair, Rational)> {
         let pair = unordered_pair.ordered();
 
@@ -301,7 +306,11 @@ impl SubGraphRegistry {
             .and_then(|g| g.get(&goes_through.ordered()))
             .map(|graph| {
                 tracing::debug!("has graph for goes through");
-                Some((graph.extends_to(), graph.complete_pair(), graph.fetch_price(edge_state)?))
+                Some((
+                    graph.extends_to(),
+                    graph.complete_pair(),
+                    graph.fetch_price(edge_state, config)?,
+                ))
             })
             // this can happen when we have pools with a token that only has that one pool.
             // this causes a one way and we can't process price. Instead, in this case
@@ -309,7 +318,7 @@ impl SubGraphRegistry {
             // that way
             .or_else(|| {
                 Some(
-                    self.get_price_all(unordered_pair, edge_state)
+                    self.get_price_all(unordered_pair, edge_state, config)
                         .map(|price| (None, unordered_pair, price)),
                 )
             })
@@ -321,6 +330,7 @@ impl SubGraphRegistry {
         &self,
         unordered_pair: Pair,
         edge_state: &FastHashMap<Address, &PoolState>,
+        config: &PathConfig,
     ) -> Option<Rational> {
         let pair = unordered_pair.ordered();
 
@@ -332,7 +342,7 @@ impl SubGraphRegistry {
                     continue
                 };
 
-                let Some(next) = graph.fetch_price(edge_state) else {
+                let Some(next) = graph.fetch_price(edge_state, config) else {
                     continue;
                 };
                 let default_pair = graph.get_unordered_pair();
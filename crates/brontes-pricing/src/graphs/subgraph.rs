@@ -7,7 +7,7 @@ use std::{
     hash::Hash,
     sync::{
         atomic::{AtomicU64, Ordering::SeqCst},
-        Arc,
+        Arc, OnceLock,
     },
 };
 
@@ -29,7 +29,7 @@ use petgraph::{
 };
 use tracing::error;
 
-use crate::{types::ProtocolState, Pair};
+use crate::{path_config::PathConfig, types::ProtocolState, Pair};
 
 pub struct VerificationOutcome {
     pub should_requery: bool,
@@ -102,6 +102,17 @@ pub struct PairSubGraph {
     /// we mark the removal time and all new subgraphs past this block,
     /// will generate a new subgrpah.
     remove_at:              Option<u64>,
+    /// the paths from the start to the end node, enumerated the first time
+    /// the subgraph is priced. State updates only change the edges' weights,
+    /// so these are kept until an edge is added or removed
+    paths:                  OnceLock<CachedPaths>,
+}
+
+#[derive(Debug, Clone)]
+struct CachedPaths {
+    max_hops:  usize,
+    max_paths: usize,
+    paths:     Vec<Vec<EdgeIndex<u16>>>,
 }
 
 impl PairSubGraph {
@@ -164,6 +175,7 @@ impl PairSubGraph {
             extends_to,
             must_go_through,
             start_nodes_liq: FastHashMap::default(),
+            paths: OnceLock::new(),
         }
     }
 
@@ -239,6 +251,7 @@ impl PairSubGraph {
 
     // returns list of pools we already have so we can derement there state tracker.
     pub fn extend_subgraph(&mut self, edges: Vec<SubGraphEdge>) -> Vec<Address> {
+        self.paths.take();
         let mut connections: FastHashMap<(u16, u16), Vec<SubGraphEdge>> = FastHashMap::default();
         let mut has = Vec::new();
 
@@ -316,8 +329,10 @@ impl PairSubGraph {
     pub fn fetch_price<T: ProtocolState>(
         &self,
         edge_state: &FastHashMap<Address, &T>,
+        config: &PathConfig,
     ) -> Option<Rational> {
-        self.dijkstra_path(edge_state)
+        self.volume_weighted_price(edge_state, config)
+            .or_else(|| self.dijkstra_path(edge_state))
     }
 
     pub fn get_all_pools(&self) -> impl Iterator<Item = &Vec<SubGraphEdge>> + '_ {
@@ -325,6 +340,7 @@ impl PairSubGraph {
     }
 
     pub fn add_new_edge(&mut self, edge_info: &'static PoolPairInformation) -> bool {
+        self.paths.take();
         let t0 = edge_info.token_0;
         let t1 = edge_info.token_1;
 
@@ -553,6 +569,7 @@ impl PairSubGraph {
     }
 
     fn add_tmp_pruned(&mut self, data: Vec<(Pair, Vec<SubGraphEdge>, Direction)>) {
+        self.paths.take();
         data.into_iter()
             .for_each(|(k, bad_edge_to_pool, direction)| {
                 let Some(n0) = self.token_to_index.get(&k.0) else {
@@ -596,6 +613,7 @@ impl PairSubGraph {
         &mut self,
         data: Vec<(Pair, Address)>,
     ) -> Vec<(Pair, Vec<SubGraphEdge>, Direction)> {
+        self.paths.take();
         data.into_iter()
             .filter_map(|(k, bad_edge_to_pool)| {
                 let Some(n0) = self.token_to_index.get(&k.0) else {
@@ -641,6 +659,7 @@ impl PairSubGraph {
     }
 
     fn prune_subgraph_rundown(&mut self, data: Vec<(Pair, Address)>) {
+        self.paths.take();
         data.into_iter().for_each(|(k, bad_edge_to_pool)| {
             let Some(n0) = self.token_to_index.get(&k.0) else {
                 tracing::error!("no token 0 in token to index");
@@ -675,6 +694,7 @@ impl PairSubGraph {
     }
 
     fn prune_subgraph(&mut self, removal_state: &FastHashMap<Pair, FastHashSet<BadEdge>>) {
+        self.paths.take();
         removal_state.iter().for_each(|(k, v)| {
             let Some(n0) = self.token_to_index.get(&k.0) else {
                 tracing::error!("no token 0 in token to index");
@@ -867,6 +887,181 @@ impl PairSubGraph {
         node_price.remove(&goal).is_none()
     }
 
+    /// The price averaged over every path of at most `max_hops` pools, each
+    /// weighted by how much its thinnest hop holds. Paths holding less than
+    /// `min_path_liquidity` are left out, `None` if that leaves none
+    pub fn volume_weighted_price<T>(
+        &self,
+        state: &FastHashMap<Address, &T>,
+        config: &PathConfig,
+    ) -> Option<Rational>
+    where
+        T: ProtocolState,
+    {
+        let min_liquidity = config.min_liquidity();
+
+        let mut pxw = Rational::ZERO;
+        let mut weight = Rational::ZERO;
+
+        // edges are shared between paths, so each edge's pools are only
+        // priced once
+        let mut edge_values = FastHashMap::default();
+        let cached = self.cached_paths(config);
+        let uncached;
+        let paths = match cached {
+            Some(cached) => &cached.paths,
+            None => {
+                uncached = self.enumerate_paths(config);
+                &uncached
+            }
+        };
+
+        'paths: for path in paths {
+            // the price of the path's last node in the start token and the
+            // liquidity of its thinnest hop, also in the start token
+            let mut price = Rational::ONE;
+            let mut thinnest = None::<Rational>;
+
+            for edge in path {
+                let Some((local_price, token_0_am, token_1_am)) = edge_values
+                    .entry(*edge)
+                    .or_insert_with(|| Self::edge_value(&self.graph[*edge], state))
+                else {
+                    continue 'paths
+                };
+
+                let token_0_priced = &*token_0_am / &price;
+                price *= &*local_price;
+                let tvl = token_0_priced + &*token_1_am / &price;
+                thinnest = Some(thinnest.map_or(tvl.clone(), |t| std::cmp::min(t, tvl)));
+            }
+
+            let Some(thinnest) = thinnest else { continue };
+            let liquidity = thinnest * &price;
+            if liquidity >= min_liquidity {
+                pxw += &price * &liquidity;
+                weight += liquidity;
+            }
+        }
+
+        (weight != Rational::ZERO).then(|| pxw / weight)
+    }
+
+    /// The paths for the limits of `config`, enumerated once per change to
+    /// the graph. `None` if they were enumerated under different limits, which
+    /// only happens when a subgraph is priced with more than one config
+    fn cached_paths(&self, config: &PathConfig) -> Option<&CachedPaths> {
+        let cached = self.paths.get_or_init(|| CachedPaths {
+            max_hops:  config.max_hops,
+            max_paths: config.max_paths,
+            paths:     self.enumerate_paths(config),
+        });
+
+        (cached.max_hops == config.max_hops && cached.max_paths == config.max_paths)
+            .then_some(cached)
+    }
+
+    /// Every path of at most `max_hops` edges from the start to the end node
+    /// that visits no node twice, up to `max_paths` of them
+    fn enumerate_paths(&self, config: &PathConfig) -> Vec<Vec<EdgeIndex<u16>>> {
+        let start: NodeIndex<u16> = self.start_node.into();
+        let goal: NodeIndex<u16> = self.end_node.into();
+        let mut paths = Vec::new();
+
+        let mut visit_next = vec![(vec![start], vec![])];
+        while let Some((nodes, edges)) = visit_next.pop() {
+            let node = *nodes.last().unwrap();
+            if node == goal {
+                paths.push(edges);
+                if paths.len() == config.max_paths {
+                    break
+                }
+                continue
+            }
+
+            if edges.len() == config.max_hops {
+                continue
+            }
+
+            for edge in self.graph.edges(node) {
+                let next = edge.target();
+                if nodes.contains(&next) {
+                    continue
+                }
+
+                let mut next_nodes = nodes.clone();
+                next_nodes.push(next);
+                let mut next_edges = edges.clone();
+                next_edges.push(edge.id());
+                visit_next.push((next_nodes, next_edges));
+            }
+        }
+
+        paths
+    }
+
+    /// The liquidity weighted price of an edge's pools, along with how much
+    /// of its source and target token they hold
+    fn edge_value<T>(
+        edge_weight: &[SubGraphEdge],
+        state: &FastHashMap<Address, &T>,
+    ) -> Option<(Rational, Rational, Rational)>
+    where
+        T: ProtocolState,
+    {
+        let mut pxw = Rational::ZERO;
+        let mut weight = Rational::ZERO;
+        let mut token_0_am = Rational::ZERO;
+        let mut token_1_am = Rational::ZERO;
+
+        for info in edge_weight {
+            let Some(pool_state) = state.get(&info.pool_addr) else {
+                tracing::debug!(addr=?info.pool_addr,"failed to fetch pool state while generating price");
+                continue;
+            };
+
+            let Ok(pool_price) = pool_state.price(info.get_base_token()) else {
+                continue;
+            };
+
+            let (t0, t1) = pool_state.tvl(info.get_base_token());
+
+            let t0xt1 = &t0 * &t1;
+            pxw += pool_price * &t0xt1;
+            weight += t0xt1;
+
+            token_0_am += t0;
+            token_1_am += t1;
+        }
+
+        if weight == Rational::ZERO {
+            return None
+        }
+
+        Some((pxw / weight, token_0_am, token_1_am))
+    }
+
+    /// The liquidity weighted price of an edge's pools, along with how much
+    /// they hold in the start token, given the `price` of the edge's source
+    /// token in the start token
+    fn edge_price_and_tvl<T>(
+        edge_weight: &[SubGraphEdge],
+        state: &FastHashMap<Address, &T>,
+        price: &Rational,
+    ) -> Option<(Rational, Rational)>
+    where
+        T: ProtocolState,
+    {
+        let (local_weighted_price, token_0_am, token_1_am) = Self::edge_value(edge_weight, state)?;
+
+        // calculate tvl of pool using the start token as the quote
+        let token_0_priced = token_0_am * price.clone().reciprocal();
+        let new_price = price * &local_weighted_price;
+        let token_1_priced = token_1_am * new_price.reciprocal();
+
+        Some((local_weighted_price, token_0_priced + token_1_priced))
+    }
+
     pub fn dijkstra_path<T>(&self, state: &FastHashMap<Address, &T>) -> Option<Rational>
     where
         T: ProtocolState,
@@ -900,42 +1095,12 @@ impl PairSubGraph {
                     continue
                 }
 
-                let mut pxw = Rational::ZERO;
-                let mut weight = Rational::ZERO;
-                let mut token_0_am = Rational::ZERO;
-                let mut token_1_am = Rational::ZERO;
-
-                // calculate tvl of pool using the start token as the quote
-
-                for info in edge_weight {
-                    let Some(pool_state) = state.get(&info.pool_addr) else {
-                        tracing::debug!(addr=?info.pool_addr,"failed to fetch pool state while generating price");
-                        continue;
-                    };
-
-                    let Ok(pool_price) = pool_state.price(info.get_base_token()) else {
-                        continue;
-                    };
-
-                    let (t0, t1) = pool_state.tvl(info.get_base_token());
-
-                    let t0xt1 = &t0 * &t1;
-                    pxw += pool_price * &t0xt1;
-                    weight += t0xt1;
-
-                    token_0_am += t0;
-                    token_1_am += t1;
-                }
-
-                if weight == Rational::ZERO {
+                let Some((local_weighted_price, tvl)) =
+                    Self::edge_price_and_tvl(edge_weight, state, &price)
+                else {
                     continue
-                }
-
-                let local_weighted_price = pxw / weight;
-                let token_0_priced = token_0_am * price.clone().reciprocal();
+                };
                 let new_price = &price * local_weighted_price;
-                let token_1_priced = token_1_am * new_price.clone().reciprocal();
-                let tvl = token_0_priced + token_1_priced;
                 let next_score = &node_score + std::cmp::max(Rational::ZERO, MAX_TVL_WEIGHT - tvl);
 
                 match scores.entry(next) {
@@ -1129,8 +1294,82 @@ pub mod test {
         state_map.insert(t3, &e3_price);
 
         // (t4 / t0) = 10 * 20 * 1 /500 * 1/52 = 1/130
-        let price = graph.fetch_price(&state_map).unwrap();
+        let price = graph
+            .fetch_price(&state_map, &PathConfig::default())
+            .unwrap();
 
         assert_eq!(price, Rational::from_unsigneds(1usize, 390usize))
     }
+    #[test]
+    fn test_volume_weighted_pricing() {
+        addresses!(t0, t1, t2, t3);
+        // t0 -> t1 -> t3 and t0 -> t2 -> t3, the pools are keyed by the edge's
+        // first token
+        let edges = vec![
+            build_edge(t0, t0, t1),
+            build_edge(t1, t1, t3),
+            build_edge(t2, t0, t2),
+            build_edge(t3, t2, t3),
+        ];
+        let pair = Pair(t0, t3);
+        let graph = PairSubGraph::init(pair, pair, pair, None, edges, 0);
+
+        // prices t3 at 2, its thinnest pool holds 30k t0, worth 60k t3
+        let a0 =
+            MockPoolState::new(Rational::from(2), Rational::from(30_000), Rational::from(60_000));
+        let a1 = MockPoolState::new(Rational::ONE, Rational::from(30_000), Rational::from(30_000));
+        // prices t3 at 4, its thinnest pool holds 20k t0, worth 80k t3
+        let b0 = MockPoolState::new(Rational::ONE, Rational::from(10_000), Rational::from(10_000));
+        let b1 =
+            MockPoolState::new(Rational::from(4), Rational::from(10_000), Rational::from(40_000));
+
+        let state_map: FastHashMap<_, _> = [(t0, &a0), (t1, &a1), (t2, &b0), (t3, &b1)]
+            .into_iter()
+            .collect();
+
+        let config = PathConfig { min_path_liquidity: 0.0, ..Default::default() };
+        assert_eq!(
+            graph.fetch_price(&state_map, &config).unwrap(),
+            Rational::from_unsigneds(22usize, 7usize)
+        );
+
+        // too thin a path is left out
+        let config = PathConfig { min_path_liquidity: 70_000.0, ..Default::default() };
+        assert_eq!(graph.fetch_price(&state_map, &config).unwrap(), Rational::from(4));
+
+        // without a short enough path the single best one is used
+        let config = PathConfig { max_hops: 1, ..Default::default() };
+        assert!(graph.volume_weighted_price(&state_map, &config).is_none());
+        assert!(graph.fetch_price(&state_map, &config).is_some());
+    }
+
+    #[test]
+    fn test_paths_follow_graph_changes() {
+        addresses!(t0, t1, t2, t3, t4);
+        let mut graph = make_simple_graph();
+        let config = PathConfig { min_path_liquidity: 0.0, ..Default::default() };
+
+        let pools = [t0, t1, t2, t3]
+            .map(|_| MockPoolState::new(Rational::ONE, Rational::ONE, Rational::ONE));
+        let moved = MockPoolState::new(Rational::from(2), Rational::ONE, Rational::ONE);
+        let direct = MockPoolState::new(Rational::from(4), Rational::ONE, Rational::from(4));
+
+        let mut state_map: FastHashMap<_, _> = [t0, t1, t2, t3].into_iter().zip(&pools).collect();
+        assert_eq!(graph.fetch_price(&state_map, &config).unwrap(), Rational::ONE);
+
+        // pool state changes are picked up without enumerating the paths again
+        state_map.insert(t3, &moved);
+        assert_eq!(graph.fetch_price(&state_map, &config).unwrap(), Rational::from(2));
+
+        // a new pool between the pair is a new path
+        let info = PoolPairInformation::new(t4, Protocol::UniswapV2, t0, t4);
+        assert!(graph.add_new_edge(Box::leak(Box::new(info))));
+        state_map.insert(t4, &direct);
+
+        // the line's thinnest pool holds 3 in t4 and the direct pool 8
+        assert_eq!(
+            graph.fetch_price(&state_map, &config).unwrap(),
+            Rational::from_unsigneds(38usize, 11usize)
+        );
+    }
 }
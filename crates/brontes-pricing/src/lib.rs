@@ -30,6 +30,8 @@ use rayon::iter::{IntoParallelIterator, ParallelIterator};
 use crate::graphs::StateWithDependencies;
 pub mod function_call_bench;
 mod graphs;
+pub mod path_config;
+pub mod price_overrides;
 pub mod protocols;
 mod subgraph_query;
 pub mod types;
@@ -52,7 +54,7 @@ use brontes_types::{
     traits::TracingProvider,
     FastHashMap, FastHashSet,
 };
-use futures::Stream;
+use futures::{Stream, StreamExt};
 pub use graphs::{
     AllPairGraph, GraphManager, StateTracker, SubGraphRegistry, SubgraphVerifier,
     VerificationResults,
@@ -62,6 +64,8 @@ use malachite::{
     num::basic::traits::{One, Zero},
     Rational,
 };
+use path_config::PathConfig;
+use price_overrides::{PriceOverrides, PriceRules};
 use protocols::lazy::{LazyExchangeLoader, LazyResult, LoadResult};
 pub use protocols::{Protocol, *};
 use subgraph_query::*;
@@ -101,6 +105,9 @@ pub struct BrontesBatchPricer<T: TracingProvider> {
     graph_manager:   GraphManager,
     /// lazy loads dex pairs so we only fetch init state that is needed
     lazy_loader:     LazyExchangeLoader<T>,
+    /// derives the price of tokens without a market of their own from their
+    /// underlying
+    price_overrides: PriceOverrides<T>,
     dex_quotes:      FastHashMap<u64, DexQuotes>,
     /// pairs that failed to be verified. we use this to avoid the fallback for
     /// transfers
@@ -138,6 +145,7 @@ impl<T: TracingProvider> BrontesBatchPricer<T> {
             update_rx,
             graph_manager,
             dex_quotes: FastHashMap::default(),
+            price_overrides: PriceOverrides::new(provider.clone(), PriceRules::default()),
             lazy_loader: LazyExchangeLoader::new(provider, executor),
             current_block,
             completed_block: current_block,
@@ -148,6 +156,16 @@ impl<T: TracingProvider> BrontesBatchPricer<T> {
         }
     }
 
+    pub fn with_price_rules(mut self, rules: PriceRules) -> Self {
+        self.price_overrides.set_rules(rules);
+        self
+    }
+
+    pub fn with_path_config(mut self, config: PathConfig) -> Self {
+        self.graph_manager.set_path_config(config);
+        self
+    }
+
     pub fn current_block_processing(&self) -> u64 {
         self.completed_block
     }
@@ -215,6 +233,9 @@ impl<T: TracingProvider> BrontesBatchPricer<T> {
             let is_transfer = msg.is_transfer();
 
             let block = msg.block;
+            self.price_overrides.request_rate(block, pair.0);
+            self.price_overrides.request_rate(block, pair.1);
+
             let pair0 = Pair(pair.0, self.quote_asset);
             let pair1 = Pair(pair.1, self.quote_asset);
 
@@ -301,6 +322,17 @@ impl<T: TracingProvider> BrontesBatchPricer<T> {
         if pool_pair.0 == pool_pair.1 {
             return Some(Rational::ONE)
         }
+
+        // price rules take precedence, as long as the underlying can be priced
+        if let Some((underlying, rate)) = self
+            .price_overrides
+            .underlying(pool_pair.0, self.completed_block)
+        {
+            if let Some(price) = self.get_dex_price(Pair(underlying, pool_pair.1), goes_through) {
+                return Some(price * rate)
+            }
+        }
+
         self.graph_manager.get_price(pool_pair, goes_through)
     }
 
@@ -965,6 +997,7 @@ impl<T: TracingProvider> BrontesBatchPricer<T> {
 
     fn can_progress(&self) -> bool {
         self.lazy_loader.can_progress(&self.completed_block)
+            && self.price_overrides.can_progress(&self.completed_block)
             && self
                 .graph_manager
                 .verification_done_for_block(self.completed_block)
@@ -1031,6 +1064,7 @@ impl<T: TracingProvider> BrontesBatchPricer<T> {
             .unwrap_or(DexQuotes(vec![]));
 
         self.handle_drastic_price_changes(&mut res);
+        self.price_overrides.finish_block(block);
         // prune dead subgraphs
         self.graph_manager
             .prune_dead_subgraphs(self.completed_block);
@@ -1162,6 +1196,7 @@ impl<T: TracingProvider> BrontesBatchPricer<T> {
             .unwrap_or(DexQuotes(vec![]));
 
         self.handle_drastic_price_changes(&mut res);
+        self.price_overrides.finish_block(block);
         // prune dead subgraphs
         self.graph_manager
             .prune_dead_subgraphs(self.completed_block);
@@ -1187,6 +1222,8 @@ impl<T: TracingProvider> BrontesBatchPricer<T> {
             self.on_pool_resolve(buf);
         }
 
+        while let Poll::Ready(Some(())) = self.price_overrides.poll_next_unpin(cx) {}
+
         let pairs = self.lazy_loader.pairs_to_verify();
         if !pairs.is_empty() {
             execute_on!(target = pricing, self.try_verify_subgraph(pairs));
@@ -1267,6 +1304,7 @@ impl<T: TracingProvider> Stream for BrontesBatchPricer<T> {
                     }
                     Poll::Pending => {
                         if self.lazy_loader.is_empty()
+                            && self.price_overrides.is_empty()
                             && self.lazy_loader.can_progress(&self.completed_block)
                             && self
                                 .graph_manager
@@ -0,0 +1,58 @@
+//! Limits on the paths a token is priced through. A long-tail token's only
+//! route to the quote is often a couple of thin pools, and a single such path
+//! can value it far off. Paths are capped in length when subgraphs are built,
+//! and a subgraph's price is the average over all its paths that can carry
+//! enough volume, weighted by how much each of them can carry.
+//!
+//! The limits are loaded from `config/pricing_path_config.toml`.
+use std::path::Path;
+
+use malachite::{num::basic::traits::Zero, Rational};
+use serde::Deserialize;
+use tracing::warn;
+
+const PATH_CONFIG_FILE: &str =
+    concat!(env!("CARGO_MANIFEST_DIR"), "/../../config/pricing_path_config.toml");
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PathConfig {
+    /// the most pools a path can go through
+    pub max_hops:           usize,
+    /// paths whose thinnest pool holds less than this, in the token the
+    /// subgraph ends in, are left out of the average. The end token is the
+    /// quote unless the subgraph extends to another pair's subgraph. If no
+    /// path is deep enough the single best path is used
+    pub min_path_liquidity: f64,
+    /// the most paths averaged over for a single subgraph
+    pub max_paths:          usize,
+}
+
+impl Default for PathConfig {
+    fn default() -> Self {
+        Self { max_hops: 4, min_path_liquidity: 10_000.0, max_paths: 32 }
+    }
+}
+
+impl PathConfig {
+    pub fn load(path: impl AsRef<Path>) -> eyre::Result<Self> {
+        let config: Self = toml::from_str(&std::fs::read_to_string(path)?)?;
+        if config.max_hops == 0 || config.max_paths == 0 {
+            eyre::bail!("max_hops and max_paths need to be at least one")
+        }
+
+        Ok(config)
+    }
+
+    /// loads the limits from `config/pricing_path_config.toml`, falling back
+    /// to the defaults if the file can't be loaded
+    pub fn load_from_config() -> Self {
+        Self::load(PATH_CONFIG_FILE)
+            .inspect_err(|e| warn!(?e, "failed to load pricing path config, using defaults"))
+            .unwrap_or_default()
+    }
+
+    pub fn min_liquidity(&self) -> Rational {
+        Rational::try_from_float_simplest(self.min_path_liquidity).unwrap_or(Rational::ZERO)
+    }
+}
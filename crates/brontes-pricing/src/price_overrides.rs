@@ -0,0 +1,233 @@
+//! Wrapped and bridged tokens such as aTokens, cTokens and LP wrappers often
+//! have no market of their own, so the pools they are in give a poor price or
+//! none at all. A price rule derives the price of such a token from its
+//! underlying instead, scaled by a fixed rate or by an exchange rate that is
+//! read from chain at the block being priced.
+//!
+//! Rules are loaded from `config/price_override_config.toml` and take
+//! precedence over pool based pricing whenever the underlying can be priced.
+use std::{future::Future, path::Path, pin::Pin, sync::Arc, task::Poll};
+
+use alloy_primitives::{keccak256, Address, U256};
+use brontes_types::{traits::TracingProvider, FastHashMap, ToScaledRational};
+use futures::{stream::FuturesUnordered, Stream, StreamExt};
+use malachite::Rational;
+use reth_rpc_types::{request::TransactionInput, TransactionRequest};
+use serde::Deserialize;
+use tracing::{error, warn};
+
+const PRICE_OVERRIDE_CONFIG_FILE: &str =
+    concat!(env!("CARGO_MANIFEST_DIR"), "/../../config/price_override_config.toml");
+
+type RateFuture = Pin<Box<dyn Future<Output = (u64, Address, eyre::Result<Rational>)> + Send>>;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PriceRule {
+    /// the token the price is derived from
+    pub underlying:    Address,
+    /// a fixed amount of the underlying that one token is worth
+    pub rate:          Option<f64>,
+    /// a view function without arguments that returns the amount of the
+    /// underlying one token is worth, e.g `exchangeRateStored()`
+    pub rate_call:     Option<String>,
+    /// the contract `rate_call` is made on, defaults to the token
+    pub rate_target:   Option<Address>,
+    /// the decimals of the value returned by `rate_call`
+    #[serde(default)]
+    pub rate_decimals: u8,
+}
+
+impl PriceRule {
+    pub fn fixed_rate(&self) -> Option<Rational> {
+        self.rate.and_then(Rational::try_from_float_simplest)
+    }
+}
+
+/// Price rules keyed by the token they price
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(transparent)]
+pub struct PriceRules(pub FastHashMap<Address, PriceRule>);
+
+impl PriceRules {
+    pub fn load(path: impl AsRef<Path>) -> eyre::Result<Self> {
+        let rules: Self = toml::from_str(&std::fs::read_to_string(path)?)?;
+
+        for (token, rule) in &rules.0 {
+            if rule.rate.is_none() == rule.rate_call.is_none() {
+                eyre::bail!("price rule for {token:?} needs exactly one of rate or rate_call")
+            }
+        }
+
+        Ok(rules)
+    }
+
+    /// loads the rules from `config/price_override_config.toml`, if the file
+    /// can't be loaded no overrides are applied
+    pub fn load_from_config() -> Self {
+        Self::load(PRICE_OVERRIDE_CONFIG_FILE)
+            .inspect_err(|e| warn!(?e, "failed to load price overrides, using none"))
+            .unwrap_or_default()
+    }
+}
+
+/// Tracks the price rules along with the exchange rates that are being
+/// fetched for the blocks that are being priced.
+pub struct PriceOverrides<T: TracingProvider> {
+    provider:          Arc<T>,
+    rules:             PriceRules,
+    /// `None` while the rate is being fetched or if fetching it failed
+    rates:             FastHashMap<(u64, Address), Option<Rational>>,
+    pending:           FuturesUnordered<RateFuture>,
+    pending_per_block: FastHashMap<u64, usize>,
+}
+
+impl<T: TracingProvider> PriceOverrides<T> {
+    pub fn new(provider: Arc<T>, rules: PriceRules) -> Self {
+        Self {
+            provider,
+            rules,
+            rates: FastHashMap::default(),
+            pending: FuturesUnordered::new(),
+            pending_per_block: FastHashMap::default(),
+        }
+    }
+
+    pub fn set_rules(&mut self, rules: PriceRules) {
+        self.rules = rules;
+    }
+
+    /// Starts fetching the exchange rate of the token at the given block, if it
+    /// has a rule that reads it from chain.
+    pub fn request_rate(&mut self, block: u64, token: Address) {
+        let Some(rule) = self.rules.0.get(&token) else { return };
+        let Some(call) = rule.rate_call.as_ref() else { return };
+        if self.rates.contains_key(&(block, token)) {
+            return
+        }
+
+        let selector = keccak256(call.as_bytes())[..4].to_vec();
+        let req = TransactionRequest {
+            to: Some(rule.rate_target.unwrap_or(token)),
+            input: TransactionInput::new(selector.into()),
+            ..Default::default()
+        };
+        let decimals = rule.rate_decimals;
+        let provider = self.provider.clone();
+
+        self.rates.insert((block, token), None);
+        *self.pending_per_block.entry(block).or_default() += 1;
+
+        self.pending.push(Box::pin(async move {
+            let rate = provider
+                .eth_call(req, Some(block.into()), None, None)
+                .await
+                .and_then(|res| {
+                    let raw: [u8; 32] = res
+                        .get(..32)
+                        .and_then(|r| r.try_into().ok())
+                        .ok_or_else(|| eyre::eyre!("invalid rate call return"))?;
+                    Ok(U256::from_be_bytes(raw).to_scaled_rational(decimals))
+                });
+
+            (block, token, rate)
+        }));
+    }
+
+    /// The underlying token and the amount of it the token is worth at the
+    /// block, `None` if the token has no rule or its rate couldn't be fetched
+    pub fn underlying(&self, token: Address, block: u64) -> Option<(Address, Rational)> {
+        let rule = self.rules.0.get(&token)?;
+        let rate = match rule.fixed_rate() {
+            Some(rate) => rate,
+            None => self.rates.get(&(block, token)).cloned().flatten()?,
+        };
+
+        Some((rule.underlying, rate))
+    }
+
+    pub fn can_progress(&self, block: &u64) -> bool {
+        self.pending_per_block.get(block).copied().unwrap_or(0) == 0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// drops the rates of a block that has been priced
+    pub fn finish_block(&mut self, block: u64) {
+        self.rates.retain(|(rate_block, _), _| *rate_block != block);
+        self.pending_per_block.remove(&block);
+    }
+}
+
+impl<T: TracingProvider> Stream for PriceOverrides<T> {
+    type Item = ();
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let Poll::Ready(Some((block, token, rate))) = self.pending.poll_next_unpin(cx) else {
+            return Poll::Pending
+        };
+
+        if let Some(pending) = self.pending_per_block.get_mut(&block) {
+            *pending -= 1;
+        }
+
+        match rate {
+            Ok(rate) => {
+                self.rates.insert((block, token), Some(rate));
+            }
+            Err(e) => {
+                error!(?token, ?block, ?e, "failed to fetch rate for price override");
+            }
+        }
+
+        Poll::Ready(Some(()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_price_rules() {
+        let rules: PriceRules = toml::from_str(
+            r#"
+            ["0x98C23E9d8f34FEFb1B7BD6a91B7FF122F4e16F5c"]
+            underlying = "0xA0b86991c6218b36c1d19d4a2e9eB0cE3606eB48"
+            rate = 1.0
+
+            ["0x39AA39c021dfbaE8faC545936693aC917d5E7563"]
+            underlying = "0xA0b86991c6218b36c1d19d4a2e9eB0cE3606eB48"
+            rate_call = "exchangeRateStored()"
+            rate_decimals = 16
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(rules.0.len(), 2);
+        let a_usdc = rules
+            .0
+            .get(
+                &"0x98C23E9d8f34FEFb1B7BD6a91B7FF122F4e16F5c"
+                    .parse::<Address>()
+                    .unwrap(),
+            )
+            .unwrap();
+        assert_eq!(a_usdc.fixed_rate(), Some(Rational::from(1)));
+
+        let c_usdc = rules
+            .0
+            .get(
+                &"0x39AA39c021dfbaE8faC545936693aC917d5E7563"
+                    .parse::<Address>()
+                    .unwrap(),
+            )
+            .unwrap();
+        assert_eq!(c_usdc.fixed_rate(), None);
+        assert_eq!(c_usdc.rate_decimals, 16);
+    }
+}
@@ -0,0 +1,331 @@
+use std::sync::Arc;
+
+use alloy_primitives::{hex, Address, Log};
+use alloy_sol_macro::sol;
+use async_trait::async_trait;
+use brontes_types::{
+    normalized_actions::Action, traits::TracingProvider, ToFloatNearest, ToScaledRational,
+};
+use malachite::{num::basic::traits::Zero, Rational};
+
+use super::make_call_request;
+use crate::{
+    curve::math::stable_spot_price,
+    errors::{AmmError, ArithmeticError, EventLogError},
+    uniswap_v2::IErc20,
+    UpdatableProtocol,
+};
+
+sol!(
+    interface IBalancerV2Pool {
+        function getPoolId() external view returns (bytes32);
+        function getNormalizedWeights() external view returns (uint256[]);
+        function getAmplificationParameter() external view returns (
+            uint256 value,
+            bool isUpdating,
+            uint256 precision
+        );
+        function getScalingFactors() external view returns (uint256[]);
+    }
+);
+
+sol!(
+    interface IBalancerV2Vault {
+        function getPoolTokens(bytes32 poolId) external view returns (
+            address[] tokens,
+            uint256[] balances,
+            uint256 lastChangeBlock
+        );
+    }
+);
+
+/// Balancer V2 pools hold no funds, their balances are kept by the vault
+pub const BALANCER_V2_VAULT: Address =
+    Address::new(hex!("BA12222222228d8Ba445958a75a0704d566BF2C8"));
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum BalancerPoolKind {
+    /// The normalized weight of each token
+    Weighted { weights: Vec<Rational> },
+    /// `amp` is the amplification without its precision, `rates` what one of
+    /// each token counts as inside the invariant, as given by the pool's
+    /// scaling factors
+    ComposableStable { amp: f64, rates: Vec<Rational> },
+}
+
+/// A Balancer V2 weighted or composable stable pool. `tokens` are the tokens
+/// registered with the vault, a composable stable pool's own bpt included. The
+/// bpt has no price here and is left out of the stable invariant.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BalancerPool {
+    pub address:  Address,
+    pub tokens:   Vec<Address>,
+    pub decimals: Vec<u8>,
+    pub balances: Vec<Rational>,
+    pub kind:     BalancerPoolKind,
+}
+
+#[async_trait]
+impl UpdatableProtocol for BalancerPool {
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    fn tokens(&self) -> Vec<Address> {
+        self.tokens.clone()
+    }
+
+    /// Prices `base_token` in whichever of the pool's first two tokens it isn't
+    fn calculate_price(&self, base_token: Address) -> Result<Rational, ArithmeticError> {
+        let i = self
+            .token_index(base_token)
+            .ok_or(ArithmeticError::TokenNotInPool)?;
+        let j = usize::from(i == 0);
+        if self.tokens[i] == self.address || self.tokens[j] == self.address {
+            return Err(ArithmeticError::TokenNotInPool)
+        }
+
+        match &self.kind {
+            BalancerPoolKind::Weighted { weights } => {
+                weighted_spot_price(&self.balances, weights, i, j)
+            }
+            BalancerPoolKind::ComposableStable { amp, rates } => {
+                // the invariant runs over every token but the bpt
+                let coins = (0..self.tokens.len())
+                    .filter(|k| self.tokens[*k] != self.address)
+                    .collect::<Vec<_>>();
+                let xp = coins
+                    .iter()
+                    .map(|k| (&self.balances[*k] * &rates[*k]).to_float())
+                    .collect::<Vec<_>>();
+                let position = |k| coins.iter().position(|c| *c == k).unwrap();
+
+                let price = stable_spot_price(*amp, &xp, position(i), position(j))
+                    .and_then(|price| Rational::try_from_float_simplest(price).ok())
+                    .ok_or(ArithmeticError::CurveNoConvergence)?;
+
+                Ok(price * &rates[i] / &rates[j])
+            }
+        }
+    }
+
+    fn sync_from_action(&mut self, action: Action) -> Result<(), AmmError> {
+        match action {
+            Action::Swap(swap) => {
+                self.add_balance(swap.token_in.address, &swap.amount_in);
+                self.sub_balance(swap.token_out.address, &swap.amount_out);
+            }
+            Action::SwapWithFee(swap) => {
+                self.add_balance(swap.token_in.address, &swap.amount_in);
+                self.sub_balance(swap.token_out.address, &swap.amount_out);
+            }
+            Action::Mint(mint) => {
+                for (token, amount) in mint.token.iter().zip(&mint.amount) {
+                    self.add_balance(token.address, amount);
+                }
+            }
+            Action::Burn(burn) => {
+                for (token, amount) in burn.token.iter().zip(&burn.amount) {
+                    self.sub_balance(token.address, amount);
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// Balance changes are logged by the vault, not the pool, so pools are
+    /// synced from the classified actions instead
+    fn sync_from_log(&mut self, _log: Log) -> Result<(), AmmError> {
+        Err(AmmError::EventLogError(EventLogError::InvalidEventSignature))
+    }
+}
+
+impl BalancerPool {
+    /// Loads the pool as a weighted pool if it has weights, and as a
+    /// composable stable pool if it has an amplification. Other kinds of pools
+    /// fail to load
+    pub async fn new_load_on_block<M: TracingProvider>(
+        address: Address,
+        middleware: Arc<M>,
+        block: u64,
+    ) -> Result<Self, AmmError> {
+        let block = Some(block);
+
+        let pool_id =
+            make_call_request(IBalancerV2Pool::getPoolIdCall::new(()), &middleware, address, block)
+                .await?
+                ._0;
+        let registered = make_call_request(
+            IBalancerV2Vault::getPoolTokensCall::new((pool_id,)),
+            &middleware,
+            BALANCER_V2_VAULT,
+            block,
+        )
+        .await?;
+        let tokens = registered.tokens;
+        if tokens.len() < 2 {
+            return Err(AmmError::NoStateError(address))
+        }
+
+        let mut decimals = Vec::with_capacity(tokens.len());
+        for token in &tokens {
+            let call = IErc20::decimalsCall::new(());
+            decimals.push(
+                make_call_request(call, &middleware, *token, block)
+                    .await?
+                    ._0,
+            );
+        }
+
+        let balances = registered
+            .balances
+            .into_iter()
+            .zip(&decimals)
+            .map(|(balance, decimals)| balance.to_scaled_rational(*decimals))
+            .collect();
+
+        let kind = if let Ok(weights) = make_call_request(
+            IBalancerV2Pool::getNormalizedWeightsCall::new(()),
+            &middleware,
+            address,
+            block,
+        )
+        .await
+        {
+            BalancerPoolKind::Weighted {
+                weights: weights
+                    ._0
+                    .into_iter()
+                    .map(|weight| weight.to_scaled_rational(18))
+                    .collect(),
+            }
+        } else {
+            let amp = make_call_request(
+                IBalancerV2Pool::getAmplificationParameterCall::new(()),
+                &middleware,
+                address,
+                block,
+            )
+            .await?;
+            let scaling_factors = make_call_request(
+                IBalancerV2Pool::getScalingFactorsCall::new(()),
+                &middleware,
+                address,
+                block,
+            )
+            .await?
+            ._0;
+
+            BalancerPoolKind::ComposableStable {
+                amp:   amp.value.saturating_to::<u64>() as f64
+                    / amp.precision.saturating_to::<u64>().max(1) as f64,
+                // scaling factors are `10^(36 - decimals)` for a rate of one
+                rates: scaling_factors
+                    .into_iter()
+                    .zip(&decimals)
+                    .map(|(factor, decimals)| {
+                        factor.to_scaled_rational(36u8.saturating_sub(*decimals))
+                    })
+                    .collect(),
+            }
+        };
+
+        Ok(Self { address, tokens, decimals, balances, kind })
+    }
+
+    pub fn get_tvl(&self, base: Address) -> (Rational, Rational) {
+        let i = self.token_index(base).unwrap_or_default();
+        let j = usize::from(i == 0);
+
+        (self.balances[i].clone(), self.balances[j].clone())
+    }
+
+    fn token_index(&self, token: Address) -> Option<usize> {
+        self.tokens.iter().position(|t| *t == token)
+    }
+
+    fn add_balance(&mut self, token: Address, amount: &Rational) {
+        if let Some(i) = self.token_index(token) {
+            self.balances[i] += amount;
+        }
+    }
+
+    fn sub_balance(&mut self, token: Address, amount: &Rational) {
+        if let Some(i) = self.token_index(token) {
+            self.balances[i] -= amount;
+            if self.balances[i] < Rational::ZERO {
+                self.balances[i] = Rational::ZERO;
+            }
+        }
+    }
+}
+
+/// How much of token `j` one of token `i` is worth in a weighted pool, the
+/// ratio of the two balances per unit of weight
+fn weighted_spot_price(
+    balances: &[Rational],
+    weights: &[Rational],
+    i: usize,
+    j: usize,
+) -> Result<Rational, ArithmeticError> {
+    if balances[i] == Rational::ZERO || weights[j] == Rational::ZERO {
+        return Err(ArithmeticError::UniV2DivZero)
+    }
+
+    Ok((&balances[j] / &weights[j]) / (&balances[i] / &weights[i]))
+}
+
+#[cfg(test)]
+mod tests {
+    use malachite::num::basic::traits::One;
+
+    use super::*;
+
+    const BAL: Address = Address::repeat_byte(1);
+    const WETH: Address = Address::repeat_byte(2);
+    const USDC: Address = Address::repeat_byte(3);
+    const POOL: Address = Address::repeat_byte(4);
+
+    #[test]
+    fn test_weighted_price() {
+        // an 80/20 bal / weth pool
+        let pool = BalancerPool {
+            address:  POOL,
+            tokens:   vec![BAL, WETH],
+            decimals: vec![18, 18],
+            balances: vec![Rational::from(8_000_000), Rational::from(10_000)],
+            kind:     BalancerPoolKind::Weighted {
+                weights: vec![Rational::from_signeds(4, 5), Rational::from_signeds(1, 5)],
+            },
+        };
+
+        assert_eq!(pool.calculate_price(BAL).unwrap(), Rational::from_signeds(1, 200));
+        assert_eq!(pool.calculate_price(WETH).unwrap(), Rational::from(200));
+    }
+
+    fn stable_pool(tokens: Vec<Address>, balances: Vec<u64>) -> BalancerPool {
+        BalancerPool {
+            address: POOL,
+            decimals: vec![18; tokens.len()],
+            kind: BalancerPoolKind::ComposableStable {
+                amp:   200.0,
+                rates: vec![Rational::ONE; tokens.len()],
+            },
+            tokens,
+            balances: balances.into_iter().map(Rational::from).collect(),
+        }
+    }
+
+    #[test]
+    fn test_composable_stable_price() {
+        // the bpt's balance is left out of the invariant
+        let pool = stable_pool(vec![USDC, BAL, POOL], vec![1_000_000, 1_000_000, u64::MAX]);
+        assert_eq!(pool.calculate_price(USDC).unwrap(), Rational::ONE);
+
+        // the pool's first two tokens include the bpt, which isn't priced
+        let pool = stable_pool(vec![USDC, POOL, BAL], vec![1_000_000, u64::MAX, 1_000_000]);
+        assert!(pool.calculate_price(USDC).is_err());
+    }
+}
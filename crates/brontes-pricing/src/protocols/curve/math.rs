@@ -0,0 +1,141 @@
+//! Spot prices of curve's two invariants. The pools' own math works on
+//! integers and only ever quotes a trade, pricing needs the marginal rate
+//! instead, which is the ratio of the invariant's partial derivatives in the
+//! two coins. Balances are passed in as `xp`, already scaled by the pool's
+//! rates so that every coin is worth about the same inside the invariant.
+
+const MAX_ITERATIONS: usize = 255;
+const PRECISION: f64 = 1e-12;
+
+/// The stableswap invariant `D` for the amplification `amp` (`A()` of the
+/// pool, without the `n^(n-1)` factor)
+pub fn stable_d(amp: f64, xp: &[f64]) -> Option<f64> {
+    let n = xp.len() as f64;
+    let sum = xp.iter().sum::<f64>();
+    if sum == 0.0 {
+        return None
+    }
+    let ann = amp * n;
+
+    let mut d = sum;
+    for _ in 0..MAX_ITERATIONS {
+        let d_p = xp.iter().fold(d, |d_p, x| d_p * d / (x * n));
+        let prev = d;
+        d = (ann * sum + d_p * n) * d / ((ann - 1.0) * d + (n + 1.0) * d_p);
+
+        if (d - prev).abs() <= d * PRECISION {
+            return d.is_finite().then_some(d)
+        }
+    }
+
+    None
+}
+
+/// How much of coin `j` one unit of coin `i` is worth in a stableswap pool
+pub fn stable_spot_price(amp: f64, xp: &[f64], i: usize, j: usize) -> Option<f64> {
+    if xp.iter().any(|x| *x <= 0.0) {
+        return None
+    }
+    let n = xp.len() as f64;
+    let ann = amp * n;
+    let d = stable_d(amp, xp)?;
+    let d_p = xp.iter().fold(d, |d_p, x| d_p * d / (x * n));
+
+    Some((ann + d_p / xp[i]) / (ann + d_p / xp[j]))
+}
+
+/// The cryptoswap invariant, zero at the pool's `D`, with the balances given
+/// as fractions `x` of `D`
+fn crypto_invariant(ann: f64, gamma: f64, x: &[f64]) -> f64 {
+    let n = x.len() as f64;
+    let k0 = x.iter().fold(n.powf(n), |k0, x| k0 * x);
+    let sum = x.iter().sum::<f64>();
+    let g = gamma + 1.0 - k0;
+
+    ann * k0 * gamma * gamma / (g * g) * (sum - 1.0) + k0 - 1.0
+}
+
+/// The cryptoswap invariant `D` for `ann` (`A()` of the pool divided by its
+/// `A_MULTIPLIER`) and `gamma` (`gamma()` divided by 1e18)
+pub fn crypto_d(ann: f64, gamma: f64, xp: &[f64]) -> Option<f64> {
+    let n = xp.len() as f64;
+    let sum = xp.iter().sum::<f64>();
+    if xp.iter().any(|x| *x <= 0.0) {
+        return None
+    }
+    // solved on the balances normalized to sum to one, which keeps the newton
+    // steps well conditioned whatever the pool's size
+    let xp = xp.iter().map(|x| x / sum).collect::<Vec<_>>();
+    let invariant =
+        |d: f64| crypto_invariant(ann, gamma, &xp.iter().map(|x| x / d).collect::<Vec<_>>());
+
+    let mut d = n * xp.iter().product::<f64>().powf(1.0 / n);
+    for _ in 0..MAX_ITERATIONS {
+        let h = d * 1e-7;
+        let slope = (invariant(d + h) - invariant(d - h)) / (2.0 * h);
+        let next = d - invariant(d) / slope;
+
+        if (next - d).abs() <= next * PRECISION {
+            return (next.is_finite() && next > 0.0).then_some(next * sum)
+        }
+        d = next;
+    }
+
+    None
+}
+
+/// How much of coin `j` one unit of coin `i` is worth in a cryptoswap pool, in
+/// the pool's internal (price scaled) units
+pub fn crypto_spot_price(ann: f64, gamma: f64, xp: &[f64], i: usize, j: usize) -> Option<f64> {
+    let n = xp.len() as f64;
+    let d = crypto_d(ann, gamma, xp)?;
+    let x = xp.iter().map(|x| x / d).collect::<Vec<_>>();
+
+    let k0 = x.iter().fold(n.powf(n), |k0, x| k0 * x);
+    let sum = x.iter().sum::<f64>();
+    let g = gamma + 1.0 - k0;
+    let k = ann * gamma * gamma * k0 / (g * g);
+    let k_prime = ann * gamma * gamma * (gamma + 1.0 + k0) / (g * g * g);
+
+    let partial = |c: usize| k + (sum - 1.0) * k_prime * k0 / x[c] + k0 / x[c];
+
+    Some(partial(i) / partial(j))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-6, "{a} != {b}");
+    }
+
+    #[test]
+    fn test_stable_spot_price() {
+        assert_close(stable_spot_price(100.0, &[1000.0, 1000.0], 0, 1).unwrap(), 1.0);
+
+        // checked against the amount out of a tiny trade on the same balances
+        let xp = [1000.0, 3000.0, 2000.0];
+        assert_close(stable_d(100.0, &xp).unwrap(), 5993.428069403101);
+        assert_close(stable_spot_price(100.0, &xp, 0, 1).unwrap(), 1.0175447449734);
+        assert_close(stable_spot_price(100.0, &xp, 1, 0).unwrap(), 1.0 / 1.0175447449734);
+
+        assert!(stable_spot_price(100.0, &[1000.0, 0.0], 0, 1).is_none());
+    }
+
+    #[test]
+    fn test_crypto_spot_price() {
+        let (ann, gamma) = (1707629.0 / 10000.0, 11809167828997.0 / 1e18);
+        assert_close(crypto_spot_price(ann, gamma, &[1e6, 1e6, 1e6], 0, 2).unwrap(), 1.0);
+
+        // checked against the amount out of a tiny trade on the same balances
+        let xp = [1e6, 1.05e6, 0.97e6];
+        assert_close(crypto_spot_price(ann, gamma, &xp, 0, 1).unwrap(), 1.0473599658383694);
+
+        let (ann, gamma) = (400000.0 / 10000.0, 145000000000000.0 / 1e18);
+        assert_close(
+            crypto_spot_price(ann, gamma, &[1e6, 1.4e6], 0, 1).unwrap(),
+            1.387050059826549,
+        );
+    }
+}
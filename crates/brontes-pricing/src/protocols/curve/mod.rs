@@ -0,0 +1,451 @@
+pub mod math;
+
+use std::sync::Arc;
+
+use alloy_primitives::{Address, Log, U256};
+use alloy_sol_macro::sol;
+use async_trait::async_trait;
+use brontes_types::{
+    constants::{ETH_ADDRESS, WETH_ADDRESS},
+    normalized_actions::Action,
+    traits::TracingProvider,
+    ToFloatNearest, ToScaledRational,
+};
+use malachite::{
+    num::basic::traits::{One, Zero},
+    Rational,
+};
+
+use super::make_call_request;
+use crate::{
+    errors::{AmmError, ArithmeticError, EventLogError},
+    uniswap_v2::IErc20,
+    Protocol, UpdatableProtocol,
+};
+
+sol!(
+    interface ICurvePool {
+        function coins(uint256 i) external view returns (address);
+        function balances(uint256 i) external view returns (uint256);
+        function A() external view returns (uint256);
+        function base_pool() external view returns (address);
+        function get_virtual_price() external view returns (uint256);
+        function stored_rates() external view returns (uint256[]);
+    }
+);
+
+// the first base pools index their coins with an int128
+sol!(
+    interface ICurveLegacyPool {
+        function coins(int128 i) external view returns (address);
+        function balances(int128 i) external view returns (uint256);
+    }
+);
+
+sol!(
+    interface ICurveCryptoPool {
+        function gamma() external view returns (uint256);
+        function price_scale() external view returns (uint256);
+    }
+);
+
+sol!(
+    interface ICurveTriCryptoPool {
+        function price_scale(uint256 k) external view returns (uint256);
+    }
+);
+
+/// The most coins a curve pool can hold
+const MAX_COINS: usize = 8;
+/// Cryptoswap pools store `A` multiplied by this
+const A_MULTIPLIER: f64 = 10_000.0;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CurveInvariant {
+    StableSwap { amp: f64 },
+    CryptoSwap { ann: f64, gamma: f64 },
+}
+
+/// A curve stableswap or cryptoswap pool. Only the pool's first two coins make
+/// up its pair in the pricing graph, the others still move the price through
+/// the invariant.
+///
+/// Balances are followed through the pool's swaps, mints and burns. The
+/// amplification, a cryptoswap pool's price scale and the rates of coins with
+/// an oracle are taken from when the pool was loaded.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CurvePool {
+    pub address:   Address,
+    pub protocol:  Protocol,
+    pub tokens:    Vec<Address>,
+    pub decimals:  Vec<u8>,
+    pub balances:  Vec<Rational>,
+    /// What one of each coin counts as inside the invariant: the stored rate
+    /// for stableswap pools, which is one unless the coin is a metapool's base
+    /// lp token or has an oracle, and the price scale for cryptoswap pools
+    pub rates:     Vec<Rational>,
+    pub invariant: CurveInvariant,
+}
+
+#[async_trait]
+impl UpdatableProtocol for CurvePool {
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    fn tokens(&self) -> Vec<Address> {
+        self.tokens.clone()
+    }
+
+    fn calculate_price(&self, base_token: Address) -> Result<Rational, ArithmeticError> {
+        let i = self
+            .coin_index(base_token)
+            .ok_or(ArithmeticError::TokenNotInPool)?;
+        let j = usize::from(i == 0);
+
+        let xp = self
+            .balances
+            .iter()
+            .zip(&self.rates)
+            .map(|(balance, rate)| (balance * rate).to_float())
+            .collect::<Vec<_>>();
+
+        let price = match self.invariant {
+            CurveInvariant::StableSwap { amp } => math::stable_spot_price(amp, &xp, i, j),
+            CurveInvariant::CryptoSwap { ann, gamma } => {
+                math::crypto_spot_price(ann, gamma, &xp, i, j)
+            }
+        }
+        .and_then(|price| Rational::try_from_float_simplest(price).ok())
+        .ok_or(ArithmeticError::CurveNoConvergence)?;
+
+        Ok(price * &self.rates[i] / &self.rates[j])
+    }
+
+    fn sync_from_action(&mut self, action: Action) -> Result<(), AmmError> {
+        match action {
+            Action::Swap(swap) => {
+                self.add_balance(swap.token_in.address, &swap.amount_in);
+                self.sub_balance(swap.token_out.address, &swap.amount_out);
+            }
+            Action::SwapWithFee(swap) => {
+                self.add_balance(swap.token_in.address, &swap.amount_in);
+                self.sub_balance(swap.token_out.address, &swap.amount_out);
+            }
+            Action::Mint(mint) => {
+                for (token, amount) in mint.token.iter().zip(&mint.amount) {
+                    self.add_balance(token.address, amount);
+                }
+            }
+            Action::Burn(burn) => {
+                for (token, amount) in burn.token.iter().zip(&burn.amount) {
+                    self.sub_balance(token.address, amount);
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// Curve's events differ between pool versions, its pools are synced from
+    /// the classified actions instead
+    fn sync_from_log(&mut self, _log: Log) -> Result<(), AmmError> {
+        Err(AmmError::EventLogError(EventLogError::InvalidEventSignature))
+    }
+}
+
+impl CurvePool {
+    pub async fn new_load_on_block<M: TracingProvider>(
+        address: Address,
+        protocol: Protocol,
+        middleware: Arc<M>,
+        block: u64,
+    ) -> Result<Self, AmmError> {
+        let block = Some(block);
+
+        let mut tokens = vec![];
+        let mut raw_balances = vec![];
+        while tokens.len() < MAX_COINS {
+            let Some((token, balance)) =
+                coin_and_balance(address, tokens.len(), &middleware, block).await
+            else {
+                break
+            };
+            tokens.push(token);
+            raw_balances.push(balance);
+        }
+
+        if tokens.len() < 2 {
+            return Err(AmmError::NoStateError(address))
+        }
+
+        let mut decimals = Vec::with_capacity(tokens.len());
+        for token in &tokens {
+            decimals.push(token_decimals(*token, &middleware, block).await?);
+        }
+
+        let balances = raw_balances
+            .into_iter()
+            .zip(&decimals)
+            .map(|(balance, decimals)| balance.to_scaled_rational(*decimals))
+            .collect();
+
+        let amp = make_call_request(ICurvePool::ACall::new(()), &middleware, address, block)
+            .await?
+            ._0
+            .saturating_to::<u64>() as f64;
+
+        let (invariant, rates) = if is_crypto_pool(protocol) {
+            let gamma = make_call_request(
+                ICurveCryptoPool::gammaCall::new(()),
+                &middleware,
+                address,
+                block,
+            )
+            .await?
+            ._0
+            .to_scaled_rational(18)
+            .to_float();
+
+            (
+                CurveInvariant::CryptoSwap { ann: amp / A_MULTIPLIER, gamma },
+                price_scales(address, protocol, tokens.len(), &middleware, block).await?,
+            )
+        } else {
+            (
+                CurveInvariant::StableSwap { amp },
+                stable_rates(address, protocol, &decimals, &middleware, block).await,
+            )
+        };
+
+        Ok(Self { address, protocol, tokens, decimals, balances, rates, invariant })
+    }
+
+    pub fn get_tvl(&self, base: Address) -> (Rational, Rational) {
+        let i = self.coin_index(base).unwrap_or_default();
+        let j = usize::from(i == 0);
+
+        (self.balances[i].clone(), self.balances[j].clone())
+    }
+
+    /// The pool's index of `token`, weth standing in for eth in pools holding
+    /// native eth
+    fn coin_index(&self, token: Address) -> Option<usize> {
+        self.tokens.iter().position(|coin| {
+            *coin == token
+                || (*coin == ETH_ADDRESS && token == WETH_ADDRESS)
+                || (*coin == WETH_ADDRESS && token == ETH_ADDRESS)
+        })
+    }
+
+    fn add_balance(&mut self, token: Address, amount: &Rational) {
+        if let Some(i) = self.coin_index(token) {
+            self.balances[i] += amount;
+        }
+    }
+
+    fn sub_balance(&mut self, token: Address, amount: &Rational) {
+        if let Some(i) = self.coin_index(token) {
+            self.balances[i] -= amount;
+            if self.balances[i] < Rational::ZERO {
+                self.balances[i] = Rational::ZERO;
+            }
+        }
+    }
+}
+
+fn is_crypto_pool(protocol: Protocol) -> bool {
+    matches!(protocol, Protocol::CurveCryptoSwapPool | Protocol::CurveTriCryptoPool)
+}
+
+fn is_meta_pool(protocol: Protocol) -> bool {
+    matches!(
+        protocol,
+        Protocol::CurveV1MetaPool | Protocol::CurveV2MetaPool | Protocol::CurvecrvUSDMetaPool
+    )
+}
+
+/// The coin at index `i` and its balance, `None` once past the pool's last coin
+async fn coin_and_balance<M: TracingProvider>(
+    pool: Address,
+    i: usize,
+    middleware: &Arc<M>,
+    block: Option<u64>,
+) -> Option<(Address, U256)> {
+    if let Ok(coin) =
+        make_call_request(ICurvePool::coinsCall::new((U256::from(i),)), middleware, pool, block)
+            .await
+    {
+        let balance = make_call_request(
+            ICurvePool::balancesCall::new((U256::from(i),)),
+            middleware,
+            pool,
+            block,
+        )
+        .await
+        .ok()?;
+
+        return Some((coin._0, balance._0))
+    }
+
+    let coin =
+        make_call_request(ICurveLegacyPool::coinsCall::new((i as i128,)), middleware, pool, block)
+            .await
+            .ok()?;
+    let balance = make_call_request(
+        ICurveLegacyPool::balancesCall::new((i as i128,)),
+        middleware,
+        pool,
+        block,
+    )
+    .await
+    .ok()?;
+
+    Some((coin._0, balance._0))
+}
+
+async fn token_decimals<M: TracingProvider>(
+    token: Address,
+    middleware: &Arc<M>,
+    block: Option<u64>,
+) -> Result<u8, AmmError> {
+    if token == ETH_ADDRESS {
+        return Ok(18)
+    }
+
+    Ok(make_call_request(IErc20::decimalsCall::new(()), middleware, token, block)
+        .await?
+        ._0)
+}
+
+/// The rates of a stableswap pool's coins. Newer pools store them, with
+/// `10^(36 - decimals)` as a rate of one, a metapool's lp coin is worth the
+/// virtual price of its base pool and everything else is one
+async fn stable_rates<M: TracingProvider>(
+    pool: Address,
+    protocol: Protocol,
+    decimals: &[u8],
+    middleware: &Arc<M>,
+    block: Option<u64>,
+) -> Vec<Rational> {
+    let ones = vec![Rational::ONE; decimals.len()];
+
+    if let Ok(stored) =
+        make_call_request(ICurvePool::stored_ratesCall::new(()), middleware, pool, block).await
+    {
+        if stored._0.len() == decimals.len() {
+            return stored
+                ._0
+                .into_iter()
+                .zip(decimals)
+                .map(|(rate, decimals)| rate.to_scaled_rational(36u8.saturating_sub(*decimals)))
+                .collect()
+        }
+    }
+
+    if !is_meta_pool(protocol) {
+        return ones
+    }
+
+    let Ok(base_pool) =
+        make_call_request(ICurvePool::base_poolCall::new(()), middleware, pool, block).await
+    else {
+        return ones
+    };
+    let Ok(virtual_price) = make_call_request(
+        ICurvePool::get_virtual_priceCall::new(()),
+        middleware,
+        base_pool._0,
+        block,
+    )
+    .await
+    else {
+        return ones
+    };
+
+    let mut rates = ones;
+    rates[1] = virtual_price._0.to_scaled_rational(18);
+    rates
+}
+
+/// A cryptoswap pool's price scale, the price of each coin in the first one
+async fn price_scales<M: TracingProvider>(
+    pool: Address,
+    protocol: Protocol,
+    coins: usize,
+    middleware: &Arc<M>,
+    block: Option<u64>,
+) -> Result<Vec<Rational>, AmmError> {
+    let mut scales = vec![Rational::ONE];
+
+    if protocol == Protocol::CurveTriCryptoPool {
+        for k in 0..coins - 1 {
+            let scale = make_call_request(
+                ICurveTriCryptoPool::price_scaleCall::new((U256::from(k),)),
+                middleware,
+                pool,
+                block,
+            )
+            .await?;
+            scales.push(scale._0.to_scaled_rational(18));
+        }
+    } else {
+        let scale =
+            make_call_request(ICurveCryptoPool::price_scaleCall::new(()), middleware, pool, block)
+                .await?;
+        scales.push(scale._0.to_scaled_rational(18));
+    }
+
+    Ok(scales)
+}
+
+#[cfg(test)]
+mod tests {
+    use brontes_types::{db::token_info::TokenInfoWithAddress, normalized_actions::NormalizedSwap};
+
+    use super::*;
+
+    const USDC: Address = Address::repeat_byte(1);
+    const USDT: Address = Address::repeat_byte(2);
+
+    fn pool(balances: [u64; 2]) -> CurvePool {
+        CurvePool {
+            address:   Address::ZERO,
+            protocol:  Protocol::CurveBasePool2,
+            tokens:    vec![USDC, USDT],
+            decimals:  vec![6, 6],
+            balances:  balances.into_iter().map(Rational::from).collect(),
+            rates:     vec![Rational::ONE; 2],
+            invariant: CurveInvariant::StableSwap { amp: 100.0 },
+        }
+    }
+
+    #[test]
+    fn test_calculate_price() {
+        let balanced = pool([1_000_000, 1_000_000]);
+        assert_eq!(balanced.calculate_price(USDC).unwrap(), Rational::ONE);
+
+        // the coin the pool holds less of is worth more
+        let imbalanced = pool([500_000, 1_500_000]);
+        assert!(imbalanced.calculate_price(USDC).unwrap() > Rational::ONE);
+        assert!(imbalanced.calculate_price(USDT).unwrap() < Rational::ONE);
+
+        assert!(balanced.calculate_price(Address::ZERO).is_err());
+    }
+
+    #[test]
+    fn test_sync_from_swap() {
+        let mut pool = pool([1_000_000, 1_000_000]);
+        let swap = NormalizedSwap {
+            token_in: TokenInfoWithAddress { address: USDC, ..Default::default() },
+            token_out: TokenInfoWithAddress { address: USDT, ..Default::default() },
+            amount_in: Rational::from(1_000),
+            amount_out: Rational::from(999),
+            ..Default::default()
+        };
+
+        pool.sync_from_action(Action::Swap(swap)).unwrap();
+        assert_eq!(pool.balances, vec![Rational::from(1_001_000), Rational::from(999_001)]);
+    }
+}
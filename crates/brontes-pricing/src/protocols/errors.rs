@@ -73,6 +73,10 @@ pub enum ArithmeticError {
     UniswapV3MathError(#[from] UniswapV3MathError),
     #[error("v2 div by zero")]
     UniV2DivZero,
+    #[error("token is not one of the pool's coins")]
+    TokenNotInPool,
+    #[error("curve invariant did not converge")]
+    CurveNoConvergence,
 }
 
 #[derive(Error, Debug)]
@@ -1,5 +1,8 @@
+pub mod balancer;
+pub mod curve;
 pub mod errors;
 pub mod lazy;
+pub mod quoted;
 pub mod uniswap_v2;
 pub mod uniswap_v3;
 
@@ -13,8 +16,11 @@ use malachite::Rational;
 use tracing::{debug, warn};
 
 use crate::{
+    balancer::BalancerPool,
+    curve::CurvePool,
     lazy::{PoolFetchError, PoolFetchSuccess},
     protocols::errors::{AmmError, ArithmeticError},
+    quoted::QuotedPool,
     types::PairWithFirstPoolHop,
     uniswap_v2::UniswapV2Pool,
     uniswap_v3::UniswapV3Pool,
@@ -52,6 +58,20 @@ impl LoadState for Protocol {
                 | Self::SushiSwapV3
                 | Self::PancakeSwapV2
                 | Self::PancakeSwapV3
+                | Self::CurveBasePool2
+                | Self::CurveBasePool3
+                | Self::CurveBasePool4
+                | Self::CurveV1MetaPool
+                | Self::CurveV2MetaPool
+                | Self::CurveV2PlainPool
+                | Self::CurvecrvUSDMetaPool
+                | Self::CurvecrvUSDPlainPool
+                | Self::CurveCryptoSwapPool
+                | Self::CurveTriCryptoPool
+                | Self::BalancerV2
+                | Self::Fraxswap
+                | Self::SolidlyV1
+                | Self::SolidlyV2
         )
     }
 
@@ -93,18 +113,84 @@ impl LoadState for Protocol {
                 ))
             }
             Self::UniswapV3 | Self::SushiSwapV3 | Self::PancakeSwapV3 => {
+                let (pool, res) = if let Ok(pool) = UniswapV3Pool::new_from_address(
+                    address,
+                    self,
+                    block_number - 1,
+                    provider.clone(),
+                )
+                .await
+                {
+                    (pool, LoadResult::Ok)
+                } else {
+                    (
+                        UniswapV3Pool::new_from_address(address, self, block_number, provider)
+                            .await
+                            .map_err(|e| {
+                                debug!(?pool_pair, protocol=%self, %block_number, pool_address=?address, err=%e, "lazy load failed");
+                                (address, self, block_number, pool_pair, fp, e)
+                            })?,
+                        LoadResult::PoolInitOnBlock,
+                    )
+                };
+
+                Ok((
+                    block_number,
+                    address,
+                    PoolState::new(
+                        crate::types::PoolVariants::UniswapV3(Box::new(pool)),
+                        block_number,
+                    ),
+                    res,
+                ))
+            }
+            Self::CurveBasePool2
+            | Self::CurveBasePool3
+            | Self::CurveBasePool4
+            | Self::CurveV1MetaPool
+            | Self::CurveV2MetaPool
+            | Self::CurveV2PlainPool
+            | Self::CurvecrvUSDMetaPool
+            | Self::CurvecrvUSDPlainPool
+            | Self::CurveCryptoSwapPool
+            | Self::CurveTriCryptoPool => {
                 let (pool, res) = if let Ok(pool) =
-                    UniswapV3Pool::new_from_address(address, block_number - 1, provider.clone())
+                    CurvePool::new_load_on_block(address, self, provider.clone(), block_number - 1)
                         .await
                 {
                     (pool, LoadResult::Ok)
                 } else {
                     (
-                        UniswapV3Pool::new_from_address(address, block_number, provider)
+                        CurvePool::new_load_on_block(address, self, provider, block_number)
                             .await
                             .map_err(|e| {
                                 debug!(?pool_pair, protocol=%self, %block_number, pool_address=?address, err=%e, "lazy load failed");
-                                (address, Protocol::UniswapV3, block_number, pool_pair, fp, e)
+                                (address, self, block_number, pool_pair, fp, e)
+                            })?,
+                        LoadResult::PoolInitOnBlock,
+                    )
+                };
+
+                Ok((
+                    block_number,
+                    address,
+                    PoolState::new(crate::types::PoolVariants::Curve(Box::new(pool)), block_number),
+                    res,
+                ))
+            }
+            Self::BalancerV2 => {
+                let (pool, res) = if let Ok(pool) =
+                    BalancerPool::new_load_on_block(address, provider.clone(), block_number - 1)
+                        .await
+                {
+                    (pool, LoadResult::Ok)
+                } else {
+                    (
+                        BalancerPool::new_load_on_block(address, provider, block_number)
+                            .await
+                            .map_err(|e| {
+                                debug!(?pool_pair, protocol=%self, %block_number, pool_address=?address, err=%e, "lazy load failed");
+                                (address, self, block_number, pool_pair, fp, e)
                             })?,
                         LoadResult::PoolInitOnBlock,
                     )
@@ -114,7 +200,47 @@ impl LoadState for Protocol {
                     block_number,
                     address,
                     PoolState::new(
-                        crate::types::PoolVariants::UniswapV3(Box::new(pool)),
+                        crate::types::PoolVariants::Balancer(Box::new(pool)),
+                        block_number,
+                    ),
+                    res,
+                ))
+            }
+            // no math for these, their pairs quote themselves
+            Self::Fraxswap | Self::SolidlyV1 | Self::SolidlyV2 => {
+                let (pool, res) = if let Ok(pool) = QuotedPool::new_load_on_block(
+                    address,
+                    self,
+                    pool_pair,
+                    provider.clone(),
+                    block_number - 1,
+                )
+                .await
+                {
+                    (pool, LoadResult::Ok)
+                } else {
+                    (
+                        QuotedPool::new_load_on_block(
+                            address,
+                            self,
+                            pool_pair,
+                            provider,
+                            block_number,
+                        )
+                        .await
+                        .map_err(|e| {
+                            debug!(?pool_pair, protocol=%self, %block_number, pool_address=?address, err=%e, "lazy load failed");
+                            (address, self, block_number, pool_pair, fp, e)
+                        })?,
+                        LoadResult::PoolInitOnBlock,
+                    )
+                };
+
+                Ok((
+                    block_number,
+                    address,
+                    PoolState::new(
+                        crate::types::PoolVariants::Quoted(Box::new(pool)),
                         block_number,
                     ),
                     res,
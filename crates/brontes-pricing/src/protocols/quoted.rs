@@ -0,0 +1,261 @@
+//! Prices for pools whose math isn't implemented here, quoted by having the
+//! pool simulate a small swap each way through `eth_call`. The quote includes
+//! the pool's fee, which is small next to the prices the graph works with.
+use std::sync::Arc;
+
+use alloy_primitives::{Address, Log, U256};
+use alloy_sol_macro::sol;
+use async_trait::async_trait;
+use brontes_types::{
+    normalized_actions::Action, pair::Pair, traits::TracingProvider, ToScaledRational,
+};
+use malachite::{num::basic::traits::Zero, Rational};
+
+use super::make_call_request;
+use crate::{
+    errors::{AmmError, ArithmeticError, EventLogError},
+    uniswap_v2::IErc20,
+    Protocol, UpdatableProtocol,
+};
+
+sol!(
+    interface IQuotingPair {
+        function getAmountOut(uint256 amountIn, address tokenIn) external view returns (uint256);
+    }
+);
+
+/// The share of its balance of the token in that a quote swaps
+const QUOTE_SHARE: u64 = 10_000;
+
+/// A pool priced from quotes taken when it was loaded. Its swaps, mints and
+/// burns then move the price by how much they change the ratio of its
+/// balances, which is exact for constant product pools and overstates the
+/// move of flatter curves.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuotedPool {
+    pub address:          Address,
+    pub protocol:         Protocol,
+    pub token_a:          Address,
+    pub token_a_decimals: u8,
+    pub token_b:          Address,
+    pub token_b_decimals: u8,
+    pub balance_a:        Rational,
+    pub balance_b:        Rational,
+    /// how much of token b one of token a is worth
+    pub price_a:          Rational,
+    /// how much of token a one of token b is worth
+    pub price_b:          Rational,
+}
+
+#[async_trait]
+impl UpdatableProtocol for QuotedPool {
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    fn tokens(&self) -> Vec<Address> {
+        vec![self.token_a, self.token_b]
+    }
+
+    fn calculate_price(&self, base_token: Address) -> Result<Rational, ArithmeticError> {
+        if base_token == self.token_a {
+            Ok(self.price_a.clone())
+        } else if base_token == self.token_b {
+            Ok(self.price_b.clone())
+        } else {
+            Err(ArithmeticError::TokenNotInPool)
+        }
+    }
+
+    fn sync_from_action(&mut self, action: Action) -> Result<(), AmmError> {
+        let (old_a, old_b) = (self.balance_a.clone(), self.balance_b.clone());
+
+        match action {
+            Action::Swap(swap) => {
+                self.add_balance(swap.token_in.address, &swap.amount_in);
+                self.sub_balance(swap.token_out.address, &swap.amount_out);
+            }
+            Action::SwapWithFee(swap) => {
+                self.add_balance(swap.token_in.address, &swap.amount_in);
+                self.sub_balance(swap.token_out.address, &swap.amount_out);
+            }
+            Action::Mint(mint) => {
+                for (token, amount) in mint.token.iter().zip(&mint.amount) {
+                    self.add_balance(token.address, amount);
+                }
+            }
+            Action::Burn(burn) => {
+                for (token, amount) in burn.token.iter().zip(&burn.amount) {
+                    self.sub_balance(token.address, amount);
+                }
+            }
+            _ => return Ok(()),
+        }
+
+        if [&old_a, &old_b, &self.balance_a, &self.balance_b]
+            .into_iter()
+            .any(|balance| *balance == Rational::ZERO)
+        {
+            return Ok(())
+        }
+
+        let moved = (&self.balance_b / &self.balance_a) / (old_b / old_a);
+        self.price_a *= &moved;
+        self.price_b /= moved;
+
+        Ok(())
+    }
+
+    fn sync_from_log(&mut self, _log: Log) -> Result<(), AmmError> {
+        Err(AmmError::EventLogError(EventLogError::InvalidEventSignature))
+    }
+}
+
+impl QuotedPool {
+    pub async fn new_load_on_block<M: TracingProvider>(
+        address: Address,
+        protocol: Protocol,
+        pair: Pair,
+        middleware: Arc<M>,
+        block: u64,
+    ) -> Result<Self, AmmError> {
+        let block = Some(block);
+        let Pair(token_a, token_b) = pair;
+
+        let mut decimals = [0u8; 2];
+        let mut balances = [U256::ZERO; 2];
+        for (i, token) in [token_a, token_b].into_iter().enumerate() {
+            decimals[i] =
+                make_call_request(IErc20::decimalsCall::new(()), &middleware, token, block)
+                    .await?
+                    ._0;
+            balances[i] = make_call_request(
+                IErc20::balanceOfCall::new((address,)),
+                &middleware,
+                token,
+                block,
+            )
+            .await?
+            ._0;
+        }
+
+        let price_a = quote(address, token_a, balances[0], decimals, &middleware, block).await?;
+        let price_b =
+            quote(address, token_b, balances[1], [decimals[1], decimals[0]], &middleware, block)
+                .await?;
+
+        Ok(Self {
+            address,
+            protocol,
+            token_a,
+            token_a_decimals: decimals[0],
+            token_b,
+            token_b_decimals: decimals[1],
+            balance_a: balances[0].to_scaled_rational(decimals[0]),
+            balance_b: balances[1].to_scaled_rational(decimals[1]),
+            price_a,
+            price_b,
+        })
+    }
+
+    pub fn get_tvl(&self, base: Address) -> (Rational, Rational) {
+        if base == self.token_a {
+            (self.balance_a.clone(), self.balance_b.clone())
+        } else {
+            (self.balance_b.clone(), self.balance_a.clone())
+        }
+    }
+
+    fn add_balance(&mut self, token: Address, amount: &Rational) {
+        if token == self.token_a {
+            self.balance_a += amount;
+        } else if token == self.token_b {
+            self.balance_b += amount;
+        }
+    }
+
+    fn sub_balance(&mut self, token: Address, amount: &Rational) {
+        let balance = if token == self.token_a {
+            &mut self.balance_a
+        } else if token == self.token_b {
+            &mut self.balance_b
+        } else {
+            return
+        };
+
+        *balance -= amount;
+        if *balance < Rational::ZERO {
+            *balance = Rational::ZERO;
+        }
+    }
+}
+
+/// What one of `token_in` gets out of the pool, from a swap of a small share
+/// of the pool's balance of it. `decimals` are those of the token in and out
+async fn quote<M: TracingProvider>(
+    pool: Address,
+    token_in: Address,
+    balance_in: U256,
+    decimals: [u8; 2],
+    middleware: &Arc<M>,
+    block: Option<u64>,
+) -> Result<Rational, AmmError> {
+    let amount_in = balance_in / U256::from(QUOTE_SHARE);
+    if amount_in.is_zero() {
+        return Err(AmmError::NoStateError(pool))
+    }
+
+    let amount_out = make_call_request(
+        IQuotingPair::getAmountOutCall::new((amount_in, token_in)),
+        middleware,
+        pool,
+        block,
+    )
+    .await?
+    ._0;
+    if amount_out.is_zero() {
+        return Err(AmmError::NoStateError(pool))
+    }
+
+    Ok(amount_out.to_scaled_rational(decimals[1]) / amount_in.to_scaled_rational(decimals[0]))
+}
+
+#[cfg(test)]
+mod tests {
+    use brontes_types::{db::token_info::TokenInfoWithAddress, normalized_actions::NormalizedSwap};
+
+    use super::*;
+
+    const TOKEN_A: Address = Address::repeat_byte(1);
+    const TOKEN_B: Address = Address::repeat_byte(2);
+
+    #[test]
+    fn test_sync_from_swap() {
+        let mut pool = QuotedPool {
+            address:          Address::ZERO,
+            protocol:         Protocol::SolidlyV1,
+            token_a:          TOKEN_A,
+            token_a_decimals: 18,
+            token_b:          TOKEN_B,
+            token_b_decimals: 18,
+            balance_a:        Rational::from(1_000),
+            balance_b:        Rational::from(2_000),
+            price_a:          Rational::from(2),
+            price_b:          Rational::from_signeds(1, 2),
+        };
+
+        // the swap flips the pool's balances, and with them its price
+        let swap = NormalizedSwap {
+            token_in: TokenInfoWithAddress { address: TOKEN_A, ..Default::default() },
+            token_out: TokenInfoWithAddress { address: TOKEN_B, ..Default::default() },
+            amount_in: Rational::from(1_000),
+            amount_out: Rational::from(1_000),
+            ..Default::default()
+        };
+        pool.sync_from_action(Action::Swap(swap)).unwrap();
+
+        assert_eq!(pool.calculate_price(TOKEN_A).unwrap(), Rational::from_signeds(1, 2));
+        assert_eq!(pool.calculate_price(TOKEN_B).unwrap(), Rational::from(2));
+        assert!(pool.calculate_price(Address::ZERO).is_err());
+    }
+}
@@ -6,12 +6,12 @@ use std::{
 use alloy_primitives::{hex, Bytes, FixedBytes, U256};
 use alloy_sol_macro::sol;
 use alloy_sol_types::SolCall;
-use brontes_types::traits::TracingProvider;
+use brontes_types::{queries::make_call_request, traits::TracingProvider, Protocol};
 use reth_primitives::{Address, Bytecode, StorageValue};
 use reth_rpc_types::{request::TransactionInput, TransactionRequest};
 
 mod test_bytecodes;
-use super::UniswapV3Pool;
+use super::{IUniswapV3Pool, UniswapV3Pool};
 use crate::errors::AmmError;
 sol!(
     IGetUniswapV3TickDataBatchRequest,
@@ -63,6 +63,11 @@ const TOKEN1_RANGE: std::ops::Range<usize> = 9128..9128 + 40;
 const FEE_RANGE: std::ops::Range<usize> = 6682..6682 + 6;
 const TICK_SPACING_RANGE: std::ops::Range<usize> = 6146..6146 + 64;
 
+// PancakeSwap V3 widens `feeProtocol` to a uint32 which pushes slot0 over two
+// storage slots, shifting liquidity down by one
+const UNI_V3_LIQUIDITY_SLOT: u8 = 4;
+const PANCAKE_V3_LIQUIDITY_SLOT: u8 = 5;
+
 //TODO: Good first issue for someone to prune the unnecessary data we are
 // loading for the pools TODO: We don't need ticks or fees, we should already
 // have token 0 & token 1 TODO: We also don't need bytecode or tick spacing
@@ -91,14 +96,44 @@ pub fn extract_uni_v3_immutables(bytecode: Bytes) -> eyre::Result<(Address, Addr
     Ok((token0, token1, fee, tick_spacing))
 }
 
+/// Queries the pool immutables through their getters. Used for forks whose
+/// bytecode layout differs from the canonical Uniswap V3 pool.
+async fn query_v3_immutables<M: TracingProvider>(
+    pool: Address,
+    block_number: Option<u64>,
+    middleware: &Arc<M>,
+) -> Result<(Address, Address, u32, i32), AmmError> {
+    let token0 =
+        make_call_request(IUniswapV3Pool::token0Call::new(()), middleware, pool, block_number)
+            .await?
+            ._0;
+    let token1 =
+        make_call_request(IUniswapV3Pool::token1Call::new(()), middleware, pool, block_number)
+            .await?
+            ._0;
+    let fee = make_call_request(IUniswapV3Pool::feeCall::new(()), middleware, pool, block_number)
+        .await?
+        ._0;
+    let tick_spacing =
+        make_call_request(IUniswapV3Pool::tickSpacingCall::new(()), middleware, pool, block_number)
+            .await?
+            ._0;
+
+    Ok((token0, token1, fee, tick_spacing))
+}
+
 pub async fn get_v3_pool_data_batch_request<M: TracingProvider>(
     pool: &mut UniswapV3Pool,
+    protocol: Protocol,
     block_number: Option<u64>,
     middleware: Arc<M>,
 ) -> Result<(), AmmError> {
     // Pool Storage Slots
     let slot0_slot: FixedBytes<32> = FixedBytes::new([0u8; 32]);
-    let liquidity_slot: FixedBytes<32> = FixedBytes::with_last_byte(4);
+    let liquidity_slot: FixedBytes<32> = match protocol {
+        Protocol::PancakeSwapV3 => FixedBytes::with_last_byte(PANCAKE_V3_LIQUIDITY_SLOT),
+        _ => FixedBytes::with_last_byte(UNI_V3_LIQUIDITY_SLOT),
+    };
 
     // Fetch from db
     let slot0: Option<StorageValue> = middleware
@@ -136,8 +171,16 @@ pub async fn get_v3_pool_data_batch_request<M: TracingProvider>(
                 pool.address
             )))
         }
-        let pool_bytecode = Bytes::from(hex::encode_prefixed(pool_bytecode.bytecode.as_ref()));
-        let (token0, token1, fee, tick_spacing) = extract_uni_v3_immutables(pool_bytecode)?;
+        let (token0, token1, fee, tick_spacing) = match protocol {
+            Protocol::PancakeSwapV3 => {
+                query_v3_immutables(pool.address, block_number, &middleware).await?
+            }
+            _ => {
+                let pool_bytecode =
+                    Bytes::from(hex::encode_prefixed(pool_bytecode.bytecode.as_ref()));
+                extract_uni_v3_immutables(pool_bytecode)?
+            }
+        };
         pool.fee = fee;
         pool.tick_spacing = tick_spacing;
         pool.token_a = token0;
@@ -1,15 +1,15 @@
 pub mod batch_request;
 pub mod uniswap_v3_math;
-use std::{cmp::Ordering, sync::Arc};
+use std::sync::Arc;
 
 use alloy_primitives::{Address, FixedBytes, Log, B256, U256};
 use alloy_sol_macro::sol;
 use alloy_sol_types::{SolCall, SolEvent};
 use async_trait::async_trait;
 use brontes_types::{
-    normalized_actions::Action, traits::TracingProvider, FastHashMap, ToScaledRational,
+    normalized_actions::Action, traits::TracingProvider, FastHashMap, Protocol, ToScaledRational,
 };
-use malachite::Rational;
+use malachite::{num::arithmetic::traits::Pow, Rational};
 use serde::{Deserialize, Serialize};
 
 use self::batch_request::get_v3_pool_data_batch_request;
@@ -103,6 +103,7 @@ pub const MAX_SQRT_RATIO: U256 =
 pub const POPULATE_TICK_DATA_STEP: u64 = 100000;
 
 pub const U256_TWO: U256 = U256::from_limbs([2, 0, 0, 0]);
+pub const Q96: U256 = U256::from_limbs([0, 4294967296, 0, 0]);
 pub const Q128: U256 = U256::from_limbs([0, 0, 1, 0]);
 pub const Q224: U256 = U256::from_limbs([0, 0, 0, 4294967296]);
 
@@ -187,24 +188,12 @@ impl UpdatableProtocol for UniswapV3Pool {
     }
 
     fn calculate_price(&self, base_token: Address) -> Result<Rational, ArithmeticError> {
-        if self.liquidity <= 10_000 {
-            return Err(ArithmeticError::UniswapV3MathError(
-                uniswap_v3_math::error::UniswapV3MathError::LiquidityTooLow(self.liquidity),
-            ))
-        }
-
-        let tick = uniswap_v3_math::tick_math::get_tick_at_sqrt_ratio(self.sqrt_price)?;
-        let shift = self.token_a_decimals as i8 - self.token_b_decimals as i8;
-        let price = match shift.cmp(&0) {
-            Ordering::Less => 1.0001_f64.powi(tick) / 10_f64.powi(-shift as i32),
-            Ordering::Greater => 1.0001_f64.powi(tick) * 10_f64.powi(shift as i32),
-            Ordering::Equal => 1.0001_f64.powi(tick),
-        };
+        let (zto, otz) = self.spot_prices()?;
 
         if base_token == self.token_a {
-            Ok(Rational::try_from(price).unwrap())
+            Ok(zto)
         } else {
-            Ok(Rational::try_from(1.0 / price).unwrap())
+            Ok(otz)
         }
     }
 }
@@ -212,15 +201,18 @@ impl UpdatableProtocol for UniswapV3Pool {
 impl UniswapV3Pool {
     async fn populate_data<M: TracingProvider>(
         &mut self,
+        protocol: Protocol,
         block: Option<u64>,
         middleware: Arc<M>,
     ) -> Result<(), AmmError> {
-        get_v3_pool_data_batch_request(self, block, middleware).await
+        get_v3_pool_data_batch_request(self, protocol, block, middleware).await
     }
 
-    // Creates a new instance of the pool from the pair address
+    // Creates a new instance of the pool from the pair address. `protocol` selects
+    // the storage layout of the fork being loaded
     pub async fn new_from_address<M: 'static + TracingProvider>(
         pair_address: Address,
+        protocol: Protocol,
         block_number: u64,
         middleware: Arc<M>,
     ) -> Result<Self, AmmError> {
@@ -246,7 +238,8 @@ impl UniswapV3Pool {
         pool.sync_ticks_around_current(block_number, 100, middleware.clone())
             .await;
 
-        pool.populate_data(Some(block_number), middleware).await?;
+        pool.populate_data(protocol, Some(block_number), middleware)
+            .await?;
 
         if !pool.data_is_populated() {
             return Err(AmmError::NoStateError(pair_address))
@@ -302,6 +295,31 @@ impl UniswapV3Pool {
         self.fee
     }
 
+    /// The pool's spot price from `sqrtPriceX96`, as the amount of token1 one
+    /// token0 is worth (zto) and the amount of token0 one token1 is worth
+    /// (otz), both adjusted for the tokens' decimals. Unlike a price derived
+    /// from the tick, which is rounded down to the tick, this is exact
+    pub fn spot_prices(&self) -> Result<(Rational, Rational), ArithmeticError> {
+        if self.liquidity <= 10_000 {
+            return Err(ArithmeticError::UniswapV3MathError(
+                uniswap_v3_math::error::UniswapV3MathError::LiquidityTooLow(self.liquidity),
+            ))
+        }
+        if self.sqrt_price.is_zero() {
+            return Err(ArithmeticError::UniswapV3MathError(
+                uniswap_v3_math::error::UniswapV3MathError::SqrtPriceIsZero,
+            ))
+        }
+
+        let sqrt_price = self.sqrt_price.to_scaled_rational(0) / Q96.to_scaled_rational(0);
+        let zto =
+            &sqrt_price * &sqrt_price * Rational::from(10u64).pow(self.token_a_decimals as u64)
+                / Rational::from(10u64).pow(self.token_b_decimals as u64);
+        let otz = Rational::from(1u64) / &zto;
+
+        Ok((zto, otz))
+    }
+
     pub fn data_is_populated(&self) -> bool {
         !(self.token_a.is_zero() || self.token_b.is_zero())
             || !(self.sqrt_price >= MIN_SQRT_RATIO && self.sqrt_price < MAX_SQRT_RATIO)
@@ -514,3 +532,35 @@ pub struct Tick {
     pub seconds_outside: u32,
     pub initialized: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pool(sqrt_price: U256, token_a_decimals: u8, token_b_decimals: u8) -> UniswapV3Pool {
+        UniswapV3Pool {
+            token_a_decimals,
+            token_b_decimals,
+            sqrt_price,
+            liquidity: u128::MAX,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_spot_prices() {
+        let (zto, otz) = pool(Q96, 18, 18).spot_prices().unwrap();
+        assert_eq!(zto, Rational::from(1u64));
+        assert_eq!(otz, Rational::from(1u64));
+
+        // a usdc / weth pool with eth at 2500 usdc: 1e18 / 2.5e9 = 4e8 raw
+        // weth per raw usdc, whose square root is 20_000
+        let (zto, otz) = pool(Q96 * U256::from(20_000), 6, 18).spot_prices().unwrap();
+        assert_eq!(zto, Rational::from_unsigneds(4u64, 10_000));
+        assert_eq!(otz, Rational::from(2_500u64));
+
+        let mut empty = pool(Q96, 18, 18);
+        empty.liquidity = 0;
+        assert!(empty.spot_prices().is_err());
+    }
+}
@@ -9,8 +9,8 @@ use brontes_types::{
 use malachite::Rational;
 
 use crate::{
-    errors::ArithmeticError, uniswap_v2::UniswapV2Pool, uniswap_v3::UniswapV3Pool, LoadState,
-    Protocol, UpdatableProtocol,
+    balancer::BalancerPool, curve::CurvePool, errors::ArithmeticError, quoted::QuotedPool,
+    uniswap_v2::UniswapV2Pool, uniswap_v3::UniswapV3Pool, LoadState, Protocol, UpdatableProtocol,
 };
 
 wrap_fixed_bytes!(extra_derives:[],
@@ -92,6 +92,9 @@ impl PoolState {
         match &self.variant {
             PoolVariants::UniswapV2(v) => Pair(v.token_a, v.token_b),
             PoolVariants::UniswapV3(v) => Pair(v.token_a, v.token_b),
+            PoolVariants::Curve(v) => Pair(v.tokens[0], v.tokens[1]),
+            PoolVariants::Balancer(v) => Pair(v.tokens[0], v.tokens[1]),
+            PoolVariants::Quoted(v) => Pair(v.token_a, v.token_b),
         }
     }
 
@@ -99,6 +102,9 @@ impl PoolState {
         match &self.variant {
             PoolVariants::UniswapV2(_) => Protocol::UniswapV2,
             PoolVariants::UniswapV3(_) => Protocol::UniswapV3,
+            PoolVariants::Curve(v) => v.protocol,
+            PoolVariants::Balancer(_) => Protocol::BalancerV2,
+            PoolVariants::Quoted(v) => v.protocol,
         }
     }
 
@@ -108,13 +114,16 @@ impl PoolState {
             return
         }
         self.last_update = state.block;
-        self.variant.increment_state(state.logs);
+        self.variant.increment_state(state);
     }
 
     pub fn address(&self) -> Address {
         match &self.variant {
             PoolVariants::UniswapV2(v) => v.address(),
             PoolVariants::UniswapV3(v) => v.address(),
+            PoolVariants::Curve(v) => v.address(),
+            PoolVariants::Balancer(v) => v.address(),
+            PoolVariants::Quoted(v) => v.address(),
         }
     }
 
@@ -122,6 +131,9 @@ impl PoolState {
         match &self.variant {
             PoolVariants::UniswapV2(v) => v.get_tvl(base),
             PoolVariants::UniswapV3(v) => v.get_tvl(base),
+            PoolVariants::Curve(v) => v.get_tvl(base),
+            PoolVariants::Balancer(v) => v.get_tvl(base),
+            PoolVariants::Quoted(v) => v.get_tvl(base),
         }
     }
 
@@ -129,6 +141,9 @@ impl PoolState {
         match &self.variant {
             PoolVariants::UniswapV2(v) => v.calculate_price(base),
             PoolVariants::UniswapV3(v) => v.calculate_price(base),
+            PoolVariants::Curve(v) => v.calculate_price(base),
+            PoolVariants::Balancer(v) => v.calculate_price(base),
+            PoolVariants::Quoted(v) => v.calculate_price(base),
         }
     }
 }
@@ -137,15 +152,31 @@ impl PoolState {
 pub enum PoolVariants {
     UniswapV2(Box<UniswapV2Pool>),
     UniswapV3(Box<UniswapV3Pool>),
+    Curve(Box<CurvePool>),
+    Balancer(Box<BalancerPool>),
+    Quoted(Box<QuotedPool>),
 }
 
 impl PoolVariants {
-    fn increment_state(&mut self, logs: Vec<Log>) {
-        for log in logs {
-            let _ = match self {
-                PoolVariants::UniswapV3(a) => a.sync_from_log(log),
-                PoolVariants::UniswapV2(a) => a.sync_from_log(log),
-            };
+    fn increment_state(&mut self, update: PoolUpdate) {
+        // curve, balancer and quoted pools follow the classified action, the
+        // others their logs
+        match self {
+            PoolVariants::Curve(a) => {
+                let _ = a.sync_from_action(update.action);
+            }
+            PoolVariants::Balancer(a) => {
+                let _ = a.sync_from_action(update.action);
+            }
+            PoolVariants::Quoted(a) => {
+                let _ = a.sync_from_action(update.action);
+            }
+            PoolVariants::UniswapV3(a) => update.logs.into_iter().for_each(|log| {
+                let _ = a.sync_from_log(log);
+            }),
+            PoolVariants::UniswapV2(a) => update.logs.into_iter().for_each(|log| {
+                let _ = a.sync_from_log(log);
+            }),
         }
     }
 }
@@ -1,8 +1,14 @@
-use std::fmt::Display;
+use std::{
+    fmt::Display,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        OnceLock,
+    },
+};
 
 use tracing::Subscriber;
 use tracing_subscriber::{
-    prelude::__tracing_subscriber_SubscriberExt, registry::LookupSpan, Registry,
+    prelude::__tracing_subscriber_SubscriberExt, registry::LookupSpan, reload, Registry,
 };
 
 /// A boxed tracing Layer.
@@ -20,10 +26,25 @@ where
     S: Subscriber,
     for<'a> S: LookupSpan<'a>,
 {
-    let filter = EnvFilter::builder()
-        .with_default_directive(default_directive.to_string().parse().unwrap())
-        .from_env_lossy()
-        .add_directive("hyper::proto::h1=off".parse().unwrap());
+    tracing_subscriber::fmt::layer()
+        .with_ansi(true)
+        .with_target(true)
+        .with_filter(startup_filter(&default_directive.to_string()))
+        .boxed()
+}
+
+static LOG_FILTER: OnceLock<LogFilterHandle> = OnceLock::new();
+
+/// Same as [`stdout`], but the filter can be changed while running through
+/// [`log_filter`]. Only the first reloadable layer built is reachable.
+pub fn stdout_reloadable(default_directive: impl Display) -> BoxedLayer<Registry> {
+    let default_directive = default_directive.to_string();
+    let (filter, handle) = reload::Layer::new(startup_filter(&default_directive));
+    let _ = LOG_FILTER.set(LogFilterHandle {
+        handle,
+        default_directive,
+        generation: AtomicU64::new(0),
+    });
 
     tracing_subscriber::fmt::layer()
         .with_ansi(true)
@@ -31,3 +52,72 @@ where
         .with_filter(filter)
         .boxed()
 }
+
+/// The handle of the filter built by [`stdout_reloadable`], if any
+pub fn log_filter() -> Option<&'static LogFilterHandle> {
+    LOG_FILTER.get()
+}
+
+pub type LogFilterError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Changes the directives of the stdout filter at runtime. Every change bumps
+/// the generation, so a temporary change can revert itself without undoing a
+/// change made after it.
+pub struct LogFilterHandle {
+    handle:            reload::Handle<EnvFilter, Registry>,
+    default_directive: String,
+    generation:        AtomicU64,
+}
+
+impl LogFilterHandle {
+    /// The directives currently applied
+    pub fn current(&self) -> String {
+        self.handle
+            .with_current(|filter| filter.to_string())
+            .unwrap_or_default()
+    }
+
+    /// Replaces the filter with the given directives, e.g
+    /// `brontes_classifier=debug,info`. Targets without a directive stay on the
+    /// startup verbosity. Returns the generation of the new filter
+    pub fn set(&self, directives: &str) -> Result<u64, LogFilterError> {
+        let filter = EnvFilter::builder()
+            .with_default_directive(self.default_directive.parse()?)
+            .parse(directives)?
+            .add_directive(hyper_off());
+
+        self.reload(filter)
+    }
+
+    /// Restores the filter the process started with
+    pub fn reset(&self) -> Result<u64, LogFilterError> {
+        self.reload(startup_filter(&self.default_directive))
+    }
+
+    /// Restores the startup filter, unless the filter was changed after the
+    /// given generation. Returns whether it was restored
+    pub fn reset_if_unchanged(&self, generation: u64) -> Result<bool, LogFilterError> {
+        if self.generation.load(Ordering::SeqCst) != generation {
+            return Ok(false)
+        }
+        self.reset()?;
+
+        Ok(true)
+    }
+
+    fn reload(&self, filter: EnvFilter) -> Result<u64, LogFilterError> {
+        self.handle.reload(filter)?;
+        Ok(self.generation.fetch_add(1, Ordering::SeqCst) + 1)
+    }
+}
+
+fn startup_filter(default_directive: &str) -> EnvFilter {
+    EnvFilter::builder()
+        .with_default_directive(default_directive.parse().unwrap())
+        .from_env_lossy()
+        .add_directive(hyper_off())
+}
+
+fn hyper_off() -> tracing_subscriber::filter::Directive {
+    "hyper::proto::h1=off".parse().unwrap()
+}
@@ -59,6 +59,14 @@ impl ProtocolInfo {
 
         tokens
     }
+
+    /// with `token0` and `token1` ordered from smallest to biggest
+    pub fn sorted(mut self) -> Self {
+        if self.token0 > self.token1 {
+            std::mem::swap(&mut self.token0, &mut self.token1)
+        }
+        self
+    }
 }
 
 impl IntoIterator for ProtocolInfo {
@@ -96,6 +104,61 @@ impl From<(Vec<String>, u64, String, Option<String>)> for ProtocolInfo {
 
 implement_table_value_codecs_with_zc!(ProtocolInfoRedefined);
 
+/// A mapping for an address that has since been replaced. It was valid from
+/// `info.init_block` up until (not including) `end_block`.
+#[derive(Debug, Default, Row, PartialEq, Clone, Eq, Serialize, Deserialize, Redefined, Hash)]
+#[redefined_attr(derive(
+    Debug,
+    PartialEq,
+    Clone,
+    Serialize,
+    rSerialize,
+    rDeserialize,
+    Archive,
+    Hash
+))]
+pub struct ProtocolInfoSnapshot {
+    pub end_block: u64,
+    pub info:      ProtocolInfo,
+}
+
+impl ProtocolInfoSnapshot {
+    pub fn is_valid_at(&self, block: u64) -> bool {
+        self.info.init_block <= block && block < self.end_block
+    }
+}
+
+/// The previous protocol mappings of an address, used so that historical
+/// blocks are classified with the mapping that was live at the time, even if
+/// the address has since been migrated or re-used.
+#[derive(Debug, Default, Row, PartialEq, Clone, Eq, Serialize, Deserialize, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct ProtocolInfoHistory(pub Vec<ProtocolInfoSnapshot>);
+
+impl ProtocolInfoHistory {
+    pub fn at_block(&self, block: u64) -> Option<&ProtocolInfo> {
+        self.0
+            .iter()
+            .find(|snapshot| snapshot.is_valid_at(block))
+            .map(|snapshot| &snapshot.info)
+    }
+
+    /// adds a snapshot, keeping them ordered by the block they start at.
+    /// Snapshots that are already present are ignored
+    pub fn insert(&mut self, snapshot: ProtocolInfoSnapshot) {
+        if self.0.contains(&snapshot) {
+            return
+        }
+
+        let idx = self
+            .0
+            .partition_point(|s| s.info.init_block <= snapshot.info.init_block);
+        self.0.insert(idx, snapshot);
+    }
+}
+
+implement_table_value_codecs_with_zc!(ProtocolInfoHistoryRedefined);
+
 #[derive(Debug, Default, Row, PartialEq, Clone, Eq, Serialize, Deserialize)]
 pub struct ProtocolInfoClickhouse {
     pub protocol:         String,
@@ -0,0 +1,191 @@
+use malachite::Rational;
+use redefined::{self_convert_redefined, Redefined};
+use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    implement_table_value_codecs_with_zc,
+    mev::{Bundle, BundleData},
+    normalized_actions::NormalizedSwap,
+    pair::{Pair, PairRedefined},
+    FastHashMap, ToFloatNearest,
+};
+
+/// The realized arbs of one kind on a pair
+#[derive(
+    Debug,
+    Default,
+    PartialEq,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    rSerialize,
+    rDeserialize,
+    Archive,
+)]
+pub struct ArbStats {
+    /// Number of arbs that traded the pair
+    pub count:      u64,
+    /// Amount of the pair's first token the arbs traded on the pair
+    pub volume:     f64,
+    /// Share of the arbs' profit attributed to the pair, arbs over several
+    /// pairs split their profit evenly between them
+    pub profit_usd: f64,
+}
+
+self_convert_redefined!(ArbStats);
+
+impl ArbStats {
+    fn add(&mut self, volume: f64, profit_usd: f64) {
+        self.count += 1;
+        self.volume += volume;
+        self.profit_usd += profit_usd;
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Clone, Serialize, Deserialize, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct PairArbStats {
+    /// The pair, ordered
+    pub pair:    Pair,
+    #[redefined(same_fields)]
+    pub atomic:  ArbStats,
+    #[redefined(same_fields)]
+    pub cex_dex: ArbStats,
+}
+
+/// Per pair arbitrage over a block range, keyed by the first block of the
+/// range. Tracks how efficiently each pair gets arbitraged over time.
+#[derive(Debug, Default, PartialEq, Clone, Serialize, Deserialize, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct PairArbSummary {
+    /// Last block of the range, inclusive
+    pub end_block: u64,
+    pub pairs:     Vec<PairArbStats>,
+}
+
+implement_table_value_codecs_with_zc!(PairArbSummaryRedefined);
+
+impl PairArbSummary {
+    pub fn from_bundles<'a>(end_block: u64, bundles: impl IntoIterator<Item = &'a Bundle>) -> Self {
+        let mut pairs: FastHashMap<Pair, PairArbStats> = FastHashMap::default();
+
+        for bundle in bundles {
+            let (swaps, is_atomic) = match &bundle.data {
+                BundleData::AtomicArb(arb) => (&arb.swaps, true),
+                BundleData::CexDex(arb) => (&arb.swaps, false),
+                BundleData::CexDexQuote(arb) => (&arb.swaps, false),
+                _ => continue,
+            };
+
+            let mut volumes: FastHashMap<Pair, f64> = FastHashMap::default();
+            for swap in swaps {
+                let (pair, volume) = pair_volume(swap);
+                *volumes.entry(pair).or_default() += volume;
+            }
+            if volumes.is_empty() {
+                continue
+            }
+
+            let profit_share = bundle.header.profit_usd / volumes.len() as f64;
+            for (pair, volume) in volumes {
+                let entry = pairs
+                    .entry(pair)
+                    .or_insert_with(|| PairArbStats { pair, ..Default::default() });
+                if is_atomic {
+                    entry.atomic.add(volume, profit_share);
+                } else {
+                    entry.cex_dex.add(volume, profit_share);
+                }
+            }
+        }
+
+        let mut pairs = pairs.into_values().collect::<Vec<_>>();
+        pairs.sort_by(|a, b| (a.pair.0, a.pair.1).cmp(&(b.pair.0, b.pair.1)));
+
+        Self { end_block, pairs }
+    }
+}
+
+/// The ordered pair of the swap and the amount of the pair's first token that
+/// was traded
+fn pair_volume(swap: &NormalizedSwap) -> (Pair, f64) {
+    let pair = Pair(swap.token_in.address, swap.token_out.address).ordered();
+    let amount: &Rational =
+        if pair.0 == swap.token_in.address { &swap.amount_in } else { &swap.amount_out };
+
+    (pair, amount.clone().to_float())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        db::token_info::TokenInfoWithAddress,
+        mev::{AtomicArb, BundleHeader, CexDex},
+    };
+
+    fn swap(
+        token_in: TokenInfoWithAddress,
+        token_out: TokenInfoWithAddress,
+        amount_in: u64,
+        amount_out: u64,
+    ) -> NormalizedSwap {
+        NormalizedSwap {
+            token_in,
+            token_out,
+            amount_in: Rational::from(amount_in),
+            amount_out: Rational::from(amount_out),
+            ..Default::default()
+        }
+    }
+
+    fn bundle(profit_usd: f64, data: BundleData) -> Bundle {
+        Bundle { header: BundleHeader { profit_usd, ..Default::default() }, data }
+    }
+
+    #[test]
+    fn test_pair_arb_index() {
+        let weth = TokenInfoWithAddress::weth();
+        let usdc = TokenInfoWithAddress::usdc();
+        let usdt = TokenInfoWithAddress::usdt();
+
+        let atomic = bundle(
+            10.0,
+            BundleData::AtomicArb(AtomicArb {
+                swaps: vec![
+                    swap(weth.clone(), usdc.clone(), 1, 3000),
+                    swap(usdc.clone(), usdt.clone(), 3000, 3001),
+                ],
+                ..Default::default()
+            }),
+        );
+        let cex_dex = bundle(
+            4.0,
+            BundleData::CexDex(CexDex {
+                swaps: vec![swap(usdc.clone(), weth.clone(), 6000, 2)],
+                ..Default::default()
+            }),
+        );
+        let sandwich = bundle(100.0, BundleData::default());
+
+        let index = PairArbSummary::from_bundles(100, [&atomic, &cex_dex, &sandwich]);
+        assert_eq!(index.end_block, 100);
+        assert_eq!(index.pairs.len(), 2);
+
+        let weth_usdc = Pair(weth.address, usdc.address).ordered();
+        let stats = index
+            .pairs
+            .iter()
+            .find(|stats| stats.pair == weth_usdc)
+            .unwrap();
+        assert_eq!(stats.atomic.count, 1);
+        assert_eq!(stats.atomic.profit_usd, 5.0);
+        assert_eq!(stats.cex_dex.count, 1);
+        assert_eq!(stats.cex_dex.profit_usd, 4.0);
+
+        let weth_volume = if weth_usdc.0 == weth.address { 2.0 } else { 6000.0 };
+        assert_eq!(stats.cex_dex.volume, weth_volume);
+    }
+}
@@ -0,0 +1,70 @@
+use redefined::self_convert_redefined;
+use serde::{Deserialize, Serialize};
+
+use crate::implement_table_value_codecs_with_zc;
+
+/// A state changing cli command that was run against the database. Entries
+/// are keyed by the time the command was started at, in nanoseconds since the
+/// unix epoch, and are never modified once written.
+#[derive(
+    Debug,
+    Default,
+    PartialEq,
+    Clone,
+    Eq,
+    Serialize,
+    Deserialize,
+    rkyv::Serialize,
+    rkyv::Deserialize,
+    rkyv::Archive,
+)]
+pub struct AuditEntry {
+    /// The subcommand that was run, e.g `db clear`
+    pub command: String,
+    /// The full command line, so the operation can be replayed
+    pub args:    Vec<String>,
+    /// The user that ran the command
+    pub user:    String,
+}
+
+impl AuditEntry {
+    /// Shell-quotes the arguments so they can be pasted back into a terminal
+    pub fn command_line(&self) -> String {
+        self.args
+            .iter()
+            .map(|arg| {
+                if arg.is_empty() || arg.contains(|c: char| c.is_whitespace() || c == '"') {
+                    format!("'{}'", arg.replace('\'', r"'\''"))
+                } else {
+                    arg.clone()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+self_convert_redefined!(AuditEntry);
+implement_table_value_codecs_with_zc!(AuditEntry);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_line_quotes_args() {
+        let entry = AuditEntry {
+            command: "db insert".to_string(),
+            args:    vec![
+                "brontes".to_string(),
+                "db".to_string(),
+                "insert".to_string(),
+                "--value".to_string(),
+                "a value".to_string(),
+            ],
+            user:    "alice".to_string(),
+        };
+
+        assert_eq!(entry.command_line(), "brontes db insert --value 'a value'");
+    }
+}
@@ -122,3 +122,72 @@ impl BuilderInfoWithAddress {
         }
     }
 }
+
+/// What searchers have paid a builder across the blocks it built, kept up to
+/// date block by block. Payments are the priority fees and coinbase transfers
+/// of the mev bundles in its blocks, along with any tokens the bundles sent to
+/// the builder's address directly
+#[derive(Debug, Default, Row, PartialEq, Clone, Serialize, Deserialize, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct BuilderRevenue {
+    /// Blocks built that had mev in them
+    pub blocks:               u64,
+    pub bundles:              u64,
+    /// In wei
+    pub priority_fees:        u128,
+    /// In wei
+    pub coinbase_transfers:   u128,
+    /// Tokens sent to the builder's address, in usd at the time of transfer
+    pub direct_transfers_usd: f64,
+    /// All of the above, in usd
+    pub total_usd:            f64,
+    pub last_block:           u64,
+}
+
+impl BuilderRevenue {
+    /// Adds the payments of a later block
+    pub fn merge(&mut self, other: BuilderRevenue) {
+        self.blocks += other.blocks;
+        self.bundles += other.bundles;
+        self.priority_fees += other.priority_fees;
+        self.coinbase_transfers += other.coinbase_transfers;
+        self.direct_transfers_usd += other.direct_transfers_usd;
+        self.total_usd += other.total_usd;
+        self.last_block = self.last_block.max(other.last_block);
+    }
+}
+
+implement_table_value_codecs_with_zc!(BuilderRevenueRedefined);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_builder_revenue() {
+        let mut revenue = BuilderRevenue {
+            blocks:               1,
+            bundles:              2,
+            priority_fees:        10,
+            coinbase_transfers:   5,
+            direct_transfers_usd: 1.0,
+            total_usd:            3.0,
+            last_block:           10,
+        };
+        revenue.merge(BuilderRevenue {
+            blocks:               1,
+            bundles:              1,
+            priority_fees:        1,
+            coinbase_transfers:   0,
+            direct_transfers_usd: 0.0,
+            total_usd:            0.5,
+            last_block:           12,
+        });
+
+        assert_eq!(revenue.blocks, 2);
+        assert_eq!(revenue.bundles, 3);
+        assert_eq!(revenue.priority_fees, 11);
+        assert_eq!(revenue.total_usd, 3.5);
+        assert_eq!(revenue.last_block, 12);
+    }
+}
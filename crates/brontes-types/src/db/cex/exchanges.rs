@@ -89,7 +89,7 @@ impl From<&str> for CexExchange {
             "binance" | "binance-futures" => CexExchange::Binance,
             "bitmex" | "Bitmex" => CexExchange::Bitmex,
             "deribit" | "Deribit" => CexExchange::Deribit,
-            "okex" | "Okex" | "okex-swap" => CexExchange::Okex,
+            "okex" | "Okex" | "okex-swap" | "okx" => CexExchange::Okex,
             "coinbase" | "Coinbase" => CexExchange::Coinbase,
             "kraken" | "Kraken" => CexExchange::Kraken,
             "bybit-spot" | "bybitspot" | "BybitSpot" | "Bybit-Spot" | "Bybit_Spot" | "bybit" => {
@@ -0,0 +1,97 @@
+//! Which venues list a token and the smallest order they accept for it. A
+//! cex-dex arb can only have hedged on a venue that trades both tokens of the
+//! swap, in a size the venue accepts.
+use std::str::FromStr;
+
+use redefined::Redefined;
+use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
+use serde::{Deserialize, Serialize};
+
+use super::CexExchange;
+use crate::implement_table_value_codecs_with_zc;
+
+#[derive(Debug, Default, PartialEq, Clone, Serialize, Deserialize, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct CexListing {
+    #[redefined(same_fields)]
+    pub exchange: CexExchange,
+    /// Ticker of the token on the venue
+    pub symbol:   String,
+    /// Smallest order the venue accepts, in units of the token
+    pub min_size: f64,
+}
+
+/// The venues listing a token. A token without listings is assumed to trade
+/// everywhere, as most of them were never filled in.
+#[derive(Debug, Default, PartialEq, Clone, Serialize, Deserialize, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct CexTokenListings {
+    pub listings: Vec<CexListing>,
+}
+
+implement_table_value_codecs_with_zc!(CexTokenListingsRedefined);
+
+impl CexTokenListings {
+    pub fn get(&self, exchange: CexExchange) -> Option<&CexListing> {
+        self.listings.iter().find(|l| l.exchange == exchange)
+    }
+
+    /// Whether `amount` of the token can be traded on the exchange
+    pub fn is_tradable(&self, exchange: CexExchange, amount: f64) -> bool {
+        self.get(exchange)
+            .is_some_and(|listing| amount >= listing.min_size)
+    }
+}
+
+/// Parses listings written as `exchange:symbol:min_size`, comma separated.
+/// e.g `binance:ETH:0.0001,coinbase:ETH:0.00000001`
+impl FromStr for CexTokenListings {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let listings = s
+            .split(',')
+            .map(|listing| {
+                let [exchange, symbol, min_size] = listing
+                    .trim()
+                    .split(':')
+                    .collect::<Vec<_>>()
+                    .try_into()
+                    .map_err(|_| {
+                        eyre::eyre!("expected exchange:symbol:min_size, got {}", listing)
+                    })?;
+
+                let exchange = CexExchange::from(exchange);
+                if exchange == CexExchange::Unknown {
+                    eyre::bail!("unknown exchange in listing {}", listing)
+                }
+
+                Ok(CexListing { exchange, symbol: symbol.to_string(), min_size: min_size.parse()? })
+            })
+            .collect::<eyre::Result<Vec<_>>>()?;
+
+        Ok(Self { listings })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_listings() {
+        let listings: CexTokenListings = "binance:ETH:0.0001, Coinbase:ETH:0.00000001"
+            .parse()
+            .unwrap();
+
+        assert_eq!(listings.listings.len(), 2);
+        assert_eq!(listings.get(CexExchange::Binance).unwrap().symbol, "ETH");
+        assert!(listings.is_tradable(CexExchange::Binance, 0.001));
+        assert!(!listings.is_tradable(CexExchange::Binance, 0.00001));
+        assert!(listings.is_tradable(CexExchange::Coinbase, 0.00001));
+        assert!(!listings.is_tradable(CexExchange::Kraken, 10.0));
+
+        assert!("binance:ETH".parse::<CexTokenListings>().is_err());
+        assert!("nowhere:ETH:1".parse::<CexTokenListings>().is_err());
+    }
+}
@@ -1,10 +1,12 @@
 mod best_cex_per_pair;
 mod cex_symbols;
 mod exchanges;
+mod listings;
 
 pub use best_cex_per_pair::*;
 pub use cex_symbols::*;
 pub use exchanges::*;
+pub use listings::*;
 
 pub mod quotes;
 pub mod trades;
@@ -32,10 +32,14 @@ use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
 use serde::{ser::SerializeSeq, Deserialize, Serialize};
 use tracing::error;
 
-use super::types::CexQuote;
+use super::types::{BookLevel, CexQuote};
 use crate::{
     db::{
-        cex::{quotes::CexQuoteRedefined, trades::Direction, CexExchange},
+        cex::{
+            quotes::{BookLevelRedefined, CexQuoteRedefined},
+            trades::Direction,
+            CexExchange,
+        },
         redefined_types::malachite::RationalRedefined,
     },
     implement_table_value_codecs_with_zc,
@@ -171,6 +175,7 @@ impl CexPriceMap {
 
                 let closest_quote = adjusted_quotes.get(index.saturating_sub(1))?;
                 let adjusted_quote = closest_quote.adjust_for_direction(direction);
+                let depth = closest_quote.hedge_depth(direction);
 
                 let fees = exchange.fees();
 
@@ -185,12 +190,13 @@ impl CexPriceMap {
                 );
 
                 Some(FeeAdjustedQuote {
-                    exchange:    *exchange,
-                    timestamp:   adjusted_quote.timestamp,
-                    pairs:       vec![*pair],
+                    exchange: *exchange,
+                    timestamp: adjusted_quote.timestamp,
+                    pairs: vec![*pair],
                     price_maker: (fee_adjusted_maker.0, fee_adjusted_maker.1),
                     price_taker: (fee_adjusted_taker.0, fee_adjusted_taker.1),
-                    amount:      adjusted_quote.amount,
+                    amount: adjusted_quote.amount,
+                    depth,
                 })
             })
     }
@@ -240,6 +246,7 @@ impl CexPriceMap {
                         price_maker: combined_price_maker,
                         price_taker: combined_price_taker,
                         amount:      normalized_bbo_amount,
+                        depth:       vec![],
                     })
                 } else {
                     None
@@ -336,6 +343,7 @@ impl CexPriceMap {
                         // window, exchange & pair. This does not represent the total amount
                         // available
                         amount: (cumulative_bbo.0, cumulative_bbo.1),
+                        depth: vec![],
                     })
                 }
             })
@@ -385,6 +393,7 @@ impl CexPriceMap {
                         price_maker: combined_price_maker,
                         price_taker: combined_price_taker,
                         amount:      normalized_bbo_amount,
+                        depth:       vec![],
                     })
                 } else {
                     None
@@ -464,6 +473,7 @@ impl CexPriceMap {
                 price_maker: (volume_weighted_bid_maker, volume_weighted_ask_maker),
                 price_taker: (volume_weighted_bid_taker, volume_weighted_ask_taker),
                 amount:      avg_amount,
+                depth:       vec![],
             })
         }
     }
@@ -691,6 +701,10 @@ pub struct FeeAdjustedQuote {
     pub price_taker: (Rational, Rational),
     /// Bid & Ask amount
     pub amount:      (Rational, Rational),
+    /// The levels of the book hedging a swap of the pair walks, see
+    /// [`CexQuote::hedge_depth`]. Empty unless the quote is direct and the
+    /// exchange's depth is known
+    pub depth:       Vec<BookLevel>,
 }
 
 impl fmt::Display for FeeAdjustedQuote {
@@ -741,6 +755,34 @@ impl FeeAdjustedQuote {
     pub fn maker_taker_ask(self) -> (Rational, Rational) {
         (self.price_maker.1, self.price_taker.1)
     }
+
+    /// The average price of hedging `amount` of the token the swap got out
+    /// across the book, as a share of the best level's price. One when the
+    /// hedge fits in the best level and less the deeper it has to go, `None`
+    /// without depth. Snapshots only hold the top of the book, so whatever
+    /// doesn't fit in them is priced at the deepest level
+    pub fn book_slippage(&self, amount: &Rational) -> Option<Rational> {
+        let best = &self.depth.first()?.price;
+        if *best == Rational::ZERO || *amount == Rational::ZERO {
+            return None
+        }
+
+        let mut remaining = amount.clone();
+        let mut value = Rational::ZERO;
+        for level in &self.depth {
+            let fill = min(&remaining, &level.amount).clone();
+            value += &fill * &level.price;
+            remaining -= fill;
+            if remaining == Rational::ZERO {
+                break
+            }
+        }
+        if remaining > Rational::ZERO {
+            value += remaining * &self.depth.last()?.price;
+        }
+
+        Some(value / amount / best)
+    }
 }
 
 impl PartialEq for FeeAdjustedQuote {
@@ -801,6 +843,17 @@ fn size_of_cex_quote(quote: &CexQuote) -> usize {
         + size_of_rational(&quote.price.1)
         + size_of_rational(&quote.amount.0)
         + size_of_rational(&quote.amount.1)
+        + quote
+            .depth
+            .0
+            .iter()
+            .chain(&quote.depth.1)
+            .map(|level| {
+                mem::size_of_val(level)
+                    + size_of_rational(&level.price)
+                    + size_of_rational(&level.amount)
+            })
+            .sum::<usize>()
         + 8
 }
 
@@ -814,3 +867,30 @@ fn size_of_natural(natural: &Natural) -> usize {
     let capacity = (natural.significant_bits() / 64 + 1) as usize;
     mem::size_of::<Natural>() + capacity * mem::size_of::<usize>()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_book_slippage() {
+        let level = |price, amount| BookLevel {
+            price:  Rational::from(price),
+            amount: Rational::from(amount),
+        };
+        let mut quote = FeeAdjustedQuote {
+            depth: vec![level(100, 2), level(90, 2), level(80, 1)],
+            ..Default::default()
+        };
+
+        // fits in the best level
+        assert_eq!(quote.book_slippage(&Rational::from(2)), Some(Rational::ONE));
+        // 2 at 100 and 2 at 90
+        assert_eq!(quote.book_slippage(&Rational::from(4)), Some(Rational::from_signeds(19, 20)));
+        // 2 at 100, 2 at 90 and the remaining 6 at the deepest level
+        assert_eq!(quote.book_slippage(&Rational::from(10)), Some(Rational::from_signeds(86, 100)));
+
+        quote.depth.clear();
+        assert_eq!(quote.book_slippage(&Rational::from(2)), None);
+    }
+}
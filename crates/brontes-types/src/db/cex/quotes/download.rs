@@ -28,6 +28,10 @@ pub struct RawCexQuotes {
     pub ask_price:  f64,
     pub bid_price:  f64,
     pub bid_amount: f64,
+    /// Bid & Ask levels as `(price, amount)`, best first, for sources with
+    /// order book snapshots. Clickhouse only has the top of the book
+    #[serde(skip)]
+    pub depth:      (Vec<(f64, f64)>, Vec<(f64, f64)>),
 }
 
 #[derive(Debug)]
@@ -5,7 +5,10 @@ use std::{
 };
 
 use clickhouse::Row;
-use malachite::{num::arithmetic::traits::Reciprocal, Rational};
+use malachite::{
+    num::{arithmetic::traits::Reciprocal, basic::traits::Zero},
+    Rational,
+};
 use redefined::Redefined;
 use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
 use serde::Serialize;
@@ -37,6 +40,27 @@ pub struct CexQuote {
     pub price:     (Rational, Rational),
     /// Bid & Ask amount
     pub amount:    (Rational, Rational),
+    /// Bid & Ask levels of the book, best first and starting at the best bid
+    /// and ask. Empty when only the top of the book is known
+    #[serde(default)]
+    pub depth:     (Vec<BookLevel>, Vec<BookLevel>),
+}
+
+/// A price level of an order book, with the amount of the base asset it holds
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, Redefined)]
+#[redefined_attr(derive(
+    Debug,
+    PartialEq,
+    Clone,
+    Hash,
+    Serialize,
+    rSerialize,
+    rDeserialize,
+    Archive
+))]
+pub struct BookLevel {
+    pub price:  Rational,
+    pub amount: Rational,
 }
 
 impl Display for CexQuote {
@@ -64,7 +88,18 @@ impl From<RawCexQuotes> for CexQuote {
             Rational::try_from_float_simplest(value.ask_amount).unwrap(),
         );
 
-        CexQuote { exchange: value.exchange, timestamp: value.timestamp, price, amount }
+        let to_levels = |levels: Vec<(f64, f64)>| {
+            levels
+                .into_iter()
+                .map(|(price, amount)| BookLevel {
+                    price:  Rational::try_from_float_simplest(price).unwrap(),
+                    amount: Rational::try_from_float_simplest(amount).unwrap(),
+                })
+                .collect()
+        };
+        let depth = (to_levels(value.depth.0), to_levels(value.depth.1));
+
+        CexQuote { exchange: value.exchange, timestamp: value.timestamp, price, amount, depth }
     }
 }
 
@@ -88,15 +123,39 @@ impl CexQuote {
                 timestamp: self.timestamp,
                 price:     self.price.clone(),
                 amount:    (&self.amount.0 * &self.price.0, &self.amount.1 * &self.price.1),
+                depth:     self.depth.clone(),
             },
             Direction::Sell => Self {
                 exchange:  self.exchange,
                 timestamp: self.timestamp,
                 price:     (self.price.0.clone().reciprocal(), self.price.1.clone().reciprocal()),
                 amount:    self.amount.clone(),
+                depth:     self.depth.clone(),
             },
         }
     }
+
+    /// The levels a swap into the pair is hedged against, priced like
+    /// [`Self::adjust_for_direction`] in the token the swap put in for each of
+    /// the token it got out, with amounts in the token it got out. With
+    /// `Direction::Sell` the swap got the quote asset out, and the hedge buys
+    /// the base back off the asks. With `Direction::Buy` it got the base out,
+    /// and the hedge sells it into the bids
+    pub fn hedge_depth(&self, direction: Direction) -> Vec<BookLevel> {
+        match direction {
+            Direction::Buy => self.depth.0.clone(),
+            Direction::Sell => self
+                .depth
+                .1
+                .iter()
+                .filter(|level| level.price != Rational::ZERO)
+                .map(|level| BookLevel {
+                    price:  level.price.clone().reciprocal(),
+                    amount: &level.amount * &level.price,
+                })
+                .collect(),
+        }
+    }
 }
 
 impl PartialEq for CexQuote {
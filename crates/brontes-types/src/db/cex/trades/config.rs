@@ -19,6 +19,12 @@ pub struct CexDexTradeConfig {
     pub pre_decay_weight_op:               f64,
     pub post_decay_weight_op:              f64,
     pub quote_offset_from_block_us:        u64,
+    /// Time between the markouts taken after the block
+    pub markout_interval_us:               u64,
+    /// How many markouts are taken, none if zero
+    pub markout_intervals:                 u64,
+    /// Width of the trade window each markout is priced over
+    pub markout_window_us:                 u64,
 }
 
 impl Default for CexDexTradeConfig {
@@ -43,6 +49,9 @@ impl Default for CexDexTradeConfig {
             pre_decay_weight_op:               -0.0000003,
             post_decay_weight_op:              -0.00000012,
             quote_offset_from_block_us:        0,
+            markout_interval_us:               1_000_000,
+            markout_intervals:                 3,
+            markout_window_us:                 500_000,
         }
     }
 }
@@ -52,4 +61,17 @@ impl CexDexTradeConfig {
         self.use_block_time_weights_optimistic = true;
         self.use_block_time_weights_vwap = true;
     }
+
+    /// How long after the block each markout is taken
+    pub fn markouts_us(&self) -> impl Iterator<Item = u64> {
+        let interval = self.markout_interval_us;
+        (1..=self.markout_intervals).map(move |i| i * interval)
+    }
+
+    /// The trade window of the markout `markout_us` after the block
+    pub fn markout_window(&self, block_timestamp: u64, markout_us: u64) -> (u64, u64) {
+        let center = block_timestamp + markout_us;
+        let half = self.markout_window_us / 2;
+        (center.saturating_sub(half), center + half)
+    }
 }
@@ -10,7 +10,7 @@ use alloy_primitives::FixedBytes;
 pub use cex_trades::*;
 pub use config::*;
 pub use download::*;
-use malachite::Rational;
+use malachite::{num::basic::traits::Zero, Rational};
 pub use optimistic::*;
 pub use time_window_vwam::*;
 use utils::SortedTrades;
@@ -84,6 +84,48 @@ impl CexTradeMap {
             )
     }
 
+    /// Volume weighted price of the pair's trades on the exchanges between
+    /// `start` and `end`, in the pair's first token per its second, along with
+    /// the volume weighted rate of `taker_fee` on the exchanges traded. Used to
+    /// mark a position out some time after the block, so trades aren't routed
+    /// through intermediaries and fees are left to the caller
+    pub fn markout_price(
+        &self,
+        exchanges: &[CexExchange],
+        pair: Pair,
+        start: u64,
+        end: u64,
+        taker_fee: impl Fn(CexExchange) -> Rational,
+    ) -> Option<(Rational, Rational)> {
+        let mut volume = Rational::ZERO;
+        let mut notional = Rational::ZERO;
+        let mut fees = Rational::ZERO;
+
+        for (exchange, pairs) in &self.0 {
+            if !exchanges.contains(exchange) {
+                continue
+            }
+            let fee = taker_fee(*exchange);
+            // trades on the flipped pair are already priced the way we want
+            for (traded, direction) in [(pair, Direction::Sell), (pair.flip(), Direction::Buy)] {
+                let Some(trades) = pairs.get(&traded) else { continue };
+                let first = trades.partition_point(|trade| trade.timestamp < start);
+
+                for trade in trades[first..]
+                    .iter()
+                    .take_while(|trade| trade.timestamp <= end)
+                {
+                    let trade = trade.adjust_for_direction(direction);
+                    notional += &trade.price * &trade.amount;
+                    fees += &fee * &trade.amount;
+                    volume += trade.amount;
+                }
+            }
+        }
+
+        (volume != Rational::ZERO).then(|| (notional / &volume, fees / volume))
+    }
+
     pub fn get_optimistic_vmap(
         &self,
         config: CexDexTradeConfig,
@@ -110,3 +152,43 @@ impl CexTradeMap {
             )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::Address;
+
+    use super::*;
+
+    #[test]
+    fn test_markout_price() {
+        let weth = Address::repeat_byte(1);
+        let usdc = Address::repeat_byte(2);
+        let trade = |timestamp, price| CexTrades {
+            exchange: CexExchange::Binance,
+            timestamp,
+            price: Rational::from(price),
+            amount: Rational::from(1),
+        };
+
+        // listed as weth/usdc, priced in usdc per weth
+        let trades = vec![trade(10, 2000), trade(20, 2000), trade(50, 3000)];
+        let map = CexTradeMap(
+            [(CexExchange::Binance, [(Pair(weth, usdc), trades)].into_iter().collect())]
+                .into_iter()
+                .collect(),
+        );
+        let exchanges = [CexExchange::Binance];
+        let fee = |_| Rational::from_unsigneds(1u32, 1000);
+
+        assert_eq!(
+            map.markout_price(&exchanges, Pair(usdc, weth), 0, 30, fee),
+            Some((Rational::from(2000), Rational::from_unsigneds(1u32, 1000)))
+        );
+        assert_eq!(
+            map.markout_price(&exchanges, Pair(weth, usdc), 0, 30, fee),
+            Some((Rational::from_unsigneds(1u32, 2000), Rational::from_unsigneds(1u32, 1000)))
+        );
+        assert_eq!(map.markout_price(&exchanges, Pair(usdc, weth), 60, 90, fee), None);
+        assert_eq!(map.markout_price(&[CexExchange::Okex], Pair(usdc, weth), 0, 30, fee), None);
+    }
+}
@@ -325,6 +325,7 @@ pub mod tx_traces_inner {
         String,
         u128,
         u128,
+        u128,
         u64,
         bool,
     );
@@ -366,6 +367,7 @@ pub mod tx_traces_inner {
             tx_hash,
             gas_used,
             effective_price,
+            blob_gas_paid,
             tx_index,
             is_success,
         ) = value;
@@ -374,6 +376,7 @@ pub mod tx_traces_inner {
         tx_trace.tx_hash = TxHash::from_str(&tx_hash).unwrap();
         tx_trace.gas_used = gas_used;
         tx_trace.effective_price = effective_price;
+        tx_trace.blob_gas_paid = blob_gas_paid;
         tx_trace.tx_index = tx_index;
         tx_trace.is_success = is_success;
 
@@ -0,0 +1,217 @@
+use alloy_primitives::{keccak256, B256};
+use redefined::Redefined;
+use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
+use serde::{Deserialize, Serialize};
+
+use super::mev_block::MevBlockWithClassified;
+use crate::{db::redefined_types::primitives::B256Redefined, implement_table_value_codecs_with_zc};
+
+/// Height of the range tree, one leaf per possible block number
+const TREE_HEIGHT: usize = 64;
+
+/// Commitment to the results brontes produced for a block. Two datasets with
+/// the same commitment for a block hold the same block summary and bundles,
+/// including the classified actions the bundles were built from.
+#[derive(Debug, Default, PartialEq, Clone, Serialize, Deserialize, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct BlockCommitment {
+    /// Hash of the mev block summary
+    pub summary_hash: B256,
+    /// Merkle root of the bundle hashes, sorted so the inspector order doesn't
+    /// matter
+    pub bundles_root: B256,
+    pub bundle_count: u64,
+    /// Hash of the block number, summary hash and bundles root. This is the
+    /// leaf of the block in the range tree
+    pub root:         B256,
+}
+
+implement_table_value_codecs_with_zc!(BlockCommitmentRedefined);
+
+impl BlockCommitment {
+    /// Results are canonicalized as their json encoding, which is stable as
+    /// none of the mev types hold maps
+    pub fn new(block: &MevBlockWithClassified) -> Result<Self, serde_json::Error> {
+        let summary_hash = keccak256(serde_json::to_vec(&block.block)?);
+
+        let mut leaves = block
+            .mev
+            .iter()
+            .map(|bundle| serde_json::to_vec(bundle).map(keccak256))
+            .collect::<Result<Vec<_>, _>>()?;
+        leaves.sort_unstable();
+        let bundles_root = merkle_root(&leaves);
+
+        let mut preimage = Vec::with_capacity(72);
+        preimage.extend_from_slice(&block.block.block_number.to_be_bytes());
+        preimage.extend_from_slice(summary_hash.as_slice());
+        preimage.extend_from_slice(bundles_root.as_slice());
+
+        Ok(Self {
+            summary_hash,
+            bundles_root,
+            bundle_count: leaves.len() as u64,
+            root: keccak256(preimage),
+        })
+    }
+}
+
+fn hash_pair(left: &B256, right: &B256) -> B256 {
+    let mut preimage = [0u8; 64];
+    preimage[..32].copy_from_slice(left.as_slice());
+    preimage[32..].copy_from_slice(right.as_slice());
+    keccak256(preimage)
+}
+
+/// Binary merkle root, an odd node is carried up to the next level as is
+fn merkle_root(leaves: &[B256]) -> B256 {
+    if leaves.is_empty() {
+        return B256::ZERO
+    }
+
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        level = level
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => hash_pair(left, right),
+                [single] => *single,
+                _ => unreachable!(),
+            })
+            .collect();
+    }
+
+    level[0]
+}
+
+/// Sparse merkle tree over block numbers with the block commitment roots as
+/// leaves. Blocks without a commitment are empty leaves, so the root of a
+/// range can be compared between datasets and a single block can be proven
+/// against a published root without the rest of the range.
+pub struct CommitmentTree {
+    /// (block number, commitment root), sorted by block number
+    leaves:      Vec<(u64, B256)>,
+    /// Root of an empty subtree of each height
+    empty_roots: [B256; TREE_HEIGHT + 1],
+}
+
+impl CommitmentTree {
+    pub fn new(leaves: impl IntoIterator<Item = (u64, B256)>) -> Self {
+        let mut leaves = leaves.into_iter().collect::<Vec<_>>();
+        leaves.sort_unstable_by_key(|(block, _)| *block);
+        leaves.dedup_by_key(|(block, _)| *block);
+
+        let mut empty_roots = [B256::ZERO; TREE_HEIGHT + 1];
+        for height in 1..=TREE_HEIGHT {
+            empty_roots[height] = hash_pair(&empty_roots[height - 1], &empty_roots[height - 1]);
+        }
+
+        Self { leaves, empty_roots }
+    }
+
+    pub fn root(&self) -> B256 {
+        self.subtree_root(&self.leaves, TREE_HEIGHT)
+    }
+
+    /// The sibling of each level on the path from the block to the root,
+    /// starting at the leaves
+    pub fn proof(&self, block: u64) -> Vec<B256> {
+        let mut siblings = Vec::with_capacity(TREE_HEIGHT);
+        for height in 0..TREE_HEIGHT {
+            let sibling_prefix = (block >> height) ^ 1;
+            let start = self
+                .leaves
+                .partition_point(|(b, _)| (b >> height) < sibling_prefix);
+            let end = self
+                .leaves
+                .partition_point(|(b, _)| (b >> height) <= sibling_prefix);
+            siblings.push(self.subtree_root(&self.leaves[start..end], height));
+        }
+
+        siblings
+    }
+
+    /// Checks that the block has the commitment in the tree with the root. An
+    /// empty leaf proves that the tree has no commitment for the block
+    pub fn verify(block: u64, leaf: B256, proof: &[B256], root: B256) -> bool {
+        if proof.len() != TREE_HEIGHT {
+            return false
+        }
+
+        let computed = proof
+            .iter()
+            .enumerate()
+            .fold(leaf, |node, (height, sibling)| {
+                if (block >> height) & 1 == 0 {
+                    hash_pair(&node, sibling)
+                } else {
+                    hash_pair(sibling, &node)
+                }
+            });
+
+        computed == root
+    }
+
+    /// Root of the subtree of the given height holding the leaves, which all
+    /// share the same prefix above that height
+    fn subtree_root(&self, leaves: &[(u64, B256)], height: usize) -> B256 {
+        if leaves.is_empty() {
+            return self.empty_roots[height]
+        }
+        if height == 0 {
+            return leaves[0].1
+        }
+
+        let split = leaves.partition_point(|(block, _)| (block >> (height - 1)) & 1 == 0);
+        hash_pair(
+            &self.subtree_root(&leaves[..split], height - 1),
+            &self.subtree_root(&leaves[split..], height - 1),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mev::{Bundle, BundleHeader};
+
+    #[test]
+    fn test_commitment_tree_proofs() {
+        let leaves = [
+            (1u64, B256::with_last_byte(1)),
+            (2, B256::with_last_byte(2)),
+            (19_000_000, B256::with_last_byte(3)),
+        ];
+        let tree = CommitmentTree::new(leaves);
+        let root = tree.root();
+
+        for (block, leaf) in leaves {
+            assert!(CommitmentTree::verify(block, leaf, &tree.proof(block), root));
+            assert!(!CommitmentTree::verify(
+                block,
+                B256::with_last_byte(9),
+                &tree.proof(block),
+                root
+            ));
+        }
+
+        // a block that isn't in the tree proves as empty
+        assert!(CommitmentTree::verify(3, B256::ZERO, &tree.proof(3), root));
+        assert_ne!(CommitmentTree::new(leaves[..2].to_vec()).root(), root);
+    }
+
+    #[test]
+    fn test_bundle_order_independent() {
+        let bundle = |tx_index| Bundle {
+            header: BundleHeader { tx_index, ..Default::default() },
+            data:   Default::default(),
+        };
+        let mut block =
+            MevBlockWithClassified { block: Default::default(), mev: vec![bundle(0), bundle(1)] };
+        let commitment = BlockCommitment::new(&block).unwrap();
+
+        block.mev.reverse();
+        assert_eq!(BlockCommitment::new(&block).unwrap(), commitment);
+        assert_eq!(commitment.bundle_count, 2);
+    }
+}
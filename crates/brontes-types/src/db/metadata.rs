@@ -2,6 +2,7 @@ use alloy_primitives::{Address, TxHash, U256};
 use clickhouse::Row;
 use malachite::{num::basic::traits::Zero, Rational};
 use redefined::Redefined;
+use reth_primitives::Withdrawal;
 use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
 use serde::Serialize;
 use serde_with::serde_as;
@@ -18,7 +19,8 @@ use crate::{
     implement_table_value_codecs_with_zc,
     pair::Pair,
     serde_utils::{option_addresss, u256, vec_txhash},
-    FastHashSet,
+    state_diff::TxStateDiff,
+    FastHashMap, FastHashSet,
 };
 #[allow(unused_imports)]
 use crate::{db::cex::CexExchange, normalized_actions::NormalizedSwap};
@@ -121,6 +123,8 @@ impl Metadata {
     }
 }
 
+const GWEI_TO_WEI: u128 = 1_000_000_000;
+
 /// Block Metadata
 #[derive(Debug, Clone, Default)]
 pub struct BlockMetadata {
@@ -135,6 +139,12 @@ pub struct BlockMetadata {
     pub eth_prices:             Rational,
     /// Tx
     pub private_flow:           FastHashSet<TxHash>,
+    /// Beacon chain withdrawals processed at the end of the block
+    pub withdrawals:            Vec<Withdrawal>,
+    /// Exact per transaction state changes, only present when the tracing
+    /// provider supports re-executing the block and profit verification is
+    /// enabled
+    pub state_diffs:            FastHashMap<TxHash, TxStateDiff>,
 }
 
 impl BlockMetadata {
@@ -160,9 +170,29 @@ impl BlockMetadata {
             proposer_mev_reward,
             private_flow,
             block_timestamp,
+            withdrawals: Vec::new(),
+            state_diffs: FastHashMap::default(),
         }
     }
 
+    /// Total amount in wei withdrawn to the given address in this block.
+    ///
+    /// Withdrawals are paid out by the consensus layer so they aren't a
+    /// payment from anyone in the block, but they do show up in the balance
+    /// of the recipient. When the fee recipient is also a validator
+    /// withdrawal address this has to be netted out of its balance change.
+    pub fn withdrawals_to(&self, address: Address) -> u128 {
+        self.withdrawals
+            .iter()
+            .filter(|withdrawal| withdrawal.address == address)
+            .map(|withdrawal| withdrawal.amount as u128 * GWEI_TO_WEI)
+            .sum()
+    }
+
+    pub fn state_diff(&self, tx_hash: TxHash) -> Option<&TxStateDiff> {
+        self.state_diffs.get(&tx_hash)
+    }
+
     pub fn microseconds_block_timestamp(&self) -> u64 {
         self.block_timestamp * 1_000_000
     }
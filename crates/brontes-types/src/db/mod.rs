@@ -3,12 +3,15 @@ use std::fmt::Debug;
 use ::clickhouse::{DbRow, InsertRow};
 pub mod address_metadata;
 pub mod address_to_protocol_info;
+pub mod arb_index;
+pub mod audit;
 
 #[rustfmt::skip]
 pub mod block_analysis;
 pub mod block_times;
 pub mod builder;
 pub mod cex;
+pub mod commitment;
 
 pub mod clickhouse;
 pub mod clickhouse_serde;
@@ -56,6 +56,7 @@ impl Serialize for TransactionRoot {
                 self.gas_details.priority_fee,
                 self.gas_details.gas_used,
                 self.gas_details.effective_gas_price,
+                self.gas_details.blob_gas_paid,
             ),
         )?;
 
@@ -157,6 +158,17 @@ pub enum ActionKind {
     NewPool,
     PoolConfigUpdate,
     Aggregator,
+    Lending,
+    CollateralSwap,
+    TwammOrder,
+    Wrap,
+    Redemption,
+    Stake,
+    NftTrade,
+    NftLoan,
+    NftTransfer,
+    Governance,
+    Approval,
     Revert,
 }
 
@@ -178,6 +190,17 @@ impl From<&Action> for ActionKind {
             Action::NewPool(_) => ActionKind::NewPool,
             Action::PoolConfigUpdate(_) => ActionKind::PoolConfigUpdate,
             Action::Aggregator(_) => ActionKind::Aggregator,
+            Action::Lending(_) => ActionKind::Lending,
+            Action::CollateralSwap(_) => ActionKind::CollateralSwap,
+            Action::TwammOrder(_) => ActionKind::TwammOrder,
+            Action::Wrap(_) => ActionKind::Wrap,
+            Action::Redemption(_) => ActionKind::Redemption,
+            Action::Stake(_) => ActionKind::Stake,
+            Action::NftTrade(_) => ActionKind::NftTrade,
+            Action::NftLoan(_) => ActionKind::NftLoan,
+            Action::NftTransfer(_) => ActionKind::NftTransfer,
+            Action::Governance(_) => ActionKind::Governance,
+            Action::Approval(_) => ActionKind::Approval,
             Action::Revert => ActionKind::Revert,
         }
     }
@@ -1,10 +1,12 @@
 use std::{
     fmt::Display,
     ops::{Deref, DerefMut},
+    str::FromStr,
 };
 
 use alloy_primitives::Address;
 use clickhouse::{DbRow, Row};
+use malachite::Rational;
 use redefined::{self_convert_redefined, Redefined};
 use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
 use serde::{ser::SerializeStruct, Deserialize, Serialize};
@@ -15,6 +17,7 @@ use crate::{
     db::redefined_types::primitives::AddressRedefined,
     implement_table_value_codecs_with_zc,
     serde_utils::addresss,
+    ToFloatNearest,
 };
 
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Deserialize, Redefined)]
@@ -127,3 +130,56 @@ impl TokenInfo {
 
 self_convert_redefined!(TokenInfo);
 implement_table_value_codecs_with_zc!(TokenInfo);
+
+/// The share of every transfer a fee on transfer token keeps for itself, in
+/// basis points of the amount sent. Recorded by the classifier when a transfer
+/// delivers less than was sent, or by hand through `db insert` for tokens
+/// known to take a fee
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct TransferFee {
+    pub fee_bps: u16,
+}
+
+implement_table_value_codecs_with_zc!(TransferFeeRedefined);
+
+impl TransferFee {
+    /// The fee of a transfer of `sent` where `fee` never arrived
+    pub fn observed(sent: &Rational, fee: &Rational) -> Self {
+        if *sent == Rational::from(0) {
+            return Self::default()
+        }
+
+        let fee_bps = (fee * Rational::from(10_000) / sent).to_float().round();
+        Self { fee_bps: fee_bps.clamp(0.0, 10_000.0) as u16 }
+    }
+}
+
+/// Parses the fee in basis points, e.g `300` for a 3% fee
+impl FromStr for TransferFee {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fee_bps: u16 = s.trim().parse()?;
+        if fee_bps > 10_000 {
+            eyre::bail!("transfer fee of {} bps is over 100%", fee_bps)
+        }
+
+        Ok(Self { fee_bps })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transfer_fee() {
+        let fee = TransferFee::observed(&Rational::from(200), &Rational::from(6));
+        assert_eq!(fee.fee_bps, 300);
+        assert_eq!(TransferFee::observed(&Rational::from(0), &Rational::from(1)).fee_bps, 0);
+
+        assert_eq!("300".parse::<TransferFee>().unwrap(), fee);
+        assert!("10001".parse::<TransferFee>().is_err());
+    }
+}
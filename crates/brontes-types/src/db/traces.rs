@@ -46,6 +46,7 @@ pub struct TxTraceRedefined {
     pub tx_hash:         FixedBytesRedefined<32>,
     pub gas_used:        u128,
     pub effective_price: u128,
+    pub blob_gas_paid:   u128,
     pub tx_index:        u64,
     // False if the transaction reverted
     pub is_success:      bool,
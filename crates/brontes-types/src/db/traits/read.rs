@@ -2,10 +2,17 @@ use alloy_primitives::Address;
 
 use crate::{
     db::{
-        address_metadata::AddressMetadata, address_to_protocol_info::ProtocolInfo,
-        builder::BuilderInfo, cex::trades::CexTradeMap, dex::DexQuotes, metadata::Metadata,
-        mev_block::MevBlockWithClassified, searcher::SearcherInfo,
-        token_info::TokenInfoWithAddress,
+        address_metadata::AddressMetadata,
+        address_to_protocol_info::{ProtocolInfo, ProtocolInfoHistory},
+        arb_index::PairArbSummary,
+        builder::{BuilderInfo, BuilderRevenue},
+        cex::{trades::CexTradeMap, CexTokenListings},
+        commitment::BlockCommitment,
+        dex::DexQuotes,
+        metadata::Metadata,
+        mev_block::MevBlockWithClassified,
+        searcher::SearcherInfo,
+        token_info::{TokenInfoWithAddress, TransferFee},
     },
     pair::Pair,
     structured_trace::TxTrace,
@@ -95,6 +102,10 @@ pub trait LibmdbxReader: Send + Sync + Unpin + 'static {
 
     fn fetch_all_builder_info(&self) -> eyre::Result<Vec<(Address, BuilderInfo)>>;
 
+    /// What searchers have paid the builder so far, `None` if it hasn't built
+    /// a block with mev yet
+    fn try_fetch_builder_payments(&self, builder: Address) -> eyre::Result<Option<BuilderRevenue>>;
+
     fn get_metadata(&self, block_num: u64, quote_asset: Address) -> eyre::Result<Metadata>;
 
     fn get_cex_trades(&self, block: u64) -> eyre::Result<CexTradeMap>;
@@ -104,6 +115,9 @@ pub trait LibmdbxReader: Send + Sync + Unpin + 'static {
 
     fn fetch_all_address_metadata(&self) -> eyre::Result<Vec<(Address, AddressMetadata)>>;
 
+    /// The venues listing the token, `None` when no listings are known for it
+    fn try_fetch_cex_listings(&self, token: Address) -> eyre::Result<Option<CexTokenListings>>;
+
     fn get_dex_quotes(&self, block: u64) -> eyre::Result<DexQuotes>;
 
     fn try_fetch_token_info(&self, address: Address) -> eyre::Result<TokenInfoWithAddress>;
@@ -112,6 +126,10 @@ pub trait LibmdbxReader: Send + Sync + Unpin + 'static {
         self.try_fetch_token_info(address).map(|info| info.decimals)
     }
 
+    /// The fee the token takes of its transfers, `None` unless it is known to
+    /// be a fee on transfer token
+    fn try_fetch_transfer_fee(&self, token: Address) -> eyre::Result<Option<TransferFee>>;
+
     fn try_fetch_mev_blocks(
         &self,
         start_block: Option<u64>,
@@ -123,6 +141,21 @@ pub trait LibmdbxReader: Send + Sync + Unpin + 'static {
         start_block: Option<u64>,
     ) -> eyre::Result<Vec<MevBlockWithClassified>>;
 
+    /// The pair arb index ranges starting in the block range, keyed by their
+    /// start block
+    fn fetch_pair_arb_index(
+        &self,
+        start_block: Option<u64>,
+        end_block: u64,
+    ) -> eyre::Result<Vec<(u64, PairArbSummary)>>;
+
+    /// The result commitments of the blocks in the range
+    fn fetch_block_commitments(
+        &self,
+        start_block: Option<u64>,
+        end_block: u64,
+    ) -> eyre::Result<Vec<(u64, BlockCommitment)>>;
+
     fn protocols_created_before(
         &self,
         start_block: u64,
@@ -140,17 +173,50 @@ pub trait LibmdbxReader: Send + Sync + Unpin + 'static {
 
     fn get_protocol_details(&self, address: Address) -> eyre::Result<ProtocolInfo>;
 
+    /// the mappings the address had before its current one
+    fn get_protocol_history(&self, address: Address) -> eyre::Result<ProtocolInfoHistory>;
+
+    /// returns the protocol details that were valid for the address at the
+    /// given block. If the current mapping starts after the block, we look for
+    /// a replaced mapping that covers it. As not all mappings have an accurate
+    /// init block, the current mapping is used when no replaced one matches.
+    /// Mappings are only replaced by newer ones, so the history isn't read for
+    /// addresses without a current mapping
+    fn get_protocol_details_at(&self, address: Address, block: u64) -> eyre::Result<ProtocolInfo> {
+        let current = self.get_protocol_details(address)?;
+        if current.init_block <= block {
+            return Ok(current)
+        }
+
+        Ok(self
+            .get_protocol_history(address)
+            .ok()
+            .and_then(|history| history.at_block(block).cloned())
+            .unwrap_or(current))
+    }
+
+    fn get_protocol_at(&self, address: Address, block: u64) -> eyre::Result<Protocol> {
+        self.get_protocol_details_at(address, block)
+            .map(|res| res.protocol)
+    }
+
     /// returns protocol details with the tokens sorted from smallest to
     /// biggest. This is needed as for some reason the tokens in the
     /// database for a given protocol don't seems to always be ordered
     /// correctly
     fn get_protocol_details_sorted(&self, address: Address) -> eyre::Result<ProtocolInfo> {
-        self.get_protocol_details(address).map(|mut info| {
-            if info.token0 > info.token1 {
-                std::mem::swap(&mut info.token0, &mut info.token1)
-            }
-            info
-        })
+        self.get_protocol_details(address).map(ProtocolInfo::sorted)
+    }
+
+    /// [`LibmdbxReader::get_protocol_details_sorted`] for the mapping that was
+    /// valid at the given block
+    fn get_protocol_details_sorted_at(
+        &self,
+        address: Address,
+        block: u64,
+    ) -> eyre::Result<ProtocolInfo> {
+        self.get_protocol_details_at(address, block)
+            .map(ProtocolInfo::sorted)
     }
 
     fn load_trace(&self, block_num: u64) -> eyre::Result<Vec<TxTrace>>;
@@ -3,8 +3,13 @@ use futures::Future;
 
 use crate::{
     db::{
-        address_metadata::AddressMetadata, block_analysis::BlockAnalysis, builder::BuilderInfo,
-        dex::DexQuotes, searcher::SearcherInfo,
+        address_metadata::AddressMetadata,
+        block_analysis::BlockAnalysis,
+        builder::{BuilderInfo, BuilderRevenue},
+        cex::{quotes::CexPriceMap, trades::CexTradeMap},
+        dex::DexQuotes,
+        searcher::SearcherInfo,
+        token_info::TransferFee,
     },
     mev::{Bundle, MevBlock},
     normalized_actions::Action,
@@ -43,6 +48,37 @@ pub trait DBWriter: Send + Unpin + 'static {
         self.inner().write_token_info(address, decimals, symbol)
     }
 
+    /// writes token info for a token whose decimals couldn't be read on chain
+    /// and were inferred instead
+    fn write_inferred_token_info(
+        &self,
+        address: Address,
+        decimals: u8,
+        symbol: String,
+    ) -> impl Future<Output = eyre::Result<()>> + Send {
+        self.inner()
+            .write_inferred_token_info(address, decimals, symbol)
+    }
+
+    /// flags the token as taking a fee on its transfers
+    fn write_transfer_fee(
+        &self,
+        token: Address,
+        fee: TransferFee,
+    ) -> impl Future<Output = eyre::Result<()>> + Send {
+        self.inner().write_transfer_fee(token, fee)
+    }
+
+    /// writes the cex quotes and trades of a block collected from a live feed
+    fn write_cex_data(
+        &self,
+        block_number: u64,
+        quotes: CexPriceMap,
+        trades: CexTradeMap,
+    ) -> impl Future<Output = eyre::Result<()>> + Send {
+        self.inner().write_cex_data(block_number, quotes, trades)
+    }
+
     fn save_mev_blocks(
         &self,
         block_number: u64,
@@ -52,6 +88,17 @@ pub trait DBWriter: Send + Unpin + 'static {
         self.inner().save_mev_blocks(block_number, block, mev)
     }
 
+    /// writes the results of an inspector re-run to the staging table
+    fn save_mev_blocks_staging(
+        &self,
+        block_number: u64,
+        block: MevBlock,
+        mev: Vec<Bundle>,
+    ) -> impl Future<Output = eyre::Result<()>> + Send {
+        self.inner()
+            .save_mev_blocks_staging(block_number, block, mev)
+    }
+
     fn write_searcher_info(
         &self,
         eoa_address: Address,
@@ -90,6 +137,16 @@ pub trait DBWriter: Send + Unpin + 'static {
             .write_builder_info(builder_address, builder_info)
     }
 
+    /// overwrites the builder's payment totals
+    fn write_builder_payments(
+        &self,
+        builder_address: Address,
+        revenue: BuilderRevenue,
+    ) -> impl Future<Output = eyre::Result<()>> + Send {
+        self.inner()
+            .write_builder_payments(builder_address, revenue)
+    }
+
     fn write_address_meta(
         &self,
         address: Address,
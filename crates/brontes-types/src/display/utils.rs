@@ -105,6 +105,52 @@ pub fn display_sandwich(bundle: &Bundle, f: &mut fmt::Formatter) -> fmt::Result
                 if let Some(gas_details) = victim_gas_details {
                     gas_details.pretty_print_with_spaces(f, 16)?;
                 }
+
+                // Victim slippage
+                if let Some(slippage) = sandwich_data
+                    .victim_slippage
+                    .iter()
+                    .find(|s| &s.tx_hash == tx_hash)
+                {
+                    writeln!(f, "          - {}:", "Slippage".bright_blue())?;
+                    writeln!(
+                        f,
+                        "                {}: {} (realized {})",
+                        if slippage.exact_in { "Min out" } else { "Max in" },
+                        slippage.limit,
+                        slippage.realized
+                    )?;
+                    writeln!(
+                        f,
+                        "                Remaining: {:.2}%",
+                        slippage.remaining_slippage * 100.0
+                    )?;
+                    if let Some(consumed) = slippage.consumed_slippage {
+                        writeln!(
+                            f,
+                            "                Consumed by frontrun: {:.2}%",
+                            consumed * 100.0
+                        )?;
+                    }
+                }
+
+                // Victim loss
+                for loss in sandwich_data
+                    .victim_losses
+                    .iter()
+                    .filter(|l| &l.tx_hash == tx_hash)
+                {
+                    writeln!(
+                        f,
+                        "          - {}: {} of {:?} (${:.2}, {:.2}% worse than before the \
+                         frontrun)",
+                        "Loss".bright_blue(),
+                        loss.token_amount_lost,
+                        loss.token,
+                        loss.amount_lost_usd,
+                        loss.price_degradation * 100.0
+                    )?;
+                }
             }
         }
     }
@@ -423,6 +469,20 @@ pub fn display_atomic_backrun(bundle: &Bundle, f: &mut fmt::Formatter) -> fmt::R
     for (i, swap) in atomic_backrun_data.swaps.iter().enumerate() {
         writeln!(f, "    {}: {}", format!(" - {}", i + 1).green(), swap)?;
     }
+    if !atomic_backrun_data.token_path.is_empty() {
+        writeln!(
+            f,
+            " - {}: {} pools, {}",
+            "Path".bright_blue(),
+            atomic_backrun_data.pool_path.len(),
+            atomic_backrun_data
+                .token_path
+                .iter()
+                .map(|token| format!("{:?}", token))
+                .collect::<Vec<_>>()
+                .join(" -> ")
+        )?;
+    }
 
     writeln!(f, " - {}:", "Gas Details".bright_blue())?;
     atomic_backrun_data
@@ -527,6 +587,282 @@ pub fn display_liquidation(bundle: &Bundle, f: &mut fmt::Formatter) -> fmt::Resu
         .gas_details
         .pretty_print_with_spaces(f, 8)?;
 
+    // Competition Section
+    let competition = &liquidation_data.competition;
+    if !competition.competitor_tx_hashes.is_empty() {
+        writeln!(f, "\n - {}:", "Competition".bright_blue())?;
+        writeln!(f, "        - Competing Searchers: {}", competition.competing_searchers)?;
+        for tx_hash in &competition.competitor_tx_hashes {
+            writeln!(f, "        - Reverted Attempt: {}", format_etherscan_url(tx_hash))?;
+        }
+        writeln!(
+            f,
+            "        - Bribe Per Gas: {:.2} gwei vs {:.2} gwei ({:.2}x)",
+            competition.winner_bribe_per_gas,
+            competition.max_competitor_bribe_per_gas,
+            competition.bribe_escalation
+        )?;
+    }
+
+    // Profitability Section
+    writeln!(f, "\n{}\n", "Profitability".bright_yellow().underline())?;
+    writeln!(
+        f,
+        " - {}: {}",
+        "Bundle Profit (USD)".bright_white(),
+        format_profit(bundle.header.profit_usd)
+            .to_string()
+            .bright_white()
+    )?;
+    writeln!(
+        f,
+        " - {}: {}\n",
+        "Bribe (USD)".bright_white(),
+        format_bribe(bundle.header.bribe_usd)
+            .to_string()
+            .bright_red()
+    )?;
+
+    bundle
+        .header
+        .balance_deltas
+        .iter()
+        .for_each(|tx_delta| writeln!(f, "{}", tx_delta).expect("Failed to write balance deltas"));
+    Ok(())
+}
+
+pub fn display_nft_mev(bundle: &Bundle, f: &mut fmt::Formatter) -> fmt::Result {
+    let ascii_header = indoc! {r#"
+
+         _   _  __ _     ___  ___
+        | \ | |/ _| |    |  \/  |
+        |  \| | |_| |_   | .  . | _____   __
+        | . ` |  _| __|  | |\/| |/ _ \ \ / /
+        | |\  | | | |_   | |  | |  __/\ V /
+        \_| \_/_|  \__|  \_|  |_/\___| \_/
+
+    "#};
+
+    for line in ascii_header.lines() {
+        writeln!(f, "{}", line.bright_red())?;
+    }
+
+    let nft_data = match &bundle.data {
+        BundleData::NftMev(data) => data,
+        _ => panic!("Wrong bundle type"),
+    };
+
+    // MEV Bot Details
+    writeln!(f, "\n{}: \n", "Transaction Details".bold().underline().bright_yellow())?;
+    writeln!(f, "   - EOA: {}", bundle.header.eoa)?;
+
+    match bundle.header.mev_contract {
+        Some(contract) => {
+            writeln!(f, "   - Mev Contract: {}", contract)?;
+        }
+        None => {
+            writeln!(f, "   - Mev Contract: None")?;
+        }
+    }
+    writeln!(f, "   - Kind: {}", nft_data.kind)?;
+    for tx_hash in &nft_data.tx_hashes {
+        writeln!(f, "   - Transaction: {}", format_etherscan_url(tx_hash))?;
+    }
+
+    // Trades Section
+    writeln!(f, "\n{}\n", "Nfts".bright_yellow().underline())?;
+    for (i, (acquisition, sale)) in nft_data
+        .acquisitions
+        .iter()
+        .zip(&nft_data.sales)
+        .enumerate()
+    {
+        writeln!(
+            f,
+            " - {}: {} #{}",
+            format!("Nft {}", i + 1).bright_blue(),
+            acquisition.collection,
+            acquisition.token_id
+        )?;
+        if acquisition.counterparty == Address::ZERO {
+            writeln!(f, "        - Minted at: {}", acquisition.marketplace)?;
+        } else {
+            writeln!(
+                f,
+                "        - Bought on {} for {} {} from {}",
+                acquisition.protocol,
+                acquisition.price,
+                acquisition.payment_token,
+                acquisition.counterparty
+            )?;
+        }
+        writeln!(
+            f,
+            "        - Sold on {} for {} {} to {}",
+            sale.protocol, sale.price, sale.payment_token, sale.counterparty
+        )?;
+    }
+
+    // Profitability Section
+    writeln!(f, "\n{}\n", "Profitability".bright_yellow().underline())?;
+    writeln!(
+        f,
+        " - {}: {}",
+        "Bundle Profit (USD)".bright_white(),
+        format_profit(bundle.header.profit_usd)
+            .to_string()
+            .bright_white()
+    )?;
+    writeln!(
+        f,
+        " - {}: {}\n",
+        "Bribe (USD)".bright_white(),
+        format_bribe(bundle.header.bribe_usd)
+            .to_string()
+            .bright_red()
+    )?;
+
+    bundle
+        .header
+        .balance_deltas
+        .iter()
+        .for_each(|tx_delta| writeln!(f, "{}", tx_delta).expect("Failed to write balance deltas"));
+    Ok(())
+}
+
+pub fn display_oracle_manipulation(bundle: &Bundle, f: &mut fmt::Formatter) -> fmt::Result {
+    let ascii_header = indoc! {r#"
+
+         _____                _
+        |  _  |              | |
+        | | | |_ __ __ _  ___| | ___
+        | | | | '__/ _` |/ __| |/ _ \
+        \ \_/ / | | (_| | (__| |  __/
+         \___/|_|  \__,_|\___|_|\___|
+
+    "#};
+
+    for line in ascii_header.lines() {
+        writeln!(f, "{}", line.bright_red())?;
+    }
+
+    let oracle_data = match &bundle.data {
+        BundleData::OracleManipulation(data) => data,
+        _ => panic!("Wrong bundle type"),
+    };
+
+    // MEV Bot Details
+    writeln!(f, "\n{}: \n", "Transaction Details".bold().underline().bright_yellow())?;
+    writeln!(f, "   - EOA: {}", bundle.header.eoa)?;
+
+    match bundle.header.mev_contract {
+        Some(contract) => {
+            writeln!(f, "   - Mev Contract: {}", contract)?;
+        }
+        None => {
+            writeln!(f, "   - Mev Contract: None")?;
+        }
+    }
+
+    // Manipulation Section
+    writeln!(f, "\n{}\n", "Manipulation".bright_yellow().underline())?;
+    writeln!(
+        f,
+        " - {}: {}",
+        "Transaction".bright_blue(),
+        format_etherscan_url(&oracle_data.manipulation_tx_hash)
+    )?;
+    writeln!(f, " - {}: {}", "Pool".bright_blue(), oracle_data.manipulated_pool)?;
+    writeln!(f, " - {}: {:.2}%", "Price Impact".bright_blue(), oracle_data.price_impact * 100.0)?;
+    for (i, swap) in oracle_data.manipulation_swaps.iter().enumerate() {
+        writeln!(f, "    {}: {}", format!(" - {}", i + 1).green(), swap)?;
+    }
+
+    // Exploit Section
+    writeln!(f, "\n{}\n", "Exploit".bright_yellow().underline())?;
+    writeln!(
+        f,
+        " - {}: {}",
+        "Transaction".bright_blue(),
+        format_etherscan_url(&oracle_data.exploit_tx_hash)
+    )?;
+    writeln!(
+        f,
+        " - {}: {} at {}",
+        "Market".bright_blue(),
+        oracle_data.exploited_protocol,
+        oracle_data.exploited_market
+    )?;
+
+    // Profitability Section
+    writeln!(f, "\n{}\n", "Profitability".bright_yellow().underline())?;
+    writeln!(
+        f,
+        " - {}: {}",
+        "Bundle Profit (USD)".bright_white(),
+        format_profit(bundle.header.profit_usd)
+            .to_string()
+            .bright_white()
+    )?;
+    writeln!(
+        f,
+        " - {}: {}\n",
+        "Bribe (USD)".bright_white(),
+        format_bribe(bundle.header.bribe_usd)
+            .to_string()
+            .bright_red()
+    )?;
+
+    bundle
+        .header
+        .balance_deltas
+        .iter()
+        .for_each(|tx_delta| writeln!(f, "{}", tx_delta).expect("Failed to write balance deltas"));
+    Ok(())
+}
+
+pub fn display_launch_snipe(bundle: &Bundle, f: &mut fmt::Formatter) -> fmt::Result {
+    let ascii_header = indoc! {r#"
+
+         _                            _
+        | |                          | |
+        | |     __ _ _   _ _ __   ___| |__
+        | |    / _` | | | | '_ \ / __| '_ \
+        | |___| (_| | |_| | | | | (__| | | |
+        \_____/\__,_|\__,_|_| |_|\___|_| |_|
+
+    "#};
+
+    for line in ascii_header.lines() {
+        writeln!(f, "{}", line.bright_red())?;
+    }
+
+    let snipe_data = match &bundle.data {
+        BundleData::LaunchSnipe(data) => data,
+        _ => panic!("Wrong bundle type"),
+    };
+
+    // MEV Bot Details
+    writeln!(f, "\n{}: \n", "Transaction Details".bold().underline().bright_yellow())?;
+    writeln!(f, "   - Tx Hash: {}", format_etherscan_url(&snipe_data.tx_hash))?;
+    writeln!(f, "   - EOA: {}", bundle.header.eoa)?;
+    writeln!(f, "   - Sniper: {}", snipe_data.sniper)?;
+
+    // Launch Section
+    writeln!(f, "\n{}\n", "Launch".bright_yellow().underline())?;
+    writeln!(f, " - {}: {}", "Token".bright_blue(), snipe_data.token)?;
+    writeln!(f, " - {}: {} at {}", "Pool".bright_blue(), snipe_data.protocol, snipe_data.pool)?;
+    writeln!(
+        f,
+        " - {}: {} ({} blocks before)",
+        "Created In Block".bright_blue(),
+        snipe_data.pool_creation_block,
+        snipe_data.blocks_after_launch()
+    )?;
+    for (i, swap) in snipe_data.swaps.iter().enumerate() {
+        writeln!(f, "    {}: {}", format!(" - {}", i + 1).green(), swap)?;
+    }
+
     // Profitability Section
     writeln!(f, "\n{}\n", "Profitability".bright_yellow().underline())?;
     writeln!(
@@ -764,6 +1100,16 @@ pub fn display_cex_dex(bundle: &Bundle, f: &mut fmt::Formatter) -> fmt::Result {
             pnl.1.clone().to_float()
         )?;
     }
+    writeln!(
+        f,
+        "  - Venues Considered: {}",
+        cex_dex_data
+            .venues_considered
+            .iter()
+            .map(|ex| ex.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    )?;
 
     writeln!(f, "\n----------------------------------------")?;
     writeln!(f, "{}", "Arb Details".bold().red().underline())?;
@@ -793,6 +1139,25 @@ pub fn display_cex_dex(bundle: &Bundle, f: &mut fmt::Formatter) -> fmt::Result {
         } else {
             writeln!(f, "   - Error: No per exchange arb details available for swap {}", i + 1)?;
         }
+
+        let markouts = cex_dex_data
+            .markout_details
+            .iter()
+            .filter(|details| details.swap_idx == i as u64)
+            .collect::<Vec<_>>();
+        if !markouts.is_empty() {
+            writeln!(f, "   - {}:", "Markouts".purple().bold().underline())?;
+            for details in markouts {
+                writeln!(
+                    f,
+                    "     +{:.1}s: Price: {:.8}, Taker Fee: {:.2} bps, PnL: {:.6}",
+                    details.markout_us as f64 / 1_000_000.0,
+                    details.cex_price.clone().to_float(),
+                    details.taker_fee.clone().to_float() * 10_000.0,
+                    details.pnl.clone().to_float()
+                )?;
+            }
+        }
     }
 
     // Gas Details
@@ -34,6 +34,8 @@ pub mod serde_utils;
 pub mod unordered_buffer_map;
 pub mod unzip_either;
 pub use queries::make_call_request;
+pub mod simulated_bundle;
+pub mod state_diff;
 pub mod structured_trace;
 pub mod traits;
 pub mod tree;
@@ -44,3 +46,5 @@ pub mod protocol;
 pub use protocol::*;
 pub mod channel_alerts;
 pub use channel_alerts::*;
+pub mod pipeline_events;
+pub use pipeline_events::*;
@@ -0,0 +1,205 @@
+//! Reconstructs the priority gas auction of a block. Every transaction, landed
+//! or reverted, is a bid of what it paid the builder per unit of gas. A mev
+//! transaction outbid the competitors with a lower bid that went after the same
+//! contracts, competitors being the reverted transactions and the mev of other
+//! searchers.
+use std::fmt;
+
+use alloy_primitives::{Address, B256};
+
+use super::{Bundle, MevType};
+use crate::{
+    normalized_actions::NormalizedAction, tree::BlockTree, FastHashMap, FastHashSet, GasDetails,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BidKind {
+    Mev(MevType),
+    Reverted,
+    Other,
+}
+
+impl fmt::Display for BidKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BidKind::Mev(mev_type) => write!(f, "{}", mev_type),
+            BidKind::Reverted => write!(f, "Reverted"),
+            BidKind::Other => write!(f, "-"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuctionBid {
+    pub tx_hash:       B256,
+    pub position:      usize,
+    pub from:          Address,
+    /// Priority fee and coinbase transfer per gas, in gwei
+    pub bribe_per_gas: f64,
+    pub kind:          BidKind,
+    /// The competitors this bid beat, only set for mev
+    pub outbid:        Vec<B256>,
+    /// Contracts the transaction called
+    contracts:         FastHashSet<Address>,
+}
+
+impl AuctionBid {
+    pub fn new(
+        tx_hash: B256,
+        position: usize,
+        from: Address,
+        gas_details: &GasDetails,
+        kind: BidKind,
+        contracts: impl IntoIterator<Item = Address>,
+    ) -> Self {
+        Self {
+            tx_hash,
+            position,
+            from,
+            bribe_per_gas: gas_details.bribe_per_gas(),
+            kind,
+            outbid: vec![],
+            contracts: contracts.into_iter().collect(),
+        }
+    }
+
+    fn competes_with(&self, other: &AuctionBid) -> bool {
+        !matches!(other.kind, BidKind::Other)
+            && self.from != other.from
+            && !self.contracts.is_disjoint(&other.contracts)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockAuction {
+    pub block_number: u64,
+    /// Bids from highest to lowest, ties in block order
+    pub bids:         Vec<AuctionBid>,
+}
+
+impl BlockAuction {
+    pub fn new<V: NormalizedAction>(tree: &BlockTree<V>, bundles: &[Bundle]) -> Self {
+        let mev_txs = bundles
+            .iter()
+            .flat_map(|bundle| {
+                bundle
+                    .data
+                    .mev_transaction_hashes()
+                    .into_iter()
+                    .map(|tx_hash| (tx_hash, bundle.header.mev_type))
+            })
+            .collect::<FastHashMap<_, _>>();
+
+        let landed = tree.tx_roots.iter().map(|root| {
+            let kind = mev_txs
+                .get(&root.tx_hash)
+                .map(|mev_type| BidKind::Mev(*mev_type))
+                .unwrap_or(BidKind::Other);
+
+            AuctionBid::new(
+                root.tx_hash,
+                root.position,
+                root.get_from_address(),
+                &root.gas_details,
+                kind,
+                root.head.all_sub_addresses(),
+            )
+        });
+        let reverted = tree.reverted_txs.iter().map(|tx| {
+            AuctionBid::new(
+                tx.tx_hash,
+                tx.position,
+                tx.from,
+                &tx.gas_details,
+                BidKind::Reverted,
+                tx.calls.iter().map(|(to, _)| *to),
+            )
+        });
+
+        Self::from_bids(tree.header.number, landed.chain(reverted).collect())
+    }
+
+    pub fn from_bids(block_number: u64, mut bids: Vec<AuctionBid>) -> Self {
+        bids.sort_by(|a, b| {
+            b.bribe_per_gas
+                .total_cmp(&a.bribe_per_gas)
+                .then(a.position.cmp(&b.position))
+        });
+
+        for i in 0..bids.len() {
+            if !matches!(bids[i].kind, BidKind::Mev(_)) {
+                continue
+            }
+
+            let outbid = bids[i + 1..]
+                .iter()
+                .filter(|other| bids[i].competes_with(other))
+                .map(|other| other.tx_hash)
+                .collect();
+            bids[i].outbid = outbid;
+        }
+
+        Self { block_number, bids }
+    }
+
+    /// Whether any mev in the block had to beat a competitor
+    pub fn has_competition(&self) -> bool {
+        self.bids.iter().any(|bid| !bid.outbid.is_empty())
+    }
+}
+
+impl fmt::Display for BlockAuction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "block {} auction, {} bids", self.block_number, self.bids.len())?;
+        for (rank, bid) in self.bids.iter().enumerate() {
+            writeln!(
+                f,
+                "{:>4} pos {:>4} {:?} {:>12.4} gwei {}",
+                rank, bid.position, bid.tx_hash, bid.bribe_per_gas, bid.kind
+            )?;
+            for tx_hash in &bid.outbid {
+                writeln!(f, "{:>14} outbid {:?}", "", tx_hash)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bid(n: u8, priority_fee: u128, kind: BidKind, contracts: &[u8]) -> AuctionBid {
+        AuctionBid::new(
+            B256::with_last_byte(n),
+            n as usize,
+            Address::with_last_byte(n),
+            &GasDetails { priority_fee, gas_used: 100_000, ..Default::default() },
+            kind,
+            contracts.iter().map(|c| Address::with_last_byte(*c)),
+        )
+    }
+
+    #[test]
+    fn test_mev_outbids_competitors_on_same_contracts() {
+        let auction = BlockAuction::from_bids(
+            1,
+            vec![
+                bid(1, 1_000_000_000, BidKind::Reverted, &[10]),
+                bid(2, 5_000_000_000, BidKind::Mev(MevType::AtomicArb), &[10, 11]),
+                bid(3, 2_000_000_000, BidKind::Other, &[10]),
+                bid(4, 3_000_000_000, BidKind::Reverted, &[12]),
+                bid(5, 6_000_000_000, BidKind::Reverted, &[11]),
+            ],
+        );
+
+        let order = auction.bids.iter().map(|b| b.position).collect::<Vec<_>>();
+        assert_eq!(order, vec![5, 2, 4, 3, 1]);
+
+        // the higher reverted bid isn't outbid, the lower one on another contract
+        // and the user transaction aren't competitors
+        assert_eq!(auction.bids[1].outbid, vec![B256::with_last_byte(1)]);
+        assert!(auction.has_competition());
+    }
+}
@@ -9,14 +9,14 @@ use ahash::HashSet;
 #[allow(unused)]
 use clickhouse::fixed_string::FixedString;
 use redefined::{self_convert_redefined, Redefined};
-use reth_primitives::B256;
+use reth_primitives::{Address, B256};
 use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 
 use super::{Mev, MevType};
 use crate::{
-    db::redefined_types::primitives::B256Redefined,
+    db::redefined_types::primitives::{AddressRedefined, B256Redefined},
     normalized_actions::{ClickhouseVecNormalizedSwap, NormalizedSwap, NormalizedSwapRedefined},
     GasDetails, Protocol,
 };
@@ -33,6 +33,44 @@ pub struct AtomicArb {
     pub gas_details:  GasDetails,
     #[redefined(same_fields)]
     pub arb_type:     AtomicArbType,
+    /// The pools the arb traded through, in the order it traded them
+    pub pool_path:    Vec<Address>,
+    /// The tokens the arb passed through, starting with the one it started
+    /// with. A closed cycle ends with that token again
+    pub token_path:   Vec<Address>,
+}
+
+impl AtomicArb {
+    /// Follows the swaps from the first one's input token through each swap
+    /// taking the previous one's output, returning the pools and tokens along
+    /// the way as `(pool_path, token_path)`. Swaps that don't continue the
+    /// path, like one leg of an order split across pools, are left out
+    pub fn token_cycle(swaps: &[NormalizedSwap]) -> (Vec<Address>, Vec<Address>) {
+        let Some(first) = swaps.first() else { return (vec![], vec![]) };
+
+        let mut used = vec![false; swaps.len()];
+        let mut pool_path = Vec::new();
+        let mut token_path = vec![first.token_in.address];
+
+        while let Some(idx) = (0..swaps.len())
+            .find(|&idx| !used[idx] && Some(&swaps[idx].token_in.address) == token_path.last())
+        {
+            used[idx] = true;
+            pool_path.push(swaps[idx].pool);
+            token_path.push(swaps[idx].token_out.address);
+
+            if token_path.last() == token_path.first() {
+                break
+            }
+        }
+
+        (pool_path, token_path)
+    }
+
+    /// Whether the path ends with the token it started with
+    pub fn is_closed_cycle(&self) -> bool {
+        self.token_path.len() > 1 && self.token_path.first() == self.token_path.last()
+    }
 }
 /// Represents the different types of atomic arb
 /// A triangle arb is a simple arb that goes from token A -> B -> C -> A
@@ -104,7 +142,7 @@ impl Serialize for AtomicArb {
     where
         S: Serializer,
     {
-        let mut ser_struct = serializer.serialize_struct("AtomicArb", 37)?;
+        let mut ser_struct = serializer.serialize_struct("AtomicArb", 40)?;
         ser_struct.serialize_field("tx_hash", &format!("{:?}", self.tx_hash))?;
         ser_struct.serialize_field("block_number", &self.block_number)?;
         ser_struct.serialize_field("trigger_tx", &format!("{:?}", self.trigger_tx))?;
@@ -120,15 +158,35 @@ impl Serialize for AtomicArb {
         ser_struct.serialize_field("swaps.token_in", &swaps.token_in)?;
         ser_struct.serialize_field("swaps.token_out", &swaps.token_out)?;
         ser_struct.serialize_field("swaps.amount_in", &swaps.amount_in)?;
+        ser_struct.serialize_field("swaps.amount_in_float", &swaps.amount_in_float)?;
         ser_struct.serialize_field("swaps.amount_out", &swaps.amount_out)?;
+        ser_struct.serialize_field("swaps.amount_out_float", &swaps.amount_out_float)?;
+        ser_struct.serialize_field("swaps.protocol", &swaps.protocol)?;
         let gas_details = (
             self.gas_details.coinbase_transfer,
             self.gas_details.priority_fee,
             self.gas_details.gas_used,
             self.gas_details.effective_gas_price,
+            self.gas_details.blob_gas_paid,
         );
         ser_struct.serialize_field("gas_details", &gas_details)?;
         ser_struct.serialize_field("arb_type", &self.arb_type.to_string())?;
+        ser_struct.serialize_field(
+            "pool_path",
+            &self
+                .pool_path
+                .iter()
+                .map(|pool| format!("{:?}", pool))
+                .collect::<Vec<_>>(),
+        )?;
+        ser_struct.serialize_field(
+            "token_path",
+            &self
+                .token_path
+                .iter()
+                .map(|token| format!("{:?}", token))
+                .collect::<Vec<_>>(),
+        )?;
         ser_struct.end()
     }
 }
@@ -145,8 +203,50 @@ impl DbRow for AtomicArb {
         "swaps.token_in",
         "swaps.token_out",
         "swaps.amount_in",
+        "swaps.amount_in_float",
         "swaps.amount_out",
+        "swaps.amount_out_float",
+        "swaps.protocol",
         "gas_details",
         "arb_type",
+        "pool_path",
+        "token_path",
     ];
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn swap(pool: u8, token_in: Address, token_out: Address) -> NormalizedSwap {
+        let mut swap = NormalizedSwap { pool: Address::repeat_byte(pool), ..Default::default() };
+        swap.token_in.address = token_in;
+        swap.token_out.address = token_out;
+        swap
+    }
+
+    #[test]
+    fn test_token_cycle() {
+        let weth = Address::repeat_byte(10);
+        let usdc = Address::repeat_byte(11);
+        let dai = Address::repeat_byte(12);
+
+        // one leg of a split order doesn't continue the path
+        let swaps = [swap(1, weth, usdc), swap(4, weth, usdc), swap(2, usdc, weth)];
+        let (pools, tokens) = AtomicArb::token_cycle(&swaps);
+        assert_eq!(pools, vec![Address::repeat_byte(1), Address::repeat_byte(2)]);
+        assert_eq!(tokens, vec![weth, usdc, weth]);
+
+        // logged out of order, the path follows the tokens
+        let swaps = [swap(1, weth, usdc), swap(3, dai, weth), swap(2, usdc, dai)];
+        let (pool_path, token_path) = AtomicArb::token_cycle(&swaps);
+        assert_eq!(
+            pool_path,
+            vec![Address::repeat_byte(1), Address::repeat_byte(2), Address::repeat_byte(3)]
+        );
+        assert_eq!(token_path, vec![weth, usdc, dai, weth]);
+
+        let arb = AtomicArb { pool_path, token_path, ..Default::default() };
+        assert!(arb.is_closed_cycle());
+    }
+}
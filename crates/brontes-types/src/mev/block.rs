@@ -56,6 +56,10 @@ pub struct MevBlock {
     pub proposer_fee_recipient:      Option<Address>,
     pub proposer_mev_reward:         Option<u128>,
     pub proposer_profit_usd:         Option<f64>,
+    // Consensus layer withdrawals to the builder or proposer fee recipient (in
+    // wei). These are not a payment for the block and are excluded from the
+    // builder & proposer profit
+    pub fee_recipient_withdrawals:   u128,
     pub total_mev_profit_usd:        f64,
     pub possible_mev:                PossibleMevCollection,
 }
@@ -170,6 +174,14 @@ impl fmt::Display for MevBlock {
             )?;
         }
 
+        if self.fee_recipient_withdrawals != 0 {
+            writeln!(
+                f,
+                "  - Fee Recipient Withdrawals (excluded): {:.6} ETH",
+                self.fee_recipient_withdrawals as f64 / 10f64.powf(18.0)
+            )?;
+        }
+
         writeln!(f, "\n{}: {}", "Missed Mev".bold().red().underline(), self.possible_mev)?;
 
         Ok(())
@@ -358,7 +370,7 @@ impl Serialize for MevBlock {
     where
         S: serde::Serializer,
     {
-        let mut ser_struct = serializer.serialize_struct("MevBlock", 33)?;
+        let mut ser_struct = serializer.serialize_struct("MevBlock", 34)?;
 
         ser_struct.serialize_field("block_hash", &format!("{:?}", self.block_hash))?;
         ser_struct.serialize_field("block_number", &self.block_number)?;
@@ -425,6 +437,7 @@ impl Serialize for MevBlock {
         )?;
         ser_struct.serialize_field("proposer_mev_reward", &self.proposer_mev_reward)?;
         ser_struct.serialize_field("proposer_profit_usd", &self.proposer_profit_usd)?;
+        ser_struct.serialize_field("fee_recipient_withdrawals", &self.fee_recipient_withdrawals)?;
         ser_struct.serialize_field("total_mev_profit_usd", &self.total_mev_profit_usd)?;
 
         let mut possible_tx_hashes = Vec::new();
@@ -433,6 +446,7 @@ impl Serialize for MevBlock {
         let mut possible_priority_fees = Vec::new();
         let mut possible_gas_useds = Vec::new();
         let mut possible_effective_gas_prices = Vec::new();
+        let mut possible_blob_gas_paid = Vec::new();
         let mut possible_is_privates = Vec::new();
         let mut possible_trigger_coinbases = Vec::new();
         let mut possible_high_priority_fee = Vec::new();
@@ -448,6 +462,7 @@ impl Serialize for MevBlock {
                         tx.gas_details.priority_fee,
                         tx.gas_details.gas_used,
                         tx.gas_details.effective_gas_price,
+                        tx.gas_details.blob_gas_paid,
                     ),
                     (
                         tx.triggers.is_private,
@@ -460,7 +475,7 @@ impl Serialize for MevBlock {
                 |(
                     hash,
                     idx,
-                    (gas_coinbase, priority_fee, gas_used, effective_gas_price),
+                    (gas_coinbase, priority_fee, gas_used, effective_gas_price, blob_gas_paid),
                     (is_private, trigger_coinbase, high_priority_fee),
                 )| {
                     possible_tx_hashes.push(hash);
@@ -469,6 +484,7 @@ impl Serialize for MevBlock {
                     possible_priority_fees.push(priority_fee);
                     possible_gas_useds.push(gas_used);
                     possible_effective_gas_prices.push(effective_gas_price);
+                    possible_blob_gas_paid.push(blob_gas_paid);
                     possible_is_privates.push(is_private);
                     possible_trigger_coinbases.push(trigger_coinbase);
                     possible_high_priority_fee.push(high_priority_fee);
@@ -488,6 +504,8 @@ impl Serialize for MevBlock {
             "possible_mev.gas_details.effective_gas_price",
             &possible_effective_gas_prices,
         )?;
+        ser_struct
+            .serialize_field("possible_mev.gas_details.blob_gas_paid", &possible_blob_gas_paid)?;
         ser_struct.serialize_field("possible_mev.triggers.is_private", &possible_is_privates)?;
         ser_struct.serialize_field(
             "possible_mev.triggers.coinbase_transfer",
@@ -533,6 +551,7 @@ impl DbRow for MevBlock {
         "proposer_fee_recipient",
         "proposer_mev_reward",
         "proposer_profit_usd",
+        "fee_recipient_withdrawals",
         "total_mev_profit_usd",
         "possible_mev.tx_hash",
         "possible_mev.tx_idx",
@@ -540,6 +559,7 @@ impl DbRow for MevBlock {
         "possible_mev.gas_details.priority_fee",
         "possible_mev.gas_details.gas_used",
         "possible_mev.gas_details.effective_gas_price",
+        "possible_mev.gas_details.blob_gas_paid",
         "possible_mev.triggers.is_private",
         "possible_mev.triggers.coinbase_transfer",
         "possible_mev.triggers.high_priority_fee",
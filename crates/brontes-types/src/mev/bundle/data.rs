@@ -31,6 +31,9 @@ pub enum BundleData {
     CexDexQuote(CexDexQuote),
     CexDex(CexDex),
     Liquidation(Liquidation),
+    NftMev(NftMev),
+    OracleManipulation(OracleManipulation),
+    LaunchSnipe(LaunchSnipe),
     Unknown(SearcherTx),
 }
 
@@ -50,6 +53,9 @@ impl Mev for BundleData {
             BundleData::CexDex(m) => m.mev_type(),
             BundleData::CexDexQuote(m) => m.mev_type(),
             BundleData::Liquidation(m) => m.mev_type(),
+            BundleData::NftMev(m) => m.mev_type(),
+            BundleData::OracleManipulation(m) => m.mev_type(),
+            BundleData::LaunchSnipe(m) => m.mev_type(),
             BundleData::Unknown(m) => m.mev_type(),
         }
     }
@@ -63,6 +69,9 @@ impl Mev for BundleData {
             BundleData::CexDex(m) => m.total_gas_paid(),
             BundleData::CexDexQuote(m) => m.total_gas_paid(),
             BundleData::Liquidation(m) => m.total_gas_paid(),
+            BundleData::NftMev(m) => m.total_gas_paid(),
+            BundleData::OracleManipulation(m) => m.total_gas_paid(),
+            BundleData::LaunchSnipe(m) => m.total_gas_paid(),
             BundleData::Unknown(s) => s.total_gas_paid(),
         }
     }
@@ -76,6 +85,9 @@ impl Mev for BundleData {
             BundleData::CexDex(m) => m.total_priority_fee_paid(base_fee),
             BundleData::CexDexQuote(m) => m.total_priority_fee_paid(base_fee),
             BundleData::Liquidation(m) => m.total_priority_fee_paid(base_fee),
+            BundleData::NftMev(m) => m.total_priority_fee_paid(base_fee),
+            BundleData::OracleManipulation(m) => m.total_priority_fee_paid(base_fee),
+            BundleData::LaunchSnipe(m) => m.total_priority_fee_paid(base_fee),
             BundleData::Unknown(s) => s.total_priority_fee_paid(base_fee),
         }
     }
@@ -89,6 +101,9 @@ impl Mev for BundleData {
             BundleData::CexDex(m) => m.bribe(),
             BundleData::CexDexQuote(m) => m.bribe(),
             BundleData::Liquidation(m) => m.bribe(),
+            BundleData::NftMev(m) => m.bribe(),
+            BundleData::OracleManipulation(m) => m.bribe(),
+            BundleData::LaunchSnipe(m) => m.bribe(),
             BundleData::Unknown(s) => s.bribe(),
         }
     }
@@ -102,6 +117,9 @@ impl Mev for BundleData {
             BundleData::CexDex(m) => m.mev_transaction_hashes(),
             BundleData::CexDexQuote(m) => m.mev_transaction_hashes(),
             BundleData::Liquidation(m) => m.mev_transaction_hashes(),
+            BundleData::NftMev(m) => m.mev_transaction_hashes(),
+            BundleData::OracleManipulation(m) => m.mev_transaction_hashes(),
+            BundleData::LaunchSnipe(m) => m.mev_transaction_hashes(),
             BundleData::Unknown(s) => s.mev_transaction_hashes(),
         }
     }
@@ -115,6 +133,9 @@ impl Mev for BundleData {
             BundleData::CexDex(m) => m.protocols(),
             BundleData::CexDexQuote(m) => m.protocols(),
             BundleData::Liquidation(m) => m.protocols(),
+            BundleData::NftMev(m) => m.protocols(),
+            BundleData::OracleManipulation(m) => m.protocols(),
+            BundleData::LaunchSnipe(m) => m.protocols(),
             BundleData::Unknown(s) => s.protocols(),
         }
     }
@@ -162,6 +183,24 @@ impl From<Liquidation> for BundleData {
     }
 }
 
+impl From<NftMev> for BundleData {
+    fn from(value: NftMev) -> Self {
+        Self::NftMev(value)
+    }
+}
+
+impl From<OracleManipulation> for BundleData {
+    fn from(value: OracleManipulation) -> Self {
+        Self::OracleManipulation(value)
+    }
+}
+
+impl From<LaunchSnipe> for BundleData {
+    fn from(value: LaunchSnipe) -> Self {
+        Self::LaunchSnipe(value)
+    }
+}
+
 impl Serialize for BundleData {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -175,6 +214,9 @@ impl Serialize for BundleData {
             BundleData::CexDex(cex_dex) => cex_dex.serialize(serializer),
             BundleData::CexDexQuote(cex_dex) => cex_dex.serialize(serializer),
             BundleData::Liquidation(liquidation) => liquidation.serialize(serializer),
+            BundleData::NftMev(nft_mev) => nft_mev.serialize(serializer),
+            BundleData::OracleManipulation(oracle) => oracle.serialize(serializer),
+            BundleData::LaunchSnipe(snipe) => snipe.serialize(serializer),
             BundleData::Unknown(s) => s.serialize(serializer),
         }
     }
@@ -190,6 +232,9 @@ impl InsertRow for BundleData {
             BundleData::CexDex(cex_dex) => cex_dex.get_column_names(),
             BundleData::CexDexQuote(cex_dex) => cex_dex.get_column_names(),
             BundleData::Liquidation(liquidation) => liquidation.get_column_names(),
+            BundleData::NftMev(nft_mev) => nft_mev.get_column_names(),
+            BundleData::OracleManipulation(oracle) => oracle.get_column_names(),
+            BundleData::LaunchSnipe(snipe) => snipe.get_column_names(),
             BundleData::Unknown(s) => s.get_column_names(),
         }
     }
@@ -32,25 +32,29 @@ use crate::{
 pub struct BundleHeader {
     pub block_number: u64,
 
-    pub tx_index:              u64,
+    pub tx_index: u64,
     #[serde(with = "txhash")]
     // For a sandwich this is always the first frontrun tx hash
     pub tx_hash: B256,
     #[serde(with = "addresss")]
-    pub eoa:                   Address,
+    pub eoa: Address,
     #[serde(with = "option_addresss")]
-    pub mev_contract:          Option<Address>,
+    pub mev_contract: Option<Address>,
     #[redefined(same_fields)]
     #[serde(default)]
-    pub fund:                  Fund,
-    pub profit_usd:            f64,
+    pub fund: Fund,
+    pub profit_usd: f64,
     // Total tx cost in USD
-    pub bribe_usd:             f64,
+    pub bribe_usd: f64,
     #[redefined(same_fields)]
-    pub mev_type:              MevType,
+    pub mev_type: MevType,
     // if we generated this arb without pricing
     pub no_pricing_calculated: bool,
-    pub balance_deltas:        Vec<TransactionAccounting>,
+    // a protocol was paused or had its funds pulled in the same block, mev next
+    // to one is often part of an exploit
+    #[serde(default)]
+    pub governance_event_in_block: bool,
+    pub balance_deltas: Vec<TransactionAccounting>,
 }
 
 #[serde_as]
@@ -124,7 +128,7 @@ impl Serialize for BundleHeader {
     where
         S: serde::Serializer,
     {
-        let mut ser_struct = serializer.serialize_struct("BundleHeader", 12)?;
+        let mut ser_struct = serializer.serialize_struct("BundleHeader", 13)?;
 
         ser_struct.serialize_field("block_number", &self.block_number)?;
         ser_struct.serialize_field("tx_index", &self.tx_index)?;
@@ -137,6 +141,7 @@ impl Serialize for BundleHeader {
         ser_struct.serialize_field("bribe_usd", &self.bribe_usd)?;
         ser_struct.serialize_field("mev_type", &self.mev_type)?;
         ser_struct.serialize_field("no_pricing_calculated", &self.no_pricing_calculated)?;
+        ser_struct.serialize_field("governance_event_in_block", &self.governance_event_in_block)?;
 
         let balance_deltas_tx_hashes = self
             .balance_deltas
@@ -209,6 +214,7 @@ impl DbRow for BundleHeader {
         "bribe_usd",
         "mev_type",
         "no_pricing_calculated",
+        "governance_event_in_block",
         "balance_deltas.tx_hash",
         "balance_deltas.address",
         "balance_deltas.name",
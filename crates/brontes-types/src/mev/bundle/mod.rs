@@ -68,6 +68,9 @@ impl fmt::Display for Bundle {
             MevType::Liquidation => display_liquidation(self, f)?,
             MevType::JitSandwich => display_jit_liquidity_sandwich(self, f)?,
             MevType::SearcherTx => display_searcher_tx(self, f)?,
+            MevType::NftMev => display_nft_mev(self, f)?,
+            MevType::OracleManipulation => display_oracle_manipulation(self, f)?,
+            MevType::LaunchSnipe => display_launch_snipe(self, f)?,
             MevType::Unknown => (),
         }
 
@@ -101,6 +104,9 @@ pub enum MevType {
     JitSandwich,
     Liquidation,
     AtomicArb,
+    NftMev,
+    OracleManipulation,
+    LaunchSnipe,
     SearcherTx,
     #[default]
     Unknown,
@@ -114,6 +120,9 @@ impl MevType {
             | MevType::Jit
             | MevType::AtomicArb
             | MevType::Liquidation
+            | MevType::NftMev
+            | MevType::OracleManipulation
+            | MevType::LaunchSnipe
             | MevType::SearcherTx
             | MevType::Unknown => false,
             MevType::CexDexRfq
@@ -133,8 +142,11 @@ impl MevType {
             MevType::Jit => "jit",
             MevType::Sandwich => "sandwich",
             MevType::JitSandwich => "jit-sandwich",
+            MevType::OracleManipulation => "oracle-manipulation",
+            MevType::LaunchSnipe => "launch-snipe",
             MevType::SearcherTx => "searcher-tx",
             MevType::Liquidation => "liquidation",
+            MevType::NftMev => "nft-mev",
             MevType::Unknown => "header",
         }
     }
@@ -153,6 +165,9 @@ impl From<String> for MevType {
             "Liquidation" => MevType::Liquidation,
             "JitSandwich" => MevType::JitSandwich,
             "AtomicArb" => MevType::AtomicArb,
+            "NftMev" => MevType::NftMev,
+            "OracleManipulation" => MevType::OracleManipulation,
+            "LaunchSnipe" => MevType::LaunchSnipe,
             "SearcherTx" => MevType::SearcherTx,
             _ => MevType::Unknown,
         }
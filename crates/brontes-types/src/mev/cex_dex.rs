@@ -83,6 +83,13 @@ pub struct CexDex {
     pub per_exchange_details: Vec<Vec<ArbDetails>>,
     #[redefined(field((CexExchange, same)))]
     pub per_exchange_pnl: Vec<(CexExchange, (Rational, Rational))>,
+    /// Venues listing the tokens of the swaps in a size they accept, only
+    /// these were priced against
+    #[redefined(field((CexExchange, same)))]
+    pub venues_considered: Vec<CexExchange>,
+    /// Each swap marked out against the cex trades at set times after the
+    /// block
+    pub markout_details: Vec<StatArbDetails>,
     #[redefined(same_fields)]
     pub gas_details: GasDetails,
 }
@@ -142,7 +149,7 @@ impl Serialize for CexDex {
     where
         S: Serializer,
     {
-        let mut ser_struct = serializer.serialize_struct("CexDex", 68)?;
+        let mut ser_struct = serializer.serialize_struct("CexDex", 78)?;
 
         ser_struct.serialize_field("tx_hash", &format!("{:?}", self.tx_hash))?;
         ser_struct.serialize_field("block_timestamp", &self.block_timestamp)?;
@@ -163,7 +170,10 @@ impl Serialize for CexDex {
         ser_struct.serialize_field("swaps.token_in", &swaps.token_in)?;
         ser_struct.serialize_field("swaps.token_out", &swaps.token_out)?;
         ser_struct.serialize_field("swaps.amount_in", &swaps.amount_in)?;
+        ser_struct.serialize_field("swaps.amount_in_float", &swaps.amount_in_float)?;
         ser_struct.serialize_field("swaps.amount_out", &swaps.amount_out)?;
+        ser_struct.serialize_field("swaps.amount_out_float", &swaps.amount_out_float)?;
+        ser_struct.serialize_field("swaps.protocol", &swaps.protocol)?;
 
         let transposed: ArbDetailsTransposed = self.global_vmap_details.clone().into();
         ser_struct.serialize_field(
@@ -608,11 +618,48 @@ impl Serialize for CexDex {
         ser_struct.serialize_field("per_exchange_pnl.pnl_maker", &pnl_maker)?;
         ser_struct.serialize_field("per_exchange_pnl.pnl_taker", &pnl_taker)?;
 
+        ser_struct.serialize_field(
+            "venues_considered",
+            &self
+                .venues_considered
+                .iter()
+                .map(|ex| ex.to_string())
+                .collect::<Vec<_>>(),
+        )?;
+
+        let (swap_idx, markout_us, window_start, window_end, cex_price, taker_fee, pnl): (
+            Vec<_>,
+            Vec<_>,
+            Vec<_>,
+            Vec<_>,
+            Vec<_>,
+            Vec<_>,
+            Vec<_>,
+        ) = itertools::multiunzip(self.markout_details.iter().map(|details| {
+            (
+                details.swap_idx,
+                details.markout_us,
+                details.window_start,
+                details.window_end,
+                rational_to_u256_fraction(&details.cex_price).unwrap_or_default(),
+                rational_to_u256_fraction(&details.taker_fee).unwrap_or_default(),
+                rational_to_u256_fraction(&details.pnl).unwrap_or_default(),
+            )
+        }));
+        ser_struct.serialize_field("markout_details.swap_idx", &swap_idx)?;
+        ser_struct.serialize_field("markout_details.markout_us", &markout_us)?;
+        ser_struct.serialize_field("markout_details.window_start", &window_start)?;
+        ser_struct.serialize_field("markout_details.window_end", &window_end)?;
+        ser_struct.serialize_field("markout_details.cex_price", &cex_price)?;
+        ser_struct.serialize_field("markout_details.taker_fee", &taker_fee)?;
+        ser_struct.serialize_field("markout_details.pnl", &pnl)?;
+
         let gas_details = (
             self.gas_details.coinbase_transfer,
             self.gas_details.priority_fee,
             self.gas_details.gas_used,
             self.gas_details.effective_gas_price,
+            self.gas_details.blob_gas_paid,
         );
 
         ser_struct.serialize_field("gas_details", &gas_details)?;
@@ -634,7 +681,10 @@ impl DbRow for CexDex {
         "swaps.token_in",
         "swaps.token_out",
         "swaps.amount_in",
+        "swaps.amount_in_float",
         "swaps.amount_out",
+        "swaps.amount_out_float",
+        "swaps.protocol",
         "global_vmap_details.pairs",
         "global_vmap_details.trade_start_time",
         "global_vmap_details.trade_end_time",
@@ -689,10 +739,40 @@ impl DbRow for CexDex {
         "per_exchange_pnl.cex_exchange",
         "per_exchange_pnl.pnl_maker",
         "per_exchange_pnl.pnl_taker",
+        "venues_considered",
+        "markout_details.swap_idx",
+        "markout_details.markout_us",
+        "markout_details.window_start",
+        "markout_details.window_end",
+        "markout_details.cex_price",
+        "markout_details.taker_fee",
+        "markout_details.pnl",
         "gas_details",
     ];
 }
 
+/// One of a cex-dex arb's swaps priced against the cex trades in a window some
+/// time after the block, for how the position the swap opened held up
+#[derive(Debug, Deserialize, PartialEq, Clone, Default, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct StatArbDetails {
+    /// Index of the swap in the arb's `swaps`
+    pub swap_idx:     u64,
+    /// How long after the block the window is centred on, in microseconds
+    pub markout_us:   u64,
+    pub window_start: u64,
+    pub window_end:   u64,
+    /// Volume weighted price of the window's trades, in the swap's token in per
+    /// token out
+    pub cex_price:    Rational,
+    /// Volume weighted taker fee of the exchanges traded in the window, at the
+    /// searcher's tier on each
+    pub taker_fee:    Rational,
+    /// What selling the swap's output at `cex_price` makes over its input after
+    /// `taker_fee`, in usd
+    pub pnl:          Rational,
+}
+
 #[serde_as]
 #[derive(
     Debug, Deserialize, PartialEq, Clone, Default, Redefined, brontes_macros::Transposable,
@@ -70,7 +70,7 @@ impl Serialize for CexDexQuote {
     where
         S: Serializer,
     {
-        let mut ser_struct = serializer.serialize_struct("CexDexQuote", 19)?;
+        let mut ser_struct = serializer.serialize_struct("CexDexQuote", 22)?;
         ser_struct.serialize_field("tx_hash", &format!("{:?}", self.tx_hash))?;
         ser_struct.serialize_field("block_timestamp", &self.block_timestamp)?;
         ser_struct.serialize_field("block_number", &self.block_number)?;
@@ -86,7 +86,10 @@ impl Serialize for CexDexQuote {
         ser_struct.serialize_field("swaps.token_in", &swaps.token_in)?;
         ser_struct.serialize_field("swaps.token_out", &swaps.token_out)?;
         ser_struct.serialize_field("swaps.amount_in", &swaps.amount_in)?;
+        ser_struct.serialize_field("swaps.amount_in_float", &swaps.amount_in_float)?;
         ser_struct.serialize_field("swaps.amount_out", &swaps.amount_out)?;
+        ser_struct.serialize_field("swaps.amount_out_float", &swaps.amount_out_float)?;
+        ser_struct.serialize_field("swaps.protocol", &swaps.protocol)?;
         ser_struct.serialize_field("pnl", &self.pnl)?;
         ser_struct.serialize_field("instant_mid_price", &self.instant_mid_price)?;
         ser_struct.serialize_field("t2_mid_price", &self.t2_mid_price)?;
@@ -102,6 +105,7 @@ impl Serialize for CexDexQuote {
                 self.gas_details.priority_fee,
                 self.gas_details.gas_used,
                 self.gas_details.effective_gas_price,
+                self.gas_details.blob_gas_paid,
             ),
         )?;
         ser_struct.end()
@@ -120,7 +124,10 @@ impl DbRow for CexDexQuote {
         "swaps.token_in",
         "swaps.token_out",
         "swaps.amount_in",
+        "swaps.amount_in_float",
         "swaps.amount_out",
+        "swaps.amount_out_float",
+        "swaps.protocol",
         "pnl",
         "instant_mid_price",
         "t2_mid_price",
@@ -83,7 +83,7 @@ impl Serialize for JitLiquidity {
     where
         S: Serializer,
     {
-        let mut ser_struct = serializer.serialize_struct("JitLiquidity", 31)?;
+        let mut ser_struct = serializer.serialize_struct("JitLiquidity", 36)?;
 
         // frontrun mint
         ser_struct.serialize_field(
@@ -104,12 +104,15 @@ impl Serialize for JitLiquidity {
         ser_struct.serialize_field("frontrun_mints.recipient", &frontrun_mints.recipient)?;
         ser_struct.serialize_field("frontrun_mints.tokens", &frontrun_mints.tokens)?;
         ser_struct.serialize_field("frontrun_mints.amounts", &frontrun_mints.amounts)?;
+        ser_struct
+            .serialize_field("frontrun_mints.amounts_float", &frontrun_mints.amounts_float)?;
 
         let frontrun_mint_gas_details = (
             self.frontrun_mint_gas_details.coinbase_transfer,
             self.frontrun_mint_gas_details.priority_fee,
             self.frontrun_mint_gas_details.gas_used,
             self.frontrun_mint_gas_details.effective_gas_price,
+            self.frontrun_mint_gas_details.blob_gas_paid,
         );
 
         ser_struct.serialize_field("frontrun_mint_gas_details", &(frontrun_mint_gas_details))?;
@@ -127,7 +130,12 @@ impl Serialize for JitLiquidity {
         ser_struct.serialize_field("victim_swaps.token_in", &victim_swaps.token_in)?;
         ser_struct.serialize_field("victim_swaps.token_out", &victim_swaps.token_out)?;
         ser_struct.serialize_field("victim_swaps.amount_in", &victim_swaps.amount_in)?;
+        ser_struct
+            .serialize_field("victim_swaps.amount_in_float", &victim_swaps.amount_in_float)?;
         ser_struct.serialize_field("victim_swaps.amount_out", &victim_swaps.amount_out)?;
+        ser_struct
+            .serialize_field("victim_swaps.amount_out_float", &victim_swaps.amount_out_float)?;
+        ser_struct.serialize_field("victim_swaps.protocol", &victim_swaps.protocol)?;
 
         let victim_gas_details: ClickhouseVecGasDetails = (
             self.victim_swaps_gas_details_tx_hashes.clone(),
@@ -146,6 +154,10 @@ impl Serialize for JitLiquidity {
             "victim_gas_details.effective_gas_price",
             &victim_gas_details.effective_gas_price,
         )?;
+        ser_struct.serialize_field(
+            "victim_gas_details.blob_gas_paid",
+            &victim_gas_details.blob_gas_paid,
+        )?;
 
         // backrun burn
         ser_struct
@@ -163,12 +175,14 @@ impl Serialize for JitLiquidity {
         ser_struct.serialize_field("backrun_burns.recipient", &backrun_burns.recipient)?;
         ser_struct.serialize_field("backrun_burns.tokens", &backrun_burns.tokens)?;
         ser_struct.serialize_field("backrun_burns.amounts", &backrun_burns.amounts)?;
+        ser_struct.serialize_field("backrun_burns.amounts_float", &backrun_burns.amounts_float)?;
 
         let backrun_burn_gas_details = (
             self.backrun_burn_gas_details.coinbase_transfer,
             self.backrun_burn_gas_details.priority_fee,
             self.backrun_burn_gas_details.gas_used,
             self.backrun_burn_gas_details.effective_gas_price,
+            self.backrun_burn_gas_details.blob_gas_paid,
         );
 
         ser_struct.serialize_field("backrun_burn_gas_details", &(backrun_burn_gas_details))?;
@@ -187,6 +201,7 @@ impl DbRow for JitLiquidity {
         "frontrun_mints.recipient",
         "frontrun_mints.tokens",
         "frontrun_mints.amounts",
+        "frontrun_mints.amounts_float",
         "frontrun_mint_gas_details",
         "victim_swaps.tx_hash",
         "victim_swaps.trace_idx",
@@ -196,12 +211,16 @@ impl DbRow for JitLiquidity {
         "victim_swaps.token_in",
         "victim_swaps.token_out",
         "victim_swaps.amount_in",
+        "victim_swaps.amount_in_float",
         "victim_swaps.amount_out",
+        "victim_swaps.amount_out_float",
+        "victim_swaps.protocol",
         "victim_gas_details.tx_hash",
         "victim_gas_details.coinbase_transfer",
         "victim_gas_details.priority_fee",
         "victim_gas_details.gas_used",
         "victim_gas_details.effective_gas_price",
+        "victim_gas_details.blob_gas_paid",
         "backrun_burn_tx_hash",
         "backrun_burns.trace_idx",
         "backrun_burns.from",
@@ -209,6 +228,7 @@ impl DbRow for JitLiquidity {
         "backrun_burns.recipient",
         "backrun_burns.tokens",
         "backrun_burns.amounts",
+        "backrun_burns.amounts_float",
         "backrun_burn_gas_details",
     ];
 }
@@ -174,17 +174,18 @@ pub fn compose_sandwich_jit(mev: Vec<Bundle>) -> Option<Bundle> {
 
     // Create new classified MEV data
     let new_classified = BundleHeader {
-        tx_index:              classified_sandwich.tx_index,
-        tx_hash:               *sandwich.frontrun_tx_hash.first().unwrap_or_default(),
-        mev_type:              MevType::JitSandwich,
-        fund:                  classified_sandwich.fund,
-        block_number:          classified_sandwich.block_number,
-        eoa:                   jit_classified.eoa,
-        mev_contract:          classified_sandwich.mev_contract,
-        profit_usd:            classified_sandwich.profit_usd,
-        balance_deltas:        classified_sandwich.balance_deltas,
-        bribe_usd:             classified_sandwich.bribe_usd,
+        tx_index: classified_sandwich.tx_index,
+        tx_hash: *sandwich.frontrun_tx_hash.first().unwrap_or_default(),
+        mev_type: MevType::JitSandwich,
+        fund: classified_sandwich.fund,
+        block_number: classified_sandwich.block_number,
+        eoa: jit_classified.eoa,
+        mev_contract: classified_sandwich.mev_contract,
+        profit_usd: classified_sandwich.profit_usd,
+        balance_deltas: classified_sandwich.balance_deltas,
+        bribe_usd: classified_sandwich.bribe_usd,
         no_pricing_calculated: classified_sandwich.no_pricing_calculated,
+        governance_event_in_block: classified_sandwich.governance_event_in_block,
     };
 
     Some(Bundle { header: new_classified, data: BundleData::JitSandwich(jit_sand) })
@@ -195,7 +196,7 @@ impl Serialize for JitLiquiditySandwich {
     where
         S: Serializer,
     {
-        let mut ser_struct = serializer.serialize_struct("JitLiquiditySandwich", 35)?;
+        let mut ser_struct = serializer.serialize_struct("JitLiquiditySandwich", 46)?;
         ser_struct.serialize_field("block_number", &self.block_number)?;
 
         // frontruns
@@ -216,7 +217,12 @@ impl Serialize for JitLiquiditySandwich {
         ser_struct.serialize_field("frontrun_swaps.token_in", &frontrun_swaps.token_in)?;
         ser_struct.serialize_field("frontrun_swaps.token_out", &frontrun_swaps.token_out)?;
         ser_struct.serialize_field("frontrun_swaps.amount_in", &frontrun_swaps.amount_in)?;
+        ser_struct
+            .serialize_field("frontrun_swaps.amount_in_float", &frontrun_swaps.amount_in_float)?;
         ser_struct.serialize_field("frontrun_swaps.amount_out", &frontrun_swaps.amount_out)?;
+        ser_struct
+            .serialize_field("frontrun_swaps.amount_out_float", &frontrun_swaps.amount_out_float)?;
+        ser_struct.serialize_field("frontrun_swaps.protocol", &frontrun_swaps.protocol)?;
 
         let frontrun_mints: ClickhouseVecNormalizedMintOrBurnWithTxHash =
             (self.frontrun_tx_hash.clone(), self.frontrun_mints.clone())
@@ -230,6 +236,8 @@ impl Serialize for JitLiquiditySandwich {
         ser_struct.serialize_field("frontrun_mints.recipient", &frontrun_mints.recipient)?;
         ser_struct.serialize_field("frontrun_mints.tokens", &frontrun_mints.tokens)?;
         ser_struct.serialize_field("frontrun_mints.amounts", &frontrun_mints.amounts)?;
+        ser_struct
+            .serialize_field("frontrun_mints.amounts_float", &frontrun_mints.amounts_float)?;
 
         let frontrun_gas_details: ClickhouseVecGasDetails =
             (self.frontrun_tx_hash.clone(), self.frontrun_gas_details.clone()).into();
@@ -249,6 +257,10 @@ impl Serialize for JitLiquiditySandwich {
             "frontrun_gas_details.effective_gas_price",
             &frontrun_gas_details.effective_gas_price,
         )?;
+        ser_struct.serialize_field(
+            "frontrun_gas_details.blob_gas_paid",
+            &frontrun_gas_details.blob_gas_paid,
+        )?;
 
         // victims
         let victim_swaps: ClickhouseDoubleVecNormalizedSwap =
@@ -263,7 +275,12 @@ impl Serialize for JitLiquiditySandwich {
         ser_struct.serialize_field("victim_swaps.token_in", &victim_swaps.token_in)?;
         ser_struct.serialize_field("victim_swaps.token_out", &victim_swaps.token_out)?;
         ser_struct.serialize_field("victim_swaps.amount_in", &victim_swaps.amount_in)?;
+        ser_struct
+            .serialize_field("victim_swaps.amount_in_float", &victim_swaps.amount_in_float)?;
         ser_struct.serialize_field("victim_swaps.amount_out", &victim_swaps.amount_out)?;
+        ser_struct
+            .serialize_field("victim_swaps.amount_out_float", &victim_swaps.amount_out_float)?;
+        ser_struct.serialize_field("victim_swaps.protocol", &victim_swaps.protocol)?;
 
         let victim_gas_details: ClickhouseVecGasDetails =
             (self.victim_swaps_tx_hashes.clone(), self.victim_swaps_gas_details.clone()).into();
@@ -279,6 +296,10 @@ impl Serialize for JitLiquiditySandwich {
             "victim_gas_details.effective_gas_price",
             &victim_gas_details.effective_gas_price,
         )?;
+        ser_struct.serialize_field(
+            "victim_gas_details.blob_gas_paid",
+            &victim_gas_details.blob_gas_paid,
+        )?;
 
         // backrun
         let fixed_str_backrun_tx_hash = format!("{:?}", &self.backrun_tx_hash);
@@ -303,7 +324,12 @@ impl Serialize for JitLiquiditySandwich {
         ser_struct.serialize_field("backrun_swaps.token_in", &backrun_swaps.token_in)?;
         ser_struct.serialize_field("backrun_swaps.token_out", &backrun_swaps.token_out)?;
         ser_struct.serialize_field("backrun_swaps.amount_in", &backrun_swaps.amount_in)?;
+        ser_struct
+            .serialize_field("backrun_swaps.amount_in_float", &backrun_swaps.amount_in_float)?;
         ser_struct.serialize_field("backrun_swaps.amount_out", &backrun_swaps.amount_out)?;
+        ser_struct
+            .serialize_field("backrun_swaps.amount_out_float", &backrun_swaps.amount_out_float)?;
+        ser_struct.serialize_field("backrun_swaps.protocol", &backrun_swaps.protocol)?;
 
         let backrun_burns: ClickhouseVecNormalizedMintOrBurn = self
             .backrun_burns
@@ -324,6 +350,7 @@ impl Serialize for JitLiquiditySandwich {
         ser_struct.serialize_field("backrun_burns.recipient", &backrun_burns.recipient)?;
         ser_struct.serialize_field("backrun_burns.tokens", &backrun_burns.tokens)?;
         ser_struct.serialize_field("backrun_burns.amounts", &backrun_burns.amounts)?;
+        ser_struct.serialize_field("backrun_burns.amounts_float", &backrun_burns.amounts_float)?;
 
         ser_struct
             .serialize_field("backrun_gas_details.tx_hash", &vec![fixed_str_backrun_tx_hash])?;
@@ -343,6 +370,10 @@ impl Serialize for JitLiquiditySandwich {
             "backrun_gas_details.effective_gas_price",
             &vec![self.backrun_gas_details.effective_gas_price],
         )?;
+        ser_struct.serialize_field(
+            "backrun_gas_details.blob_gas_paid",
+            &vec![self.backrun_gas_details.blob_gas_paid],
+        )?;
 
         ser_struct.end()
     }
@@ -360,7 +391,10 @@ impl DbRow for JitLiquiditySandwich {
         "frontrun_swaps.token_in",
         "frontrun_swaps.token_out",
         "frontrun_swaps.amount_in",
+        "frontrun_swaps.amount_in_float",
         "frontrun_swaps.amount_out",
+        "frontrun_swaps.amount_out_float",
+        "frontrun_swaps.protocol",
         "frontrun_mints.tx_hash",
         "frontrun_mints.trace_idx",
         "frontrun_mints.from",
@@ -368,11 +402,13 @@ impl DbRow for JitLiquiditySandwich {
         "frontrun_mints.recipient",
         "frontrun_mints.tokens",
         "frontrun_mints.amounts",
+        "frontrun_mints.amounts_float",
         "frontrun_gas_details.tx_hash",
         "frontrun_gas_details.coinbase_transfer",
         "frontrun_gas_details.priority_fee",
         "frontrun_gas_details.gas_used",
         "frontrun_gas_details.effective_gas_price",
+        "frontrun_gas_details.blob_gas_paid",
         "victim_swaps.tx_hash",
         "victim_swaps.trace_idx",
         "victim_swaps.from",
@@ -381,12 +417,16 @@ impl DbRow for JitLiquiditySandwich {
         "victim_swaps.token_in",
         "victim_swaps.token_out",
         "victim_swaps.amount_in",
+        "victim_swaps.amount_in_float",
         "victim_swaps.amount_out",
+        "victim_swaps.amount_out_float",
+        "victim_swaps.protocol",
         "victim_gas_details.tx_hash",
         "victim_gas_details.coinbase_transfer",
         "victim_gas_details.priority_fee",
         "victim_gas_details.gas_used",
         "victim_gas_details.effective_gas_price",
+        "victim_gas_details.blob_gas_paid",
         "backrun_tx_hash",
         "backrun_swaps.tx_hash",
         "backrun_swaps.trace_idx",
@@ -396,7 +436,10 @@ impl DbRow for JitLiquiditySandwich {
         "backrun_swaps.token_in",
         "backrun_swaps.token_out",
         "backrun_swaps.amount_in",
+        "backrun_swaps.amount_in_float",
         "backrun_swaps.amount_out",
+        "backrun_swaps.amount_out_float",
+        "backrun_swaps.protocol",
         "backrun_burns.tx_hash",
         "backrun_burns.trace_idx",
         "backrun_burns.from",
@@ -404,10 +447,12 @@ impl DbRow for JitLiquiditySandwich {
         "backrun_burns.recipient",
         "backrun_burns.tokens",
         "backrun_burns.amounts",
+        "backrun_burns.amounts_float",
         "backrun_gas_details.tx_hash",
         "backrun_gas_details.coinbase_transfer",
         "backrun_gas_details.priority_fee",
         "backrun_gas_details.gas_used",
         "backrun_gas_details.effective_gas_price",
+        "backrun_gas_details.blob_gas_paid",
     ];
 }
@@ -0,0 +1,144 @@
+use ::clickhouse::DbRow;
+use ::serde::ser::{SerializeStruct, Serializer};
+use ahash::HashSet;
+use redefined::Redefined;
+use reth_primitives::{Address, B256};
+use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+use super::{Mev, MevType};
+use crate::{
+    db::redefined_types::primitives::*,
+    normalized_actions::{ClickhouseVecNormalizedSwap, NormalizedSwap, NormalizedSwapRedefined},
+    GasDetails, Protocol,
+};
+
+/// A searcher contract buying a freshly launched token from its pool within a
+/// few blocks of the pool being created, paying the builder to land first.
+#[serde_as]
+#[derive(Debug, Deserialize, PartialEq, Clone, Default, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct LaunchSnipe {
+    pub block_number:        u64,
+    pub tx_hash:             B256,
+    /// The contract the buys were made through
+    pub sniper:              Address,
+    /// The token bought
+    pub token:               Address,
+    /// The pool the token was launched in
+    pub pool:                Address,
+    #[redefined(same_fields)]
+    pub protocol:            Protocol,
+    pub pool_creation_block: u64,
+    /// The buys on the launch pool
+    pub swaps:               Vec<NormalizedSwap>,
+    #[redefined(same_fields)]
+    pub gas_details:         GasDetails,
+}
+
+impl LaunchSnipe {
+    /// How many blocks after the pool was created the snipe landed, zero for
+    /// the creation block itself
+    pub fn blocks_after_launch(&self) -> u64 {
+        self.block_number.saturating_sub(self.pool_creation_block)
+    }
+}
+
+impl Mev for LaunchSnipe {
+    fn mev_type(&self) -> MevType {
+        MevType::LaunchSnipe
+    }
+
+    fn total_gas_paid(&self) -> u128 {
+        self.gas_details.gas_paid()
+    }
+
+    fn total_priority_fee_paid(&self, base_fee: u128) -> u128 {
+        self.gas_details.priority_fee_paid(base_fee)
+    }
+
+    fn bribe(&self) -> u128 {
+        self.gas_details.coinbase_transfer.unwrap_or(0)
+    }
+
+    fn mev_transaction_hashes(&self) -> Vec<B256> {
+        vec![self.tx_hash]
+    }
+
+    fn protocols(&self) -> HashSet<Protocol> {
+        [self.protocol].into_iter().collect()
+    }
+}
+
+impl Serialize for LaunchSnipe {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut ser_struct = serializer.serialize_struct("LaunchSnipe", 20)?;
+
+        ser_struct.serialize_field("block_number", &self.block_number)?;
+        ser_struct.serialize_field("tx_hash", &format!("{:?}", self.tx_hash))?;
+        ser_struct.serialize_field("sniper", &format!("{:?}", self.sniper))?;
+        ser_struct.serialize_field("token", &format!("{:?}", self.token))?;
+        ser_struct.serialize_field("pool", &format!("{:?}", self.pool))?;
+        ser_struct.serialize_field("protocol", &self.protocol.to_string())?;
+        ser_struct.serialize_field("pool_creation_block", &self.pool_creation_block)?;
+        ser_struct.serialize_field("blocks_after_launch", &self.blocks_after_launch())?;
+
+        let swaps: ClickhouseVecNormalizedSwap = self
+            .swaps
+            .clone()
+            .try_into()
+            .map_err(serde::ser::Error::custom)?;
+
+        ser_struct.serialize_field("swaps.trace_idx", &swaps.trace_index)?;
+        ser_struct.serialize_field("swaps.from", &swaps.from)?;
+        ser_struct.serialize_field("swaps.recipient", &swaps.recipient)?;
+        ser_struct.serialize_field("swaps.pool", &swaps.pool)?;
+        ser_struct.serialize_field("swaps.token_in", &swaps.token_in)?;
+        ser_struct.serialize_field("swaps.token_out", &swaps.token_out)?;
+        ser_struct.serialize_field("swaps.amount_in", &swaps.amount_in)?;
+        ser_struct.serialize_field("swaps.amount_in_float", &swaps.amount_in_float)?;
+        ser_struct.serialize_field("swaps.amount_out", &swaps.amount_out)?;
+        ser_struct.serialize_field("swaps.amount_out_float", &swaps.amount_out_float)?;
+        ser_struct.serialize_field("swaps.protocol", &swaps.protocol)?;
+
+        let gas_details = (
+            self.gas_details.coinbase_transfer,
+            self.gas_details.priority_fee,
+            self.gas_details.gas_used,
+            self.gas_details.effective_gas_price,
+            self.gas_details.blob_gas_paid,
+        );
+        ser_struct.serialize_field("gas_details", &gas_details)?;
+
+        ser_struct.end()
+    }
+}
+
+impl DbRow for LaunchSnipe {
+    const COLUMN_NAMES: &'static [&'static str] = &[
+        "block_number",
+        "tx_hash",
+        "sniper",
+        "token",
+        "pool",
+        "protocol",
+        "pool_creation_block",
+        "blocks_after_launch",
+        "swaps.trace_idx",
+        "swaps.from",
+        "swaps.recipient",
+        "swaps.pool",
+        "swaps.token_in",
+        "swaps.token_out",
+        "swaps.amount_in",
+        "swaps.amount_in_float",
+        "swaps.amount_out",
+        "swaps.amount_out_float",
+        "swaps.protocol",
+        "gas_details",
+    ];
+}
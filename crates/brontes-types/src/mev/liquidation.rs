@@ -27,6 +27,27 @@ pub struct Liquidation {
     pub liquidations:        Vec<NormalizedLiquidation>,
     #[redefined(same_fields)]
     pub gas_details:         GasDetails,
+    /// Reverted attempts at liquidating the same accounts in the block
+    pub competition:         LiquidationCompetition,
+}
+
+/// Keepers competing for the same liquidation mostly lose by reverting, so
+/// the reverted attempts in the block show how contested a liquidation was
+/// and how much the winner had to outbid the others by.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone, Default, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct LiquidationCompetition {
+    /// Reverted transactions that tried to liquidate one of the same accounts
+    pub competitor_tx_hashes:         Vec<B256>,
+    /// Distinct senders of the competing transactions, excluding the winner
+    pub competing_searchers:          u64,
+    /// Priority fee and coinbase transfer per unit of gas, in gwei
+    pub winner_bribe_per_gas:         f64,
+    /// Highest priority fee per unit of gas of the competitors, in gwei
+    pub max_competitor_bribe_per_gas: f64,
+    /// The winners bribe per gas over the highest competitor's. Zero if there
+    /// was no competition
+    pub bribe_escalation:             f64,
 }
 
 impl Mev for Liquidation {
@@ -70,7 +91,7 @@ impl Serialize for Liquidation {
     where
         S: Serializer,
     {
-        let mut ser_struct = serializer.serialize_struct("Liquidation", 34)?;
+        let mut ser_struct = serializer.serialize_struct("Liquidation", 41)?;
 
         // frontrun
         ser_struct
@@ -91,8 +112,17 @@ impl Serialize for Liquidation {
         ser_struct.serialize_field("liquidation_swaps.token_in", &liquidation_swaps.token_in)?;
         ser_struct.serialize_field("liquidation_swaps.token_out", &liquidation_swaps.token_out)?;
         ser_struct.serialize_field("liquidation_swaps.amount_in", &liquidation_swaps.amount_in)?;
+        ser_struct.serialize_field(
+            "liquidation_swaps.amount_in_float",
+            &liquidation_swaps.amount_in_float,
+        )?;
         ser_struct
             .serialize_field("liquidation_swaps.amount_out", &liquidation_swaps.amount_out)?;
+        ser_struct.serialize_field(
+            "liquidation_swaps.amount_out_float",
+            &liquidation_swaps.amount_out_float,
+        )?;
+        ser_struct.serialize_field("liquidation_swaps.protocol", &liquidation_swaps.protocol)?;
 
         // victims
         let liquidations: ClickhouseVecNormalizedLiquidation = self
@@ -109,20 +139,41 @@ impl Serialize for Liquidation {
             .serialize_field("liquidations.collateral_asset", &liquidations.collateral_asset)?;
         ser_struct.serialize_field("liquidations.debt_asset", &liquidations.debt_asset)?;
         ser_struct.serialize_field("liquidations.covered_debt", &liquidations.covered_debt)?;
+        ser_struct
+            .serialize_field("liquidations.covered_debt_float", &liquidations.covered_debt_float)?;
         ser_struct.serialize_field(
             "liquidations.liquidated_collateral",
             &liquidations.liquidated_collateral,
         )?;
+        ser_struct.serialize_field(
+            "liquidations.liquidated_collateral_float",
+            &liquidations.liquidated_collateral_float,
+        )?;
+        ser_struct.serialize_field("liquidations.protocol", &liquidations.protocol)?;
 
         let gas_details = (
             self.gas_details.coinbase_transfer,
             self.gas_details.priority_fee,
             self.gas_details.gas_used,
             self.gas_details.effective_gas_price,
+            self.gas_details.blob_gas_paid,
         );
         //serializer.seri
         ser_struct.serialize_field("gas_details", &(gas_details))?;
 
+        let competition = (
+            self.competition
+                .competitor_tx_hashes
+                .iter()
+                .map(|tx_hash| format!("{:?}", tx_hash))
+                .collect::<Vec<_>>(),
+            self.competition.competing_searchers,
+            self.competition.winner_bribe_per_gas,
+            self.competition.max_competitor_bribe_per_gas,
+            self.competition.bribe_escalation,
+        );
+        ser_struct.serialize_field("competition", &competition)?;
+
         ser_struct.end()
     }
 }
@@ -138,7 +189,10 @@ impl DbRow for Liquidation {
         "liquidation_swaps.token_in",
         "liquidation_swaps.token_out",
         "liquidation_swaps.amount_in",
+        "liquidation_swaps.amount_in_float",
         "liquidation_swaps.amount_out",
+        "liquidation_swaps.amount_out_float",
+        "liquidation_swaps.protocol",
         "liquidations.trace_idx",
         "liquidations.pool",
         "liquidations.liquidator",
@@ -146,7 +200,11 @@ impl DbRow for Liquidation {
         "liquidations.collateral_asset",
         "liquidations.debt_asset",
         "liquidations.covered_debt",
+        "liquidations.covered_debt_float",
         "liquidations.liquidated_collateral",
+        "liquidations.liquidated_collateral_float",
+        "liquidations.protocol",
         "gas_details",
+        "competition",
     ];
 }
@@ -10,12 +10,20 @@ pub mod cex_dex;
 pub use cex_dex::*;
 pub mod liquidation;
 pub use liquidation::*;
+pub mod nft;
+pub use nft::*;
 pub mod jit_sandwich;
 pub use jit_sandwich::*;
 pub mod block;
 pub use block::*;
 pub mod searcher_tx;
 pub use searcher_tx::*;
+pub mod launch_snipe;
+pub use launch_snipe::*;
+pub mod oracle_manipulation;
+pub use oracle_manipulation::*;
 
 pub mod cex_dex_quotes;
 pub use cex_dex_quotes::*;
+pub mod auction;
+pub use auction::*;
@@ -0,0 +1,247 @@
+use ::clickhouse::DbRow;
+use ::serde::ser::{SerializeStruct, Serializer};
+use ahash::HashSet;
+use redefined::{self_convert_redefined, Redefined};
+use reth_primitives::{Address, B256, U256};
+use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use strum::Display;
+
+use super::{Mev, MevType};
+use crate::{db::redefined_types::primitives::*, ClickhouseVecGasDetails, GasDetails, Protocol};
+
+/// Nfts a searcher bought or minted and sold again within the same block.
+///
+/// Each nft the searcher came by is matched to the sale of that same nft, so
+/// `acquisitions` and `sales` line up index by index. A bundle can span
+/// several transactions of the same searcher, e.g. a buy in one transaction
+/// and the sale into a bid on another marketplace in a later one.
+#[serde_as]
+#[derive(Debug, Deserialize, PartialEq, Clone, Default, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct NftMev {
+    pub block_number: u64,
+    pub kind:         NftMevKind,
+    /// The transactions the nfts were acquired and sold in, in block order
+    pub tx_hashes:    Vec<B256>,
+    /// Gas details of each of the transactions in `tx_hashes`
+    #[redefined(same_fields)]
+    pub gas_details:  Vec<GasDetails>,
+    /// How the searcher came by each of the sold nfts
+    pub acquisitions: Vec<NftMevLeg>,
+    /// The sale of each of the acquired nfts
+    pub sales:        Vec<NftMevLeg>,
+}
+
+#[derive(
+    Debug,
+    Default,
+    Deserialize,
+    Serialize,
+    PartialEq,
+    Eq,
+    Hash,
+    Clone,
+    Copy,
+    Display,
+    rSerialize,
+    rDeserialize,
+    Archive,
+)]
+pub enum NftMevKind {
+    /// An nft bought on a marketplace and sold for more, on the same or
+    /// another marketplace
+    #[default]
+    Arbitrage,
+    /// Freshly minted nfts flipped on a marketplace before the collection
+    /// trades up to its floor
+    MintSnipe,
+}
+
+self_convert_redefined!(NftMevKind);
+
+/// A single nft changing hands to or from the searcher
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone, Default, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct NftMevLeg {
+    pub tx_hash:       B256,
+    pub trace_idx:     u64,
+    /// Unknown for a mint
+    #[redefined(same_fields)]
+    pub protocol:      Protocol,
+    /// The marketplace the order was filled at, the collection for a mint
+    pub marketplace:   Address,
+    pub collection:    Address,
+    pub token_id:      U256,
+    /// The other side of the trade, the zero address for a mint
+    pub counterparty:  Address,
+    pub payment_token: Address,
+    /// Zero for a mint, what was paid for it is part of the balance deltas
+    pub price:         f64,
+}
+
+impl Mev for NftMev {
+    fn mev_type(&self) -> MevType {
+        MevType::NftMev
+    }
+
+    fn total_gas_paid(&self) -> u128 {
+        self.gas_details.iter().map(|gd| gd.gas_paid()).sum()
+    }
+
+    fn total_priority_fee_paid(&self, base_fee: u128) -> u128 {
+        self.gas_details
+            .iter()
+            .map(|gd| gd.priority_fee_paid(base_fee))
+            .sum()
+    }
+
+    fn bribe(&self) -> u128 {
+        self.gas_details
+            .iter()
+            .filter_map(|gd| gd.coinbase_transfer)
+            .sum()
+    }
+
+    fn mev_transaction_hashes(&self) -> Vec<B256> {
+        self.tx_hashes.clone()
+    }
+
+    fn protocols(&self) -> HashSet<Protocol> {
+        self.acquisitions
+            .iter()
+            .chain(&self.sales)
+            .map(|leg| leg.protocol)
+            .filter(|protocol| *protocol != Protocol::Unknown)
+            .collect()
+    }
+}
+
+impl Serialize for NftMev {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut ser_struct = serializer.serialize_struct("NftMev", 26)?;
+
+        ser_struct.serialize_field("block_number", &self.block_number)?;
+        ser_struct.serialize_field("kind", &self.kind.to_string())?;
+        ser_struct.serialize_field(
+            "tx_hashes",
+            &self
+                .tx_hashes
+                .iter()
+                .map(|tx_hash| format!("{:?}", tx_hash))
+                .collect::<Vec<_>>(),
+        )?;
+
+        let gas_details: ClickhouseVecGasDetails =
+            (self.tx_hashes.clone(), self.gas_details.clone()).into();
+        ser_struct.serialize_field("gas_details.tx_hash", &gas_details.tx_hash)?;
+        ser_struct
+            .serialize_field("gas_details.coinbase_transfer", &gas_details.coinbase_transfer)?;
+        ser_struct.serialize_field("gas_details.priority_fee", &gas_details.priority_fee)?;
+        ser_struct.serialize_field("gas_details.gas_used", &gas_details.gas_used)?;
+        ser_struct
+            .serialize_field("gas_details.effective_gas_price", &gas_details.effective_gas_price)?;
+        ser_struct.serialize_field("gas_details.blob_gas_paid", &gas_details.blob_gas_paid)?;
+
+        let acquisitions = ClickhouseVecNftMevLeg::from(self.acquisitions.as_slice());
+        ser_struct.serialize_field("acquisitions.tx_hash", &acquisitions.tx_hash)?;
+        ser_struct.serialize_field("acquisitions.trace_idx", &acquisitions.trace_idx)?;
+        ser_struct.serialize_field("acquisitions.protocol", &acquisitions.protocol)?;
+        ser_struct.serialize_field("acquisitions.marketplace", &acquisitions.marketplace)?;
+        ser_struct.serialize_field("acquisitions.collection", &acquisitions.collection)?;
+        ser_struct.serialize_field("acquisitions.token_id", &acquisitions.token_id)?;
+        ser_struct.serialize_field("acquisitions.counterparty", &acquisitions.counterparty)?;
+        ser_struct.serialize_field("acquisitions.payment_token", &acquisitions.payment_token)?;
+        ser_struct.serialize_field("acquisitions.price", &acquisitions.price)?;
+
+        let sales = ClickhouseVecNftMevLeg::from(self.sales.as_slice());
+        ser_struct.serialize_field("sales.tx_hash", &sales.tx_hash)?;
+        ser_struct.serialize_field("sales.trace_idx", &sales.trace_idx)?;
+        ser_struct.serialize_field("sales.protocol", &sales.protocol)?;
+        ser_struct.serialize_field("sales.marketplace", &sales.marketplace)?;
+        ser_struct.serialize_field("sales.collection", &sales.collection)?;
+        ser_struct.serialize_field("sales.token_id", &sales.token_id)?;
+        ser_struct.serialize_field("sales.counterparty", &sales.counterparty)?;
+        ser_struct.serialize_field("sales.payment_token", &sales.payment_token)?;
+        ser_struct.serialize_field("sales.price", &sales.price)?;
+
+        ser_struct.end()
+    }
+}
+
+/// Column per field of the legs, for the clickhouse nested columns
+struct ClickhouseVecNftMevLeg {
+    tx_hash:       Vec<String>,
+    trace_idx:     Vec<u64>,
+    protocol:      Vec<String>,
+    marketplace:   Vec<String>,
+    collection:    Vec<String>,
+    token_id:      Vec<[u8; 32]>,
+    counterparty:  Vec<String>,
+    payment_token: Vec<String>,
+    price:         Vec<f64>,
+}
+
+impl From<&[NftMevLeg]> for ClickhouseVecNftMevLeg {
+    fn from(legs: &[NftMevLeg]) -> Self {
+        Self {
+            tx_hash:       legs.iter().map(|l| format!("{:?}", l.tx_hash)).collect(),
+            trace_idx:     legs.iter().map(|l| l.trace_idx).collect(),
+            protocol:      legs.iter().map(|l| l.protocol.to_string()).collect(),
+            marketplace:   legs
+                .iter()
+                .map(|l| format!("{:?}", l.marketplace))
+                .collect(),
+            collection:    legs.iter().map(|l| format!("{:?}", l.collection)).collect(),
+            token_id:      legs
+                .iter()
+                .map(|l| l.token_id.to_le_bytes::<32>())
+                .collect(),
+            counterparty:  legs
+                .iter()
+                .map(|l| format!("{:?}", l.counterparty))
+                .collect(),
+            payment_token: legs
+                .iter()
+                .map(|l| format!("{:?}", l.payment_token))
+                .collect(),
+            price:         legs.iter().map(|l| l.price).collect(),
+        }
+    }
+}
+
+impl DbRow for NftMev {
+    const COLUMN_NAMES: &'static [&'static str] = &[
+        "block_number",
+        "kind",
+        "tx_hashes",
+        "gas_details.tx_hash",
+        "gas_details.coinbase_transfer",
+        "gas_details.priority_fee",
+        "gas_details.gas_used",
+        "gas_details.effective_gas_price",
+        "gas_details.blob_gas_paid",
+        "acquisitions.tx_hash",
+        "acquisitions.trace_idx",
+        "acquisitions.protocol",
+        "acquisitions.marketplace",
+        "acquisitions.collection",
+        "acquisitions.token_id",
+        "acquisitions.counterparty",
+        "acquisitions.payment_token",
+        "acquisitions.price",
+        "sales.tx_hash",
+        "sales.trace_idx",
+        "sales.protocol",
+        "sales.marketplace",
+        "sales.collection",
+        "sales.token_id",
+        "sales.counterparty",
+        "sales.payment_token",
+        "sales.price",
+    ];
+}
@@ -0,0 +1,177 @@
+use ::clickhouse::DbRow;
+use ::serde::ser::{SerializeStruct, Serializer};
+use ahash::HashSet;
+use redefined::Redefined;
+use reth_primitives::{Address, B256};
+use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+use super::{Mev, MevType};
+use crate::{
+    db::redefined_types::primitives::*,
+    normalized_actions::{ClickhouseVecNormalizedSwap, NormalizedSwap, NormalizedSwapRedefined},
+    ClickhouseVecGasDetails, GasDetails, Protocol,
+};
+
+/// A pool price pushed far from where it was so that a lending market reading
+/// its price from that pool lets the searcher borrow, withdraw or liquidate
+/// against the skewed price.
+///
+/// The manipulation and the exploit are often in the same transaction, funded
+/// by a flash loan and followed by a swap back. When they are in different
+/// transactions, both are sent by the same searcher.
+#[serde_as]
+#[derive(Debug, Deserialize, PartialEq, Clone, Default, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct OracleManipulation {
+    pub block_number:         u64,
+    pub manipulation_tx_hash: B256,
+    /// The pool whose price was moved
+    pub manipulated_pool:     Address,
+    /// The swaps on the manipulated pool that moved its price
+    pub manipulation_swaps:   Vec<NormalizedSwap>,
+    /// How much worse than the pre-transaction price the swaps were filled
+    /// at, as a fraction of their input value
+    pub price_impact:         f64,
+    /// The transaction of the lending action, the manipulation transaction if
+    /// both are in the same one
+    pub exploit_tx_hash:      B256,
+    #[redefined(same_fields)]
+    pub exploited_protocol:   Protocol,
+    /// The lending market that read the manipulated price
+    pub exploited_market:     Address,
+    pub exploit_trace_idx:    u64,
+    /// Gas details of the manipulation transaction and, if it is a different
+    /// one, the exploit transaction
+    #[redefined(same_fields)]
+    pub gas_details:          Vec<GasDetails>,
+}
+
+impl OracleManipulation {
+    pub fn tx_hashes(&self) -> Vec<B256> {
+        if self.manipulation_tx_hash == self.exploit_tx_hash {
+            vec![self.manipulation_tx_hash]
+        } else {
+            vec![self.manipulation_tx_hash, self.exploit_tx_hash]
+        }
+    }
+}
+
+impl Mev for OracleManipulation {
+    fn mev_type(&self) -> MevType {
+        MevType::OracleManipulation
+    }
+
+    fn total_gas_paid(&self) -> u128 {
+        self.gas_details.iter().map(|gd| gd.gas_paid()).sum()
+    }
+
+    fn total_priority_fee_paid(&self, base_fee: u128) -> u128 {
+        self.gas_details
+            .iter()
+            .map(|gd| gd.priority_fee_paid(base_fee))
+            .sum()
+    }
+
+    fn bribe(&self) -> u128 {
+        self.gas_details
+            .iter()
+            .filter_map(|gd| gd.coinbase_transfer)
+            .sum()
+    }
+
+    fn mev_transaction_hashes(&self) -> Vec<B256> {
+        self.tx_hashes()
+    }
+
+    fn protocols(&self) -> HashSet<Protocol> {
+        self.manipulation_swaps
+            .iter()
+            .map(|swap| swap.protocol)
+            .chain(std::iter::once(self.exploited_protocol))
+            .collect()
+    }
+}
+
+impl Serialize for OracleManipulation {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut ser_struct = serializer.serialize_struct("OracleManipulation", 24)?;
+
+        ser_struct.serialize_field("block_number", &self.block_number)?;
+        ser_struct
+            .serialize_field("manipulation_tx_hash", &format!("{:?}", self.manipulation_tx_hash))?;
+        ser_struct.serialize_field("manipulated_pool", &format!("{:?}", self.manipulated_pool))?;
+
+        let swaps: ClickhouseVecNormalizedSwap = self
+            .manipulation_swaps
+            .clone()
+            .try_into()
+            .map_err(serde::ser::Error::custom)?;
+
+        ser_struct.serialize_field("manipulation_swaps.trace_idx", &swaps.trace_index)?;
+        ser_struct.serialize_field("manipulation_swaps.from", &swaps.from)?;
+        ser_struct.serialize_field("manipulation_swaps.recipient", &swaps.recipient)?;
+        ser_struct.serialize_field("manipulation_swaps.pool", &swaps.pool)?;
+        ser_struct.serialize_field("manipulation_swaps.token_in", &swaps.token_in)?;
+        ser_struct.serialize_field("manipulation_swaps.token_out", &swaps.token_out)?;
+        ser_struct.serialize_field("manipulation_swaps.amount_in", &swaps.amount_in)?;
+        ser_struct.serialize_field("manipulation_swaps.amount_in_float", &swaps.amount_in_float)?;
+        ser_struct.serialize_field("manipulation_swaps.amount_out", &swaps.amount_out)?;
+        ser_struct
+            .serialize_field("manipulation_swaps.amount_out_float", &swaps.amount_out_float)?;
+        ser_struct.serialize_field("manipulation_swaps.protocol", &swaps.protocol)?;
+
+        ser_struct.serialize_field("price_impact", &self.price_impact)?;
+        ser_struct.serialize_field("exploit_tx_hash", &format!("{:?}", self.exploit_tx_hash))?;
+        ser_struct.serialize_field("exploited_protocol", &self.exploited_protocol.to_string())?;
+        ser_struct.serialize_field("exploited_market", &format!("{:?}", self.exploited_market))?;
+        ser_struct.serialize_field("exploit_trace_idx", &self.exploit_trace_idx)?;
+
+        let gas_details: ClickhouseVecGasDetails =
+            (self.tx_hashes(), self.gas_details.clone()).into();
+        ser_struct.serialize_field("gas_details.tx_hash", &gas_details.tx_hash)?;
+        ser_struct
+            .serialize_field("gas_details.coinbase_transfer", &gas_details.coinbase_transfer)?;
+        ser_struct.serialize_field("gas_details.priority_fee", &gas_details.priority_fee)?;
+        ser_struct.serialize_field("gas_details.gas_used", &gas_details.gas_used)?;
+        ser_struct
+            .serialize_field("gas_details.effective_gas_price", &gas_details.effective_gas_price)?;
+        ser_struct.serialize_field("gas_details.blob_gas_paid", &gas_details.blob_gas_paid)?;
+
+        ser_struct.end()
+    }
+}
+
+impl DbRow for OracleManipulation {
+    const COLUMN_NAMES: &'static [&'static str] = &[
+        "block_number",
+        "manipulation_tx_hash",
+        "manipulated_pool",
+        "manipulation_swaps.trace_idx",
+        "manipulation_swaps.from",
+        "manipulation_swaps.recipient",
+        "manipulation_swaps.pool",
+        "manipulation_swaps.token_in",
+        "manipulation_swaps.token_out",
+        "manipulation_swaps.amount_in",
+        "manipulation_swaps.amount_in_float",
+        "manipulation_swaps.amount_out",
+        "manipulation_swaps.amount_out_float",
+        "manipulation_swaps.protocol",
+        "price_impact",
+        "exploit_tx_hash",
+        "exploited_protocol",
+        "exploited_market",
+        "exploit_trace_idx",
+        "gas_details.tx_hash",
+        "gas_details.coinbase_transfer",
+        "gas_details.priority_fee",
+        "gas_details.gas_used",
+        "gas_details.effective_gas_price",
+        "gas_details.blob_gas_paid",
+    ];
+}
@@ -3,18 +3,15 @@ use std::fmt::Debug;
 use ::clickhouse::DbRow;
 use ::serde::ser::{SerializeStruct, Serializer};
 use ahash::HashSet;
-use malachite::Rational;
 use redefined::Redefined;
-use reth_primitives::{Address, B256};
+use reth_primitives::{Address, B256, U256};
 use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 
 use super::{Mev, MevType};
 use crate::{
-    db::{redefined_types::primitives::*, token_info::TokenInfoWithAddress},
-    normalized_actions::*,
-    ClickhouseVecGasDetails, Protocol,
+    db::redefined_types::primitives::*, normalized_actions::*, ClickhouseVecGasDetails, Protocol,
 };
 #[allow(unused_imports)]
 use crate::{
@@ -88,17 +85,60 @@ pub struct Sandwich {
     /// Gas details for each backrunning transaction.
     #[redefined(same_fields)]
     pub backrun_gas_details:      GasDetails,
+    /// Slippage limits of the victim transactions we were able to decode
+    pub victim_slippage:          Vec<VictimSlippage>,
+    /// Loss of each victim that traded a pool the frontrun moved, against
+    /// the price before the frontrun
+    pub victim_losses:            Vec<VictimLossAmount>,
 }
 
-/// calcuation for the loss per user
-#[derive(Debug, Deserialize, PartialEq, Clone, Default)]
+/// The slippage limit a victim set on their router call, compared against
+/// what they were actually filled at.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone, Default, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct VictimSlippage {
+    pub tx_hash:            B256,
+    /// The token the limit is denominated in. This is the output token for
+    /// exact in swaps and the input token for exact out swaps
+    pub token:              Address,
+    pub exact_in:           bool,
+    /// `amountOutMin` for exact in swaps, `amountInMax` for exact out swaps
+    pub limit:              f64,
+    /// The amount the victim received (exact in) or paid (exact out)
+    pub realized:           f64,
+    /// Zero if the victim didn't set a price limit on the pool
+    pub sqrt_price_limit:   U256,
+    /// How far the fill was from the limit, as a fraction of the realized
+    /// amount. Zero means the victim was filled right at their limit
+    pub remaining_slippage: f64,
+    /// Share of the allowed slippage that the frontrun consumed, estimated
+    /// from the frontrun's execution price on the shared pool
+    pub consumed_slippage:  Option<f64>,
+}
+
+/// What a victim lost to the frontrun in one of the tokens they received.
+/// The loss is what they would have received at the price before the frontrun
+/// less what they did
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone, Default, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
 pub struct VictimLossAmount {
     pub tx_hash:           B256,
-    pub vicitim_eoa:       Address,
-    pub token:             TokenInfoWithAddress,
-    pub token_amount_lost: Rational,
+    pub victim_eoa:        Address,
+    pub token:             Address,
+    pub token_amount_lost: f64,
     /// is zero if we don't have a price for the given token
-    pub amount_lost_usd:   Rational,
+    pub amount_lost_usd:   f64,
+    /// How much worse the victim's execution price was than the price before
+    /// the frontrun, as a fraction of what they would have received at it
+    #[serde(default)]
+    pub price_degradation: f64,
+}
+
+impl Sandwich {
+    /// What all of the sandwich's victims lost, in usd
+    pub fn victim_loss_usd(&self) -> f64 {
+        self.victim_losses.iter().map(|l| l.amount_lost_usd).sum()
+    }
 }
 
 impl Mev for Sandwich {
@@ -166,7 +206,7 @@ impl Serialize for Sandwich {
     where
         S: Serializer,
     {
-        let mut ser_struct = serializer.serialize_struct("Sandwich", 35)?;
+        let mut ser_struct = serializer.serialize_struct("Sandwich", 68)?;
         ser_struct.serialize_field("block_number", &self.block_number)?;
 
         // frontrun
@@ -187,7 +227,12 @@ impl Serialize for Sandwich {
         ser_struct.serialize_field("frontrun_swaps.token_in", &frontrun_swaps.token_in)?;
         ser_struct.serialize_field("frontrun_swaps.token_out", &frontrun_swaps.token_out)?;
         ser_struct.serialize_field("frontrun_swaps.amount_in", &frontrun_swaps.amount_in)?;
+        ser_struct
+            .serialize_field("frontrun_swaps.amount_in_float", &frontrun_swaps.amount_in_float)?;
         ser_struct.serialize_field("frontrun_swaps.amount_out", &frontrun_swaps.amount_out)?;
+        ser_struct
+            .serialize_field("frontrun_swaps.amount_out_float", &frontrun_swaps.amount_out_float)?;
+        ser_struct.serialize_field("frontrun_swaps.protocol", &frontrun_swaps.protocol)?;
 
         let frontrun_gas_details: ClickhouseVecGasDetails =
             (self.frontrun_tx_hash.clone(), self.frontrun_gas_details.clone()).into();
@@ -207,6 +252,10 @@ impl Serialize for Sandwich {
             "frontrun_gas_details.effective_gas_price",
             &frontrun_gas_details.effective_gas_price,
         )?;
+        ser_struct.serialize_field(
+            "frontrun_gas_details.blob_gas_paid",
+            &frontrun_gas_details.blob_gas_paid,
+        )?;
 
         // victims
         let victim_swaps: ClickhouseDoubleVecNormalizedSwap =
@@ -221,7 +270,12 @@ impl Serialize for Sandwich {
         ser_struct.serialize_field("victim_swaps.token_in", &victim_swaps.token_in)?;
         ser_struct.serialize_field("victim_swaps.token_out", &victim_swaps.token_out)?;
         ser_struct.serialize_field("victim_swaps.amount_in", &victim_swaps.amount_in)?;
+        ser_struct
+            .serialize_field("victim_swaps.amount_in_float", &victim_swaps.amount_in_float)?;
         ser_struct.serialize_field("victim_swaps.amount_out", &victim_swaps.amount_out)?;
+        ser_struct
+            .serialize_field("victim_swaps.amount_out_float", &victim_swaps.amount_out_float)?;
+        ser_struct.serialize_field("victim_swaps.protocol", &victim_swaps.protocol)?;
 
         let victim_gas_details: ClickhouseVecGasDetails =
             (self.victim_swaps_tx_hashes.clone(), self.victim_swaps_gas_details.clone()).into();
@@ -237,6 +291,10 @@ impl Serialize for Sandwich {
             "victim_gas_details.effective_gas_price",
             &victim_gas_details.effective_gas_price,
         )?;
+        ser_struct.serialize_field(
+            "victim_gas_details.blob_gas_paid",
+            &victim_gas_details.blob_gas_paid,
+        )?;
 
         // backrun
         let fixed_str_backrun_tx_hash = format!("{:?}", &self.backrun_tx_hash);
@@ -261,7 +319,12 @@ impl Serialize for Sandwich {
         ser_struct.serialize_field("backrun_swaps.token_in", &backrun_swaps.token_in)?;
         ser_struct.serialize_field("backrun_swaps.token_out", &backrun_swaps.token_out)?;
         ser_struct.serialize_field("backrun_swaps.amount_in", &backrun_swaps.amount_in)?;
+        ser_struct
+            .serialize_field("backrun_swaps.amount_in_float", &backrun_swaps.amount_in_float)?;
         ser_struct.serialize_field("backrun_swaps.amount_out", &backrun_swaps.amount_out)?;
+        ser_struct
+            .serialize_field("backrun_swaps.amount_out_float", &backrun_swaps.amount_out_float)?;
+        ser_struct.serialize_field("backrun_swaps.protocol", &backrun_swaps.protocol)?;
 
         ser_struct
             .serialize_field("backrun_gas_details.tx_hash", &vec![fixed_str_backrun_tx_hash])?;
@@ -281,6 +344,102 @@ impl Serialize for Sandwich {
             "backrun_gas_details.effective_gas_price",
             &vec![self.backrun_gas_details.effective_gas_price],
         )?;
+        ser_struct.serialize_field(
+            "backrun_gas_details.blob_gas_paid",
+            &vec![self.backrun_gas_details.blob_gas_paid],
+        )?;
+
+        // victim slippage
+        let slippage = &self.victim_slippage;
+        ser_struct.serialize_field(
+            "victim_slippage.tx_hash",
+            &slippage
+                .iter()
+                .map(|s| format!("{:?}", s.tx_hash))
+                .collect::<Vec<_>>(),
+        )?;
+        ser_struct.serialize_field(
+            "victim_slippage.token",
+            &slippage
+                .iter()
+                .map(|s| format!("{:?}", s.token))
+                .collect::<Vec<_>>(),
+        )?;
+        ser_struct.serialize_field(
+            "victim_slippage.exact_in",
+            &slippage.iter().map(|s| s.exact_in).collect::<Vec<_>>(),
+        )?;
+        ser_struct.serialize_field(
+            "victim_slippage.limit",
+            &slippage.iter().map(|s| s.limit).collect::<Vec<_>>(),
+        )?;
+        ser_struct.serialize_field(
+            "victim_slippage.realized",
+            &slippage.iter().map(|s| s.realized).collect::<Vec<_>>(),
+        )?;
+        ser_struct.serialize_field(
+            "victim_slippage.sqrt_price_limit",
+            &slippage
+                .iter()
+                .map(|s| s.sqrt_price_limit.to_le_bytes::<32>())
+                .collect::<Vec<_>>(),
+        )?;
+        ser_struct.serialize_field(
+            "victim_slippage.remaining_slippage",
+            &slippage
+                .iter()
+                .map(|s| s.remaining_slippage)
+                .collect::<Vec<_>>(),
+        )?;
+        ser_struct.serialize_field(
+            "victim_slippage.consumed_slippage",
+            &slippage
+                .iter()
+                .map(|s| s.consumed_slippage)
+                .collect::<Vec<_>>(),
+        )?;
+
+        // victim losses
+        let losses = &self.victim_losses;
+        ser_struct.serialize_field(
+            "victim_losses.tx_hash",
+            &losses
+                .iter()
+                .map(|l| format!("{:?}", l.tx_hash))
+                .collect::<Vec<_>>(),
+        )?;
+        ser_struct.serialize_field(
+            "victim_losses.victim_eoa",
+            &losses
+                .iter()
+                .map(|l| format!("{:?}", l.victim_eoa))
+                .collect::<Vec<_>>(),
+        )?;
+        ser_struct.serialize_field(
+            "victim_losses.token",
+            &losses
+                .iter()
+                .map(|l| format!("{:?}", l.token))
+                .collect::<Vec<_>>(),
+        )?;
+        ser_struct.serialize_field(
+            "victim_losses.token_amount_lost",
+            &losses
+                .iter()
+                .map(|l| l.token_amount_lost)
+                .collect::<Vec<_>>(),
+        )?;
+        ser_struct.serialize_field(
+            "victim_losses.amount_lost_usd",
+            &losses.iter().map(|l| l.amount_lost_usd).collect::<Vec<_>>(),
+        )?;
+        ser_struct.serialize_field(
+            "victim_losses.price_degradation",
+            &losses
+                .iter()
+                .map(|l| l.price_degradation)
+                .collect::<Vec<_>>(),
+        )?;
 
         ser_struct.end()
     }
@@ -298,12 +457,16 @@ impl DbRow for Sandwich {
         "frontrun_swaps.token_in",
         "frontrun_swaps.token_out",
         "frontrun_swaps.amount_in",
+        "frontrun_swaps.amount_in_float",
         "frontrun_swaps.amount_out",
+        "frontrun_swaps.amount_out_float",
+        "frontrun_swaps.protocol",
         "frontrun_gas_details.tx_hash",
         "frontrun_gas_details.coinbase_transfer",
         "frontrun_gas_details.priority_fee",
         "frontrun_gas_details.gas_used",
         "frontrun_gas_details.effective_gas_price",
+        "frontrun_gas_details.blob_gas_paid",
         "victim_swaps.tx_hash",
         "victim_swaps.trace_idx",
         "victim_swaps.from",
@@ -312,12 +475,16 @@ impl DbRow for Sandwich {
         "victim_swaps.token_in",
         "victim_swaps.token_out",
         "victim_swaps.amount_in",
+        "victim_swaps.amount_in_float",
         "victim_swaps.amount_out",
+        "victim_swaps.amount_out_float",
+        "victim_swaps.protocol",
         "victim_gas_details.tx_hash",
         "victim_gas_details.coinbase_transfer",
         "victim_gas_details.priority_fee",
         "victim_gas_details.gas_used",
         "victim_gas_details.effective_gas_price",
+        "victim_gas_details.blob_gas_paid",
         "backrun_tx_hash",
         "backrun_swaps.tx_hash",
         "backrun_swaps.trace_idx",
@@ -327,11 +494,29 @@ impl DbRow for Sandwich {
         "backrun_swaps.token_in",
         "backrun_swaps.token_out",
         "backrun_swaps.amount_in",
+        "backrun_swaps.amount_in_float",
         "backrun_swaps.amount_out",
+        "backrun_swaps.amount_out_float",
+        "backrun_swaps.protocol",
         "backrun_gas_details.tx_hash",
         "backrun_gas_details.coinbase_transfer",
         "backrun_gas_details.priority_fee",
         "backrun_gas_details.gas_used",
         "backrun_gas_details.effective_gas_price",
+        "backrun_gas_details.blob_gas_paid",
+        "victim_slippage.tx_hash",
+        "victim_slippage.token",
+        "victim_slippage.exact_in",
+        "victim_slippage.limit",
+        "victim_slippage.realized",
+        "victim_slippage.sqrt_price_limit",
+        "victim_slippage.remaining_slippage",
+        "victim_slippage.consumed_slippage",
+        "victim_losses.tx_hash",
+        "victim_losses.victim_eoa",
+        "victim_losses.token",
+        "victim_losses.token_amount_lost",
+        "victim_losses.amount_lost_usd",
+        "victim_losses.price_degradation",
     ];
 }
@@ -60,7 +60,7 @@ impl Serialize for SearcherTx {
     where
         S: Serializer,
     {
-        let mut ser_struct = serializer.serialize_struct("SearcherTx", 9)?;
+        let mut ser_struct = serializer.serialize_struct("SearcherTx", 11)?;
 
         ser_struct.serialize_field("tx_hash", &format!("{:?}", self.tx_hash))?;
         ser_struct.serialize_field("block_number", &self.block_number)?;
@@ -75,13 +75,16 @@ impl Serialize for SearcherTx {
         ser_struct.serialize_field("transfers.to", &victim_transfer.to)?;
         ser_struct.serialize_field("transfers.token", &victim_transfer.token)?;
         ser_struct.serialize_field("transfers.amount", &victim_transfer.amount)?;
+        ser_struct.serialize_field("transfers.amount_float", &victim_transfer.amount_float)?;
         ser_struct.serialize_field("transfers.fee", &victim_transfer.fee)?;
+        ser_struct.serialize_field("transfers.fee_float", &victim_transfer.fee_float)?;
 
         let gas_details = (
             self.gas_details.coinbase_transfer,
             self.gas_details.priority_fee,
             self.gas_details.gas_used,
             self.gas_details.effective_gas_price,
+            self.gas_details.blob_gas_paid,
         );
 
         ser_struct.serialize_field("gas_details", &(gas_details))?;
@@ -99,7 +102,9 @@ impl DbRow for SearcherTx {
         "transfers.to",
         "transfers.token",
         "transfers.amount",
+        "transfers.amount_float",
         "transfers.fee",
+        "transfers.fee_float",
         "gas_details",
     ];
 }
@@ -30,6 +30,13 @@ impl MultiBlockData {
     pub fn get_most_recent_block(&self) -> &BlockData {
         self.per_block_data.last().unwrap()
     }
+
+    /// The blocks before the most recent one, oldest first
+    pub fn previous_blocks(&self) -> impl Iterator<Item = &BlockData> {
+        self.per_block_data
+            .iter()
+            .take(self.per_block_data.len().saturating_sub(1))
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -0,0 +1,47 @@
+use std::fmt::Debug;
+
+use clickhouse::Row;
+use reth_primitives::{Address, U256};
+use serde::{Deserialize, Serialize};
+
+use super::accounting::{AddressDeltas, TokenAccounting};
+
+#[derive(Debug, Default, Serialize, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+pub enum ApprovalKind {
+    #[default]
+    Approve,
+    IncreaseAllowance,
+    /// An eip-2612 permit, or dai's older permit, signed by the owner and
+    /// submitted by anyone
+    Permit,
+}
+
+/// An owner allowing a spender to move its tokens. Token drainers and approval
+/// frontrunning both rely on approvals, so they are kept in the tree even
+/// though they don't move any tokens themselves. The amount is left raw as the
+/// token info isn't needed to tell what was approved
+#[derive(Debug, Default, Serialize, Clone, Row, PartialEq, Eq, Deserialize)]
+pub struct NormalizedApproval {
+    pub trace_index: u64,
+    pub kind:        ApprovalKind,
+    pub token:       Address,
+    pub owner:       Address,
+    pub spender:     Address,
+    /// the new allowance, or the amount added to it for `increaseAllowance`
+    pub amount:      U256,
+}
+
+impl NormalizedApproval {
+    pub fn is_unlimited(&self) -> bool {
+        self.amount == U256::MAX
+    }
+
+    /// Setting the allowance to zero takes away an earlier approval
+    pub fn is_revoke(&self) -> bool {
+        self.kind != ApprovalKind::IncreaseAllowance && self.amount.is_zero()
+    }
+}
+
+impl TokenAccounting for NormalizedApproval {
+    fn apply_token_deltas(&self, _: &mut AddressDeltas) {}
+}
@@ -1,6 +1,12 @@
 use std::fmt::Debug;
 
-use super::{Action, NormalizedCollect, NormalizedMint, NormalizedSwap, NormalizedTransfer};
+use alloy_primitives::Address;
+use malachite::Rational;
+
+use super::{
+    Action, NormalizedCollect, NormalizedMint, NormalizedSwap, NormalizedTransfer,
+    NormalizedTwammOrder, TwammOrderAction,
+};
 
 impl<T: Sized + SubordinateAction<O>, O: ActionCmp<T>> ActionComparison<O> for T {}
 
@@ -21,6 +27,7 @@ impl ActionCmp<Action> for Action {
             Action::Mint(m) => m.is_superior_action(other),
             Action::Collect(c) => c.is_superior_action(other),
             Action::SwapWithFee(s) => s.swap.is_superior_action(other),
+            Action::TwammOrder(t) => t.is_superior_action(other),
             Action::FlashLoan(f) => f.child_actions.iter().any(|a| a.is_superior_action(other)),
             Action::Batch(b) => {
                 let user = b.user_swaps.iter().any(|b| b.is_superior_action(other));
@@ -119,3 +126,35 @@ impl ActionCmp<Action> for NormalizedCollect {
         }
     }
 }
+
+impl ActionCmp<NormalizedTransfer> for NormalizedTwammOrder {
+    fn is_superior_action(&self, transfer: &NormalizedTransfer) -> bool {
+        let pays_out = |token: Address, amount: &Rational| {
+            transfer.from == self.pool
+                && transfer.token.address == token
+                && &transfer.amount == amount
+        };
+
+        match self.action {
+            TwammOrderAction::Place => {
+                transfer.to == self.pool
+                    && transfer.token.address == self.token_in.address
+                    && &transfer.amount + &transfer.fee == self.amount_in
+            }
+            // the refund of the unsold amount and the proceeds
+            TwammOrderAction::Cancel | TwammOrderAction::Withdraw => {
+                pays_out(self.token_in.address, &self.amount_in)
+                    || pays_out(self.token_out.address, &self.amount_out)
+            }
+        }
+    }
+}
+
+impl ActionCmp<Action> for NormalizedTwammOrder {
+    fn is_superior_action(&self, other: &Action) -> bool {
+        match other {
+            Action::Transfer(t) => self.is_superior_action(t),
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use std::fmt::Debug;
+
+use clickhouse::Row;
+use reth_primitives::Address;
+use serde::{Deserialize, Serialize};
+
+use super::accounting::{AddressDeltas, TokenAccounting};
+use crate::Protocol;
+
+#[derive(Debug, Default, Serialize, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+pub enum GovernanceAction {
+    #[default]
+    Pause,
+    Unpause,
+    /// Funds are pulled out of the protocol by its admin, such as Convex
+    /// shutting down a pool and withdrawing its lp tokens from the gauge
+    EmergencyWithdraw,
+}
+
+/// An admin or guardian of a protocol pausing or unpausing it, or pulling its
+/// funds out in an emergency. These mostly happen in response to an exploit, so
+/// mev in the same block is often part of it
+#[derive(Debug, Default, Serialize, Clone, Row, PartialEq, Eq, Deserialize)]
+pub struct NormalizedGovernance {
+    pub protocol:    Protocol,
+    pub trace_index: u64,
+    pub action:      GovernanceAction,
+    /// the contract that was paused
+    pub target:      Address,
+    /// the admin or guardian making the call
+    pub from:        Address,
+}
+
+/// Governance actions don't move tokens themselves, an emergency withdrawal
+/// moves them with transfers that are accounted for on their own
+impl TokenAccounting for NormalizedGovernance {
+    fn apply_token_deltas(&self, _: &mut AddressDeltas) {}
+}
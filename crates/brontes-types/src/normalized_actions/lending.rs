@@ -6,6 +6,7 @@ use malachite::Rational;
 use reth_primitives::Address;
 use serde::{Deserialize, Serialize};
 
+use super::accounting::{AddressDeltas, TokenAccounting};
 use crate::{db::token_info::TokenInfoWithAddress, FastHashMap, Protocol};
 
 #[derive(Debug, Serialize, Clone, Row, PartialEq, Eq, Deserialize)]
@@ -31,3 +32,139 @@ pub struct NormalizedRepayment {
     pub collateral:       FastHashMap<TokenInfoWithAddress, Rational>,
     pub msg_value:        U256,
 }
+
+#[derive(Debug, Default, Serialize, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+pub enum LendingAction {
+    #[default]
+    Deposit,
+    Withdraw,
+    Borrow,
+    Repay,
+}
+
+/// A single interaction with a lending market
+#[derive(Debug, Default, Serialize, Clone, Row, PartialEq, Eq, Deserialize)]
+pub struct NormalizedLending {
+    pub protocol:    Protocol,
+    pub trace_index: u64,
+    pub action:      LendingAction,
+    pub market:      Address,
+    /// the caller of the lending market
+    pub from:        Address,
+    /// the owner of the position that is being modified
+    pub account:     Address,
+    pub token:       TokenInfoWithAddress,
+    pub amount:      Rational,
+    pub msg_value:   U256,
+}
+
+impl TokenAccounting for NormalizedLending {
+    /// The funds don't always move through the market, e.g Aave holds them in
+    /// the aToken, so the token transfers of the action are what is accounted
+    /// for, along with the mint or burn of the position's tokens
+    fn apply_token_deltas(&self, _: &mut AddressDeltas) {}
+}
+
+/// A set of lending actions in a single transaction that moves an account's
+/// collateral or debt from one market or asset to another, e.g a refinancing
+/// or a liquidation-prevention collateral swap. These are commonly funded
+/// by a flash loan and mostly resemble an arbitrage from the outside.
+#[derive(Debug, Default, Serialize, Clone, Row, PartialEq, Eq, Deserialize)]
+pub struct NormalizedCollateralSwap {
+    pub trace_index:     u64,
+    pub account:         Address,
+    pub lending_actions: Vec<NormalizedLending>,
+}
+
+impl NormalizedCollateralSwap {
+    /// Checks whether the given lending actions of a single account form a
+    /// collateral or debt swap. This is the case when collateral is both
+    /// withdrawn and deposited, or debt is both repaid and borrowed, across
+    /// different markets or assets.
+    pub fn try_new(account: Address, mut lending_actions: Vec<NormalizedLending>) -> Option<Self> {
+        let moved = |out: LendingAction, into: LendingAction| {
+            lending_actions.iter().filter(|a| a.action == out).any(|o| {
+                lending_actions
+                    .iter()
+                    .filter(|a| a.action == into)
+                    .any(|i| {
+                        o.protocol != i.protocol
+                            || o.market != i.market
+                            || o.token.address != i.token.address
+                    })
+            })
+        };
+
+        if !moved(LendingAction::Withdraw, LendingAction::Deposit)
+            && !moved(LendingAction::Repay, LendingAction::Borrow)
+        {
+            return None
+        }
+
+        lending_actions.sort_by_key(|a| a.trace_index);
+
+        Some(Self { trace_index: lending_actions[0].trace_index, account, lending_actions })
+    }
+}
+
+impl TokenAccounting for NormalizedCollateralSwap {
+    /// like its lending actions, the swap is accounted for by the token
+    /// transfers
+    fn apply_token_deltas(&self, _: &mut AddressDeltas) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::normalized_actions::{accounting::ActionAccounting, Action, NormalizedTransfer};
+
+    #[test]
+    fn test_lending_deltas_follow_the_transfers() {
+        let user = Address::with_last_byte(1);
+        let pool = Address::with_last_byte(2);
+        let usdc =
+            TokenInfoWithAddress { address: Address::with_last_byte(3), ..Default::default() };
+        let a_usdc =
+            TokenInfoWithAddress { address: Address::with_last_byte(4), ..Default::default() };
+        let amount = Rational::from(100);
+
+        let deposit = NormalizedLending {
+            market: pool,
+            from: user,
+            account: user,
+            token: usdc.clone(),
+            amount: amount.clone(),
+            ..Default::default()
+        };
+        // the underlying goes to the aToken and the position is minted
+        let transfers = [
+            NormalizedTransfer {
+                trace_index: 1,
+                from: user,
+                to: a_usdc.address,
+                token: usdc.clone(),
+                amount: amount.clone(),
+                ..Default::default()
+            },
+            NormalizedTransfer {
+                trace_index: 2,
+                from: Address::ZERO,
+                to: user,
+                token: a_usdc.clone(),
+                amount: amount.clone(),
+                ..Default::default()
+            },
+        ];
+
+        let deltas = [Action::Lending(deposit)]
+            .into_iter()
+            .chain(transfers.into_iter().map(Action::Transfer))
+            .account_for_actions();
+
+        let user_deltas = deltas.get(&user).unwrap();
+        assert_eq!(user_deltas.get(&usdc.address), Some(&-amount.clone()));
+        assert_eq!(user_deltas.get(&a_usdc.address), Some(&amount));
+        assert_eq!(deltas.get(&a_usdc.address).unwrap().get(&usdc.address), Some(&amount));
+        assert!(!deltas.contains_key(&pool));
+    }
+}
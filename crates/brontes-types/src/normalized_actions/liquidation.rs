@@ -16,7 +16,7 @@ use crate::{
         redefined_types::{malachite::RationalRedefined, primitives::*},
         token_info::{TokenInfoWithAddress, TokenInfoWithAddressRedefined},
     },
-    rational_to_u256_fraction, Protocol,
+    rational_to_u256_fraction, Protocol, ToFloatNearest,
 };
 
 #[derive(Default, Debug, Serialize, Clone, Row, PartialEq, Eq, Deserialize, Redefined)]
@@ -146,14 +146,19 @@ impl NormalizedLiquidation {
 }
 
 pub struct ClickhouseVecNormalizedLiquidation {
-    pub trace_index:           Vec<u64>,
-    pub pool:                  Vec<String>,
-    pub liquidator:            Vec<String>,
-    pub debtor:                Vec<String>,
-    pub collateral_asset:      Vec<(String, String)>,
-    pub debt_asset:            Vec<(String, String)>,
-    pub covered_debt:          Vec<([u8; 32], [u8; 32])>,
+    pub trace_index: Vec<u64>,
+    pub pool: Vec<String>,
+    pub liquidator: Vec<String>,
+    pub debtor: Vec<String>,
+    pub collateral_asset: Vec<(String, String)>,
+    pub debt_asset: Vec<(String, String)>,
+    pub covered_debt: Vec<([u8; 32], [u8; 32])>,
     pub liquidated_collateral: Vec<([u8; 32], [u8; 32])>,
+    /// the amounts in token units, e.g 1.5 WETH
+    pub covered_debt_float: Vec<f64>,
+    pub liquidated_collateral_float: Vec<f64>,
+    /// (family, version, fork) of the lending protocol
+    pub protocol: Vec<(String, String, String)>,
 }
 
 impl TryFrom<Vec<NormalizedLiquidation>> for ClickhouseVecNormalizedLiquidation {
@@ -172,22 +177,34 @@ impl TryFrom<Vec<NormalizedLiquidation>> for ClickhouseVecNormalizedLiquidation
                 .map(|val| format!("{:?}", val.debtor))
                 .collect(),
 
-            collateral_asset:      value
+            collateral_asset:            value
                 .iter()
                 .map(|val| val.collateral_asset.clickhouse_fmt())
                 .collect(),
-            debt_asset:            value
+            debt_asset:                  value
                 .iter()
                 .map(|val| val.debt_asset.clickhouse_fmt())
                 .collect(),
-            covered_debt:          value
+            covered_debt:                value
                 .iter()
                 .map(|val| rational_to_u256_fraction(&val.covered_debt))
                 .collect::<eyre::Result<Vec<_>>>()?,
-            liquidated_collateral: value
+            liquidated_collateral:       value
                 .iter()
                 .map(|val| rational_to_u256_fraction(&val.liquidated_collateral))
                 .collect::<eyre::Result<Vec<_>>>()?,
+            covered_debt_float:          value
+                .iter()
+                .map(|val| val.covered_debt.clone().to_float())
+                .collect(),
+            liquidated_collateral_float: value
+                .iter()
+                .map(|val| val.liquidated_collateral.clone().to_float())
+                .collect(),
+            protocol:                    value
+                .iter()
+                .map(|val| val.protocol.version().clickhouse_fmt())
+                .collect(),
         })
     }
 }
@@ -89,12 +89,14 @@ impl TokenAccounting for NormalizedCollect {
 
 #[derive(Default)]
 pub struct ClickhouseVecNormalizedMintOrBurn {
-    pub trace_index: Vec<u64>,
-    pub from:        Vec<String>,
-    pub pool:        Vec<String>,
-    pub recipient:   Vec<String>,
-    pub tokens:      Vec<Vec<(String, String)>>,
-    pub amounts:     Vec<Vec<([u8; 32], [u8; 32])>>,
+    pub trace_index:   Vec<u64>,
+    pub from:          Vec<String>,
+    pub pool:          Vec<String>,
+    pub recipient:     Vec<String>,
+    pub tokens:        Vec<Vec<(String, String)>>,
+    pub amounts:       Vec<Vec<([u8; 32], [u8; 32])>>,
+    /// the amounts in token units, e.g 1.5 WETH
+    pub amounts_float: Vec<Vec<f64>>,
 }
 
 impl fmt::Display for NormalizedMint {
@@ -164,11 +166,11 @@ impl TryFrom<Vec<NormalizedMint>> for ClickhouseVecNormalizedMintOrBurn {
                 .map(|val| format!("{:?}", val.recipient))
                 .collect(),
 
-            tokens:  value
+            tokens:        value
                 .iter()
                 .map(|val| val.token.iter().map(|t| t.clickhouse_fmt()).collect_vec())
                 .collect(),
-            amounts: value
+            amounts:       value
                 .iter()
                 .map(|val| {
                     val.amount
@@ -177,6 +179,10 @@ impl TryFrom<Vec<NormalizedMint>> for ClickhouseVecNormalizedMintOrBurn {
                         .collect::<eyre::Result<Vec<_>>>()
                 })
                 .collect::<eyre::Result<Vec<_>>>()?,
+            amounts_float: value
+                .iter()
+                .map(|val| val.amount.iter().map(|a| a.clone().to_float()).collect())
+                .collect(),
         })
     }
 }
@@ -194,11 +200,11 @@ impl TryFrom<Vec<NormalizedBurn>> for ClickhouseVecNormalizedMintOrBurn {
                 .map(|val| format!("{:?}", val.recipient))
                 .collect(),
 
-            tokens:  value
+            tokens:        value
                 .iter()
                 .map(|val| val.token.iter().map(|t| t.clickhouse_fmt()).collect_vec())
                 .collect(),
-            amounts: value
+            amounts:       value
                 .iter()
                 .map(|val| {
                     val.amount
@@ -207,19 +213,25 @@ impl TryFrom<Vec<NormalizedBurn>> for ClickhouseVecNormalizedMintOrBurn {
                         .collect::<eyre::Result<Vec<_>>>()
                 })
                 .collect::<eyre::Result<Vec<_>>>()?,
+            amounts_float: value
+                .iter()
+                .map(|val| val.amount.iter().map(|a| a.clone().to_float()).collect())
+                .collect(),
         })
     }
 }
 
 #[derive(Default)]
 pub struct ClickhouseVecNormalizedMintOrBurnWithTxHash {
-    pub tx_hash:     Vec<String>,
-    pub trace_index: Vec<u64>,
-    pub from:        Vec<String>,
-    pub pool:        Vec<String>,
-    pub recipient:   Vec<String>,
-    pub tokens:      Vec<Vec<(String, String)>>,
-    pub amounts:     Vec<Vec<([u8; 32], [u8; 32])>>,
+    pub tx_hash:       Vec<String>,
+    pub trace_index:   Vec<u64>,
+    pub from:          Vec<String>,
+    pub pool:          Vec<String>,
+    pub recipient:     Vec<String>,
+    pub tokens:        Vec<Vec<(String, String)>>,
+    pub amounts:       Vec<Vec<([u8; 32], [u8; 32])>>,
+    /// the amounts in token units, e.g 1.5 WETH
+    pub amounts_float: Vec<Vec<f64>>,
 }
 
 // (tx_hashes, mints)
@@ -256,6 +268,7 @@ impl TryFrom<(Vec<TxHash>, Vec<Option<Vec<NormalizedMint>>>)>
                 this.recipient.extend(db_mint_with_tx.recipient);
                 this.tokens.extend(db_mint_with_tx.tokens);
                 this.amounts.extend(db_mint_with_tx.amounts);
+                this.amounts_float.extend(db_mint_with_tx.amounts_float);
             });
 
         Ok(this)
@@ -294,6 +307,7 @@ impl TryFrom<(Vec<TxHash>, Vec<Option<Vec<NormalizedBurn>>>)>
                 this.recipient.extend(db_burn_with_tx.recipient);
                 this.tokens.extend(db_burn_with_tx.tokens);
                 this.amounts.extend(db_burn_with_tx.amounts);
+                this.amounts_float.extend(db_burn_with_tx.amounts_float);
             });
 
         Ok(this)
@@ -1,36 +1,50 @@
 pub mod accounting;
 pub mod aggregator;
+pub mod approval;
 pub mod batch;
 pub mod comparison;
 pub mod eth_transfer;
 pub mod flashloan;
+pub mod governance;
 pub mod lending;
 pub mod liquidation;
 pub mod liquidity;
 pub mod multi_callframe;
+pub mod nft;
 pub mod pool;
+pub mod redemption;
 pub mod self_destruct;
+pub mod stake;
 pub mod swaps;
 pub mod transfer;
+pub mod twamm;
+pub mod wrap;
 use std::fmt::Debug;
 
 use ::clickhouse::DbRow;
 use accounting::{AddressDeltas, TokenAccounting};
 pub use aggregator::*;
 use alloy_primitives::{Address, Bytes, Log};
+pub use approval::*;
 pub use batch::*;
 use clickhouse::InsertRow;
 pub use eth_transfer::*;
 pub use flashloan::*;
+pub use governance::*;
 pub use lending::*;
 pub use liquidation::*;
 pub use liquidity::*;
 pub use multi_callframe::*;
+pub use nft::*;
 pub use pool::*;
+pub use redemption::*;
 use reth_rpc_types::trace::parity::Action as TraceAction;
 pub use self_destruct::*;
+pub use stake::*;
 pub use swaps::*;
 pub use transfer::*;
+pub use twamm::*;
+pub use wrap::*;
 
 use crate::{
     structured_trace::{TraceActions, TransactionTraceWithLogs},
@@ -97,6 +111,17 @@ impl NormalizedAction for Action {
             Self::NewPool(p) => p.trace_index,
             Self::PoolConfigUpdate(p) => p.trace_index,
             Self::Aggregator(a) => a.trace_index,
+            Self::Lending(l) => l.trace_index,
+            Self::CollateralSwap(c) => c.trace_index,
+            Self::TwammOrder(t) => t.trace_index,
+            Self::Wrap(w) => w.trace_index,
+            Self::Redemption(r) => r.trace_index,
+            Self::Stake(s) => s.trace_index,
+            Self::NftTrade(n) => n.trace_index,
+            Self::NftLoan(n) => n.trace_index,
+            Self::NftTransfer(n) => n.trace_index,
+            Self::Governance(g) => g.trace_index,
+            Self::Approval(a) => a.trace_index,
             Self::Revert => unreachable!("no trace index for revert"),
         }
     }
@@ -119,6 +144,17 @@ pub enum Action {
     NewPool(NormalizedNewPool),
     PoolConfigUpdate(NormalizedPoolConfigUpdate),
     Aggregator(NormalizedAggregator),
+    Lending(NormalizedLending),
+    CollateralSwap(NormalizedCollateralSwap),
+    TwammOrder(NormalizedTwammOrder),
+    Wrap(NormalizedWrap),
+    Redemption(NormalizedRedemption),
+    Stake(NormalizedStake),
+    NftTrade(NormalizedNftTrade),
+    NftLoan(NormalizedNftLoan),
+    NftTransfer(NormalizedNftTransfer),
+    Governance(NormalizedGovernance),
+    Approval(NormalizedApproval),
     Unclassified(TransactionTraceWithLogs),
     Revert,
 }
@@ -141,6 +177,17 @@ impl InsertRow for Action {
             Action::PoolConfigUpdate(_) => todo!(),
             Action::Unclassified(..) | Action::Revert => panic!(),
             Action::Aggregator(_) => NormalizedAggregator::COLUMN_NAMES,
+            Action::Lending(_) => NormalizedLending::COLUMN_NAMES,
+            Action::CollateralSwap(_) => NormalizedCollateralSwap::COLUMN_NAMES,
+            Action::TwammOrder(_) => NormalizedTwammOrder::COLUMN_NAMES,
+            Action::Wrap(_) => NormalizedWrap::COLUMN_NAMES,
+            Action::Redemption(_) => NormalizedRedemption::COLUMN_NAMES,
+            Action::Stake(_) => NormalizedStake::COLUMN_NAMES,
+            Action::NftTrade(_) => NormalizedNftTrade::COLUMN_NAMES,
+            Action::NftLoan(_) => NormalizedNftLoan::COLUMN_NAMES,
+            Action::NftTransfer(_) => NormalizedNftTransfer::COLUMN_NAMES,
+            Action::Governance(_) => NormalizedGovernance::COLUMN_NAMES,
+            Action::Approval(_) => NormalizedApproval::COLUMN_NAMES,
         }
     }
 }
@@ -163,6 +210,17 @@ impl serde::Serialize for Action {
             Action::Liquidation(c) => c.serialize(serializer),
             Action::SelfDestruct(sd) => sd.serialize(serializer),
             Action::EthTransfer(et) => et.serialize(serializer),
+            Action::Lending(l) => l.serialize(serializer),
+            Action::CollateralSwap(c) => c.serialize(serializer),
+            Action::TwammOrder(t) => t.serialize(serializer),
+            Action::Wrap(w) => w.serialize(serializer),
+            Action::Redemption(r) => r.serialize(serializer),
+            Action::Stake(s) => s.serialize(serializer),
+            Action::NftTrade(n) => n.serialize(serializer),
+            Action::NftLoan(n) => n.serialize(serializer),
+            Action::NftTransfer(n) => n.serialize(serializer),
+            Action::Governance(g) => g.serialize(serializer),
+            Action::Approval(a) => a.serialize(serializer),
             Action::Unclassified(trace) => (trace).serialize(serializer),
             action => format!("{:?}", action).serialize(serializer),
             //action => unreachable!("no action serialization for {action:?}"),
@@ -218,14 +276,50 @@ impl Action {
                     from: a.from,
                     ..Default::default()
                 }),
+                Self::Lending(l) => (!l.msg_value.is_zero()).then(|| NormalizedEthTransfer {
+                    value: l.msg_value,
+                    to: l.market,
+                    from: l.from,
+                    ..Default::default()
+                }),
+                Self::TwammOrder(t) => (!t.msg_value.is_zero()).then(|| NormalizedEthTransfer {
+                    value: t.msg_value,
+                    to: t.pool,
+                    from: t.from,
+                    ..Default::default()
+                }),
+                Self::Wrap(w) => (!w.msg_value.is_zero()).then(|| NormalizedEthTransfer {
+                    value: w.msg_value,
+                    to: w.weth,
+                    from: w.from,
+                    ..Default::default()
+                }),
+                Self::NftTrade(n) => (!n.msg_value.is_zero()).then(|| NormalizedEthTransfer {
+                    value: n.msg_value,
+                    to: n.marketplace,
+                    from: n.taker,
+                    ..Default::default()
+                }),
+                Self::NftLoan(n) => (!n.msg_value.is_zero()).then(|| NormalizedEthTransfer {
+                    value: n.msg_value,
+                    to: n.market,
+                    from: n.from,
+                    ..Default::default()
+                }),
                 Self::Mint(_) => None,
                 Self::Burn(_) => None,
                 Self::Transfer(_) => None,
+                Self::NftTransfer(_) => None,
+                Self::Governance(_) => None,
+                Self::Approval(_) => None,
                 Self::Collect(_) => None,
                 Self::SelfDestruct(_) => None,
                 Self::EthTransfer(_) => None,
                 Self::NewPool(_) => None,
+                Self::Redemption(_) => None,
+                Self::Stake(_) => None,
                 Self::PoolConfigUpdate(_) => None,
+                Self::CollateralSwap(_) => None,
                 Self::Revert => None,
             };
         if res.is_some() {
@@ -258,6 +352,17 @@ impl Action {
             Self::NewPool(p) => p.trace_index,
             Self::PoolConfigUpdate(p) => p.trace_index,
             Self::Aggregator(a) => a.trace_index,
+            Self::Lending(l) => l.trace_index,
+            Self::CollateralSwap(c) => c.trace_index,
+            Self::TwammOrder(t) => t.trace_index,
+            Self::Wrap(w) => w.trace_index,
+            Self::Redemption(r) => r.trace_index,
+            Self::Stake(s) => s.trace_index,
+            Self::NftTrade(n) => n.trace_index,
+            Self::NftLoan(n) => n.trace_index,
+            Self::NftTransfer(n) => n.trace_index,
+            Self::Governance(g) => g.trace_index,
+            Self::Approval(a) => a.trace_index,
             Self::Revert => return None,
         })
     }
@@ -335,6 +440,17 @@ impl Action {
             Action::EthTransfer(t) => t.to,
             Action::NewPool(p) => p.pool_address,
             Action::PoolConfigUpdate(p) => p.pool_address,
+            Action::Lending(l) => l.market,
+            Action::CollateralSwap(_) => Address::ZERO,
+            Action::TwammOrder(t) => t.pool,
+            Action::Wrap(w) => w.weth,
+            Action::Redemption(r) => r.pool,
+            Action::Stake(s) => s.staking_contract,
+            Action::NftTrade(n) => n.marketplace,
+            Action::NftLoan(n) => n.market,
+            Action::NftTransfer(n) => n.to,
+            Action::Governance(g) => g.target,
+            Action::Approval(a) => a.token,
             Action::Revert => Address::ZERO,
         }
     }
@@ -362,6 +478,17 @@ impl Action {
             Action::Revert => unreachable!(),
             Action::NewPool(_) => Address::ZERO,
             Action::PoolConfigUpdate(_) => Address::ZERO,
+            Action::Lending(l) => l.from,
+            Action::CollateralSwap(c) => c.account,
+            Action::TwammOrder(t) => t.from,
+            Action::Wrap(w) => w.from,
+            Action::Redemption(r) => r.redeemer,
+            Action::Stake(s) => s.from,
+            Action::NftTrade(n) => n.taker,
+            Action::NftLoan(n) => n.from,
+            Action::NftTransfer(n) => n.from,
+            Action::Governance(g) => g.from,
+            Action::Approval(a) => a.owner,
         }
     }
 
@@ -431,6 +558,50 @@ impl Action {
         matches!(self, Action::PoolConfigUpdate(_))
     }
 
+    pub const fn is_lending(&self) -> bool {
+        matches!(self, Action::Lending(_))
+    }
+
+    pub const fn is_collateral_swap(&self) -> bool {
+        matches!(self, Action::CollateralSwap(_))
+    }
+
+    pub const fn is_twamm_order(&self) -> bool {
+        matches!(self, Action::TwammOrder(_))
+    }
+
+    pub const fn is_wrap(&self) -> bool {
+        matches!(self, Action::Wrap(_))
+    }
+
+    pub const fn is_redemption(&self) -> bool {
+        matches!(self, Action::Redemption(_))
+    }
+
+    pub const fn is_stake(&self) -> bool {
+        matches!(self, Action::Stake(_))
+    }
+
+    pub const fn is_nft_trade(&self) -> bool {
+        matches!(self, Action::NftTrade(_))
+    }
+
+    pub const fn is_nft_loan(&self) -> bool {
+        matches!(self, Action::NftLoan(_))
+    }
+
+    pub const fn is_nft_transfer(&self) -> bool {
+        matches!(self, Action::NftTransfer(_))
+    }
+
+    pub const fn is_governance(&self) -> bool {
+        matches!(self, Action::Governance(_))
+    }
+
+    pub const fn is_approval(&self) -> bool {
+        matches!(self, Action::Approval(_))
+    }
+
     pub const fn is_unclassified(&self) -> bool {
         matches!(self, Action::Unclassified(_))
     }
@@ -448,6 +619,14 @@ impl Action {
             Action::NewPool(p) => p.protocol,
             Action::PoolConfigUpdate(p) => p.protocol,
             Action::Aggregator(a) => a.protocol,
+            Action::Lending(l) => l.protocol,
+            Action::TwammOrder(t) => t.protocol,
+            Action::Wrap(w) => w.protocol,
+            Action::Redemption(r) => r.protocol,
+            Action::Stake(s) => s.protocol,
+            Action::NftTrade(n) => n.protocol,
+            Action::NftLoan(n) => n.protocol,
+            Action::Governance(g) => g.protocol,
             _ => Protocol::Unknown,
         }
     }
@@ -526,7 +705,18 @@ extra_impls!(
     (FlashLoan, NormalizedFlashLoan),
     (Aggregator, NormalizedAggregator),
     (Batch, NormalizedBatch),
-    (NewPool, NormalizedNewPool)
+    (NewPool, NormalizedNewPool),
+    (Lending, NormalizedLending),
+    (CollateralSwap, NormalizedCollateralSwap),
+    (TwammOrder, NormalizedTwammOrder),
+    (Wrap, NormalizedWrap),
+    (Redemption, NormalizedRedemption),
+    (Stake, NormalizedStake),
+    (NftTrade, NormalizedNftTrade),
+    (NftLoan, NormalizedNftLoan),
+    (NftTransfer, NormalizedNftTransfer),
+    (Governance, NormalizedGovernance),
+    (Approval, NormalizedApproval)
 );
 
 /// Custom impl for itering over swaps and swap with fee
@@ -577,6 +767,19 @@ impl TokenAccounting for Action {
             Action::Mint(mint) => mint.apply_token_deltas(delta_map),
             Action::SwapWithFee(swap_with_fee) => swap_with_fee.swap.apply_token_deltas(delta_map),
             Action::Collect(collect) => collect.apply_token_deltas(delta_map),
+            Action::Lending(lending) => lending.apply_token_deltas(delta_map),
+            Action::CollateralSwap(collateral_swap) => {
+                collateral_swap.apply_token_deltas(delta_map)
+            }
+            Action::TwammOrder(twamm_order) => twamm_order.apply_token_deltas(delta_map),
+            Action::Wrap(wrap) => wrap.apply_token_deltas(delta_map),
+            Action::Redemption(redemption) => redemption.apply_token_deltas(delta_map),
+            Action::Stake(stake) => stake.apply_token_deltas(delta_map),
+            Action::NftTrade(trade) => trade.apply_token_deltas(delta_map),
+            Action::NftLoan(loan) => loan.apply_token_deltas(delta_map),
+            Action::NftTransfer(transfer) => transfer.apply_token_deltas(delta_map),
+            Action::Governance(governance) => governance.apply_token_deltas(delta_map),
+            Action::Approval(approval) => approval.apply_token_deltas(delta_map),
             Action::EthTransfer(eth_transfer) => eth_transfer.apply_token_deltas(delta_map),
             Action::Unclassified(_) => (), /* Potentially no token deltas to apply, adjust as */
             // necessary
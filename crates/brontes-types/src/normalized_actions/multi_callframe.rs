@@ -36,6 +36,7 @@ pub enum MultiFrameAction {
     Batch,
     Liquidation,
     Aggregator,
+    Stake,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -66,6 +67,9 @@ impl MultiFrameRequest {
                 call_type: MultiFrameAction::Aggregator,
                 trace_idx,
             }),
+            Action::Stake(s) => {
+                Some(Self { protocol: s.protocol, call_type: MultiFrameAction::Stake, trace_idx })
+            }
             _ => None,
         }
     }
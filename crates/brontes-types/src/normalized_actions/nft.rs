@@ -0,0 +1,136 @@
+use std::fmt::Debug;
+
+use alloy_primitives::U256;
+use clickhouse::Row;
+use malachite::Rational;
+use reth_primitives::Address;
+use serde::{Deserialize, Serialize};
+
+use super::accounting::{AddressDeltas, TokenAccounting};
+use crate::{db::token_info::TokenInfoWithAddress, Protocol};
+
+/// The side of the order that was on the marketplace
+#[derive(Debug, Default, Serialize, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+pub enum NftOrderSide {
+    /// A listing, the maker sells the nft
+    #[default]
+    Ask,
+    /// An offer, the maker buys the nft
+    Bid,
+}
+
+/// An nft bought for a fungible payment on a marketplace such as Seaport. The
+/// maker signed the order, the taker filled it on chain
+#[derive(Debug, Default, Serialize, Clone, Row, PartialEq, Eq, Deserialize)]
+pub struct NormalizedNftTrade {
+    pub protocol:      Protocol,
+    pub trace_index:   u64,
+    /// the marketplace contract the order was filled at
+    pub marketplace:   Address,
+    pub side:          NftOrderSide,
+    pub maker:         Address,
+    pub taker:         Address,
+    pub collection:    Address,
+    pub token_id:      U256,
+    /// always 1 for an erc721, the amount of the token for an erc1155
+    pub amount:        U256,
+    pub payment_token: TokenInfoWithAddress,
+    /// the total paid by the buyer, including the marketplace and creator fees
+    pub price:         Rational,
+    pub msg_value:     U256,
+}
+
+impl NormalizedNftTrade {
+    pub fn buyer(&self) -> Address {
+        match self.side {
+            NftOrderSide::Ask => self.taker,
+            NftOrderSide::Bid => self.maker,
+        }
+    }
+
+    pub fn seller(&self) -> Address {
+        match self.side {
+            NftOrderSide::Ask => self.maker,
+            NftOrderSide::Bid => self.taker,
+        }
+    }
+}
+
+/// The payment is paid out to the seller and the fee recipients by transfers
+/// in the call frames of the trade, which are accounted for on their own. The
+/// nft has no fungible value to add up
+impl TokenAccounting for NormalizedNftTrade {
+    fn apply_token_deltas(&self, _: &mut AddressDeltas) {}
+}
+
+#[derive(Debug, Default, Serialize, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+pub enum NftLoanAction {
+    #[default]
+    Borrow,
+    Repay,
+    /// A new lender takes over the loan, paying out the previous one
+    Refinance,
+    /// The lender calls the loan, the borrower has to repay or have it
+    /// refinanced before the auction ends
+    StartAuction,
+    /// The lender takes the collateral of a loan that wasn't repaid
+    Seize,
+}
+
+/// An interaction with a loan backed by a single nft, such as Blur's Blend
+/// liens. The loan is identified by its lien, which holds the collateral
+#[derive(Debug, Default, Serialize, Clone, Row, PartialEq, Eq, Deserialize)]
+pub struct NormalizedNftLoan {
+    pub protocol:    Protocol,
+    pub trace_index: u64,
+    pub action:      NftLoanAction,
+    /// the lending contract holding the collateral
+    pub market:      Address,
+    /// the caller of the lending contract
+    pub from:        Address,
+    pub lien_id:     U256,
+    /// the lender of the loan, for a refinance the lender taking it over
+    pub lender:      Address,
+    pub borrower:    Address,
+    pub collection:  Address,
+    pub token_id:    U256,
+    pub token:       TokenInfoWithAddress,
+    /// the principal of the loan, for a refinance the new principal
+    pub amount:      Rational,
+    pub msg_value:   U256,
+}
+
+/// The loaned token is moved by transfers in the call frames of the loan,
+/// which are accounted for on their own
+impl TokenAccounting for NormalizedNftLoan {
+    fn apply_token_deltas(&self, _: &mut AddressDeltas) {}
+}
+
+#[derive(Debug, Default, Serialize, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+pub enum NftStandard {
+    #[default]
+    Erc721,
+    Erc1155,
+}
+
+/// Nfts of a single collection moved from one holder to another, decoded from
+/// the erc721 `Transfer` or erc1155 `TransferSingle` and `TransferBatch`
+/// events. A mint is a transfer from the zero address, a burn one to it
+#[derive(Debug, Default, Serialize, Clone, Row, PartialEq, Eq, Deserialize)]
+pub struct NormalizedNftTransfer {
+    pub trace_index: u64,
+    pub collection:  Address,
+    pub standard:    NftStandard,
+    pub from:        Address,
+    pub to:          Address,
+    /// a single id unless it is an erc1155 batch transfer
+    pub token_ids:   Vec<U256>,
+    /// the amount of each id, always 1 for an erc721
+    pub amounts:     Vec<U256>,
+    pub msg_value:   U256,
+}
+
+/// Nfts have no fungible value to add up
+impl TokenAccounting for NormalizedNftTransfer {
+    fn apply_token_deltas(&self, _: &mut AddressDeltas) {}
+}
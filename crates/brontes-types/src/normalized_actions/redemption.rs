@@ -0,0 +1,103 @@
+use std::fmt::Debug;
+
+use alloy_primitives::U256;
+use clickhouse::Row;
+use malachite::Rational;
+use reth_primitives::Address;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    accounting::{apply_delta, AddressDeltas, TokenAccounting},
+    NormalizedSwap,
+};
+use crate::{db::token_info::TokenInfoWithAddress, Protocol};
+
+/// A stablecoin redeemed against the collateral backing it at face value, such
+/// as Liquity's `redeemCollateral`. Buying the stablecoin below peg and
+/// redeeming it is an arbitrage, so a redemption can stand in for a swap.
+#[derive(Debug, Default, Serialize, Clone, Row, PartialEq, Eq, Deserialize)]
+pub struct NormalizedRedemption {
+    pub protocol:    Protocol,
+    pub trace_index: u64,
+    /// the contract the stablecoin is redeemed at
+    pub pool:        Address,
+    pub redeemer:    Address,
+    /// the stablecoin that is burnt
+    pub token_in:    TokenInfoWithAddress,
+    pub amount_in:   Rational,
+    /// the collateral the redeemer receives, net of the redemption fee
+    pub token_out:   TokenInfoWithAddress,
+    pub amount_out:  Rational,
+    /// the part of the collateral kept by the protocol as the redemption fee
+    pub fee:         Rational,
+    pub msg_value:   U256,
+}
+
+impl NormalizedRedemption {
+    pub fn as_swap(&self) -> NormalizedSwap {
+        NormalizedSwap {
+            protocol:    self.protocol,
+            trace_index: self.trace_index,
+            from:        self.redeemer,
+            recipient:   self.redeemer,
+            pool:        self.pool,
+            token_in:    self.token_in.clone(),
+            token_out:   self.token_out.clone(),
+            amount_in:   self.amount_in.clone(),
+            amount_out:  self.amount_out.clone(),
+            msg_value:   self.msg_value,
+        }
+    }
+}
+
+impl TokenAccounting for NormalizedRedemption {
+    fn apply_token_deltas(&self, delta_map: &mut AddressDeltas) {
+        apply_delta(self.redeemer, self.token_in.address, -self.amount_in.clone(), delta_map);
+        apply_delta(self.pool, self.token_in.address, self.amount_in.clone(), delta_map);
+
+        apply_delta(self.pool, self.token_out.address, -self.amount_out.clone(), delta_map);
+        apply_delta(self.redeemer, self.token_out.address, self.amount_out.clone(), delta_map);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        constants::WETH_ADDRESS,
+        normalized_actions::{accounting::ActionAccounting, Action},
+    };
+
+    #[test]
+    fn test_redemption_deltas() {
+        let redeemer = Address::with_last_byte(1);
+        let trove_manager = Address::with_last_byte(2);
+        let lusd =
+            TokenInfoWithAddress { address: Address::with_last_byte(3), ..Default::default() };
+
+        let redemption = NormalizedRedemption {
+            pool: trove_manager,
+            redeemer,
+            token_in: lusd.clone(),
+            amount_in: Rational::from(1000),
+            token_out: TokenInfoWithAddress::native_eth(),
+            amount_out: Rational::from_unsigneds(99u8, 200u8),
+            fee: Rational::from_unsigneds(1u8, 200u8),
+            ..Default::default()
+        };
+        let swap = redemption.as_swap();
+        assert_eq!(swap.token_in.address, lusd.address);
+        assert_eq!(swap.recipient, redeemer);
+
+        let deltas = [Action::Redemption(redemption)]
+            .into_iter()
+            .account_for_actions();
+        let redeemer_deltas = deltas.get(&redeemer).unwrap();
+
+        assert_eq!(redeemer_deltas.get(&lusd.address), Some(&Rational::from(-1000)));
+        assert_eq!(
+            redeemer_deltas.get(&WETH_ADDRESS),
+            Some(&Rational::from_unsigneds(99u8, 200u8))
+        );
+    }
+}
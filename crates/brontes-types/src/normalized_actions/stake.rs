@@ -0,0 +1,89 @@
+use std::fmt::Debug;
+
+use clickhouse::Row;
+use malachite::Rational;
+use reth_primitives::Address;
+use serde::{Deserialize, Serialize};
+
+use super::accounting::{apply_delta, AddressDeltas, TokenAccounting};
+use crate::{db::token_info::TokenInfoWithAddress, Protocol};
+
+#[derive(Debug, Default, Serialize, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+pub enum StakeAction {
+    #[default]
+    Deposit,
+    Withdraw,
+}
+
+/// Tokens, usually LP tokens, deposited into or withdrawn from a staking
+/// contract such as a Curve gauge. The staking contract mints a receipt for
+/// the deposit without exchanging anything, so the token moving in next to a
+/// token moving out isn't a swap.
+#[derive(Debug, Default, Serialize, Clone, Row, PartialEq, Eq, Deserialize)]
+pub struct NormalizedStake {
+    pub protocol:         Protocol,
+    pub trace_index:      u64,
+    pub action:           StakeAction,
+    pub staking_contract: Address,
+    /// the caller of the staking contract, who provides the tokens of a
+    /// deposit
+    pub from:             Address,
+    /// the owner of the stake, who receives the tokens of a withdrawal
+    pub account:          Address,
+    pub token:            TokenInfoWithAddress,
+    pub amount:           Rational,
+}
+
+impl NormalizedStake {
+    pub fn is_deposit(&self) -> bool {
+        matches!(self.action, StakeAction::Deposit)
+    }
+}
+
+impl TokenAccounting for NormalizedStake {
+    fn apply_token_deltas(&self, delta_map: &mut AddressDeltas) {
+        let (payer, receiver) = if self.is_deposit() {
+            (self.from, self.staking_contract)
+        } else {
+            (self.staking_contract, self.account)
+        };
+
+        apply_delta(payer, self.token.address, -self.amount.clone(), delta_map);
+        apply_delta(receiver, self.token.address, self.amount.clone(), delta_map);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::normalized_actions::{accounting::ActionAccounting, Action};
+
+    #[test]
+    fn test_stake_deltas() {
+        let account = Address::with_last_byte(1);
+        let gauge = Address::with_last_byte(2);
+        let lp_token =
+            TokenInfoWithAddress { address: Address::with_last_byte(3), ..Default::default() };
+
+        let deposit = NormalizedStake {
+            staking_contract: gauge,
+            from: account,
+            account,
+            token: lp_token.clone(),
+            amount: Rational::from(10),
+            ..Default::default()
+        };
+        let withdrawal = NormalizedStake {
+            action: StakeAction::Withdraw,
+            amount: Rational::from(4),
+            ..deposit.clone()
+        };
+
+        let deltas = [Action::Stake(deposit), Action::Stake(withdrawal)]
+            .into_iter()
+            .account_for_actions();
+
+        assert_eq!(deltas.get(&account).unwrap().get(&lp_token.address), Some(&Rational::from(-6)));
+        assert_eq!(deltas.get(&gauge).unwrap().get(&lp_token.address), Some(&Rational::from(6)));
+    }
+}
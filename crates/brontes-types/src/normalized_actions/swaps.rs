@@ -117,14 +117,19 @@ impl TokenAccounting for NormalizedSwap {
 }
 
 pub struct ClickhouseVecNormalizedSwap {
-    pub trace_index: Vec<u64>,
-    pub from:        Vec<String>,
-    pub recipient:   Vec<String>,
-    pub pool:        Vec<String>,
-    pub token_in:    Vec<(String, String)>,
-    pub token_out:   Vec<(String, String)>,
-    pub amount_in:   Vec<([u8; 32], [u8; 32])>,
-    pub amount_out:  Vec<([u8; 32], [u8; 32])>,
+    pub trace_index:      Vec<u64>,
+    pub from:             Vec<String>,
+    pub recipient:        Vec<String>,
+    pub pool:             Vec<String>,
+    pub token_in:         Vec<(String, String)>,
+    pub token_out:        Vec<(String, String)>,
+    pub amount_in:        Vec<([u8; 32], [u8; 32])>,
+    pub amount_out:       Vec<([u8; 32], [u8; 32])>,
+    /// the amounts in token units, e.g 1.5 WETH
+    pub amount_in_float:  Vec<f64>,
+    pub amount_out_float: Vec<f64>,
+    /// (family, version, fork) of the protocol the swap was on
+    pub protocol:         Vec<(String, String, String)>,
 }
 
 impl TryFrom<Vec<NormalizedSwap>> for ClickhouseVecNormalizedSwap {
@@ -132,44 +137,61 @@ impl TryFrom<Vec<NormalizedSwap>> for ClickhouseVecNormalizedSwap {
 
     fn try_from(value: Vec<NormalizedSwap>) -> eyre::Result<Self> {
         Ok(ClickhouseVecNormalizedSwap {
-            trace_index: value.iter().map(|val| val.trace_index).collect(),
-            from:        value.iter().map(|val| format!("{:?}", val.from)).collect(),
-            recipient:   value
+            trace_index:      value.iter().map(|val| val.trace_index).collect(),
+            from:             value.iter().map(|val| format!("{:?}", val.from)).collect(),
+            recipient:        value
                 .iter()
                 .map(|val| format!("{:?}", val.recipient))
                 .collect(),
-            pool:        value.iter().map(|val| format!("{:?}", val.pool)).collect(),
-            token_in:    value
+            pool:             value.iter().map(|val| format!("{:?}", val.pool)).collect(),
+            token_in:         value
                 .iter()
                 .map(|val| val.token_in.clickhouse_fmt())
                 .collect(),
-            token_out:   value
+            token_out:        value
                 .iter()
                 .map(|val| val.token_out.clickhouse_fmt())
                 .collect(),
-            amount_in:   value
+            amount_in:        value
                 .iter()
                 .map(|val| rational_to_u256_fraction(&val.amount_in))
                 .collect::<eyre::Result<Vec<_>>>()?,
-            amount_out:  value
+            amount_out:       value
                 .iter()
                 .map(|val| rational_to_u256_fraction(&val.amount_out))
                 .collect::<eyre::Result<Vec<_>>>()?,
+            amount_in_float:  value
+                .iter()
+                .map(|val| val.amount_in.clone().to_float())
+                .collect(),
+            amount_out_float: value
+                .iter()
+                .map(|val| val.amount_out.clone().to_float())
+                .collect(),
+            protocol:         value
+                .iter()
+                .map(|val| val.protocol.version().clickhouse_fmt())
+                .collect(),
         })
     }
 }
 
 #[derive(Default)]
 pub struct ClickhouseDoubleVecNormalizedSwap {
-    pub tx_hash:     Vec<String>,
-    pub trace_index: Vec<u64>,
-    pub from:        Vec<String>,
-    pub recipient:   Vec<String>,
-    pub pool:        Vec<String>,
-    pub token_in:    Vec<(String, String)>,
-    pub token_out:   Vec<(String, String)>,
-    pub amount_in:   Vec<([u8; 32], [u8; 32])>,
-    pub amount_out:  Vec<([u8; 32], [u8; 32])>,
+    pub tx_hash:          Vec<String>,
+    pub trace_index:      Vec<u64>,
+    pub from:             Vec<String>,
+    pub recipient:        Vec<String>,
+    pub pool:             Vec<String>,
+    pub token_in:         Vec<(String, String)>,
+    pub token_out:        Vec<(String, String)>,
+    pub amount_in:        Vec<([u8; 32], [u8; 32])>,
+    pub amount_out:       Vec<([u8; 32], [u8; 32])>,
+    /// the amounts in token units, e.g 1.5 WETH
+    pub amount_in_float:  Vec<f64>,
+    pub amount_out_float: Vec<f64>,
+    /// (family, version, fork) of the protocol the swap was on
+    pub protocol:         Vec<(String, String, String)>,
 }
 
 impl TryFrom<(Vec<TxHash>, Vec<Vec<NormalizedSwap>>)> for ClickhouseDoubleVecNormalizedSwap {
@@ -209,6 +231,9 @@ impl TryFrom<(Vec<TxHash>, Vec<Vec<NormalizedSwap>>)> for ClickhouseDoubleVecNor
             this.token_out.extend(inner_swaps.token_out);
             this.amount_in.extend(inner_swaps.amount_in);
             this.amount_out.extend(inner_swaps.amount_out);
+            this.amount_in_float.extend(inner_swaps.amount_in_float);
+            this.amount_out_float.extend(inner_swaps.amount_out_float);
+            this.protocol.extend(inner_swaps.protocol);
         });
 
         Ok(this)
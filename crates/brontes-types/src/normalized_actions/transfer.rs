@@ -1,5 +1,6 @@
 use std::fmt::Debug;
 
+use alloy_primitives::hex;
 use clickhouse::Row;
 use malachite::Rational;
 use redefined::Redefined;
@@ -13,7 +14,7 @@ use crate::{
         redefined_types::{malachite::*, primitives::*},
         token_info::{TokenInfoWithAddress, TokenInfoWithAddressRedefined},
     },
-    rational_to_u256_fraction,
+    rational_to_u256_fraction, ToFloatNearest,
 };
 
 #[derive(Debug, Default, Serialize, Deserialize, Clone, Row, PartialEq, Eq, Redefined)]
@@ -28,8 +29,30 @@ pub struct NormalizedTransfer {
     pub msg_value:   U256,
 }
 
+/// Addresses tokens are sent to to burn them, as no one holds their key. The
+/// zero address isn't one of them, as unwrapping burns to it and pays out the
+/// underlying, so the burnt side has to be accounted for
+pub const BURN_ADDRESSES: [Address; 2] = [
+    Address::new(hex!("000000000000000000000000000000000000dEaD")),
+    Address::new(hex!("dEaD000000000000000000000000000000000000")),
+];
+
+impl NormalizedTransfer {
+    /// The transfer doesn't move tokens to another holder, either it goes back
+    /// to the sender or it takes the tokens out of the supply by sending them
+    /// to the token contract or a burn address.
+    pub fn is_supply_event(&self) -> bool {
+        self.from == self.to || self.to == self.token.address || BURN_ADDRESSES.contains(&self.to)
+    }
+}
+
 impl TokenAccounting for NormalizedTransfer {
+    /// Supply events aren't part of anyone's profit, so they are left out
     fn apply_token_deltas(&self, delta_map: &mut AddressDeltas) {
+        if self.is_supply_event() {
+            return
+        }
+
         let amount_sent = &self.amount + &self.fee;
 
         apply_delta(self.from, self.token.address, -amount_sent.clone(), delta_map);
@@ -38,13 +61,16 @@ impl TokenAccounting for NormalizedTransfer {
 }
 
 pub struct ClickhouseVecNormalizedTransfer {
-    pub trace_index: Vec<u64>,
-    pub from:        Vec<String>,
-    pub to:          Vec<String>,
-    pub token:       Vec<(String, String)>,
-    pub amount:      Vec<([u8; 32], [u8; 32])>,
-    pub fee:         Vec<([u8; 32], [u8; 32])>,
-    pub msg_value:   Vec<U256>,
+    pub trace_index:  Vec<u64>,
+    pub from:         Vec<String>,
+    pub to:           Vec<String>,
+    pub token:        Vec<(String, String)>,
+    pub amount:       Vec<([u8; 32], [u8; 32])>,
+    pub fee:          Vec<([u8; 32], [u8; 32])>,
+    /// the amounts in token units, e.g 1.5 WETH
+    pub amount_float: Vec<f64>,
+    pub fee_float:    Vec<f64>,
+    pub msg_value:    Vec<U256>,
 }
 
 impl TryFrom<Vec<NormalizedTransfer>> for ClickhouseVecNormalizedTransfer {
@@ -52,19 +78,63 @@ impl TryFrom<Vec<NormalizedTransfer>> for ClickhouseVecNormalizedTransfer {
 
     fn try_from(value: Vec<NormalizedTransfer>) -> eyre::Result<Self> {
         Ok(ClickhouseVecNormalizedTransfer {
-            trace_index: value.iter().map(|val| val.trace_index).collect(),
-            from:        value.iter().map(|val| format!("{:?}", val.from)).collect(),
-            to:          value.iter().map(|val| format!("{:?}", val.to)).collect(),
-            token:       value.iter().map(|val| val.token.clickhouse_fmt()).collect(),
-            amount:      value
+            trace_index:  value.iter().map(|val| val.trace_index).collect(),
+            from:         value.iter().map(|val| format!("{:?}", val.from)).collect(),
+            to:           value.iter().map(|val| format!("{:?}", val.to)).collect(),
+            token:        value.iter().map(|val| val.token.clickhouse_fmt()).collect(),
+            amount:       value
                 .iter()
                 .map(|val| rational_to_u256_fraction(&val.amount))
                 .collect::<eyre::Result<Vec<_>>>()?,
-            fee:         value
+            fee:          value
                 .iter()
                 .map(|val| rational_to_u256_fraction(&val.fee))
                 .collect::<eyre::Result<Vec<_>>>()?,
-            msg_value:   value.iter().map(|val| val.msg_value).collect::<Vec<_>>(),
+            amount_float: value
+                .iter()
+                .map(|val| val.amount.clone().to_float())
+                .collect(),
+            fee_float:    value.iter().map(|val| val.fee.clone().to_float()).collect(),
+            msg_value:    value.iter().map(|val| val.msg_value).collect::<Vec<_>>(),
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::normalized_actions::{accounting::ActionAccounting, Action};
+
+    #[test]
+    fn test_supply_events_arent_accounted() {
+        let searcher = Address::with_last_byte(1);
+        let pool = Address::with_last_byte(2);
+        let token =
+            TokenInfoWithAddress { address: Address::with_last_byte(3), ..Default::default() };
+
+        let transfer = NormalizedTransfer {
+            from: searcher,
+            to: pool,
+            token: token.clone(),
+            amount: Rational::from(10),
+            ..Default::default()
+        };
+        let to_self = NormalizedTransfer { to: searcher, trace_index: 1, ..transfer.clone() };
+        let to_token = NormalizedTransfer { to: token.address, trace_index: 2, ..transfer.clone() };
+        let burnt =
+            NormalizedTransfer { to: BURN_ADDRESSES[0], trace_index: 3, ..transfer.clone() };
+
+        assert!(!transfer.is_supply_event());
+        assert!(to_self.is_supply_event() && to_token.is_supply_event() && burnt.is_supply_event());
+
+        let deltas = [transfer, to_self, to_token, burnt]
+            .into_iter()
+            .map(Action::Transfer)
+            .account_for_actions();
+
+        assert_eq!(deltas.get(&searcher).unwrap().get(&token.address), Some(&Rational::from(-10)));
+        assert_eq!(deltas.get(&pool).unwrap().get(&token.address), Some(&Rational::from(10)));
+        assert!(!deltas.contains_key(&token.address));
+        assert!(!deltas.contains_key(&BURN_ADDRESSES[0]));
+    }
+}
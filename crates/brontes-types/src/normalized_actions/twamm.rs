@@ -0,0 +1,133 @@
+use std::fmt::Debug;
+
+use alloy_primitives::U256;
+use clickhouse::Row;
+use malachite::Rational;
+use reth_primitives::Address;
+use serde::{Deserialize, Serialize};
+
+use super::accounting::{apply_delta, AddressDeltas, TokenAccounting};
+use crate::{db::token_info::TokenInfoWithAddress, Protocol};
+
+#[derive(Debug, Default, Serialize, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+pub enum TwammOrderAction {
+    /// a new long term order that is sold over a number of time intervals
+    #[default]
+    Place,
+    /// the order is cancelled, refunding the unsold amount and paying out
+    /// what has been bought so far
+    Cancel,
+    /// the proceeds of the order are withdrawn
+    Withdraw,
+}
+
+/// An interaction with a long term (time-weighted) order on a TWAMM pool.
+/// Unlike a swap, the order is executed by the pool over many blocks, so the
+/// amounts here are what moved at the time of the interaction.
+#[derive(Debug, Default, Serialize, Clone, Row, PartialEq, Eq, Deserialize)]
+pub struct NormalizedTwammOrder {
+    pub protocol:       Protocol,
+    pub trace_index:    u64,
+    pub action:         TwammOrderAction,
+    pub pool:           Address,
+    /// the owner of the order
+    pub from:           Address,
+    pub order_id:       U256,
+    pub token_in:       TokenInfoWithAddress,
+    pub token_out:      TokenInfoWithAddress,
+    /// sold amount deposited into the pool on place, refunded amount on cancel
+    pub amount_in:      Rational,
+    /// purchased amount paid out on cancel or withdraw
+    pub amount_out:     Rational,
+    /// the number of time intervals the order is sold over, only known on place
+    pub time_intervals: U256,
+    pub msg_value:      U256,
+}
+
+impl TokenAccounting for NormalizedTwammOrder {
+    fn apply_token_deltas(&self, delta_map: &mut AddressDeltas) {
+        match self.action {
+            TwammOrderAction::Place => {
+                apply_delta(self.from, self.token_in.address, -self.amount_in.clone(), delta_map);
+                apply_delta(self.pool, self.token_in.address, self.amount_in.clone(), delta_map);
+            }
+            TwammOrderAction::Cancel | TwammOrderAction::Withdraw => {
+                apply_delta(self.pool, self.token_in.address, -self.amount_in.clone(), delta_map);
+                apply_delta(self.from, self.token_in.address, self.amount_in.clone(), delta_map);
+
+                apply_delta(self.pool, self.token_out.address, -self.amount_out.clone(), delta_map);
+                apply_delta(self.from, self.token_out.address, self.amount_out.clone(), delta_map);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::normalized_actions::{accounting::ActionAccounting, Action, NormalizedTransfer};
+
+    #[test]
+    fn test_twamm_order_transfers_arent_double_counted() {
+        let owner = Address::with_last_byte(1);
+        let pool = Address::with_last_byte(2);
+        let frax =
+            TokenInfoWithAddress { address: Address::with_last_byte(3), ..Default::default() };
+        let fxs =
+            TokenInfoWithAddress { address: Address::with_last_byte(4), ..Default::default() };
+
+        let place = NormalizedTwammOrder {
+            action: TwammOrderAction::Place,
+            pool,
+            from: owner,
+            token_in: frax.clone(),
+            token_out: fxs.clone(),
+            amount_in: Rational::from(1000),
+            ..Default::default()
+        };
+        let deposit = NormalizedTransfer {
+            trace_index: 1,
+            from: owner,
+            to: pool,
+            token: frax.clone(),
+            amount: Rational::from(1000),
+            ..Default::default()
+        };
+
+        let deltas = [Action::TwammOrder(place), Action::Transfer(deposit)]
+            .into_iter()
+            .account_for_actions();
+        assert_eq!(deltas.get(&owner).unwrap().get(&frax.address), Some(&Rational::from(-1000)));
+        assert_eq!(deltas.get(&pool).unwrap().get(&frax.address), Some(&Rational::from(1000)));
+
+        let cancel = NormalizedTwammOrder {
+            trace_index: 2,
+            action: TwammOrderAction::Cancel,
+            pool,
+            from: owner,
+            token_in: frax.clone(),
+            token_out: fxs.clone(),
+            amount_in: Rational::from(400),
+            amount_out: Rational::from(30),
+            ..Default::default()
+        };
+        let payouts = [(frax.clone(), 400), (fxs.clone(), 30)].map(|(token, amount)| {
+            Action::Transfer(NormalizedTransfer {
+                trace_index: 3,
+                from: pool,
+                to: owner,
+                token,
+                amount: Rational::from(amount),
+                ..Default::default()
+            })
+        });
+
+        let deltas = [Action::TwammOrder(cancel)]
+            .into_iter()
+            .chain(payouts)
+            .account_for_actions();
+        let owner_deltas = deltas.get(&owner).unwrap();
+        assert_eq!(owner_deltas.get(&frax.address), Some(&Rational::from(400)));
+        assert_eq!(owner_deltas.get(&fxs.address), Some(&Rational::from(30)));
+    }
+}
@@ -0,0 +1,108 @@
+use std::fmt::Debug;
+
+use alloy_primitives::U256;
+use clickhouse::Row;
+use malachite::Rational;
+use reth_primitives::Address;
+use serde::{Deserialize, Serialize};
+
+use super::accounting::{apply_delta, AddressDeltas, TokenAccounting};
+use crate::{db::token_info::TokenInfoWithAddress, Protocol};
+
+#[derive(Debug, Default, Serialize, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+pub enum WrapAction {
+    /// native eth is deposited and wrapped eth is minted
+    #[default]
+    Wrap,
+    /// wrapped eth is burnt and native eth is sent back
+    Unwrap,
+}
+
+/// A conversion between native eth and wrapped eth
+#[derive(Debug, Default, Serialize, Clone, Row, PartialEq, Eq, Deserialize)]
+pub struct NormalizedWrap {
+    pub protocol:    Protocol,
+    pub trace_index: u64,
+    pub action:      WrapAction,
+    /// the wrapped eth contract
+    pub weth:        Address,
+    /// the account that is wrapping or unwrapping
+    pub from:        Address,
+    pub token:       TokenInfoWithAddress,
+    pub amount:      Rational,
+    pub msg_value:   U256,
+}
+
+impl NormalizedWrap {
+    pub fn is_wrap(&self) -> bool {
+        matches!(self.action, WrapAction::Wrap)
+    }
+}
+
+impl TokenAccounting for NormalizedWrap {
+    /// Only the wrapped eth leg is applied here. The native eth leg is already
+    /// accounted for, as the msg value of a deposit or the eth transfer back to
+    /// the caller of a withdraw.
+    fn apply_token_deltas(&self, delta_map: &mut AddressDeltas) {
+        let (payer, receiver) =
+            if self.is_wrap() { (self.weth, self.from) } else { (self.from, self.weth) };
+
+        apply_delta(payer, self.token.address, -self.amount.clone(), delta_map);
+        apply_delta(receiver, self.token.address, self.amount.clone(), delta_map);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use malachite::num::basic::traits::Zero;
+
+    use super::*;
+    use crate::{
+        constants::{ETH_ADDRESS, WETH_ADDRESS},
+        normalized_actions::{accounting::ActionAccounting, Action, NormalizedEthTransfer},
+    };
+
+    #[test]
+    fn test_wrap_round_trip_nets_out() {
+        let searcher = Address::with_last_byte(1);
+        let amount = Rational::from(2);
+        let value = U256::from(2_000_000_000_000_000_000u128);
+
+        let actions = vec![
+            Action::EthTransfer(NormalizedEthTransfer {
+                from: searcher,
+                to: WETH_ADDRESS,
+                value,
+                ..Default::default()
+            }),
+            Action::Wrap(NormalizedWrap {
+                action: WrapAction::Wrap,
+                weth: WETH_ADDRESS,
+                from: searcher,
+                token: TokenInfoWithAddress::weth(),
+                amount: amount.clone(),
+                ..Default::default()
+            }),
+            Action::Wrap(NormalizedWrap {
+                action: WrapAction::Unwrap,
+                weth: WETH_ADDRESS,
+                from: searcher,
+                token: TokenInfoWithAddress::weth(),
+                amount,
+                ..Default::default()
+            }),
+            Action::EthTransfer(NormalizedEthTransfer {
+                from: WETH_ADDRESS,
+                to: searcher,
+                value,
+                ..Default::default()
+            }),
+        ];
+
+        let deltas = actions.into_iter().account_for_actions();
+        let searcher_deltas = deltas.get(&searcher).unwrap();
+
+        assert_eq!(searcher_deltas.get(&ETH_ADDRESS), Some(&Rational::ZERO));
+        assert_eq!(searcher_deltas.get(&WETH_ADDRESS), Some(&Rational::ZERO));
+    }
+}
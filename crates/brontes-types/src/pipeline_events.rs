@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use tokio::sync::broadcast;
+
+use crate::mev::Bundle;
+
+/// amount of events a slow subscriber can lag behind before it starts missing
+/// events
+const EVENT_CHANNEL_CAPACITY: usize = 1024;
+
+/// The stages a block goes through in the brontes pipeline.
+#[derive(Debug, Clone)]
+pub enum PipelineEvent {
+    /// the traces for the block are being fetched
+    BlockStarted { block_number: u64 },
+    /// the block tree has been built and classified
+    TreeBuilt { block_number: u64, tx_count: usize },
+    /// the block metadata, including the dex prices, has been loaded
+    PricesReady { block_number: u64 },
+    /// the inspectors have finished running over the block
+    MevFound { block_number: u64, mev: Arc<Vec<Bundle>> },
+    /// the results for the block have been written to the database
+    BlockCommitted { block_number: u64 },
+}
+
+impl PipelineEvent {
+    pub fn block_number(&self) -> u64 {
+        match self {
+            Self::BlockStarted { block_number }
+            | Self::TreeBuilt { block_number, .. }
+            | Self::PricesReady { block_number }
+            | Self::MevFound { block_number, .. }
+            | Self::BlockCommitted { block_number } => *block_number,
+        }
+    }
+}
+
+/// Handle to the pipeline event stream. Applications embedding brontes can
+/// subscribe to it in order to track progress or react to intermediate
+/// results without having to poll the database.
+#[derive(Debug, Clone)]
+pub struct PipelineEvents {
+    tx: broadcast::Sender<PipelineEvent>,
+}
+
+impl Default for PipelineEvents {
+    fn default() -> Self {
+        Self::new(EVENT_CHANNEL_CAPACITY)
+    }
+}
+
+impl PipelineEvents {
+    pub fn new(capacity: usize) -> Self {
+        let (tx, _) = broadcast::channel(capacity);
+        Self { tx }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<PipelineEvent> {
+        self.tx.subscribe()
+    }
+
+    /// Sends the event to all current subscribers. Does nothing when there
+    /// are none.
+    pub fn emit(&self, event: PipelineEvent) {
+        if self.tx.receiver_count() != 0 {
+            let _ = self.tx.send(event);
+        }
+    }
+}
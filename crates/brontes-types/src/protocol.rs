@@ -102,55 +102,135 @@ utils!(
         ClipperExchange,
         PropellerLabsSolver,
         Dodo,
+        BancorV3,
+        Fraxswap,
+        SolidlyV1,
+        SolidlyV2,
+        OneInchV6,
+        Hashflow,
+        Bebop,
+        Weth,
+        CompoundV3,
+        MakerClipper,
+        Liquity,
+        MorphoBlue,
+        Spark,
+        Lido,
+        CurveGauge,
+        Convex,
+        Seaport,
+        Blur,
+        BlurBlend,
+        UniswapUniversalRouter,
+        UniswapSwapRouter02,
+        AlgebraV1,
+        ParaswapV5,
         #[default]
         Unknown,
     }
 );
 
 impl Protocol {
-    pub fn into_clickhouse_protocol(&self) -> (&str, &str) {
+    /// The family, version and fork parent of the protocol. Forks are kept
+    /// apart from the protocol they were forked from, as they can differ in
+    /// parameters such as fees even though they share the pool logic.
+    pub const fn version(&self) -> ProtocolVersion {
         match self {
-            Protocol::UniswapV2 => ("Uniswap", "V2"),
-            Protocol::SushiSwapV2 => ("SushiSwap", "V2"),
-            Protocol::PancakeSwapV2 => ("PancakeSwap", "V2"),
-            Protocol::UniswapV3 => ("Uniswap", "V3"),
-            Protocol::SushiSwapV3 => ("SushiSwap", "V3"),
-            Protocol::PancakeSwapV3 => ("PancakeSwap", "V3"),
-            Protocol::AaveV2 => ("Aave", "V2"),
-            Protocol::AaveV3 => ("Aave", "V3"),
-            Protocol::BalancerV1 => ("Balancer", "V1"),
-            Protocol::BalancerV2 => ("Balancer", "V2"),
-            Protocol::BalancerV1CRP => ("Balancer", "V1SmartPool"),
-            Protocol::UniswapX => ("Uniswap", "X"),
-            Protocol::ZeroX => ("ZeroX", ""),
-            Protocol::Cowswap => ("Cowswap", ""),
-            Protocol::CurveBasePool2 => ("Curve.fi", "Base"),
-            Protocol::CurveBasePool3 => ("Curve.fi", "Base"),
-            Protocol::CurveBasePool4 => ("Curve.fi", "Base"),
-            Protocol::CurveV1MetaPool => ("Curve.fi", "V1 Metapool"),
-            Protocol::CurveV1MetapoolImpl => ("Curve.fi", "V1 Metapool Impl"),
-            Protocol::CurveV2MetaPool => ("Curve.fi", "V2 Metapool"),
-            Protocol::CurveV2MetapoolImpl => ("Curve.fi", "V2 Metapool Impl"),
-            Protocol::CurveV2PlainPool => ("Curve.fi", "V2 Plain"),
-            Protocol::CurveV2PlainPoolImpl => ("Curve.fi", "V2 Plain Impl"),
-            Protocol::CurvecrvUSDMetaPool => ("Curve.fi", "crvUSD Metapool"),
-            Protocol::CurvecrvUSDMetapoolImpl => ("Curve.fi", "crvUSD Metapool Impl"),
-            Protocol::CurvecrvUSDPlainPool => ("Curve.fi", "crvUSD Plain"),
-            Protocol::CurvecrvUSDPlainPoolImpl => ("Curve.fi", "crvUSD Plain Impl"),
-            Protocol::CurveCryptoSwapPool => ("Curve.fi", "CryptoSwap"),
-            Protocol::CurveTriCryptoPool => ("Curve.fi", "TriCrypto"),
-            Protocol::CompoundV2 => ("Compound", "V2"),
-            Protocol::MakerPSM => ("Maker", "PSM"),
-            Protocol::MakerDssFlash => ("Maker", "DssFlash"),
-            Protocol::OneInchV5 => ("OneInch", "V5"),
-            Protocol::OneInchFusion => ("OneInch", "Fusion"),
-            Protocol::ClipperExchange => ("ClipperExchange", ""),
-            Protocol::PropellerLabsSolver => ("Propeller Labs Solver", ""),
-            Protocol::Dodo => ("Dodo", "V1/V2"),
-            Protocol::Unknown => ("Unknown", "Unknown"),
+            Protocol::UniswapV2 => ProtocolVersion::new("Uniswap", "V2", None),
+            Protocol::SushiSwapV2 => {
+                ProtocolVersion::new("SushiSwap", "V2", Some(Protocol::UniswapV2))
+            }
+            Protocol::PancakeSwapV2 => {
+                ProtocolVersion::new("PancakeSwap", "V2", Some(Protocol::UniswapV2))
+            }
+            Protocol::UniswapV3 => ProtocolVersion::new("Uniswap", "V3", None),
+            Protocol::SushiSwapV3 => {
+                ProtocolVersion::new("SushiSwap", "V3", Some(Protocol::UniswapV3))
+            }
+            Protocol::PancakeSwapV3 => {
+                ProtocolVersion::new("PancakeSwap", "V3", Some(Protocol::UniswapV3))
+            }
+            Protocol::AaveV2 => ProtocolVersion::new("Aave", "V2", None),
+            Protocol::AaveV3 => ProtocolVersion::new("Aave", "V3", None),
+            Protocol::BalancerV1 => ProtocolVersion::new("Balancer", "V1", None),
+            Protocol::BalancerV2 => ProtocolVersion::new("Balancer", "V2", None),
+            Protocol::BalancerV1CRP => ProtocolVersion::new("Balancer", "V1SmartPool", None),
+            Protocol::UniswapX => ProtocolVersion::new("Uniswap", "X", None),
+            Protocol::ZeroX => ProtocolVersion::new("ZeroX", "", None),
+            Protocol::Cowswap => ProtocolVersion::new("Cowswap", "", None),
+            Protocol::CurveBasePool2 => ProtocolVersion::new("Curve.fi", "Base", None),
+            Protocol::CurveBasePool3 => ProtocolVersion::new("Curve.fi", "Base", None),
+            Protocol::CurveBasePool4 => ProtocolVersion::new("Curve.fi", "Base", None),
+            Protocol::CurveV1MetaPool => ProtocolVersion::new("Curve.fi", "V1 Metapool", None),
+            Protocol::CurveV1MetapoolImpl => {
+                ProtocolVersion::new("Curve.fi", "V1 Metapool Impl", None)
+            }
+            Protocol::CurveV2MetaPool => ProtocolVersion::new("Curve.fi", "V2 Metapool", None),
+            Protocol::CurveV2MetapoolImpl => {
+                ProtocolVersion::new("Curve.fi", "V2 Metapool Impl", None)
+            }
+            Protocol::CurveV2PlainPool => ProtocolVersion::new("Curve.fi", "V2 Plain", None),
+            Protocol::CurveV2PlainPoolImpl => {
+                ProtocolVersion::new("Curve.fi", "V2 Plain Impl", None)
+            }
+            Protocol::CurvecrvUSDMetaPool => {
+                ProtocolVersion::new("Curve.fi", "crvUSD Metapool", None)
+            }
+            Protocol::CurvecrvUSDMetapoolImpl => {
+                ProtocolVersion::new("Curve.fi", "crvUSD Metapool Impl", None)
+            }
+            Protocol::CurvecrvUSDPlainPool => {
+                ProtocolVersion::new("Curve.fi", "crvUSD Plain", None)
+            }
+            Protocol::CurvecrvUSDPlainPoolImpl => {
+                ProtocolVersion::new("Curve.fi", "crvUSD Plain Impl", None)
+            }
+            Protocol::CurveCryptoSwapPool => ProtocolVersion::new("Curve.fi", "CryptoSwap", None),
+            Protocol::CurveTriCryptoPool => ProtocolVersion::new("Curve.fi", "TriCrypto", None),
+            Protocol::CompoundV2 => ProtocolVersion::new("Compound", "V2", None),
+            Protocol::MakerPSM => ProtocolVersion::new("Maker", "PSM", None),
+            Protocol::MakerDssFlash => ProtocolVersion::new("Maker", "DssFlash", None),
+            Protocol::OneInchV5 => ProtocolVersion::new("OneInch", "V5", None),
+            Protocol::OneInchFusion => ProtocolVersion::new("OneInch", "Fusion", None),
+            Protocol::ClipperExchange => ProtocolVersion::new("ClipperExchange", "", None),
+            Protocol::PropellerLabsSolver => {
+                ProtocolVersion::new("Propeller Labs Solver", "", None)
+            }
+            Protocol::Dodo => ProtocolVersion::new("Dodo", "V1/V2", None),
+            Protocol::BancorV3 => ProtocolVersion::new("Bancor", "V3", None),
+            Protocol::Fraxswap => ProtocolVersion::new("Fraxswap", "", Some(Protocol::UniswapV2)),
+            Protocol::SolidlyV1 => ProtocolVersion::new("Solidly", "V1", None),
+            Protocol::SolidlyV2 => ProtocolVersion::new("Solidly", "V2", None),
+            Protocol::OneInchV6 => ProtocolVersion::new("OneInch", "V6", None),
+            Protocol::Hashflow => ProtocolVersion::new("Hashflow", "", None),
+            Protocol::Bebop => ProtocolVersion::new("Bebop", "", None),
+            Protocol::Weth => ProtocolVersion::new("WETH", "9", None),
+            Protocol::CompoundV3 => ProtocolVersion::new("Compound", "V3", None),
+            Protocol::MakerClipper => ProtocolVersion::new("Maker", "Clipper", None),
+            Protocol::Liquity => ProtocolVersion::new("Liquity", "V1", None),
+            Protocol::MorphoBlue => ProtocolVersion::new("Morpho", "Blue", None),
+            Protocol::Spark => ProtocolVersion::new("Spark", "", Some(Protocol::AaveV3)),
+            Protocol::Lido => ProtocolVersion::new("Lido", "", None),
+            Protocol::CurveGauge => ProtocolVersion::new("Curve.fi", "Gauge", None),
+            Protocol::Convex => ProtocolVersion::new("Convex", "", None),
+            Protocol::Seaport => ProtocolVersion::new("Seaport", "", None),
+            Protocol::Blur => ProtocolVersion::new("Blur", "", None),
+            Protocol::BlurBlend => ProtocolVersion::new("Blur", "Blend", None),
+            Protocol::UniswapUniversalRouter => {
+                ProtocolVersion::new("Uniswap", "Universal Router", None)
+            }
+            Protocol::UniswapSwapRouter02 => ProtocolVersion::new("Uniswap", "SwapRouter02", None),
+            Protocol::AlgebraV1 => ProtocolVersion::new("Algebra", "V1", None),
+            Protocol::ParaswapV5 => ProtocolVersion::new("Paraswap", "V5", None),
+            Protocol::Unknown => ProtocolVersion::new("Unknown", "Unknown", None),
         }
     }
 
+    pub fn into_clickhouse_protocol(&self) -> (&str, &str) {
+        let version = self.version();
+        (version.family, version.version)
+    }
+
     pub fn from_db_string(s: &str) -> Self {
         match s.to_lowercase().as_str() {
             "uniswapv2" => Protocol::UniswapV2,
@@ -167,11 +247,18 @@ impl Protocol {
             "curve.ficrvusd plain" => Protocol::CurvecrvUSDPlainPool,
             "curve.ficryptoswap" => Protocol::CurveCryptoSwapPool,
             "curve.fitricrypto" => Protocol::CurveTriCryptoPool,
+            "curve.figauge" => Protocol::CurveGauge,
             "propellerlabssolver" => Protocol::PropellerLabsSolver,
             "balancerv1" => Protocol::BalancerV1,
             "balancerv1smartpool" => Protocol::BalancerV1CRP,
             "balancerv2" => Protocol::BalancerV2,
             "dodov1/v2" => Protocol::Dodo,
+            "bancorv3" => Protocol::BancorV3,
+            "fraxswap" => Protocol::Fraxswap,
+            "solidlyv1" => Protocol::SolidlyV1,
+            "solidlyv2" => Protocol::SolidlyV2,
+            "algebrav1" => Protocol::AlgebraV1,
+            "paraswapv5" => Protocol::ParaswapV5,
             "pancakeswapv2" => Protocol::PancakeSwapV2,
             "pancakeswapv3" => Protocol::PancakeSwapV3,
             _ => Protocol::Unknown,
@@ -222,11 +309,110 @@ impl fmt::Display for Protocol {
                 Protocol::ClipperExchange => "Clipper",
                 Protocol::PropellerLabsSolver => "Propeller Labs",
                 Protocol::Dodo => "Dodo",
+                Protocol::BancorV3 => "Bancor V3",
+                Protocol::Fraxswap => "Fraxswap",
+                Protocol::SolidlyV1 => "Solidly V1",
+                Protocol::SolidlyV2 => "Solidly V2",
+                Protocol::OneInchV6 => "1inch V6",
+                Protocol::Hashflow => "Hashflow",
+                Protocol::Bebop => "Bebop",
+                Protocol::Weth => "WETH",
+                Protocol::CompoundV3 => "Compound V3",
+                Protocol::MakerClipper => "Maker Clipper",
+                Protocol::Liquity => "Liquity",
+                Protocol::MorphoBlue => "Morpho Blue",
+                Protocol::Spark => "Spark",
+                Protocol::Lido => "Lido",
+                Protocol::CurveGauge => "Curve Gauge",
+                Protocol::Convex => "Convex",
+                Protocol::Seaport => "Seaport",
+                Protocol::Blur => "Blur",
+                Protocol::BlurBlend => "Blur Blend",
+                Protocol::UniswapUniversalRouter => "Uni Universal Router",
+                Protocol::UniswapSwapRouter02 => "Uni SwapRouter02",
+                Protocol::AlgebraV1 => "Algebra V1",
+                Protocol::ParaswapV5 => "Paraswap V5",
                 Protocol::Unknown => "Unknown",
             }
         )
     }
 }
 
+/// Structured identity of a [`Protocol`], used in outputs so that forks and
+/// versions of the same protocol can't be conflated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub struct ProtocolVersion {
+    pub family:  &'static str,
+    pub version: &'static str,
+    /// The protocol this one is a fork of
+    pub fork:    Option<Protocol>,
+}
+
+impl ProtocolVersion {
+    const fn new(family: &'static str, version: &'static str, fork: Option<Protocol>) -> Self {
+        Self { family, version, fork }
+    }
+
+    /// (family, version, fork) with the fork written as the family and version
+    /// it was forked from, or an empty string for protocols that aren't a fork
+    pub fn clickhouse_fmt(&self) -> (String, String, String) {
+        (
+            self.family.to_string(),
+            self.version.to_string(),
+            self.fork
+                .map(|fork| fork.version().to_string())
+                .unwrap_or_default(),
+        )
+    }
+}
+
+impl fmt::Display for ProtocolVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.family)?;
+        if !self.version.is_empty() {
+            write!(f, " {}", self.version)?;
+        }
+        if let Some(fork) = self.fork {
+            write!(f, " (fork of {})", fork.version())?;
+        }
+        Ok(())
+    }
+}
+
 self_convert_redefined!(Protocol);
 implement_table_value_codecs_with_zc!(Protocol);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_forks_are_not_conflated() {
+        let uni = Protocol::UniswapV2.version();
+        let pancake = Protocol::PancakeSwapV2.version();
+
+        assert_eq!(uni.fork, None);
+        assert_eq!(pancake.fork, Some(Protocol::UniswapV2));
+        assert_ne!(uni.clickhouse_fmt(), pancake.clickhouse_fmt());
+        assert_eq!(pancake.to_string(), "PancakeSwap V2 (fork of Uniswap V2)");
+    }
+
+    #[test]
+    fn test_fork_clickhouse_fmt() {
+        assert_eq!(
+            Protocol::SushiSwapV3.version().clickhouse_fmt(),
+            ("SushiSwap".to_string(), "V3".to_string(), "Uniswap V3".to_string())
+        );
+        assert_eq!(Protocol::UniswapV3.version().clickhouse_fmt().2, "");
+    }
+
+    #[test]
+    fn test_curve_base_pools_keep_version() {
+        // existing address to protocol rows are keyed by the shared version
+        for protocol in
+            [Protocol::CurveBasePool2, Protocol::CurveBasePool3, Protocol::CurveBasePool4]
+        {
+            assert_eq!(protocol.into_clickhouse_protocol(), ("Curve.fi", "Base"));
+        }
+    }
+}
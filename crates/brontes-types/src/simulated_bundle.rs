@@ -0,0 +1,56 @@
+use alloy_primitives::{Bytes, TxHash};
+use reth_rpc_types::state::StateOverride;
+use serde::{Deserialize, Serialize};
+
+/// A transaction of a bundle that is simulated instead of read from a block
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum BundleTransaction {
+    /// A transaction that is already on chain, re-executed on top of the
+    /// simulation state
+    Hash(TxHash),
+    /// A raw signed, eip-2718 encoded transaction
+    Raw(Bytes),
+}
+
+/// A bundle to analyze offline. The transactions are executed in order on top
+/// of the state at the end of `block`, as if they were the start of the next
+/// block
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimulatedBundle {
+    pub block:           u64,
+    pub transactions:    Vec<BundleTransaction>,
+    #[serde(default)]
+    pub state_overrides: Option<StateOverride>,
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::hex;
+
+    use super::*;
+
+    #[test]
+    fn test_bundle_json() {
+        let bundle: SimulatedBundle = serde_json::from_str(
+            r#"{
+                "block": 19000000,
+                "transactions": [
+                    "0x5b8fd42ef2c2d5c4c5c07b7ce0ce4fc8ad9a1cd9de5e26d3b1f07c5e1f5c0a3d",
+                    "0x02f86c0180843b9aca00850c6f3b40008252089400000000000000000000000000000000000000010180c0"
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(bundle.block, 19000000);
+        assert_eq!(
+            bundle.transactions[0],
+            BundleTransaction::Hash(
+                hex!("5b8fd42ef2c2d5c4c5c07b7ce0ce4fc8ad9a1cd9de5e26d3b1f07c5e1f5c0a3d").into()
+            )
+        );
+        assert!(matches!(bundle.transactions[1], BundleTransaction::Raw(_)));
+        assert!(bundle.state_overrides.is_none());
+    }
+}
@@ -0,0 +1,82 @@
+use alloy_primitives::{Address, TxHash, U256};
+use malachite::{num::basic::traits::Zero, Rational};
+
+use crate::{FastHashMap, ToScaledRational};
+
+/// The value of a balance or storage slot before and after a transaction
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ValueDiff {
+    pub pre:  U256,
+    pub post: U256,
+}
+
+impl ValueDiff {
+    pub fn new(pre: U256, post: U256) -> Self {
+        Self { pre, post }
+    }
+
+    pub fn changed(&self) -> bool {
+        self.pre != self.post
+    }
+}
+
+/// The exact state changes of a transaction, taken from re-executing the
+/// whole block in a single pass. Only accounts and slots that changed are
+/// kept.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TxStateDiff {
+    pub tx_hash:  TxHash,
+    pub tx_index: u64,
+    pub balances: FastHashMap<Address, ValueDiff>,
+    pub storage:  FastHashMap<Address, FastHashMap<U256, ValueDiff>>,
+}
+
+impl TxStateDiff {
+    pub fn new(tx_hash: TxHash, tx_index: u64) -> Self {
+        Self { tx_hash, tx_index, ..Default::default() }
+    }
+
+    pub fn insert_balance(&mut self, address: Address, diff: ValueDiff) {
+        if diff.changed() {
+            self.balances.insert(address, diff);
+        }
+    }
+
+    pub fn insert_storage(&mut self, address: Address, slot: U256, diff: ValueDiff) {
+        if diff.changed() {
+            self.storage.entry(address).or_default().insert(slot, diff);
+        }
+    }
+
+    /// The change in the native eth balance of the address, in eth. An
+    /// untouched address has a delta of zero
+    pub fn eth_delta(&self, address: Address) -> Rational {
+        self.balances
+            .get(&address)
+            .map(|diff| diff.post.to_scaled_rational(18) - diff.pre.to_scaled_rational(18))
+            .unwrap_or(Rational::ZERO)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eth_delta() {
+        let searcher = Address::with_last_byte(1);
+        let mut diff = TxStateDiff::new(TxHash::ZERO, 0);
+        diff.insert_balance(
+            searcher,
+            ValueDiff::new(U256::from(3_000_000_000_000_000_000u128), U256::from(10u128.pow(18))),
+        );
+        diff.insert_balance(
+            Address::with_last_byte(2),
+            ValueDiff::new(U256::from(1), U256::from(1)),
+        );
+
+        assert_eq!(diff.eth_delta(searcher), Rational::from(-2));
+        assert_eq!(diff.eth_delta(Address::with_last_byte(2)), Rational::ZERO);
+        assert_eq!(diff.balances.len(), 1);
+    }
+}
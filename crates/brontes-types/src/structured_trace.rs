@@ -14,6 +14,7 @@ use crate::{
     constants::{EXECUTE_FFS_YO, SCP_MAIN_CEX_DEX_BOT},
     db::clickhouse_serde::tx_trace::*,
     serde_utils::u256,
+    Protocol,
 };
 pub trait TraceActions {
     fn get_callframe_info(&self) -> CallFrameInfo<'_>;
@@ -110,6 +111,7 @@ impl TraceActions for TransactionTraceWithLogs {
             delegate_logs:  vec![],
             msg_sender:     self.msg_sender,
             msg_value:      self.get_msg_value(),
+            protocol:       None,
         }
     }
 }
@@ -148,6 +150,10 @@ pub struct CallFrameInfo<'a> {
     pub delegate_logs:  Vec<&'a Log>,
     pub msg_sender:     Address,
     pub msg_value:      U256,
+    /// the protocol the call was already resolved to, e.g. the one of the
+    /// implementation an unmapped proxy delegates to. Looked up from the target
+    /// when not set
+    pub protocol:       Option<Protocol>,
 }
 
 #[derive(Debug, Clone)]
@@ -157,16 +163,19 @@ pub struct CallInfo {
     pub from_address:   Address,
     pub msg_sender:     Address,
     pub msg_value:      U256,
+    /// the block the call is in, protocol details are looked up as of it
+    pub block:          u64,
 }
 
 impl CallFrameInfo<'_> {
-    pub fn get_fixed_fields(&self) -> CallInfo {
+    pub fn get_fixed_fields(&self, block: u64) -> CallInfo {
         CallInfo {
-            trace_idx:      self.trace_idx,
+            trace_idx: self.trace_idx,
             target_address: self.target_address,
-            from_address:   self.from_address,
-            msg_sender:     self.msg_sender,
-            msg_value:      self.msg_value,
+            from_address: self.from_address,
+            msg_sender: self.msg_sender,
+            msg_value: self.msg_value,
+            block,
         }
     }
 }
@@ -223,6 +232,9 @@ pub struct TxTrace {
     pub tx_hash:         B256,
     pub gas_used:        u128,
     pub effective_price: u128,
+    /// Blob gas used times the block's blob gas price, only set for type-3
+    /// transactions
+    pub blob_gas_paid:   u128,
     pub tx_index:        u64,
     // False if the transaction reverted
     pub is_success:      bool,
@@ -238,7 +250,16 @@ impl TxTrace {
         effective_price: u128,
         is_success: bool,
     ) -> Self {
-        Self { block_number, trace, tx_hash, tx_index, effective_price, gas_used, is_success }
+        Self {
+            block_number,
+            trace,
+            tx_hash,
+            tx_index,
+            effective_price,
+            gas_used,
+            blob_gas_paid: 0,
+            is_success,
+        }
     }
 }
 
@@ -253,6 +274,7 @@ impl Serialize for TxTrace {
         ser_struct.serialize_field("tx_hash", &format!("{:?}", self.tx_hash))?;
         ser_struct.serialize_field("gas_used", &self.gas_used)?;
         ser_struct.serialize_field("effective_price", &self.effective_price)?;
+        ser_struct.serialize_field("blob_gas_paid", &self.blob_gas_paid)?;
         ser_struct.serialize_field("tx_index", &self.tx_index)?;
         ser_struct.serialize_field("is_success", &self.is_success)?;
 
@@ -370,6 +392,7 @@ impl DbRow for TxTrace {
         "tx_hash",
         "gas_used",
         "effective_price",
+        "blob_gas_paid",
         "tx_index",
         "is_success",
         "trace_meta.trace_idx",
@@ -1,13 +1,16 @@
 use alloy_primitives::TxHash;
 use alloy_rpc_types::AnyReceiptEnvelope;
 use reth_primitives::{
-    Address, BlockId, BlockNumber, BlockNumberOrTag, Bytecode, Bytes, Header, StorageValue, B256,
+    Address, BlockId, BlockNumber, BlockNumberOrTag, Bytecode, Bytes, Header, StorageValue,
+    Withdrawal, B256,
 };
 use reth_rpc_types::{
     state::StateOverride, BlockOverrides, Log, TransactionReceipt, TransactionRequest,
 };
 
-use crate::structured_trace::TxTrace;
+use crate::{
+    simulated_bundle::BundleTransaction, state_diff::TxStateDiff, structured_trace::TxTrace,
+};
 
 #[async_trait::async_trait]
 #[auto_impl::auto_impl(Box)]
@@ -43,6 +46,28 @@ pub trait TracingProvider: Send + Sync + 'static {
         block_id: BlockId,
     ) -> eyre::Result<Option<Vec<TxTrace>>>;
 
+    /// Re-executes the whole block in a single pass, returning the exact state
+    /// changes of every transaction. Returns `None` if the provider can't
+    /// execute blocks locally
+    async fn replay_block_state_diffs(
+        &self,
+        _block_id: BlockId,
+    ) -> eyre::Result<Option<Vec<TxStateDiff>>> {
+        Ok(None)
+    }
+
+    /// Executes the transactions in order on top of the state at the end of
+    /// the block, returning their traces. Providers that can't execute
+    /// transactions locally don't support this
+    async fn simulate_bundle(
+        &self,
+        _transactions: Vec<BundleTransaction>,
+        _block_id: BlockId,
+        _state_overrides: Option<StateOverride>,
+    ) -> eyre::Result<Vec<TxTrace>> {
+        eyre::bail!("bundle simulation isn't supported by this tracing provider")
+    }
+
     async fn block_receipts(
         &self,
         number: BlockNumberOrTag,
@@ -50,6 +75,11 @@ pub trait TracingProvider: Send + Sync + 'static {
 
     async fn header_by_number(&self, number: BlockNumber) -> eyre::Result<Option<Header>>;
 
+    /// the beacon chain withdrawals processed in the block, these credit
+    /// balances without a transaction so they never show up in the traces
+    async fn block_withdrawals(&self, number: BlockNumber)
+        -> eyre::Result<Option<Vec<Withdrawal>>>;
+
     async fn block_and_tx_index(&self, hash: TxHash) -> eyre::Result<(u64, usize)>;
 
     // DB Access Methods
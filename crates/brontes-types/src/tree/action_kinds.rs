@@ -0,0 +1,48 @@
+use crate::{db::normalized_actions::ActionKind, normalized_actions::NormalizedAction};
+
+/// The kinds of actions that are present anywhere in a transaction. Built
+/// when the tree is finalized so that searches can skip transactions that
+/// can't contain what they are looking for without walking them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ActionKindSet(u32);
+
+impl ActionKindSet {
+    pub fn from_actions<'a, V: NormalizedAction + 'a>(
+        actions: impl Iterator<Item = &'a V>,
+    ) -> Self {
+        let mut this = Self::default();
+        actions.for_each(|action| this.insert(ActionKind::from(action.get_action())));
+        this
+    }
+
+    pub fn insert(&mut self, kind: ActionKind) {
+        self.0 |= 1 << kind as u32;
+    }
+
+    pub fn contains(&self, kind: ActionKind) -> bool {
+        self.0 & (1 << kind as u32) != 0
+    }
+
+    pub fn contains_any(&self, kinds: &[ActionKind]) -> bool {
+        kinds.iter().any(|kind| self.contains(*kind))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_action_kind_set() {
+        let mut set = ActionKindSet::default();
+        assert!(!set.contains_any(&[ActionKind::Swap, ActionKind::Revert]));
+
+        set.insert(ActionKind::Swap);
+        set.insert(ActionKind::Revert);
+
+        assert!(set.contains(ActionKind::Swap));
+        assert!(set.contains(ActionKind::Revert));
+        assert!(!set.contains(ActionKind::Transfer));
+        assert!(set.contains_any(&[ActionKind::Transfer, ActionKind::Revert]));
+    }
+}
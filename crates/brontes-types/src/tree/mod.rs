@@ -7,11 +7,15 @@ use tracing::{error, info, span, Level};
 
 use crate::{normalized_actions::MultiCallFrameClassification, tree::types::NodeWithDataRef};
 
+pub mod action_kinds;
+pub use action_kinds::*;
 pub mod frontend_prunes;
 pub use frontend_prunes::*;
 
 use crate::db::traits::LibmdbxReader;
 pub mod node;
+pub mod reverted;
+pub use reverted::*;
 mod types;
 #[allow(unused_parens)]
 pub mod util;
@@ -35,6 +39,8 @@ pub struct BlockTree<V: NormalizedAction> {
     pub header:               Header,
     pub priority_fee_std_dev: f64,
     pub avg_priority_fee:     f64,
+    /// Transactions in the block that reverted
+    pub reverted_txs:         Vec<RevertedTx>,
 }
 
 impl<V: NormalizedAction> BlockTree<V> {
@@ -44,6 +50,7 @@ impl<V: NormalizedAction> BlockTree<V> {
             header,
             priority_fee_std_dev: 0.0,
             avg_priority_fee: 0.0,
+            reverted_txs: vec![],
         }
     }
 
@@ -17,6 +17,11 @@ pub struct Node {
     pub trace_address: Vec<usize>,
     pub address:       Address,
     pub data:          usize,
+    /// the call is a callback into the caller of the parent contract, the
+    /// actions in it were started by the caller and not by the parent
+    pub callback:      bool,
+    /// the call or one of its parents reverted, so nothing done in it happened
+    pub reverted:      bool,
 }
 
 impl Node {
@@ -26,6 +31,8 @@ impl Node {
             trace_address,
             address,
             finalized: false,
+            callback: false,
+            reverted: false,
             data: 0,
             inner: vec![],
             subactions: vec![],
@@ -276,9 +283,13 @@ impl Node {
         }
     }
 
+    /// the data indexes of this node and the ones under it. Reverted nodes
+    /// don't have any, as none of their actions happened
     pub fn get_all_sub_actions(&self) -> Vec<usize> {
         if self.finalized {
             self.subactions.clone()
+        } else if self.reverted {
+            vec![]
         } else {
             let mut res = vec![self.data];
             res.extend(
@@ -559,20 +570,57 @@ impl Node {
     ) where
         T: Fn(NodeWithDataRef<'_, V>) -> R,
     {
+        let mut nodes_left = call.node_budget().unwrap_or(usize::MAX);
+        self.collect_with_budget(results, call, wanted_data, data, &mut nodes_left);
+    }
+
+    /// returns false once the search budget is exhausted, so the rest of the
+    /// tree isn't visited
+    fn collect_with_budget<T, R, V: NormalizedAction>(
+        &self,
+        results: &mut Vec<R>,
+        call: &TreeSearchBuilder<V>,
+        wanted_data: &T,
+        data: &NodeData<V>,
+        nodes_left: &mut usize,
+    ) -> bool
+    where
+        T: Fn(NodeWithDataRef<'_, V>) -> R,
+    {
+        if self.callback && call.skips_callback_frames() {
+            return true
+        }
+        if *nodes_left == 0 {
+            return false
+        }
+        *nodes_left -= 1;
+
+        let max_results = call.max_results().unwrap_or(usize::MAX);
         let TreeSearchArgs { collect_current_node, child_node_to_collect, collect_idxs } =
             call.generate_search_args(self, data);
         if collect_current_node {
             if let Some(datas) = data.get_ref(self.data) {
                 for idx in collect_idxs {
+                    if results.len() >= max_results {
+                        return false
+                    }
                     results.push(wanted_data(NodeWithDataRef::new(self, &datas[idx], idx)))
                 }
             }
         }
 
-        if child_node_to_collect {
-            self.inner
-                .iter()
-                .for_each(|i| i.collect(results, call, wanted_data, data))
+        if results.len() >= max_results {
+            return false
         }
+
+        if child_node_to_collect && !call.should_stop_descending(self, data) {
+            for inner in &self.inner {
+                if !inner.collect_with_budget(results, call, wanted_data, data, nodes_left) {
+                    return false
+                }
+            }
+        }
+
+        true
     }
 }
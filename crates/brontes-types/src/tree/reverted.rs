@@ -0,0 +1,49 @@
+use alloy_primitives::{Address, Bytes, B256};
+
+use crate::{
+    structured_trace::{TraceActions, TxTrace},
+    GasDetails,
+};
+
+/// A transaction that reverted. These don't make it into the tree as they
+/// have no effect on state, but failed attempts tell us who else was
+/// competing for an opportunity and what they were willing to pay for it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RevertedTx {
+    pub tx_hash:     B256,
+    pub position:    usize,
+    pub from:        Address,
+    pub gas_details: GasDetails,
+    /// Target and calldata of every call frame of the transaction, including
+    /// the ones made before it reverted
+    pub calls:       Vec<(Address, Bytes)>,
+}
+
+impl RevertedTx {
+    pub fn new(trace: &TxTrace, base_fee: u128) -> Self {
+        let calls = trace
+            .trace
+            .iter()
+            .filter(|frame| !frame.is_create() && !frame.is_static_call())
+            .map(|frame| (frame.get_to_address(), frame.get_calldata()))
+            .collect();
+
+        Self {
+            tx_hash: trace.tx_hash,
+            position: trace.tx_index as usize,
+            from: trace
+                .trace
+                .first()
+                .map(|frame| frame.get_from_addr())
+                .unwrap_or_default(),
+            gas_details: GasDetails {
+                coinbase_transfer:   None,
+                priority_fee:        trace.effective_price.saturating_sub(base_fee),
+                gas_used:            trace.gas_used,
+                effective_gas_price: trace.effective_price,
+                blob_gas_paid:       trace.blob_gas_paid,
+            },
+            calls,
+        }
+    }
+}
@@ -8,7 +8,7 @@ use redefined::self_convert_redefined;
 use reth_primitives::{Address, B256};
 use serde::{Deserialize, Serialize};
 
-use super::Node;
+use super::{ActionKindSet, Node};
 use crate::{
     db::{
         address_metadata::AddressMetadata, metadata::Metadata, searcher::SearcherInfo,
@@ -18,7 +18,7 @@ use crate::{
         Action, MultiCallFrameClassification, NormalizedAction, NormalizedEthTransfer,
     },
     tree::types::NodeWithDataRef,
-    FastHashMap, FastHashSet, TreeSearchBuilder, TxInfo,
+    AggregatorRoute, FastHashMap, FastHashSet, TreeSearchBuilder, TxInfo,
 };
 
 #[derive(Debug, Clone)]
@@ -60,6 +60,10 @@ pub struct Root<V: NormalizedAction> {
     /// eth transfers
     pub total_msg_value_transfers: Vec<NormalizedEthTransfer>,
     pub data_store: NodeData<V>,
+    /// the kinds of actions in the transaction, set once finalized
+    pub action_kinds: Option<ActionKindSet>,
+    /// the aggregator the transaction was sent to, if any
+    pub aggregator_route: Option<AggregatorRoute>,
 }
 
 impl<V: NormalizedAction> Root<V> {
@@ -184,6 +188,7 @@ impl<V: NormalizedAction> Root<V> {
                 searcher_eoa_info,
                 None,
                 self.total_msg_value_transfers.clone(),
+                self.aggregator_route,
             ))
         }
 
@@ -202,6 +207,7 @@ impl<V: NormalizedAction> Root<V> {
             searcher_eoa_info,
             searcher_contract_info,
             self.total_msg_value_transfers.clone(),
+            self.aggregator_route,
         ))
     }
 
@@ -258,6 +264,10 @@ impl<V: NormalizedAction> Root<V> {
 
     pub fn collect(&self, call: &TreeSearchBuilder<V>) -> Vec<V> {
         let mut result = Vec::new();
+        if !call.should_search_tx(self) {
+            return result
+        }
+
         self.head
             .collect(&mut result, call, &|data| data.data.clone(), &self.data_store);
 
@@ -283,6 +293,8 @@ impl<V: NormalizedAction> Root<V> {
 
     pub fn finalize(&mut self) {
         self.head.finalize();
+        self.action_kinds =
+            Some(ActionKindSet::from_actions(self.data_store.0.iter().flatten().flatten()));
     }
 
     pub fn is_private(&self) -> bool {
@@ -343,6 +355,9 @@ pub struct GasDetails {
     pub priority_fee:        u128,
     pub gas_used:            u128,
     pub effective_gas_price: u128,
+    /// What a type-3 transaction paid for its blobs, zero for every other
+    /// transaction and for blocks before Dencun
+    pub blob_gas_paid:       u128,
 }
 //TODO: Fix this
 impl Display for GasDetails {
@@ -350,8 +365,12 @@ impl Display for GasDetails {
         write!(
             f,
             "GasDetails {{ coinbase_transfer: {:?}, priority_fee: {}, gas_used: {}, \
-             effective_gas_price: {} }}",
-            self.coinbase_transfer, self.priority_fee, self.gas_used, self.effective_gas_price
+             effective_gas_price: {}, blob_gas_paid: {} }}",
+            self.coinbase_transfer,
+            self.priority_fee,
+            self.gas_used,
+            self.effective_gas_price,
+            self.blob_gas_paid
         )
     }
 }
@@ -359,8 +378,10 @@ impl Display for GasDetails {
 self_convert_redefined!(GasDetails);
 
 impl GasDetails {
+    /// Everything the transaction spent to be included: execution gas, blob
+    /// gas and any coinbase transfer
     pub fn gas_paid(&self) -> u128 {
-        let mut gas = self.gas_used * self.effective_gas_price;
+        let mut gas = self.fees_paid();
 
         if let Some(coinbase) = self.coinbase_transfer {
             gas += coinbase
@@ -369,6 +390,12 @@ impl GasDetails {
         gas
     }
 
+    /// The fees the sender's balance was charged for the transaction, execution
+    /// and blob gas, without the coinbase transfer
+    pub fn fees_paid(&self) -> u128 {
+        self.gas_used * self.effective_gas_price + self.blob_gas_paid
+    }
+
     pub fn priority_fee(&self, base_fee: u128) -> u128 {
         self.effective_gas_price - base_fee
     }
@@ -381,6 +408,17 @@ impl GasDetails {
         self.coinbase_transfer.unwrap_or_default()
     }
 
+    /// What the transaction paid the builder per unit of gas, priority fee and
+    /// coinbase transfer combined, in gwei
+    pub fn bribe_per_gas(&self) -> f64 {
+        if self.gas_used == 0 {
+            return 0.0
+        }
+
+        let bribe = self.priority_fee * self.gas_used + self.coinbase_transfer();
+        bribe as f64 / self.gas_used as f64 / 1e9
+    }
+
     pub fn merge(&mut self, other: &GasDetails) {
         self.coinbase_transfer = Some(
             self.coinbase_transfer.unwrap_or_default()
@@ -391,6 +429,7 @@ impl GasDetails {
         self.priority_fee += other.priority_fee;
         self.gas_used += other.gas_used;
         self.effective_gas_price += other.effective_gas_price;
+        self.blob_gas_paid += other.blob_gas_paid;
     }
 
     // Pretty print after 'spaces' spaces
@@ -406,6 +445,7 @@ impl GasDetails {
             ("Priority Fee", format!("{} Wei", self.priority_fee)),
             ("Gas Used", self.gas_used.to_string()),
             ("Effective Gas Price", format!("{} Wei", self.effective_gas_price)),
+            ("Blob Gas Paid", format!("{:.7} ETH", self.blob_gas_paid as f64 / 1e18)),
             ("Total Gas Paid in ETH", format!("{:.7} ETH", self.gas_paid() as f64 / 1e18)),
         ];
 
@@ -445,6 +485,7 @@ pub struct ClickhouseVecGasDetails {
     pub priority_fee:        Vec<u128>,
     pub gas_used:            Vec<u128>,
     pub effective_gas_price: Vec<u128>,
+    pub blob_gas_paid:       Vec<u128>,
 }
 
 impl From<(Vec<TxHash>, Vec<GasDetails>)> for ClickhouseVecGasDetails {
@@ -460,6 +501,7 @@ impl From<(Vec<TxHash>, Vec<GasDetails>)> for ClickhouseVecGasDetails {
                     gas.priority_fee,
                     gas.gas_used,
                     gas.effective_gas_price,
+                    gas.blob_gas_paid,
                 )
             })
             .collect::<Vec<_>>();
@@ -470,6 +512,7 @@ impl From<(Vec<TxHash>, Vec<GasDetails>)> for ClickhouseVecGasDetails {
             priority_fee:        vec_vals.iter().map(|val| val.2.to_owned()).collect_vec(),
             gas_used:            vec_vals.iter().map(|val| val.3.to_owned()).collect_vec(),
             effective_gas_price: vec_vals.iter().map(|val| val.4.to_owned()).collect_vec(),
+            blob_gas_paid:       vec_vals.iter().map(|val| val.5.to_owned()).collect_vec(),
         }
     }
 }
@@ -488,6 +531,27 @@ pub enum FalsePositiveEntity {
     MaestroBots,
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blob_gas_paid() {
+        let gas = GasDetails {
+            coinbase_transfer: Some(5),
+            gas_used: 100,
+            effective_gas_price: 10,
+            blob_gas_paid: 50,
+            ..Default::default()
+        };
+        assert_eq!(gas.fees_paid(), 1_050);
+        assert_eq!(gas.gas_paid(), 1_055);
+
+        let vec_gas: ClickhouseVecGasDetails = (vec![TxHash::ZERO], vec![gas]).into();
+        assert_eq!(vec_gas.blob_gas_paid, vec![50]);
+    }
+}
+
 /*
 #[cfg(test)]
 pub mod test {
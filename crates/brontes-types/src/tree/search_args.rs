@@ -1,6 +1,10 @@
 use alloy_primitives::Address;
 
-use crate::{tree::NormalizedAction, Node, NodeData};
+use crate::{
+    db::normalized_actions::ActionKind,
+    tree::{NormalizedAction, Root},
+    Node, NodeData,
+};
 
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct TreeSearchArgs {
@@ -21,6 +25,18 @@ pub struct TreeSearchBuilder<V: NormalizedAction> {
     has_from_address:     Option<Address>,
     /// gets and'd together
     has_to_address:       Option<Vec<Address>>,
+    /// children of a node that has one of these actions aren't searched
+    stop_descending_at:   Vec<fn(&V) -> bool>,
+    /// max amount of nodes visited per transaction
+    node_budget:          Option<usize>,
+    /// stop searching a transaction once this many actions are collected
+    max_results:          Option<usize>,
+    /// transactions that don't contain any of these kinds are skipped
+    tx_action_kinds:      Vec<ActionKind>,
+    /// transactions for which any of these return true are skipped
+    skip_tx_if:           Vec<fn(&Root<V>) -> bool>,
+    /// callback frames and their children aren't searched
+    skip_callback_frames: bool,
 }
 impl<V: NormalizedAction> Default for TreeSearchBuilder<V> {
     fn default() -> Self {
@@ -36,6 +52,12 @@ impl<V: NormalizedAction> TreeSearchBuilder<V> {
             child_nodes_contains: vec![],
             has_from_address:     None,
             has_to_address:       None,
+            stop_descending_at:   vec![],
+            node_budget:          None,
+            max_results:          None,
+            tx_action_kinds:      vec![],
+            skip_tx_if:           vec![],
+            skip_callback_frames: false,
         }
     }
 
@@ -95,6 +117,92 @@ impl<V: NormalizedAction> TreeSearchBuilder<V> {
         self
     }
 
+    /// Once a node has an action matching one of the given functions, its
+    /// children won't be searched. The node itself is still collected if it
+    /// matches the search
+    pub fn stop_descending_at<const N: usize>(mut self, action_fns: [fn(&V) -> bool; N]) -> Self {
+        self.stop_descending_at.extend(action_fns);
+        self
+    }
+
+    /// Limits the amount of nodes that are visited per transaction, anything
+    /// past the budget is ignored
+    pub fn with_node_budget(mut self, max_nodes: usize) -> Self {
+        self.node_budget = Some(max_nodes);
+        self
+    }
+
+    /// Stops searching a transaction once this many actions have been
+    /// collected from it
+    pub fn with_max_results(mut self, max_results: usize) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    /// Skips transactions that don't contain any action of the given kinds,
+    /// using the index built when the tree is finalized
+    pub fn with_tx_action_kinds<const N: usize>(mut self, kinds: [ActionKind; N]) -> Self {
+        self.tx_action_kinds.extend(kinds);
+        self
+    }
+
+    /// Skips transactions for which the given function returns true
+    pub fn skip_tx_if(mut self, skip_fn: fn(&Root<V>) -> bool) -> Self {
+        self.skip_tx_if.push(skip_fn);
+        self
+    }
+
+    /// Doesn't search callback frames, the actions in them were started by
+    /// the caller re-entering from a callback and not by the node that called
+    /// back
+    pub fn skip_callback_frames(mut self) -> Self {
+        self.skip_callback_frames = true;
+        self
+    }
+
+    pub fn skips_callback_frames(&self) -> bool {
+        self.skip_callback_frames
+    }
+
+    /// Whether the transaction should be searched at all
+    pub fn should_search_tx(&self, root: &Root<V>) -> bool {
+        if self.skip_tx_if.iter().any(|skip| skip(root)) {
+            return false
+        }
+
+        if self.tx_action_kinds.is_empty() {
+            return true
+        }
+
+        root.action_kinds
+            .map(|kinds| kinds.contains_any(&self.tx_action_kinds))
+            // the tree isn't finalized yet
+            .unwrap_or(true)
+    }
+
+    pub fn should_stop_descending(&self, node: &Node, node_data: &NodeData<V>) -> bool {
+        if self.stop_descending_at.is_empty() {
+            return false
+        }
+
+        node_data
+            .get_ref(node.data)
+            .map(|actions| {
+                actions
+                    .iter()
+                    .any(|action| self.stop_descending_at.iter().any(|ptr| ptr(action)))
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn node_budget(&self) -> Option<usize> {
+        self.node_budget
+    }
+
+    pub fn max_results(&self) -> Option<usize> {
+        self.max_results
+    }
+
     pub fn generate_search_args(&self, node: &Node, node_data: &NodeData<V>) -> TreeSearchArgs {
         let (collect_current_node, collect_idxs) = self.collect_current_node(node, node_data);
         let child_node_to_collect =
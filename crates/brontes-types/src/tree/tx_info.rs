@@ -3,10 +3,30 @@ use alloy_primitives::{Address, TxHash};
 use crate::{
     db::{address_metadata::ContractType, searcher::SearcherInfo},
     mev::MevType,
-    normalized_actions::NormalizedEthTransfer,
-    FastHashSet, GasDetails,
+    normalized_actions::{Action, NormalizedEthTransfer},
+    FastHashSet, GasDetails, Protocol,
 };
 
+/// The aggregator or settlement contract a transaction was sent to, which
+/// routed the swap on behalf of the user
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AggregatorRoute {
+    pub protocol:   Protocol,
+    pub entrypoint: Address,
+}
+
+impl AggregatorRoute {
+    pub fn from_action(action: &Action) -> Option<Self> {
+        match action {
+            Action::Aggregator(agg) => Some(Self { protocol: agg.protocol, entrypoint: agg.to }),
+            Action::Batch(batch) => {
+                Some(Self { protocol: batch.protocol, entrypoint: batch.settlement_contract })
+            }
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TxInfo {
     pub block_number: u64,
@@ -26,6 +46,8 @@ pub struct TxInfo {
     pub searcher_eoa_info:      Option<SearcherInfo>,
     pub searcher_contract_info: Option<SearcherInfo>,
     pub total_eth_value:        Vec<NormalizedEthTransfer>,
+    /// set when the transaction was sent straight to an aggregator router
+    pub aggregator_route:       Option<AggregatorRoute>,
 }
 
 impl TxInfo {
@@ -45,9 +67,11 @@ impl TxInfo {
         searcher_eoa_info: Option<SearcherInfo>,
         searcher_contract_info: Option<SearcherInfo>,
         total_eth_value: Vec<NormalizedEthTransfer>,
+        aggregator_route: Option<AggregatorRoute>,
     ) -> Self {
         Self {
             total_eth_value,
+            aggregator_route,
             tx_index,
             block_number,
             mev_contract,
@@ -68,6 +92,12 @@ impl TxInfo {
         &self.total_eth_value
     }
 
+    /// Whether the user swapped through an aggregator instead of calling
+    /// their own contract
+    pub fn is_aggregator_routed(&self) -> bool {
+        self.aggregator_route.is_some()
+    }
+
     pub fn split_to_storage_info(self) -> (TxHash, GasDetails) {
         (self.tx_hash, self.gas_details)
     }
@@ -167,3 +197,43 @@ pub fn collect_address_set_for_accounting(tx_infos: &[TxInfo]) -> FastHashSet<Ad
 
     mev_addresses
 }
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::U256;
+
+    use super::*;
+    use crate::normalized_actions::{NormalizedAggregator, NormalizedBatch, NormalizedSwap};
+
+    #[test]
+    fn test_aggregator_route_from_action() {
+        let router = Address::repeat_byte(0x01);
+        let aggregator = Action::Aggregator(NormalizedAggregator {
+            protocol:      Protocol::ParaswapV5,
+            trace_index:   0,
+            from:          Address::repeat_byte(0x02),
+            to:            router,
+            recipient:     Address::repeat_byte(0x02),
+            child_actions: vec![],
+            msg_value:     U256::ZERO,
+        });
+        assert_eq!(
+            AggregatorRoute::from_action(&aggregator),
+            Some(AggregatorRoute { protocol: Protocol::ParaswapV5, entrypoint: router })
+        );
+
+        let settlement = Address::repeat_byte(0x03);
+        let batch = Action::Batch(NormalizedBatch {
+            protocol: Protocol::Cowswap,
+            settlement_contract: settlement,
+            ..Default::default()
+        });
+        assert_eq!(
+            AggregatorRoute::from_action(&batch),
+            Some(AggregatorRoute { protocol: Protocol::Cowswap, entrypoint: settlement })
+        );
+
+        // a swap made straight on a pool isn't routed
+        assert_eq!(AggregatorRoute::from_action(&Action::Swap(NormalizedSwap::default())), None);
+    }
+}
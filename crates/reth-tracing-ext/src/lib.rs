@@ -4,7 +4,11 @@ use std::{
     sync::Arc,
 };
 
-use brontes_types::{structured_trace::TxTrace, BrontesTaskExecutor};
+use brontes_types::{
+    state_diff::{TxStateDiff, ValueDiff},
+    structured_trace::TxTrace,
+    BrontesTaskExecutor,
+};
 use reth_beacon_consensus::BeaconConsensus;
 use reth_blockchain_tree::{
     externals::TreeExternals, BlockchainTree, BlockchainTreeConfig, ShareableBlockchainTree,
@@ -34,8 +38,10 @@ use reth_transaction_pool::{
     blobstore::NoopBlobStore, validate::EthTransactionValidatorBuilder, CoinbaseTipOrdering,
     EthPooledTransaction, EthTransactionValidator, Pool, TransactionValidationTaskExecutor,
 };
+use revm::inspectors::NoOpInspector;
 mod provider;
 pub mod reth_tracer;
+mod simulation;
 
 pub type Provider = BlockchainProvider<
     Arc<DatabaseEnv>,
@@ -149,32 +155,84 @@ impl TracingClient {
         &self,
         block_id: BlockId,
     ) -> EthResult<Option<Vec<TxTrace>>> {
-        let insp_setup = || BrontesTracingInspector {
-            config:                TracingInspectorConfig {
-                record_logs:              true,
-                record_steps:             false,
-                record_state_diff:        false,
-                record_stack_snapshots:   StackSnapshotType::None,
-                record_memory_snapshots:  false,
-                record_call_return_data:  true,
-                exclude_precompile_calls: true,
-            },
-            traces:                CallTraceArena::default(),
-            trace_stack:           Vec::new(),
-            step_stack:            Vec::new(),
-            last_call_return_data: None,
-            gas_inspector:         GasInspector::default(),
-            spec_id:               None,
-        };
+        self.api
+            .trace_block_with_inspector(
+                block_id,
+                brontes_tracing_inspector,
+                move |tx_info, inspector, res, _, _| {
+                    Ok(inspector.into_trace_results(tx_info, &res))
+                },
+            )
+            .await
+    }
 
+    /// Re-executes all transactions in a block without tracing, recording the
+    /// balance and storage changes of each transaction. This is a single pass
+    /// over the block so is cheap enough to run during backfills
+    pub async fn replay_block_with_state_diffs(
+        &self,
+        block_id: BlockId,
+    ) -> EthResult<Option<Vec<TxStateDiff>>> {
         self.api
-            .trace_block_with_inspector(block_id, insp_setup, move |tx_info, inspector, res, _, _| {
-                Ok(inspector.into_trace_results(tx_info, &res))
-            })
+            .trace_block_with_inspector(
+                block_id,
+                || NoOpInspector,
+                move |tx_info, _, _, state, db| {
+                    let mut diff = TxStateDiff::new(
+                        tx_info.hash.unwrap_or_default(),
+                        tx_info.index.unwrap_or_default(),
+                    );
+
+                    for (address, account) in state.iter().filter(|(_, a)| a.is_touched()) {
+                        // the db hasn't had the transactions changes committed yet, so
+                        // the cached account is the state before the transaction
+                        let pre_balance = db
+                            .accounts
+                            .get(address)
+                            .map(|pre| pre.info.balance)
+                            .unwrap_or_default();
+                        diff.insert_balance(
+                            *address,
+                            ValueDiff::new(pre_balance, account.info.balance),
+                        );
+
+                        for (slot, value) in &account.storage {
+                            diff.insert_storage(
+                                *address,
+                                *slot,
+                                ValueDiff::new(value.original_value(), value.present_value()),
+                            );
+                        }
+                    }
+
+                    Ok(diff)
+                },
+            )
             .await
     }
 }
 
+/// The inspector used for all brontes traces
+pub(crate) fn brontes_tracing_inspector() -> BrontesTracingInspector {
+    BrontesTracingInspector {
+        config:                TracingInspectorConfig {
+            record_logs:              true,
+            record_steps:             false,
+            record_state_diff:        false,
+            record_stack_snapshots:   StackSnapshotType::None,
+            record_memory_snapshots:  false,
+            record_call_return_data:  true,
+            exclude_precompile_calls: true,
+        },
+        traces:                CallTraceArena::default(),
+        trace_stack:           Vec::new(),
+        step_stack:            Vec::new(),
+        last_call_return_data: None,
+        gas_inspector:         GasInspector::default(),
+        spec_id:               None,
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct StackStep {
     _trace_idx: usize,
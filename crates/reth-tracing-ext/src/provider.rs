@@ -1,13 +1,16 @@
 use std::cmp::min;
 
 use alloy_rpc_types::AnyReceiptEnvelope;
-use brontes_types::{structured_trace::TxTrace, traits::TracingProvider};
+use brontes_types::{
+    simulated_bundle::BundleTransaction, state_diff::TxStateDiff, structured_trace::TxTrace,
+    traits::TracingProvider,
+};
 use eyre::eyre;
 use reth_primitives::{
-    Address, BlockId, BlockNumber, BlockNumberOrTag, Bytecode, Bytes, Header, StorageValue, TxHash,
-    B256, U256,
+    Address, BlockHashOrNumber, BlockId, BlockNumber, BlockNumberOrTag, Bytecode, Bytes, Header,
+    StorageValue, TxHash, Withdrawal, B256, U256,
 };
-use reth_provider::{BlockIdReader, BlockNumReader, HeaderProvider};
+use reth_provider::{BlockIdReader, BlockNumReader, BlockReader, HeaderProvider};
 use reth_revm::{database::StateProviderDatabase, db::CacheDB};
 use reth_rpc::eth::{
     error::{EthApiError, EthResult, RevertError, RpcInvalidTransactionError},
@@ -88,6 +91,26 @@ impl TracingProvider for TracingClient {
             .map_err(Into::into)
     }
 
+    async fn replay_block_state_diffs(
+        &self,
+        block_id: BlockId,
+    ) -> eyre::Result<Option<Vec<TxStateDiff>>> {
+        self.replay_block_with_state_diffs(block_id)
+            .await
+            .map_err(Into::into)
+    }
+
+    async fn simulate_bundle(
+        &self,
+        transactions: Vec<BundleTransaction>,
+        block_id: BlockId,
+        state_overrides: Option<StateOverride>,
+    ) -> eyre::Result<Vec<TxTrace>> {
+        self.simulate_bundle_with_inspector(transactions, block_id, state_overrides)
+            .await
+            .map_err(Into::into)
+    }
+
     async fn block_receipts(
         &self,
         number: BlockNumberOrTag,
@@ -114,6 +137,17 @@ impl TracingProvider for TracingClient {
             .map_err(Into::into)
     }
 
+    async fn block_withdrawals(
+        &self,
+        number: BlockNumber,
+    ) -> eyre::Result<Option<Vec<Withdrawal>>> {
+        Ok(self
+            .trace
+            .provider()
+            .block(BlockHashOrNumber::Number(number))?
+            .map(|block| block.withdrawals.into_iter().flatten().collect()))
+    }
+
     // DB Access Methods
     async fn get_storage(
         &self,
@@ -0,0 +1,139 @@
+use brontes_types::{simulated_bundle::BundleTransaction, structured_trace::TxTrace};
+use reth_primitives::{
+    keccak256, revm::env::tx_env_with_recovered, BlockId, TransactionSigned,
+    TransactionSignedEcRecovered, U256,
+};
+use reth_provider::TransactionsProvider;
+use reth_revm::{database::StateProviderDatabase, db::CacheDB};
+use reth_rpc::eth::{
+    error::{EthApiError, EthResult},
+    EthTransactions,
+};
+use reth_rpc_types::{state::StateOverride, TransactionInfo};
+use revm::{
+    primitives::{db::DatabaseRef, Bytecode, EnvWithHandlerCfg},
+    Database, DatabaseCommit,
+};
+
+use crate::{brontes_tracing_inspector, TracingClient};
+
+const SECONDS_PER_SLOT: u64 = 12;
+
+impl TracingClient {
+    /// Executes the bundle on top of the state at the end of the block, as if
+    /// it were the start of the next one. Each transaction sees the changes of
+    /// the ones before it
+    pub async fn simulate_bundle_with_inspector(
+        &self,
+        transactions: Vec<BundleTransaction>,
+        block_id: BlockId,
+        state_overrides: Option<StateOverride>,
+    ) -> EthResult<Vec<TxTrace>> {
+        let transactions = transactions
+            .into_iter()
+            .map(|tx| self.recover_bundle_transaction(tx))
+            .collect::<EthResult<Vec<_>>>()?;
+
+        let (cfg, mut block_env, at) = self.api.evm_env_at(block_id).await?;
+        block_env.number += U256::from(1);
+        block_env.timestamp += U256::from(SECONDS_PER_SLOT);
+
+        let state = self.api.state_at(at)?;
+        let mut db = CacheDB::new(StateProviderDatabase::new(state));
+        if let Some(overrides) = state_overrides {
+            apply_state_overrides(overrides, &mut db)?;
+        }
+
+        let mut traces = Vec::with_capacity(transactions.len());
+        for (index, tx) in transactions.into_iter().enumerate() {
+            let env = EnvWithHandlerCfg::new_with_cfg_env(
+                cfg.clone(),
+                block_env.clone(),
+                tx_env_with_recovered(&tx),
+            );
+            let mut inspector = brontes_tracing_inspector();
+            let (res, _) = self.api.inspect(&mut db, env, &mut inspector)?;
+
+            let tx_info = TransactionInfo {
+                hash:         Some(tx.hash()),
+                index:        Some(index as u64),
+                block_hash:   None,
+                block_number: Some(block_env.number.to()),
+                base_fee:     Some(block_env.basefee.to()),
+            };
+            traces.push(inspector.into_trace_results(tx_info, &res.result));
+            db.commit(res.state);
+        }
+
+        Ok(traces)
+    }
+
+    fn recover_bundle_transaction(
+        &self,
+        tx: BundleTransaction,
+    ) -> EthResult<TransactionSignedEcRecovered> {
+        let signed = match tx {
+            BundleTransaction::Raw(raw) => {
+                TransactionSigned::decode_enveloped(&mut raw.as_ref())
+                    .map_err(|_| EthApiError::FailedToDecodeSignedTransaction)?
+            }
+            BundleTransaction::Hash(hash) => self
+                .trace
+                .provider()
+                .transaction_by_hash(hash)?
+                .ok_or(EthApiError::TransactionNotFound)?,
+        };
+
+        signed
+            .into_ecrecovered()
+            .ok_or(EthApiError::InvalidTransactionSignature)
+    }
+}
+
+/// Applies the overrides the same way `eth_call` does, `state` replaces the
+/// whole storage of the account while `stateDiff` only sets the given slots
+fn apply_state_overrides<DB>(overrides: StateOverride, db: &mut CacheDB<DB>) -> EthResult<()>
+where
+    DB: DatabaseRef,
+    EthApiError: From<<DB as DatabaseRef>::Error>,
+{
+    for (address, account_override) in overrides {
+        let mut info = db.basic(address)?.unwrap_or_default();
+        if let Some(balance) = account_override.balance {
+            info.balance = balance;
+        }
+        if let Some(nonce) = account_override.nonce {
+            info.nonce = nonce.to();
+        }
+        if let Some(code) = account_override.code {
+            info.code_hash = keccak256(&code);
+            info.code = Some(Bytecode::new_raw(code));
+        }
+        db.insert_account_info(address, info);
+
+        match (account_override.state, account_override.state_diff) {
+            (Some(_), Some(_)) => return Err(EthApiError::BothStateAndStateDiffInOverride(address)),
+            (Some(state), None) => db.replace_account_storage(
+                address,
+                state
+                    .into_iter()
+                    .map(|(slot, value)| {
+                        (U256::from_be_bytes(slot.0), U256::from_be_bytes(value.0))
+                    })
+                    .collect(),
+            )?,
+            (None, Some(state_diff)) => {
+                for (slot, value) in state_diff {
+                    db.insert_account_storage(
+                        address,
+                        U256::from_be_bytes(slot.0),
+                        U256::from_be_bytes(value.0),
+                    )?;
+                }
+            }
+            (None, None) => {}
+        }
+    }
+
+    Ok(())
+}